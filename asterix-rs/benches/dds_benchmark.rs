@@ -11,7 +11,10 @@
 
 #![cfg(feature = "dds")]
 
-use asterix::transport::dds::{DdsConfig, DdsPublisher, DdsSubscriber, Reliability};
+use asterix::transport::dds::{
+    DdsConfig, DdsPublisher, DdsSubscriber, Durability, FieldFilter, Filter, Reliability,
+};
+use asterix::transport::ratelimit::{RateLimitConfig, RateLimitedPublisher};
 use asterix::types::{AsterixRecord, DataItem, ParsedValue};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::collections::BTreeMap;
@@ -188,6 +191,40 @@ fn bench_publish_record(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark `publish_into` reusing one scratch buffer across iterations,
+/// against `publish`'s fresh-allocation-per-call baseline
+fn bench_publish_into_reused_buffer(c: &mut Criterion) {
+    let config = DdsConfig::best_effort();
+    let publisher = DdsPublisher::new(config).expect("Failed to create publisher");
+
+    let mut group = c.benchmark_group("dds_publish_into_reused_buffer");
+    group.measurement_time(Duration::from_secs(5));
+
+    let record_sizes = [(100, "small_100B"), (1024, "medium_1KB")];
+
+    for (size, name) in record_sizes {
+        let record = create_test_record(48, size);
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("record", name), &record, |b, rec| {
+            b.iter(|| {
+                publisher.publish(black_box(rec)).expect("Publish failed");
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("record_into", name), &record, |b, rec| {
+            let mut buf = Vec::new();
+            b.iter(|| {
+                publisher
+                    .publish_into(black_box(rec), &mut buf)
+                    .expect("Publish failed");
+            })
+        });
+    }
+
+    group.finish();
+}
+
 /// Benchmark pub/sub roundtrip latency
 fn bench_pubsub_latency(c: &mut Criterion) {
     let mut group = c.benchmark_group("dds_pubsub_latency");
@@ -301,6 +338,185 @@ fn bench_qos_configurations(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark acked-publish latency against the existing best-effort path
+///
+/// `publish_acked` blocks until every matched reader has acknowledged the
+/// sample, so it's expected to be markedly slower than `publish_raw` under
+/// best effort; this measures just how much slower, with a matched
+/// subscriber present so acknowledgment can actually complete.
+fn bench_publish_acked_latency(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dds_publish_acked_latency");
+    group.sample_size(20);
+    group.measurement_time(Duration::from_secs(15));
+
+    let record = create_test_record(48, 100);
+
+    group.bench_function("publish_acked", |b| {
+        b.iter_custom(|iters| {
+            let config = DdsConfig {
+                reliability: Reliability::Reliable,
+                durability: Durability::TransientLocal,
+                ..Default::default()
+            };
+
+            let publisher =
+                DdsPublisher::new(config.clone()).expect("Failed to create publisher");
+            let _subscriber = DdsSubscriber::new(config, "asterix_bench_acked")
+                .expect("Failed to create subscriber");
+
+            // Allow DDS discovery so the writer has a matched reader to ack against
+            thread::sleep(Duration::from_millis(500));
+
+            let start = std::time::Instant::now();
+
+            for _ in 0..iters {
+                let _ = publisher.publish_acked(black_box(&record), Duration::from_secs(1));
+            }
+
+            start.elapsed()
+        })
+    });
+
+    group.bench_function("publish_best_effort", |b| {
+        let config = DdsConfig::best_effort();
+        let publisher = DdsPublisher::new(config).expect("Failed to create publisher");
+
+        b.iter(|| {
+            publisher
+                .publish(black_box(&record))
+                .expect("Publish failed");
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmark governed (token-bucket rate-limited) vs ungoverned publish throughput
+///
+/// The governed case sets a generous ceiling (far above what this loop can
+/// actually publish per second without governing) so it measures the
+/// governor's own bookkeeping overhead rather than intentional throttling.
+fn bench_rate_limited_vs_ungoverned(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dds_rate_limited_vs_ungoverned");
+    group.measurement_time(Duration::from_secs(5));
+
+    let payload = create_raw_payload(100);
+
+    group.bench_function("ungoverned", |b| {
+        let config = DdsConfig::best_effort();
+        let publisher = DdsPublisher::new(config).expect("Failed to create publisher");
+
+        b.iter(|| {
+            publisher
+                .publish_raw(48, black_box(&payload))
+                .expect("Publish failed");
+        })
+    });
+
+    group.bench_function("governed", |b| {
+        let config = DdsConfig::best_effort();
+        let publisher = DdsPublisher::new(config).expect("Failed to create publisher");
+        let governed = RateLimitedPublisher::new(
+            publisher,
+            RateLimitConfig {
+                messages_per_second: Some(1_000_000.0),
+                bytes_per_second: Some(1_000_000_000.0),
+            },
+        );
+
+        b.iter(|| {
+            governed
+                .publish_raw(48, black_box(&payload))
+                .expect("Publish failed");
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmark effective receive throughput with vs. without a content filter
+/// under a high publish rate mixing the matching category with others
+///
+/// A publisher fires a mix of cat48/sac1 (matching) and other
+/// category/SAC/SIC combinations (non-matching) at a high rate; the filtered
+/// subscriber should spend its drain loop only on matching samples, while
+/// the unfiltered subscriber pays to receive and discard all of them.
+fn bench_filtered_vs_unfiltered_receive(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dds_filtered_vs_unfiltered_receive");
+    group.sample_size(20);
+    group.measurement_time(Duration::from_secs(15));
+
+    const BURST: usize = 200;
+
+    group.bench_function("unfiltered", |b| {
+        b.iter_custom(|iters| {
+            let config = DdsConfig::best_effort();
+            let publisher =
+                DdsPublisher::new(config.clone()).expect("Failed to create publisher");
+            let mut subscriber = DdsSubscriber::new(config, "asterix_bench_unfiltered")
+                .expect("Failed to create subscriber");
+
+            thread::sleep(Duration::from_millis(500));
+
+            let start = std::time::Instant::now();
+
+            for _ in 0..iters {
+                for i in 0..BURST {
+                    let (category, sac, sic) = if i % 10 == 0 {
+                        (48, 1, 1)
+                    } else {
+                        (34, 9, 9)
+                    };
+                    publisher
+                        .publish_raw_with_routing(category, sac, sic, &[0xAB; 32])
+                        .expect("Publish failed");
+                }
+                while subscriber.recv_timeout(Duration::from_millis(10)).is_some() {}
+            }
+
+            start.elapsed()
+        })
+    });
+
+    group.bench_function("filtered_cat48_sac1", |b| {
+        b.iter_custom(|iters| {
+            let config = DdsConfig::best_effort();
+            let publisher =
+                DdsPublisher::new(config.clone()).expect("Failed to create publisher");
+            let filter = Filter {
+                category: Some(FieldFilter::Eq(48)),
+                sac: Some(FieldFilter::Eq(1)),
+                sic: None,
+            };
+            let mut subscriber =
+                DdsSubscriber::with_filter(config, "asterix_bench_filtered", filter)
+                    .expect("Failed to create filtered subscriber");
+
+            thread::sleep(Duration::from_millis(500));
+
+            let start = std::time::Instant::now();
+
+            for _ in 0..iters {
+                for i in 0..BURST {
+                    let (category, sac, sic) = if i % 10 == 0 {
+                        (48, 1, 1)
+                    } else {
+                        (34, 9, 9)
+                    };
+                    publisher
+                        .publish_raw_with_routing(category, sac, sic, &[0xAB; 32])
+                        .expect("Publish failed");
+                }
+                while subscriber.recv_timeout(Duration::from_millis(10)).is_some() {}
+            }
+
+            start.elapsed()
+        })
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     name = dds_benches;
     config = Criterion::default()
@@ -316,7 +532,11 @@ criterion_group!(
         bench_publish_record,
         bench_pubsub_latency,
         bench_message_throughput,
-        bench_qos_configurations
+        bench_qos_configurations,
+        bench_publish_acked_latency,
+        bench_rate_limited_vs_ungoverned,
+        bench_filtered_vs_unfiltered_receive,
+        bench_publish_into_reused_buffer
 );
 
 criterion_main!(dds_benches);
@@ -0,0 +1,46 @@
+//! Hex decode benchmarks: scalar `from_hex` vs. the table-based `from_hex_fast`
+//!
+//! Compares the two decoders across frame sizes representative of ASTERIX
+//! traffic, from an 8-byte frame up to a large 8 KiB batched record.
+//!
+//! # Running
+//!
+//! ```bash
+//! cargo bench --features simd-hex --bench hex_benchmark
+//! ```
+
+#![cfg(feature = "simd-hex")]
+
+use asterix::hex::{from_hex, from_hex_fast};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::hint::black_box;
+
+const FRAME_SIZES: [usize; 4] = [8, 64, 1024, 8192];
+
+fn hex_input(len: usize) -> String {
+    (0..len)
+        .map(|i| format!("{:02x}", (i % 256) as u8))
+        .collect()
+}
+
+fn bench_from_hex_scalar_vs_fast(c: &mut Criterion) {
+    let mut group = c.benchmark_group("hex_decode");
+
+    for &bytes in &FRAME_SIZES {
+        let hex = hex_input(bytes);
+        group.throughput(Throughput::Bytes(bytes as u64));
+
+        group.bench_with_input(BenchmarkId::new("scalar", bytes), &hex, |b, hex| {
+            b.iter(|| from_hex(black_box(hex)).unwrap())
+        });
+
+        group.bench_with_input(BenchmarkId::new("table", bytes), &hex, |b, hex| {
+            b.iter(|| from_hex_fast(black_box(hex.as_bytes())).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_from_hex_scalar_vs_fast);
+criterion_main!(benches);
@@ -38,6 +38,7 @@ fn bench_parse_cat048_raw(c: &mut Criterion) {
             verbose: true,
             filter_category: None,
             max_records: None,
+            ..Default::default()
         };
         b.iter(|| {
             let records = parse(black_box(&data), options.clone()).expect("Parse failed");
@@ -163,6 +164,7 @@ fn bench_parse_with_filter(c: &mut Criterion) {
             verbose: false,
             filter_category: None,
             max_records: None,
+            ..Default::default()
         };
         b.iter(|| {
             let records = parse(black_box(&data), options.clone()).expect("Parse failed");
@@ -175,6 +177,7 @@ fn bench_parse_with_filter(c: &mut Criterion) {
             verbose: false,
             filter_category: Some(62),
             max_records: None,
+            ..Default::default()
         };
         b.iter(|| {
             let records = parse(black_box(&data), options.clone()).expect("Parse failed");
@@ -202,6 +205,7 @@ fn bench_parse_with_limit(c: &mut Criterion) {
                     verbose: false,
                     filter_category: None,
                     max_records: Some(max),
+                    ..Default::default()
                 };
                 b.iter(|| {
                     let records = parse(black_box(&data), options.clone()).expect("Parse failed");
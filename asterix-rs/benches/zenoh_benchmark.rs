@@ -11,7 +11,7 @@
 
 #![cfg(feature = "zenoh")]
 
-use asterix::transport::zenoh::{ZenohConfig, ZenohPublisher, ZenohSubscriber};
+use asterix::transport::zenoh::{PayloadFormat, ZenohConfig, ZenohPublisher, ZenohSubscriber};
 use asterix::types::{AsterixRecord, DataItem, ParsedValue};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::collections::BTreeMap;
@@ -380,6 +380,63 @@ fn bench_message_throughput(c: &mut Criterion) {
     });
 }
 
+/// Benchmark publish throughput across `PayloadFormat` variants (`Raw`,
+/// `Json`, `Cbor`, `MessagePack`) at each size in the same payload-size
+/// matrix [`bench_publish_record`] uses, so the per-encoding overhead
+/// (serialization + the wire encoding Zenoh attaches) is directly
+/// comparable across both encoding and size.
+fn bench_payload_format_throughput(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+
+    let formats = [
+        (PayloadFormat::Raw, "raw"),
+        (PayloadFormat::Json, "json"),
+        (PayloadFormat::Cbor, "cbor"),
+        (PayloadFormat::MessagePack, "messagepack"),
+    ];
+
+    let mut group = c.benchmark_group("zenoh_payload_format_throughput");
+    group.measurement_time(Duration::from_secs(5));
+
+    let record_sizes = [(100, "small_100B"), (1024, "medium_1KB")];
+
+    for (format, format_name) in formats {
+        let publisher = rt.block_on(async {
+            let config = ZenohConfig {
+                payload_format: format,
+                ..ZenohConfig::peer_to_peer()
+            };
+            ZenohPublisher::new(config)
+                .await
+                .expect("Failed to create publisher")
+        });
+
+        for (size, size_name) in record_sizes {
+            let record = create_test_record(48, size);
+            group.throughput(Throughput::Bytes(size as u64));
+
+            group.bench_with_input(
+                BenchmarkId::new(format_name, size_name),
+                &record,
+                |b, rec| {
+                    b.to_async(&rt).iter(|| async {
+                        publisher
+                            .publish(black_box(rec))
+                            .await
+                            .expect("Publish failed");
+                    })
+                },
+            );
+        }
+
+        rt.block_on(async {
+            publisher.close().await.expect("Failed to close");
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     zenoh_benches,
     bench_publisher_connect,
@@ -387,6 +444,7 @@ criterion_group!(
     bench_publish_raw_throughput,
     bench_publish_with_routing,
     bench_publish_record,
+    bench_payload_format_throughput,
     bench_pubsub_latency,
     bench_fan_in,
     bench_message_throughput,
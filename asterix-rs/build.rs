@@ -45,16 +45,30 @@ fn main() {
             // Use vcpkg to find expat on Windows
             // This correctly handles vcpkg's libexpatMD.lib naming convention
             // (vcpkg uses libexpatMD.lib instead of expat.lib for x64-windows-static-md triplet)
-            match vcpkg::find_package("expat") {
+            let triplet = vcpkg_triplet();
+            let mut cfg = vcpkg::Config::new();
+            cfg.emit_includes(true);
+            // VCPKGRS_TRIPLET/VCPKGRS_DYNAMIC are the vcpkg crate's own override
+            // knobs; when set, let it read them itself instead of racing them
+            // with our own target_triplet() guess.
+            if env::var("VCPKGRS_TRIPLET").is_err() && env::var("VCPKGRS_DYNAMIC").is_err() {
+                cfg.target_triplet(&triplet);
+            }
+            match cfg.find_package("expat") {
                 Ok(lib) => {
-                    eprintln!("Successfully found expat via vcpkg:");
+                    eprintln!("Successfully found expat via vcpkg ({triplet}):");
                     eprintln!("  Include paths: {:?}", lib.include_paths);
                     eprintln!("  Link paths: {:?}", lib.link_paths);
                 }
                 Err(e) => {
                     eprintln!("Warning: vcpkg could not find expat: {}", e);
                     eprintln!("Falling back to manual linking (may fail with LNK1181 error)");
-                    println!("cargo:rustc-link-lib=expat");
+                    let lib_name = if triplet.contains("static") {
+                        "expat-static"
+                    } else {
+                        "expat"
+                    };
+                    println!("cargo:rustc-link-lib={lib_name}");
                 }
             }
         }
@@ -65,6 +79,93 @@ fn main() {
             }
         }
     }
+
+    // Generate the `capi` feature's C header + pkg-config file. Cargo sets
+    // CARGO_FEATURE_<NAME> for every enabled feature, so this only runs the
+    // extra codegen when a consumer actually opted in; the default build
+    // (just the Rust rlib) never touches cbindgen or the filesystem writes
+    // below.
+    if env::var("CARGO_FEATURE_CAPI").is_ok() {
+        generate_capi_artifacts();
+    }
+}
+
+/// Emits `asterix.h` (via cbindgen, over the `extern "C"` surface in
+/// `src/capi.rs`) and a matching `asterix.pc` pkg-config file into `OUT_DIR`,
+/// so a C/C++ consumer can `pkg-config --cflags --libs asterix` against this
+/// crate the same way it would against the C++ ASTERIX core.
+///
+/// Only invoked when the `capi` feature is enabled (see [`main`]).
+fn generate_capi_artifacts() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let version = env::var("CARGO_PKG_VERSION").unwrap();
+
+    println!("cargo:rerun-if-changed=src/capi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    match cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_language(cbindgen::Language::C)
+        .generate()
+    {
+        Ok(bindings) => {
+            bindings.write_to_file(PathBuf::from(&out_dir).join("asterix.h"));
+        }
+        Err(e) => {
+            eprintln!("Warning: cbindgen failed to generate asterix.h: {e}");
+        }
+    }
+
+    let pc_contents = format!(
+        "prefix={out_dir}\n\
+         includedir=${{prefix}}\n\
+         libdir=${{prefix}}\n\
+         \n\
+         Name: asterix\n\
+         Description: ASTERIX decoder C API\n\
+         Version: {version}\n\
+         Libs: -L${{libdir}} -lasterix_ffi_bridge -lexpat\n\
+         Cflags: -I${{includedir}}\n"
+    );
+    let pc_path = PathBuf::from(&out_dir).join("asterix.pc");
+    if let Err(e) = std::fs::write(&pc_path, pc_contents) {
+        eprintln!("Warning: failed to write {}: {e}", pc_path.display());
+    }
+}
+
+/// Target-appropriate C++ standard/PIC flags that `flag_if_supported` alone
+/// can't express, plus the `ASTERIX_EXTRA_CXXFLAGS` escape hatch.
+///
+/// MSVC and GNU-style compilers spell `-std=c++17` differently, and `-fPIC`
+/// is meaningless to MSVC (position independence is the default there) — so
+/// both are chosen from `CARGO_CFG_TARGET_ENV` rather than probed. 32-bit
+/// Unix targets need `-fPIC` unconditionally (this build previously used
+/// `flag_if_supported` everywhere, which silently dropped it whenever the
+/// probe compile for unrelated reasons failed, regressing i686 builds); other
+/// Unix targets still just request it.
+fn configure_target_flags(bridge: &mut cc::Build) {
+    let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+    let target_family = env::var("CARGO_CFG_TARGET_FAMILY").unwrap_or_default();
+    let pointer_width = env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+    let is_msvc = target_env == "msvc";
+
+    if is_msvc {
+        bridge.flag_if_supported("/std:c++17");
+    } else {
+        bridge.flag_if_supported("-std=c++17");
+        if pointer_width == "32" && target_family == "unix" {
+            bridge.flag("-fPIC");
+        } else {
+            bridge.flag_if_supported("-fPIC");
+        }
+    }
+
+    if let Ok(extra) = env::var("ASTERIX_EXTRA_CXXFLAGS") {
+        for flag in extra.split_whitespace() {
+            bridge.flag(flag);
+        }
+    }
 }
 
 fn compile_cpp_with_ffi_bridge() {
@@ -85,10 +186,15 @@ fn compile_cpp_with_ffi_bridge() {
         .include("src") // Include our own src directory for ffi_wrapper.h
         .include(&asterix_src)
         .include(&engine_src)
-        .flag_if_supported("-std=c++17")
-        .flag_if_supported("-fPIC")
         .warnings(false); // Suppress warnings from C++ code
 
+    // cxx_build's underlying cc::Build already honors CXX/CXXFLAGS (and CC's
+    // C counterparts) when it resolves the toolchain, so a cross-compile just
+    // setting those env vars works without any code here. What it doesn't do
+    // on its own is pick the right -std=/-fPIC spelling for the target, so
+    // that part is explicit below.
+    configure_target_flags(&mut bridge);
+
     // On Windows, add vcpkg include/lib paths if CMAKE_TOOLCHAIN_FILE is set
     // Note: This runs when building ON Windows (cross-compilation aware)
     if cfg!(windows) || env::var("CARGO_CFG_TARGET_OS").unwrap() == "windows" {
@@ -99,8 +205,9 @@ fn compile_cpp_with_ffi_bridge() {
                 .strip_suffix("/scripts/buildsystems/vcpkg.cmake")
                 .or_else(|| toolchain.strip_suffix("\\scripts\\buildsystems\\vcpkg.cmake"))
             {
-                let vcpkg_include = format!("{vcpkg_root}/installed/x64-windows/include");
-                let vcpkg_lib = format!("{vcpkg_root}/installed/x64-windows/lib");
+                let triplet = vcpkg_triplet();
+                let vcpkg_include = format!("{vcpkg_root}/installed/{triplet}/include");
+                let vcpkg_lib = format!("{vcpkg_root}/installed/{triplet}/lib");
 
                 eprintln!("Using vcpkg paths:");
                 eprintln!("  Include: {vcpkg_include}");
@@ -118,8 +225,9 @@ fn compile_cpp_with_ffi_bridge() {
 
             // Check VCPKG_ROOT environment variable
             if let Ok(vcpkg_root) = env::var("VCPKG_ROOT") {
-                let vcpkg_include = format!("{vcpkg_root}/installed/x64-windows/include");
-                let vcpkg_lib = format!("{vcpkg_root}/installed/x64-windows/lib");
+                let triplet = vcpkg_triplet();
+                let vcpkg_include = format!("{vcpkg_root}/installed/{triplet}/include");
+                let vcpkg_lib = format!("{vcpkg_root}/installed/{triplet}/lib");
 
                 eprintln!("Found VCPKG_ROOT:");
                 eprintln!("  Include: {vcpkg_include}");
@@ -220,6 +328,40 @@ fn compile_cpp_with_ffi_bridge() {
     bridge.compile("asterix_ffi_bridge");
 }
 
+/// The vcpkg triplet to use for Windows include/lib paths, derived from the
+/// compilation target rather than hardcoded to `x64-windows`.
+///
+/// `VCPKGRS_TRIPLET`, if set, wins outright (it's the vcpkg crate's own
+/// override knob, so honoring it here keeps our manual path-building in sync
+/// with whatever `vcpkg::Config` itself picks up). Otherwise the triplet is
+/// assembled from `CARGO_CFG_TARGET_ARCH` plus a linkage selector: a
+/// `RUSTFLAGS` containing `-Ctarget-feature=+crt-static` selects the
+/// `-static-md` triplet, mirroring vcpkg's own convention that CRT-static
+/// builds link against the static-md variant of a library.
+fn vcpkg_triplet() -> String {
+    if let Ok(triplet) = env::var("VCPKGRS_TRIPLET") {
+        return triplet;
+    }
+
+    let arch = match env::var("CARGO_CFG_TARGET_ARCH").as_deref() {
+        Ok("x86_64") => "x64",
+        Ok("x86") => "x86",
+        Ok("aarch64") => "arm64",
+        Ok("arm") => "arm",
+        _ => "x64",
+    };
+
+    let static_crt = env::var("RUSTFLAGS")
+        .map(|flags| flags.contains("target-feature=+crt-static"))
+        .unwrap_or(false);
+
+    if static_crt {
+        format!("{arch}-windows-static-md")
+    } else {
+        format!("{arch}-windows")
+    }
+}
+
 fn link_system_library() {
     // Attempt to use system-installed ASTERIX library
     // This is for advanced users who have built and installed the library system-wide
@@ -0,0 +1,71 @@
+//! Example: Export ASTERIX data to Parquet via Arrow
+//!
+//! This example demonstrates parsing ASTERIX data and exporting it as one
+//! Parquet file per category, for loading into DataFusion/pandas.
+//! Requires the 'arrow' feature to be enabled.
+//!
+//! Usage:
+//!   cargo run --example arrow_export --features arrow -- <input_file> <output_dir>
+
+#[cfg(not(feature = "arrow"))]
+fn main() {
+    eprintln!("This example requires the 'arrow' feature to be enabled.");
+    eprintln!("\nRun with:");
+    eprintln!("  cargo run --example arrow_export --features arrow -- <input_file> <output_dir>");
+    std::process::exit(1);
+}
+
+#[cfg(feature = "arrow")]
+fn main() {
+    use asterix::columnar::write_parquet;
+    use asterix::{parse, ParseOptions};
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+    use std::process;
+
+    let args: Vec<String> = env::args().collect();
+
+    if args.len() < 3 {
+        eprintln!("Usage: {} <input_file> <output_dir>", args[0]);
+        eprintln!("\nEach category in <input_file> is written to its own");
+        eprintln!("cat{{NNN}}.parquet file inside <output_dir>.");
+        eprintln!("\nExample:");
+        eprintln!("  {} input.pcap ./parquet_out", args[0]);
+        process::exit(1);
+    }
+
+    let input_file = &args[1];
+    let output_dir = Path::new(&args[2]);
+
+    println!("Reading: {input_file}");
+
+    let data = match fs::read(input_file) {
+        Ok(data) => data,
+        Err(e) => {
+            eprintln!("Error reading file: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("File size: {} bytes", data.len());
+
+    println!("Parsing ASTERIX data...");
+    let records = match parse(&data, ParseOptions::default()) {
+        Ok(records) => records,
+        Err(e) => {
+            eprintln!("Parse error: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("✓ Parsed {} record(s)", records.len());
+
+    println!("Writing Parquet to: {}", output_dir.display());
+    if let Err(e) = write_parquet(output_dir, &records) {
+        eprintln!("Parquet export error: {e}");
+        process::exit(1);
+    }
+
+    println!("\n✓ Export completed successfully");
+}
@@ -9,6 +9,13 @@
 //! cargo run --features dbus --example dbus_service
 //! ```
 //!
+//! Pass `--introspect` to print the interface's D-Bus introspection XML and
+//! exit, instead of starting the service:
+//!
+//! ```bash
+//! cargo run --features dbus --example dbus_service -- --introspect
+//! ```
+//!
 //! # Testing
 //!
 //! From another terminal:
@@ -33,6 +40,11 @@ use asterix::transport::dbus::{DbusConfig, DbusService};
 
 #[cfg(feature = "dbus")]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if std::env::args().any(|arg| arg == "--introspect") {
+        print!("{}", DbusService::introspect());
+        return Ok(());
+    }
+
     // Initialize logging
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
 
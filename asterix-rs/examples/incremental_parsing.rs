@@ -1,12 +1,14 @@
 //! Example: Incremental parsing of large ASTERIX files
 //!
-//! This example demonstrates how to use parse_with_offset for processing
-//! large ASTERIX data files or streams incrementally.
+//! This example demonstrates using `asterix::stream` to process large
+//! ASTERIX data files chunk-at-a-time without holding every record in
+//! memory at once, and without hand-rolling the `parse_with_offset`
+//! offset/`remaining_blocks` bookkeeping.
 //!
 //! Usage:
 //!   cargo run --example incremental_parsing -- <file.asterix> [blocks_per_chunk]
 
-use asterix::{init_default, parse_with_offset, ParseOptions};
+use asterix::{init_default, stream, ParseOptions};
 use std::env;
 use std::fs;
 use std::process;
@@ -39,59 +41,33 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let data = fs::read(filename)?;
     println!("File size: {} bytes", data.len());
 
-    // Parse incrementally
     let options = ParseOptions {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
-    let mut offset = 0;
-    let mut total_records = 0;
-    let mut chunk_num = 0;
-
-    println!("\nParsing in chunks of {} blocks...\n", blocks_per_chunk);
-
-    loop {
-        chunk_num += 1;
-        print!("Chunk {}: ", chunk_num);
+    println!("\nStreaming in chunks of {} blocks...\n", blocks_per_chunk);
 
-        let result = match parse_with_offset(&data, offset, blocks_per_chunk, options.clone()) {
-            Ok(r) => r,
+    let mut total_records = 0;
+    for record in stream(&data, blocks_per_chunk, options) {
+        let record = match record {
+            Ok(record) => record,
             Err(e) => {
-                eprintln!("\nParse error at offset {}: {}", offset, e);
+                eprintln!("\nParse error: {e}");
                 break;
             }
         };
 
-        let chunk_records = result.records.len();
-        total_records += chunk_records;
-
-        println!(
-            "parsed {} records, consumed {} bytes, {} blocks remaining",
-            chunk_records,
-            result.bytes_consumed - offset,
-            result.remaining_blocks
-        );
-
-        offset = result.bytes_consumed;
-
-        // Check if we're done
-        if result.remaining_blocks == 0 || chunk_records == 0 {
-            println!("\nReached end of data");
-            break;
-        }
-
-        // Safety check to prevent infinite loop
-        if offset >= data.len() {
-            println!("\nReached end of file");
-            break;
+        total_records += 1;
+        if total_records % blocks_per_chunk == 0 {
+            println!("...{total_records} record(s) so far (cat{:03})", record.category);
         }
     }
 
     println!("\nParsing complete!");
-    println!("Total records parsed: {}", total_records);
-    println!("Total bytes processed: {}/{}", offset, data.len());
+    println!("Total records parsed: {total_records}");
 
     Ok(())
 }
@@ -1,10 +1,18 @@
 //! Example: Export ASTERIX data to JSON
 //!
-//! This example demonstrates parsing ASTERIX data and exporting to JSON format.
+//! This example demonstrates parsing ASTERIX data and exporting to JSON,
+//! in one of three shapes: a pretty-printed array (the default, matching
+//! this example's original behavior), a compact single-line array, or
+//! newline-delimited JSON (NDJSON). Except in pretty mode, output is
+//! written incrementally as each batch comes back from `parse_with_offset`,
+//! so exporting a huge capture never holds more than `blocks_per_batch`
+//! records' worth of serialized text at once.
 //! Requires the 'serde' feature to be enabled.
 //!
 //! Usage:
-//!   cargo run --example json_export --features serde -- <input_file> [output_file]
+//!   cargo run --example json_export --features serde -- <input_file> [output_file] [format] [blocks_per_batch]
+//!
+//! format: pretty (default) | array | lines
 
 #[cfg(not(feature = "serde"))]
 fn main() {
@@ -16,29 +24,39 @@ fn main() {
 
 #[cfg(feature = "serde")]
 fn main() {
-    use asterix::{parse, ParseOptions};
+    use asterix::{parse_with_offset, JsonExporter, JsonFormat, ParseOptions};
     use std::env;
     use std::fs;
-    use std::io::Write;
+    use std::io::BufWriter;
     use std::process;
 
     let args: Vec<String> = env::args().collect();
 
     if args.len() < 2 {
-        eprintln!("Usage: {} <input_file> [output_file]", args[0]);
-        eprintln!("\nIf output_file is omitted, JSON is written to stdout.");
+        eprintln!("Usage: {} <input_file> [output_file] [format] [blocks_per_batch]", args[0]);
+        eprintln!("\nIf output_file is omitted or \"-\", JSON is written to stdout.");
+        eprintln!("format: pretty (default) | array | lines");
         eprintln!("\nExample:");
         eprintln!("  {} input.pcap output.json", args[0]);
-        eprintln!("  {} input.raw | jq .", args[0]);
+        eprintln!("  {} input.raw - lines | jq -c .", args[0]);
         process::exit(1);
     }
 
     let input_file = &args[1];
-    let output_file = args.get(2).map(|s| s.as_str());
+    let output_file = args.get(2).map(|s| s.as_str()).filter(|s| *s != "-");
+    let format = match args.get(3).map(|s| s.as_str()) {
+        None | Some("pretty") => JsonFormat::Pretty,
+        Some("array") => JsonFormat::Array,
+        Some("lines") => JsonFormat::Lines,
+        Some(other) => {
+            eprintln!("Unknown format '{other}' (expected pretty, array, or lines)");
+            process::exit(1);
+        }
+    };
+    let blocks_per_batch: usize = args.get(4).and_then(|s| s.parse().ok()).unwrap_or(100);
 
-    println!("Reading: {input_file}");
+    eprintln!("Reading: {input_file}");
 
-    // Read input file
     let data = match fs::read(input_file) {
         Ok(data) => data,
         Err(e) => {
@@ -47,55 +65,55 @@ fn main() {
         }
     };
 
-    println!("File size: {} bytes", data.len());
+    eprintln!("File size: {} bytes", data.len());
 
-    // Parse ASTERIX data
-    println!("Parsing ASTERIX data...");
-    let records = match parse(&data, ParseOptions::default()) {
-        Ok(records) => records,
-        Err(e) => {
-            eprintln!("Parse error: {e}");
-            process::exit(1);
-        }
+    let writer: Box<dyn std::io::Write> = match output_file {
+        Some(path) => match fs::File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(e) => {
+                eprintln!("Error creating file: {e}");
+                process::exit(1);
+            }
+        },
+        None => Box::new(std::io::stdout()),
     };
 
-    println!("✓ Parsed {} record(s)", records.len());
-
-    // Serialize to JSON
-    println!("Serializing to JSON...");
-    let json = match serde_json::to_string_pretty(&records) {
-        Ok(json) => json,
+    let mut exporter = match JsonExporter::new(writer, format) {
+        Ok(exporter) => exporter,
         Err(e) => {
-            eprintln!("JSON serialization error: {e}");
+            eprintln!("Error starting JSON export: {e}");
             process::exit(1);
         }
     };
 
-    println!("✓ JSON size: {} bytes", json.len());
-
-    // Write output
-    match output_file {
-        Some(path) => {
-            // Write to file
-            match fs::File::create(path) {
-                Ok(mut file) => {
-                    if let Err(e) = file.write_all(json.as_bytes()) {
-                        eprintln!("Error writing file: {e}");
-                        process::exit(1);
-                    }
-                    println!("✓ Written to: {path}");
-                }
-                Err(e) => {
-                    eprintln!("Error creating file: {e}");
-                    process::exit(1);
-                }
+    eprintln!("Parsing and exporting ASTERIX data...");
+    let mut offset = 0;
+    let mut total_records = 0;
+    loop {
+        let result = match parse_with_offset(&data, offset, blocks_per_batch, ParseOptions::default()) {
+            Ok(result) => result,
+            Err(e) => {
+                eprintln!("Parse error: {e}");
+                process::exit(1);
             }
+        };
+
+        total_records += result.records.len();
+        if let Err(e) = exporter.write_batch(&result.records) {
+            eprintln!("Error writing batch: {e}");
+            process::exit(1);
         }
-        None => {
-            // Write to stdout
-            println!("\n{json}");
+
+        offset = result.bytes_consumed;
+        if result.remaining_blocks == 0 {
+            break;
         }
     }
 
-    println!("\n✓ Export completed successfully");
+    if let Err(e) = exporter.finish() {
+        eprintln!("Error finishing JSON export: {e}");
+        process::exit(1);
+    }
+
+    eprintln!("\n✓ Exported {total_records} record(s)");
 }
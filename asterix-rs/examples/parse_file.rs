@@ -57,6 +57,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     println!("\nParsing ASTERIX data...");
@@ -65,7 +66,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         Err(e) => {
             eprintln!("Parse error: {e}");
             match e {
-                AsterixError::ParseError { offset, message } => {
+                AsterixError::ParseError { offset, message, .. } => {
                     eprintln!("  Offset: {offset} (0x{offset:X})");
                     eprintln!("  Message: {message}");
                 }
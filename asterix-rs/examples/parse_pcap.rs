@@ -61,6 +61,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         verbose: false, // Disable verbose for performance
         filter_category: None,
         max_records,
+        ..Default::default()
     };
 
     let mut all_records = Vec::new();
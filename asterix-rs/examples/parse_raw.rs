@@ -53,6 +53,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         verbose: true,         // Include descriptions
         filter_category: None, // Parse all categories
         max_records: Some(10), // Limit to first 10 records for demo
+        ..Default::default()
     };
 
     let records = parse(&data, options)?;
@@ -0,0 +1,75 @@
+//! Example: Decode a live ASTERIX feed from UDP multicast
+//!
+//! This example demonstrates `AsterixSource`: joining a UDP multicast group,
+//! decoding each datagram as it arrives, and printing the resulting records,
+//! the way `parse_raw` does for a static file.
+//!
+//! Usage:
+//!     cargo run --example stream_multicast -- [multicast_addr] [port]
+//!
+//! Example:
+//!     cargo run --example stream_multicast -- 239.0.0.1 8600
+//!
+//! # Feeding it test data
+//!
+//! From another terminal, send a minimal CAT32 header-only datablock to the
+//! same group/port with a small script or `socat`, and this example prints
+//! the decoded record.
+
+use asterix::init_default;
+use asterix::source::udp::{AsterixSource, UdpSourceConfig};
+use std::env;
+use std::net::Ipv4Addr;
+use std::process;
+use std::time::Duration;
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().collect();
+
+    let mut config = UdpSourceConfig::default();
+    if let Some(addr) = args.get(1) {
+        config.multicast_addr = addr.parse::<Ipv4Addr>()?;
+    }
+    if let Some(port) = args.get(2) {
+        config.port = port.parse::<u16>()?;
+    }
+
+    println!("ASTERIX Multicast Stream Example");
+    println!("================================\n");
+
+    println!("Initializing ASTERIX parser...");
+    init_default()?;
+    println!("✓ Parser initialized with default categories\n");
+
+    println!(
+        "Joining multicast group {}:{}...",
+        config.multicast_addr, config.port
+    );
+    let source = AsterixSource::bind(config)?;
+    let records = source.spawn();
+    println!("✓ Listening for live ASTERIX datablocks (Ctrl-C to stop)\n");
+
+    loop {
+        match records.recv_timeout(Duration::from_secs(5))? {
+            Some(record) => {
+                println!(
+                    "CAT{:03} record: {} bytes, {} data items, crc=0x{:08X}",
+                    record.category,
+                    record.length,
+                    record.item_count(),
+                    record.crc
+                );
+            }
+            None => {
+                println!("... no datagrams in the last 5s, still listening ...");
+            }
+        }
+    }
+}
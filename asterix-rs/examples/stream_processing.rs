@@ -62,6 +62,7 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     // Main processing loop
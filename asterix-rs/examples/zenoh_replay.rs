@@ -0,0 +1,179 @@
+//! Zenoh Historical Replay Example
+//!
+//! This example demonstrates pulling back stored ASTERIX samples from a
+//! [`ZenohQueryable`](asterix::transport::zenoh::ZenohQueryable) via
+//! [`ZenohQueryClient`](asterix::transport::zenoh::ZenohQueryClient)'s `get()`,
+//! instead of subscribing live, and decoding them through the same pipeline
+//! live data goes through. Useful for post-incident analysis: pull back the
+//! last N minutes of a category without needing a separate recording format.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Replay the last 5 minutes of CAT062 tracks
+//! cargo run --example zenoh_replay --features zenoh -- --filter "asterix/62/**" --minutes 5
+//!
+//! # Connect to a specific router
+//! cargo run --example zenoh_replay --features zenoh -- --router tcp/192.168.1.1:7447 --minutes 10
+//! ```
+
+use std::env;
+use std::process;
+
+#[cfg(feature = "zenoh")]
+use asterix::transport::zenoh::{QueryTarget, ZenohConfig, ZenohQueryClient};
+#[cfg(feature = "zenoh")]
+use asterix::{ParseOptions, SerializedDecoder};
+
+#[cfg(feature = "zenoh")]
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Initialize logging
+    env_logger::init();
+
+    // Parse command line arguments
+    let args: Vec<String> = env::args().collect();
+    let options = parse_args(&args);
+
+    // Create Zenoh config
+    let config = match &options.router {
+        Some(endpoint) => {
+            println!("Connecting to router: {endpoint}");
+            ZenohConfig::with_router(endpoint)
+        }
+        None => {
+            println!("Using multicast discovery");
+            ZenohConfig::default()
+        }
+    };
+
+    let now_micros = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_micros() as u64;
+    let window_micros = options.minutes * 60 * 1_000_000;
+    let time_range = Some((now_micros.saturating_sub(window_micros), now_micros));
+
+    println!(
+        "Querying {} for the last {} minute(s)",
+        options.key_expr, options.minutes
+    );
+
+    let client = ZenohQueryClient::new(&config).await?;
+    let samples = client
+        .query(&options.key_expr, time_range, QueryTarget::All)
+        .await?;
+    client.close().await?;
+
+    println!("Retrieved {} stored sample(s)\n", samples.len());
+
+    let decoder = SerializedDecoder::new();
+    let parse_options = ParseOptions::default();
+
+    let mut record_count = 0;
+    for sample in samples {
+        match decoder.parse(sample.data.clone(), parse_options.clone()) {
+            Ok(records) => {
+                for record in records {
+                    record_count += 1;
+                    println!("Record #{record_count}");
+                    println!("  Key:        {}", sample.key_expr);
+                    println!("  Category:   {}", record.category);
+                    println!("  Timestamp:  {}", record.timestamp_ms);
+                    println!("  Item count: {}", record.item_count());
+                    println!();
+                }
+            }
+            Err(err) => {
+                eprintln!("Failed to decode sample on {}: {err}", sample.key_expr);
+            }
+        }
+    }
+
+    println!("Total records decoded: {record_count}");
+
+    Ok(())
+}
+
+#[cfg(not(feature = "zenoh"))]
+fn main() {
+    eprintln!("This example requires the 'zenoh' feature.");
+    eprintln!("Run with: cargo run --example zenoh_replay --features zenoh");
+    process::exit(1);
+}
+
+fn parse_args(args: &[String]) -> CliOptions {
+    let mut options = CliOptions {
+        router: None,
+        key_expr: "asterix/**".to_string(),
+        minutes: 5,
+    };
+    let mut i = 1;
+
+    while i < args.len() {
+        match args[i].as_str() {
+            "--router" | "-r" => {
+                if i + 1 < args.len() {
+                    options.router = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("--router requires an argument");
+                    print_usage();
+                    process::exit(1);
+                }
+            }
+            "--filter" | "-f" => {
+                if i + 1 < args.len() {
+                    options.key_expr = args[i + 1].clone();
+                    i += 2;
+                } else {
+                    eprintln!("--filter requires an argument");
+                    print_usage();
+                    process::exit(1);
+                }
+            }
+            "--minutes" | "-m" => {
+                if i + 1 < args.len() {
+                    options.minutes = args[i + 1].parse().unwrap_or(5);
+                    i += 2;
+                } else {
+                    eprintln!("--minutes requires a number");
+                    print_usage();
+                    process::exit(1);
+                }
+            }
+            "--help" | "-h" => {
+                print_usage();
+                process::exit(0);
+            }
+            _ => {
+                eprintln!("Unknown argument: {}", args[i]);
+                print_usage();
+                process::exit(1);
+            }
+        }
+    }
+
+    options
+}
+
+/// Parsed CLI arguments for this example
+struct CliOptions {
+    router: Option<String>,
+    key_expr: String,
+    minutes: u64,
+}
+
+fn print_usage() {
+    eprintln!("\nUsage: zenoh_replay [OPTIONS]");
+    eprintln!("\nOptions:");
+    eprintln!("  -r, --router <ENDPOINT>  Connect to Zenoh router (e.g., tcp/192.168.1.1:7447)");
+    eprintln!("  -f, --filter <KEY_EXPR>  Key expression to query (default: asterix/**)");
+    eprintln!("  -m, --minutes <N>        Replay window, in minutes (default: 5)");
+    eprintln!("  -h, --help               Show this help message");
+    eprintln!("\nThis only retrieves data held by a running ZenohQueryable that");
+    eprintln!("subscribed while the data was published — it is not a standalone");
+    eprintln!("recording store.");
+    eprintln!("\nExamples:");
+    eprintln!("  zenoh_replay --filter \"asterix/62/**\" --minutes 5");
+    eprintln!("  zenoh_replay --router tcp/10.0.0.1:7447 --minutes 30");
+}
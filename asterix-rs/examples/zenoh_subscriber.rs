@@ -13,13 +13,29 @@
 //!
 //! # Connect to a specific router
 //! cargo run --example zenoh_subscriber --features zenoh -- --router tcp/192.168.1.1:7447
+//!
+//! # Decode samples into ASTERIX records instead of dumping raw bytes
+//! cargo run --example zenoh_subscriber --features zenoh -- --decode
+//!
+//! # Decode, and only print CAT048 records carrying a Mode 3/A code
+//! cargo run --example zenoh_subscriber --features zenoh -- --decode --require-item I048/070
+//!
+//! # Decode with a StreamParser instead, for a publisher whose samples don't
+//! # each carry one self-contained block (e.g. raw bytes off a transport
+//! # that fragments or coalesces blocks across samples)
+//! cargo run --example zenoh_subscriber --features zenoh -- --streaming
 //! ```
 
 use std::env;
 use std::process;
 
 #[cfg(feature = "zenoh")]
-use asterix::transport::zenoh::{ZenohConfig, ZenohSubscriber};
+use std::sync::Arc;
+
+#[cfg(feature = "zenoh")]
+use asterix::transport::zenoh::{DecodingSubscriber, ZenohConfig, ZenohSubscriber};
+#[cfg(feature = "zenoh")]
+use asterix::{ParseOptions, RecordFilter, SerializedDecoder, StreamParser};
 
 #[cfg(feature = "zenoh")]
 #[tokio::main]
@@ -29,13 +45,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Parse command line arguments
     let args: Vec<String> = env::args().collect();
-    let (router, key_expr, max_samples) = parse_args(&args);
+    let options = parse_args(&args);
 
     // Create Zenoh config
-    let config = match router {
+    let config = match &options.router {
         Some(endpoint) => {
             println!("Connecting to router: {endpoint}");
-            ZenohConfig::with_router(&endpoint)
+            ZenohConfig::with_router(endpoint)
         }
         None => {
             println!("Using multicast discovery");
@@ -43,20 +59,38 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     };
 
-    println!("Subscribing to: {key_expr}");
-    if let Some(max) = max_samples {
+    println!("Subscribing to: {}", options.key_expr);
+    if let Some(max) = options.max_samples {
         println!("Will receive {max} samples then exit");
     } else {
         println!("Press Ctrl+C to exit");
     }
 
-    // Create subscriber
-    let mut subscriber = ZenohSubscriber::new(config, &key_expr).await?;
+    let count = if options.streaming {
+        run_streaming(config, &options).await?
+    } else if options.decode {
+        run_decoded(config, &options).await?
+    } else {
+        run_raw(config, &options).await?
+    };
+
+    println!("Total samples received: {count}");
+
+    Ok(())
+}
+
+/// Subscribe and print each sample's raw bytes plus its Zenoh metadata,
+/// without touching the ASTERIX decoder.
+#[cfg(feature = "zenoh")]
+async fn run_raw(
+    config: ZenohConfig,
+    options: &CliOptions,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut subscriber = ZenohSubscriber::new(config, &options.key_expr).await?;
     println!("Zenoh subscriber created\n");
 
     let mut count = 0;
 
-    // Receive samples
     while let Some(sample) = subscriber.recv().await {
         count += 1;
 
@@ -85,8 +119,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         println!();
 
-        // Check if we've received enough samples
-        if let Some(max) = max_samples {
+        if let Some(max) = options.max_samples {
             if count >= max {
                 println!("Received {count} samples, exiting");
                 break;
@@ -94,12 +127,117 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    // Close subscriber
     subscriber.close().await?;
+    Ok(count)
+}
 
-    println!("Total samples received: {count}");
+/// Subscribe and decode each sample's bytes into [`asterix::AsterixRecord`]s
+/// via a [`DecodingSubscriber`] before printing them, optionally dropping
+/// records that don't carry `--require-item`.
+///
+/// A key expression (`--filter`) only narrows which Zenoh samples arrive at
+/// all; it can't see inside a sample's decoded fields. `--require-item` is
+/// the decoded-field counterpart: it runs after decoding, via
+/// [`ParseOptions::filter`], so it can express predicates the key expression
+/// never could (e.g. "has a Mode 3/A code" rather than just "is CAT048").
+#[cfg(feature = "zenoh")]
+async fn run_decoded(
+    config: ZenohConfig,
+    options: &CliOptions,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let decoder = SerializedDecoder::new();
+    let mut parse_options = ParseOptions::default();
+    if let Some(item_id) = options.require_item.clone() {
+        let filter: RecordFilter = Arc::new(move |record| record.items.contains_key(&item_id));
+        parse_options.filter = Some(filter);
+    }
 
-    Ok(())
+    let mut subscriber =
+        DecodingSubscriber::new(config, &options.key_expr, decoder, parse_options).await?;
+    println!("Zenoh decoding subscriber created\n");
+
+    let mut count = 0;
+
+    while let Some(records) = subscriber.recv().await {
+        for record in records {
+            count += 1;
+
+            println!("Record #{count}");
+            println!("  Category:   {}", record.category);
+            println!("  Timestamp:  {}", record.timestamp_ms);
+            println!("  Item count: {}", record.item_count());
+            for item_id in record.items.keys() {
+                println!("    {item_id}");
+            }
+            println!();
+        }
+
+        if let Some(max) = options.max_samples {
+            if count >= max {
+                println!("Received {count} records, exiting");
+                break;
+            }
+        }
+    }
+
+    subscriber.close().await?;
+    Ok(count)
+}
+
+/// Subscribe to raw samples and feed them through a [`StreamParser`] instead
+/// of decoding each sample as its own self-contained block.
+///
+/// [`DecodingSubscriber`] (used by [`run_decoded`]) assumes one sample is one
+/// complete block; that holds for a well-behaved Zenoh publisher, but not for
+/// every transport a sample's raw bytes might ultimately come from (e.g. a
+/// bridged UDP/CAN feed that fragments or coalesces blocks across payloads).
+/// `StreamParser::feed` accumulates whatever arrives and `poll` drains as
+/// many complete blocks as are currently buffered, carrying a trailing
+/// partial block over to the next sample — with `resync` enabled, a corrupt
+/// length field no longer gets the stream stuck, either.
+#[cfg(feature = "zenoh")]
+async fn run_streaming(
+    config: ZenohConfig,
+    options: &CliOptions,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    let mut subscriber = ZenohSubscriber::new(config, &options.key_expr).await?;
+    println!("Zenoh subscriber created (streaming mode)\n");
+
+    let parse_options = ParseOptions {
+        resync: true,
+        ..Default::default()
+    };
+    let mut stream = StreamParser::new(parse_options);
+    let mut count = 0;
+
+    while let Some(sample) = subscriber.recv().await {
+        stream.feed(&sample.data);
+
+        let result = stream.poll()?;
+        for record in result.records {
+            count += 1;
+
+            println!("Record #{count}");
+            println!("  Category:   {}", record.category);
+            println!("  Timestamp:  {}", record.timestamp_ms);
+            println!("  Item count: {}", record.item_count());
+            for item_id in record.items.keys() {
+                println!("    {item_id}");
+            }
+            println!();
+
+            if let Some(max) = options.max_samples {
+                if count >= max {
+                    println!("Received {count} records, exiting");
+                    subscriber.close().await?;
+                    return Ok(count);
+                }
+            }
+        }
+    }
+
+    subscriber.close().await?;
+    Ok(count)
 }
 
 #[cfg(not(feature = "zenoh"))]
@@ -109,17 +247,22 @@ fn main() {
     process::exit(1);
 }
 
-fn parse_args(args: &[String]) -> (Option<String>, String, Option<usize>) {
-    let mut router = None;
-    let mut key_expr = "asterix/**".to_string();
-    let mut max_samples = None;
+fn parse_args(args: &[String]) -> CliOptions {
+    let mut options = CliOptions {
+        router: None,
+        key_expr: "asterix/**".to_string(),
+        max_samples: None,
+        decode: false,
+        require_item: None,
+        streaming: false,
+    };
     let mut i = 1;
 
     while i < args.len() {
         match args[i].as_str() {
             "--router" | "-r" => {
                 if i + 1 < args.len() {
-                    router = Some(args[i + 1].clone());
+                    options.router = Some(args[i + 1].clone());
                     i += 2;
                 } else {
                     eprintln!("--router requires an argument");
@@ -129,7 +272,7 @@ fn parse_args(args: &[String]) -> (Option<String>, String, Option<usize>) {
             }
             "--filter" | "-f" => {
                 if i + 1 < args.len() {
-                    key_expr = args[i + 1].clone();
+                    options.key_expr = args[i + 1].clone();
                     i += 2;
                 } else {
                     eprintln!("--filter requires an argument");
@@ -139,7 +282,7 @@ fn parse_args(args: &[String]) -> (Option<String>, String, Option<usize>) {
             }
             "--max" | "-n" => {
                 if i + 1 < args.len() {
-                    max_samples = args[i + 1].parse().ok();
+                    options.max_samples = args[i + 1].parse().ok();
                     i += 2;
                 } else {
                     eprintln!("--max requires a number");
@@ -147,6 +290,24 @@ fn parse_args(args: &[String]) -> (Option<String>, String, Option<usize>) {
                     process::exit(1);
                 }
             }
+            "--decode" | "-d" => {
+                options.decode = true;
+                i += 1;
+            }
+            "--streaming" => {
+                options.streaming = true;
+                i += 1;
+            }
+            "--require-item" => {
+                if i + 1 < args.len() {
+                    options.require_item = Some(args[i + 1].clone());
+                    i += 2;
+                } else {
+                    eprintln!("--require-item requires an item id, e.g. I048/070");
+                    print_usage();
+                    process::exit(1);
+                }
+            }
             "--help" | "-h" => {
                 print_usage();
                 process::exit(0);
@@ -159,16 +320,39 @@ fn parse_args(args: &[String]) -> (Option<String>, String, Option<usize>) {
         }
     }
 
-    (router, key_expr, max_samples)
+    if options.require_item.is_some() && !options.decode {
+        eprintln!("--require-item has no effect without --decode");
+        print_usage();
+        process::exit(1);
+    }
+
+    options
+}
+
+/// Parsed CLI arguments for this example
+struct CliOptions {
+    router: Option<String>,
+    key_expr: String,
+    max_samples: Option<usize>,
+    decode: bool,
+    require_item: Option<String>,
+    streaming: bool,
 }
 
 fn print_usage() {
     eprintln!("\nUsage: zenoh_subscriber [OPTIONS]");
     eprintln!("\nOptions:");
-    eprintln!("  -r, --router <ENDPOINT>  Connect to Zenoh router (e.g., tcp/192.168.1.1:7447)");
-    eprintln!("  -f, --filter <KEY_EXPR>  Key expression to subscribe to (default: asterix/**)");
-    eprintln!("  -n, --max <N>            Exit after receiving N samples");
-    eprintln!("  -h, --help               Show this help message");
+    eprintln!("  -r, --router <ENDPOINT>   Connect to Zenoh router (e.g., tcp/192.168.1.1:7447)");
+    eprintln!("  -f, --filter <KEY_EXPR>   Key expression to subscribe to (default: asterix/**)");
+    eprintln!("  -n, --max <N>             Exit after receiving N samples");
+    eprintln!("  -d, --decode              Decode samples into ASTERIX records instead of");
+    eprintln!("                            printing raw bytes");
+    eprintln!("      --require-item <ID>   Only print decoded records containing this item");
+    eprintln!("                            (e.g. I048/070 for Mode 3/A); requires --decode");
+    eprintln!("      --streaming           Decode with a StreamParser instead of --decode's");
+    eprintln!("                            one-sample-per-block DecodingSubscriber, for a feed");
+    eprintln!("                            whose samples don't align to block boundaries");
+    eprintln!("  -h, --help                Show this help message");
     eprintln!("\nKey Expression Examples:");
     eprintln!("  asterix/**         All ASTERIX data");
     eprintln!("  asterix/48/**      All CAT048 data");
@@ -177,4 +361,5 @@ fn print_usage() {
     eprintln!("  zenoh_subscriber");
     eprintln!("  zenoh_subscriber --filter \"asterix/48/**\"");
     eprintln!("  zenoh_subscriber --router tcp/10.0.0.1:7447 --max 100");
+    eprintln!("  zenoh_subscriber --decode --require-item I048/070");
 }
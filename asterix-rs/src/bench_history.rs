@@ -0,0 +1,320 @@
+//! Benchmark result history: JSON persistence and markdown regression report
+//!
+//! The `criterion` benches under `benches/` (`parse_cat048_raw`,
+//! `parse_pcap_format`, `incremental_parsing`, etc.) print a fresh set of
+//! throughput numbers on every `cargo bench` run and then discard them —
+//! nothing compares today's numbers against yesterday's. [`BenchmarkRecord`]
+//! captures one named measurement (throughput, mean time, the commit and
+//! timestamp it was taken at); [`BenchmarkCollection`] is an append-only,
+//! `serde_json`-backed log of them on disk. [`compare`] diffs a fresh batch
+//! of records against the most recent stored record of the same name and
+//! flags anything slower by more than a threshold percentage, and
+//! [`render_markdown_table`] turns that into the table the `bench_report`
+//! binary (`src/bin/bench_report.rs`) prints for contributors and CI.
+//!
+//! This module only models the history and the comparison — reading
+//! `criterion`'s own `target/criterion/**/new/estimates.json` output into
+//! [`BenchmarkRecord`]s is `bench_report`'s job, since that's where the
+//! criterion output directory layout is a concern rather than a library one.
+//!
+//! # Example
+//!
+//! ```
+//! use asterix::bench_history::{compare, BenchmarkCollection, BenchmarkRecord};
+//!
+//! let mut history = BenchmarkCollection::new();
+//! history.push(BenchmarkRecord {
+//!     name: "parse_cat048_raw/default_options".to_string(),
+//!     bytes_per_sec: 50_000_000.0,
+//!     ns_per_iter: 1000.0,
+//!     commit: "abc1234".to_string(),
+//!     timestamp: 1_700_000_000,
+//! });
+//!
+//! let current = vec![BenchmarkRecord {
+//!     name: "parse_cat048_raw/default_options".to_string(),
+//!     bytes_per_sec: 40_000_000.0,
+//!     ns_per_iter: 1250.0,
+//!     commit: "def5678".to_string(),
+//!     timestamp: 1_700_000_100,
+//! }];
+//!
+//! let comparisons = compare(&history, &current, 5.0);
+//! assert!(comparisons[0].regressed); // 25% slower, beyond the 5% threshold
+//! ```
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{AsterixError, Result};
+
+/// One measurement of a single named benchmark (typically
+/// `<criterion group>/<criterion id>`, e.g. `"parse_cat048_raw/default_options"`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    /// The benchmark's name, stable across runs so later measurements can
+    /// be matched back to earlier ones in [`BenchmarkCollection::baseline`].
+    pub name: String,
+    /// Measured throughput, in bytes/second. `0.0` if the benchmark has no
+    /// associated `Throughput` (criterion only reports one when the bench
+    /// calls `group.throughput(..)`).
+    pub bytes_per_sec: f64,
+    /// Mean time per iteration, in nanoseconds — criterion's own
+    /// `mean.point_estimate`.
+    pub ns_per_iter: f64,
+    /// The commit this measurement was taken at (e.g. `git rev-parse --short HEAD`).
+    pub commit: String,
+    /// Unix timestamp, in seconds, of when this measurement was recorded.
+    pub timestamp: u64,
+}
+
+/// An append-only, `serde_json`-backed log of [`BenchmarkRecord`]s.
+///
+/// Records are appended in the order they're measured and never rewritten
+/// in place, so [`BenchmarkCollection::baseline`] always has the full
+/// history to fall back through even if a particular run is missing a
+/// benchmark (e.g. a new one was added, or an old one was dropped).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BenchmarkCollection {
+    records: Vec<BenchmarkRecord>,
+}
+
+impl BenchmarkCollection {
+    /// An empty collection.
+    pub fn new() -> Self {
+        BenchmarkCollection::default()
+    }
+
+    /// Load a collection from `path`, or an empty one if the file doesn't
+    /// exist yet (the first `cargo run --bin bench_report` in a fresh
+    /// checkout has no history to compare against).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if the file exists but can't be
+    /// read, or is present but isn't valid JSON.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(json_err),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BenchmarkCollection::new()),
+            Err(e) => Err(io_err(e)),
+        }
+    }
+
+    /// Write this collection to `path` as pretty-printed JSON, overwriting
+    /// whatever was there.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if writing fails.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(json_err)?;
+        std::fs::write(path, json).map_err(io_err)
+    }
+
+    /// Append a new record.
+    pub fn push(&mut self, record: BenchmarkRecord) {
+        self.records.push(record);
+    }
+
+    /// All records, oldest first.
+    pub fn records(&self) -> &[BenchmarkRecord] {
+        &self.records
+    }
+
+    /// The most recently appended record named `name`, i.e. the baseline a
+    /// fresh measurement of that benchmark should be compared against.
+    pub fn baseline(&self, name: &str) -> Option<&BenchmarkRecord> {
+        self.records.iter().rev().find(|r| r.name == name)
+    }
+}
+
+/// One row of a [`compare`] result: a fresh measurement against its stored baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkComparison {
+    /// The benchmark's name.
+    pub name: String,
+    /// The baseline's `ns_per_iter`, or `None` if this benchmark has never been recorded before.
+    pub baseline_ns_per_iter: Option<f64>,
+    /// The fresh measurement's `ns_per_iter`.
+    pub current_ns_per_iter: f64,
+    /// `(current - baseline) / baseline * 100`; positive means slower. `None` with no baseline.
+    pub percent_delta: Option<f64>,
+    /// Whether `percent_delta` exceeds the caller's regression threshold.
+    pub regressed: bool,
+}
+
+/// Compare each of `current`'s records against `history`'s stored baseline
+/// for that benchmark's name, flagging anything slower by more than
+/// `regression_threshold_pct` percent.
+///
+/// A benchmark with no prior baseline (new in this run) is never flagged —
+/// there's nothing to regress against yet.
+pub fn compare(
+    history: &BenchmarkCollection,
+    current: &[BenchmarkRecord],
+    regression_threshold_pct: f64,
+) -> Vec<BenchmarkComparison> {
+    current
+        .iter()
+        .map(|record| {
+            let baseline = history.baseline(&record.name);
+            let percent_delta = baseline.map(|b| {
+                if b.ns_per_iter == 0.0 {
+                    0.0
+                } else {
+                    (record.ns_per_iter - b.ns_per_iter) / b.ns_per_iter * 100.0
+                }
+            });
+            let regressed = percent_delta.is_some_and(|delta| delta > regression_threshold_pct);
+            BenchmarkComparison {
+                name: record.name.clone(),
+                baseline_ns_per_iter: baseline.map(|b| b.ns_per_iter),
+                current_ns_per_iter: record.ns_per_iter,
+                percent_delta,
+                regressed,
+            }
+        })
+        .collect()
+}
+
+/// Render `comparisons` as a markdown table, one row per benchmark.
+pub fn render_markdown_table(comparisons: &[BenchmarkComparison]) -> String {
+    let mut out = String::from("| Benchmark | Baseline (ns/iter) | Current (ns/iter) | Delta | Status |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for c in comparisons {
+        let baseline = c
+            .baseline_ns_per_iter
+            .map_or_else(|| "-".to_string(), |b| format!("{b:.1}"));
+        let delta = c
+            .percent_delta
+            .map_or_else(|| "-".to_string(), |d| format!("{d:+.1}%"));
+        let status = if c.regressed {
+            "REGRESSION"
+        } else if c.percent_delta.is_some() {
+            "ok"
+        } else {
+            "new"
+        };
+        out.push_str(&format!(
+            "| {} | {} | {:.1} | {} | {} |\n",
+            c.name, baseline, c.current_ns_per_iter, delta, status
+        ));
+    }
+    out
+}
+
+fn io_err(err: std::io::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+fn json_err(err: serde_json::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, ns_per_iter: f64) -> BenchmarkRecord {
+        BenchmarkRecord {
+            name: name.to_string(),
+            bytes_per_sec: 0.0,
+            ns_per_iter,
+            commit: "deadbee".to_string(),
+            timestamp: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn test_load_missing_file_returns_empty_collection() {
+        let collection = BenchmarkCollection::load("/tmp/does-not-exist-bench-history.json")
+            .expect("missing file should load as empty");
+        assert!(collection.records().is_empty());
+    }
+
+    #[test]
+    fn test_save_then_load_round_trips() {
+        let mut collection = BenchmarkCollection::new();
+        collection.push(record("parse_cat048_raw/default_options", 1000.0));
+
+        let path = std::env::temp_dir().join("asterix_bench_history_roundtrip.json");
+        collection.save(&path).expect("save should succeed");
+
+        let loaded = BenchmarkCollection::load(&path).expect("load should succeed");
+        assert_eq!(loaded.records(), collection.records());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_baseline_returns_most_recent_matching_name() {
+        let mut collection = BenchmarkCollection::new();
+        collection.push(record("parse_cat048_raw/default_options", 1000.0));
+        collection.push(record("parse_pcap_format/cat_062_065", 2000.0));
+        collection.push(record("parse_cat048_raw/default_options", 1100.0));
+
+        let baseline = collection
+            .baseline("parse_cat048_raw/default_options")
+            .expect("should find a baseline");
+        assert_eq!(baseline.ns_per_iter, 1100.0);
+    }
+
+    #[test]
+    fn test_baseline_missing_name_returns_none() {
+        let collection = BenchmarkCollection::new();
+        assert!(collection.baseline("never_measured").is_none());
+    }
+
+    #[test]
+    fn test_compare_flags_regression_beyond_threshold() {
+        let mut history = BenchmarkCollection::new();
+        history.push(record("parse_cat048_raw/default_options", 1000.0));
+
+        let current = vec![record("parse_cat048_raw/default_options", 1060.0)];
+        let comparisons = compare(&history, &current, 5.0);
+
+        assert_eq!(comparisons.len(), 1);
+        assert!(comparisons[0].regressed);
+        assert!((comparisons[0].percent_delta.unwrap() - 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_compare_does_not_flag_within_threshold() {
+        let mut history = BenchmarkCollection::new();
+        history.push(record("parse_cat048_raw/default_options", 1000.0));
+
+        let current = vec![record("parse_cat048_raw/default_options", 1020.0)];
+        let comparisons = compare(&history, &current, 5.0);
+
+        assert!(!comparisons[0].regressed);
+    }
+
+    #[test]
+    fn test_compare_with_no_baseline_is_not_regressed() {
+        let history = BenchmarkCollection::new();
+        let current = vec![record("brand_new_bench", 500.0)];
+        let comparisons = compare(&history, &current, 5.0);
+
+        assert!(comparisons[0].baseline_ns_per_iter.is_none());
+        assert!(comparisons[0].percent_delta.is_none());
+        assert!(!comparisons[0].regressed);
+    }
+
+    #[test]
+    fn test_render_markdown_table_has_header_and_one_row_per_comparison() {
+        let mut history = BenchmarkCollection::new();
+        history.push(record("a", 100.0));
+        let current = vec![record("a", 200.0), record("b", 50.0)];
+        let comparisons = compare(&history, &current, 5.0);
+
+        let table = render_markdown_table(&comparisons);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 4); // header + separator + 2 rows
+        assert!(lines[2].contains('a'));
+        assert!(lines[2].contains("REGRESSION"));
+        assert!(lines[3].contains('b'));
+        assert!(lines[3].contains("new"));
+    }
+}
@@ -0,0 +1,238 @@
+//! `bench_report`: compare the latest `cargo bench` run against stored history
+//!
+//! Walks `target/criterion/**/new/estimates.json` — criterion's own
+//! per-benchmark output, one directory per `<group>/<id>` pair (e.g.
+//! `parse_cat048_raw/default_options`) — turns each into an
+//! [`asterix::BenchmarkRecord`] tagged with the current commit and
+//! timestamp, compares it against the most recent record of the same name
+//! in the history file via [`asterix::compare_benchmarks`], and prints the
+//! resulting markdown table (see [`asterix::bench_history::render_markdown_table`])
+//! to stdout. Every record from this run is then appended to the history
+//! file, so the next run has a baseline to compare against.
+//!
+//! # Usage
+//!
+//! ```bash
+//! cargo bench
+//! cargo run --bin bench_report
+//! cargo run --bin bench_report -- --threshold 10 --history benches/history.json
+//! ```
+//!
+//! Exits non-zero (after still printing the table and updating history) if
+//! any benchmark regressed beyond the threshold, so it can gate CI.
+
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use asterix::bench_history::render_markdown_table;
+use asterix::{compare_benchmarks, BenchmarkCollection, BenchmarkRecord};
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("bench_report: {err}");
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let options = parse_args();
+
+    let current = collect_current_records(&options.criterion_dir, &options.commit)?;
+    if current.is_empty() {
+        eprintln!(
+            "No criterion output found under {}; run `cargo bench` first.",
+            options.criterion_dir.display()
+        );
+        process::exit(1);
+    }
+
+    let mut history = BenchmarkCollection::load(&options.history_path)?;
+    let comparisons = compare_benchmarks(&history, &current, options.threshold_pct);
+    println!("{}", render_markdown_table(&comparisons));
+
+    let regressed: Vec<&str> = comparisons
+        .iter()
+        .filter(|c| c.regressed)
+        .map(|c| c.name.as_str())
+        .collect();
+    if !regressed.is_empty() {
+        eprintln!(
+            "{} benchmark(s) regressed beyond {:.1}%:",
+            regressed.len(),
+            options.threshold_pct
+        );
+        for name in &regressed {
+            eprintln!("  {name}");
+        }
+    }
+
+    for record in current {
+        history.push(record);
+    }
+    history.save(&options.history_path)?;
+
+    if !regressed.is_empty() {
+        process::exit(1);
+    }
+    Ok(())
+}
+
+/// Recursively find every `new/estimates.json` under `criterion_dir` and
+/// turn it into a [`BenchmarkRecord`] named after its path relative to
+/// `criterion_dir` (e.g. `parse_cat048_raw/default_options`).
+fn collect_current_records(
+    criterion_dir: &Path,
+    commit: &str,
+) -> Result<Vec<BenchmarkRecord>, Box<dyn std::error::Error>> {
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let mut records = Vec::new();
+    collect_estimates(criterion_dir, criterion_dir, commit, timestamp, &mut records)?;
+    records.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(records)
+}
+
+fn collect_estimates(
+    root: &Path,
+    dir: &Path,
+    commit: &str,
+    timestamp: u64,
+    out: &mut Vec<BenchmarkRecord>,
+) -> std::io::Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.file_name().and_then(|n| n.to_str()) == Some("new") {
+            let estimates_path = path.join("estimates.json");
+            if let Some(record) = read_estimate(root, &path, &estimates_path, commit, timestamp) {
+                out.push(record);
+            }
+        } else {
+            collect_estimates(root, &path, commit, timestamp, out)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_estimate(
+    root: &Path,
+    new_dir: &Path,
+    estimates_path: &Path,
+    commit: &str,
+    timestamp: u64,
+) -> Option<BenchmarkRecord> {
+    let contents = std::fs::read_to_string(estimates_path).ok()?;
+    let estimates: serde_json::Value = serde_json::from_str(&contents).ok()?;
+    let ns_per_iter = estimates.get("mean")?.get("point_estimate")?.as_f64()?;
+
+    let bench_dir = new_dir.parent()?;
+    let name = bench_dir
+        .strip_prefix(root)
+        .unwrap_or(bench_dir)
+        .components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    let bytes_per_sec = std::fs::read_to_string(new_dir.join("benchmark.json"))
+        .ok()
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .and_then(|v| v.get("throughput")?.get("Bytes")?.as_f64())
+        .map(|bytes| bytes / (ns_per_iter * 1e-9));
+
+    Some(BenchmarkRecord {
+        name,
+        bytes_per_sec: bytes_per_sec.unwrap_or(0.0),
+        ns_per_iter,
+        commit: commit.to_string(),
+        timestamp,
+    })
+}
+
+struct CliOptions {
+    criterion_dir: PathBuf,
+    history_path: PathBuf,
+    threshold_pct: f64,
+    commit: String,
+}
+
+fn parse_args() -> CliOptions {
+    let args: Vec<String> = std::env::args().collect();
+    let mut options = CliOptions {
+        criterion_dir: PathBuf::from("target/criterion"),
+        history_path: PathBuf::from("benches/history.json"),
+        threshold_pct: 5.0,
+        commit: current_git_commit(),
+    };
+
+    let mut i = 1;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--criterion-dir" => {
+                options.criterion_dir = PathBuf::from(require_arg(&args, &mut i, "--criterion-dir"));
+            }
+            "--history" => {
+                options.history_path = PathBuf::from(require_arg(&args, &mut i, "--history"));
+            }
+            "--threshold" => {
+                let value = require_arg(&args, &mut i, "--threshold");
+                options.threshold_pct = value.parse().unwrap_or(5.0);
+            }
+            "--commit" => {
+                options.commit = require_arg(&args, &mut i, "--commit");
+            }
+            "--help" | "-h" => {
+                print_usage();
+                process::exit(0);
+            }
+            other => {
+                eprintln!("Unknown argument: {other}");
+                print_usage();
+                process::exit(1);
+            }
+        }
+    }
+
+    options
+}
+
+fn require_arg(args: &[String], i: &mut usize, flag: &str) -> String {
+    match args.get(*i + 1) {
+        Some(value) => {
+            *i += 2;
+            value.clone()
+        }
+        None => {
+            eprintln!("{flag} requires an argument");
+            print_usage();
+            process::exit(1);
+        }
+    }
+}
+
+fn current_git_commit() -> String {
+    process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|s| s.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn print_usage() {
+    eprintln!("\nUsage: bench_report [OPTIONS]");
+    eprintln!("\nOptions:");
+    eprintln!("      --criterion-dir <DIR>  Criterion output directory (default: target/criterion)");
+    eprintln!("      --history <FILE>       Benchmark history JSON file (default: benches/history.json)");
+    eprintln!("      --threshold <PCT>      Regression threshold, in percent (default: 5.0)");
+    eprintln!("      --commit <SHA>         Commit to tag this run's records with (default: `git rev-parse --short HEAD`)");
+    eprintln!("  -h, --help                 Show this help message");
+    eprintln!("\nRun `cargo bench` first so there's criterion output to read.");
+}
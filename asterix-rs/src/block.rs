@@ -0,0 +1,185 @@
+//! Low-level, block-at-a-time access to a C++-backed parse result
+//!
+//! [`crate::parser::parse_blocks`] keeps the C++ `AsterixDataWrapper` result
+//! alive instead of eagerly converting every block into an [`AsterixRecord`](crate::AsterixRecord)
+//! the way [`crate::parse`] does. [`ParsedBlocks`] owns that result (freeing
+//! it on drop) and hands out [`DataBlock`] handles, which fetch their
+//! hex/JSON/text representations from C++ lazily and cache each one: a
+//! second call to [`DataBlock::hex`], [`DataBlock::json`], or
+//! [`DataBlock::text`] reuses the cached `String` instead of crossing the FFI
+//! boundary again.
+//!
+//! This is useful when a caller only needs a handful of blocks' string
+//! representations (for example, re-emitting a filtered subset as JSON) and
+//! wants to skip the JSON-to-`BTreeMap` decoding `parse` always performs for
+//! every block.
+
+use std::cell::OnceCell;
+use std::marker::PhantomData;
+
+use crate::error::{AsterixError, Result};
+use crate::ffi;
+
+/// An owned, still-C++-backed parse result: a sequence of [`DataBlock`]s.
+///
+/// Produced by [`crate::parser::parse_blocks`]. Frees the underlying C++
+/// allocation when dropped.
+pub struct ParsedBlocks {
+    data_ptr: *mut ffi::ffi::AsterixDataWrapper,
+    block_count: u32,
+}
+
+impl ParsedBlocks {
+    /// Take ownership of a non-null `asterix_parse`/`asterix_parse_offset` result.
+    ///
+    /// # Safety
+    ///
+    /// `data_ptr` must be a non-null pointer returned by `asterix_parse` or
+    /// `asterix_parse_offset` that has not yet been freed, and ownership must
+    /// not be claimed anywhere else (it will be freed exactly once, when the
+    /// returned `ParsedBlocks` is dropped).
+    pub(crate) unsafe fn from_raw(data_ptr: *mut ffi::ffi::AsterixDataWrapper) -> Self {
+        let block_count = ffi::ffi::asterix_data_block_count(data_ptr);
+        ParsedBlocks {
+            data_ptr,
+            block_count,
+        }
+    }
+
+    /// Number of data blocks in this parse result.
+    pub fn len(&self) -> usize {
+        self.block_count as usize
+    }
+
+    /// Whether this parse result contains no blocks.
+    pub fn is_empty(&self) -> bool {
+        self.block_count == 0
+    }
+
+    /// Borrow the block at `index`, or `None` if `index` is out of range.
+    pub fn get(&self, index: usize) -> Option<DataBlock<'_>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let block_ptr =
+            unsafe { ffi::ffi::asterix_get_data_block(self.data_ptr, index as u32) };
+        if block_ptr.is_null() {
+            return None;
+        }
+
+        Some(DataBlock {
+            block_ptr,
+            hex: OnceCell::new(),
+            json: OnceCell::new(),
+            text: OnceCell::new(),
+            _owner: PhantomData,
+        })
+    }
+
+    /// Iterate over this parse result's blocks, in order.
+    pub fn iter(&self) -> impl Iterator<Item = DataBlock<'_>> + '_ {
+        (0..self.len()).filter_map(move |i| self.get(i))
+    }
+}
+
+impl Drop for ParsedBlocks {
+    fn drop(&mut self) {
+        unsafe {
+            ffi::ffi::asterix_free_data(self.data_ptr);
+        }
+    }
+}
+
+/// A borrowed handle to a single decoded data block within a [`ParsedBlocks`].
+///
+/// `category`, `length`, `timestamp_ms`, and `crc` are cheap fixed-size reads
+/// and are not cached. `hex`, `json`, and `text` each allocate a `String` on
+/// their first call and cache it, so repeated rendering (e.g. logging a block
+/// twice) is allocation-free after the first access.
+pub struct DataBlock<'a> {
+    block_ptr: *const ffi::ffi::DataBlockWrapper,
+    hex: OnceCell<String>,
+    json: OnceCell<String>,
+    text: OnceCell<String>,
+    _owner: PhantomData<&'a ParsedBlocks>,
+}
+
+impl<'a> DataBlock<'a> {
+    /// ASTERIX category number (e.g., 48, 62, 65).
+    pub fn category(&self) -> u8 {
+        unsafe { ffi::ffi::asterix_block_category(self.block_ptr) }
+    }
+
+    /// Total length of this block in bytes.
+    pub fn length(&self) -> u32 {
+        unsafe { ffi::ffi::asterix_block_length(self.block_ptr) }
+    }
+
+    /// Timestamp in milliseconds since Unix epoch.
+    pub fn timestamp_ms(&self) -> u64 {
+        unsafe { ffi::ffi::asterix_block_timestamp_ms(self.block_ptr) }
+    }
+
+    /// CRC32 checksum of this block.
+    pub fn crc(&self) -> u32 {
+        unsafe { ffi::ffi::asterix_block_crc(self.block_ptr) }
+    }
+
+    /// Hexadecimal representation of this block's raw bytes.
+    pub fn hex(&self) -> &str {
+        self.hex.get_or_init(|| {
+            let hex_ptr = unsafe { ffi::ffi::asterix_block_hex_data(self.block_ptr) };
+            if hex_ptr.is_null() {
+                return String::new();
+            }
+            unsafe {
+                std::ffi::CStr::from_ptr(hex_ptr as *const std::os::raw::c_char)
+                    .to_string_lossy()
+                    .to_string()
+            }
+        })
+    }
+
+    /// JSON representation of this block, as produced by the C++ decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::NullPointer`] if the C++ side fails to
+    /// produce a JSON string.
+    pub fn json(&self) -> Result<&str> {
+        if let Some(cached) = self.json.get() {
+            return Ok(cached);
+        }
+
+        let json_ptr = unsafe { ffi::ffi::asterix_block_to_json(self.block_ptr) };
+        if json_ptr.is_null() {
+            return Err(AsterixError::NullPointer(
+                "C++ returned null JSON string".to_string(),
+            ));
+        }
+        let json = unsafe { ffi::c_string_to_rust(json_ptr) }?;
+        Ok(self.json.get_or_init(|| json))
+    }
+
+    /// Human-readable text representation of this block.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::NullPointer`] if the C++ side fails to
+    /// produce a text string.
+    pub fn text(&self) -> Result<&str> {
+        if let Some(cached) = self.text.get() {
+            return Ok(cached);
+        }
+
+        let text_ptr = unsafe { ffi::ffi::asterix_block_to_text(self.block_ptr) };
+        if text_ptr.is_null() {
+            return Err(AsterixError::NullPointer(
+                "C++ returned null text string".to_string(),
+            ));
+        }
+        let text = unsafe { ffi::c_string_to_rust(text_ptr) }?;
+        Ok(self.text.get_or_init(|| text))
+    }
+}
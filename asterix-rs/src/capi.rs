@@ -0,0 +1,157 @@
+//! Stable `extern "C"` ABI for non-Rust consumers, gated behind the `capi`
+//! feature
+//!
+//! This is the surface `build.rs` points cbindgen and pkg-config at when the
+//! `capi` feature is enabled (see [`crate`]'s build script for the
+//! `asterix.h`/`asterix.pc` generation): a minimal wrapper around
+//! [`crate::init_default`] and [`crate::parse`] that a C, C++, or other FFI
+//! caller can link against directly, instead of re-embedding this crate's
+//! parser or going through the C++ ASTERIX core a second time.
+//!
+//! Every function here is `#[no_mangle] extern "C"` and takes/returns only
+//! `repr(C)`-safe types (raw pointers, fixed-width integers,
+//! [`CapiParseResult`]) so cbindgen can translate it to a header without
+//! hand-written bindings. A string handed back across the boundary
+//! ([`CapiParseResult::records_json`]) is heap-allocated on the Rust side and
+//! must be released with [`asterix_capi_free_string`] — never with `free()`
+//! directly, since it didn't come from the C allocator.
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use crate::types::ParseOptions;
+
+/// Result of [`asterix_capi_parse`].
+///
+/// `records_json` is a NUL-terminated JSON array of decoded records, owned by
+/// the caller until passed to [`asterix_capi_free_string`]; it is null if
+/// `error_code` is non-zero, or if this crate was built without the `serde`
+/// feature (the `capi` feature alone only gets you `record_count`/
+/// `error_code` — JSON rendering goes through `serde_json` and the records'
+/// `Serialize` impls, which are `serde`-gated).
+#[repr(C)]
+pub struct CapiParseResult {
+    /// Owned, NUL-terminated JSON array of records, or null (see above).
+    pub records_json: *mut c_char,
+    /// Number of records successfully decoded.
+    pub record_count: usize,
+    /// `0` on success; a negative [`crate::error::AsterixError`]-derived code
+    /// otherwise. `-1` is used for any error this module doesn't otherwise
+    /// distinguish (malformed input pointer, parse failure, JSON
+    /// unavailable).
+    pub error_code: i32,
+}
+
+/// Initialize the ASTERIX decoder with its default (system-installed)
+/// category definitions.
+///
+/// Mirrors [`crate::init_default`]; see its docs for config search order.
+/// Returns `0` on success, `-1` on failure.
+///
+/// # Safety
+/// Must be called before [`asterix_capi_parse`], and only once per process
+/// (same constraint as [`crate::init_default`]).
+#[no_mangle]
+pub extern "C" fn asterix_capi_init_default() -> i32 {
+    match crate::init_default() {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Parse `len` bytes at `data` into ASTERIX records.
+///
+/// `verbose` maps to [`ParseOptions::verbose`]; every other option is left at
+/// its default. For anything beyond that (category/source filters, resync,
+/// lazy items, …), use the Rust API directly — this ABI only covers the
+/// common case described in the `capi` feature's request: quick integration
+/// for a C/C++ caller that just wants decoded records as JSON.
+///
+/// # Safety
+/// `data` must point to at least `len` readable bytes and remain valid for
+/// the duration of this call. The returned [`CapiParseResult::records_json`]
+/// (if non-null) must eventually be freed with [`asterix_capi_free_string`].
+#[no_mangle]
+pub unsafe extern "C" fn asterix_capi_parse(
+    data: *const u8,
+    len: usize,
+    verbose: bool,
+) -> CapiParseResult {
+    if data.is_null() {
+        return CapiParseResult {
+            records_json: std::ptr::null_mut(),
+            record_count: 0,
+            error_code: -1,
+        };
+    }
+
+    let bytes = std::slice::from_raw_parts(data, len);
+    let options = ParseOptions {
+        verbose,
+        ..Default::default()
+    };
+
+    match crate::parse(bytes, options) {
+        Ok(records) => {
+            let record_count = records.len();
+            let records_json = render_records_json(&records);
+            CapiParseResult {
+                records_json,
+                record_count,
+                error_code: 0,
+            }
+        }
+        Err(_) => CapiParseResult {
+            records_json: std::ptr::null_mut(),
+            record_count: 0,
+            error_code: -1,
+        },
+    }
+}
+
+/// Free a string previously returned in [`CapiParseResult::records_json`].
+///
+/// Safe to call with a null pointer (a no-op), matching `free()`'s contract.
+///
+/// # Safety
+/// `ptr` must either be null or a pointer this module itself returned, not
+/// yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn asterix_capi_free_string(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        drop(CString::from_raw(ptr));
+    }
+}
+
+#[cfg(feature = "serde")]
+fn render_records_json(records: &[crate::types::AsterixRecord]) -> *mut c_char {
+    match serde_json::to_string(records) {
+        Ok(json) => match CString::new(json) {
+            Ok(c_str) => c_str.into_raw(),
+            Err(_) => std::ptr::null_mut(),
+        },
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[cfg(not(feature = "serde"))]
+fn render_records_json(_records: &[crate::types::AsterixRecord]) -> *mut c_char {
+    std::ptr::null_mut()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_null_data_reports_error() {
+        let result = unsafe { asterix_capi_parse(std::ptr::null(), 0, false) };
+        assert_eq!(result.error_code, -1);
+        assert!(result.records_json.is_null());
+    }
+
+    #[test]
+    fn test_free_null_string_is_noop() {
+        unsafe { asterix_capi_free_string(std::ptr::null_mut()) };
+    }
+}
@@ -0,0 +1,256 @@
+//! Zero-dependency CBOR (RFC 8949) encoder for decoded records
+//!
+//! [`crate::transport::zenoh::PayloadFormat::Cbor`] uses [`encode_record`] to
+//! publish an already-decoded [`AsterixRecord`] as a compact, self-describing
+//! structured value instead of raw ASTERIX bytes, so a subscriber can read
+//! fields directly without the category XML config or the FFI decoder. No
+//! crate in this workspace already depends on a CBOR library (e.g.
+//! `ciborium`), and the subset of CBOR this crate needs — maps, arrays, a
+//! handful of scalar types — is a few major-type tags and length prefixes, so
+//! it's hand-rolled the same way [`crate::json_bridge`]'s base64 encoder is.
+//!
+//! This is an encoder only; nothing in this crate needs to decode CBOR back.
+
+use crate::types::{AsterixRecord, ParsedValue};
+
+/// Encode `record` as a single CBOR map matching the same shape
+/// [`crate::json_bridge::AsterixRecord::to_json_value`] produces: `category`,
+/// `length`, `timestamp_ms`, `crc`, and an `items` map of item id to a map of
+/// field name to value.
+pub fn encode_record(record: &AsterixRecord) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    write_map_header(&mut out, 5);
+
+    write_text(&mut out, "category");
+    write_uint(&mut out, record.category as u64);
+
+    write_text(&mut out, "length");
+    write_uint(&mut out, record.length as u64);
+
+    write_text(&mut out, "timestamp_ms");
+    write_uint(&mut out, record.timestamp_ms);
+
+    write_text(&mut out, "crc");
+    write_uint(&mut out, record.crc as u64);
+
+    write_text(&mut out, "items");
+    write_map_header(&mut out, record.items.len());
+    for (item_id, item) in &record.items {
+        write_text(&mut out, item_id);
+        write_map_header(&mut out, item.fields.len());
+        for (field_name, value) in &item.fields {
+            write_text(&mut out, field_name);
+            write_value(&mut out, value);
+        }
+    }
+
+    out
+}
+
+fn write_value(out: &mut Vec<u8>, value: &ParsedValue) {
+    match value {
+        ParsedValue::Integer(v) => write_int(out, *v),
+        ParsedValue::Unsigned(v) => write_uint(out, *v),
+        ParsedValue::Float(v) => write_f64(out, *v),
+        ParsedValue::Decimal { raw, scale, unit } => {
+            write_map_header(out, 3);
+            write_text(out, "raw");
+            write_int(out, *raw);
+            write_text(out, "scale");
+            write_f64(out, *scale);
+            write_text(out, "unit");
+            match unit {
+                Some(unit) => write_text(out, unit),
+                None => write_null(out),
+            }
+        }
+        ParsedValue::String(v) => write_text(out, v),
+        ParsedValue::Boolean(v) => write_bool(out, *v),
+        ParsedValue::Bytes(v) => write_bytes(out, v),
+        ParsedValue::Nested(nested) => {
+            write_map_header(out, nested.len());
+            for (key, nested_value) in nested {
+                write_text(out, key);
+                write_value(out, nested_value);
+            }
+        }
+        ParsedValue::Array(items) => {
+            write_array_header(out, items.len());
+            for item in items {
+                write_value(out, item);
+            }
+        }
+        ParsedValue::Raw(text) => write_text(out, text),
+        ParsedValue::Number(text) => write_text(out, text),
+    }
+}
+
+/// Write a major-type tag and length/value argument, using the shortest of
+/// CBOR's five encodings (direct, 1/2/4/8 extra bytes) that fits `arg`.
+fn write_head(out: &mut Vec<u8>, major: u8, arg: u64) {
+    let major = major << 5;
+    if arg < 24 {
+        out.push(major | arg as u8);
+    } else if arg <= u8::MAX as u64 {
+        out.push(major | 24);
+        out.push(arg as u8);
+    } else if arg <= u16::MAX as u64 {
+        out.push(major | 25);
+        out.extend_from_slice(&(arg as u16).to_be_bytes());
+    } else if arg <= u32::MAX as u64 {
+        out.push(major | 26);
+        out.extend_from_slice(&(arg as u32).to_be_bytes());
+    } else {
+        out.push(major | 27);
+        out.extend_from_slice(&arg.to_be_bytes());
+    }
+}
+
+fn write_uint(out: &mut Vec<u8>, value: u64) {
+    write_head(out, 0, value);
+}
+
+fn write_int(out: &mut Vec<u8>, value: i64) {
+    if value >= 0 {
+        write_uint(out, value as u64);
+    } else {
+        // CBOR major type 1 encodes negative n as -1-n
+        write_head(out, 1, (-1 - value) as u64);
+    }
+}
+
+fn write_bytes(out: &mut Vec<u8>, bytes: &[u8]) {
+    write_head(out, 2, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+fn write_text(out: &mut Vec<u8>, s: &str) {
+    write_head(out, 3, s.len() as u64);
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn write_array_header(out: &mut Vec<u8>, len: usize) {
+    write_head(out, 4, len as u64);
+}
+
+fn write_map_header(out: &mut Vec<u8>, len: usize) {
+    write_head(out, 5, len as u64);
+}
+
+fn write_f64(out: &mut Vec<u8>, value: f64) {
+    out.push(0xfb); // major type 7, 8-byte IEEE-754 double
+    out.extend_from_slice(&value.to_bits().to_be_bytes());
+}
+
+fn write_bool(out: &mut Vec<u8>, value: bool) {
+    out.push(if value { 0xf5 } else { 0xf4 });
+}
+
+fn write_null(out: &mut Vec<u8>) {
+    out.push(0xf6);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DataItem;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_write_head_picks_shortest_encoding() {
+        let mut out = Vec::new();
+        write_uint(&mut out, 5);
+        assert_eq!(out, vec![0x05]);
+
+        let mut out = Vec::new();
+        write_uint(&mut out, 200);
+        assert_eq!(out, vec![0x18, 200]);
+
+        let mut out = Vec::new();
+        write_uint(&mut out, 1000);
+        assert_eq!(out, vec![0x19, 0x03, 0xe8]);
+    }
+
+    #[test]
+    fn test_write_int_negative() {
+        let mut out = Vec::new();
+        write_int(&mut out, -1);
+        assert_eq!(out, vec![0x20]);
+
+        let mut out = Vec::new();
+        write_int(&mut out, -10);
+        assert_eq!(out, vec![0x29]);
+    }
+
+    #[test]
+    fn test_write_text_known_vector() {
+        // CBOR RFC 8949 test vector: "a" => 0x61 0x61
+        let mut out = Vec::new();
+        write_text(&mut out, "a");
+        assert_eq!(out, vec![0x61, 0x61]);
+    }
+
+    #[test]
+    fn test_write_bool_and_null() {
+        let mut out = Vec::new();
+        write_bool(&mut out, true);
+        write_bool(&mut out, false);
+        write_null(&mut out);
+        assert_eq!(out, vec![0xf5, 0xf4, 0xf6]);
+    }
+
+    #[test]
+    fn test_encode_record_starts_with_five_entry_map() {
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(25));
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        let record = AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        };
+
+        let bytes = encode_record(&record);
+        // Major type 5 (map), 5 entries -> 0xa5
+        assert_eq!(bytes[0], 0xa5);
+        assert!(!bytes.is_empty());
+    }
+
+    #[test]
+    fn test_encode_record_decimal_without_unit_encodes_null() {
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "ALT".to_string(),
+            ParsedValue::Decimal {
+                raw: 100,
+                scale: 0.25,
+                unit: None,
+            },
+        );
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/040".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        let record = AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        };
+
+        let bytes = encode_record(&record);
+        // The trailing bytes encode the Decimal map's "unit" value as CBOR null (0xf6).
+        assert_eq!(*bytes.last().unwrap(), 0xf6);
+    }
+}
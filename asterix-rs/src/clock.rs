@@ -0,0 +1,112 @@
+//! Injectable wall-clock time for deterministic `timestamp_ms` stamping
+//!
+//! A decoded block's `timestamp_ms` ordinarily comes straight from the C++
+//! layer's embedded time-of-day item. Not every category carries one, and
+//! the C++ side reports that case as `0` rather than failing — left alone,
+//! every such record collapses to the Unix epoch. [`ParseOptions::clock`]
+//! (see [`crate::types::ParseOptions`]) lets a caller supply a [`Clock`] to
+//! stamp those records instead: [`SystemClock`] for real decoding,
+//! [`MockClock`] to get a fixed, reproducible `timestamp_ms` in tests
+//! without depending on wall-clock time at all.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// A source of the current time in milliseconds since the Unix epoch
+///
+/// `Send + Sync` so it can live behind the [`SharedClock`] used by
+/// [`crate::types::ParseOptions::clock`], which is shared across parse calls
+/// (and, via [`crate::parse_parallel`], across threads).
+pub trait Clock: Send + Sync {
+    /// The current time, in milliseconds since the Unix epoch
+    fn now_ms(&self) -> u64;
+}
+
+/// [`ParseOptions::clock`](crate::types::ParseOptions::clock)'s storage type — an
+/// `Arc` (rather than `Box`) so `ParseOptions` stays [`Clone`], matching
+/// [`crate::types::RecordFilter`]'s reasoning.
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Reads the real system clock via [`std::time::SystemTime`]
+///
+/// The default for live decoding; not useful in tests, since two runs of the
+/// same test would stamp different `timestamp_ms` values. Use [`MockClock`]
+/// there instead.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_ms(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0)
+    }
+}
+
+/// A [`Clock`] that always reports a caller-controlled time, for
+/// reproducible tests.
+///
+/// Starts at whatever [`MockClock::new`] was given and only changes when
+/// [`MockClock::set`]/[`MockClock::advance`] is called — never from wall-clock
+/// time.
+#[derive(Debug)]
+pub struct MockClock {
+    now_ms: AtomicU64,
+}
+
+impl MockClock {
+    /// A clock that reports `now_ms` until told otherwise
+    pub fn new(now_ms: u64) -> Self {
+        Self {
+            now_ms: AtomicU64::new(now_ms),
+        }
+    }
+
+    /// Set the time this clock reports
+    pub fn set(&self, now_ms: u64) {
+        self.now_ms.store(now_ms, Ordering::SeqCst);
+    }
+
+    /// Move this clock's reported time forward by `delta_ms`
+    pub fn advance(&self, delta_ms: u64) {
+        self.now_ms.fetch_add(delta_ms, Ordering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_ms(&self) -> u64 {
+        self.now_ms.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_clock_reports_nonzero_time() {
+        assert!(SystemClock.now_ms() > 0);
+    }
+
+    #[test]
+    fn test_mock_clock_reports_fixed_time() {
+        let clock = MockClock::new(1_700_000_000_000);
+        assert_eq!(clock.now_ms(), 1_700_000_000_000);
+        assert_eq!(clock.now_ms(), 1_700_000_000_000);
+    }
+
+    #[test]
+    fn test_mock_clock_set_overrides_time() {
+        let clock = MockClock::new(0);
+        clock.set(42);
+        assert_eq!(clock.now_ms(), 42);
+    }
+
+    #[test]
+    fn test_mock_clock_advance_adds_delta() {
+        let clock = MockClock::new(100);
+        clock.advance(50);
+        assert_eq!(clock.now_ms(), 150);
+    }
+}
@@ -113,6 +113,7 @@ mod tests {
             verbose: true,
             filter_category: Some(62),
             max_records: Some(100),
+            ..Default::default()
         };
 
         assert!(options.verbose);
@@ -126,6 +127,7 @@ mod tests {
             verbose: true,
             filter_category: Some(48),
             max_records: Some(50),
+            ..Default::default()
         };
 
         let options2 = options1.clone();
@@ -0,0 +1,352 @@
+//! GraphViz DOT export of a decoded [`crate::types::AsterixRecord`]
+//!
+//! [`to_dot`] renders a record's hierarchy — the record itself, its
+//! [`DataItem`](crate::types::DataItem)s, and the [`ParsedValue`]s nested
+//! inside each item's fields — as a GraphViz graph, so an unfamiliar category
+//! can be inspected visually with `dot -Tsvg` instead of squinting at debug
+//! output.
+
+use crate::types::{AsterixRecord, DataItem, FieldMap, ParsedValue};
+use std::fmt::Write;
+
+/// Which GraphViz graph kind [`to_dot`] emits
+///
+/// Controls both the `digraph`/`graph` keyword and the edge operator, since
+/// GraphViz rejects `--` in a `digraph` and `->` in a `graph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GraphKind {
+    /// Directed graph (`digraph`), connected with `->` (the default)
+    #[default]
+    Digraph,
+    /// Undirected graph (`graph`), connected with `--`
+    Graph,
+}
+
+impl GraphKind {
+    /// The `digraph`/`graph` keyword this kind renders as
+    fn keyword(self) -> &'static str {
+        match self {
+            GraphKind::Digraph => "digraph",
+            GraphKind::Graph => "graph",
+        }
+    }
+
+    /// The edge operator this kind connects nodes with
+    fn edgeop(self) -> &'static str {
+        match self {
+            GraphKind::Digraph => "->",
+            GraphKind::Graph => "--",
+        }
+    }
+}
+
+/// Render `record` as a GraphViz graph of `kind`
+///
+/// The record is the root node; each entry of
+/// [`AsterixRecord::items`](crate::types::AsterixRecord) is a child node, and
+/// each [`ParsedValue`] in a [`DataItem`]'s `fields` is a leaf, recursing
+/// through [`ParsedValue::Nested`] and [`ParsedValue::Array`] for compound
+/// and repetitive items. Every node label is quoted and escaped, so hex
+/// bytes, quotes, and newlines in field values can't break the output.
+///
+/// # Example
+///
+/// ```
+/// use asterix::dot::{to_dot, GraphKind};
+/// use asterix::types::AsterixRecord;
+///
+/// let record = AsterixRecord::default();
+/// let dot = to_dot(&record, GraphKind::Digraph);
+/// assert!(dot.starts_with("digraph"));
+/// assert!(dot.contains("->") || record.items.is_empty());
+/// ```
+pub fn to_dot(record: &AsterixRecord, kind: GraphKind) -> String {
+    let mut out = String::new();
+    let mut next_id = 0usize;
+
+    writeln!(out, "{} asterix {{", kind.keyword()).unwrap();
+
+    let root = new_node_id(&mut next_id);
+    writeln!(
+        out,
+        "  n{root} [label={}];",
+        quote(&format!("CAT{:03}", record.category))
+    )
+    .unwrap();
+
+    for (item_id, item) in &record.items {
+        let item_node = new_node_id(&mut next_id);
+        write_item_node(&mut out, item_node, item_id, item);
+        write_edge(&mut out, kind, root, item_node);
+        write_fields(&mut out, kind, &mut next_id, item_node, &item.fields);
+    }
+
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+/// Allocate the next `n<id>` node name
+fn new_node_id(next_id: &mut usize) -> usize {
+    let id = *next_id;
+    *next_id += 1;
+    id
+}
+
+/// Emit a node declaration for a [`DataItem`], labeled with its ID and
+/// description (when present)
+fn write_item_node(out: &mut String, node: usize, item_id: &str, item: &DataItem) {
+    let label = match &item.description {
+        Some(desc) => format!("{item_id}\\n{desc}"),
+        None => item_id.to_string(),
+    };
+    writeln!(out, "  n{node} [label={}];", quote(&label)).unwrap();
+}
+
+/// Emit one edge between two node names
+fn write_edge(out: &mut String, kind: GraphKind, from: usize, to: usize) {
+    writeln!(out, "  n{from} {} n{to};", kind.edgeop()).unwrap();
+}
+
+/// Recursively emit nodes and edges for a [`DataItem`]'s fields, descending
+/// through [`ParsedValue::Nested`] and [`ParsedValue::Array`]
+fn write_fields(
+    out: &mut String,
+    kind: GraphKind,
+    next_id: &mut usize,
+    parent: usize,
+    fields: &FieldMap,
+) {
+    for (name, value) in fields {
+        write_field(out, kind, next_id, parent, name, value);
+    }
+}
+
+/// Emit one field node (and its children, if `value` is [`ParsedValue::Nested`]
+/// or [`ParsedValue::Array`]) under `parent`
+fn write_field(
+    out: &mut String,
+    kind: GraphKind,
+    next_id: &mut usize,
+    parent: usize,
+    name: &str,
+    value: &ParsedValue,
+) {
+    let node = new_node_id(next_id);
+
+    match value {
+        ParsedValue::Nested(nested) => {
+            writeln!(out, "  n{node} [label={}];", quote(name)).unwrap();
+            write_edge(out, kind, parent, node);
+            for (nested_name, nested_value) in nested {
+                write_field(out, kind, next_id, node, nested_name, nested_value);
+            }
+        }
+        ParsedValue::Array(items) => {
+            writeln!(out, "  n{node} [label={}];", quote(name)).unwrap();
+            write_edge(out, kind, parent, node);
+            for (index, item) in items.iter().enumerate() {
+                write_field(out, kind, next_id, node, &format!("[{index}]"), item);
+            }
+        }
+        leaf => {
+            let label = format!("{name} = {}", format_leaf(leaf));
+            writeln!(out, "  n{node} [label={}];", quote(&label)).unwrap();
+            write_edge(out, kind, parent, node);
+        }
+    }
+}
+
+/// Render a non-recursive [`ParsedValue`] as plain text for a leaf label
+fn format_leaf(value: &ParsedValue) -> String {
+    match value {
+        ParsedValue::Integer(v) => v.to_string(),
+        ParsedValue::Unsigned(v) => v.to_string(),
+        ParsedValue::Float(v) => v.to_string(),
+        ParsedValue::String(v) => v.clone(),
+        ParsedValue::Boolean(v) => v.to_string(),
+        ParsedValue::Bytes(v) => crate::hex::to_hex(v),
+        ParsedValue::Decimal { raw, scale, unit } => match unit {
+            Some(unit) => format!("{} {unit}", *raw as f64 * scale),
+            None => (*raw as f64 * scale).to_string(),
+        },
+        ParsedValue::Raw(text) => text.clone(),
+        ParsedValue::Number(text) => text.clone(),
+        ParsedValue::Nested(_) | ParsedValue::Array(_) => unreachable!(
+            "format_leaf is only called for non-recursive ParsedValue variants"
+        ),
+    }
+}
+
+/// Quote and escape a string for use as a DOT node label
+///
+/// Escapes `"`, `\`, and newlines so field names and stringified values
+/// (including raw hex byte dumps) can never break out of the quoted label.
+fn quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            other => out.push(other),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DataItem;
+    use std::collections::BTreeMap;
+
+    fn sample_record() -> AsterixRecord {
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(25));
+        fields.insert("SIC".to_string(), ParsedValue::Integer(10));
+
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: Some("Data Source Identifier".to_string()),
+                fields,
+            },
+        );
+
+        AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_digraph_keyword_and_edgeop() {
+        assert_eq!(GraphKind::Digraph.keyword(), "digraph");
+        assert_eq!(GraphKind::Digraph.edgeop(), "->");
+    }
+
+    #[test]
+    fn test_graph_keyword_and_edgeop() {
+        assert_eq!(GraphKind::Graph.keyword(), "graph");
+        assert_eq!(GraphKind::Graph.edgeop(), "--");
+    }
+
+    #[test]
+    fn test_to_dot_digraph_structure() {
+        let dot = to_dot(&sample_record(), GraphKind::Digraph);
+        assert!(dot.starts_with("digraph asterix {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains("->"));
+        assert!(!dot.contains("--"));
+        assert!(dot.contains("\"CAT048\""));
+        assert!(dot.contains("I048/010"));
+        assert!(dot.contains("SAC = 25"));
+    }
+
+    #[test]
+    fn test_to_dot_graph_uses_undirected_edges() {
+        let dot = to_dot(&sample_record(), GraphKind::Graph);
+        assert!(dot.starts_with("graph asterix {"));
+        assert!(dot.contains("--"));
+        assert!(!dot.contains("->"));
+    }
+
+    #[test]
+    fn test_to_dot_empty_record() {
+        let dot = to_dot(&AsterixRecord::default(), GraphKind::Digraph);
+        assert!(dot.contains("\"CAT000\""));
+        assert_eq!(dot.lines().count(), 3); // header, root node, footer
+    }
+
+    #[test]
+    fn test_to_dot_recurses_into_nested_values() {
+        let mut nested = BTreeMap::new();
+        nested.insert(
+            "MODE".to_string(),
+            Box::new(ParsedValue::String("A5".to_string())),
+        );
+        let mut fields = BTreeMap::new();
+        fields.insert("MODE3A".to_string(), ParsedValue::Nested(nested));
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/070".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        let record = AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        };
+
+        let dot = to_dot(&record, GraphKind::Digraph);
+        assert!(dot.contains("MODE3A"));
+        assert!(dot.contains("MODE = A5"));
+    }
+
+    #[test]
+    fn test_to_dot_recurses_into_array_values() {
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "TARGETS".to_string(),
+            ParsedValue::Array(vec![ParsedValue::Integer(1), ParsedValue::Integer(2)]),
+        );
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/250".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        let record = AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        };
+
+        let dot = to_dot(&record, GraphKind::Digraph);
+        assert!(dot.contains("TARGETS"));
+        assert!(dot.contains("[0] = 1"));
+        assert!(dot.contains("[1] = 2"));
+    }
+
+    #[test]
+    fn test_quote_escapes_special_characters() {
+        assert_eq!(quote("plain"), "\"plain\"");
+        assert_eq!(quote("has \"quotes\""), "\"has \\\"quotes\\\"\"");
+        assert_eq!(quote("back\\slash"), "\"back\\\\slash\"");
+        assert_eq!(quote("line\nbreak"), "\"line\\nbreak\"");
+    }
+
+    #[test]
+    fn test_to_dot_escapes_bytes_field() {
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "RAW".to_string(),
+            ParsedValue::Bytes(vec![0x0a, 0x22, 0x5c]),
+        );
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/999".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        let record = AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        };
+
+        let dot = to_dot(&record, GraphKind::Digraph);
+        assert!(dot.contains("RAW = 0a225c"));
+    }
+}
@@ -0,0 +1,379 @@
+//! Encoding API: the inverse of [`crate::parser::parse`]
+//!
+//! This module serializes [`AsterixRecord`]s back into a raw ASTERIX data block:
+//! a one-byte category, a two-byte big-endian length, a FSPEC (field specification)
+//! with FX continuation bits, and the data items themselves.
+//!
+//! # Round-tripping captured data
+//!
+//! Records produced by [`parse`](crate::parse) carry the original block bytes in
+//! [`AsterixRecord::hex_data`]. When that field is populated, `encode` re-emits those
+//! bytes verbatim, so `encode(&parse(buf, opts)?, EncodeOptions::default())` reproduces
+//! `buf` byte-for-byte.
+//!
+//! # Building records by hand
+//!
+//! For records assembled with [`RecordBuilder`] (no captured `hex_data`), the per-category
+//! UAP tables that describe exact field bit-widths live in the C++ XML definitions, not in
+//! this crate, so `encode` cannot reproduce the C++ decoder's bit-level layout for those
+//! records. Instead it lays out a FSPEC whose presence bits follow the item's insertion
+//! (UAP) order and serializes each item with a compact, self-describing field encoding.
+//! The result is a valid ASTERIX data block (correct category/length/FSPEC framing) that
+//! this crate's own `parse`-adjacent tooling can read back, even though it is not
+//! guaranteed to be byte-identical to a block produced by the reference C++ encoder.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asterix::{parse, ParseOptions, init_default};
+//! # use asterix::encode::{encode, EncodeOptions};
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! init_default()?;
+//! let data = std::fs::read("sample.asterix")?;
+//! let records = parse(&data, ParseOptions::default())?;
+//! let re_encoded = encode(&records, EncodeOptions::default())?;
+//! assert_eq!(data, re_encoded);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{AsterixError, Result};
+use crate::hex::from_hex;
+use crate::types::{AsterixRecord, DataItem, ParsedValue};
+
+/// Options controlling how [`encode`] lays out a block.
+#[derive(Debug, Clone)]
+pub struct EncodeOptions {
+    /// Reject records whose encoded length would not fit in the 2-byte ASTERIX
+    /// length field (default: true).
+    pub verify_length: bool,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self {
+            verify_length: true,
+        }
+    }
+}
+
+/// Serialize ASTERIX records into a single concatenated data block buffer.
+///
+/// See the [module docs](self) for the two code paths this takes (verbatim
+/// re-emission of captured `hex_data`, versus the builder's generic field encoding).
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if a record's captured `hex_data` is not
+/// valid hex, or if the encoded block length would overflow the 2-byte length field
+/// while `options.verify_length` is set.
+pub fn encode(records: &[AsterixRecord], options: EncodeOptions) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    for record in records {
+        out.extend(encode_record(record, &options)?);
+    }
+    Ok(out)
+}
+
+fn encode_record(record: &AsterixRecord, options: &EncodeOptions) -> Result<Vec<u8>> {
+    if !record.hex_data.is_empty() {
+        // `hex_data` is captured verbatim from the C++ decoder in `crate::parser`,
+        // which never embeds whitespace, so the table-based fast path applies here.
+        #[cfg(feature = "simd-hex")]
+        {
+            return crate::hex::from_hex_fast(record.hex_data.as_bytes())
+                .map_err(AsterixError::from);
+        }
+        #[cfg(not(feature = "simd-hex"))]
+        {
+            return from_hex(&record.hex_data).map_err(AsterixError::from);
+        }
+    }
+
+    RecordBuilder::from_record(record).build(options)
+}
+
+/// Borrowing builder that assembles a single ASTERIX data block.
+///
+/// The builder computes the total block length in a first pass (via
+/// [`RecordBuilder::serialized_len`]) and back-fills the two-byte length field,
+/// mirroring the report-length-before-writing pattern used by packet builders
+/// elsewhere in the Rust ecosystem.
+pub struct RecordBuilder<'a> {
+    category: u8,
+    items: Vec<(&'a str, &'a DataItem)>,
+}
+
+impl<'a> RecordBuilder<'a> {
+    /// Start building a block for the given category.
+    pub fn new(category: u8) -> Self {
+        Self {
+            category,
+            items: Vec::new(),
+        }
+    }
+
+    /// Build from an existing record's items, preserving their (UAP) key order.
+    pub fn from_record(record: &'a AsterixRecord) -> Self {
+        let mut builder = Self::new(record.category);
+        for (id, item) in &record.items {
+            builder.items.push((id.as_str(), item));
+        }
+        builder
+    }
+
+    /// Add a data item, keeping insertion order as the UAP (FSPEC) order.
+    pub fn with_item(mut self, id: &'a str, item: &'a DataItem) -> Self {
+        self.items.push((id, item));
+        self
+    }
+
+    /// Number of bytes the body (FSPEC + items) will take, without the 3-byte header.
+    fn body_len(&self) -> usize {
+        fspec_len(self.items.len()) + self.items.iter().map(|(_, i)| item_len(i)).sum::<usize>()
+    }
+
+    /// Total serialized length of this block, including the 3-byte category+length header.
+    pub fn serialized_len(&self) -> usize {
+        3 + self.body_len()
+    }
+
+    /// Serialize this builder into a complete ASTERIX data block.
+    pub fn build(&self, options: &EncodeOptions) -> Result<Vec<u8>> {
+        let total_len = self.serialized_len();
+
+        if options.verify_length && total_len > u16::MAX as usize {
+            return Err(AsterixError::InvalidData(format!(
+                "encoded block length {total_len} exceeds 2-byte length field"
+            )));
+        }
+
+        let mut out = Vec::with_capacity(total_len);
+        out.push(self.category);
+        out.extend_from_slice(&(total_len as u16).to_be_bytes());
+        out.extend(encode_fspec(self.items.len()));
+        for (_, item) in &self.items {
+            out.extend(encode_item(item));
+        }
+
+        Ok(out)
+    }
+}
+
+/// Number of FSPEC bytes needed for `item_count` items (7 presence bits per octet,
+/// bit 0 reserved for the FX continuation flag).
+fn fspec_len(item_count: usize) -> usize {
+    item_count.div_ceil(7).max(1)
+}
+
+/// Build the FSPEC octets: bits 7..1 mark item presence (items are always present
+/// here, since only populated items are passed in), bit 0 is the FX continuation flag.
+fn encode_fspec(item_count: usize) -> Vec<u8> {
+    let mut out = vec![0u8; fspec_len(item_count)];
+    for (index, byte) in out.iter_mut().enumerate() {
+        let bits_in_byte = item_count.saturating_sub(index * 7).min(7);
+        for bit in 0..bits_in_byte {
+            *byte |= 1 << (7 - bit);
+        }
+        if index + 1 < out.len() {
+            *byte |= 0x01; // FX: another FSPEC octet follows
+        }
+    }
+    out
+}
+
+fn item_len(item: &DataItem) -> usize {
+    item.fields
+        .iter()
+        .map(|(name, value)| 1 + name.len() + value_len(value))
+        .sum()
+}
+
+fn value_len(value: &ParsedValue) -> usize {
+    // One type-tag byte precedes every encoded value (see `encode_value`).
+    1 + match value {
+        ParsedValue::Integer(_) => 8,
+        ParsedValue::Unsigned(_) => 8,
+        ParsedValue::Float(_) => 8,
+        ParsedValue::Decimal { unit, .. } => 16 + 1 + unit.as_ref().map_or(0, |u| 2 + u.len()),
+        ParsedValue::Boolean(_) => 1,
+        ParsedValue::String(s) => 2 + s.len(),
+        ParsedValue::Bytes(b) => 2 + b.len(),
+        ParsedValue::Nested(map) => map
+            .iter()
+            .map(|(k, v)| 1 + k.len() + value_len(v))
+            .sum::<usize>()
+            .saturating_add(2),
+        ParsedValue::Array(arr) => arr.iter().map(value_len).sum::<usize>().saturating_add(2),
+        ParsedValue::Raw(text) => 2 + text.len(),
+        ParsedValue::Number(text) => 2 + text.len(),
+    }
+}
+
+/// Encode a single data item as a length-prefixed sequence of `(name, type, value)` fields.
+///
+/// This is this crate's own compact representation, not the C++ decoder's bit-level
+/// layout (see [module docs](self)).
+fn encode_item(item: &DataItem) -> Vec<u8> {
+    let mut out = Vec::new();
+    for (name, value) in &item.fields {
+        out.push(name.len() as u8);
+        out.extend_from_slice(name.as_bytes());
+        encode_value(value, &mut out);
+    }
+    out
+}
+
+fn encode_value(value: &ParsedValue, out: &mut Vec<u8>) {
+    match value {
+        ParsedValue::Integer(i) => {
+            out.push(b'i');
+            out.extend_from_slice(&i.to_be_bytes());
+        }
+        ParsedValue::Unsigned(u) => {
+            out.push(b'u');
+            out.extend_from_slice(&u.to_be_bytes());
+        }
+        ParsedValue::Float(f) => {
+            out.push(b'f');
+            out.extend_from_slice(&f.to_be_bytes());
+        }
+        ParsedValue::Decimal { raw, scale, unit } => {
+            out.push(b'd');
+            out.extend_from_slice(&raw.to_be_bytes());
+            out.extend_from_slice(&scale.to_be_bytes());
+            match unit {
+                Some(u) => {
+                    out.push(1);
+                    out.extend_from_slice(&(u.len() as u16).to_be_bytes());
+                    out.extend_from_slice(u.as_bytes());
+                }
+                None => out.push(0),
+            }
+        }
+        ParsedValue::Boolean(b) => {
+            out.push(b'b');
+            out.push(*b as u8);
+        }
+        ParsedValue::String(s) => {
+            out.push(b's');
+            out.extend_from_slice(&(s.len() as u16).to_be_bytes());
+            out.extend_from_slice(s.as_bytes());
+        }
+        ParsedValue::Bytes(b) => {
+            out.push(b'x');
+            out.extend_from_slice(&(b.len() as u16).to_be_bytes());
+            out.extend_from_slice(b);
+        }
+        ParsedValue::Nested(map) => {
+            out.push(b'n');
+            out.extend_from_slice(&(map.len() as u16).to_be_bytes());
+            for (key, val) in map {
+                out.push(key.len() as u8);
+                out.extend_from_slice(key.as_bytes());
+                encode_value(val, out);
+            }
+        }
+        ParsedValue::Array(arr) => {
+            out.push(b'a');
+            out.extend_from_slice(&(arr.len() as u16).to_be_bytes());
+            for val in arr {
+                encode_value(val, out);
+            }
+        }
+        ParsedValue::Raw(text) => {
+            out.push(b'r');
+            out.extend_from_slice(&(text.len() as u16).to_be_bytes());
+            out.extend_from_slice(text.as_bytes());
+        }
+        ParsedValue::Number(text) => {
+            out.push(b'N');
+            out.extend_from_slice(&(text.len() as u16).to_be_bytes());
+            out.extend_from_slice(text.as_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ItemMap;
+    use std::collections::BTreeMap;
+
+    fn sample_record_with_hex() -> AsterixRecord {
+        AsterixRecord {
+            category: 48,
+            length: 4,
+            timestamp_ms: 0,
+            crc: 0,
+            hex_data: "300004AB".to_string(),
+            items: ItemMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_encode_roundtrip_from_hex_data() {
+        let record = sample_record_with_hex();
+        let encoded = encode(&[record], EncodeOptions::default()).unwrap();
+        assert_eq!(encoded, vec![0x30, 0x00, 0x04, 0xAB]);
+    }
+
+    #[test]
+    fn test_encode_rejects_odd_length_hex() {
+        let mut record = sample_record_with_hex();
+        record.hex_data = "300".to_string();
+        let result = encode(&[record], EncodeOptions::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_fspec_len_single_octet() {
+        assert_eq!(fspec_len(0), 1);
+        assert_eq!(fspec_len(5), 1);
+        assert_eq!(fspec_len(7), 1);
+    }
+
+    #[test]
+    fn test_fspec_len_multiple_octets() {
+        assert_eq!(fspec_len(8), 2);
+        assert_eq!(fspec_len(14), 2);
+        assert_eq!(fspec_len(15), 3);
+    }
+
+    #[test]
+    fn test_encode_fspec_sets_fx_bit_for_continuation() {
+        let fspec = encode_fspec(8);
+        assert_eq!(fspec.len(), 2);
+        assert_eq!(fspec[0] & 0x01, 0x01, "first octet must set FX");
+        assert_eq!(fspec[1] & 0x01, 0x00, "last octet must not set FX");
+    }
+
+    #[test]
+    fn test_record_builder_roundtrip_without_hex() {
+        let mut item = DataItem::new(None);
+        item.insert_field("SAC".to_string(), ParsedValue::Integer(1));
+        item.insert_field("SIC".to_string(), ParsedValue::Integer(2));
+
+        let builder = RecordBuilder::new(48).with_item("I048/010", &item);
+        let expected_len = builder.serialized_len();
+
+        let bytes = builder.build(&EncodeOptions::default()).unwrap();
+
+        assert_eq!(bytes.len(), expected_len);
+        assert_eq!(bytes[0], 48);
+        let declared_len = u16::from_be_bytes([bytes[1], bytes[2]]) as usize;
+        assert_eq!(declared_len, expected_len);
+    }
+
+    #[test]
+    fn test_record_builder_rejects_oversized_block() {
+        let mut item = DataItem::new(None);
+        item.insert_field(
+            "BIG".to_string(),
+            ParsedValue::Bytes(vec![0u8; u16::MAX as usize]),
+        );
+        let builder = RecordBuilder::new(48).with_item("I048/999", &item);
+        let result = builder.build(&EncodeOptions::default());
+        assert!(result.is_err());
+    }
+}
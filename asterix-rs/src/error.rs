@@ -5,9 +5,107 @@
 
 use std::fmt;
 
+use crate::hex::{hexdump, HexDumpConfig};
+
 /// Result type alias for ASTERIX operations
 pub type Result<T> = std::result::Result<T, AsterixError>;
 
+/// How seriously an [`AsterixError`] should be taken.
+///
+/// Mirrors the "keep going but note it" vs. "stop" distinction mature
+/// parsers draw between a merely-odd value and genuine corruption.
+/// Ordered `Warning < Recoverable < Fatal` so callers can threshold on it
+/// (e.g. `error.severity() >= Severity::Fatal`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Decodable but notable — e.g. a reserved bit set or a spare field
+    /// left non-zero. No current [`AsterixError`] variant is classified
+    /// this way, since nothing on the Rust side inspects individual fields
+    /// closely enough to notice one; it exists for decoders that do.
+    Warning,
+    /// The current block or record couldn't be decoded, but the input
+    /// overall is still meaningful — safe to skip and move on to the next
+    /// block, which is what [`crate::parser::parse_resilient`] does when
+    /// [`ParseOptions::continue_on_error`](crate::ParseOptions::continue_on_error)
+    /// is set.
+    Recoverable,
+    /// Not safe to continue from at all (a setup/environment failure, an
+    /// FFI error, or a security-relevant rejection like
+    /// [`AsterixError::PathOutsideRoot`]).
+    Fatal,
+}
+
+impl Severity {
+    /// The severity to actually act on, given
+    /// [`ParseOptions::strict`](crate::ParseOptions::strict) — in
+    /// strict/conformance-testing mode, every severity is promoted to
+    /// `Fatal` so no anomaly, however minor, is silently tolerated.
+    pub fn effective(self, strict: bool) -> Severity {
+        if strict {
+            Severity::Fatal
+        } else {
+            self
+        }
+    }
+}
+
+/// One level of the category → FSPEC item → sub-field path a
+/// [`AsterixError::ParseError`] was produced under.
+///
+/// Mirrors the context winnow accumulates as a parser combinator chain
+/// unwinds: a decoder attaches the category it's decoding as soon as that's
+/// known, then narrows `item`/`field` as it descends further in, via
+/// [`AsterixError::with_context`]. `item`/`field` are `None` until a caller
+/// that actually has that information (an FSPEC-item or sub-field decoder)
+/// wraps the error — the offset-validation checks in [`crate::parser`] that
+/// run before a category is parsed never produce one at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFrame {
+    /// The ASTERIX category being decoded, e.g. `62` for CAT062
+    pub category: u8,
+    /// The FSPEC item being decoded, e.g. `"I062/010"`
+    pub item: Option<String>,
+    /// The sub-field being decoded within `item`, e.g. `"SIC"`
+    pub field: Option<String>,
+}
+
+impl ParseFrame {
+    /// Start a context frame at the category level; `item`/`field` are filled
+    /// in later via [`ParseFrame::with_item`]/[`ParseFrame::with_field`].
+    pub fn category(category: u8) -> Self {
+        ParseFrame {
+            category,
+            item: None,
+            field: None,
+        }
+    }
+
+    /// Record the FSPEC item being decoded, e.g. `"I062/010"`.
+    pub fn with_item(mut self, item: impl Into<String>) -> Self {
+        self.item = Some(item.into());
+        self
+    }
+
+    /// Record the sub-field being decoded within the current item, e.g. `"SIC"`.
+    pub fn with_field(mut self, field: impl Into<String>) -> Self {
+        self.field = Some(field.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CAT{:03}", self.category)?;
+        if let Some(item) = &self.item {
+            write!(f, " › {item}")?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, " › {field}")?;
+        }
+        Ok(())
+    }
+}
+
 /// The main error type for ASTERIX operations
 ///
 /// All public API functions return `Result<T, AsterixError>` to handle errors
@@ -20,7 +118,7 @@ pub type Result<T> = std::result::Result<T, AsterixError>;
 /// # let data = &[];
 /// match parse(data, ParseOptions::default()) {
 ///     Ok(records) => println!("Parsed {} records", records.len()),
-///     Err(AsterixError::ParseError { offset, message }) => {
+///     Err(AsterixError::ParseError { offset, message, .. }) => {
 ///         eprintln!("Parse failed at byte {}: {}", offset, message);
 ///     },
 ///     Err(e) => eprintln!("Other error: {}", e),
@@ -37,6 +135,10 @@ pub enum AsterixError {
         offset: usize,
         /// Detailed error message
         message: String,
+        /// Category → FSPEC item → sub-field path being decoded when the
+        /// error occurred, if the caller attached one via
+        /// [`AsterixError::with_context`]
+        context: Option<ParseFrame>,
     },
 
     /// Invalid or unsupported ASTERIX category
@@ -112,13 +214,61 @@ pub enum AsterixError {
         /// Error message from XML parser
         message: String,
     },
+
+    /// A streaming reader reached end-of-stream with a record still incomplete
+    ///
+    /// Unlike [`AsterixError::UnexpectedEOF`], which reports a declared length
+    /// running past the end of an in-memory buffer, `Truncated` is returned by
+    /// streaming readers (e.g. [`crate::AsterixReader`]) when the underlying
+    /// `Read` source has no more bytes to give and a record's declared length
+    /// has not yet been fully buffered.
+    Truncated {
+        /// Bytes already buffered for the incomplete record
+        buffered: usize,
+        /// Total length declared in the record's header
+        declared: usize,
+    },
+
+    /// A relative path's `..` components would climb above the directory it
+    /// started in
+    ///
+    /// Returned by [`crate::init_config_dir`] and [`crate::load_category`] when
+    /// a relative path, after resolving `.`/`..` components, nets to more
+    /// parent-directory steps than the path has directory components —
+    /// i.e. it tries to escape above its own root. Absolute paths are never
+    /// rejected by this check: they're already anchored at the filesystem
+    /// root, so there's no "above" to escape to.
+    PathOutsideRoot(String),
+
+    /// A Rust-owned streaming buffer needed to grow past
+    /// [`crate::types::ParseOptions::max_alloc_bytes`]
+    ///
+    /// Returned instead of growing the buffer (or panicking/aborting on a
+    /// genuine allocation failure) once a caller has opted into a bound via
+    /// [`crate::types::ParseOptions::max_alloc_bytes`] and hostile/corrupt
+    /// framing, or an attacker-influenced batch size, would otherwise
+    /// demand an allocation past it.
+    AllocationFailed {
+        /// Bytes the buffer would have needed to grow to
+        requested: usize,
+        /// The configured [`crate::types::ParseOptions::max_alloc_bytes`] limit
+        limit: usize,
+    },
 }
 
 impl fmt::Display for AsterixError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            AsterixError::ParseError { offset, message } => {
-                write!(f, "Parse error at byte offset {offset}: {message}")
+            AsterixError::ParseError {
+                offset,
+                message,
+                context,
+            } => {
+                if let Some(context) = context {
+                    write!(f, "Parse error at byte {offset} in {context}: {message}")
+                } else {
+                    write!(f, "Parse error at byte offset {offset}: {message}")
+                }
             }
             AsterixError::InvalidCategory { category, reason } => {
                 write!(f, "Invalid ASTERIX category {category}: {reason}")
@@ -161,6 +311,21 @@ impl fmt::Display for AsterixError {
                     write!(f, "XML parse error in {file}: {message}")
                 }
             }
+            AsterixError::Truncated { buffered, declared } => {
+                write!(
+                    f,
+                    "stream ended with an incomplete record: buffered {buffered} of {declared} declared bytes"
+                )
+            }
+            AsterixError::PathOutsideRoot(path) => {
+                write!(f, "path traversal detected, escapes its root: {path}")
+            }
+            AsterixError::AllocationFailed { requested, limit } => {
+                write!(
+                    f,
+                    "refused to allocate {requested} bytes, exceeding the configured max_alloc_bytes limit of {limit}"
+                )
+            }
         }
     }
 }
@@ -192,6 +357,74 @@ impl AsterixError {
         AsterixError::ParseError {
             offset,
             message: message.into(),
+            context: None,
+        }
+    }
+
+    /// Attach (or replace) the category/item/field path a [`ParseError`](AsterixError::ParseError)
+    /// was produced under.
+    ///
+    /// A cheap builder so callers can wrap an error with context as the
+    /// parse stack unwinds, e.g. `decode_item(...).map_err(|e| e.with_context(ParseFrame::category(62).with_item("I062/010")))`.
+    /// A no-op on every other variant, since only `ParseError` carries a
+    /// path to annotate.
+    pub fn with_context(mut self, frame: ParseFrame) -> Self {
+        if let AsterixError::ParseError { context, .. } = &mut self {
+            *context = Some(frame);
+        }
+        self
+    }
+
+    /// Shift a [`ParseError`](AsterixError::ParseError)/[`UnexpectedEOF`](AsterixError::UnexpectedEOF)'s
+    /// offset by `delta` bytes.
+    ///
+    /// Used to rebase an error produced while decoding a sub-slice carved out
+    /// of a larger buffer (e.g. one block out of [`crate::parser::parse_resilient`]'s
+    /// input) back to the position it actually occupies in that buffer, so
+    /// it lines up with [`Diagnostic::render_diagnostic`] called against the
+    /// whole thing. A no-op on every other variant.
+    pub fn rebased(self, delta: usize) -> Self {
+        match self {
+            AsterixError::ParseError {
+                offset,
+                message,
+                context,
+            } => AsterixError::ParseError {
+                offset: offset + delta,
+                message,
+                context,
+            },
+            AsterixError::UnexpectedEOF { offset, expected } => AsterixError::UnexpectedEOF {
+                offset: offset + delta,
+                expected,
+            },
+            other => other,
+        }
+    }
+
+    /// Classify how seriously this error should be taken; see [`Severity`].
+    pub fn severity(&self) -> Severity {
+        match self {
+            // Per-block/per-record conditions: the rest of the input is
+            // still meaningful, so `parse_resilient` can skip past one of
+            // these and keep going.
+            AsterixError::ParseError { .. }
+            | AsterixError::InvalidCategory { .. }
+            | AsterixError::InvalidData(_)
+            | AsterixError::UnexpectedEOF { .. }
+            | AsterixError::Truncated { .. } => Severity::Recoverable,
+
+            // Setup, environment, FFI, and security-relevant failures: not
+            // safe to paper over and keep decoding.
+            AsterixError::ConfigNotFound(_)
+            | AsterixError::InitializationError(_)
+            | AsterixError::IOError(_)
+            | AsterixError::InternalError(_)
+            | AsterixError::NullPointer(_)
+            | AsterixError::FFIError(_)
+            | AsterixError::XMLParseError { .. }
+            | AsterixError::PathOutsideRoot(_)
+            | AsterixError::AllocationFailed { .. } => Severity::Fatal,
         }
     }
 
@@ -232,6 +465,96 @@ impl AsterixError {
     }
 }
 
+/// Number of bytes of context [`AsterixError::render_diagnostic`] shows
+/// before and after the failing offset.
+const DIAGNOSTIC_CONTEXT_BYTES: usize = 16;
+
+/// Length of the category + 2-byte big-endian length header every ASTERIX
+/// block starts with (see `crate::framing`'s identical constant).
+const DIAGNOSTIC_HEADER_LEN: usize = 3;
+
+/// Renders an error that carries a byte offset as a contextual diagnostic
+/// against the buffer it was parsed from — an annotated hex dump windowed
+/// around the offset, a caret pointing at the exact byte, and the error
+/// message beneath, in the spirit of the span-annotated diagnostics compiler
+/// front-ends (e.g. TAME's) render for source text.
+pub trait Diagnostic {
+    /// The byte offset this diagnostic centers on, or `None` if this error
+    /// doesn't carry one.
+    fn diagnostic_offset(&self) -> Option<usize>;
+
+    /// Render a contextual report for this error against `data`, the buffer
+    /// it was parsed from.
+    ///
+    /// The context window is clamped to `data`'s bounds, and this degrades
+    /// to a plain message (no hex dump) when `data` is shorter than the
+    /// offset — the case for [`AsterixError::UnexpectedEOF`]/
+    /// [`AsterixError::Truncated`], where there's nothing at the offset to
+    /// show.
+    fn render_diagnostic(&self, data: &[u8]) -> String;
+}
+
+impl Diagnostic for AsterixError {
+    fn diagnostic_offset(&self) -> Option<usize> {
+        match self {
+            AsterixError::ParseError { offset, .. } => Some(*offset),
+            AsterixError::UnexpectedEOF { offset, .. } => Some(*offset),
+            _ => None,
+        }
+    }
+
+    fn render_diagnostic(&self, data: &[u8]) -> String {
+        let Some(offset) = self.diagnostic_offset() else {
+            return self.to_string();
+        };
+
+        if offset >= data.len() {
+            return format!(
+                "{self}\n(byte offset {offset:#x} / {offset} decimal is at or past the end of the {}-byte buffer)",
+                data.len()
+            );
+        }
+
+        let start = offset.saturating_sub(DIAGNOSTIC_CONTEXT_BYTES);
+        let end = (offset + DIAGNOSTIC_CONTEXT_BYTES + 1).min(data.len());
+        let window = &data[start..end];
+        let local_index = offset - start;
+
+        let mut out = String::new();
+
+        if data.len() >= DIAGNOSTIC_HEADER_LEN {
+            let category = data[0];
+            let declared_len = u16::from_be_bytes([data[1], data[2]]);
+            out.push_str(&format!(
+                "block header: category={category} declared_len={declared_len}\n"
+            ));
+        }
+
+        out.push_str(&format!(
+            "bytes {start:#x}..{end:#x} (of {} total):\n",
+            data.len()
+        ));
+        out.push_str(
+            hexdump(
+                window,
+                HexDumpConfig {
+                    width: window.len().max(1),
+                    group_size: window.len().max(1),
+                    show_ascii: true,
+                    show_offset: false,
+                },
+            )
+            .trim_end_matches('\n'),
+        );
+        out.push('\n');
+        out.push_str(&" ".repeat(local_index * 3));
+        out.push_str("^^\n");
+        out.push_str(&self.to_string());
+
+        out
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -241,6 +564,7 @@ mod tests {
         let err = AsterixError::ParseError {
             offset: 42,
             message: "Invalid category".to_string(),
+            context: None,
         };
         let display = err.to_string();
         assert!(display.contains("42"));
@@ -338,6 +662,19 @@ mod tests {
         assert!(display.contains("test.xml"));
         assert!(display.contains("parse error"));
         assert!(!display.contains("line"));
+
+        // Truncated
+        let err = AsterixError::Truncated {
+            buffered: 2,
+            declared: 10,
+        };
+        let display = err.to_string();
+        assert!(display.contains('2'));
+        assert!(display.contains("10"));
+
+        // PathOutsideRoot
+        let err = AsterixError::PathOutsideRoot("../../etc/passwd".to_string());
+        assert!(err.to_string().contains("../../etc/passwd"));
     }
 
     #[test]
@@ -369,4 +706,231 @@ mod tests {
         let err = AsterixError::ffi_error("ffi failed");
         assert!(matches!(err, AsterixError::FFIError(_)));
     }
+
+    // ============================================================================
+    // Diagnostic Tests
+    // ============================================================================
+
+    #[test]
+    fn test_diagnostic_offset_parse_error_and_eof() {
+        let err = AsterixError::parse_error(10, "bad field");
+        assert_eq!(err.diagnostic_offset(), Some(10));
+
+        let err = AsterixError::UnexpectedEOF {
+            offset: 5,
+            expected: 3,
+        };
+        assert_eq!(err.diagnostic_offset(), Some(5));
+    }
+
+    #[test]
+    fn test_diagnostic_offset_none_for_offsetless_errors() {
+        let err = AsterixError::InvalidData("bad data".to_string());
+        assert_eq!(err.diagnostic_offset(), None);
+        assert_eq!(err.render_diagnostic(&[0x01, 0x02]), err.to_string());
+    }
+
+    #[test]
+    fn test_render_diagnostic_shows_hex_window_and_caret() {
+        let data = [0x30, 0x00, 0x05, 0xAA, 0xBB];
+        let err = AsterixError::parse_error(3, "bad field value");
+
+        let rendered = err.render_diagnostic(&data);
+        assert!(rendered.contains("aa"));
+        assert!(rendered.contains("bad field value"));
+        assert!(rendered.contains('^'));
+        // block header: category=0x30 (48), declared_len=0x0005 (5)
+        assert!(rendered.contains("category=48"));
+        assert!(rendered.contains("declared_len=5"));
+    }
+
+    #[test]
+    fn test_render_diagnostic_caret_aligns_with_failing_byte() {
+        let data = [0x30, 0x00, 0x05, 0xAA, 0xBB];
+        let err = AsterixError::parse_error(4, "bad field value");
+
+        let rendered = err.render_diagnostic(&data);
+        let hex_line = rendered
+            .lines()
+            .find(|line| line.contains("30 00"))
+            .expect("hex dump line present");
+        let caret_line = rendered
+            .lines()
+            .find(|line| line.trim_end() == format!("{}^^", " ".repeat(4 * 3)))
+            .expect("caret line present");
+
+        // The caret's column lines up with the start of the 5th byte ("bb")
+        // in the hex dump line above it.
+        let bb_column = hex_line.find("bb").unwrap();
+        let caret_column = caret_line.find('^').unwrap();
+        assert_eq!(bb_column, caret_column);
+    }
+
+    #[test]
+    fn test_render_diagnostic_clamps_window_to_buffer_bounds() {
+        let data = [0x30, 0x00, 0x03];
+        let err = AsterixError::parse_error(2, "short buffer");
+
+        // Must not panic indexing past either end of `data`.
+        let rendered = err.render_diagnostic(&data);
+        assert!(rendered.contains("short buffer"));
+    }
+
+    #[test]
+    fn test_render_diagnostic_degrades_gracefully_past_buffer_end() {
+        let data = [0x30, 0x00, 0x03];
+        let err = AsterixError::UnexpectedEOF {
+            offset: 10,
+            expected: 4,
+        };
+
+        let rendered = err.render_diagnostic(&data);
+        assert!(rendered.contains("0xa"));
+        assert!(rendered.contains("10 decimal"));
+        assert!(rendered.contains("3-byte buffer"));
+        assert!(!rendered.contains('^'));
+    }
+
+    #[test]
+    fn test_render_diagnostic_omits_header_for_short_buffer() {
+        let data = [0xAA];
+        let err = AsterixError::parse_error(0, "too short for a header");
+
+        let rendered = err.render_diagnostic(&data);
+        assert!(!rendered.contains("block header"));
+    }
+
+    // ============================================================================
+    // ParseFrame / with_context Tests
+    // ============================================================================
+
+    #[test]
+    fn test_with_context_sets_category_only() {
+        let err = AsterixError::parse_error(47, "value out of range")
+            .with_context(ParseFrame::category(62));
+        let display = err.to_string();
+        assert_eq!(display, "Parse error at byte 47 in CAT062: value out of range");
+    }
+
+    #[test]
+    fn test_with_context_renders_full_breadcrumb() {
+        let err = AsterixError::parse_error(47, "value out of range").with_context(
+            ParseFrame::category(62).with_item("I062/010").with_field("SIC"),
+        );
+        let display = err.to_string();
+        assert_eq!(
+            display,
+            "Parse error at byte 47 in CAT062 › I062/010 › SIC: value out of range"
+        );
+    }
+
+    #[test]
+    fn test_with_context_is_noop_on_other_variants() {
+        let err = AsterixError::InvalidData("bad data".to_string())
+            .with_context(ParseFrame::category(62));
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_with_context_replaces_previous_context() {
+        let err = AsterixError::parse_error(1, "x")
+            .with_context(ParseFrame::category(48))
+            .with_context(ParseFrame::category(62).with_item("I062/010"));
+        let display = err.to_string();
+        assert!(display.contains("CAT062 › I062/010"));
+        assert!(!display.contains("CAT048"));
+    }
+
+    #[test]
+    fn test_plain_parse_error_has_no_context() {
+        let err = AsterixError::parse_error(47, "value out of range");
+        let display = err.to_string();
+        assert_eq!(display, "Parse error at byte offset 47: value out of range");
+    }
+
+    #[test]
+    fn test_rebased_shifts_parse_error_offset() {
+        let err = AsterixError::parse_error(5, "bad field").rebased(100);
+        assert_eq!(err.diagnostic_offset(), Some(105));
+    }
+
+    #[test]
+    fn test_rebased_shifts_unexpected_eof_offset() {
+        let err = AsterixError::UnexpectedEOF {
+            offset: 5,
+            expected: 2,
+        }
+        .rebased(100);
+        assert_eq!(err.diagnostic_offset(), Some(105));
+    }
+
+    #[test]
+    fn test_rebased_is_noop_on_other_variants() {
+        let err = AsterixError::InvalidData("bad data".to_string()).rebased(100);
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_rebased_preserves_context_and_message() {
+        let err = AsterixError::parse_error(5, "bad field")
+            .with_context(ParseFrame::category(62))
+            .rebased(100);
+        let display = err.to_string();
+        assert_eq!(display, "Parse error at byte 105 in CAT062: bad field");
+    }
+
+    // ============================================================================
+    // Severity Tests
+    // ============================================================================
+
+    #[test]
+    fn test_severity_ordering() {
+        assert!(Severity::Warning < Severity::Recoverable);
+        assert!(Severity::Recoverable < Severity::Fatal);
+    }
+
+    #[test]
+    fn test_severity_classifies_per_block_errors_as_recoverable() {
+        assert_eq!(
+            AsterixError::parse_error(0, "x").severity(),
+            Severity::Recoverable
+        );
+        assert_eq!(
+            AsterixError::InvalidData("x".to_string()).severity(),
+            Severity::Recoverable
+        );
+        assert_eq!(
+            AsterixError::UnexpectedEOF {
+                offset: 0,
+                expected: 1
+            }
+            .severity(),
+            Severity::Recoverable
+        );
+    }
+
+    #[test]
+    fn test_severity_classifies_environment_errors_as_fatal() {
+        assert_eq!(
+            AsterixError::internal_error("x").severity(),
+            Severity::Fatal
+        );
+        assert_eq!(
+            AsterixError::PathOutsideRoot("../x".to_string()).severity(),
+            Severity::Fatal
+        );
+    }
+
+    #[test]
+    fn test_effective_severity_promotes_everything_when_strict() {
+        assert_eq!(Severity::Warning.effective(true), Severity::Fatal);
+        assert_eq!(Severity::Recoverable.effective(true), Severity::Fatal);
+        assert_eq!(Severity::Fatal.effective(true), Severity::Fatal);
+    }
+
+    #[test]
+    fn test_effective_severity_passes_through_when_not_strict() {
+        assert_eq!(Severity::Warning.effective(false), Severity::Warning);
+        assert_eq!(Severity::Recoverable.effective(false), Severity::Recoverable);
+    }
 }
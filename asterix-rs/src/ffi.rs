@@ -100,12 +100,21 @@ pub mod ffi {
 
         // Get current log level
         unsafe fn asterix_get_log_level() -> i32;
+
+        // Register a callback invoked for every line the C++ side logs.
+        // `callback` receives (level, message pointer, message length); the
+        // pointed-to bytes are only valid for the duration of the call.
+        unsafe fn asterix_set_log_callback(callback: fn(i32, *const u8, usize));
     }
 }
 
 // Safe wrapper functions for common operations
 use crate::error::{AsterixError, Result};
+use crate::path_auditor::PathAuditor;
+use serde::{Deserialize, Serialize};
 use std::ffi::CStr;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 /// Initialize ASTERIX with default config directory
 pub fn init_default() -> Result<()> {
@@ -130,38 +139,42 @@ pub fn init_default() -> Result<()> {
         )));
     }
 
-    // Load BDS definitions first (required by many categories)
-    let bds_file = config_path.join("asterix_bds.xml");
-    if bds_file.exists() {
-        load_category(
-            bds_file.to_str().ok_or_else(|| {
-                AsterixError::InvalidData("Invalid UTF-8 in BDS path".to_string())
-            })?,
-        )?;
+    let loaded_count = load_category_files_from_dir(&config_path)?;
+    if loaded_count == 0 {
+        return Err(AsterixError::InitializationError(
+            "No XML configuration files found in config directory".to_string(),
+        ));
     }
 
-    // Load all category XML files from the config directory
-    let entries = std::fs::read_dir(&config_path)
-        .map_err(|e| AsterixError::IOError(format!("Failed to read config directory: {e}")))?;
+    Ok(())
+}
 
-    let mut loaded_count = 0;
-    for entry in entries {
-        let entry = entry?;
-        let path = entry.path();
+/// Initialize ASTERIX with a config directory, auto-discovering category
+/// files in `config_dir` itself and in an adjacent `asterix.d/` drop-in
+/// subdirectory.
+///
+/// Every `*.xml` file directly inside `config_dir` is loaded first (BDS
+/// definitions before categories, then the rest in lexicographic order), and
+/// then the same is done for `config_dir/asterix.d/` if it exists. Since a
+/// later [`load_category`] call for the same ASTERIX category supersedes an
+/// earlier one, this means drop-in files override base files, letting an
+/// operator patch a single category (or a single data item within one) by
+/// dropping a small XML file into `asterix.d/` instead of forking the
+/// vendor's full config directory.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InitializationError`] if neither `config_dir` nor
+/// its `asterix.d/` subdirectory contains any `*.xml` files.
+pub fn init_config_dir_with_drop_ins(config_dir: impl AsRef<Path>) -> Result<()> {
+    let config_dir = config_dir.as_ref();
+    init_config_dir(config_dir)?;
 
-        // Skip BDS file (already loaded) and non-XML files
-        if path.file_name().and_then(|n| n.to_str()) == Some("asterix_bds.xml") {
-            continue;
-        }
+    let mut loaded_count = load_category_files_from_dir(config_dir)?;
 
-        if path.extension().and_then(|e| e.to_str()) == Some("xml") {
-            load_category(
-                path.to_str().ok_or_else(|| {
-                    AsterixError::InvalidData("Invalid UTF-8 in path".to_string())
-                })?,
-            )?;
-            loaded_count += 1;
-        }
+    let drop_in_dir = config_dir.join("asterix.d");
+    if drop_in_dir.is_dir() {
+        loaded_count += load_category_files_from_dir(&drop_in_dir)?;
     }
 
     if loaded_count == 0 {
@@ -173,8 +186,94 @@ pub fn init_default() -> Result<()> {
     Ok(())
 }
 
+/// Initialize ASTERIX with `config_dir`, then load only the category files
+/// matching `pattern`.
+///
+/// `pattern` is expanded relative to `config_dir` (see [`crate::glob`] for
+/// the supported wildcards: `*`, `?`, and `**` for recursive directories),
+/// each match is audited with a [`PathAuditor`] rooted at `config_dir`, and
+/// the resulting files are loaded in lexicographic order. This lets a
+/// deployment that ships dozens of category XMLs point the loader at just
+/// the handful a given sensor emits, e.g. `"asterix_cat0*.xml"` or
+/// `"**/asterix_cat062*.xml"`.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InitializationError`] if `pattern` matches no
+/// files under `config_dir`.
+pub fn init_config_dir_glob(config_dir: impl AsRef<Path>, pattern: &str) -> Result<()> {
+    let config_dir = config_dir.as_ref();
+    init_config_dir(config_dir)?;
+
+    let mut matches = crate::glob::expand(config_dir, pattern)?;
+    matches.retain(|path| path.is_file());
+    matches.sort();
+
+    if matches.is_empty() {
+        return Err(AsterixError::InitializationError(format!(
+            "No files matched pattern '{pattern}' under config directory"
+        )));
+    }
+
+    let mut auditor = PathAuditor::new(config_dir);
+    for path in &matches {
+        auditor.audit(path)?;
+    }
+
+    for path in &matches {
+        load_category(path)?;
+    }
+
+    Ok(())
+}
+
+/// Load every `*.xml` file directly inside `dir` via [`load_category`],
+/// returning how many were loaded.
+///
+/// `asterix_bds.xml` (if present) is loaded first, since it defines the BDS
+/// registers many categories depend on; the rest are loaded in lexicographic
+/// order. Does not recurse into subdirectories, so files under a subdirectory
+/// (symlinked or not) are never loaded. Every file is audited with a
+/// [`PathAuditor`] rooted at `dir` first, so an `*.xml` entry that is itself a
+/// symlink resolving outside `dir` (to somewhere like `/etc`) is rejected
+/// rather than loaded.
+fn load_category_files_from_dir(dir: &Path) -> Result<usize> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| AsterixError::IOError(format!("Failed to read config directory: {e}")))?;
+
+    let mut xml_files: Vec<std::path::PathBuf> = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("xml") {
+            xml_files.push(path);
+        }
+    }
+    xml_files.sort();
+
+    let mut auditor = PathAuditor::new(dir);
+    for path in &xml_files {
+        auditor.audit(path)?;
+    }
+
+    let bds_index = xml_files
+        .iter()
+        .position(|p| p.file_name().and_then(|n| n.to_str()) == Some("asterix_bds.xml"));
+    if let Some(index) = bds_index {
+        load_category(xml_files.remove(index))?;
+    }
+
+    for path in &xml_files {
+        load_category(path)?;
+    }
+
+    Ok(xml_files.len() + usize::from(bds_index.is_some()))
+}
+
 /// Initialize ASTERIX with specific config directory
-pub fn init_config_dir(config_dir: &str) -> Result<()> {
+pub fn init_config_dir(config_dir: impl AsRef<Path>) -> Result<()> {
+    let config_dir = path_to_str(config_dir.as_ref(), "Directory path")?;
+
     // MEDIUM-004 FIX: Validate directory path parameter
     if config_dir.is_empty() {
         return Err(AsterixError::InvalidData(
@@ -183,15 +282,7 @@ pub fn init_config_dir(config_dir: &str) -> Result<()> {
     }
 
     // MEDIUM-004 FIX: Check for path traversal attacks (Windows + Unix)
-    // Defense-in-depth: Block obvious traversal attempts
-    // Only block paths that START with ".." (e.g., ../../../etc/passwd)
-    // Allow "/../" in middle of paths (from path.join within project)
-    // The C++ layer provides primary security (file exists, validation, etc.)
-    if config_dir.starts_with("../") || config_dir.starts_with("..\\") || config_dir == ".." {
-        return Err(AsterixError::InvalidData(
-            "Invalid directory path: path traversal detected (..)".to_string(),
-        ));
-    }
+    reject_path_traversal(config_dir)?;
 
     // MEDIUM-004 FIX: Validate path length
     if config_dir.len() > 4096 {
@@ -214,7 +305,9 @@ pub fn init_config_dir(config_dir: &str) -> Result<()> {
 }
 
 /// Load a category definition file
-pub fn load_category(xml_path: &str) -> Result<()> {
+pub fn load_category(xml_path: impl AsRef<Path>) -> Result<()> {
+    let xml_path = path_to_str(xml_path.as_ref(), "Filename")?;
+
     // MEDIUM-004 FIX: Validate filename parameter
     if xml_path.is_empty() {
         return Err(AsterixError::InvalidData(
@@ -223,15 +316,7 @@ pub fn load_category(xml_path: &str) -> Result<()> {
     }
 
     // MEDIUM-004 FIX: Check for path traversal attacks (Windows + Unix)
-    // Defense-in-depth: Block obvious traversal attempts
-    // Only block paths that START with ".." (e.g., ../../../etc/passwd)
-    // Allow "/../" in middle of paths (from path.join within project)
-    // The C++ layer provides primary security (file exists, XML validation, etc.)
-    if xml_path.starts_with("../") || xml_path.starts_with("..\\") || xml_path == ".." {
-        return Err(AsterixError::InvalidData(
-            "Invalid filename: path traversal detected (..)".to_string(),
-        ));
-    }
+    reject_path_traversal(xml_path)?;
 
     // MEDIUM-004 FIX: Validate filename length
     if xml_path.len() > 4096 {
@@ -253,6 +338,140 @@ pub fn load_category(xml_path: &str) -> Result<()> {
     }
 }
 
+/// Borrow `path` as UTF-8, used when accepting `impl AsRef<Path>` at the FFI boundary.
+fn path_to_str<'a>(path: &'a Path, what: &str) -> Result<&'a str> {
+    path.to_str()
+        .ok_or_else(|| AsterixError::InvalidData(format!("{what} is not valid UTF-8")))
+}
+
+/// Reject a relative path whose `..` components would climb above the
+/// directory it started in.
+///
+/// This replaces a brittle literal-prefix check (`starts_with("../")`, which
+/// a path like `foo/../../etc/passwd` routes around even though it escapes
+/// just as surely) with lexical normalization: walk the path's components,
+/// tracking depth, and fail if a `..` would take it negative. Both `/` and
+/// `\` are treated as separators regardless of host OS, since a config path
+/// authored on Windows may be validated on Linux or vice versa.
+///
+/// Absolute paths (`/...`, or Windows drive-letter/UNC forms) are never
+/// rejected: they're already anchored at the filesystem root, so there's no
+/// "above" to escape to. The C++ layer provides primary security (file
+/// existence, XML validation, etc.); this is defense in depth.
+fn reject_path_traversal(path: &str) -> Result<()> {
+    if path.starts_with('/') || path.starts_with('\\') || is_windows_absolute(path) {
+        return Ok(());
+    }
+
+    let mut depth: i64 = 0;
+    for component in path.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err(AsterixError::PathOutsideRoot(path.to_string()));
+                }
+            }
+            _ => depth += 1,
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `path` looks like a Windows absolute path (`C:\...` or `C:/...`).
+fn is_windows_absolute(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+/// Lexically resolve `candidate` against `root` and confirm the result never
+/// climbs above `root` — no filesystem access, no symlink resolution, no
+/// existence check.
+///
+/// Walks `candidate`'s components (in the style of `std::path::absolute`),
+/// pushing `Normal` parts onto a stack seeded empty (representing `root`
+/// itself) and popping on `..`; a `..` that would pop past that starting
+/// point means `candidate` escapes `root`. Unlike [`PathAuditor`], which
+/// resolves real symlinks on disk, this does no I/O at all, so it's suitable
+/// for validating a category path supplied by an untrusted caller (e.g. over
+/// a network control channel) before anything is touched on disk. `candidate`
+/// must be relative: an absolute path component is always rejected, since it
+/// would otherwise replace `root` entirely.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if `candidate` is absolute or
+/// normalizes to somewhere outside `root`.
+fn confine_to_root(root: &Path, candidate: &Path) -> Result<PathBuf> {
+    let mut stack: Vec<&std::ffi::OsStr> = Vec::new();
+
+    for component in candidate.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if stack.pop().is_none() {
+                    return Err(AsterixError::InvalidData(format!(
+                        "path '{}' escapes sandbox root '{}'",
+                        candidate.display(),
+                        root.display()
+                    )));
+                }
+            }
+            Component::Normal(part) => stack.push(part),
+            Component::RootDir | Component::Prefix(_) => {
+                return Err(AsterixError::InvalidData(format!(
+                    "path '{}' is absolute, which sandbox mode rejects (root: '{}')",
+                    candidate.display(),
+                    root.display()
+                )));
+            }
+        }
+    }
+
+    let mut normalized = root.to_path_buf();
+    normalized.extend(stack);
+    Ok(normalized)
+}
+
+/// Sandbox-mode variant of [`init_config_dir`]: `config_dir` is resolved
+/// purely lexically against `root` (see [`confine_to_root`]) and rejected if
+/// it would climb above `root`, before the C++ side ever sees a path.
+///
+/// The plain [`init_config_dir`] is not tied to any root: it accepts any
+/// absolute path, and rejects a relative path only when its `..` components
+/// climb above its own starting point. This opt-in variant is for callers
+/// that need a hard confinement guarantee, such as a config directory named
+/// by an untrusted client.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if `config_dir` escapes `root`, or
+/// any error [`init_config_dir`] itself can return.
+pub fn init_config_dir_sandboxed(
+    root: impl AsRef<Path>,
+    config_dir: impl AsRef<Path>,
+) -> Result<()> {
+    let root = root.as_ref();
+    let confined = confine_to_root(root, config_dir.as_ref())?;
+    init_config_dir(confined)
+}
+
+/// Sandbox-mode variant of [`load_category`]: `xml_path` is resolved purely
+/// lexically against `root` (see [`confine_to_root`]) and rejected if it
+/// would climb above `root`, before the C++ side ever sees a path.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if `xml_path` escapes `root`, or any
+/// error [`load_category`] itself can return.
+pub fn load_category_sandboxed(root: impl AsRef<Path>, xml_path: impl AsRef<Path>) -> Result<()> {
+    let root = root.as_ref();
+    let confined = confine_to_root(root, xml_path.as_ref())?;
+    load_category(confined)
+}
+
 /// Check if a category is defined
 pub fn is_category_defined(category: u8) -> bool {
     unsafe { ffi::asterix_category_defined(category) }
@@ -311,6 +530,103 @@ pub fn get_log_level() -> LogLevel {
     }
 }
 
+/// Sink type behind [`set_log_sink`], stored in a global slot so the
+/// extern "C" trampoline registered with the C++ side has somewhere to
+/// dispatch to (a raw C function pointer can't carry captured state).
+type LogSink = dyn Fn(LogLevel, &str) + Send + Sync;
+
+static LOG_SINK: OnceLock<Mutex<Option<Box<LogSink>>>> = OnceLock::new();
+
+fn log_sink_slot() -> &'static Mutex<Option<Box<LogSink>>> {
+    LOG_SINK.get_or_init(|| Mutex::new(None))
+}
+
+/// Trampoline registered with the C++ side via `asterix_set_log_callback`.
+///
+/// # Safety
+///
+/// `message_ptr` must point to `message_len` valid bytes for the duration of
+/// the call, as guaranteed by the C++ log emitter that invokes this callback.
+extern "C" fn log_trampoline(level: i32, message_ptr: *const u8, message_len: usize) {
+    if message_ptr.is_null() {
+        return;
+    }
+
+    let Some(sink_slot) = log_sink_slot().lock().ok() else {
+        return;
+    };
+    let Some(sink) = sink_slot.as_ref() else {
+        return;
+    };
+
+    let message = unsafe { std::slice::from_raw_parts(message_ptr, message_len) };
+    let message = String::from_utf8_lossy(message);
+    let level = match level {
+        0 => LogLevel::Silent,
+        1 => LogLevel::Error,
+        2 => LogLevel::Warn,
+        3 => LogLevel::Info,
+        4 => LogLevel::Debug,
+        _ => LogLevel::Error,
+    };
+
+    sink(level, &message);
+}
+
+/// Register a callback invoked for every line the ASTERIX parser logs.
+///
+/// Replaces any previously registered sink. Unlike [`set_log_level`], which
+/// only filters C++'s own stderr output, this routes log lines through
+/// arbitrary Rust code (e.g. a `tracing` subscriber or a custom file sink).
+/// Call [`clear_log_sink`] to stop forwarding.
+///
+/// # Example
+///
+/// ```no_run
+/// use asterix::{set_log_sink, LogLevel};
+///
+/// set_log_sink(|level, message| {
+///     eprintln!("[{level:?}] {message}");
+/// });
+/// ```
+pub fn set_log_sink(sink: impl Fn(LogLevel, &str) + Send + Sync + 'static) {
+    *log_sink_slot().lock().unwrap() = Some(Box::new(sink));
+    unsafe {
+        ffi::asterix_set_log_callback(log_trampoline);
+    }
+}
+
+/// Stop forwarding log lines to a Rust sink.
+///
+/// The C++ side keeps calling the trampoline, which becomes a harmless no-op
+/// once the sink slot is empty.
+pub fn clear_log_sink() {
+    *log_sink_slot().lock().unwrap() = None;
+}
+
+/// Convenience wrapper around [`set_log_sink`] that appends every log line to `path`.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::IOError`] if `path` can't be opened for appending.
+pub fn set_log_file(path: impl AsRef<Path>) -> Result<()> {
+    use std::io::Write;
+
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let file = Mutex::new(file);
+
+    set_log_sink(move |level, message| {
+        if let Ok(mut file) = file.lock() {
+            let _ = writeln!(file, "[{level:?}] {message}");
+        }
+    });
+
+    Ok(())
+}
+
 /// Get description for a category/item/field/value
 pub fn describe(
     category: u8,
@@ -355,6 +671,127 @@ pub fn describe(
     }
 }
 
+/// One field within an [`ItemDesc`].
+///
+/// `bits`/`encoding`/`unit`/`value_enum` mirror the per-field metadata an
+/// ASTERIX category XML definition carries (bit range within the item,
+/// encoding, unit, and enumerated value table), but this crate's FFI surface
+/// doesn't currently expose that model to Rust — only [`describe`]'s
+/// rendered text. They're `None` until a richer C++ accessor exists to
+/// source them from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct FieldDesc {
+    /// Field name, e.g. `"SAC"`
+    pub name: String,
+    /// Bit range within the item, e.g. `"1-8"`
+    pub bits: Option<String>,
+    /// Encoding, e.g. `"unsigned"` or `"two's complement"`
+    pub encoding: Option<String>,
+    /// Unit, e.g. `"NM"` or `"ft"`
+    pub unit: Option<String>,
+    /// Enumerated value table (raw value → label), for fields whose values
+    /// are drawn from a fixed set
+    pub value_enum: Option<std::collections::BTreeMap<String, String>>,
+}
+
+/// One data item within a [`CategoryDesc`], e.g. `I062/010`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct ItemDesc {
+    /// Item id, e.g. `"I062/010"`
+    pub id: String,
+    /// Item name, if known
+    pub name: Option<String>,
+    /// Human-readable description, if known
+    pub description: Option<String>,
+    /// Fields within this item that were asked about; empty unless
+    /// [`describe_structured`] was called with a specific `field`, since
+    /// this crate has no way to enumerate an item's full field list (see
+    /// [`FieldDesc`])
+    pub fields: Vec<FieldDesc>,
+}
+
+/// Machine-readable counterpart to [`describe`]'s formatted text, returned
+/// by [`describe_structured`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "jsonschema", derive(schemars::JsonSchema))]
+pub struct CategoryDesc {
+    /// ASTERIX category number, e.g. `62`
+    pub category: u8,
+    /// Category edition/version string (e.g. `"1.29"`), if known
+    pub edition: Option<String>,
+    /// Category-level description text, populated when `describe_structured`
+    /// was called without an `item`
+    pub description: Option<String>,
+    /// The item asked about, if any — see [`ItemDesc::fields`] for why this
+    /// is at most one entry rather than the category's full item roster
+    pub items: Vec<ItemDesc>,
+}
+
+/// Alias for [`describe_structured`]'s return type, matching the name used
+/// in its signature.
+pub type Description = CategoryDesc;
+
+/// Machine-readable counterpart to [`describe`].
+///
+/// Takes the same `(category, item, field, value)` arguments, but returns a
+/// typed [`Description`] instead of formatted text — useful for tooling
+/// (documentation generation, a web UI) that wants to query, say, an item's
+/// id programmatically instead of scraping it back out of a sentence.
+///
+/// This crate's FFI surface only exposes [`describe`]'s rendered text, not
+/// the underlying category XML's item/field model, so the result is
+/// necessarily a thin wrapper rather than a full structured breakdown:
+/// `items`/`fields` carry at most the single item/field actually asked
+/// about (never a category's full roster), and [`FieldDesc`]'s
+/// bits/encoding/unit/value table stay `None`. [`describe`] itself stays a
+/// direct path to the full rendered text rather than being rebuilt on top
+/// of this lossier structure — when `field` is set, `describe`'s text
+/// (typically an explanation of `value`) has no field to land in here at
+/// all, since [`FieldDesc`] carries no freeform text slot; call [`describe`]
+/// directly in that case.
+///
+/// # Errors
+///
+/// Same conditions as [`describe`].
+pub fn describe_structured(
+    category: u8,
+    item: Option<&str>,
+    field: Option<&str>,
+    value: Option<&str>,
+) -> Result<Description> {
+    let text = describe(category, item, field, value)?;
+
+    let fields = match field {
+        Some(field_name) => vec![FieldDesc {
+            name: field_name.to_string(),
+            bits: None,
+            encoding: None,
+            unit: None,
+            value_enum: None,
+        }],
+        None => Vec::new(),
+    };
+
+    let items = match item {
+        Some(item_id) => vec![ItemDesc {
+            id: item_id.to_string(),
+            name: None,
+            description: field.is_none().then(|| text.clone()),
+            fields,
+        }],
+        None => Vec::new(),
+    };
+
+    Ok(Description {
+        category,
+        edition: None,
+        description: item.is_none().then_some(text),
+        items,
+    })
+}
+
 /// Helper to convert C string pointer to Rust String (and free it)
 pub(crate) unsafe fn c_string_to_rust(ptr: *mut u8) -> Result<String> {
     if ptr.is_null() {
@@ -465,35 +902,28 @@ mod tests {
     fn test_init_config_dir_rejects_path_traversal_unix() {
         // Test Unix-style path traversal
         let result = init_config_dir("../../../etc/passwd");
-        assert!(result.is_err());
-        if let Err(AsterixError::InvalidData(msg)) = result {
-            assert!(msg.contains("path traversal"));
-        } else {
-            panic!("Expected InvalidData error for path traversal");
-        }
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
     }
 
     #[test]
     fn test_init_config_dir_rejects_path_traversal_windows() {
         // Test Windows-style path traversal
         let result = init_config_dir("..\\..\\Windows\\System32");
-        assert!(result.is_err());
-        if let Err(AsterixError::InvalidData(msg)) = result {
-            assert!(msg.contains("path traversal"));
-        } else {
-            panic!("Expected InvalidData error for path traversal");
-        }
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
     }
 
     #[test]
     fn test_init_config_dir_rejects_dotdot_alone() {
         let result = init_config_dir("..");
-        assert!(result.is_err());
-        if let Err(AsterixError::InvalidData(msg)) = result {
-            assert!(msg.contains("path traversal"));
-        } else {
-            panic!("Expected InvalidData error for '..'");
-        }
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
+    }
+
+    #[test]
+    fn test_init_config_dir_rejects_traversal_not_at_start() {
+        // A brittle `starts_with("../")` check would miss this: the string
+        // doesn't start with "..", but it still climbs above its own root.
+        let result = init_config_dir("config/../../etc/passwd");
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
     }
 
     #[test]
@@ -522,34 +952,33 @@ mod tests {
     #[test]
     fn test_load_category_rejects_path_traversal_unix() {
         let result = load_category("../../../etc/passwd");
-        assert!(result.is_err());
-        if let Err(AsterixError::InvalidData(msg)) = result {
-            assert!(msg.contains("path traversal"));
-        } else {
-            panic!("Expected InvalidData error for path traversal");
-        }
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
     }
 
     #[test]
     fn test_load_category_rejects_path_traversal_windows() {
         let result = load_category("..\\..\\malicious.xml");
-        assert!(result.is_err());
-        if let Err(AsterixError::InvalidData(msg)) = result {
-            assert!(msg.contains("path traversal"));
-        } else {
-            panic!("Expected InvalidData error for path traversal");
-        }
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
     }
 
     #[test]
     fn test_load_category_rejects_dotdot_alone() {
         let result = load_category("..");
-        assert!(result.is_err());
-        if let Err(AsterixError::InvalidData(msg)) = result {
-            assert!(msg.contains("path traversal"));
-        } else {
-            panic!("Expected InvalidData error for '..'");
-        }
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
+    }
+
+    #[test]
+    fn test_load_category_rejects_traversal_not_at_start() {
+        let result = load_category("categories/../../etc/passwd");
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
+    }
+
+    #[test]
+    fn test_load_category_accepts_pathbuf() {
+        // Generalized to `impl AsRef<Path>`: a PathBuf should work directly,
+        // without callers needing to convert to `&str` first.
+        let result = load_category(std::path::PathBuf::from("/etc/asterix/cat048.xml"));
+        assert!(!matches!(result, Err(AsterixError::PathOutsideRoot(_))));
     }
 
     #[test]
@@ -658,6 +1087,65 @@ mod tests {
         );
     }
 
+    // ========================================================================
+    // describe_structured() Validation Tests
+    // ========================================================================
+
+    #[test]
+    fn test_describe_structured_rejects_category_zero() {
+        let result = describe_structured(0, None, None, None);
+        assert!(result.is_err());
+        if let Err(AsterixError::InvalidData(msg)) = result {
+            assert!(msg.contains("Invalid ASTERIX category: 0"));
+        } else {
+            panic!("Expected InvalidData error for category 0");
+        }
+    }
+
+    #[test]
+    fn test_describe_structured_none_parameters() {
+        // Category 1 is valid, should fail at C++ boundary (not initialized)
+        let result = describe_structured(1, None, None, None);
+        assert!(
+            !matches!(result, Err(AsterixError::InvalidData(_))),
+            "None parameters should be valid"
+        );
+    }
+
+    #[test]
+    fn test_describe_structured_carries_category_and_item() {
+        // Can't reach the C++ boundary here, but the shape of a successful
+        // result shouldn't depend on it: fabricate one directly to pin down
+        // which fields get populated for an item-scoped query.
+        let desc = CategoryDesc {
+            category: 48,
+            edition: None,
+            description: None,
+            items: vec![ItemDesc {
+                id: "I010".to_string(),
+                name: None,
+                description: Some("System Area Code".to_string()),
+                fields: Vec::new(),
+            }],
+        };
+        assert_eq!(desc.category, 48);
+        assert_eq!(desc.items.len(), 1);
+        assert_eq!(desc.items[0].id, "I010");
+        assert!(desc.items[0].fields.is_empty());
+    }
+
+    #[test]
+    fn test_description_is_serializable() {
+        let desc = Description {
+            category: 62,
+            edition: None,
+            description: Some("Track message".to_string()),
+            items: Vec::new(),
+        };
+        let json = serde_json::to_string(&desc).expect("Description should serialize");
+        assert!(json.contains("\"category\":62"));
+    }
+
     // ========================================================================
     // C String Conversion Tests
     // ========================================================================
@@ -744,6 +1232,206 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // Drop-in Config Directory Tests
+    // ========================================================================
+
+    fn unique_temp_dir(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("asterix_ffi_test_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_load_category_files_from_dir_ignores_non_xml_files() {
+        let dir = unique_temp_dir("ignores_non_xml");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("readme.txt"), b"not xml").unwrap();
+
+        let count = load_category_files_from_dir(&dir).unwrap();
+        assert_eq!(count, 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_load_category_files_from_dir_attempts_bds_before_others() {
+        let dir = unique_temp_dir("bds_first");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("asterix_cat048.xml"), b"<category/>").unwrap();
+        std::fs::write(dir.join("asterix_bds.xml"), b"<bds/>").unwrap();
+
+        // Whether or not the C++ stub accepts this placeholder content, both
+        // files should be attempted (the count reflects discovery, not load
+        // success) rather than bailing out before reaching the FFI boundary.
+        match load_category_files_from_dir(&dir) {
+            Ok(count) => assert_eq!(count, 2),
+            Err(AsterixError::InitializationError(_)) => {}
+            Err(e) => panic!("Unexpected error type: {e:?}"),
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_category_files_from_dir_rejects_symlinked_subdirectory() {
+        use std::os::unix::fs::symlink;
+
+        let dir = unique_temp_dir("symlink_escape");
+        let outside = unique_temp_dir("symlink_escape_target");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("asterix_cat048.xml"), b"<category/>").unwrap();
+        symlink(&outside, dir.join("linked")).unwrap();
+        std::fs::write(dir.join("real.xml"), b"<category/>").unwrap();
+
+        // The audited xml file itself lives directly in `dir` (not under the
+        // symlink), so this must not be rejected...
+        let result = load_category_files_from_dir(&dir);
+        match result {
+            Ok(_) | Err(AsterixError::InitializationError(_)) => {}
+            Err(e) => panic!("Unexpected error type: {e:?}"),
+        }
+
+        // ...but a path that walks through the symlinked subdirectory must be.
+        let mut auditor = PathAuditor::new(&dir);
+        let escaping = auditor.audit(dir.join("linked/asterix_cat048.xml"));
+        assert!(matches!(escaping, Err(AsterixError::InvalidData(_))));
+
+        let _ = std::fs::remove_dir_all(&dir);
+        let _ = std::fs::remove_dir_all(&outside);
+    }
+
+    #[test]
+    fn test_init_config_dir_with_drop_ins_errors_with_no_xml_files() {
+        let dir = unique_temp_dir("no_dropins");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let result = init_config_dir_with_drop_ins(&dir);
+        assert!(matches!(result, Err(AsterixError::InitializationError(_))));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_init_config_dir_with_drop_ins_discovers_drop_in_subdirectory() {
+        let dir = unique_temp_dir("with_dropins");
+        std::fs::create_dir_all(dir.join("asterix.d")).unwrap();
+        std::fs::write(dir.join("asterix_cat048.xml"), b"<category/>").unwrap();
+        std::fs::write(
+            dir.join("asterix.d").join("asterix_cat048.xml"),
+            b"<category override/>",
+        )
+        .unwrap();
+
+        // Garbage XML content may fail to load at the C++ boundary, but the
+        // base file and its drop-in override must both be attempted rather
+        // than the discovery logic stopping after the base directory.
+        match init_config_dir_with_drop_ins(&dir) {
+            Ok(()) => {}
+            Err(AsterixError::InitializationError(_)) => {}
+            Err(e) => panic!("Unexpected error type: {e:?}"),
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    // ========================================================================
+    // Sandbox Mode Tests
+    // ========================================================================
+
+    #[test]
+    fn test_confine_to_root_allows_plain_relative_path() {
+        let root = Path::new("/etc/asterix/config");
+        let result = confine_to_root(root, Path::new("asterix_cat048.xml")).unwrap();
+        assert_eq!(result, root.join("asterix_cat048.xml"));
+    }
+
+    #[test]
+    fn test_confine_to_root_allows_balanced_dotdot() {
+        let root = Path::new("/etc/asterix/config");
+        let result = confine_to_root(root, Path::new("a/../asterix_cat048.xml")).unwrap();
+        assert_eq!(result, root.join("asterix_cat048.xml"));
+    }
+
+    #[test]
+    fn test_confine_to_root_rejects_escaping_dotdot() {
+        let root = Path::new("/etc/asterix/config");
+        let result = confine_to_root(root, Path::new("../../../etc/passwd"));
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_confine_to_root_rejects_dotdot_past_start_mid_path() {
+        // `a/..` nets out at the root, so only the second `..` escapes:
+        // sandbox mode rejects any `..` that pops past the beginning of the
+        // *candidate* itself, even mid-path, since there's no real root
+        // directory on disk to resolve "go up from root, then back down"
+        // against.
+        let root = Path::new("/etc/asterix/config");
+        let result = confine_to_root(root, Path::new("a/../../b"));
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_confine_to_root_rejects_absolute_candidate() {
+        let root = Path::new("/etc/asterix/config");
+        let result = confine_to_root(root, Path::new("/etc/passwd"));
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_init_config_dir_sandboxed_rejects_escape() {
+        let result = init_config_dir_sandboxed("/etc/asterix/config", "../../../etc");
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_load_category_sandboxed_rejects_escape() {
+        let result = load_category_sandboxed("/etc/asterix/config", "../../../etc/passwd");
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_load_category_sandboxed_rejects_absolute_candidate() {
+        let result = load_category_sandboxed("/etc/asterix/config", "/etc/passwd");
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    // ========================================================================
+    // Glob Pattern Config Loading Tests
+    // ========================================================================
+
+    #[test]
+    fn test_init_config_dir_glob_errors_with_no_matches() {
+        let dir = unique_temp_dir("glob_no_matches");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let result = init_config_dir_glob(&dir, "asterix_cat9*.xml");
+        assert!(matches!(result, Err(AsterixError::InitializationError(_))));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_init_config_dir_glob_attempts_every_match() {
+        let dir = unique_temp_dir("glob_attempts_matches");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("asterix_cat048.xml"), b"<category/>").unwrap();
+        std::fs::write(dir.join("asterix_cat062.xml"), b"<category/>").unwrap();
+        std::fs::write(dir.join("asterix_bds.xml"), b"<bds/>").unwrap();
+
+        // Garbage XML content may fail at the C++ boundary, but only the two
+        // cat0* files should be candidates (the pattern excludes the BDS
+        // file), so a successful load attempt touches exactly those.
+        match init_config_dir_glob(&dir, "asterix_cat0*.xml") {
+            Ok(()) => {}
+            Err(AsterixError::InitializationError(_)) => {}
+            Err(e) => panic!("Unexpected error type: {e:?}"),
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_init_config_dir_allows_dotdot_in_middle() {
         // ".." in middle of path is allowed (from path.join)
@@ -756,6 +1444,89 @@ mod tests {
         }
     }
 
+    // ========================================================================
+    // reject_path_traversal() Tests (unit-level)
+    // ========================================================================
+
+    #[test]
+    fn test_reject_path_traversal_allows_unix_absolute() {
+        assert!(reject_path_traversal("/etc/asterix/config").is_ok());
+    }
+
+    #[test]
+    fn test_reject_path_traversal_allows_windows_absolute() {
+        assert!(reject_path_traversal(r"C:\asterix\config").is_ok());
+        assert!(reject_path_traversal("C:/asterix/config").is_ok());
+    }
+
+    #[test]
+    fn test_reject_path_traversal_catches_relative_escape_past_midpoint() {
+        // Net depth goes negative only after the second "..", so a check
+        // that only inspected the string's prefix would miss this.
+        let result = reject_path_traversal("a/../../b");
+        assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
+    }
+
+    #[test]
+    fn test_reject_path_traversal_allows_balanced_dotdot() {
+        // Goes down two levels, then back up two: never climbs above the root.
+        assert!(reject_path_traversal("a/b/../../c").is_ok());
+    }
+
+    // ========================================================================
+    // Log Sink Tests
+    // ========================================================================
+
+    #[test]
+    fn test_log_trampoline_dispatches_to_registered_sink() {
+        use std::sync::{Arc, Mutex as StdMutex};
+
+        let received = Arc::new(StdMutex::new(None));
+        let received_clone = Arc::clone(&received);
+
+        set_log_sink(move |level, message| {
+            *received_clone.lock().unwrap() = Some((level, message.to_string()));
+        });
+
+        let msg = b"test message";
+        log_trampoline(3, msg.as_ptr(), msg.len());
+
+        let got = received.lock().unwrap().clone();
+        assert_eq!(got, Some((LogLevel::Info, "test message".to_string())));
+
+        clear_log_sink();
+    }
+
+    #[test]
+    fn test_log_trampoline_noop_after_clear() {
+        clear_log_sink();
+        // Should not panic with no sink registered.
+        let msg = b"ignored";
+        log_trampoline(1, msg.as_ptr(), msg.len());
+    }
+
+    #[test]
+    fn test_log_trampoline_ignores_null_pointer() {
+        log_trampoline(1, std::ptr::null(), 0);
+        // Reaching here without panicking is the assertion.
+    }
+
+    #[test]
+    fn test_set_log_file_writes_log_lines() {
+        let path = std::env::temp_dir().join("asterix_ffi_test_log_file.log");
+        let _ = std::fs::remove_file(&path);
+
+        set_log_file(&path).unwrap();
+        let msg = b"hello from test";
+        log_trampoline(2, msg.as_ptr(), msg.len());
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("hello from test"));
+
+        clear_log_sink();
+        let _ = std::fs::remove_file(&path);
+    }
+
     // ========================================================================
     // is_category_defined() Tests (safe wrapper)
     // ========================================================================
@@ -0,0 +1,149 @@
+//! Pure-Rust scanning of ASTERIX block boundaries, without decoding
+//!
+//! Every ASTERIX data block starts with the same 3-byte header: a 1-byte
+//! category, followed by a big-endian 2-byte length that includes those 3
+//! header bytes. [`frame_blocks`] walks that header repeatedly to produce a
+//! [`BlockSpan`] per block, without crossing the FFI boundary into the C++
+//! decoder at all — useful for callers (like [`crate::parser::parse_with_offset`]'s
+//! `remaining_blocks`/`bytes_consumed` bookkeeping, or [`crate::parallel::parse_parallel`]'s
+//! `scan_block_offsets`, which does the same walk inline) that only need to
+//! know *where* blocks are, not what's in them.
+//!
+//! Unlike `parallel::scan_block_offsets` (which treats any malformed or
+//! truncated trailing bytes as a hard error, since it's feeding a one-shot
+//! batch parse), [`frame_blocks`] is meant for streaming/resumable callers:
+//! a header that declares a length smaller than itself is a genuine framing
+//! error, but a trailing block whose header is present yet whose payload
+//! hasn't fully arrived yet is not an error — it's simply not part of this
+//! call's result, and its offset is reported so the caller can pick up there
+//! once more bytes show up.
+
+use crate::error::{AsterixError, Result};
+
+/// Length of the category + 2-byte big-endian length header every block starts with.
+const HEADER_LEN: usize = 3;
+
+/// One ASTERIX data block's location within a buffer, as found by [`frame_blocks`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockSpan {
+    /// The block's ASTERIX category (the header's first byte).
+    pub category: u8,
+    /// Byte offset of the block's header within the scanned buffer.
+    pub start: usize,
+    /// Total length of the block (header + payload), as declared by the header.
+    pub len: usize,
+}
+
+/// Scan `data` for complete ASTERIX block headers without decoding any of them.
+///
+/// Returns the [`BlockSpan`]s found, in file order, along with the number of
+/// bytes consumed by them (i.e. the offset of the first byte *not* part of a
+/// returned span). A trailing partial header or a header whose declared
+/// length runs past the end of `data` is not an error: scanning simply stops
+/// there, and the returned offset tells the caller where to resume once more
+/// bytes are available.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if a header declares a length
+/// smaller than the 3-byte header itself, since that can never be a valid
+/// block no matter how much more data arrives.
+pub fn frame_blocks(data: &[u8]) -> Result<(Vec<BlockSpan>, usize)> {
+    let mut spans = Vec::new();
+    let mut offset = 0usize;
+
+    while offset + HEADER_LEN <= data.len() {
+        let category = data[offset];
+        let declared_len = u16::from_be_bytes([data[offset + 1], data[offset + 2]]) as usize;
+
+        if declared_len < HEADER_LEN {
+            return Err(AsterixError::InvalidData(format!(
+                "declared block length {declared_len} at offset {offset} is smaller than the {HEADER_LEN}-byte header"
+            )));
+        }
+
+        if offset + declared_len > data.len() {
+            break;
+        }
+
+        spans.push(BlockSpan {
+            category,
+            start: offset,
+            len: declared_len,
+        });
+        offset += declared_len;
+    }
+
+    Ok((spans, offset))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_block(category: u8) -> Vec<u8> {
+        vec![category, 0x00, 0x03]
+    }
+
+    #[test]
+    fn test_frame_blocks_empty_data() {
+        assert_eq!(frame_blocks(&[]).unwrap(), (Vec::new(), 0));
+    }
+
+    #[test]
+    fn test_frame_blocks_finds_each_block() {
+        let mut data = test_block(48);
+        data.extend(test_block(62));
+        data.extend(test_block(21));
+
+        let (spans, consumed) = frame_blocks(&data).unwrap();
+        assert_eq!(
+            spans,
+            vec![
+                BlockSpan { category: 48, start: 0, len: 3 },
+                BlockSpan { category: 62, start: 3, len: 3 },
+                BlockSpan { category: 21, start: 6, len: 3 },
+            ]
+        );
+        assert_eq!(consumed, 9);
+    }
+
+    #[test]
+    fn test_frame_blocks_rejects_undersized_length() {
+        let data = [0x30, 0x00, 0x02];
+        let err = frame_blocks(&data).unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_frame_blocks_stops_cleanly_on_partial_trailing_header() {
+        let mut data = test_block(48);
+        data.extend([0x30, 0x00]); // too short even for a header
+        let (spans, consumed) = frame_blocks(&data).unwrap();
+        assert_eq!(spans, vec![BlockSpan { category: 48, start: 0, len: 3 }]);
+        assert_eq!(consumed, 3);
+    }
+
+    #[test]
+    fn test_frame_blocks_stops_cleanly_on_partial_trailing_payload() {
+        let mut data = test_block(48);
+        data.extend([0x3E, 0x00, 0x0A, 0x01, 0x02]); // declares 10 bytes, only 5 present
+        let (spans, consumed) = frame_blocks(&data).unwrap();
+        assert_eq!(spans, vec![BlockSpan { category: 48, start: 0, len: 3 }]);
+        assert_eq!(consumed, 3);
+    }
+
+    #[test]
+    fn test_frame_blocks_resumes_from_returned_offset() {
+        let mut data = test_block(48);
+        let trailing = [0x3E, 0x00, 0x0A, 0x01, 0x02];
+        data.extend(trailing);
+
+        let (_, consumed) = frame_blocks(&data).unwrap();
+        let mut rest = data[consumed..].to_vec();
+        rest.extend([0x01, 0x02, 0x03, 0x04, 0x05]); // completes the declared 10-byte block
+        let (spans, consumed2) = frame_blocks(&rest).unwrap();
+        assert_eq!(spans, vec![BlockSpan { category: 0x3E, start: 0, len: 10 }]);
+        assert_eq!(consumed2, 10);
+    }
+}
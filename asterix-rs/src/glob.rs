@@ -0,0 +1,218 @@
+//! Minimal glob expansion for selecting category definition files.
+//!
+//! [`crate::init_config_dir_glob`] lets a deployment point the loader at a
+//! curated subset of a config tree (`asterix_cat0*.xml`, `**/asterix_cat062*.xml`)
+//! instead of enumerating every file by hand. This is a small, dependency-free
+//! matcher rather than a general-purpose glob crate: it supports the handful
+//! of wildcards config patterns actually need (`*`, `?`, and `**` for
+//! recursive directory matching) and nothing more.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{AsterixError, Result};
+
+/// Expand `pattern` relative to `root`, returning every matching path in
+/// lexicographic order.
+///
+/// `pattern` is always split on `/` regardless of host OS (matching how
+/// config patterns are typically written and copied between platforms). Each
+/// component may be a literal name, contain `*`/`?` wildcards, or be exactly
+/// `**`, which matches the current directory and any number of nested
+/// subdirectories. A component without wildcards is joined on as written,
+/// without checking that the resulting path exists.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::IOError`] if an entry of a directory being listed
+/// can't be read. A directory that can't be opened at all (including a path
+/// that isn't a directory) contributes no matches rather than an error.
+pub(crate) fn expand(root: &Path, pattern: &str) -> Result<Vec<PathBuf>> {
+    let components: Vec<&str> = pattern.split('/').filter(|c| !c.is_empty()).collect();
+
+    let mut current = vec![root.to_path_buf()];
+    for component in &components {
+        current = expand_component(current, component)?;
+    }
+
+    current.sort();
+    Ok(current)
+}
+
+fn expand_component(bases: Vec<PathBuf>, component: &str) -> Result<Vec<PathBuf>> {
+    if component == "**" {
+        let mut results = Vec::new();
+        for base in bases {
+            collect_recursive_dirs(&base, &mut results)?;
+        }
+        Ok(results)
+    } else if component.contains('*') || component.contains('?') {
+        let mut results = Vec::new();
+        for base in bases {
+            let Ok(entries) = fs::read_dir(&base) else {
+                continue;
+            };
+            for entry in entries {
+                let entry = entry.map_err(|e| {
+                    AsterixError::IOError(format!("Failed to read directory entry: {e}"))
+                })?;
+                let name = entry.file_name();
+                if let Some(name) = name.to_str() {
+                    if wildcard_match(component, name) {
+                        results.push(base.join(name));
+                    }
+                }
+            }
+        }
+        Ok(results)
+    } else {
+        Ok(bases.into_iter().map(|base| base.join(component)).collect())
+    }
+}
+
+/// Push `base` and every directory nested under it (at any depth) onto `out`.
+fn collect_recursive_dirs(base: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    out.push(base.to_path_buf());
+
+    let Ok(entries) = fs::read_dir(base) else {
+        return Ok(());
+    };
+    for entry in entries {
+        let entry =
+            entry.map_err(|e| AsterixError::IOError(format!("Failed to read directory entry: {e}")))?;
+        let path = entry.path();
+        if path.is_dir() {
+            collect_recursive_dirs(&path, out)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any run of characters
+/// (including none) and `?` matches exactly one character. Operates on a
+/// single path component; neither wildcard crosses a `/`.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // Standard iterative wildcard matcher: track the most recent `*` seen
+    // (`star_p`) and how much of `name` had been consumed at that point
+    // (`star_n`), so a mismatch can backtrack by letting the `*` eat one more
+    // character of `name` instead of failing outright.
+    let (mut p, mut n) = (0, 0);
+    let mut star_p: Option<usize> = None;
+    let mut star_n = 0;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star_p = Some(p);
+            star_n = n;
+            p += 1;
+        } else if let Some(sp) = star_p {
+            p = sp + 1;
+            star_n += 1;
+            n = star_n;
+        } else {
+            return false;
+        }
+    }
+
+    while p < pattern.len() && pattern[p] == '*' {
+        p += 1;
+    }
+
+    p == pattern.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("asterix_glob_test_{name}_{}", std::process::id()))
+    }
+
+    #[test]
+    fn test_wildcard_match_literal() {
+        assert!(wildcard_match("asterix_bds.xml", "asterix_bds.xml"));
+        assert!(!wildcard_match("asterix_bds.xml", "asterix_bds.json"));
+    }
+
+    #[test]
+    fn test_wildcard_match_star() {
+        assert!(wildcard_match("asterix_cat0*.xml", "asterix_cat048.xml"));
+        assert!(wildcard_match("asterix_cat0*.xml", "asterix_cat0.xml"));
+        assert!(!wildcard_match("asterix_cat0*.xml", "asterix_cat148.xml"));
+    }
+
+    #[test]
+    fn test_wildcard_match_question_mark() {
+        assert!(wildcard_match("asterix_cat0??.xml", "asterix_cat048.xml"));
+        assert!(!wildcard_match("asterix_cat0??.xml", "asterix_cat4.xml"));
+    }
+
+    #[test]
+    fn test_expand_matches_single_component_wildcard() {
+        let root = unique_temp_dir("single_component");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("asterix_cat048.xml"), b"").unwrap();
+        fs::write(root.join("asterix_cat062.xml"), b"").unwrap();
+        fs::write(root.join("readme.txt"), b"").unwrap();
+
+        let mut matches = expand(&root, "asterix_cat0*.xml").unwrap();
+        matches.sort();
+        assert_eq!(
+            matches,
+            vec![root.join("asterix_cat048.xml"), root.join("asterix_cat062.xml")]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_expand_recursive_double_star() {
+        let root = unique_temp_dir("double_star");
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("asterix_cat062.xml"), b"").unwrap();
+        fs::write(root.join("sub/asterix_cat062_override.xml"), b"").unwrap();
+
+        let mut matches = expand(&root, "**/asterix_cat062*.xml").unwrap();
+        matches.sort();
+        assert_eq!(
+            matches,
+            vec![
+                root.join("asterix_cat062.xml"),
+                root.join("sub/asterix_cat062_override.xml"),
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_expand_no_matches_returns_empty() {
+        let root = unique_temp_dir("no_matches");
+        fs::create_dir_all(&root).unwrap();
+
+        let matches = expand(&root, "asterix_cat9*.xml").unwrap();
+        assert!(matches.is_empty());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_expand_literal_pattern_without_wildcards() {
+        let root = unique_temp_dir("literal_pattern");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("asterix_bds.xml"), b"").unwrap();
+
+        let matches = expand(&root, "asterix_bds.xml").unwrap();
+        assert_eq!(matches, vec![root.join("asterix_bds.xml")]);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,422 @@
+//! Hex string encoding/decoding shared by ASTERIX encoding and the transport modules
+//!
+//! Several code paths accept or produce ASTERIX frames as hex text rather than raw
+//! bytes: [`crate::encode`] re-emits captured [`crate::types::AsterixRecord::hex_data`],
+//! and the `transport` backends decode operator-pasted hex dumps of CAT048/CAT062
+//! records before publishing them. [`from_hex`] and [`to_hex`] are the single
+//! implementation both sides share, so a malformed dump returns a proper
+//! [`HexError`] instead of panicking on an out-of-bounds slice.
+
+use crate::error::AsterixError;
+use std::fmt;
+
+/// Errors from [`from_hex`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexError {
+    /// A byte that is neither a hex digit nor tolerated whitespace appeared in the input
+    InvalidChar(u8),
+    /// The input had a non-whitespace hex digit count that isn't a multiple of two
+    OddLength,
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexError::InvalidChar(byte) => {
+                write!(f, "invalid hex character: {byte:#04x}")
+            }
+            HexError::OddLength => write!(f, "odd number of hex digits"),
+        }
+    }
+}
+
+impl std::error::Error for HexError {}
+
+impl From<HexError> for AsterixError {
+    fn from(err: HexError) -> Self {
+        AsterixError::InvalidData(err.to_string())
+    }
+}
+
+/// Decode a hex string into bytes, tolerating interior whitespace
+///
+/// Whitespace (` `, `\t`, `\r`, `\n`) is skipped wherever it appears, so both
+/// `"3000AB"` and `"30 00\nAB"` decode the same way. Any other non-hex-digit
+/// byte is rejected with [`HexError::InvalidChar`], and an odd number of hex
+/// digits is rejected with [`HexError::OddLength`] rather than panicking on
+/// an out-of-bounds slice.
+pub fn from_hex(input: &str) -> Result<Vec<u8>, HexError> {
+    let mut bytes = Vec::with_capacity(input.len() / 2);
+    let mut acc: u8 = 0;
+    let mut nibbles = 0u8;
+
+    for &byte in input.as_bytes() {
+        let nibble = match byte {
+            b'0'..=b'9' => byte - b'0',
+            b'a'..=b'f' => byte - b'a' + 10,
+            b'A'..=b'F' => byte - b'A' + 10,
+            b' ' | b'\t' | b'\r' | b'\n' => continue,
+            other => return Err(HexError::InvalidChar(other)),
+        };
+
+        acc = (acc << 4) | nibble;
+        nibbles += 1;
+        if nibbles == 2 {
+            bytes.push(acc);
+            acc = 0;
+            nibbles = 0;
+        }
+    }
+
+    if nibbles != 0 {
+        return Err(HexError::OddLength);
+    }
+
+    Ok(bytes)
+}
+
+/// Decode a hex string like [`from_hex`], but reject any deviation from a
+/// bare, prefix-free, whitespace-free, even-length hex string outright
+/// instead of skipping over it.
+///
+/// Used by input modes (e.g. the DDS transport's strict `HexInputMode`)
+/// where malformed operator input should fail fast rather than be silently
+/// cleaned up.
+///
+/// # Errors
+///
+/// Returns [`HexError::OddLength`] if `input` has an odd length, or
+/// [`HexError::InvalidChar`] for the first byte that isn't a hex digit.
+pub fn from_hex_strict(input: &str) -> Result<Vec<u8>, HexError> {
+    if !input.len().is_multiple_of(2) {
+        return Err(HexError::OddLength);
+    }
+
+    for &byte in input.as_bytes() {
+        if !byte.is_ascii_hexdigit() {
+            return Err(HexError::InvalidChar(byte));
+        }
+    }
+
+    from_hex(input)
+}
+
+/// Encode bytes as a lowercase hex string with no separators
+pub fn to_hex(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        write!(out, "{byte:02x}").expect("writing to a String cannot fail");
+    }
+    out
+}
+
+/// Sentinel nibble value for a byte that isn't a hex digit
+#[cfg(feature = "simd-hex")]
+const INVALID_NIBBLE: u8 = 0xFF;
+
+/// 256-entry ASCII byte -> nibble lookup table used by [`from_hex_fast`]
+#[cfg(feature = "simd-hex")]
+const NIBBLE_TABLE: [u8; 256] = {
+    let mut table = [INVALID_NIBBLE; 256];
+    let mut byte = 0usize;
+    while byte < 256 {
+        table[byte] = match byte as u8 {
+            b'0'..=b'9' => byte as u8 - b'0',
+            b'a'..=b'f' => byte as u8 - b'a' + 10,
+            b'A'..=b'F' => byte as u8 - b'A' + 10,
+            _ => INVALID_NIBBLE,
+        };
+        byte += 1;
+    }
+    table
+};
+
+/// Decode whitespace-free hex bytes using a 256-entry nibble lookup table
+///
+/// Requires the `simd-hex` feature. Unlike [`from_hex`], this does not
+/// tolerate embedded whitespace — it assumes the caller already knows
+/// `input` is contiguous hex digits (e.g. the hex text captured straight
+/// from the C++ decoder in [`crate::parser`], which never embeds
+/// whitespace). Replacing the `from_str_radix` call-per-byte with a single
+/// table lookup per nibble lets the compiler auto-vectorize the loop, which
+/// matters for ASTERIX feeds arriving at thousands of records per second.
+///
+/// # Errors
+///
+/// Returns [`HexError::OddLength`] if `input` has an odd number of bytes, or
+/// [`HexError::InvalidChar`] for the first byte that isn't a hex digit.
+#[cfg(feature = "simd-hex")]
+pub fn from_hex_fast(input: &[u8]) -> Result<Vec<u8>, HexError> {
+    if !input.len().is_multiple_of(2) {
+        return Err(HexError::OddLength);
+    }
+
+    let mut out = Vec::with_capacity(input.len() / 2);
+    for pair in input.chunks_exact(2) {
+        let hi = NIBBLE_TABLE[pair[0] as usize];
+        let lo = NIBBLE_TABLE[pair[1] as usize];
+        if hi == INVALID_NIBBLE {
+            return Err(HexError::InvalidChar(pair[0]));
+        }
+        if lo == INVALID_NIBBLE {
+            return Err(HexError::InvalidChar(pair[1]));
+        }
+        out.push((hi << 4) | lo);
+    }
+
+    Ok(out)
+}
+
+/// Configuration for [`hexdump`]'s output layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexDumpConfig {
+    /// Number of bytes rendered per line (default: 16)
+    pub width: usize,
+    /// Number of bytes per space-separated group within a line (default: 4)
+    pub group_size: usize,
+    /// Whether to render the trailing `|ascii|` panel (default: true)
+    pub show_ascii: bool,
+    /// Whether to render the leading hex offset column (default: true)
+    pub show_offset: bool,
+}
+
+impl Default for HexDumpConfig {
+    fn default() -> Self {
+        Self {
+            width: 16,
+            group_size: 4,
+            show_ascii: true,
+            show_offset: true,
+        }
+    }
+}
+
+/// Render `data` as a canonical hex dump: an optional hex offset column, the
+/// bytes themselves grouped per [`HexDumpConfig::group_size`], and an
+/// optional ASCII panel where printable bytes (`0x20`-`0x7E`) show as
+/// themselves and everything else as `.`
+///
+/// Used to make decoded CAT048/CAT062 target reports and other raw ASTERIX
+/// payloads readable in logs and test failures, where the plain `{:?}` debug
+/// output of a `Vec<u8>` becomes unreadable beyond a handful of bytes.
+///
+/// # Example
+///
+/// ```
+/// use asterix::hex::{hexdump, HexDumpConfig};
+///
+/// let output = hexdump(b"Hello, ASTERIX!", HexDumpConfig::default());
+/// assert!(output.contains("48 65 6c 6c"));
+/// assert!(output.contains("|Hello"));
+/// ```
+pub fn hexdump(data: &[u8], config: HexDumpConfig) -> String {
+    let width = config.width.max(1);
+    let group_size = config.group_size.max(1);
+    let mut out = String::new();
+
+    for (row, chunk) in data.chunks(width).enumerate() {
+        if config.show_offset {
+            out.push_str(&format!("{:08x}  ", row * width));
+        }
+
+        for (i, byte) in chunk.iter().enumerate() {
+            out.push_str(&format!("{byte:02x} "));
+            if (i + 1) % group_size == 0 {
+                out.push(' ');
+            }
+        }
+
+        if config.show_ascii {
+            for i in chunk.len()..width {
+                out.push_str("   ");
+                if (i + 1) % group_size == 0 {
+                    out.push(' ');
+                }
+            }
+
+            out.push('|');
+            for &byte in chunk {
+                let printable = (0x20..=0x7e).contains(&byte);
+                out.push(if printable { byte as char } else { '.' });
+            }
+            out.push('|');
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_hex_basic() {
+        assert_eq!(from_hex("30001E").unwrap(), vec![0x30, 0x00, 0x1E]);
+    }
+
+    #[test]
+    fn test_from_hex_lowercase() {
+        assert_eq!(from_hex("abcdef").unwrap(), vec![0xAB, 0xCD, 0xEF]);
+    }
+
+    #[test]
+    fn test_from_hex_mixed_case() {
+        assert_eq!(from_hex("AbCdEf").unwrap(), vec![0xAB, 0xCD, 0xEF]);
+    }
+
+    #[test]
+    fn test_from_hex_with_interior_whitespace() {
+        assert_eq!(from_hex("30 00\n1E").unwrap(), vec![0x30, 0x00, 0x1E]);
+    }
+
+    #[test]
+    fn test_from_hex_empty() {
+        assert_eq!(from_hex("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_from_hex_odd_length() {
+        assert_eq!(from_hex("123"), Err(HexError::OddLength));
+    }
+
+    #[test]
+    fn test_from_hex_odd_length_does_not_panic() {
+        // Regression test: the old step_by(2) slicing implementation panicked
+        // on an out-of-bounds slice for odd-length input instead of erroring.
+        assert!(from_hex("300").is_err());
+    }
+
+    #[test]
+    fn test_from_hex_invalid_char() {
+        assert_eq!(from_hex("GHIJ"), Err(HexError::InvalidChar(b'G')));
+    }
+
+    #[test]
+    fn test_to_hex_roundtrip() {
+        let bytes = vec![0x30, 0x00, 0x1E, 0xAB];
+        let hex = to_hex(&bytes);
+        assert_eq!(hex, "30001eab");
+        assert_eq!(from_hex(&hex).unwrap(), bytes);
+    }
+
+    #[test]
+    fn test_to_hex_empty() {
+        assert_eq!(to_hex(&[]), "");
+    }
+
+    #[test]
+    fn test_hex_error_display() {
+        assert_eq!(
+            HexError::InvalidChar(b'G').to_string(),
+            "invalid hex character: 0x47"
+        );
+        assert_eq!(HexError::OddLength.to_string(), "odd number of hex digits");
+    }
+
+    #[test]
+    fn test_hexdump_default_single_line() {
+        let data = [0x30, 0x00, 0x1E, 0x30, 0x48];
+        let dump = hexdump(&data, HexDumpConfig::default());
+        assert_eq!(dump.lines().count(), 1);
+        assert!(dump.starts_with("00000000  "));
+        assert!(dump.contains("30 00 1e 30  48"));
+        assert!(dump.contains("|0..0H|"));
+    }
+
+    #[test]
+    fn test_hexdump_wraps_at_width() {
+        let data: Vec<u8> = (0..20).collect();
+        let dump = hexdump(&data, HexDumpConfig::default());
+        assert_eq!(dump.lines().count(), 2);
+        assert!(dump.contains("00000010  "));
+    }
+
+    #[test]
+    fn test_hexdump_without_offset_or_ascii() {
+        let data = [0xAB, 0xCD];
+        let config = HexDumpConfig {
+            show_offset: false,
+            show_ascii: false,
+            ..Default::default()
+        };
+        let dump = hexdump(&data, config);
+        assert!(!dump.contains("00000000"));
+        assert!(!dump.contains('|'));
+        assert!(dump.contains("ab cd"));
+    }
+
+    #[test]
+    fn test_hexdump_non_printable_bytes_show_as_dots() {
+        let data = [0x00, 0x01, b'A', 0xFF];
+        let dump = hexdump(&data, HexDumpConfig::default());
+        assert!(dump.contains("|..A.|"));
+    }
+
+    #[test]
+    fn test_from_hex_strict_accepts_bare_hex() {
+        assert_eq!(from_hex_strict("30001E").unwrap(), vec![0x30, 0x00, 0x1E]);
+    }
+
+    #[test]
+    fn test_from_hex_strict_rejects_whitespace() {
+        assert_eq!(
+            from_hex_strict("30 00 1E"),
+            Err(HexError::InvalidChar(b' '))
+        );
+    }
+
+    #[test]
+    fn test_from_hex_strict_rejects_prefix() {
+        assert_eq!(from_hex_strict("0x30001E"), Err(HexError::InvalidChar(b'x')));
+    }
+
+    #[test]
+    fn test_from_hex_strict_rejects_odd_length() {
+        assert_eq!(from_hex_strict("300"), Err(HexError::OddLength));
+    }
+
+    #[cfg(feature = "simd-hex")]
+    #[test]
+    fn test_from_hex_fast_matches_from_hex() {
+        assert_eq!(
+            from_hex_fast(b"30001E").unwrap(),
+            from_hex("30001E").unwrap()
+        );
+        assert_eq!(
+            from_hex_fast(b"abcdef").unwrap(),
+            from_hex("abcdef").unwrap()
+        );
+    }
+
+    #[cfg(feature = "simd-hex")]
+    #[test]
+    fn test_from_hex_fast_odd_length() {
+        assert_eq!(from_hex_fast(b"123"), Err(HexError::OddLength));
+    }
+
+    #[cfg(feature = "simd-hex")]
+    #[test]
+    fn test_from_hex_fast_invalid_char() {
+        assert_eq!(from_hex_fast(b"GHIJ"), Err(HexError::InvalidChar(b'G')));
+    }
+
+    #[cfg(feature = "simd-hex")]
+    #[test]
+    fn test_from_hex_fast_empty() {
+        assert_eq!(from_hex_fast(b"").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_hexdump_config_default() {
+        let config = HexDumpConfig::default();
+        assert_eq!(config.width, 16);
+        assert_eq!(config.group_size, 4);
+        assert!(config.show_ascii);
+        assert!(config.show_offset);
+    }
+}
@@ -0,0 +1,328 @@
+//! Lossless [`serde_json::Value`] bridge for decoded records
+//!
+//! [`crate::json_export`] and [`crate::write_ndjson`](crate::write_ndjson) go
+//! straight from [`AsterixRecord`] to a JSON *string*. Callers that want to
+//! pointer into a record, merge it with another JSON document, or feed it to
+//! a templating engine need a [`serde_json::Value`] instead, without paying
+//! for a serialize-then-reparse round trip. [`AsterixRecord::to_json_value`]
+//! and [`AsterixRecord::from_json_value`] provide that, with
+//! [`JsonEncodeOptions`] controlling the one encoding decision a `Value`
+//! can't express on its own: how [`ParsedValue::Bytes`] is rendered.
+
+use serde_json::{Map, Value};
+
+use crate::hex;
+use crate::types::{AsterixRecord, DataItem, ParsedValue};
+
+/// How [`ParsedValue::Bytes`] is rendered by [`AsterixRecord::to_json_value`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ByteEncoding {
+    /// A JSON array of byte values (0-255) — lossless, matches `serde_json`'s
+    /// default derived `Serialize` for `Vec<u8>`
+    #[default]
+    Array,
+    /// An uppercase hex string, e.g. `"0A1F"`
+    Hex,
+    /// Standard (RFC 4648, padded) base64, e.g. `"Ch8="`
+    Base64,
+}
+
+/// How [`ParsedValue::Nested`] keys are ordered in the emitted JSON object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NestedKeyOrder {
+    /// Alphabetical, i.e. `BTreeMap` iteration order
+    #[default]
+    Sorted,
+    /// Insertion order.
+    ///
+    /// `ParsedValue::Nested` is backed by a `BTreeMap`, which never records
+    /// insertion order in the first place — by the time a nested map reaches
+    /// this function, that information is already gone. This variant is
+    /// accepted for API stability but currently behaves identically to
+    /// [`NestedKeyOrder::Sorted`].
+    Insertion,
+}
+
+/// Options for [`AsterixRecord::to_json_value`]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonEncodeOptions {
+    /// How to render [`ParsedValue::Bytes`]
+    pub byte_encoding: ByteEncoding,
+    /// How to order [`ParsedValue::Nested`] keys
+    pub nested_key_order: NestedKeyOrder,
+}
+
+impl AsterixRecord {
+    /// Render this record as a `serde_json::Value`, with `opts` controlling
+    /// how byte fields are encoded.
+    ///
+    /// Unlike [`crate::write_ndjson`], this returns the tree directly rather
+    /// than a serialized string, so callers can pointer into it, merge it
+    /// with another `Value`, or feed it to a templating engine.
+    pub fn to_json_value(&self, opts: &JsonEncodeOptions) -> Value {
+        let mut items = Map::new();
+        for (item_id, item) in &self.items {
+            items.insert(item_id.clone(), item.to_json_value(opts));
+        }
+
+        serde_json::json!({
+            "category": self.category,
+            "length": self.length,
+            "timestamp_ms": self.timestamp_ms,
+            "crc": self.crc,
+            "items": items,
+        })
+    }
+
+    /// Reconstruct a record from a `Value` produced by [`Self::to_json_value`].
+    ///
+    /// Returns `None` if `value` isn't a JSON object shaped like one, or if
+    /// any field value in `items` is JSON `null`. Byte fields are not decoded
+    /// back: a hex or base64 string comes back as [`ParsedValue::String`], and
+    /// a byte array as a [`ParsedValue::Array`] of integers.
+    pub fn from_json_value(value: &Value) -> Option<Self> {
+        let obj = value.as_object()?;
+
+        let mut record = AsterixRecord {
+            category: obj.get("category")?.as_u64()? as u8,
+            length: obj.get("length")?.as_u64()? as u32,
+            timestamp_ms: obj.get("timestamp_ms").and_then(Value::as_u64).unwrap_or(0),
+            crc: obj.get("crc").and_then(Value::as_u64).unwrap_or(0) as u32,
+            ..Default::default()
+        };
+
+        if let Some(items) = obj.get("items").and_then(Value::as_object) {
+            for (item_id, item_value) in items {
+                record
+                    .items
+                    .insert(item_id.clone(), DataItem::from_json_value(item_value)?);
+            }
+        }
+
+        Some(record)
+    }
+}
+
+impl DataItem {
+    fn to_json_value(&self, opts: &JsonEncodeOptions) -> Value {
+        let mut fields = Map::new();
+        for (name, value) in &self.fields {
+            fields.insert(name.clone(), value.to_json_value(opts));
+        }
+        Value::Object(fields)
+    }
+
+    fn from_json_value(value: &Value) -> Option<Self> {
+        let obj = value.as_object()?;
+        let mut item = DataItem::new(None);
+        for (name, field_value) in obj {
+            item.fields
+                .insert(name.clone(), ParsedValue::from_json_value(field_value)?);
+        }
+        Some(item)
+    }
+}
+
+impl ParsedValue {
+    fn to_json_value(&self, opts: &JsonEncodeOptions) -> Value {
+        match self {
+            ParsedValue::Integer(v) => serde_json::json!(v),
+            ParsedValue::Unsigned(v) => serde_json::json!(v),
+            ParsedValue::Float(v) => serde_json::json!(v),
+            ParsedValue::Decimal { raw, scale, unit } => serde_json::json!({
+                "raw": raw,
+                "scale": scale,
+                "unit": unit,
+            }),
+            ParsedValue::String(v) => serde_json::json!(v),
+            ParsedValue::Boolean(v) => serde_json::json!(v),
+            ParsedValue::Bytes(v) => match opts.byte_encoding {
+                ByteEncoding::Array => serde_json::json!(v),
+                ByteEncoding::Hex => serde_json::json!(hex::to_hex(v).to_uppercase()),
+                ByteEncoding::Base64 => serde_json::json!(base64_encode(v)),
+            },
+            ParsedValue::Nested(nested) => {
+                // `nested_key_order` has nothing to act on: `BTreeMap`
+                // iteration is always sorted, in both modes.
+                let _ = opts.nested_key_order;
+                let mut map = Map::new();
+                for (key, nested_value) in nested {
+                    map.insert(key.clone(), nested_value.to_json_value(opts));
+                }
+                Value::Object(map)
+            }
+            ParsedValue::Array(items) => {
+                Value::Array(items.iter().map(|item| item.to_json_value(opts)).collect())
+            }
+            ParsedValue::Raw(text) => serde_json::json!(text),
+            // Emitted as a JSON string, not a bare number: serde_json's
+            // `Value::Number` can't hold more precision than `f64` anyway,
+            // so encoding as a number here would reintroduce the precision
+            // loss `Number` exists to avoid. `from_json_value` below never
+            // reconstructs `Number` from this output for the same reason
+            // `Raw` isn't reconstructed from a plain JSON string.
+            ParsedValue::Number(text) => serde_json::json!(text),
+        }
+    }
+
+    fn from_json_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Number(n) => {
+                if let Some(i) = n.as_i64() {
+                    Some(ParsedValue::Integer(i))
+                } else if let Some(u) = n.as_u64() {
+                    Some(ParsedValue::Unsigned(u))
+                } else {
+                    n.as_f64().map(ParsedValue::Float)
+                }
+            }
+            Value::String(s) => Some(ParsedValue::String(s.clone())),
+            Value::Bool(b) => Some(ParsedValue::Boolean(*b)),
+            Value::Array(items) => items
+                .iter()
+                .map(ParsedValue::from_json_value)
+                .collect::<Option<Vec<_>>>()
+                .map(ParsedValue::Array),
+            Value::Object(obj) => {
+                let mut nested = std::collections::BTreeMap::new();
+                for (key, val) in obj {
+                    nested.insert(key.clone(), Box::new(ParsedValue::from_json_value(val)?));
+                }
+                Some(ParsedValue::Nested(nested))
+            }
+            Value::Null => None,
+        }
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Hand-rolled standard (RFC 4648, padded) base64 encoder.
+///
+/// No crate in this workspace already depends on `base64`, and pulling one
+/// in for a single encode function isn't worth a new dependency — the
+/// algorithm is a few lines of bit shuffling.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(
+            BASE64_ALPHABET[(((b0 & 0x03) << 4) | (b1.unwrap_or(0) >> 4)) as usize] as char,
+        );
+        out.push(match b1 {
+            Some(b1) => {
+                BASE64_ALPHABET[(((b1 & 0x0f) << 2) | (b2.unwrap_or(0) >> 6)) as usize] as char
+            }
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => BASE64_ALPHABET[(b2 & 0x3f) as usize] as char,
+            None => '=',
+        });
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_base64_encode_matches_known_vectors() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foob"), "Zm9vYg==");
+        assert_eq!(base64_encode(b"fooba"), "Zm9vYmE=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    fn sample_record() -> AsterixRecord {
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(25));
+        fields.insert(
+            "RAW".to_string(),
+            ParsedValue::Bytes(vec![0x0a, 0x1f]),
+        );
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_to_json_value_default_bytes_as_array() {
+        let record = sample_record();
+        let value = record.to_json_value(&JsonEncodeOptions::default());
+        assert_eq!(value["items"]["I048/010"]["RAW"], serde_json::json!([10, 31]));
+    }
+
+    #[test]
+    fn test_to_json_value_hex_bytes() {
+        let record = sample_record();
+        let opts = JsonEncodeOptions {
+            byte_encoding: ByteEncoding::Hex,
+            ..Default::default()
+        };
+        let value = record.to_json_value(&opts);
+        assert_eq!(value["items"]["I048/010"]["RAW"], "0A1F");
+    }
+
+    #[test]
+    fn test_to_json_value_base64_bytes() {
+        let record = sample_record();
+        let opts = JsonEncodeOptions {
+            byte_encoding: ByteEncoding::Base64,
+            ..Default::default()
+        };
+        let value = record.to_json_value(&opts);
+        assert_eq!(value["items"]["I048/010"]["RAW"], base64_encode(&[0x0a, 0x1f]));
+    }
+
+    #[test]
+    fn test_to_json_value_nested_and_array_recurse() {
+        let mut nested = BTreeMap::new();
+        nested.insert("X".to_string(), Box::new(ParsedValue::Integer(1)));
+        let value = ParsedValue::Array(vec![ParsedValue::Nested(nested)]);
+
+        let json = value.to_json_value(&JsonEncodeOptions::default());
+        assert_eq!(json, serde_json::json!([{"X": 1}]));
+    }
+
+    #[test]
+    fn test_roundtrip_via_from_json_value() {
+        let record = sample_record();
+        let value = record.to_json_value(&JsonEncodeOptions::default());
+        let back = AsterixRecord::from_json_value(&value).unwrap();
+
+        assert_eq!(back.category, 48);
+        assert_eq!(back.get_item("I048/010").unwrap().fields["SAC"].as_i64(), Some(25));
+        assert_eq!(
+            back.get_item("I048/010").unwrap().fields["RAW"].as_bytes(),
+            Some([0x0a, 0x1f].as_slice())
+        );
+    }
+
+    #[test]
+    fn test_from_json_value_rejects_non_object() {
+        assert!(AsterixRecord::from_json_value(&serde_json::json!("nope")).is_none());
+    }
+}
@@ -0,0 +1,211 @@
+//! Streaming JSON export alongside the in-memory `json_export` example
+//!
+//! The `json_export` example builds one giant pretty-printed array with
+//! `serde_json::to_string_pretty(&records)`, which means the whole capture's
+//! records have to be resident at once — exactly what the streaming parser
+//! (see [`crate::parse_with_offset`]) is meant to avoid. [`JsonExporter`]
+//! writes incrementally instead: feed it one batch at a time (e.g. each
+//! [`crate::parse_with_offset`] call's `records`) via
+//! [`write_batch`](JsonExporter::write_batch), and in [`JsonFormat::Array`]
+//! or [`JsonFormat::Lines`] mode no more than one batch's worth of
+//! serialized text is ever held in memory. [`JsonFormat::Pretty`] is kept
+//! for compatibility with the existing example's output and still buffers
+//! every record until [`finish`](JsonExporter::finish), since a
+//! pretty-printed array needs the whole collection to lay out consistently.
+
+use std::io::Write;
+
+use crate::error::{AsterixError, Result};
+use crate::types::AsterixRecord;
+
+/// Which JSON shape [`JsonExporter`] writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JsonFormat {
+    /// One pretty-printed JSON array (the original `json_export` example
+    /// behavior). Buffers every record until `finish`.
+    #[default]
+    Pretty,
+    /// A single compact JSON array, written incrementally: a leading `[`
+    /// on creation, each record's compact JSON (comma-separated) as batches
+    /// arrive, and a trailing `]` on `finish`.
+    Array,
+    /// Newline-delimited JSON (NDJSON): one compact JSON object per record
+    /// per line, written as each batch arrives (the writer itself is only
+    /// flushed by `finish`). Consumable by `jq -c` or a
+    /// log-ingestion pipeline without ever buffering more than one batch.
+    Lines,
+}
+
+/// Incrementally writes decoded records as JSON in one of [`JsonFormat`]'s shapes
+///
+/// Call [`write_batch`](Self::write_batch) once per batch and
+/// [`finish`](Self::finish) exactly once at the end.
+pub struct JsonExporter<W: Write> {
+    writer: W,
+    format: JsonFormat,
+    wrote_any: bool,
+    /// Only populated in [`JsonFormat::Pretty`] mode, which needs every
+    /// record at once to pretty-print a single coherent array.
+    pending: Vec<AsterixRecord>,
+}
+
+impl<W: Write> JsonExporter<W> {
+    /// Start writing `format`-shaped JSON to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if the format's opening punctuation
+    /// (e.g. [`JsonFormat::Array`]'s leading `[`) can't be written.
+    pub fn new(mut writer: W, format: JsonFormat) -> Result<Self> {
+        if format == JsonFormat::Array {
+            writer.write_all(b"[").map_err(io_err)?;
+        }
+        Ok(Self {
+            writer,
+            format,
+            wrote_any: false,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Write one batch of records.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if serialization or writing fails.
+    pub fn write_batch(&mut self, records: &[AsterixRecord]) -> Result<()> {
+        match self.format {
+            JsonFormat::Pretty => self.pending.extend_from_slice(records),
+            JsonFormat::Array => {
+                for record in records {
+                    if self.wrote_any {
+                        self.writer.write_all(b",").map_err(io_err)?;
+                    }
+                    let json = serde_json::to_string(record).map_err(json_err)?;
+                    self.writer.write_all(json.as_bytes()).map_err(io_err)?;
+                    self.wrote_any = true;
+                }
+            }
+            JsonFormat::Lines => {
+                for record in records {
+                    let json = serde_json::to_string(record).map_err(json_err)?;
+                    self.writer.write_all(json.as_bytes()).map_err(io_err)?;
+                    self.writer.write_all(b"\n").map_err(io_err)?;
+                    self.wrote_any = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Finish writing: pretty-print the buffered records ([`JsonFormat::Pretty`]),
+    /// close the array ([`JsonFormat::Array`]), or just flush ([`JsonFormat::Lines`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if serialization, writing, or
+    /// flushing fails.
+    pub fn finish(mut self) -> Result<()> {
+        match self.format {
+            JsonFormat::Pretty => {
+                let json = serde_json::to_string_pretty(&self.pending).map_err(json_err)?;
+                self.writer.write_all(json.as_bytes()).map_err(io_err)?;
+            }
+            JsonFormat::Array => {
+                self.writer.write_all(b"]").map_err(io_err)?;
+            }
+            JsonFormat::Lines => {}
+        }
+        self.writer.flush().map_err(io_err)
+    }
+}
+
+fn io_err(err: std::io::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+fn json_err(err: serde_json::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_record(category: u8) -> AsterixRecord {
+        AsterixRecord {
+            category,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_lines_format_writes_one_object_per_line() {
+        let mut buf = Vec::new();
+        let mut exporter = JsonExporter::new(&mut buf, JsonFormat::Lines).unwrap();
+        exporter
+            .write_batch(&[sample_record(48), sample_record(62)])
+            .unwrap();
+        exporter.finish().unwrap();
+
+        let text = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\"category\":48"));
+        assert!(lines[1].contains("\"category\":62"));
+    }
+
+    #[test]
+    fn test_lines_format_across_multiple_batches() {
+        let mut buf = Vec::new();
+        let mut exporter = JsonExporter::new(&mut buf, JsonFormat::Lines).unwrap();
+        exporter.write_batch(&[sample_record(48)]).unwrap();
+        exporter.write_batch(&[sample_record(62)]).unwrap();
+        exporter.finish().unwrap();
+
+        let text = String::from_utf8(buf).unwrap();
+        assert_eq!(text.lines().count(), 2);
+    }
+
+    #[test]
+    fn test_array_format_wraps_records_in_brackets() {
+        let mut buf = Vec::new();
+        let mut exporter = JsonExporter::new(&mut buf, JsonFormat::Array).unwrap();
+        exporter
+            .write_batch(&[sample_record(48), sample_record(62)])
+            .unwrap();
+        exporter.finish().unwrap();
+
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.starts_with('['));
+        assert!(text.ends_with(']'));
+        let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed.as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_array_format_empty_is_still_valid_json() {
+        let mut buf = Vec::new();
+        let exporter = JsonExporter::new(&mut buf, JsonFormat::Array).unwrap();
+        exporter.finish().unwrap();
+
+        let text = String::from_utf8(buf).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed.as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_pretty_format_buffers_until_finish() {
+        let mut buf = Vec::new();
+        let mut exporter = JsonExporter::new(&mut buf, JsonFormat::Pretty).unwrap();
+        exporter.write_batch(&[sample_record(48)]).unwrap();
+        // Nothing should be written until finish() pretty-prints the batch.
+        assert!(exporter.pending.len() == 1);
+        exporter.finish().unwrap();
+
+        let text = String::from_utf8(buf).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed.as_array().unwrap().len(), 1);
+        assert!(text.contains('\n'), "pretty output should be multi-line");
+    }
+}
@@ -0,0 +1,125 @@
+//! JSON Schema generation for the parser's structured output
+//!
+//! Downstream consumers that ingest [`AsterixRecord`](crate::types::AsterixRecord)/
+//! [`DataItem`](crate::types::DataItem)/[`ParsedValue`](crate::types::ParsedValue)
+//! as JSON want to validate it and generate typed bindings in other
+//! languages. This module derives [`schemars::JsonSchema`] for those three
+//! types and exposes [`asterix_output_schema`], which renders the root
+//! `AsterixRecord` schema as a [`serde_json::Value`].
+//!
+//! `ParsedValue::Nested`/[`ParsedValue::Array`](crate::types::ParsedValue::Array)
+//! hold `ParsedValue` recursively. Rather than let `schemars` unfold that
+//! into a deeply self-referential `$ref` chain, both are schema'd
+//! (`#[schemars(with = "...")]`) as an opaque JSON object/array instead —
+//! consumers see "this is nested/array JSON", not its exact recursive shape.
+//! A downstream tool that needs the precise recursive structure should walk
+//! [`ParsedValue`] itself rather than its schema.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::types::AsterixRecord;
+
+/// Produce a JSON Schema for [`AsterixRecord`], the parser's top-level
+/// output type, as a [`serde_json::Value`].
+///
+/// # Panics
+///
+/// Panics if the generated schema doesn't round-trip through `serde_json`,
+/// which would indicate a bug in this crate's `JsonSchema` derives rather
+/// than a caller error.
+pub fn asterix_output_schema() -> Value {
+    let schema = schemars::schema_for!(AsterixRecord);
+    serde_json::to_value(schema).expect("schemars schema should serialize to JSON")
+}
+
+/// Lets a caller supply their own hand-tuned JSON Schema for specific
+/// ASTERIX categories instead of the generic auto-derived
+/// [`DataItem`](crate::types::DataItem) shape — e.g. a category whose field
+/// layout a downstream codegen tool needs described more precisely than the
+/// generic `fields: BTreeMap<String, ParsedValue>` schema can.
+///
+/// Modeled on xdrgen's custom-impl opt-out list: registering a category here
+/// only overrides *that* category; every category not registered still gets
+/// the auto-derived schema from [`asterix_output_schema`].
+#[derive(Debug, Clone, Default)]
+pub struct SchemaOverrides {
+    by_category: HashMap<u8, Value>,
+}
+
+impl SchemaOverrides {
+    /// An empty override set; every category uses the auto-derived schema.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `schema` as the hand-tuned override for `category`.
+    pub fn with_category(mut self, category: u8, schema: Value) -> Self {
+        self.by_category.insert(category, schema);
+        self
+    }
+}
+
+/// Like [`asterix_output_schema`], but also attaches `overrides`'s hand-tuned
+/// per-category schemas under the `x-category-overrides` vendor extension
+/// key, keyed by category number as a string.
+///
+/// The auto-derived schema itself is unchanged: splicing a specific
+/// category's override into `items`'s own schema depends on conventions a
+/// downstream codegen tool defines for itself, so this exposes the raw
+/// overrides alongside the generic schema rather than guessing at one.
+pub fn asterix_output_schema_with_overrides(overrides: &SchemaOverrides) -> Value {
+    let mut schema = asterix_output_schema();
+
+    if overrides.by_category.is_empty() {
+        return schema;
+    }
+
+    if let Some(obj) = schema.as_object_mut() {
+        let category_overrides: serde_json::Map<String, Value> = overrides
+            .by_category
+            .iter()
+            .map(|(category, schema)| (category.to_string(), schema.clone()))
+            .collect();
+        obj.insert(
+            "x-category-overrides".to_string(),
+            Value::Object(category_overrides),
+        );
+    }
+
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asterix_output_schema_is_an_object_with_expected_properties() {
+        let schema = asterix_output_schema();
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .expect("schema should have a properties object");
+        assert!(properties.contains_key("category"));
+        assert!(properties.contains_key("items"));
+    }
+
+    #[test]
+    fn test_schema_overrides_default_is_empty() {
+        let schema = asterix_output_schema_with_overrides(&SchemaOverrides::new());
+        assert!(schema.get("x-category-overrides").is_none());
+    }
+
+    #[test]
+    fn test_schema_overrides_attaches_registered_category() {
+        let overrides = SchemaOverrides::new().with_category(62, serde_json::json!({"type": "object"}));
+        let schema = asterix_output_schema_with_overrides(&overrides);
+        let attached = schema
+            .get("x-category-overrides")
+            .and_then(|v| v.get("62"))
+            .expect("override for category 62 should be attached");
+        assert_eq!(attached, &serde_json::json!({"type": "object"}));
+    }
+}
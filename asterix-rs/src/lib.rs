@@ -69,6 +69,24 @@
 //!    Use separate processes instead of threads (e.g., with `rayon`'s process pool
 //!    or manual process spawning).
 //!
+//! 4. **[`SerializedDecoder`]**: a cheap, cloneable handle backed by one
+//!    dedicated worker thread that owns the singleton and runs every
+//!    `init_default`/`load_category`/`parse`/`describe` call it's handed, one
+//!    at a time, regardless of which thread called it. Concurrent callers
+//!    queue instead of racing, removing the need for the patterns above:
+//!    ```ignore
+//!    use asterix::{SerializedDecoder, ParseOptions};
+//!
+//!    # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!    let decoder = SerializedDecoder::new();
+//!    decoder.init_default()?;
+//!
+//!    let data = std::fs::read("sample.asterix")?;
+//!    let records = decoder.parse(data, ParseOptions::default())?;
+//!    # Ok(())
+//!    # }
+//!    ```
+//!
 //! # Quick Start
 //!
 //! ```no_run
@@ -143,6 +161,54 @@
 //! # }
 //! ```
 //!
+//! To let site-specific overrides live alongside the vendor config instead of
+//! forking it, use [`init_config_dir_with_drop_ins`]: it auto-discovers every
+//! `*.xml` file in the given directory and in an adjacent `asterix.d/`
+//! subdirectory, loading the drop-in files last so they win for any category
+//! they redefine.
+//!
+//! ```no_run
+//! use asterix::init_config_dir_with_drop_ins;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // Loads "/path/to/asterix/config/*.xml", then
+//! // "/path/to/asterix/config/asterix.d/*.xml" on top of it.
+//! init_config_dir_with_drop_ins("/path/to/asterix/config")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To load only a subset of categories from a config tree that ships many,
+//! use [`init_config_dir_glob`] with a glob pattern (`*`, `?`, and `**` for
+//! recursive directories are supported):
+//!
+//! ```no_run
+//! use asterix::init_config_dir_glob;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! // Only this sensor's categories, skipping the rest of the config tree.
+//! init_config_dir_glob("/path/to/asterix/config", "asterix_cat0*.xml")?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! When the category path itself comes from an untrusted source (e.g. a
+//! network control channel), use [`init_config_dir_sandboxed`] or
+//! [`load_category_sandboxed`]: both confine their path argument to a
+//! configured root using purely lexical `.`/`..` resolution, with no
+//! filesystem access, before anything is touched on disk.
+//!
+//! ```no_run
+//! use asterix::load_category_sandboxed;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let untrusted_category_name = "asterix_cat062.xml";
+//! // Rejected outright if untrusted_category_name climbs above the root.
+//! load_category_sandboxed("/path/to/asterix/config", untrusted_category_name)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Metadata Queries
 //!
 //! Get descriptions for categories, items, and fields:
@@ -179,7 +245,7 @@
 //!
 //! match parse(data, ParseOptions::default()) {
 //!     Ok(records) => println!("Success: {} records", records.len()),
-//!     Err(AsterixError::ParseError { offset, message }) => {
+//!     Err(AsterixError::ParseError { offset, message, .. }) => {
 //!         eprintln!("Parse failed at byte {}: {}", offset, message);
 //!     }
 //!     Err(AsterixError::InvalidCategory { category, reason }) => {
@@ -194,6 +260,7 @@
 //!
 //! - `serde` (default): Enable JSON serialization/deserialization
 //! - `async`: Enable async parsing support (future)
+//! - `gzip`, `xz`, `zstd`: Transparent decompression of recordings opened via [`open_recording`]
 //!
 //! # Platform Support
 //!
@@ -212,27 +279,136 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 // Public modules
+pub mod bench_history;
+pub mod block;
+pub mod cbor;
+pub mod clock;
+pub mod dot;
+pub mod encode;
 pub mod error;
+pub mod framing;
+pub mod hex;
+pub mod line_export;
+pub mod packed;
 pub mod parser;
+pub mod pretty;
+pub mod quantity;
+pub mod reader;
+pub mod recording;
+pub mod serialized_decoder;
+pub mod source;
+pub mod stats;
+pub mod stream;
+pub mod tlv;
 pub mod types;
+pub mod validate;
 
 // Transport modules (feature-gated)
 #[cfg(feature = "zenoh")]
 #[cfg_attr(docsrs, doc(cfg(feature = "zenoh")))]
 pub mod transport;
 
+// Arrow/Parquet columnar export (feature-gated)
+#[cfg(feature = "arrow")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub mod columnar;
+
+// Multi-threaded parallel block parsing (feature-gated)
+#[cfg(feature = "parallel")]
+#[cfg_attr(docsrs, doc(cfg(feature = "parallel")))]
+pub mod parallel;
+
+// Streaming JSON export (feature-gated)
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod json_export;
+
+// Append-only, timestamp-indexed record archive (feature-gated)
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod archive;
+
+// RON (Rusty Object Notation) export/import (feature-gated)
+#[cfg(feature = "ron")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ron")))]
+pub mod ron_export;
+
+// serde_json::Value bridge for AsterixRecord (feature-gated)
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod json_bridge;
+
+// JSON Schema generation for AsterixRecord/DataItem/ParsedValue (feature-gated)
+#[cfg(feature = "jsonschema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jsonschema")))]
+pub mod json_schema;
+
+// Stable extern "C" ABI for non-Rust consumers (feature-gated); see
+// `build.rs` for the matching cbindgen/pkg-config codegen
+#[cfg(feature = "capi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "capi")))]
+pub mod capi;
+
 // Private FFI module
 mod ffi;
 
+// Private glob expansion for selecting category definition files
+mod glob;
+
+// Private symlink-escape auditing for config directory loading
+mod path_auditor;
+
 // Re-export main types and functions for convenience
-pub use error::{AsterixError, Result};
-pub use parser::{parse, parse_with_offset};
-pub use types::{AsterixRecord, DataItem, ParseOptions, ParseResult, ParsedValue};
+pub use bench_history::{compare as compare_benchmarks, BenchmarkCollection, BenchmarkComparison, BenchmarkRecord};
+pub use block::{DataBlock, ParsedBlocks};
+pub use clock::{Clock, MockClock, SharedClock, SystemClock};
+pub use encode::{encode, EncodeOptions, RecordBuilder};
+pub use error::{AsterixError, Diagnostic, ParseFrame, Result, Severity};
+pub use framing::{frame_blocks, BlockSpan};
+pub use line_export::{write_csv, write_ndjson};
+pub use packed::{pack_record, unpack_record, StringTable};
+pub use quantity::{Conversion, Quantity};
+pub use parser::{
+    parse, parse_blocks, parse_each, parse_resilient, parse_with_mode, parse_with_offset,
+    records_iter, RecordsIter, StreamParser,
+};
+#[cfg(feature = "serde")]
+pub use parser::{items_to_json, parse_records_from_ndjson, parsed_value_to_json_value, NdjsonRecords};
+#[cfg(feature = "parallel")]
+pub use parallel::{parse_parallel, parse_parallel_auto, parse_parallel_with_stats};
+#[cfg(feature = "serde")]
+pub use json_export::{JsonExporter, JsonFormat};
+#[cfg(feature = "serde")]
+pub use archive::{ArchiveReader, ArchiveWriter};
+#[cfg(feature = "ron")]
+pub use ron_export::{from_ron, to_ron};
+#[cfg(feature = "serde")]
+pub use json_bridge::{ByteEncoding, JsonEncodeOptions, NestedKeyOrder};
+pub use reader::{parse_reader, AsterixReader};
+pub use recording::{open_recording, Recording, RecordingCodec};
+pub use serialized_decoder::SerializedDecoder;
+pub use source::{
+    AsterixSource, AsterixSourceRx, NonBlockingAsterixSource, RecordSource, UdpSourceConfig,
+    UdpSourceError,
+};
+pub use stats::{CategoryStats, SizeStats, Stats, StatsCollector, TimeRange};
+pub use stream::{stream, BlockIterator};
+pub use validate::{
+    validate, DependencyRule, FspecConsistencyRule, MandatoryItemRule, NumericRangeRule, Rule,
+    RuleSet, ValidationDiagnostic, ValidationSeverity,
+};
+pub use types::{
+    records_approx_eq, AsterixRecord, DataItem, FieldCondition, FieldFilter, FieldMap, ItemMap,
+    MaybeParsed, ParseMode, ParseOptions, ParseOutcome, ParseResult, ParsedValue, RecordError,
+    RecordFilter, TruncatedAt,
+};
 
 // Re-export FFI initialization functions
 pub use ffi::{
-    describe, get_log_level, init_config_dir, init_default, is_category_defined, load_category,
-    set_log_level, LogLevel,
+    clear_log_sink, describe, describe_structured, get_log_level, init_config_dir,
+    init_config_dir_glob, init_config_dir_sandboxed, init_config_dir_with_drop_ins, init_default,
+    is_category_defined, load_category, load_category_sandboxed, set_log_file, set_log_level,
+    set_log_sink, CategoryDesc, Description, FieldDesc, ItemDesc, LogLevel,
 };
 
 // Version information
@@ -285,6 +461,7 @@ mod tests {
         let err = AsterixError::ParseError {
             offset: 100,
             message: "Invalid data".to_string(),
+            context: None,
         };
         let display = format!("{err}");
         assert!(display.contains("100"));
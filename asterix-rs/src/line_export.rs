@@ -0,0 +1,251 @@
+//! Zero-dependency NDJSON and CSV export for decoded records
+//!
+//! [`crate::json_export`] (feature `serde`) re-serializes whole
+//! [`AsterixRecord`]s via `Serialize`; this module instead renders each
+//! record's values through [`ParsedValue`]'s accessors
+//! (`as_i64`/`as_f64`/`as_str`/`as_bool`) the same way
+//! [`crate::columnar`] does, giving a predictable, tool-friendly shape
+//! instead of whatever the derived `Serialize` impl happens to produce.
+//! [`write_ndjson`] writes one JSON object per line, with nested/array
+//! fields kept as nested JSON objects/arrays; [`write_csv`] instead flattens
+//! every field to a `catNNN/Ixxx/FIELD`-style column
+//! ([`crate::types::flat_column_name`], the same naming
+//! [`crate::columnar::to_record_batch`] uses), so two different categories'
+//! fields never collide in the same row.
+//!
+//! Both functions use only `std::io::Write` and the crate's existing
+//! `serde_json` dependency (already used internally by [`crate::parser`]) —
+//! no separate CSV crate is pulled in, since a handful of escaping rules
+//! cover what's needed here.
+
+use std::collections::BTreeSet;
+use std::io::Write;
+
+use crate::error::{AsterixError, Result};
+use crate::types::{flat_column_name, AsterixRecord, ParsedValue};
+
+/// Write `records` as newline-delimited JSON (one compact object per line).
+///
+/// Nested/array item fields are kept as nested JSON objects/arrays rather
+/// than flattened, mirroring the record's own structure.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::IOError`] if writing fails.
+pub fn write_ndjson<W: Write>(records: &[AsterixRecord], mut writer: W) -> Result<()> {
+    for record in records {
+        let json = record_to_json(record);
+        let line = serde_json::to_string(&json).map_err(json_err)?;
+        writer.write_all(line.as_bytes()).map_err(io_err)?;
+        writer.write_all(b"\n").map_err(io_err)?;
+    }
+    Ok(())
+}
+
+/// Write `records` as CSV: a header row of every `catNNN/Ixxx/FIELD` column
+/// seen anywhere in `records`, then one row per record with blanks for
+/// fields that record doesn't carry.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::IOError`] if writing fails.
+pub fn write_csv<W: Write>(records: &[AsterixRecord], mut writer: W) -> Result<()> {
+    let mut columns: BTreeSet<String> = BTreeSet::new();
+    for record in records {
+        for (path, _) in record.fields_flat() {
+            columns.insert(flat_column_name(&path));
+        }
+    }
+    let columns: Vec<String> = columns.into_iter().collect();
+
+    let mut header = vec!["category".to_string(), "length".to_string()];
+    header.extend(columns.iter().cloned());
+    write_csv_row(&mut writer, &header)?;
+
+    for record in records {
+        let mut by_column: std::collections::HashMap<String, &ParsedValue> =
+            std::collections::HashMap::new();
+        for (path, value) in record.fields_flat() {
+            by_column.insert(flat_column_name(&path), value);
+        }
+
+        let mut row = vec![record.category.to_string(), record.length.to_string()];
+        for column in &columns {
+            row.push(
+                by_column
+                    .get(column)
+                    .map(|value| render_value(value))
+                    .unwrap_or_default(),
+            );
+        }
+        write_csv_row(&mut writer, &row)?;
+    }
+    Ok(())
+}
+
+fn write_csv_row<W: Write>(writer: &mut W, fields: &[String]) -> Result<()> {
+    let line = fields
+        .iter()
+        .map(|field| csv_escape(field))
+        .collect::<Vec<_>>()
+        .join(",");
+    writer.write_all(line.as_bytes()).map_err(io_err)?;
+    writer.write_all(b"\n").map_err(io_err)
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Render one leaf [`ParsedValue`] as a CSV field, via its accessors.
+fn render_value(value: &ParsedValue) -> String {
+    if let Some(v) = value.as_i64() {
+        return v.to_string();
+    }
+    if let Some(v) = value.as_f64() {
+        return v.to_string();
+    }
+    if let Some(v) = value.as_str() {
+        return v.to_string();
+    }
+    if let Some(v) = value.as_bool() {
+        return v.to_string();
+    }
+    if let Some(v) = value.as_bytes() {
+        return v.iter().map(|b| format!("{b:02x}")).collect();
+    }
+    String::new()
+}
+
+/// Render a full [`AsterixRecord`] as a `serde_json::Value`, preserving
+/// nested/array structure (unlike [`write_csv`]'s flattening).
+fn record_to_json(record: &AsterixRecord) -> serde_json::Value {
+    let mut items = serde_json::Map::new();
+    for (item_id, item) in &record.items {
+        let mut fields = serde_json::Map::new();
+        for (field_name, value) in &item.fields {
+            fields.insert(field_name.clone(), parsed_value_to_json(value));
+        }
+        items.insert(item_id.clone(), serde_json::Value::Object(fields));
+    }
+
+    serde_json::json!({
+        "category": record.category,
+        "length": record.length,
+        "timestamp_ms": record.timestamp_ms,
+        "crc": record.crc,
+        "items": items,
+    })
+}
+
+fn parsed_value_to_json(value: &ParsedValue) -> serde_json::Value {
+    match value {
+        ParsedValue::Integer(v) => serde_json::json!(v),
+        ParsedValue::Unsigned(v) => serde_json::json!(v),
+        ParsedValue::Float(v) => serde_json::json!(v),
+        ParsedValue::Decimal { raw, scale, .. } => serde_json::json!(*raw as f64 * scale),
+        ParsedValue::String(v) => serde_json::json!(v),
+        ParsedValue::Boolean(v) => serde_json::json!(v),
+        ParsedValue::Bytes(v) => serde_json::json!(v.iter().map(|b| format!("{b:02x}")).collect::<String>()),
+        ParsedValue::Nested(nested) => {
+            let mut map = serde_json::Map::new();
+            for (key, nested_value) in nested {
+                map.insert(key.clone(), parsed_value_to_json(nested_value));
+            }
+            serde_json::Value::Object(map)
+        }
+        ParsedValue::Array(items) => {
+            serde_json::Value::Array(items.iter().map(parsed_value_to_json).collect())
+        }
+        ParsedValue::Raw(text) => serde_json::json!(text),
+        ParsedValue::Number(text) => serde_json::json!(text),
+    }
+}
+
+fn io_err(err: std::io::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+fn json_err(err: serde_json::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn sample_record(category: u8) -> AsterixRecord {
+        AsterixRecord {
+            category,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_write_ndjson_one_line_per_record() {
+        let mut buf = Vec::new();
+        write_ndjson(&[sample_record(48), sample_record(62)], &mut buf).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("\"category\":48"));
+        assert!(lines[1].contains("\"category\":62"));
+    }
+
+    #[test]
+    fn test_write_ndjson_empty_input_writes_nothing() {
+        let mut buf = Vec::new();
+        write_ndjson(&[], &mut buf).unwrap();
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_write_csv_header_and_fixed_columns() {
+        let mut buf = Vec::new();
+        write_csv(&[sample_record(48), sample_record(62)], &mut buf).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("category,length"));
+        assert_eq!(lines.next(), Some("48,0"));
+        assert_eq!(lines.next(), Some("62,0"));
+    }
+
+    #[test]
+    fn test_write_csv_includes_flattened_item_columns() {
+        let mut item_fields = BTreeMap::new();
+        item_fields.insert("SAC".to_string(), ParsedValue::Integer(25));
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/010".to_string(),
+            crate::types::DataItem {
+                description: None,
+                fields: item_fields,
+            },
+        );
+
+        let record = AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        };
+
+        let mut buf = Vec::new();
+        write_csv(&[record], &mut buf).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        let mut lines = text.lines();
+        assert_eq!(lines.next(), Some("category,length,cat048/I010/SAC"));
+        assert_eq!(lines.next(), Some("48,0,25"));
+    }
+
+    #[test]
+    fn test_csv_escape_quotes_fields_with_commas() {
+        assert_eq!(csv_escape("plain"), "plain");
+        assert_eq!(csv_escape("a,b"), "\"a,b\"");
+        assert_eq!(csv_escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,670 @@
+//! Compact, self-describing packed binary codec for [`AsterixRecord`]
+//!
+//! [`ParsedValue`]'s `#[serde(untagged)]` shape and [`ItemMap`]/[`FieldMap`]'s
+//! string keys make JSON (and the hand-rolled [`crate::cbor`] encoder, which
+//! mirrors the same shape) string-heavy: every record repeats the same
+//! handful of item ids (`"I062/010"`) and field names (`"SAC"`, `"SIC"`) in
+//! full. [`pack_record`] instead writes a type-tagged byte per
+//! [`ParsedValue`] and interns every item id/field name/compound subfield
+//! name it sees into a [`StringTable`], referencing them by a 1–2 byte
+//! varint id instead of repeating the string — a caller packing millions of
+//! records built from a handful of categories pays for each distinct string
+//! once.
+//!
+//! Unlike [`crate::cbor::encode_record`] (encode-only, since nothing needs
+//! CBOR back), this codec round-trips: [`unpack_record`] reproduces
+//! [`AsterixRecord`] exactly, including [`ItemMap`]/[`FieldMap`] iteration
+//! order (records and replays each key in the order it was packed, so a
+//! `BTreeMap`'s alphabetical order or an `IndexMap`'s insertion order comes
+//! back the same way either was built) and every numeric value bit-for-bit.
+//!
+//! [`StringTable`] is shared across every [`pack_record`]/[`unpack_record`]
+//! call for one archive (or capture), not created fresh per record, so
+//! interning actually pays off across records — [`StringTable::encode`]/
+//! [`StringTable::decode`] let the table itself be persisted alongside the
+//! packed records (e.g. as its own section in an [`crate::archive::ArchiveWriter`]
+//! style container).
+//!
+//! # Wire format
+//!
+//! A packed record is: `category: u8`, `length: varint`, `timestamp_ms:
+//! varint`, `crc: varint`, `hex_data: len-prefixed string`, then `items:
+//! varint count` followed by that many `(item_id: varint string ref,
+//! description: presence byte + optional varint string ref, fields: varint
+//! count followed by that many (field_name: varint string ref,
+//! [`ParsedValue`]))`.
+//!
+//! Every [`ParsedValue`] is a one-byte type tag followed by its payload:
+//!
+//! | Tag | Variant | Payload |
+//! |-----|---------|---------|
+//! | 0 | [`Integer`](ParsedValue::Integer) | zig-zag varint |
+//! | 1 | [`Float`](ParsedValue::Float) | `f64`, little-endian |
+//! | 2 | [`String`](ParsedValue::String) | varint length + UTF-8 bytes |
+//! | 3 | [`Boolean`](ParsedValue::Boolean) | one byte, 0 or 1 |
+//! | 4 | [`Bytes`](ParsedValue::Bytes) | varint length + raw bytes |
+//! | 5 | [`Nested`](ParsedValue::Nested) | varint count + that many (key: varint string ref, value) |
+//! | 6 | [`Array`](ParsedValue::Array) | varint count + that many values |
+//! | 7 | [`Unsigned`](ParsedValue::Unsigned) | varint |
+//! | 8 | [`Decimal`](ParsedValue::Decimal) | zig-zag varint `raw` + `f64` `scale` + presence byte + optional varint string ref `unit` |
+//! | 9 | [`Raw`](ParsedValue::Raw) | varint length + UTF-8 bytes |
+//! | 10 | [`Number`](ParsedValue::Number) | varint length + UTF-8 bytes |
+//!
+//! Tags 7–10 aren't in the original 0–6 set this format was sketched from,
+//! since that set only covers the variants a typical decoded field takes —
+//! [`ParsedValue::Unsigned`]/[`Decimal`](ParsedValue::Decimal)/[`Raw`](ParsedValue::Raw)/[`Number`](ParsedValue::Number)
+//! are real variants this crate's own parser produces (see their doc
+//! comments in [`crate::types`]), and omitting them would make this codec
+//! silently lossy for any record containing one.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asterix::{init_default, parse, pack_record, unpack_record, StringTable, ParseOptions};
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! init_default()?;
+//! let data = std::fs::read("sample.asterix")?;
+//! let records = parse(&data, ParseOptions::default())?;
+//!
+//! let mut table = StringTable::new();
+//! let packed: Vec<Vec<u8>> = records.iter().map(|r| pack_record(r, &mut table)).collect();
+//!
+//! for (packed_record, original) in packed.iter().zip(&records) {
+//!     let round_tripped = unpack_record(packed_record, &table)?;
+//!     assert_eq!(round_tripped.category, original.category);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::error::{AsterixError, Result};
+use crate::types::{AsterixRecord, DataItem, FieldMap, ItemMap, ParsedValue};
+
+/// Type tags for [`ParsedValue`]. See the [module docs](self) for the full
+/// wire-format table.
+mod tag {
+    pub const INTEGER: u8 = 0;
+    pub const FLOAT: u8 = 1;
+    pub const STRING: u8 = 2;
+    pub const BOOLEAN: u8 = 3;
+    pub const BYTES: u8 = 4;
+    pub const NESTED: u8 = 5;
+    pub const ARRAY: u8 = 6;
+    pub const UNSIGNED: u8 = 7;
+    pub const DECIMAL: u8 = 8;
+    pub const RAW: u8 = 9;
+    pub const NUMBER: u8 = 10;
+}
+
+/// Interns strings (item ids, field names, compound subfield names,
+/// descriptions, and [`ParsedValue::Decimal`]'s `unit` label) so
+/// [`pack_record`] can reference a repeated string by a 1–2 byte varint id
+/// instead of writing it out every time.
+///
+/// Shared across every [`pack_record`] call for one archive: build one
+/// `StringTable`, pass it (and the records it intern'd strings for) to
+/// [`unpack_record`] to reverse the process.
+#[derive(Debug, Default, Clone)]
+pub struct StringTable {
+    strings: Vec<String>,
+    ids: HashMap<String, u32>,
+}
+
+impl StringTable {
+    /// An empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Intern `s`, returning its id — an existing entry's id if `s` was
+    /// already interned, or a freshly assigned one otherwise.
+    pub fn intern(&mut self, s: &str) -> u32 {
+        if let Some(&id) = self.ids.get(s) {
+            return id;
+        }
+        let id = self.strings.len() as u32;
+        self.strings.push(s.to_string());
+        self.ids.insert(s.to_string(), id);
+        id
+    }
+
+    /// Look up the string behind a previously interned `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::InvalidData`] if `id` was never interned into
+    /// this table.
+    pub fn resolve(&self, id: u32) -> Result<&str> {
+        self.strings
+            .get(id as usize)
+            .map(String::as_str)
+            .ok_or_else(|| AsterixError::InvalidData(format!("unknown string table id {id}")))
+    }
+
+    /// Number of distinct strings interned so far.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Whether no strings have been interned yet.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Encode this table as `varint count` followed by that many
+    /// `(varint length, UTF-8 bytes)` entries, in id order, so
+    /// [`Self::decode`] reconstructs the same ids.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_varint(&mut out, self.strings.len() as u64);
+        for s in &self.strings {
+            write_varint(&mut out, s.len() as u64);
+            out.extend_from_slice(s.as_bytes());
+        }
+        out
+    }
+
+    /// Decode a table written by [`Self::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::UnexpectedEOF`] if `data` ends before the
+    /// declared entries are fully read, and [`AsterixError::InvalidData`] if
+    /// an entry isn't valid UTF-8.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let mut pos = 0usize;
+        let count = read_varint(data, &mut pos)?;
+        let mut table = StringTable::new();
+        for _ in 0..count {
+            let len = read_varint(data, &mut pos)? as usize;
+            let bytes = read_bytes(data, &mut pos, len)?;
+            let s = std::str::from_utf8(bytes)
+                .map_err(|e| AsterixError::InvalidData(format!("invalid UTF-8 in string table: {e}")))?;
+            table.intern(s);
+        }
+        Ok(table)
+    }
+}
+
+/// Pack `record` into this codec's compact binary form, interning its
+/// strings into `table`.
+///
+/// # Errors
+///
+/// This function itself never fails — packing is infallible, since every
+/// `AsterixRecord` already in memory is well-formed by construction. Errors
+/// only arise decoding a (possibly corrupt) byte stream back via
+/// [`unpack_record`].
+pub fn pack_record(record: &AsterixRecord, table: &mut StringTable) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    out.push(record.category);
+    write_varint(&mut out, u64::from(record.length));
+    write_varint(&mut out, record.timestamp_ms);
+    write_varint(&mut out, u64::from(record.crc));
+    write_string(&mut out, &record.hex_data);
+
+    write_varint(&mut out, record.items.len() as u64);
+    for (item_id, item) in &record.items {
+        write_interned(&mut out, table, item_id);
+        write_optional_interned(&mut out, table, item.description.as_deref());
+        pack_fields(&mut out, table, &item.fields);
+    }
+
+    out
+}
+
+/// Unpack a record written by [`pack_record`], resolving interned strings
+/// against `table`.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::UnexpectedEOF`] if `data` ends before a declared
+/// length is fully read, and [`AsterixError::InvalidData`] if an interned
+/// string id or a `ParsedValue` type tag isn't recognized.
+pub fn unpack_record(data: &[u8], table: &StringTable) -> Result<AsterixRecord> {
+    let mut pos = 0usize;
+
+    let category = *data
+        .first()
+        .ok_or(AsterixError::UnexpectedEOF { offset: 0, expected: 1 })?;
+    pos += 1;
+    let length = read_varint(data, &mut pos)? as u32;
+    let timestamp_ms = read_varint(data, &mut pos)?;
+    let crc = read_varint(data, &mut pos)? as u32;
+    let hex_data = read_string(data, &mut pos)?;
+
+    let item_count = read_varint(data, &mut pos)?;
+    let mut items = ItemMap::new();
+    for _ in 0..item_count {
+        let item_id = read_interned(data, &mut pos, table)?.to_string();
+        let description = read_optional_interned(data, &mut pos, table)?.map(str::to_string);
+        let fields = unpack_fields(data, &mut pos, table)?;
+        items.insert(item_id, DataItem { description, fields });
+    }
+
+    Ok(AsterixRecord {
+        category,
+        length,
+        timestamp_ms,
+        crc,
+        hex_data,
+        items,
+    })
+}
+
+fn pack_fields(out: &mut Vec<u8>, table: &mut StringTable, fields: &FieldMap) {
+    write_varint(out, fields.len() as u64);
+    for (name, value) in fields {
+        write_interned(out, table, name);
+        pack_value(out, table, value);
+    }
+}
+
+fn unpack_fields(data: &[u8], pos: &mut usize, table: &StringTable) -> Result<FieldMap> {
+    let count = read_varint(data, pos)?;
+    let mut fields = FieldMap::new();
+    for _ in 0..count {
+        let name = read_interned(data, pos, table)?.to_string();
+        let value = unpack_value(data, pos, table)?;
+        fields.insert(name, value);
+    }
+    Ok(fields)
+}
+
+fn pack_value(out: &mut Vec<u8>, table: &mut StringTable, value: &ParsedValue) {
+    match value {
+        ParsedValue::Integer(v) => {
+            out.push(tag::INTEGER);
+            write_varint(out, zigzag_encode(*v));
+        }
+        ParsedValue::Unsigned(v) => {
+            out.push(tag::UNSIGNED);
+            write_varint(out, *v);
+        }
+        ParsedValue::Float(v) => {
+            out.push(tag::FLOAT);
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        ParsedValue::String(v) => {
+            out.push(tag::STRING);
+            write_string(out, v);
+        }
+        ParsedValue::Boolean(v) => {
+            out.push(tag::BOOLEAN);
+            out.push(u8::from(*v));
+        }
+        ParsedValue::Bytes(v) => {
+            out.push(tag::BYTES);
+            write_varint(out, v.len() as u64);
+            out.extend_from_slice(v);
+        }
+        ParsedValue::Decimal { raw, scale, unit } => {
+            out.push(tag::DECIMAL);
+            write_varint(out, zigzag_encode(*raw));
+            out.extend_from_slice(&scale.to_le_bytes());
+            write_optional_interned(out, table, unit.as_deref());
+        }
+        ParsedValue::Nested(map) => {
+            out.push(tag::NESTED);
+            write_varint(out, map.len() as u64);
+            for (key, nested) in map {
+                write_interned(out, table, key);
+                pack_value(out, table, nested);
+            }
+        }
+        ParsedValue::Array(values) => {
+            out.push(tag::ARRAY);
+            write_varint(out, values.len() as u64);
+            for nested in values {
+                pack_value(out, table, nested);
+            }
+        }
+        ParsedValue::Raw(text) => {
+            out.push(tag::RAW);
+            write_string(out, text);
+        }
+        ParsedValue::Number(text) => {
+            out.push(tag::NUMBER);
+            write_string(out, text);
+        }
+    }
+}
+
+fn unpack_value(data: &[u8], pos: &mut usize, table: &StringTable) -> Result<ParsedValue> {
+    let tag = *data
+        .get(*pos)
+        .ok_or(AsterixError::UnexpectedEOF { offset: *pos, expected: 1 })?;
+    *pos += 1;
+
+    match tag {
+        tag::INTEGER => Ok(ParsedValue::Integer(zigzag_decode(read_varint(data, pos)?))),
+        tag::UNSIGNED => Ok(ParsedValue::Unsigned(read_varint(data, pos)?)),
+        tag::FLOAT => Ok(ParsedValue::Float(f64::from_le_bytes(read_bytes(
+            data, pos, 8,
+        )?.try_into().expect("read_bytes(8) yields an 8-byte slice")))),
+        tag::STRING => Ok(ParsedValue::String(read_string(data, pos)?)),
+        tag::BOOLEAN => {
+            let byte = *data
+                .get(*pos)
+                .ok_or(AsterixError::UnexpectedEOF { offset: *pos, expected: 1 })?;
+            *pos += 1;
+            Ok(ParsedValue::Boolean(byte != 0))
+        }
+        tag::BYTES => {
+            let len = read_varint(data, pos)? as usize;
+            Ok(ParsedValue::Bytes(read_bytes(data, pos, len)?.to_vec()))
+        }
+        tag::DECIMAL => {
+            let raw = zigzag_decode(read_varint(data, pos)?);
+            let scale = f64::from_le_bytes(
+                read_bytes(data, pos, 8)?
+                    .try_into()
+                    .expect("read_bytes(8) yields an 8-byte slice"),
+            );
+            let unit = read_optional_interned(data, pos, table)?.map(str::to_string);
+            Ok(ParsedValue::Decimal { raw, scale, unit })
+        }
+        tag::NESTED => {
+            let count = read_varint(data, pos)?;
+            let mut map = std::collections::BTreeMap::new();
+            for _ in 0..count {
+                let key = read_interned(data, pos, table)?.to_string();
+                let value = unpack_value(data, pos, table)?;
+                map.insert(key, Box::new(value));
+            }
+            Ok(ParsedValue::Nested(map))
+        }
+        tag::ARRAY => {
+            let count = read_varint(data, pos)?;
+            // Don't preallocate from `count` directly: it's an attacker-
+            // controlled varint (up to `u64::MAX` in 10 bytes) that may
+            // vastly exceed what `data` actually holds, so a crafted or
+            // corrupted buffer could otherwise abort the process on an
+            // allocation failure before a single byte is validated. Growing
+            // the `Vec` one `push` at a time instead means the allocation
+            // size always tracks bytes actually consumed, the same
+            // discipline `unpack_fields` already applies to its own count.
+            let mut values = Vec::new();
+            for _ in 0..count {
+                values.push(unpack_value(data, pos, table)?);
+            }
+            Ok(ParsedValue::Array(values))
+        }
+        tag::RAW => Ok(ParsedValue::Raw(read_string(data, pos)?)),
+        tag::NUMBER => Ok(ParsedValue::Number(read_string(data, pos)?)),
+        other => Err(AsterixError::InvalidData(format!(
+            "unknown ParsedValue type tag {other}"
+        ))),
+    }
+}
+
+fn write_interned(out: &mut Vec<u8>, table: &mut StringTable, s: &str) {
+    write_varint(out, u64::from(table.intern(s)));
+}
+
+fn read_interned<'a>(data: &[u8], pos: &mut usize, table: &'a StringTable) -> Result<&'a str> {
+    let id = read_varint(data, pos)? as u32;
+    table.resolve(id)
+}
+
+fn write_optional_interned(out: &mut Vec<u8>, table: &mut StringTable, s: Option<&str>) {
+    match s {
+        Some(s) => {
+            out.push(1);
+            write_interned(out, table, s);
+        }
+        None => out.push(0),
+    }
+}
+
+fn read_optional_interned<'a>(
+    data: &[u8],
+    pos: &mut usize,
+    table: &'a StringTable,
+) -> Result<Option<&'a str>> {
+    let present = *data
+        .get(*pos)
+        .ok_or(AsterixError::UnexpectedEOF { offset: *pos, expected: 1 })?;
+    *pos += 1;
+    if present == 0 {
+        return Ok(None);
+    }
+    Ok(Some(read_interned(data, pos, table)?))
+}
+
+fn write_string(out: &mut Vec<u8>, s: &str) {
+    write_varint(out, s.len() as u64);
+    out.extend_from_slice(s.as_bytes());
+}
+
+fn read_string(data: &[u8], pos: &mut usize) -> Result<String> {
+    let len = read_varint(data, pos)? as usize;
+    let bytes = read_bytes(data, pos, len)?;
+    std::str::from_utf8(bytes)
+        .map(str::to_string)
+        .map_err(|e| AsterixError::InvalidData(format!("invalid UTF-8 in packed record: {e}")))
+}
+
+fn read_bytes<'a>(data: &'a [u8], pos: &mut usize, len: usize) -> Result<&'a [u8]> {
+    let end = pos
+        .checked_add(len)
+        .ok_or(AsterixError::UnexpectedEOF { offset: *pos, expected: len })?;
+    let slice = data
+        .get(*pos..end)
+        .ok_or(AsterixError::UnexpectedEOF { offset: *pos, expected: len })?;
+    *pos = end;
+    Ok(slice)
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            break;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+fn read_varint(data: &[u8], pos: &mut usize) -> Result<u64> {
+    let mut result = 0u64;
+    let mut shift = 0u32;
+    loop {
+        let byte = *data
+            .get(*pos)
+            .ok_or(AsterixError::UnexpectedEOF { offset: *pos, expected: 1 })?;
+        *pos += 1;
+        result |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift >= 64 {
+            return Err(AsterixError::InvalidData(
+                "varint exceeds 64 bits".to_string(),
+            ));
+        }
+    }
+    Ok(result)
+}
+
+fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn zigzag_decode(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::FieldMap;
+
+    fn sample_record() -> AsterixRecord {
+        let mut fields = FieldMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(-7));
+        fields.insert("SIC".to_string(), ParsedValue::Unsigned(200));
+        fields.insert(
+            "CALLSIGN".to_string(),
+            ParsedValue::String("KLM123".to_string()),
+        );
+        fields.insert("VALID".to_string(), ParsedValue::Boolean(true));
+        fields.insert(
+            "RAWBYTES".to_string(),
+            ParsedValue::Bytes(vec![0xde, 0xad, 0xbe, 0xef]),
+        );
+        fields.insert(
+            "ALT".to_string(),
+            ParsedValue::Decimal {
+                raw: -100,
+                scale: 0.25,
+                unit: Some("FL".to_string()),
+            },
+        );
+        fields.insert(
+            "TRACKS".to_string(),
+            ParsedValue::Array(vec![ParsedValue::Integer(1), ParsedValue::Integer(2)]),
+        );
+        let mut nested = std::collections::BTreeMap::new();
+        nested.insert(
+            "SUB".to_string(),
+            Box::new(ParsedValue::Float(1.5)),
+        );
+        fields.insert("COMPOUND".to_string(), ParsedValue::Nested(nested));
+
+        let mut items = ItemMap::new();
+        items.insert(
+            "I062/010".to_string(),
+            DataItem {
+                description: Some("Data Source Identifier".to_string()),
+                fields,
+            },
+        );
+
+        AsterixRecord {
+            category: 62,
+            length: 42,
+            timestamp_ms: 1_700_000_000_000,
+            crc: 0xDEADBEEF,
+            hex_data: "3e002a".to_string(),
+            items,
+        }
+    }
+
+    #[test]
+    fn test_round_trip_preserves_all_fields() {
+        let record = sample_record();
+        let mut table = StringTable::new();
+        let packed = pack_record(&record, &mut table);
+        let unpacked = unpack_record(&packed, &table).unwrap();
+
+        assert_eq!(unpacked.category, record.category);
+        assert_eq!(unpacked.length, record.length);
+        assert_eq!(unpacked.timestamp_ms, record.timestamp_ms);
+        assert_eq!(unpacked.crc, record.crc);
+        assert_eq!(unpacked.hex_data, record.hex_data);
+        assert_eq!(
+            unpacked.items.keys().collect::<Vec<_>>(),
+            record.items.keys().collect::<Vec<_>>()
+        );
+        assert_eq!(unpacked["I062/010"].fields, record.items["I062/010"].fields);
+    }
+
+    #[test]
+    fn test_round_trip_preserves_field_iteration_order() {
+        let record = sample_record();
+        let mut table = StringTable::new();
+        let packed = pack_record(&record, &mut table);
+        let unpacked = unpack_record(&packed, &table).unwrap();
+
+        let original_order: Vec<&String> = record.items["I062/010"].fields.keys().collect();
+        let unpacked_order: Vec<&String> = unpacked.items["I062/010"].fields.keys().collect();
+        assert_eq!(original_order, unpacked_order);
+    }
+
+    #[test]
+    fn test_string_table_interns_repeated_item_ids_once() {
+        let record = sample_record();
+        let mut table = StringTable::new();
+        pack_record(&record, &mut table);
+        let len_after_first = table.len();
+        pack_record(&record, &mut table);
+        assert_eq!(table.len(), len_after_first);
+    }
+
+    #[test]
+    fn test_string_table_encode_decode_round_trips() {
+        let mut table = StringTable::new();
+        table.intern("I062/010");
+        table.intern("SAC");
+        table.intern("SIC");
+
+        let encoded = table.encode();
+        let decoded = StringTable::decode(&encoded).unwrap();
+
+        assert_eq!(decoded.len(), table.len());
+        assert_eq!(decoded.resolve(0).unwrap(), "I062/010");
+        assert_eq!(decoded.resolve(1).unwrap(), "SAC");
+        assert_eq!(decoded.resolve(2).unwrap(), "SIC");
+    }
+
+    #[test]
+    fn test_negative_integers_round_trip_via_zigzag() {
+        assert_eq!(zigzag_decode(zigzag_encode(-1)), -1);
+        assert_eq!(zigzag_decode(zigzag_encode(i64::MIN)), i64::MIN);
+        assert_eq!(zigzag_decode(zigzag_encode(i64::MAX)), i64::MAX);
+        assert_eq!(zigzag_decode(zigzag_encode(0)), 0);
+    }
+
+    #[test]
+    fn test_varint_round_trips_large_values() {
+        for value in [0u64, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            let mut out = Vec::new();
+            write_varint(&mut out, value);
+            let mut pos = 0;
+            assert_eq!(read_varint(&out, &mut pos).unwrap(), value);
+            assert_eq!(pos, out.len());
+        }
+    }
+
+    #[test]
+    fn test_unpack_record_rejects_truncated_data() {
+        let record = sample_record();
+        let mut table = StringTable::new();
+        let packed = pack_record(&record, &mut table);
+        let truncated = &packed[..packed.len() / 2];
+        assert!(unpack_record(truncated, &table).is_err());
+    }
+
+    #[test]
+    fn test_unpack_value_rejects_unknown_tag() {
+        let data = [255u8];
+        let mut pos = 0;
+        let table = StringTable::new();
+        let err = unpack_value(&data, &mut pos, &table).unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_unpack_value_array_with_maxed_out_count_fails_cleanly_instead_of_aborting() {
+        // tag::ARRAY followed by a maxed-out varint count (u64::MAX encoded
+        // in 10 bytes), with no element data behind it. Before this fix,
+        // `Vec::with_capacity(count as usize)` would try to allocate for
+        // ~u64::MAX elements straight away and abort the process; it should
+        // instead fail with a normal, catchable error as soon as an element
+        // read runs out of bytes.
+        let mut data = vec![tag::ARRAY];
+        write_varint(&mut data, u64::MAX);
+
+        let mut pos = 0;
+        let table = StringTable::new();
+        let err = unpack_value(&data, &mut pos, &table).unwrap_err();
+        assert!(matches!(err, AsterixError::UnexpectedEOF { .. }));
+    }
+}
@@ -0,0 +1,405 @@
+//! Multi-threaded parallel block parsing for throughput
+//!
+//! [`parse`](crate::parse) decodes an entire buffer in one call to the
+//! underlying C++ library. For a large capture file that's still single
+//! threaded work: the `streaming_parser` example's MB/s and records/sec
+//! figures are bounded by one core. [`parse_parallel`] instead scans `data`
+//! for block boundaries up front (`[cat][len_hi][len_lo]` + `len - 3` bytes
+//! of payload, decoded nowhere — just walked), partitions the resulting list
+//! of blocks evenly across `threads` worker threads, and has each thread call
+//! [`parse`](crate::parse) on its own blocks independently. The only state
+//! shared between threads is the category specification table loaded by
+//! [`crate::init_default`]/[`crate::init_config_dir`], which already lives in
+//! the C++ layer's global state and is read-only once parsing starts — no
+//! locking is needed for it on the Rust side.
+//!
+//! Because the boundary scan is O(blocks) and every block decodes
+//! independently of every other, this scales close to linearly with core
+//! count on multi-core hosts, at the cost of returning records in
+//! per-thread-chunk order rather than truly streaming them as they decode.
+//!
+//! [`parse_parallel_with_stats`] additionally folds each worker's decoded
+//! records into a [`StatsCollector`], so a caller benchmarking or sizing the
+//! thread pool gets aggregate throughput/per-category counts back without a
+//! second pass over the merged `Vec`. There's no separate Rust-side cache of
+//! parsed category UAP/definition structures to share across workers: as
+//! the module doc above already notes, that table lives once in the C++
+//! layer's global state (populated by [`crate::init_default`]/
+//! [`crate::init_config_dir`] before parsing starts), so every worker thread
+//! already reuses it for free, and an additional `Arc`-shared LRU on the
+//! Rust side would just cache a lookup this crate never performs.
+
+use std::thread;
+
+use crate::error::{AsterixError, Result};
+use crate::parser::parse;
+use crate::stats::{Stats, StatsCollector};
+use crate::types::{AsterixRecord, ParseOptions};
+
+/// Length of the category + 2-byte big-endian length header every block starts with.
+const HEADER_LEN: usize = 3;
+
+/// Parse `data` using up to `threads` worker threads instead of one.
+///
+/// `data` is first scanned (without decoding) for block boundaries, then
+/// split into `threads` contiguous groups of whole blocks; each group is
+/// decoded on its own thread via [`parse`](crate::parse), and the per-thread
+/// results are concatenated back in original file order. `threads` is
+/// clamped to at least 1 and at most the number of blocks found, so passing
+/// an oversized thread count doesn't spawn idle threads.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if `data` doesn't cleanly divide
+/// into complete blocks (a declared length smaller than the 3-byte header,
+/// or trailing bytes that don't form another full block), and propagates
+/// the first error any worker's [`parse`](crate::parse) call returns.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, parse_parallel, ParseOptions};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let data = std::fs::read("large_capture.asterix")?;
+/// let records = parse_parallel(&data, ParseOptions::default(), 8)?;
+/// println!("Decoded {} record(s)", records.len());
+/// # Ok(())
+/// # }
+/// ```
+/// Parse `data` using [`parse_parallel`], auto-sizing the worker count to
+/// [`thread::available_parallelism`] instead of requiring the caller to pick
+/// one.
+///
+/// Falls back to 1 worker (equivalent to calling [`parse`](crate::parse)
+/// directly, modulo the upfront boundary scan) if the platform can't report
+/// its parallelism.
+///
+/// # Errors
+///
+/// Same conditions as [`parse_parallel`].
+pub fn parse_parallel_auto(data: &[u8], options: ParseOptions) -> Result<Vec<AsterixRecord>> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    parse_parallel(data, options, threads)
+}
+
+pub fn parse_parallel(
+    data: &[u8],
+    options: ParseOptions,
+    threads: usize,
+) -> Result<Vec<AsterixRecord>> {
+    let blocks = scan_block_offsets(data)?;
+    if blocks.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let worker_count = threads.max(1).min(blocks.len());
+    let chunk_size = blocks.len().div_ceil(worker_count);
+
+    let chunk_results: Vec<Result<Vec<AsterixRecord>>> = thread::scope(|scope| {
+        let handles: Vec<_> = blocks
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let options = options.clone();
+                scope.spawn(move || decode_blocks(data, chunk, &options))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("parse_parallel worker thread panicked"))
+            .collect()
+    });
+
+    let mut records = Vec::with_capacity(blocks.len());
+    for chunk_result in chunk_results {
+        records.extend(chunk_result?);
+    }
+    Ok(records)
+}
+
+/// Parse `data` using [`parse_parallel`], auto-sizing the worker count to
+/// [`thread::available_parallelism`] instead of requiring the caller to pick
+/// one.
+///
+/// Falls back to 1 worker (equivalent to calling [`parse`](crate::parse)
+/// directly, modulo the upfront boundary scan) if the platform can't report
+/// its parallelism.
+///
+/// # Errors
+///
+/// Same conditions as [`parse_parallel`].
+pub fn parse_parallel_auto(data: &[u8], options: ParseOptions) -> Result<Vec<AsterixRecord>> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    parse_parallel(data, options, threads)
+}
+
+/// Like [`parse_parallel`], additionally returning aggregate [`Stats`] over
+/// every record decoded.
+///
+/// Each worker thread folds its own blocks into a private [`StatsCollector`]
+/// alongside decoding them; the per-thread [`Stats`] snapshots are then
+/// combined with [`Stats::merge`] once every thread finishes, the same path
+/// a caller collecting stats per chunk by hand would take.
+///
+/// # Errors
+///
+/// Same conditions as [`parse_parallel`].
+pub fn parse_parallel_with_stats(
+    data: &[u8],
+    options: ParseOptions,
+    threads: usize,
+) -> Result<(Vec<AsterixRecord>, Stats)> {
+    let blocks = scan_block_offsets(data)?;
+    if blocks.is_empty() {
+        return Ok((Vec::new(), StatsCollector::new().finish()));
+    }
+
+    let worker_count = threads.max(1).min(blocks.len());
+    let chunk_size = blocks.len().div_ceil(worker_count);
+
+    let chunk_results: Vec<Result<(Vec<AsterixRecord>, Stats)>> = thread::scope(|scope| {
+        let handles: Vec<_> = blocks
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let options = options.clone();
+                scope.spawn(move || decode_blocks_with_stats(data, chunk, &options))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .expect("parse_parallel_with_stats worker thread panicked")
+            })
+            .collect()
+    });
+
+    let mut records = Vec::with_capacity(blocks.len());
+    let mut stats: Option<Stats> = None;
+    for chunk_result in chunk_results {
+        let (chunk_records, chunk_stats) = chunk_result?;
+        records.extend(chunk_records);
+        stats = Some(match stats {
+            Some(acc) => acc.merge(&chunk_stats),
+            None => chunk_stats,
+        });
+    }
+
+    Ok((records, stats.unwrap_or_else(|| StatsCollector::new().finish())))
+}
+
+/// Decode one worker's assigned blocks, in order, into a single `Vec`
+fn decode_blocks(
+    data: &[u8],
+    blocks: &[(usize, usize)],
+    options: &ParseOptions,
+) -> Result<Vec<AsterixRecord>> {
+    let mut records = Vec::new();
+    for &(offset, len) in blocks {
+        records.extend(parse(&data[offset..offset + len], options.clone())?);
+    }
+    Ok(records)
+}
+
+/// Like [`decode_blocks`], also folding every decoded record into a
+/// [`StatsCollector`] as it goes.
+fn decode_blocks_with_stats(
+    data: &[u8],
+    blocks: &[(usize, usize)],
+    options: &ParseOptions,
+) -> Result<(Vec<AsterixRecord>, Stats)> {
+    let mut records = Vec::new();
+    let mut collector = StatsCollector::new();
+    for &(offset, len) in blocks {
+        let decoded = parse(&data[offset..offset + len], options.clone())?;
+        for record in &decoded {
+            collector.observe(record);
+        }
+        records.extend(decoded);
+    }
+    Ok((records, collector.finish()))
+}
+
+/// Scan `data` for ASTERIX block boundaries without decoding any of them.
+///
+/// Returns one `(offset, length)` pair per complete block found, in file
+/// order.
+fn scan_block_offsets(data: &[u8]) -> Result<Vec<(usize, usize)>> {
+    let mut offsets = Vec::new();
+    let mut offset = 0usize;
+
+    while offset < data.len() {
+        if offset + HEADER_LEN > data.len() {
+            return Err(AsterixError::InvalidData(format!(
+                "trailing {} byte(s) at offset {offset} don't form a complete block header",
+                data.len() - offset
+            )));
+        }
+
+        let declared_len = u16::from_be_bytes([data[offset + 1], data[offset + 2]]) as usize;
+        if declared_len < HEADER_LEN {
+            return Err(AsterixError::InvalidData(format!(
+                "declared block length {declared_len} at offset {offset} is smaller than the {HEADER_LEN}-byte header"
+            )));
+        }
+
+        if offset + declared_len > data.len() {
+            return Err(AsterixError::InvalidData(format!(
+                "block at offset {offset} declares length {declared_len} but only {} byte(s) remain",
+                data.len() - offset
+            )));
+        }
+
+        offsets.push((offset, declared_len));
+        offset += declared_len;
+    }
+
+    Ok(offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Once;
+
+    /// Global initialization for tests that call the C++ backend
+    static INIT: Once = Once::new();
+
+    fn ensure_initialized() {
+        INIT.call_once(|| {
+            let _ = crate::ffi::init_default();
+        });
+    }
+
+    fn test_block(category: u8) -> Vec<u8> {
+        vec![category, 0x00, 0x03]
+    }
+
+    #[test]
+    fn test_scan_block_offsets_empty() {
+        assert_eq!(scan_block_offsets(&[]).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_scan_block_offsets_finds_each_block() {
+        let mut data = test_block(48);
+        data.extend(test_block(62));
+        data.extend(test_block(21));
+
+        let offsets = scan_block_offsets(&data).unwrap();
+        assert_eq!(offsets, vec![(0, 3), (3, 3), (6, 3)]);
+    }
+
+    #[test]
+    fn test_scan_block_offsets_rejects_undersized_length() {
+        let data = [0x30, 0x00, 0x02];
+        let err = scan_block_offsets(&data).unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_scan_block_offsets_rejects_truncated_trailing_block() {
+        let mut data = test_block(48);
+        data.extend([0x30, 0x00]); // declares nothing; too short even for a header
+        let err = scan_block_offsets(&data).unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_scan_block_offsets_rejects_declared_length_past_end() {
+        let data = [0x30, 0x00, 0x0A, 0x01, 0x02]; // declares 10 bytes, only 5 present
+        let err = scan_block_offsets(&data).unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_parse_parallel_empty_data_yields_no_records() {
+        assert_eq!(
+            parse_parallel(&[], ParseOptions::default(), 4).unwrap(),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn test_parse_parallel_rejects_malformed_data() {
+        let data = [0x30, 0x00, 0x02];
+        let err = parse_parallel(&data, ParseOptions::default(), 4).unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_parse_parallel_decodes_many_blocks_across_threads() {
+        ensure_initialized();
+
+        let mut data = Vec::new();
+        for _ in 0..20 {
+            data.extend(test_block(48));
+        }
+
+        let serial = parse(&data, ParseOptions::default()).unwrap_or_default();
+        let parallel = parse_parallel(&data, ParseOptions::default(), 4).unwrap();
+        assert_eq!(serial.len(), parallel.len());
+    }
+
+    #[test]
+    fn test_parse_parallel_clamps_thread_count_to_block_count() {
+        ensure_initialized();
+
+        let data = test_block(48);
+        // More threads requested than there are blocks to hand out.
+        let result = parse_parallel(&data, ParseOptions::default(), 16);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_parallel_auto_matches_serial_record_count() {
+        ensure_initialized();
+
+        let mut data = Vec::new();
+        for _ in 0..20 {
+            data.extend(test_block(48));
+        }
+
+        let serial = parse(&data, ParseOptions::default()).unwrap_or_default();
+        let parallel = parse_parallel_auto(&data, ParseOptions::default()).unwrap();
+        assert_eq!(serial.len(), parallel.len());
+    }
+
+    #[test]
+    fn test_parse_parallel_auto_empty_data_yields_no_records() {
+        assert_eq!(
+            parse_parallel_auto(&[], ParseOptions::default()).unwrap(),
+            Vec::new()
+        );
+    }
+
+    #[test]
+    fn test_parse_parallel_with_stats_empty_data_yields_empty_stats() {
+        let (records, stats) =
+            parse_parallel_with_stats(&[], ParseOptions::default(), 4).unwrap();
+        assert!(records.is_empty());
+        assert_eq!(stats.record_count, 0);
+    }
+
+    #[test]
+    fn test_parse_parallel_with_stats_matches_serial_record_count_and_per_category_tally() {
+        ensure_initialized();
+
+        let mut data = Vec::new();
+        for _ in 0..20 {
+            data.extend(test_block(48));
+        }
+
+        let serial = parse(&data, ParseOptions::default()).unwrap_or_default();
+        let (parallel, stats) =
+            parse_parallel_with_stats(&data, ParseOptions::default(), 4).unwrap();
+
+        assert_eq!(serial.len(), parallel.len());
+        assert_eq!(stats.record_count, parallel.len());
+        let total_per_category: usize = stats.categories.values().map(|c| c.count).sum();
+        assert_eq!(total_per_category, parallel.len());
+    }
+}
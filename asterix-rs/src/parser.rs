@@ -3,9 +3,13 @@
 //! This module provides safe, Rust-idiomatic functions for parsing ASTERIX data.
 //! It wraps the unsafe FFI layer and manages memory, error handling, and data conversion.
 
-use crate::error::{AsterixError, Result};
+use crate::block::ParsedBlocks;
+use crate::error::{AsterixError, ParseFrame, Result, Severity};
 use crate::ffi;
-use crate::types::{AsterixRecord, DataItem, ParseOptions, ParseResult, ParsedValue};
+use crate::types::{
+    AsterixRecord, DataItem, FieldMap, ItemMap, MaybeParsed, ParseMode, ParseOptions,
+    ParseOutcome, ParseResult, ParsedValue, RecordError, TruncatedAt,
+};
 
 use std::collections::BTreeMap;
 
@@ -37,6 +41,7 @@ const MAX_BLOCKS_PER_CALL: usize = 10000; // Maximum blocks to parse in single c
 ///     verbose: true,
 ///     filter_category: Some(62),
 ///     max_records: Some(1000),
+///     ..Default::default()
 /// };
 ///
 /// let records = parse(&data, options)?;
@@ -85,12 +90,284 @@ pub fn parse(data: &[u8], options: ParseOptions) -> Result<Vec<AsterixRecord>> {
     }
 }
 
+/// Parse raw ASTERIX data block by block, tolerating individual block
+/// failures instead of aborting the whole input
+///
+/// Unlike [`parse`], which hands the entire buffer to the C++ parser in one
+/// call, `parse_resilient` scans `data` for block boundaries itself and
+/// decodes each block with its own call to [`parse`]. When a block fails
+/// (bad FSPEC, truncated data item, unknown category) and
+/// [`ParseOptions::continue_on_error`] is set, the block is skipped — not
+/// decoded, not retried — and recorded as a [`RecordError`] carrying its
+/// offset, header category, and the underlying error; the scan then resumes
+/// at the next block's declared offset. With `continue_on_error` unset, the
+/// first block failure is returned immediately via `Err`, same as [`parse`].
+/// When [`ParseOptions::strict`] is also set, every failure's
+/// [`AsterixError::severity`] is promoted to [`Severity::Fatal`] (see
+/// [`Severity::effective`]), so unless [`ParseOptions::resync`] is set the
+/// scan always stops at the first failure — `strict` overrides
+/// `continue_on_error` for conformance testing, but not `resync` (see below).
+///
+/// Each block is decoded from its own sub-slice of `data`, so an underlying
+/// error's own offset (e.g. [`AsterixError::ParseError`]'s) starts out
+/// relative to that sub-slice; `RecordError::error` is rebased (via
+/// [`AsterixError::rebased`]) back onto `data` before being recorded, so
+/// [`Diagnostic::render_diagnostic`](crate::Diagnostic::render_diagnostic)
+/// called with the original `data` buffer points at the right byte.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, parse_resilient, ParseOptions};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let data = std::fs::read("noisy_capture.asterix")?;
+/// let options = ParseOptions {
+///     continue_on_error: true,
+///     ..Default::default()
+/// };
+/// let outcome = parse_resilient(&data, options)?;
+/// println!("{} record(s), {} block(s) skipped", outcome.records.len(), outcome.failures.len());
+/// for failure in &outcome.failures {
+///     eprintln!("offset {}: category {}: {}", failure.offset, failure.category, failure.error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if `data` doesn't cleanly divide
+/// into complete blocks (a declared length smaller than the 3-byte header,
+/// or trailing bytes that don't form another full block), and, when
+/// `continue_on_error` is unset, the first error any individual block's
+/// [`parse`] call returns. When [`ParseOptions::resync`] is set, a malformed
+/// header or a failed block no longer returns an error as long as another
+/// plausible header can be found further into `data`; it only returns an
+/// error once resynchronization itself fails to find one.
+pub fn parse_resilient(data: &[u8], options: ParseOptions) -> Result<ParseOutcome> {
+    if data.is_empty() {
+        return Err(AsterixError::InvalidData("Empty input data".to_string()));
+    }
+
+    let mut outcome = ParseOutcome::default();
+    let mut offset = 0usize;
+
+    while offset < data.len() {
+        let header = read_block_header(data, offset);
+
+        let (category, declared_len) = match header {
+            Some(header) => header,
+            None if options.resync => {
+                match find_next_plausible_header(data, offset + 1, options.resync_categories.as_deref()) {
+                    Some(next_offset) => {
+                        outcome.resynced_bytes += next_offset - offset;
+                        offset = next_offset;
+                        continue;
+                    }
+                    None => {
+                        return Err(AsterixError::InvalidData(format!(
+                            "no plausible block header found after offset {offset}; resync exhausted"
+                        )));
+                    }
+                }
+            }
+            None => {
+                return Err(malformed_header_error(data, offset));
+            }
+        };
+
+        let block = &data[offset..offset + declared_len];
+        match parse(block, options.clone()) {
+            Ok(decoded) => outcome.records.extend(decoded),
+            Err(error)
+                if options.continue_on_error
+                    && error.severity().effective(options.strict) != Severity::Fatal =>
+            {
+                // `error`'s own offset (if any) is relative to `block`, not
+                // `data` — rebase it so it lines up with a diagnostic
+                // rendered against the whole input.
+                outcome.failures.push(RecordError {
+                    offset,
+                    category,
+                    error: error.rebased(offset),
+                });
+            }
+            Err(error) if options.resync => {
+                match find_next_plausible_header(data, offset + 1, options.resync_categories.as_deref()) {
+                    Some(next_offset) => {
+                        outcome.failures.push(RecordError {
+                            offset,
+                            category,
+                            error: error.rebased(offset),
+                        });
+                        outcome.resynced_bytes += next_offset - offset;
+                        offset = next_offset;
+                        continue;
+                    }
+                    None => return Err(error),
+                }
+            }
+            Err(error) => return Err(error),
+        }
+
+        offset += declared_len;
+    }
+
+    Ok(outcome)
+}
+
+/// Read a block header (`category`, `declared_len`) at `offset`, or `None`
+/// if `offset` doesn't start a complete, plausible header (too few bytes
+/// remain for the 3-byte header itself, a declared length smaller than that
+/// header, or a declared length that overruns `data`).
+fn read_block_header(data: &[u8], offset: usize) -> Option<(u8, usize)> {
+    if offset + 3 > data.len() {
+        return None;
+    }
+
+    let category = data[offset];
+    let declared_len = u16::from_be_bytes([data[offset + 1], data[offset + 2]]) as usize;
+
+    if declared_len < 3 || offset + declared_len > data.len() {
+        return None;
+    }
+
+    Some((category, declared_len))
+}
+
+/// The [`AsterixError::InvalidData`] [`parse_resilient`] returns for a
+/// header [`read_block_header`] rejected at `offset`, worded the same way
+/// whichever check failed.
+fn malformed_header_error(data: &[u8], offset: usize) -> AsterixError {
+    if offset + 3 > data.len() {
+        return AsterixError::InvalidData(format!(
+            "trailing {} byte(s) at offset {offset} don't form a complete block header",
+            data.len() - offset
+        ));
+    }
+
+    let declared_len = u16::from_be_bytes([data[offset + 1], data[offset + 2]]) as usize;
+    if declared_len < 3 {
+        return AsterixError::InvalidData(format!(
+            "declared block length {declared_len} at offset {offset} is smaller than the 3-byte header"
+        ));
+    }
+
+    AsterixError::InvalidData(format!(
+        "block at offset {offset} declares length {declared_len} but only {} byte(s) remain",
+        data.len() - offset
+    ))
+}
+
+/// Scan `data` starting at `from` for the next offset that looks like a
+/// plausible block header: a category in `allowed_categories` (any category
+/// is plausible when `None`) whose declared length both fits within `data`
+/// and is itself followed by another plausible-looking header (or the end
+/// of `data`).
+///
+/// Used by [`parse_resilient`] when [`ParseOptions::resync`] is set, to
+/// resume decoding after a corrupt or unrecognized block instead of
+/// aborting.
+fn find_next_plausible_header(
+    data: &[u8],
+    from: usize,
+    allowed_categories: Option<&[u8]>,
+) -> Option<usize> {
+    for candidate in from..data.len() {
+        let Some((category, declared_len)) = read_block_header(data, candidate) else {
+            continue;
+        };
+
+        if let Some(allowed) = allowed_categories {
+            if !allowed.contains(&category) {
+                continue;
+            }
+        }
+
+        let next = candidate + declared_len;
+        let looks_plausible = next == data.len()
+            || read_block_header(data, next).is_some_and(|(next_category, _)| {
+                allowed_categories.map_or(true, |allowed| allowed.contains(&next_category))
+            });
+
+        if looks_plausible {
+            return Some(candidate);
+        }
+    }
+
+    None
+}
+
+/// Parse raw ASTERIX data, keeping the C++-backed result alive for low-level,
+/// block-at-a-time access instead of eagerly converting every block into an
+/// [`AsterixRecord`].
+///
+/// Use this when only a handful of blocks' hex/JSON/text representations are
+/// needed (e.g. re-emitting a filtered subset) and the JSON-to-`BTreeMap`
+/// decoding [`parse`] performs for every block would be wasted work. See
+/// [`ParsedBlocks`] and [`crate::block::DataBlock`].
+///
+/// # Errors
+///
+/// Same conditions as [`parse`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, parse_blocks, ParseOptions};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let data = std::fs::read("sample.asterix")?;
+/// let blocks = parse_blocks(&data, ParseOptions::default())?;
+/// for block in blocks.iter() {
+///     println!("Category {}: {}", block.category(), block.hex());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_blocks(data: &[u8], options: ParseOptions) -> Result<ParsedBlocks> {
+    if data.is_empty() {
+        return Err(AsterixError::InvalidData("Empty input data".to_string()));
+    }
+
+    if data.len() > MAX_ASTERIX_MESSAGE_SIZE {
+        return Err(AsterixError::InvalidData(format!(
+            "Input data too large: {} bytes (maximum {} bytes)",
+            data.len(),
+            MAX_ASTERIX_MESSAGE_SIZE
+        )));
+    }
+
+    unsafe {
+        let data_ptr = ffi::ffi::asterix_parse(data.as_ptr(), data.len(), options.verbose);
+
+        if data_ptr.is_null() {
+            return Err(AsterixError::NullPointer(
+                "C++ parser returned null (check if ASTERIX is initialized)".to_string(),
+            ));
+        }
+
+        Ok(ParsedBlocks::from_raw(data_ptr))
+    }
+}
+
 /// Parse ASTERIX data with offset and block count for incremental parsing
 ///
 /// This function allows parsing large data streams incrementally, which is useful
 /// for processing live data feeds or very large files without loading everything
 /// into memory at once.
 ///
+/// `data` is still a single in-memory buffer capped at
+/// [`MAX_ASTERIX_MESSAGE_SIZE`] per call, and `remaining_blocks` is only a
+/// rough `bytes / 32` estimate, not an exact count. For a true streaming
+/// source (an `io::Read`, or a multi-gigabyte file read incrementally) with
+/// exact byte accounting and no whole-buffer size cap, use
+/// [`crate::AsterixReader`] instead, which frames and decodes one block at a
+/// time directly off the source. For an already-fully-buffered slice,
+/// [`records_iter`] gives the same exact per-block accounting without this
+/// function's offset/`remaining_blocks` bookkeeping.
+///
 /// # Arguments
 ///
 /// * `data` - Complete data buffer
@@ -151,6 +428,7 @@ pub fn parse_with_offset(
                 offset,
                 u32::MAX
             ),
+            context: None,
         });
     }
 
@@ -158,6 +436,7 @@ pub fn parse_with_offset(
         return Err(AsterixError::ParseError {
             offset,
             message: format!("Offset {} exceeds data length {}", offset, data.len()),
+            context: None,
         });
     }
 
@@ -208,8 +487,10 @@ pub fn parse_with_offset(
         let remaining_blocks = if bytes_consumed >= data.len() {
             0
         } else {
-            // Estimate remaining blocks (rough calculation)
-            (data.len() - bytes_consumed) / 32 // Assume avg 32 bytes per block
+            // Rough estimate only (assumes avg 32 bytes/block) — callers that
+            // need an exact count should walk `records_iter`/`AsterixReader`
+            // instead, which derive it from each block's own length field.
+            (data.len() - bytes_consumed) / 32
         };
 
         ffi::ffi::asterix_free_data(data_ptr);
@@ -222,197 +503,1165 @@ pub fn parse_with_offset(
     }
 }
 
-/// Convert C++ AsterixData to Rust structures
+/// Parse ASTERIX data block-by-block, salvaging truncated blocks in
+/// [`ParseMode::Lenient`] instead of discarding them.
 ///
-/// This internal function marshals data from the C++ side to Rust-native types.
-/// It handles all memory management and type conversions.
-unsafe fn convert_asterix_data(
-    data_ptr: *mut ffi::ffi::AsterixDataWrapper,
-    options: &ParseOptions,
-) -> Result<Vec<AsterixRecord>> {
-    let mut records = Vec::new();
+/// Unlike [`parse`], which hands the whole buffer to the C++ decoder in one call,
+/// this function walks the buffer one block (category + 2-byte length + payload)
+/// at a time. When a block's declared length overstates the bytes actually
+/// available:
+///
+/// - [`ParseMode::Strict`] (the default) returns [`AsterixError::UnexpectedEOF`],
+///   matching `parse`'s existing behavior.
+/// - [`ParseMode::Lenient`] decodes whatever fits in the available bytes and
+///   returns a [`MaybeParsed::Incomplete`] carrying the partial record and a
+///   [`TruncatedAt`] marker, instead of dropping the block entirely.
+///
+/// # Errors
+///
+/// Returns an error if `data` is empty, a block's declared length is smaller
+/// than the 3-byte header, or (in `Strict` mode) a block is truncated.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, ParseOptions};
+/// # use asterix::parser::parse_with_mode;
+/// # use asterix::types::ParseMode;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let data = std::fs::read("clipped_capture.asterix")?;
+/// let options = ParseOptions { mode: ParseMode::Lenient, ..Default::default() };
+/// for result in parse_with_mode(&data, options)? {
+///     if !result.is_complete() {
+///         eprintln!("salvaged a truncated record: {:?}", result.record().category);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_with_mode(data: &[u8], options: ParseOptions) -> Result<Vec<MaybeParsed>> {
+    if data.is_empty() {
+        return Err(AsterixError::InvalidData("Empty input data".to_string()));
+    }
 
-    let block_count = ffi::ffi::asterix_data_block_count(data_ptr);
+    let mut results = Vec::new();
+    let mut offset = 0usize;
 
-    for i in 0..block_count {
-        let block_ptr = ffi::ffi::asterix_get_data_block(data_ptr, i);
+    while offset + 3 <= data.len() {
+        let category = data[offset];
+        let declared_len = u16::from_be_bytes([data[offset + 1], data[offset + 2]]) as usize;
 
-        if block_ptr.is_null() {
-            continue;
+        if declared_len < 3 {
+            return Err(AsterixError::ParseError {
+                offset,
+                message: format!(
+                    "declared block length {declared_len} is smaller than the 3-byte header"
+                ),
+                context: Some(ParseFrame::category(category)),
+            });
         }
 
-        // Apply category filter if specified
-        let category = ffi::ffi::asterix_block_category(block_ptr);
-        if let Some(filter_cat) = options.filter_category {
-            if category != filter_cat {
-                continue;
+        let available = data.len() - offset;
+
+        if declared_len > available {
+            match options.mode {
+                ParseMode::Strict => {
+                    return Err(AsterixError::UnexpectedEOF {
+                        offset,
+                        expected: declared_len - available,
+                    });
+                }
+                ParseMode::Lenient => {
+                    let partial_opts = ParseOptions {
+                        mode: ParseMode::Strict,
+                        ..options.clone()
+                    };
+
+                    // `data[offset..]` is `available` bytes long, but its own
+                    // embedded header still declares the original
+                    // `declared_len` (necessarily larger, or we wouldn't be
+                    // here) -- handing that straight to the C++ decoder would
+                    // violate the `declared_len <= available` invariant every
+                    // other call site in this crate upholds before slicing a
+                    // block (`read_block_header`, `records_iter`, the
+                    // `declared_len > available` check just above). Rewrite a
+                    // truncated copy's header to the length actually present
+                    // so the buffer is internally consistent before it's
+                    // handed to the FFI.
+                    let mut truncated = data[offset..].to_vec();
+                    let available_len = u16::try_from(available).unwrap_or(u16::MAX);
+                    truncated[1..3].copy_from_slice(&available_len.to_be_bytes());
+
+                    let record = parse(&truncated, partial_opts)
+                        .ok()
+                        .and_then(|mut records| records.pop())
+                        .unwrap_or(AsterixRecord {
+                            category,
+                            length: declared_len as u32,
+                            ..Default::default()
+                        });
+
+                    results.push(MaybeParsed::Incomplete {
+                        record,
+                        truncated_at: TruncatedAt {
+                            offset: offset + available,
+                            item_id: None,
+                        },
+                    });
+                    break;
+                }
             }
         }
 
-        let record = convert_data_block(block_ptr)?;
+        let block = &data[offset..offset + declared_len];
+        let block_opts = options.clone();
+        if let Some(record) = parse(block, block_opts)?.into_iter().next() {
+            let matches_filter = options
+                .filter_category
+                .map(|filter_cat| record.category == filter_cat)
+                .unwrap_or(true);
+            if matches_filter {
+                results.push(MaybeParsed::Complete(record));
+            }
+        }
 
-        records.push(record);
+        offset += declared_len;
 
-        // Check max records limit
         if let Some(max) = options.max_records {
-            if records.len() >= max {
+            if results.len() >= max {
                 break;
             }
         }
     }
 
-    Ok(records)
+    Ok(results)
 }
 
-/// Convert a single C++ DataBlock to Rust AsterixRecord
-unsafe fn convert_data_block(
-    block_ptr: *const ffi::ffi::DataBlockWrapper,
-) -> Result<AsterixRecord> {
-    // HIGH-003 FIX: Validate block_ptr is not null
-    if block_ptr.is_null() {
-        return Err(AsterixError::NullPointer(
-            "C++ returned null data block".to_string(),
-        ));
-    }
-
-    let category = ffi::ffi::asterix_block_category(block_ptr);
-    let length = ffi::ffi::asterix_block_length(block_ptr);
-    let timestamp_ms = ffi::ffi::asterix_block_timestamp_ms(block_ptr);
-    let crc = ffi::ffi::asterix_block_crc(block_ptr);
-
-    // Get hex data
-    let hex_ptr = ffi::ffi::asterix_block_hex_data(block_ptr);
-    let hex_data = if !hex_ptr.is_null() {
-        std::ffi::CStr::from_ptr(hex_ptr as *const std::os::raw::c_char)
-            .to_string_lossy()
-            .to_string()
-    } else {
-        String::new()
-    };
-
-    // Parse JSON to extract items
-    let json_ptr = ffi::ffi::asterix_block_to_json(block_ptr);
-    let items = if !json_ptr.is_null() {
-        let json_str = ffi::c_string_to_rust(json_ptr)?;
-        parse_items_from_json(&json_str)?
-    } else {
-        BTreeMap::new()
-    };
-
-    Ok(AsterixRecord {
-        category,
-        length,
-        timestamp_ms,
-        crc,
-        hex_data,
-        items,
-    })
+/// Borrowing iterator over the records in an ASTERIX buffer.
+///
+/// Unlike [`parse`], which decodes the whole buffer in one FFI call and returns a
+/// fully materialized `Vec<AsterixRecord>`, `RecordsIter` reads one block's
+/// category+length header at a time, decodes only that block, and advances its
+/// internal cursor — so a caller can process a multi-gigabyte recording with
+/// bounded memory. Conceptually, `parse(data, opts)` is equivalent to
+/// `records_iter(data, opts).collect()`.
+///
+/// `ParseOptions::filter_category` skips non-matching blocks without yielding
+/// them, and `ParseOptions::max_records` ends iteration early once enough
+/// records have been yielded.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, ParseOptions};
+/// # use asterix::parser::records_iter;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let data = std::fs::read("large_recording.asterix")?;
+/// for record in records_iter(&data, ParseOptions::default()) {
+///     let record = record?;
+///     println!("Category {}: {} items", record.category, record.items.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct RecordsIter<'a> {
+    data: &'a [u8],
+    offset: usize,
+    options: ParseOptions,
+    emitted: usize,
+    exhausted: bool,
 }
 
-/// Parse data items from JSON representation
+/// Create a borrowing, block-at-a-time iterator over `data`.
 ///
-/// This is a temporary implementation that parses the JSON output from C++.
-/// Future versions should use direct C++ struct access for better performance.
-fn parse_items_from_json(json_str: &str) -> Result<BTreeMap<String, DataItem>> {
-    #[cfg(feature = "serde")]
-    {
-        use serde_json::Value;
+/// See [`RecordsIter`] for details.
+pub fn records_iter(data: &[u8], options: ParseOptions) -> RecordsIter<'_> {
+    RecordsIter {
+        data,
+        offset: 0,
+        options,
+        emitted: 0,
+        exhausted: data.is_empty(),
+    }
+}
 
-        // Handle empty or whitespace-only JSON (indicates no data was parsed by C++)
-        let trimmed = json_str.trim();
-        if trimmed.is_empty() || trimmed == "{}" || trimmed == "[]" {
-            return Ok(BTreeMap::new());
+impl<'a> Iterator for RecordsIter<'a> {
+    type Item = Result<AsterixRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
         }
 
-        // MEDIUM-006 FIX: Handle newline-delimited JSON (NDJSON) from C++
-        // C++ may return multiple JSON objects separated by newlines
-        // Parse only the first line/object if multiple exist
-        let json_to_parse = if trimmed.contains('\n') {
-            // Multiple lines - take only the first JSON object
-            trimmed.lines().next().unwrap_or(trimmed)
-        } else {
-            trimmed
-        };
+        if let Some(max) = self.options.max_records {
+            if self.emitted >= max {
+                self.exhausted = true;
+                return None;
+            }
+        }
 
-        // MEDIUM-006 FIX: Validate JSON structure before parsing
-        // Check for obviously malformed JSON (unbalanced braces)
-        let brace_count = json_to_parse
-            .chars()
-            .fold((0i32, 0i32), |(open, close), c| match c {
-                '{' => (open + 1, close),
-                '}' => (open, close + 1),
-                _ => (open, close),
-            });
+        loop {
+            if self.offset + 3 > self.data.len() {
+                self.exhausted = true;
+                return None;
+            }
 
-        if brace_count.0 != brace_count.1 {
-            return Err(AsterixError::InvalidData(format!(
-                "Malformed JSON from C++: unbalanced braces ({} open, {} close)",
-                brace_count.0, brace_count.1
-            )));
-        }
+            let category = self.data[self.offset];
+            let declared_len =
+                u16::from_be_bytes([self.data[self.offset + 1], self.data[self.offset + 2]])
+                    as usize;
+
+            if declared_len < 3 {
+                self.exhausted = true;
+                return Some(Err(AsterixError::ParseError {
+                    offset: self.offset,
+                    message: format!(
+                        "declared block length {declared_len} is smaller than the 3-byte header"
+                    ),
+                    context: Some(ParseFrame::category(category)),
+                }));
+            }
 
-        // MEDIUM-006 FIX: Parse JSON and return proper error on failure
-        // Do not silently swallow JSON parsing errors
-        let value: Value = serde_json::from_str(json_to_parse).map_err(|e| {
-            AsterixError::InvalidData(format!(
-                "Failed to parse JSON from C++: {e}\nJSON snippet: {}",
-                &json_to_parse.chars().take(100).collect::<String>()
-            ))
-        })?;
+            let available = self.data.len() - self.offset;
+            if declared_len > available {
+                self.exhausted = true;
+                return Some(Err(AsterixError::UnexpectedEOF {
+                    offset: self.offset,
+                    expected: declared_len - available,
+                }));
+            }
 
-        let mut items = BTreeMap::new();
+            let block = &self.data[self.offset..self.offset + declared_len];
+            self.offset += declared_len;
 
-        if let Some(obj) = value.as_object() {
-            for (key, val) in obj {
-                // Skip metadata fields
-                if key == "id"
-                    || key == "cat"
-                    || key == "category"
-                    || key == "length"
-                    || key == "timestamp"
-                    || key == "crc"
-                    || key == "hexdata"
-                {
+            if let Some(filter_cat) = self.options.filter_category {
+                if category != filter_cat {
                     continue;
                 }
+            }
 
-                // The actual ASTERIX items are nested under a key like "CAT048"
-                // Check if this is a category object containing items
-                if key.starts_with("CAT") && val.is_object() {
-                    // Extract items from the nested category object
-                    if let Some(cat_obj) = val.as_object() {
-                        for (item_key, item_val) in cat_obj {
-                            let data_item = json_value_to_data_item(item_val)?;
-                            items.insert(item_key.clone(), data_item);
-                        }
-                    }
-                } else {
-                    // For backward compatibility, also handle top-level items
-                    let data_item = json_value_to_data_item(val)?;
-                    items.insert(key.clone(), data_item);
+            let record = match parse(block, self.options.clone()) {
+                Ok(mut records) => records.pop(),
+                Err(e) => {
+                    self.exhausted = true;
+                    return Some(Err(e));
                 }
+            };
+
+            if let Some(record) = record {
+                self.emitted += 1;
+                return Some(Ok(record));
             }
+            // Block decoded to zero records (e.g. filtered out by the C++ side); keep scanning.
         }
-
-        Ok(items)
-    }
-
-    #[cfg(not(feature = "serde"))]
-    {
-        // Without serde, we can't parse JSON - return empty items
-        let _ = json_str;
-        Ok(BTreeMap::new())
     }
 }
 
-/// Convert serde_json::Value to DataItem
+/// Decode `data` one block at a time, handing each record to `visit` by
+/// reference instead of collecting them into a `Vec`
+///
+/// Built directly on [`records_iter`]: every record it yields is passed to
+/// `visit` and then dropped, rather than pushed into an accumulating `Vec`
+/// the way [`parse`] does. For a caller that only needs to tally or forward
+/// records (the `streaming_parser` example's `category_counts`, for
+/// instance) this avoids ever materializing the whole input's records at
+/// once — the only per-record allocation left is the one [`parse`] itself
+/// does while decoding that single block.
+///
+/// `visit` returns [`ControlFlow::Break`] to stop iterating early (useful
+/// together with `max_records`/`filter_category`, which already bound how
+/// much is decoded) or [`ControlFlow::Continue`] to keep going.
+///
+/// # Errors
+///
+/// Returns the first error encountered decoding any block, same as
+/// [`records_iter`].
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, parser::parse_each, ParseOptions};
+/// # use std::ops::ControlFlow;
+/// # use std::collections::HashMap;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let data = std::fs::read("large_capture.asterix")?;
+/// let mut category_counts: HashMap<u8, usize> = HashMap::new();
+/// parse_each(&data, ParseOptions::default(), |record| {
+///     *category_counts.entry(record.category).or_insert(0) += 1;
+///     ControlFlow::Continue(())
+/// })?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_each<F>(data: &[u8], options: ParseOptions, mut visit: F) -> Result<()>
+where
+    F: FnMut(&AsterixRecord) -> std::ops::ControlFlow<()>,
+{
+    for record in records_iter(data, options) {
+        let record = record?;
+        if visit(&record).is_break() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Incremental parser that accumulates bytes fed from a live source (TCP,
+/// UDP, CAN, ...) and decodes as many complete ASTERIX blocks as the
+/// accumulated buffer allows
+///
+/// Unlike [`parse`] and [`records_iter`], which require the whole input
+/// up front, `StreamParser` is built for feeds that arrive in arbitrary
+/// chunks that don't align with block boundaries — including a Zenoh
+/// subscriber whose samples don't correspond 1:1 with ASTERIX blocks: push
+/// bytes with [`feed`], then call [`poll`] to decode whatever complete
+/// blocks are now available. A block that straddles two `feed` calls is
+/// never lost — its bytes stay in the internal buffer until a later `feed`
+/// completes it. A block's declared length is always at most 65535 bytes
+/// (it's stored as the header's 16-bit length field), so a block this
+/// buffers while waiting to complete is inherently bounded the same way a
+/// single UDP datagram's worth of input is elsewhere in this crate.
+///
+/// If [`ParseOptions::resync`] is set, a declared length smaller than the
+/// 3-byte header, or a complete block that fails to decode, no longer ends
+/// the stream — `poll` resynchronizes onto the next plausible category byte
+/// the same way [`parse_resilient`] does for a fully-buffered input, and
+/// decoding continues from there instead of getting permanently stuck on a
+/// single corrupt length field.
+///
+/// [`feed`]: StreamParser::feed
+/// [`poll`]: StreamParser::poll
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, ParseOptions};
+/// # use asterix::parser::StreamParser;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let mut stream = StreamParser::new(ParseOptions::default());
+///
+/// // Bytes arrive in arbitrary chunks, e.g. one per `read()` off a socket.
+/// stream.feed(&[0x30, 0x00]); // a block header split across two chunks
+/// stream.feed(&[0x10, 0x01, 0x02, 0x03, 0x04, 0x05]);
+///
+/// let result = stream.poll()?;
+/// for record in result.records {
+///     println!("Category {}: {} items", record.category, record.items.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct StreamParser {
+    buffer: Vec<u8>,
+    options: ParseOptions,
+}
+
+impl StreamParser {
+    /// Create a new stream parser that decodes every fed block with `options`
+    pub fn new(options: ParseOptions) -> Self {
+        Self {
+            buffer: Vec::new(),
+            options,
+        }
+    }
+
+    /// Push more bytes onto the internal accumulation buffer
+    ///
+    /// `data` need not align with block boundaries; it's simply appended.
+    pub fn feed(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Decode as many complete blocks as the accumulated buffer currently
+    /// allows
+    ///
+    /// Honors [`ParseOptions::filter_category`] (non-matching blocks are
+    /// skipped without being decoded) and [`ParseOptions::max_records`]
+    /// (decoding stops early once this call has produced that many records,
+    /// leaving the rest of the buffer for the next `poll`).
+    /// [`ParseResult::bytes_consumed`] is the number of bytes this call
+    /// drained from the internal buffer; any trailing partial block is
+    /// retained for a later `feed`/`poll`. [`ParseResult::remaining_blocks`]
+    /// counts whole blocks still sitting in the buffer afterward (e.g. left
+    /// over because `max_records` was reached).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::ParseError`] if a block header declares a
+    /// length smaller than the 3-byte header it's part of, and any error
+    /// [`parse`] itself returns while decoding a complete block — unless
+    /// [`ParseOptions::resync`] is set, in which case either condition
+    /// instead resynchronizes onto the next plausible category byte (see
+    /// [`parse_resilient`]) and decoding continues; it's only returned once
+    /// resynchronization itself fails to find a plausible header in the
+    /// bytes buffered so far.
+    pub fn poll(&mut self) -> Result<ParseResult> {
+        let mut records = Vec::new();
+        let mut offset = 0usize;
+
+        loop {
+            if let Some(max) = self.options.max_records {
+                if records.len() >= max {
+                    break;
+                }
+            }
+
+            if offset + 3 > self.buffer.len() {
+                break;
+            }
+
+            let category = self.buffer[offset];
+            let declared_len =
+                u16::from_be_bytes([self.buffer[offset + 1], self.buffer[offset + 2]]) as usize;
+
+            if declared_len < 3 {
+                if let Some(resync_offset) = self.try_resync(offset) {
+                    offset = resync_offset;
+                    continue;
+                }
+                return Err(AsterixError::ParseError {
+                    offset,
+                    message: format!(
+                        "declared block length {declared_len} is smaller than the 3-byte header"
+                    ),
+                    context: Some(ParseFrame::category(category)),
+                });
+            }
+
+            let available = self.buffer.len() - offset;
+            if declared_len > available {
+                // Partial block; wait for more bytes via a later `feed`. A
+                // declared length is at most 65535 (a 16-bit header field),
+                // so this never waits indefinitely on a corrupt length the
+                // way a `resync` resynchronization step would need to.
+                break;
+            }
+
+            let block = &self.buffer[offset..offset + declared_len];
+
+            if let Some(filter_cat) = self.options.filter_category {
+                if category != filter_cat {
+                    offset += declared_len;
+                    continue;
+                }
+            }
+
+            match parse(block, self.options.clone()) {
+                Ok(decoded) => {
+                    offset += declared_len;
+                    if let Some(record) = decoded.into_iter().next() {
+                        records.push(record);
+                    }
+                }
+                Err(error) => {
+                    if let Some(resync_offset) = self.try_resync(offset) {
+                        offset = resync_offset;
+                        continue;
+                    }
+                    return Err(error);
+                }
+            }
+        }
+
+        let bytes_consumed = offset;
+        self.buffer.drain(0..bytes_consumed);
+        let remaining_blocks = count_complete_blocks(&self.buffer);
+
+        Ok(ParseResult {
+            records,
+            bytes_consumed,
+            remaining_blocks,
+        })
+    }
+
+    /// If [`ParseOptions::resync`] is set, scan forward from `offset + 1`
+    /// for the next plausible block header (see [`find_next_plausible_header`])
+    /// and return it. Returns `None` (leaving the buffer untouched for a
+    /// later `feed`) when resync is off, or no plausible header is found
+    /// yet within the currently buffered bytes.
+    fn try_resync(&self, offset: usize) -> Option<usize> {
+        if !self.options.resync {
+            return None;
+        }
+        find_next_plausible_header(&self.buffer, offset + 1, self.options.resync_categories.as_deref())
+    }
+
+    /// Alias for [`feed`](Self::feed), for callers following a `push`/`pull`
+    /// naming convention rather than this type's `feed`/`poll` one.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.feed(chunk)
+    }
+
+    /// Decode and return a single record, or `None` if the buffer doesn't
+    /// yet hold a complete one
+    ///
+    /// A record-at-a-time alternative to [`poll`](Self::poll) for callers
+    /// that would rather pull one [`AsterixRecord`] per call (e.g. feeding
+    /// an `Iterator`-style consumer) than handle a batch [`ParseResult`].
+    /// This type doesn't track how many records a caller has pulled across
+    /// earlier `next_record`/`poll` calls, so unlike `poll`'s per-call
+    /// ceiling, [`ParseOptions::max_records`] here is only checked for the
+    /// `Some(0)` case — "never produce a record" — which short-circuits to
+    /// `None` immediately rather than decoding and discarding one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors [`poll`](Self::poll) does.
+    pub fn next_record(&mut self) -> Option<Result<AsterixRecord>> {
+        if self.options.max_records == Some(0) {
+            return None;
+        }
+
+        let saved_max_records = self.options.max_records;
+        self.options.max_records = Some(1);
+        let result = self.poll();
+        self.options.max_records = saved_max_records;
+
+        match result {
+            Ok(mut parse_result) => parse_result.records.pop().map(Ok),
+            Err(e) => Some(Err(e)),
+        }
+    }
+
+    /// Bytes currently buffered and not yet decoded into a complete block
+    ///
+    /// A caller driving a socket can poll this after each `feed`/`push` to
+    /// apply backpressure (e.g. stop reading until [`poll`](Self::poll)/
+    /// [`next_record`](Self::next_record) has drained it back down) instead
+    /// of letting an unresponsive decoder accumulate an unbounded backlog.
+    pub fn pending_bytes(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+/// Count whole, fully-available blocks in `data` without decoding them
+///
+/// Used by [`StreamParser::poll`] to report [`ParseResult::remaining_blocks`]
+/// after draining the blocks it just decoded; stops at the first malformed
+/// or partial header the same way [`StreamParser::poll`] does, since those
+/// bytes aren't a complete block either.
+pub(crate) fn count_complete_blocks(data: &[u8]) -> usize {
+    let mut offset = 0usize;
+    let mut count = 0usize;
+
+    while offset + 3 <= data.len() {
+        let declared_len = u16::from_be_bytes([data[offset + 1], data[offset + 2]]) as usize;
+        if declared_len < 3 {
+            break;
+        }
+
+        let available = data.len() - offset;
+        if declared_len > available {
+            break;
+        }
+
+        count += 1;
+        offset += declared_len;
+    }
+
+    count
+}
+
+/// Convert C++ AsterixData to Rust structures
+///
+/// This internal function marshals data from the C++ side to Rust-native types.
+/// It handles all memory management and type conversions.
+unsafe fn convert_asterix_data(
+    data_ptr: *mut ffi::ffi::AsterixDataWrapper,
+    options: &ParseOptions,
+) -> Result<Vec<AsterixRecord>> {
+    let mut records = Vec::new();
+
+    let block_count = ffi::ffi::asterix_data_block_count(data_ptr);
+
+    for i in 0..block_count {
+        let block_ptr = ffi::ffi::asterix_get_data_block(data_ptr, i);
+
+        if block_ptr.is_null() {
+            continue;
+        }
+
+        // Apply category filter if specified
+        let category = ffi::ffi::asterix_block_category(block_ptr);
+        if let Some(filter_cat) = options.filter_category {
+            if category != filter_cat {
+                continue;
+            }
+        }
+
+        let record = convert_data_block(block_ptr, options)?;
+
+        // Apply source (SAC/SIC) filter if specified, once items are decoded
+        if let Some((sac, sic)) = options.filter_source {
+            let item_id = format!("I{:03}/010", record.category);
+            let matches = record
+                .get_item(&item_id)
+                .map(|item| {
+                    let item_sac = item.get_field("SAC").and_then(|v| v.as_i64());
+                    let item_sic = item.get_field("SIC").and_then(|v| v.as_i64());
+                    item_sac == Some(sac as i64) && item_sic == Some(sic as i64)
+                })
+                .unwrap_or(false);
+            if !matches {
+                continue;
+            }
+        }
+
+        // Apply declarative per-field predicates, once items are decoded
+        if !options.filters.iter().all(|f| f.matches(&record)) {
+            continue;
+        }
+
+        // Apply the arbitrary predicate, if any, after items have been decoded
+        if let Some(filter) = &options.filter {
+            if !filter(&record) {
+                continue;
+            }
+        }
+
+        records.push(record);
+
+        // Check max records limit
+        if let Some(max) = options.max_records {
+            if records.len() >= max {
+                break;
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+/// Convert a single C++ DataBlock to Rust AsterixRecord
+unsafe fn convert_data_block(
+    block_ptr: *const ffi::ffi::DataBlockWrapper,
+    options: &ParseOptions,
+) -> Result<AsterixRecord> {
+    // HIGH-003 FIX: Validate block_ptr is not null
+    if block_ptr.is_null() {
+        return Err(AsterixError::NullPointer(
+            "C++ returned null data block".to_string(),
+        ));
+    }
+
+    let category = ffi::ffi::asterix_block_category(block_ptr);
+    let length = ffi::ffi::asterix_block_length(block_ptr);
+    let crc = ffi::ffi::asterix_block_crc(block_ptr);
+
+    // A block with no embedded time-of-day item reports timestamp_ms as 0;
+    // stamp it with the injected clock (if any) rather than leaving every
+    // such record pinned to the Unix epoch.
+    let timestamp_ms = match ffi::ffi::asterix_block_timestamp_ms(block_ptr) {
+        0 => options.clock.as_ref().map_or(0, |clock| clock.now_ms()),
+        ts => ts,
+    };
+
+    // Get hex data
+    let hex_ptr = ffi::ffi::asterix_block_hex_data(block_ptr);
+    let hex_data = if !hex_ptr.is_null() {
+        std::ffi::CStr::from_ptr(hex_ptr as *const std::os::raw::c_char)
+            .to_string_lossy()
+            .to_string()
+    } else {
+        String::new()
+    };
+
+    // Parse JSON to extract items
+    let json_ptr = ffi::ffi::asterix_block_to_json(block_ptr);
+    let mut items = if !json_ptr.is_null() {
+        let json_str = ffi::c_string_to_rust(json_ptr)?;
+        parse_items_from_json(&json_str, options)?
+    } else {
+        ItemMap::new()
+    };
+
+    if let Some(conversions) = &options.conversions {
+        apply_conversions(&mut items, conversions, options.eager_conversions);
+    }
+
+    Ok(AsterixRecord {
+        category,
+        length,
+        timestamp_ms,
+        crc,
+        hex_data,
+        items,
+    })
+}
+
+/// Re-wrap every field in `items` matching a `"{item_id}/{field_name}"` key
+/// in `conversions`, per [`ParseOptions::conversions`]/[`ParseOptions::eager_conversions`].
+fn apply_conversions(
+    items: &mut ItemMap,
+    conversions: &BTreeMap<String, crate::quantity::Conversion>,
+    eager: bool,
+) {
+    for (item_id, item) in items.iter_mut() {
+        for (field_name, value) in item.fields.iter_mut() {
+            let path = format!("{item_id}/{field_name}");
+            if let Some(conversion) = conversions.get(&path) {
+                if let Some(converted) = conversion.convert(value, eager) {
+                    *value = converted;
+                }
+            }
+        }
+    }
+}
+
+/// Parse data items from JSON representation
+///
+/// This is a temporary implementation that parses the JSON output from C++.
+/// Future versions should use direct C++ struct access for better performance.
+///
+/// Items named in `options.lazy_items` (or every item, if `options.lazy_all`
+/// is set) are stored undecoded instead, as a single
+/// [`crate::types::RAW_ITEM_FIELD`] field holding [`ParsedValue::Raw`] of
+/// that item's own still-unwalked JSON text — see [`ParsedValue::decode`].
+fn parse_items_from_json(
+    json_str: &str,
+    options: &ParseOptions,
+) -> Result<ItemMap> {
+    #[cfg(feature = "serde")]
+    {
+        use serde_json::Value;
+
+        // Handle empty or whitespace-only JSON (indicates no data was parsed by C++)
+        let trimmed = json_str.trim();
+        if trimmed.is_empty() || trimmed == "{}" || trimmed == "[]" {
+            return Ok(ItemMap::new());
+        }
+
+        let mut items = ItemMap::new();
+
+        // The common case is a single JSON object for the whole block; try
+        // simd-json's in-place DOM parser for that case first, since it's
+        // several times faster than serde_json on AVX2 hosts. Fall back to
+        // the serde_json path below (unchanged) when the feature is off, the
+        // block isn't a lone top-level object (e.g. NDJSON with more than
+        // one), or simd-json fails to parse it for any reason.
+        #[cfg(feature = "simd-json")]
+        if let Some(result) = simd_parse_single_object(trimmed, options, &mut items) {
+            return result.map(|()| items);
+        }
+
+        // The C++ wrapper may emit several whitespace/newline-separated JSON
+        // objects for one data block (e.g. one object per repeated subfield
+        // group). `StreamDeserializer` parses each top-level value in turn and
+        // surfaces a real parse error (with byte offset) on the first
+        // malformed one, so every object is honored instead of only the first.
+        let is_lazy = |item_id: &str| {
+            options.lazy_all
+                || options
+                    .lazy_items
+                    .as_ref()
+                    .is_some_and(|names| names.iter().any(|name| name == item_id))
+        };
+
+        for value in serde_json::Deserializer::from_str(trimmed).into_iter::<Value>() {
+            let value = value.map_err(|e| {
+                AsterixError::InvalidData(format!(
+                    "Failed to parse JSON from C++: {e}\nJSON snippet: {}",
+                    &trimmed.chars().take(100).collect::<String>()
+                ))
+            })?;
+
+            let Some(obj) = value.as_object() else {
+                continue;
+            };
+
+            for (key, val) in obj {
+                // Skip metadata fields
+                if key == "id"
+                    || key == "cat"
+                    || key == "category"
+                    || key == "length"
+                    || key == "timestamp"
+                    || key == "crc"
+                    || key == "hexdata"
+                {
+                    continue;
+                }
+
+                // The actual ASTERIX items are nested under a key like "CAT048"
+                // Check if this is a category object containing items
+                if key.starts_with("CAT") && val.is_object() {
+                    // Extract items from the nested category object
+                    if let Some(cat_obj) = val.as_object() {
+                        for (item_key, item_val) in cat_obj {
+                            let data_item = if is_lazy(item_key) {
+                                lazy_data_item(item_val)?
+                            } else {
+                                json_value_to_data_item(item_val, options)?
+                            };
+                            insert_data_item(&mut items, item_key.clone(), data_item);
+                        }
+                    }
+                } else {
+                    // For backward compatibility, also handle top-level items
+                    let data_item = if is_lazy(key) {
+                        lazy_data_item(val)?
+                    } else {
+                        json_value_to_data_item(val, options)?
+                    };
+                    insert_data_item(&mut items, key.clone(), data_item);
+                }
+            }
+        }
+
+        Ok(items)
+    }
+
+    #[cfg(not(feature = "serde"))]
+    {
+        let _ = options;
+        // Without serde, we can't parse JSON - return empty items
+        let _ = json_str;
+        Ok(ItemMap::new())
+    }
+}
+
+/// Decode a newline-delimited JSON capture file, one item map per line.
+///
+/// Each non-empty line is parsed independently through the same
+/// [`parse_items_from_json`] logic used for a single block's JSON, so a
+/// large NDJSON file (one ASTERIX record's worth of JSON per line) streams
+/// through as a `Vec` of per-line item maps instead of being merged into one
+/// map the way passing the whole file as a single string to
+/// `parse_items_from_json` would (see its NDJSON handling above). Blank
+/// lines are skipped.
+///
+/// For a large file, prefer [`NdjsonRecords`], which reads and decodes one
+/// line at a time rather than materializing `input` and every decoded line
+/// up front.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] naming the offending line's 1-based
+/// line number if any line fails to parse, rather than aborting with no way
+/// to tell which line was bad.
+#[cfg(feature = "serde")]
+pub fn parse_records_from_ndjson(input: &str) -> Result<Vec<ItemMap>> {
+    let options = ParseOptions::default();
+    let mut records = Vec::new();
+
+    for (line_no, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let items = parse_items_from_json(line, &options)
+            .map_err(|e| AsterixError::InvalidData(format!("line {}: {e}", line_no + 1)))?;
+        records.push(items);
+    }
+
+    Ok(records)
+}
+
+/// Lazily yields one decoded item map per non-empty line of a
+/// [`BufRead`] source of newline-delimited JSON.
+///
+/// Unlike [`parse_records_from_ndjson`], which reads `input` fully before
+/// returning, `NdjsonRecords` reads and decodes one line at a time, so a
+/// multi-gigabyte capture file streams through without ever holding more
+/// than a line's worth of JSON in memory. Modeled on `serde_json`'s
+/// `StreamDeserializer`: each line is independent, so one malformed line
+/// doesn't stop the rest of the file from being read.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::parser::NdjsonRecords;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let file = std::io::BufReader::new(std::fs::File::open("capture.ndjson")?);
+/// for items in NdjsonRecords::new(file) {
+///     let items = items?;
+///     println!("{} items in this line", items.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+pub struct NdjsonRecords<R> {
+    lines: std::io::Lines<R>,
+    options: ParseOptions,
+    line_no: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<R: std::io::BufRead> NdjsonRecords<R> {
+    /// Wrap `reader`, decoding every line's items with default parse options.
+    pub fn new(reader: R) -> Self {
+        Self::with_options(reader, ParseOptions::default())
+    }
+
+    /// Wrap `reader`, applying `options` to every decoded line.
+    pub fn with_options(reader: R, options: ParseOptions) -> Self {
+        NdjsonRecords {
+            lines: reader.lines(),
+            options,
+            line_no: 0,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: std::io::BufRead> Iterator for NdjsonRecords<R> {
+    type Item = Result<ItemMap>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = self.lines.next()?;
+            self.line_no += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(AsterixError::from(e))),
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let line_no = self.line_no;
+            return Some(
+                parse_items_from_json(&line, &self.options)
+                    .map_err(|e| AsterixError::InvalidData(format!("line {line_no}: {e}"))),
+            );
+        }
+    }
+}
+
+/// Insert `data_item` under `key`, merging into any existing entry instead
+/// of overwriting it: each of `data_item`'s fields is merged into the
+/// existing [`DataItem`]'s matching field (see [`merge_parsed_value_field`]),
+/// so a field seen under the same item key across multiple NDJSON objects
+/// accumulates as a [`ParsedValue::Array`] instead of the later occurrence
+/// clobbering the earlier one.
+#[cfg(feature = "serde")]
+fn insert_data_item(items: &mut ItemMap, key: String, data_item: DataItem) {
+    #[cfg(not(feature = "preserve_order"))]
+    use std::collections::btree_map::Entry;
+    #[cfg(feature = "preserve_order")]
+    use indexmap::map::Entry;
+
+    match items.entry(key) {
+        Entry::Vacant(entry) => {
+            entry.insert(data_item);
+        }
+        Entry::Occupied(mut entry) => {
+            let existing = entry.get_mut();
+            if existing.description.is_none() {
+                existing.description = data_item.description;
+            }
+            for (field_key, value) in data_item.fields {
+                merge_parsed_value_field(&mut existing.fields, field_key, value);
+            }
+        }
+    }
+}
+
+/// Merge `value` into `fields[field_key]`: if the field isn't present yet,
+/// insert as-is; if it is, accumulate both occurrences into (or onto) a
+/// [`ParsedValue::Array`] rather than overwrite the earlier value.
+#[cfg(feature = "serde")]
+fn merge_parsed_value_field(
+    fields: &mut FieldMap,
+    field_key: String,
+    value: ParsedValue,
+) {
+    #[cfg(not(feature = "preserve_order"))]
+    use std::collections::btree_map::Entry;
+    #[cfg(feature = "preserve_order")]
+    use indexmap::map::Entry;
+
+    match fields.entry(field_key) {
+        Entry::Vacant(entry) => {
+            entry.insert(value);
+        }
+        Entry::Occupied(mut entry) => match entry.get_mut() {
+            ParsedValue::Array(values) => values.push(value),
+            existing => {
+                let previous = std::mem::replace(existing, ParsedValue::Array(Vec::new()));
+                if let ParsedValue::Array(values) = existing {
+                    values.push(previous);
+                    values.push(value);
+                }
+            }
+        },
+    }
+}
+
+/// Build a deferred [`DataItem`] holding `value`'s own still-unwalked JSON
+/// text as a single [`crate::types::RAW_ITEM_FIELD`] field, instead of
+/// decoding it into a full field set.
+#[cfg(feature = "serde")]
+fn lazy_data_item(value: &serde_json::Value) -> Result<DataItem> {
+    let raw_text = serde_json::to_string(value).map_err(|e| {
+        AsterixError::InvalidData(format!("Failed to re-serialize item for lazy decode: {e}"))
+    })?;
+    let mut data_item = DataItem::new(None);
+    data_item
+        .fields
+        .insert(crate::types::RAW_ITEM_FIELD.to_string(), ParsedValue::Raw(raw_text));
+    Ok(data_item)
+}
+
+/// Try to parse `trimmed` as a single top-level JSON object via simd-json's
+/// in-place DOM parser, storing its items into `items` on success.
+///
+/// Returns `None` (leaving `items` untouched) when `trimmed` doesn't parse as
+/// simd-json wants (e.g. it's NDJSON with more than one top-level value, or
+/// outright malformed), so the caller can fall back to the serde_json path
+/// below. Returns `Some(Err(_))` only for an error found *after* simd-json
+/// successfully parsed the document (e.g. re-serializing a lazy item), which
+/// is a real error rather than a reason to fall back.
+#[cfg(all(feature = "serde", feature = "simd-json"))]
+fn simd_parse_single_object(
+    trimmed: &str,
+    options: &ParseOptions,
+    items: &mut ItemMap,
+) -> Option<Result<()>> {
+    use simd_json::BorrowedValue;
+
+    let mut buf = trimmed.as_bytes().to_vec();
+    let value: BorrowedValue = simd_json::to_borrowed_value(&mut buf).ok()?;
+    let BorrowedValue::Object(obj) = &value else {
+        return None;
+    };
+
+    let is_lazy = |item_id: &str| {
+        options.lazy_all
+            || options
+                .lazy_items
+                .as_ref()
+                .is_some_and(|names| names.iter().any(|name| name == item_id))
+    };
+
+    Some((|| {
+        for (key, val) in obj.iter() {
+            if key == "id"
+                || key == "cat"
+                || key == "category"
+                || key == "length"
+                || key == "timestamp"
+                || key == "crc"
+                || key == "hexdata"
+            {
+                continue;
+            }
+
+            if key.starts_with("CAT") {
+                if let BorrowedValue::Object(cat_obj) = val {
+                    for (item_key, item_val) in cat_obj.iter() {
+                        let data_item = if is_lazy(item_key) {
+                            simd_lazy_data_item(item_val)?
+                        } else {
+                            simd_value_to_data_item(item_val, options)?
+                        };
+                        insert_data_item(items, item_key.to_string(), data_item);
+                    }
+                }
+            } else {
+                let data_item = if is_lazy(key) {
+                    simd_lazy_data_item(val)?
+                } else {
+                    simd_value_to_data_item(val, options)?
+                };
+                insert_data_item(items, key.to_string(), data_item);
+            }
+        }
+        Ok(())
+    })())
+}
+
+/// Store `value`'s still-unwalked JSON text as a single
+/// [`crate::types::RAW_ITEM_FIELD`] field, mirroring [`lazy_data_item`]
+#[cfg(all(feature = "serde", feature = "simd-json"))]
+fn simd_lazy_data_item(value: &simd_json::BorrowedValue) -> Result<DataItem> {
+    let raw_text = value.to_string();
+    let mut data_item = DataItem::new(None);
+    data_item
+        .fields
+        .insert(crate::types::RAW_ITEM_FIELD.to_string(), ParsedValue::Raw(raw_text));
+    Ok(data_item)
+}
+
+/// Convert a simd-json `BorrowedValue` object to [`DataItem`], mirroring
+/// [`json_value_to_data_item`] (including [`ParseOptions::lazy_fields`])
+#[cfg(all(feature = "serde", feature = "simd-json"))]
+fn simd_value_to_data_item(value: &simd_json::BorrowedValue, options: &ParseOptions) -> Result<DataItem> {
+    use simd_json::BorrowedValue;
+
+    let mut data_item = DataItem::new(None);
+
+    if let BorrowedValue::Object(obj) = value {
+        for (key, val) in obj.iter() {
+            let parsed_val = if options.lazy_fields {
+                ParsedValue::Raw(val.to_string())
+            } else {
+                simd_value_to_parsed_value(val)?
+            };
+            data_item.fields.insert(key.to_string(), parsed_val);
+        }
+    }
+
+    Ok(data_item)
+}
+
+/// Convert a simd-json `BorrowedValue` to [`ParsedValue`], using the same
+/// mapping rules as [`json_value_to_parsed_value`]
+#[cfg(all(feature = "serde", feature = "simd-json"))]
+fn simd_value_to_parsed_value(value: &simd_json::BorrowedValue) -> Result<ParsedValue> {
+    use simd_json::{BorrowedValue, StaticNode};
+
+    match value {
+        BorrowedValue::Static(StaticNode::I64(i)) => Ok(ParsedValue::Integer(*i)),
+        BorrowedValue::Static(StaticNode::U64(u)) => Ok(match i64::try_from(*u) {
+            Ok(i) => ParsedValue::Integer(i),
+            Err(_) => ParsedValue::Unsigned(*u),
+        }),
+        BorrowedValue::Static(StaticNode::F64(f)) => Ok(ParsedValue::Float(*f)),
+        BorrowedValue::Static(StaticNode::Bool(b)) => Ok(ParsedValue::Boolean(*b)),
+        BorrowedValue::Static(StaticNode::Null) => Ok(ParsedValue::String("null".to_string())),
+        BorrowedValue::String(s) => Ok(ParsedValue::String(s.to_string())),
+        BorrowedValue::Array(arr) => {
+            let mut parsed_arr = Vec::new();
+            for item in arr.iter() {
+                parsed_arr.push(simd_value_to_parsed_value(item)?);
+            }
+            Ok(ParsedValue::Array(parsed_arr))
+        }
+        BorrowedValue::Object(obj) => {
+            let mut nested = BTreeMap::new();
+            for (key, val) in obj.iter() {
+                nested.insert(key.to_string(), Box::new(simd_value_to_parsed_value(val)?));
+            }
+            Ok(ParsedValue::Nested(nested))
+        }
+    }
+}
+
+/// Convert serde_json::Value to DataItem
+///
+/// When `options.lazy_fields` is set, each field is stored as an unparsed
+/// [`ParsedValue::Raw`] instead of being recursively walked into a full
+/// [`ParsedValue`] tree here; call [`DataItem::field_parsed`] to materialize
+/// one on first access.
 #[cfg(feature = "serde")]
-fn json_value_to_data_item(value: &serde_json::Value) -> Result<DataItem> {
+fn json_value_to_data_item(value: &serde_json::Value, options: &ParseOptions) -> Result<DataItem> {
     let mut data_item = DataItem::new(None);
 
     if let Some(obj) = value.as_object() {
         for (key, val) in obj {
-            let parsed_val = json_value_to_parsed_value(val)?;
+            let parsed_val = if options.lazy_fields {
+                raw_field_value(val)?
+            } else {
+                json_value_to_parsed_value(val)?
+            };
             data_item.fields.insert(key.clone(), parsed_val);
         }
     }
@@ -420,19 +1669,53 @@ fn json_value_to_data_item(value: &serde_json::Value) -> Result<DataItem> {
     Ok(data_item)
 }
 
+/// Re-serialize `value` as a [`ParsedValue::Raw`], for
+/// [`ParseOptions::lazy_fields`]
+#[cfg(feature = "serde")]
+fn raw_field_value(value: &serde_json::Value) -> Result<ParsedValue> {
+    let raw_text = serde_json::to_string(value).map_err(|e| {
+        AsterixError::InvalidData(format!("Failed to re-serialize field for lazy decode: {e}"))
+    })?;
+    Ok(ParsedValue::Raw(raw_text))
+}
+
+/// Returns `n` as an `f64` only if doing so loses no digits.
+///
+/// Compares `n`'s own source text (via [`serde_json::Number::to_string`],
+/// which preserves the verbatim token under serde_json's
+/// `arbitrary_precision` feature rather than an already-lossy `f64`) against
+/// the text `n.as_f64()` itself would produce, so a value is accepted only
+/// if converting it and formatting it back reproduces the exact same
+/// digits. This catches both an exponent too large for `f64` (e.g.
+/// `1e999999`, where `as_f64()` returns `f64::INFINITY`) and a long-mantissa
+/// decimal `f64` can't represent losslessly.
+#[cfg(feature = "serde")]
+fn number_round_trips_as_f64(n: &serde_json::Number) -> Option<f64> {
+    let f = n.as_f64()?;
+    (f.is_finite() && f.to_string() == n.to_string()).then_some(f)
+}
+
 /// Convert serde_json::Value to ParsedValue
 #[cfg(feature = "serde")]
-fn json_value_to_parsed_value(value: &serde_json::Value) -> Result<ParsedValue> {
+pub(crate) fn json_value_to_parsed_value(value: &serde_json::Value) -> Result<ParsedValue> {
     use serde_json::Value;
 
     match value {
         Value::Number(n) => {
             if let Some(i) = n.as_i64() {
                 Ok(ParsedValue::Integer(i))
-            } else if let Some(f) = n.as_f64() {
+            } else if let Some(u) = n.as_u64() {
+                Ok(ParsedValue::Unsigned(u))
+            } else if let Some(f) = number_round_trips_as_f64(n) {
                 Ok(ParsedValue::Float(f))
             } else {
-                Ok(ParsedValue::Integer(0))
+                // Neither i64/u64-exact nor f64-round-trippable (e.g. a
+                // 64-bit value beyond u64::MAX, an exponent too large for
+                // f64 like `1e999999`, or a long-mantissa decimal that f64
+                // can't hold to the last digit). Rather than silently
+                // truncate it the way the old as_i64()-then-as_f64()
+                // fallback did, keep the original token verbatim.
+                Ok(ParsedValue::Number(n.to_string()))
             }
         }
         Value::String(s) => Ok(ParsedValue::String(s.clone())),
@@ -455,9 +1738,82 @@ fn json_value_to_parsed_value(value: &serde_json::Value) -> Result<ParsedValue>
     }
 }
 
+/// Convert a [`ParsedValue`] to a `serde_json::Value`
+///
+/// Inverse of [`json_value_to_parsed_value`], used by [`items_to_json`] to
+/// re-serialize a decoded (and possibly modified) item map. [`ParsedValue::Raw`]
+/// and [`ParsedValue::Number`] are both emitted as their own verbatim text
+/// wrapped in a JSON string — there's no way to tell a bare JSON string
+/// apart from one of these two on the way back in, so re-parsing this
+/// output always produces [`ParsedValue::String`], never reconstructing
+/// `Raw`/`Number` (see their own doc comments in [`crate::types`]).
+#[cfg(feature = "serde")]
+pub fn parsed_value_to_json_value(value: &ParsedValue) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        ParsedValue::Integer(v) => serde_json::json!(v),
+        ParsedValue::Unsigned(v) => serde_json::json!(v),
+        ParsedValue::Float(v) => serde_json::json!(v),
+        ParsedValue::Decimal { raw, scale, unit } => serde_json::json!({
+            "raw": raw,
+            "scale": scale,
+            "unit": unit,
+        }),
+        ParsedValue::String(v) => serde_json::json!(v),
+        ParsedValue::Boolean(v) => serde_json::json!(v),
+        ParsedValue::Bytes(v) => serde_json::json!(v),
+        ParsedValue::Nested(nested) => {
+            let mut map = serde_json::Map::new();
+            for (key, nested_value) in nested {
+                map.insert(key.clone(), parsed_value_to_json_value(nested_value));
+            }
+            Value::Object(map)
+        }
+        ParsedValue::Array(items) => {
+            Value::Array(items.iter().map(parsed_value_to_json_value).collect())
+        }
+        ParsedValue::Raw(text) => serde_json::json!(text),
+        ParsedValue::Number(text) => serde_json::json!(text),
+    }
+}
+
+/// Re-serialize `items` back into JSON text.
+///
+/// Inverse of [`parse_items_from_json`]: each item's fields are walked back
+/// into a `serde_json::Value` through [`parsed_value_to_json_value`], then
+/// the whole item map is serialized to a compact JSON string — so a caller
+/// that decoded a record, mutated one of its `ParsedValue`s, and wants to
+/// emit it again (e.g. as another NDJSON line) doesn't have to hand-assemble
+/// JSON text.
+///
+/// ASTERIX data items (and a compound item's own subfields) carry a defined
+/// FRN/UAP ordering. `items`'s own iteration order is preserved into the
+/// output `serde_json::Map` here, but whether that survives into the final
+/// *text* also depends on `serde_json`'s own `preserve_order` feature being
+/// enabled in this crate's dependency — without it, `serde_json::Map` is
+/// itself a `BTreeMap` and re-sorts keys alphabetically regardless of what
+/// order they were inserted in. Build `items` with this crate's
+/// `preserve_order` feature enabled (see [`ItemMap`]) and ensure
+/// `serde_json`'s matching feature is also on if the original order must
+/// survive the round trip.
+#[cfg(feature = "serde")]
+pub fn items_to_json(items: &ItemMap) -> String {
+    let mut map = serde_json::Map::new();
+    for (item_id, item) in items {
+        let mut fields = serde_json::Map::new();
+        for (name, value) in &item.fields {
+            fields.insert(name.clone(), parsed_value_to_json_value(value));
+        }
+        map.insert(item_id.clone(), serde_json::Value::Object(fields));
+    }
+    serde_json::Value::Object(map).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Diagnostic;
     use std::sync::Once;
 
     /// Global initialization for parser tests
@@ -564,7 +1920,7 @@ mod tests {
         let data = vec![0x30, 0x00, 0x10];
         let result = parse_with_offset(&data, 10, 1, ParseOptions::default());
         assert!(result.is_err());
-        if let Err(AsterixError::ParseError { offset, message }) = result {
+        if let Err(AsterixError::ParseError { offset, message, .. }) = result {
             assert_eq!(offset, 10);
             assert!(message.contains("exceeds data length"));
         } else {
@@ -577,7 +1933,7 @@ mod tests {
         let data = vec![0x30, 0x00, 0x10];
         let result = parse_with_offset(&data, 3, 1, ParseOptions::default());
         assert!(result.is_err());
-        if let Err(AsterixError::ParseError { offset, message }) = result {
+        if let Err(AsterixError::ParseError { offset, message, .. }) = result {
             assert_eq!(offset, 3);
             assert!(message.contains("exceeds data length"));
         } else {
@@ -678,6 +2034,170 @@ mod tests {
         }
     }
 
+    // ========== parse_with_mode() tests ==========
+
+    #[test]
+    fn test_parse_with_mode_strict_errors_on_truncation() {
+        // Declares 10 bytes but only 3 are present
+        let data = vec![32, 0x00, 0x0A];
+        let result = parse_with_mode(&data, ParseOptions::default());
+        assert!(matches!(result, Err(AsterixError::UnexpectedEOF { .. })));
+    }
+
+    #[test]
+    fn test_parse_with_mode_lenient_salvages_truncated_block() {
+        ensure_initialized();
+        // Category 63 (CAT063), declares 9 bytes of block but only 6 are
+        // present: a 3-byte header followed by a 1-byte FSPEC (just the
+        // SAC/SIC item) and 2 bytes of SAC/SIC payload -- a real, decodable
+        // item, not just a bare header, so a genuine partial decode has
+        // something to salvage.
+        let data = vec![63, 0x00, 0x09, 0x80, 0x01, 0x17];
+        let options = ParseOptions {
+            mode: crate::types::ParseMode::Lenient,
+            ..Default::default()
+        };
+        let results = parse_with_mode(&data, options).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].is_complete());
+        let record = results[0].record();
+        assert_eq!(record.category, 63);
+
+        // The truncated buffer handed to the FFI is rewritten so its own
+        // header matches what's actually present (6 bytes), rather than the
+        // declared 9 -- so this must succeed as a real decode rather than
+        // erroring out on a now-consistent, if short, block.
+        //
+        // `hex_data` is only ever populated by a real `convert_data_block`
+        // call (src/parser.rs's FFI conversion path); the `unwrap_or`
+        // empty-stub fallback leaves it as `String::new()`. Asserting it's
+        // non-empty is what actually distinguishes "the FFI decoded this
+        // block" from "decoding failed and we fell back to a stub record",
+        // independent of whether a given build's category definitions
+        // decode every item in `items`.
+        assert!(
+            !record.hex_data.is_empty(),
+            "expected a real salvaged decode, not the empty-stub fallback"
+        );
+
+        if let MaybeParsed::Incomplete { truncated_at, .. } = &results[0] {
+            assert_eq!(truncated_at.offset, data.len());
+        } else {
+            panic!("expected MaybeParsed::Incomplete");
+        }
+    }
+
+    #[test]
+    fn test_parse_with_mode_rejects_undersized_length_field() {
+        let data = vec![32, 0x00, 0x02];
+        let result = parse_with_mode(&data, ParseOptions::default());
+        assert!(matches!(result, Err(AsterixError::ParseError { .. })));
+    }
+
+    #[test]
+    fn test_parse_with_mode_empty_data() {
+        let result = parse_with_mode(&[], ParseOptions::default());
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    // ========== RecordsIter tests ==========
+
+    #[test]
+    fn test_records_iter_empty_data() {
+        let mut iter = records_iter(&[], ParseOptions::default());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_records_iter_reports_truncation() {
+        let data = vec![32, 0x00, 0x0A];
+        let mut iter = records_iter(&data, ParseOptions::default());
+        let result = iter.next().unwrap();
+        assert!(matches!(result, Err(AsterixError::UnexpectedEOF { .. })));
+        assert!(iter.next().is_none(), "iterator must stop after an error");
+    }
+
+    #[test]
+    fn test_records_iter_rejects_undersized_length_field() {
+        let data = vec![32, 0x00, 0x02];
+        let mut iter = records_iter(&data, ParseOptions::default());
+        let result = iter.next().unwrap();
+        assert!(matches!(result, Err(AsterixError::ParseError { .. })));
+    }
+
+    #[test]
+    fn test_records_iter_respects_max_records() {
+        ensure_initialized();
+        // Two minimal CAT032 header-only blocks back to back
+        let data = vec![32, 0x00, 0x03, 32, 0x00, 0x03];
+        let options = ParseOptions {
+            max_records: Some(0),
+            ..Default::default()
+        };
+        let mut iter = records_iter(&data, options);
+        assert!(iter.next().is_none(), "max_records: 0 yields nothing");
+    }
+
+    #[test]
+    fn test_records_iter_skips_filtered_categories() {
+        ensure_initialized();
+        let data = vec![32, 0x00, 0x03];
+        let options = ParseOptions {
+            filter_category: Some(48),
+            ..Default::default()
+        };
+        let mut iter = records_iter(&data, options);
+        assert!(iter.next().is_none(), "non-matching category must be skipped");
+    }
+
+    // ========== filter_source / filter predicate tests ==========
+
+    #[test]
+    fn test_filter_source_default_is_none() {
+        let opts = ParseOptions::default();
+        assert_eq!(opts.filter_source, None);
+        assert!(opts.filter.is_none());
+    }
+
+    #[test]
+    fn test_parse_options_with_predicate_filter() {
+        use crate::types::RecordFilter;
+        use std::sync::Arc;
+
+        let predicate: RecordFilter = Arc::new(|record: &AsterixRecord| record.category == 48);
+        let opts = ParseOptions {
+            filter: Some(predicate),
+            ..Default::default()
+        };
+        let record = AsterixRecord {
+            category: 48,
+            ..Default::default()
+        };
+        assert!(opts.filter.as_ref().unwrap()(&record));
+
+        let other = AsterixRecord {
+            category: 62,
+            ..Default::default()
+        };
+        assert!(!opts.filter.as_ref().unwrap()(&other));
+    }
+
+    #[test]
+    fn test_parse_options_clone_preserves_filter() {
+        use crate::types::RecordFilter;
+        use std::sync::Arc;
+
+        let predicate: RecordFilter = Arc::new(|_: &AsterixRecord| true);
+        let opts = ParseOptions {
+            filter: Some(predicate),
+            filter_source: Some((1, 2)),
+            ..Default::default()
+        };
+        let cloned = opts.clone();
+        assert_eq!(cloned.filter_source, Some((1, 2)));
+        assert!(cloned.filter.is_some());
+    }
+
     // ========== ParseOptions tests ==========
 
     #[test]
@@ -694,6 +2214,7 @@ mod tests {
             verbose: true,
             filter_category: None,
             max_records: None,
+            ..Default::default()
         };
         assert!(opts.verbose);
     }
@@ -704,6 +2225,7 @@ mod tests {
             verbose: false,
             filter_category: Some(62),
             max_records: None,
+            ..Default::default()
         };
         assert_eq!(opts.filter_category, Some(62));
     }
@@ -714,6 +2236,7 @@ mod tests {
             verbose: false,
             filter_category: None,
             max_records: Some(1000),
+            ..Default::default()
         };
         assert_eq!(opts.max_records, Some(1000));
     }
@@ -724,19 +2247,85 @@ mod tests {
             verbose: true,
             filter_category: Some(48),
             max_records: Some(500),
+            ..Default::default()
         };
         assert!(opts.verbose);
         assert_eq!(opts.filter_category, Some(48));
         assert_eq!(opts.max_records, Some(500));
     }
 
+    // ========== apply_conversions() tests ==========
+
+    fn item_map_with(item_id: &str, field_name: &str, value: ParsedValue) -> ItemMap {
+        let mut fields = FieldMap::new();
+        fields.insert(field_name.to_string(), value);
+        let mut items = ItemMap::new();
+        items.insert(
+            item_id.to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        items
+    }
+
+    #[test]
+    fn test_apply_conversions_lazy_yields_decimal() {
+        let mut items = item_map_with("I062/380", "IAS", ParsedValue::Integer(40));
+        let mut conversions = BTreeMap::new();
+        conversions.insert(
+            "I062/380/IAS".to_string(),
+            crate::quantity::Conversion::new(0.25, Some("NM".to_string())),
+        );
+
+        apply_conversions(&mut items, &conversions, false);
+
+        assert_eq!(
+            items["I062/380"]["IAS"],
+            ParsedValue::Decimal {
+                raw: 40,
+                scale: 0.25,
+                unit: Some("NM".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_conversions_eager_yields_float() {
+        let mut items = item_map_with("I062/380", "IAS", ParsedValue::Integer(40));
+        let mut conversions = BTreeMap::new();
+        conversions.insert(
+            "I062/380/IAS".to_string(),
+            crate::quantity::Conversion::new(0.25, Some("NM".to_string())),
+        );
+
+        apply_conversions(&mut items, &conversions, true);
+
+        assert_eq!(items["I062/380"]["IAS"], ParsedValue::Float(10.0));
+    }
+
+    #[test]
+    fn test_apply_conversions_ignores_unmatched_paths() {
+        let mut items = item_map_with("I062/380", "IAS", ParsedValue::Integer(40));
+        let mut conversions = BTreeMap::new();
+        conversions.insert(
+            "I062/380/OTHER".to_string(),
+            crate::quantity::Conversion::new(0.25, None),
+        );
+
+        apply_conversions(&mut items, &conversions, false);
+
+        assert_eq!(items["I062/380"]["IAS"], ParsedValue::Integer(40));
+    }
+
     // ========== JSON parsing tests ==========
 
     #[cfg(feature = "serde")]
     #[test]
     fn test_parse_items_from_json() {
         let json = r#"{"I062/010": {"SAC": 1, "SIC": 2}}"#;
-        let items = parse_items_from_json(json).unwrap();
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
         assert!(items.contains_key("I062/010"));
     }
 
@@ -744,64 +2333,168 @@ mod tests {
     #[test]
     fn test_parse_items_from_json_empty() {
         let json = "";
-        let items = parse_items_from_json(json).unwrap();
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_empty_object() {
+        let json = "{}";
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_empty_array() {
+        let json = "[]";
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_whitespace_only() {
+        let json = "   \n\t  ";
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
         assert!(items.is_empty());
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_parse_items_from_json_empty_object() {
-        let json = "{}";
-        let items = parse_items_from_json(json).unwrap();
-        assert!(items.is_empty());
+    fn test_parse_items_from_json_unbalanced_braces() {
+        let json = r#"{"I062/010": {"SAC": 1"#;
+        let result = parse_items_from_json(json, &ParseOptions::default());
+        assert!(result.is_err());
+        if let Err(AsterixError::InvalidData(msg)) = result {
+            assert!(msg.contains("Failed to parse JSON"));
+        } else {
+            panic!("Expected InvalidData for unbalanced braces");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_ndjson() {
+        // Newline-delimited JSON (NDJSON): every object should be parsed,
+        // not just the first.
+        let json = r#"{"I062/010": {"SAC": 1}}
+{"I048/020": {"TYP": 2}}"#;
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
+        assert!(items.contains_key("I062/010"));
+        assert!(items.contains_key("I048/020"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_ndjson_whitespace_separated() {
+        // Objects need not be newline-separated either; any whitespace
+        // between top-level values is fine for a streaming deserializer.
+        let json = r#"{"I062/010": {"SAC": 1}}   {"I062/015": {"SIC": 2}}"#;
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
+        assert!(items.contains_key("I062/010"));
+        assert!(items.contains_key("I062/015"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_ndjson_reports_error_for_later_malformed_object() {
+        // A malformed object after a valid one must still surface as a real
+        // parse error instead of being silently ignored.
+        let json = r#"{"I062/010": {"SAC": 1}}
+not valid json"#;
+        let result = parse_items_from_json(json, &ParseOptions::default());
+        assert!(result.is_err());
+        if let Err(AsterixError::InvalidData(msg)) = result {
+            assert!(msg.contains("Failed to parse JSON"));
+        } else {
+            panic!("Expected InvalidData for malformed trailing object");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_ndjson_duplicate_item_key_accumulates_array() {
+        // The same item key recurring across NDJSON objects should have its
+        // fields accumulate into a ParsedValue::Array rather than the later
+        // occurrence overwriting the earlier one.
+        let json = r#"{"I062/010": {"SAC": 1}}
+{"I062/010": {"SAC": 2}}"#;
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
+        let sac = &items["I062/010"].fields["SAC"];
+        match sac {
+            ParsedValue::Array(values) => {
+                assert_eq!(values.len(), 2);
+                assert_eq!(values[0].as_i64(), Some(1));
+                assert_eq!(values[1].as_i64(), Some(2));
+            }
+            other => panic!("Expected SAC to accumulate into an Array, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_records_from_ndjson_one_map_per_line() {
+        // Unlike parse_items_from_json on the same input, each line keeps
+        // its own item map instead of merging into one.
+        let input = "{\"I062/010\": {\"SAC\": 1}}\n{\"I062/010\": {\"SAC\": 2}}\n";
+        let records = parse_records_from_ndjson(input).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["I062/010"].fields["SAC"].as_i64(), Some(1));
+        assert_eq!(records[1]["I062/010"].fields["SAC"].as_i64(), Some(2));
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_parse_items_from_json_empty_array() {
-        let json = "[]";
-        let items = parse_items_from_json(json).unwrap();
-        assert!(items.is_empty());
+    fn test_parse_records_from_ndjson_skips_blank_lines() {
+        let input = "{\"I062/010\": {\"SAC\": 1}}\n\n   \n{\"I062/015\": {\"SIC\": 2}}\n";
+        let records = parse_records_from_ndjson(input).unwrap();
+        assert_eq!(records.len(), 2);
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_parse_items_from_json_whitespace_only() {
-        let json = "   \n\t  ";
-        let items = parse_items_from_json(json).unwrap();
-        assert!(items.is_empty());
+    fn test_parse_records_from_ndjson_reports_1_based_line_number() {
+        let input = "{\"I062/010\": {\"SAC\": 1}}\nnot valid json\n";
+        let err = parse_records_from_ndjson(input).unwrap_err();
+        match err {
+            AsterixError::InvalidData(msg) => assert!(msg.starts_with("line 2:")),
+            other => panic!("Expected InvalidData, got {other:?}"),
+        }
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_parse_items_from_json_unbalanced_braces() {
-        let json = r#"{"I062/010": {"SAC": 1"#;
-        let result = parse_items_from_json(json);
-        assert!(result.is_err());
-        if let Err(AsterixError::InvalidData(msg)) = result {
-            assert!(msg.contains("unbalanced braces"));
-        } else {
-            panic!("Expected InvalidData for unbalanced braces");
-        }
+    fn test_ndjson_records_iterator_yields_one_map_per_line() {
+        let input = b"{\"I062/010\": {\"SAC\": 1}}\n\n{\"I062/015\": {\"SIC\": 2}}\n".to_vec();
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let records: Vec<_> = NdjsonRecords::new(reader)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records[0].contains_key("I062/010"));
+        assert!(records[1].contains_key("I062/015"));
     }
 
     #[cfg(feature = "serde")]
     #[test]
-    fn test_parse_items_from_json_ndjson() {
-        // Newline-delimited JSON (NDJSON) - should parse only first line
-        let json = r#"{"I062/010": {"SAC": 1}}
-{"I048/020": {"TYP": 2}}"#;
-        let items = parse_items_from_json(json).unwrap();
-        assert!(items.contains_key("I062/010"));
-        // Second line should be ignored
-        assert!(!items.contains_key("I048/020"));
+    fn test_ndjson_records_iterator_reports_1_based_line_number() {
+        let input = b"{\"I062/010\": {\"SAC\": 1}}\nnot valid json\n".to_vec();
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let mut iter = NdjsonRecords::new(reader);
+        assert!(iter.next().unwrap().is_ok());
+        match iter.next().unwrap() {
+            Err(AsterixError::InvalidData(msg)) => assert!(msg.starts_with("line 2:")),
+            other => panic!("Expected InvalidData, got {other:?}"),
+        }
     }
 
     #[cfg(feature = "serde")]
     #[test]
     fn test_parse_items_from_json_invalid_json() {
         let json = r#"not valid json at all"#;
-        let result = parse_items_from_json(json);
+        let result = parse_items_from_json(json, &ParseOptions::default());
         assert!(result.is_err());
         if let Err(AsterixError::InvalidData(msg)) = result {
             assert!(msg.contains("Failed to parse JSON"));
@@ -815,10 +2508,44 @@ mod tests {
     fn test_parse_items_from_json_nested_category() {
         // Test CAT prefix handling
         let json = r#"{"CAT062": {"I062/010": {"SAC": 1, "SIC": 2}}}"#;
-        let items = parse_items_from_json(json).unwrap();
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
         assert!(items.contains_key("I062/010"));
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_lazy_items_stores_raw() {
+        let json = r#"{"I062/010": {"SAC": 1, "SIC": 2}, "I062/380": {"ADR": 7}}"#;
+        let options = ParseOptions {
+            lazy_items: Some(vec!["I062/380".to_string()]),
+            ..Default::default()
+        };
+        let items = parse_items_from_json(json, &options).unwrap();
+
+        let decoded = items.get("I062/010").unwrap();
+        assert_eq!(decoded.fields["SAC"].as_i64(), Some(1));
+
+        let lazy = items.get("I062/380").unwrap();
+        assert!(lazy.fields[crate::types::RAW_ITEM_FIELD].is_raw());
+        let expanded = lazy.fields[crate::types::RAW_ITEM_FIELD].decode().unwrap();
+        assert_eq!(expanded["ADR"].as_i64(), Some(7));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parse_items_from_json_lazy_all_defers_every_item() {
+        let json = r#"{"I062/010": {"SAC": 1}, "I062/380": {"ADR": 7}}"#;
+        let options = ParseOptions {
+            lazy_all: true,
+            ..Default::default()
+        };
+        let items = parse_items_from_json(json, &options).unwrap();
+
+        for item in items.values() {
+            assert!(item.fields[crate::types::RAW_ITEM_FIELD].is_raw());
+        }
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_json_value_to_parsed_value_integer() {
@@ -876,4 +2603,548 @@ mod tests {
         let parsed = json_value_to_parsed_value(&value).unwrap();
         assert!(matches!(parsed, ParsedValue::String(s) if s == "null"));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_value_to_parsed_value_above_u64_max_preserves_digits() {
+        // A 64-bit counter one past u64::MAX: too big for Integer/Unsigned,
+        // and an f64 can't hold it exactly either.
+        let value: serde_json::Value =
+            serde_json::from_str("18446744073709551616").unwrap();
+        let parsed = json_value_to_parsed_value(&value).unwrap();
+        assert!(matches!(parsed, ParsedValue::Number(s) if s == "18446744073709551616"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_value_to_parsed_value_huge_exponent_preserves_digits() {
+        // 1e999999 is valid JSON number syntax but overflows f64 to
+        // infinity, which must not be silently returned as a Float.
+        let value: serde_json::Value = serde_json::from_str("1e999999").unwrap();
+        let parsed = json_value_to_parsed_value(&value).unwrap();
+        assert!(matches!(parsed, ParsedValue::Number(_)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_value_to_parsed_value_long_mantissa_preserves_digits() {
+        // More significant digits than an f64 mantissa can hold without
+        // rounding.
+        let value: serde_json::Value =
+            serde_json::from_str("1.234567890123456789012345678901234567890123").unwrap();
+        let parsed = json_value_to_parsed_value(&value).unwrap();
+        assert!(matches!(parsed, ParsedValue::Number(_) | ParsedValue::Float(_)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_value_to_parsed_value_exact_float_stays_float() {
+        // A value that round-trips through f64 losslessly should still
+        // become a Float, not unnecessarily fall back to Number.
+        let value: serde_json::Value = serde_json::from_str("3.5").unwrap();
+        let parsed = json_value_to_parsed_value(&value).unwrap();
+        assert!(matches!(parsed, ParsedValue::Float(f) if f == 3.5));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parsed_value_to_json_value_round_trips_scalars() {
+        let original = json_value_to_parsed_value(&serde_json::json!(42)).unwrap();
+        assert_eq!(parsed_value_to_json_value(&original), serde_json::json!(42));
+
+        let original = json_value_to_parsed_value(&serde_json::json!(3.5)).unwrap();
+        assert_eq!(parsed_value_to_json_value(&original), serde_json::json!(3.5));
+
+        let original = json_value_to_parsed_value(&serde_json::json!("hello")).unwrap();
+        assert_eq!(
+            parsed_value_to_json_value(&original),
+            serde_json::json!("hello")
+        );
+
+        let original = json_value_to_parsed_value(&serde_json::json!(true)).unwrap();
+        assert_eq!(parsed_value_to_json_value(&original), serde_json::json!(true));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_parsed_value_to_json_value_round_trips_array_and_nested() {
+        let original =
+            json_value_to_parsed_value(&serde_json::json!([1, 2, 3])).unwrap();
+        assert_eq!(
+            parsed_value_to_json_value(&original),
+            serde_json::json!([1, 2, 3])
+        );
+
+        let original =
+            json_value_to_parsed_value(&serde_json::json!({"MODE": "A5"})).unwrap();
+        assert_eq!(
+            parsed_value_to_json_value(&original),
+            serde_json::json!({"MODE": "A5"})
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_items_to_json_round_trips_every_field() {
+        let json = r#"{"CAT048": {"I048/010": {"SAC": 25, "SIC": 10, "OK": true}}}"#;
+        let items = parse_items_from_json(json, &ParseOptions::default()).unwrap();
+
+        let round_tripped: serde_json::Value =
+            serde_json::from_str(&items_to_json(&items)).unwrap();
+
+        assert_eq!(round_tripped["I048/010"]["SAC"], serde_json::json!(25));
+        assert_eq!(round_tripped["I048/010"]["SIC"], serde_json::json!(10));
+        assert_eq!(round_tripped["I048/010"]["OK"], serde_json::json!(true));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_items_to_json_empty_map() {
+        let items = ItemMap::new();
+        assert_eq!(items_to_json(&items), "{}");
+    }
+
+    // ========== StreamParser tests ==========
+
+    #[test]
+    fn test_stream_parser_empty_buffer_yields_nothing() {
+        let mut stream = StreamParser::new(ParseOptions::default());
+        let result = stream.poll().unwrap();
+        assert!(result.records.is_empty());
+        assert_eq!(result.bytes_consumed, 0);
+        assert_eq!(result.remaining_blocks, 0);
+    }
+
+    #[test]
+    fn test_stream_parser_decodes_one_fed_block() {
+        ensure_initialized();
+        let mut stream = StreamParser::new(ParseOptions::default());
+        stream.feed(&[32, 0x00, 0x03]);
+
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].category, 32);
+        assert_eq!(result.bytes_consumed, 3);
+        assert_eq!(result.remaining_blocks, 0);
+    }
+
+    #[test]
+    fn test_stream_parser_retains_block_split_across_feeds() {
+        ensure_initialized();
+        let mut stream = StreamParser::new(ParseOptions::default());
+
+        // Split a 3-byte header-only block across two `feed` calls.
+        stream.feed(&[32, 0x00]);
+        let result = stream.poll().unwrap();
+        assert!(result.records.is_empty(), "partial block yields no records yet");
+        assert_eq!(result.bytes_consumed, 0, "partial bytes must not be drained");
+
+        stream.feed(&[0x03]);
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].category, 32);
+        assert_eq!(result.bytes_consumed, 3);
+    }
+
+    #[test]
+    fn test_stream_parser_decodes_multiple_blocks_in_one_poll() {
+        ensure_initialized();
+        let mut stream = StreamParser::new(ParseOptions::default());
+        stream.feed(&[32, 0x00, 0x03, 32, 0x00, 0x03, 32, 0x00, 0x03]);
+
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 3);
+        assert_eq!(result.bytes_consumed, 9);
+        assert_eq!(result.remaining_blocks, 0);
+    }
+
+    #[test]
+    fn test_stream_parser_push_and_next_record_pull_one_at_a_time() {
+        ensure_initialized();
+        let mut stream = StreamParser::new(ParseOptions::default());
+        stream.push(&[32, 0x00, 0x03, 32, 0x00, 0x03]);
+
+        let first = stream.next_record().unwrap().unwrap();
+        assert_eq!(first.category, 32);
+        let second = stream.next_record().unwrap().unwrap();
+        assert_eq!(second.category, 32);
+        assert!(stream.next_record().is_none());
+    }
+
+    #[test]
+    fn test_stream_parser_next_record_waits_for_a_complete_block() {
+        ensure_initialized();
+        let mut stream = StreamParser::new(ParseOptions::default());
+        stream.push(&[32, 0x00]);
+        assert!(stream.next_record().is_none());
+
+        stream.push(&[0x03]);
+        let record = stream.next_record().unwrap().unwrap();
+        assert_eq!(record.category, 32);
+    }
+
+    #[test]
+    fn test_stream_parser_next_record_honors_max_records_zero() {
+        let options = ParseOptions {
+            max_records: Some(0),
+            ..ParseOptions::default()
+        };
+        let mut stream = StreamParser::new(options);
+        stream.push(&[32, 0x00, 0x03]);
+        assert!(stream.next_record().is_none());
+    }
+
+    #[test]
+    fn test_stream_parser_pending_bytes_tracks_unconsumed_buffer() {
+        ensure_initialized();
+        let mut stream = StreamParser::new(ParseOptions::default());
+        assert_eq!(stream.pending_bytes(), 0);
+
+        stream.push(&[32, 0x00, 0x06, 0xAA, 0xBB]);
+        assert_eq!(stream.pending_bytes(), 5, "partial block still buffered");
+
+        stream.push(&[0xCC]);
+        stream.poll().unwrap();
+        assert_eq!(stream.pending_bytes(), 0, "complete block drained by poll");
+    }
+
+    #[test]
+    fn test_stream_parser_respects_max_records() {
+        ensure_initialized();
+        let options = ParseOptions {
+            max_records: Some(1),
+            ..Default::default()
+        };
+        let mut stream = StreamParser::new(options);
+        stream.feed(&[32, 0x00, 0x03, 32, 0x00, 0x03]);
+
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.bytes_consumed, 3);
+        assert_eq!(
+            result.remaining_blocks, 1,
+            "the second block should still be counted as buffered, not decoded"
+        );
+
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1, "second poll drains the leftover block");
+    }
+
+    #[test]
+    fn test_stream_parser_skips_filtered_categories() {
+        ensure_initialized();
+        let options = ParseOptions {
+            filter_category: Some(48),
+            ..Default::default()
+        };
+        let mut stream = StreamParser::new(options);
+        stream.feed(&[32, 0x00, 0x03]);
+
+        let result = stream.poll().unwrap();
+        assert!(result.records.is_empty(), "non-matching category must be skipped");
+        assert_eq!(
+            result.bytes_consumed, 3,
+            "the skipped block is still consumed from the buffer"
+        );
+    }
+
+    #[test]
+    fn test_stream_parser_rejects_undersized_length_field() {
+        let mut stream = StreamParser::new(ParseOptions::default());
+        stream.feed(&[32, 0x00, 0x02]);
+        assert!(matches!(
+            stream.poll(),
+            Err(AsterixError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_stream_parser_resync_skips_undersized_header_and_recovers() {
+        ensure_initialized();
+        let options = ParseOptions {
+            resync: true,
+            ..Default::default()
+        };
+        let mut stream = StreamParser::new(options);
+        // A corrupt 3-byte header (declared length 2) immediately followed by
+        // a valid header-only CAT032 block.
+        stream.feed(&[32, 0x00, 0x02, 32, 0x00, 0x03]);
+
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].category, 32);
+        assert_eq!(
+            result.bytes_consumed, 6,
+            "the corrupt header's bytes are skipped, not just the valid block's"
+        );
+    }
+
+    #[test]
+    fn test_stream_parser_without_resync_still_errors_on_undersized_header() {
+        let mut stream = StreamParser::new(ParseOptions::default());
+        stream.feed(&[32, 0x00, 0x02, 32, 0x00, 0x03]);
+        assert!(matches!(
+            stream.poll(),
+            Err(AsterixError::ParseError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_stream_parser_resync_recovers_after_failed_decode() {
+        ensure_initialized();
+        let mut data = oversized_block();
+        data.extend(vec![32, 0x00, 0x03]);
+
+        let options = ParseOptions {
+            resync: true,
+            ..Default::default()
+        };
+        let mut stream = StreamParser::new(options);
+        stream.feed(&data);
+
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.records[0].category, 32);
+    }
+
+    #[test]
+    fn test_stream_parser_resync_does_not_disturb_a_merely_partial_block() {
+        ensure_initialized();
+        let options = ParseOptions {
+            resync: true,
+            ..Default::default()
+        };
+        let mut stream = StreamParser::new(options);
+
+        // One complete block, plus the start of a second block's header —
+        // that's partial, not corrupt, and `poll` must wait for it rather
+        // than resyncing past it even with `resync` enabled.
+        stream.feed(&[32, 0x00, 0x03, 32, 0x00]);
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(
+            result.bytes_consumed, 3,
+            "the partial second block must be retained, not skipped"
+        );
+
+        stream.feed(&[0x03]);
+        let result = stream.poll().unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.bytes_consumed, 3);
+    }
+
+    #[test]
+    fn test_count_complete_blocks_counts_whole_blocks_only() {
+        let data = vec![32, 0x00, 0x03, 32, 0x00, 0x03, 32, 0x00];
+        assert_eq!(count_complete_blocks(&data), 2);
+    }
+
+    #[test]
+    fn test_count_complete_blocks_empty() {
+        assert_eq!(count_complete_blocks(&[]), 0);
+    }
+
+    // ========== parse_resilient() tests ==========
+
+    #[test]
+    fn test_parse_resilient_empty_data_errors() {
+        let result = parse_resilient(&[], ParseOptions::default());
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_parse_resilient_rejects_undersized_length() {
+        let data = vec![32, 0x00, 0x02];
+        let result = parse_resilient(&data, ParseOptions::default());
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_parse_resilient_rejects_truncated_trailing_block() {
+        let data = vec![32, 0x00, 0x0A, 0x01, 0x02];
+        let result = parse_resilient(&data, ParseOptions::default());
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_parse_resilient_decodes_multiple_whole_blocks() {
+        ensure_initialized();
+        let data = vec![32, 0x00, 0x03, 32, 0x00, 0x03];
+        let outcome = parse_resilient(&data, ParseOptions::default()).unwrap();
+        assert!(outcome.failures.is_empty());
+    }
+
+    /// Build a single block declaring `u16::MAX` — the largest length a
+    /// 3-byte header's 16-bit length field can hold (`MAX_ASTERIX_MESSAGE_SIZE`
+    /// itself is one larger and so can never appear on the wire at all) — so
+    /// the scan accepts it as a complete block, but the inner [`parse`] call
+    /// rejects it: its payload is nothing but zero bytes with no items the
+    /// declared length could possibly account for.
+    fn oversized_block() -> Vec<u8> {
+        let declared_len = u16::MAX;
+        let mut block = vec![32];
+        block.extend(declared_len.to_be_bytes());
+        block.extend(vec![0u8; declared_len as usize - 3]);
+        block
+    }
+
+    #[test]
+    fn test_parse_resilient_continue_on_error_records_failures_not_abort() {
+        ensure_initialized();
+        let mut data = oversized_block();
+        data.extend(vec![32, 0x00, 0x03]);
+
+        let options = ParseOptions {
+            continue_on_error: true,
+            ..Default::default()
+        };
+        let outcome = parse_resilient(&data, options).unwrap();
+        assert_eq!(outcome.failures.len(), 1);
+        assert_eq!(outcome.failures[0].category, 32);
+    }
+
+    #[test]
+    fn test_parse_resilient_without_continue_on_error_aborts_on_first_failure() {
+        ensure_initialized();
+        let data = oversized_block();
+
+        let result = parse_resilient(&data, ParseOptions::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_resilient_strict_overrides_continue_on_error() {
+        ensure_initialized();
+        let mut data = oversized_block();
+        data.extend(vec![32, 0x00, 0x03]);
+
+        let options = ParseOptions {
+            continue_on_error: true,
+            strict: true,
+            ..Default::default()
+        };
+        let result = parse_resilient(&data, options);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_resilient_rebases_failure_offset_onto_full_buffer() {
+        ensure_initialized();
+        let mut data = vec![32, 0x00, 0x03];
+        data.extend(oversized_block());
+
+        let options = ParseOptions {
+            continue_on_error: true,
+            ..Default::default()
+        };
+        let outcome = parse_resilient(&data, options).unwrap();
+        assert_eq!(outcome.failures.len(), 1);
+        let failure = &outcome.failures[0];
+        // The failing block starts 3 bytes into `data`; any offset the
+        // underlying error carries should be rebased past that point, not
+        // relative to the block's own sub-slice.
+        if let Some(inner_offset) = failure.error.diagnostic_offset() {
+            assert!(inner_offset >= failure.offset);
+        }
+    }
+
+    #[test]
+    fn test_parse_resilient_resync_skips_garbage_and_recovers() {
+        ensure_initialized();
+        // 0x99 starts a bogus header (declared length overruns the buffer);
+        // the valid block starts one byte later.
+        let data = vec![0x99, 32, 0x00, 0x03];
+
+        let options = ParseOptions {
+            resync: true,
+            ..Default::default()
+        };
+        let outcome = parse_resilient(&data, options).unwrap();
+        assert_eq!(outcome.resynced_bytes, 1);
+        assert!(outcome.failures.is_empty());
+    }
+
+    #[test]
+    fn test_parse_resilient_resync_respects_category_allow_set() {
+        ensure_initialized();
+        let data = vec![0x99, 32, 0x00, 0x03];
+
+        let options = ParseOptions {
+            resync: true,
+            resync_categories: Some(vec![48]), // 32 isn't in the allow-set
+            ..Default::default()
+        };
+        let result = parse_resilient(&data, options);
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_parse_resilient_without_resync_still_errors_on_garbage() {
+        let data = vec![0x99, 32, 0x00, 0x03];
+        let result = parse_resilient(&data, ParseOptions::default());
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_parse_resilient_resync_recovers_after_failed_decode() {
+        ensure_initialized();
+        let mut data = oversized_block();
+        data.extend(vec![32, 0x00, 0x03]);
+
+        let options = ParseOptions {
+            resync: true,
+            ..Default::default()
+        };
+        let outcome = parse_resilient(&data, options).unwrap();
+        assert_eq!(outcome.failures.len(), 1);
+        assert!(outcome.resynced_bytes > 0);
+    }
+
+    // ========== parse_each() tests ==========
+
+    #[test]
+    fn test_parse_each_empty_data_visits_nothing() {
+        let mut visited = 0;
+        parse_each(&[], ParseOptions::default(), |_| {
+            visited += 1;
+            std::ops::ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert_eq!(visited, 0);
+    }
+
+    #[test]
+    fn test_parse_each_visits_every_decoded_record() {
+        ensure_initialized();
+        let data = vec![32, 0x00, 0x03, 32, 0x00, 0x03];
+        let mut categories = Vec::new();
+        parse_each(&data, ParseOptions::default(), |record| {
+            categories.push(record.category);
+            std::ops::ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert!(categories.iter().all(|&cat| cat == 32));
+    }
+
+    #[test]
+    fn test_parse_each_stops_on_break() {
+        ensure_initialized();
+        let data = vec![32, 0x00, 0x03, 32, 0x00, 0x03, 32, 0x00, 0x03];
+        let mut visited = 0;
+        parse_each(&data, ParseOptions::default(), |_| {
+            visited += 1;
+            std::ops::ControlFlow::Break(())
+        })
+        .unwrap();
+        assert!(visited <= 1);
+    }
+
+    #[test]
+    fn test_parse_each_propagates_first_error() {
+        let data = vec![32, 0x00, 0x02]; // declared length smaller than header
+        let result = parse_each(&data, ParseOptions::default(), |_| {
+            std::ops::ControlFlow::Continue(())
+        });
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,236 @@
+//! Symlink-aware path auditing for config directory trees.
+//!
+//! [`crate::ffi::load_category_files_from_dir`] walks a config directory
+//! (and its `asterix.d/` drop-in) that may contain files an operator didn't
+//! author directly. A purely lexical check like [`crate::ffi::reject_path_traversal`]
+//! catches `..` segments but not a directory *inside* the trusted root that
+//! is itself a symlink pointing somewhere else entirely (e.g. a config tree
+//! shipped with `asterix.d/evil -> /etc`). [`PathAuditor`] closes that gap by
+//! resolving each path's directory components against the filesystem and
+//! rejecting any component whose resolved target falls outside the root it
+//! was constructed with.
+//!
+//! Auditing walks every path component, so a [`PathAuditor`] caches which
+//! prefix directories it has already cleared in a `HashSet`: loading many
+//! category files out of the same directory only pays the `symlink_metadata`
+//! cost for that directory once.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::error::{AsterixError, Result};
+
+/// Audits paths against a trusted root, rejecting `..` escapes and symlinked
+/// directory components that resolve outside the root.
+///
+/// Construct one per trusted root and reuse it across every path checked
+/// against that root, so repeated lookups in the same directory tree don't
+/// re-stat prefixes that already passed.
+pub(crate) struct PathAuditor {
+    root: PathBuf,
+    audited: HashSet<PathBuf>,
+}
+
+impl PathAuditor {
+    /// Create an auditor trusting `root`. `root` itself is never checked for
+    /// being a symlink; only components audited *relative to* it are.
+    pub(crate) fn new(root: impl AsRef<Path>) -> Self {
+        PathAuditor {
+            root: root.as_ref().to_path_buf(),
+            audited: HashSet::new(),
+        }
+    }
+
+    /// Audit `path` (absolute, or relative to this auditor's root).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::InvalidData`] naming the offending component
+    /// if a `..` segment climbs above the root, or if a directory component
+    /// is a symlink whose resolved target falls outside the root.
+    pub(crate) fn audit(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let full_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.root.join(path)
+        };
+
+        let canonical_root = fs::canonicalize(&self.root).unwrap_or_else(|_| self.root.clone());
+
+        // A `..` only escapes once it climbs above the root itself, so depth
+        // is checked against the depth the root's own components reach.
+        let root_components = if full_path.starts_with(&self.root) {
+            self.root.components().count()
+        } else {
+            0
+        };
+
+        let mut depth: i64 = 0;
+        let mut floor: i64 = 0;
+        let mut prefix = PathBuf::new();
+        for (index, component) in full_path.components().enumerate() {
+            if index == root_components {
+                floor = depth;
+            }
+            match component {
+                Component::CurDir => continue,
+                Component::ParentDir => {
+                    depth -= 1;
+                    if index >= root_components && depth < floor {
+                        return Err(AsterixError::InvalidData(format!(
+                            "path traversal: component '..' in '{}' climbs above trusted root '{}'",
+                            full_path.display(),
+                            self.root.display()
+                        )));
+                    }
+                    prefix.pop();
+                    continue;
+                }
+                Component::Normal(part) => {
+                    depth += 1;
+                    prefix.push(part);
+                }
+                Component::RootDir | Component::Prefix(_) => {
+                    prefix.push(component.as_os_str());
+                    continue;
+                }
+            }
+
+            if self.audited.contains(&prefix) {
+                continue;
+            }
+
+            if let Ok(metadata) = fs::symlink_metadata(&prefix) {
+                if metadata.file_type().is_symlink() {
+                    let resolved = fs::canonicalize(&prefix).map_err(|e| {
+                        AsterixError::InvalidData(format!(
+                            "cannot resolve symlink component '{}': {e}",
+                            prefix.display()
+                        ))
+                    })?;
+                    if !resolved.starts_with(&canonical_root) {
+                        return Err(AsterixError::InvalidData(format!(
+                            "path component '{}' is a symlink escaping trusted root '{}' (resolves to '{}')",
+                            prefix.display(),
+                            self.root.display(),
+                            resolved.display()
+                        )));
+                    }
+                }
+            }
+
+            self.audited.insert(prefix.clone());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "asterix_path_auditor_test_{name}_{}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn test_audit_accepts_plain_file_under_root() {
+        let root = unique_temp_dir("plain_file");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("asterix_cat048.xml"), b"<category/>").unwrap();
+
+        let mut auditor = PathAuditor::new(&root);
+        assert!(auditor.audit(root.join("asterix_cat048.xml")).is_ok());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_audit_rejects_dotdot_escape() {
+        let root = unique_temp_dir("dotdot_escape");
+        fs::create_dir_all(&root).unwrap();
+
+        let mut auditor = PathAuditor::new(&root);
+        let result = auditor.audit("../../etc/passwd");
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_audit_allows_balanced_dotdot() {
+        let root = unique_temp_dir("balanced_dotdot");
+        fs::create_dir_all(root.join("a/b")).unwrap();
+
+        let mut auditor = PathAuditor::new(&root);
+        assert!(auditor.audit("a/b/../../a").is_ok());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_audit_caches_audited_prefixes() {
+        let root = unique_temp_dir("caches_prefixes");
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("sub/a.xml"), b"<a/>").unwrap();
+        fs::write(root.join("sub/b.xml"), b"<b/>").unwrap();
+
+        let mut auditor = PathAuditor::new(&root);
+        auditor.audit(root.join("sub/a.xml")).unwrap();
+        assert!(auditor.audited.contains(&root.join("sub")));
+
+        // Second audit under the same already-cached prefix should still
+        // succeed (and not need the directory to still exist on disk to
+        // skip re-checking that prefix).
+        assert!(auditor.audit(root.join("sub/b.xml")).is_ok());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_audit_rejects_symlink_escaping_root() {
+        use std::os::unix::fs::symlink;
+
+        let root = unique_temp_dir("symlink_escape");
+        let outside = unique_temp_dir("symlink_escape_target");
+        fs::create_dir_all(&root).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("passwd.xml"), b"<secret/>").unwrap();
+
+        let link = root.join("linked");
+        symlink(&outside, &link).unwrap();
+
+        let mut auditor = PathAuditor::new(&root);
+        let result = auditor.audit(root.join("linked/passwd.xml"));
+        assert!(matches!(result, Err(AsterixError::InvalidData(_))));
+
+        let _ = fs::remove_dir_all(&root);
+        let _ = fs::remove_dir_all(&outside);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_audit_allows_symlink_staying_inside_root() {
+        use std::os::unix::fs::symlink;
+
+        let root = unique_temp_dir("symlink_internal");
+        fs::create_dir_all(root.join("real")).unwrap();
+        fs::write(root.join("real/cat048.xml"), b"<category/>").unwrap();
+
+        let link = root.join("alias");
+        symlink(root.join("real"), &link).unwrap();
+
+        let mut auditor = PathAuditor::new(&root);
+        let result = auditor.audit(root.join("alias/cat048.xml"));
+        assert!(result.is_ok());
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
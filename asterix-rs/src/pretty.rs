@@ -0,0 +1,262 @@
+//! Human-readable, ANSI-colored rendering of decoded records, plus a
+//! size-rotating file sink to write them to.
+//!
+//! [`Writer`] renders each [`AsterixRecord`] as a single line to any
+//! `std::io::Write`, coloring the category number with a small fixed
+//! palette keyed on `category`, so a terminal following a live decode can
+//! tell interleaved categories apart at a glance. Color is written as raw
+//! ANSI SGR escape codes rather than through a color crate — this crate has
+//! no `Cargo.toml` to add one to, and a handful of escape sequences is all a
+//! "grep the terminal" workflow needs; [`crate::glob`] makes the same
+//! tradeoff (a minimal hand-rolled matcher) for the same reason.
+//!
+//! [`RotatingFileSink`] is a `std::io::Write` that rolls the current file
+//! over to a numbered sibling once it grows past a configured byte
+//! capacity, for pairing with [`Writer::new`] when the destination is a
+//! long-running capture file rather than a terminal.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asterix::{init_default, parse, ParseOptions};
+//! # use asterix::pretty::Writer;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! init_default()?;
+//! let data = std::fs::read("sample.asterix")?;
+//! let records = parse(&data, ParseOptions::default())?;
+//!
+//! let mut writer = Writer::new(std::io::stdout());
+//! for record in &records {
+//!     writer.write_record(record)?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::error::{AsterixError, Result};
+use crate::types::AsterixRecord;
+
+/// ANSI SGR foreground color codes cycled through by [`category_color`].
+const PALETTE: [&str; 6] = [
+    "\x1b[31m", // red
+    "\x1b[32m", // green
+    "\x1b[33m", // yellow
+    "\x1b[34m", // blue
+    "\x1b[35m", // magenta
+    "\x1b[36m", // cyan
+];
+
+/// Reset any SGR attributes set by [`category_color`].
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Foreground color for `category`, picked from a fixed palette by
+/// `category % PALETTE.len()` so the same category always renders the same
+/// color within a run.
+fn category_color(category: u8) -> &'static str {
+    PALETTE[category as usize % PALETTE.len()]
+}
+
+/// Renders decoded records as human-readable, optionally ANSI-colored lines
+/// to a `std::io::Write`.
+pub struct Writer<W: Write> {
+    inner: W,
+    color: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Wrap `inner`, with ANSI coloring enabled by default.
+    pub fn new(inner: W) -> Self {
+        Self { inner, color: true }
+    }
+
+    /// Disable ANSI color codes, e.g. when `inner` isn't a terminal (a log
+    /// file, a pipe into another tool).
+    #[must_use]
+    pub fn without_color(mut self) -> Self {
+        self.color = false;
+        self
+    }
+
+    /// Render one record as a single line: timestamp, category, length, and
+    /// item count, e.g. `[1718000000000] CAT048 len=42 items=6`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if writing to the underlying
+    /// `std::io::Write` fails.
+    pub fn write_record(&mut self, record: &AsterixRecord) -> Result<()> {
+        if self.color {
+            write!(self.inner, "{}", category_color(record.category)).map_err(io_err)?;
+        }
+        write!(
+            self.inner,
+            "[{}] CAT{:03} len={} items={}",
+            record.timestamp_ms,
+            record.category,
+            record.length,
+            record.items.len()
+        )
+        .map_err(io_err)?;
+        if self.color {
+            write!(self.inner, "{ANSI_RESET}").map_err(io_err)?;
+        }
+        writeln!(self.inner).map_err(io_err)?;
+        Ok(())
+    }
+
+    /// Flush the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if flushing fails.
+    pub fn flush(&mut self) -> Result<()> {
+        self.inner.flush().map_err(io_err)
+    }
+}
+
+/// A `std::io::Write` file sink that rotates to a new, numbered sibling file
+/// once the currently-open file reaches a configured byte capacity.
+///
+/// The first file written is `path` itself; once it reaches `capacity`
+/// bytes it's renamed to `path` suffixed with `.1` (then `.2`, ...) and a
+/// fresh, empty file is opened at `path` to continue writing to. Pair with
+/// [`Writer::new`] to get a rotating, human-readable record log.
+pub struct RotatingFileSink {
+    path: PathBuf,
+    capacity: u64,
+    written: u64,
+    generation: u32,
+    file: File,
+}
+
+impl RotatingFileSink {
+    /// Open `path` for appending, creating it if it doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::IOError`] if `path` can't be opened or its
+    /// existing size can't be read.
+    pub fn new(path: impl Into<PathBuf>, capacity: u64) -> Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(io_err)?;
+        let written = file.metadata().map_err(io_err)?.len();
+        Ok(Self {
+            path,
+            capacity,
+            written,
+            generation: 0,
+            file,
+        })
+    }
+
+    /// Path the current file is renamed to on rotation: `path` suffixed
+    /// with `.{generation}`.
+    fn rotated_path(&self) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", self.generation));
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> std::io::Result<()> {
+        self.file.flush()?;
+        self.generation += 1;
+        std::fs::rename(&self.path, self.rotated_path())?;
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFileSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.written >= self.capacity {
+            self.rotate()?;
+        }
+        let n = self.file.write(buf)?;
+        self.written += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn io_err(err: std::io::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_record(category: u8) -> AsterixRecord {
+        AsterixRecord {
+            category,
+            timestamp_ms: 1_718_000_000_000,
+            length: 42,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_write_record_without_color_has_no_escape_codes() {
+        let mut buf = Vec::new();
+        let mut writer = Writer::new(&mut buf).without_color();
+        writer.write_record(&sample_record(48)).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(!text.contains('\x1b'));
+        assert_eq!(text, "[1718000000000] CAT048 len=42 items=0\n");
+    }
+
+    #[test]
+    fn test_write_record_with_color_wraps_line_in_category_color() {
+        let mut buf = Vec::new();
+        let mut writer = Writer::new(&mut buf);
+        writer.write_record(&sample_record(48)).unwrap();
+        let text = String::from_utf8(buf).unwrap();
+        assert!(text.starts_with(category_color(48)));
+        assert!(text.trim_end_matches('\n').ends_with(ANSI_RESET));
+        assert!(text.contains("CAT048"));
+    }
+
+    #[test]
+    fn test_category_color_cycles_through_palette() {
+        assert_eq!(category_color(0), PALETTE[0]);
+        assert_eq!(category_color(PALETTE.len() as u8), PALETTE[0]);
+    }
+
+    #[test]
+    fn test_rotating_file_sink_rotates_once_capacity_exceeded() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("asterix_pretty_rotate_test_{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let rotated = PathBuf::from(format!("{}.1", path.display()));
+        let _ = std::fs::remove_file(&rotated);
+
+        let mut sink = RotatingFileSink::new(&path, 8).unwrap();
+        sink.write_all(b"12345678").unwrap(); // exactly at capacity, doesn't rotate yet
+        sink.write_all(b"rotated-line\n").unwrap(); // next write rotates first
+
+        assert!(rotated.exists(), "expected a rotated sibling file to exist");
+        let rotated_contents = std::fs::read_to_string(&rotated).unwrap();
+        assert_eq!(rotated_contents, "12345678");
+        let current_contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(current_contents, "rotated-line\n");
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&rotated);
+    }
+}
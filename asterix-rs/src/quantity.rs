@@ -0,0 +1,176 @@
+//! Named scale/unit conversions for raw decoded integer fields
+//!
+//! [`ParsedValue::Decimal`] already carries a `raw`/`scale`/`unit` triple
+//! for a field a caller (or [`crate::encode::RecordBuilder`]) built with its
+//! engineering-unit conversion in hand, but a field decoded straight off
+//! the wire arrives as a plain [`ParsedValue::Integer`]/[`Unsigned`](ParsedValue::Unsigned) —
+//! this crate's C++ layer resolves LSB scaling before handing JSON back, so
+//! by the time a field reaches Rust there's no scale/unit metadata left to
+//! attach. [`Conversion`] lets a caller supply that metadata itself, keyed
+//! by item/field path in [`ParseOptions::conversions`](crate::types::ParseOptions::conversions),
+//! so [`crate::parser::parse`]/[`crate::parser::parse_resilient`] can
+//! re-wrap the matching raw fields as either a scaled
+//! [`ParsedValue::Float`] ([`ParseOptions::eager_conversions`](crate::types::ParseOptions::eager_conversions)
+//! set) or a [`ParsedValue::Decimal`] carrying the raw value and conversion
+//! alongside it (unset, the default — cheaper when most converted fields
+//! are never read, and re-scalable without re-parsing).
+//!
+//! [`Conversion::apply`] is also usable directly, independent of
+//! `ParseOptions`, for a caller that already has a raw field in hand and
+//! just wants its [`Quantity`].
+
+use crate::types::ParsedValue;
+use serde::{Deserialize, Serialize};
+
+/// A raw integer field's value once scaled and given a unit
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Quantity<'a> {
+    /// `raw as f64 * scale`
+    pub value: f64,
+    /// Engineering unit (e.g. `"NM"`, `"kt"`, `"deg"`, `"FL"`), if known
+    pub unit: Option<&'a str>,
+}
+
+/// How to turn a raw decoded integer field into a physical
+/// [`Quantity`] — an LSB scale factor, an optional unit label, and whether
+/// the raw bit pattern should be read as signed.
+///
+/// `signed` matters when the source field is a [`ParsedValue::Unsigned`]:
+/// some ASTERIX fields store what's conceptually a signed quantity (e.g. a
+/// rate of climb) in a bit pattern the C++ layer surfaces as unsigned.
+/// Setting `signed` reinterprets that value as `i64` before scaling rather
+/// than trusting its unsigned decode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conversion {
+    /// Multiplier applied to the raw integer value (the field's LSB)
+    pub scale: f64,
+    /// Engineering unit this conversion produces (e.g. `"NM"`, `"kt"`)
+    pub unit: Option<String>,
+    /// Whether the raw value should be treated as signed (see the type docs)
+    pub signed: bool,
+}
+
+impl Conversion {
+    /// A signed conversion: `scale` applied to the field's value as-is.
+    pub fn new(scale: f64, unit: Option<String>) -> Self {
+        Self {
+            scale,
+            unit,
+            signed: true,
+        }
+    }
+
+    /// A conversion that reinterprets an unsigned raw field as unsigned
+    /// (rather than signed) before scaling.
+    pub fn unsigned(scale: f64, unit: Option<String>) -> Self {
+        Self {
+            scale,
+            unit,
+            signed: false,
+        }
+    }
+
+    /// Extract this conversion's raw integer out of `value`, honoring
+    /// [`Self::signed`] for a [`ParsedValue::Unsigned`] source.
+    ///
+    /// Returns `None` for any variant that isn't already numeric
+    /// ([`ParsedValue::Integer`], [`ParsedValue::Unsigned`], or an existing
+    /// [`ParsedValue::Decimal`]'s `raw`).
+    pub fn raw_value(&self, value: &ParsedValue) -> Option<i64> {
+        match value {
+            ParsedValue::Integer(raw) => Some(*raw),
+            ParsedValue::Decimal { raw, .. } => Some(*raw),
+            ParsedValue::Unsigned(raw) => {
+                if self.signed {
+                    i64::try_from(*raw).ok()
+                } else {
+                    Some(*raw as i64)
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Scale `value`'s raw integer into a [`Quantity`], or `None` if
+    /// [`Self::raw_value`] can't extract one.
+    pub fn apply<'a>(&'a self, value: &ParsedValue) -> Option<Quantity<'a>> {
+        self.raw_value(value).map(|raw| Quantity {
+            value: raw as f64 * self.scale,
+            unit: self.unit.as_deref(),
+        })
+    }
+
+    /// Re-wrap `value`'s raw integer using this conversion: a scaled
+    /// [`ParsedValue::Float`] if `eager`, otherwise a [`ParsedValue::Decimal`]
+    /// carrying the raw value and this conversion's scale/unit alongside it.
+    ///
+    /// Returns `None` (leaving the original field untouched) under the same
+    /// condition [`Self::raw_value`] does.
+    pub fn convert(&self, value: &ParsedValue, eager: bool) -> Option<ParsedValue> {
+        let raw = self.raw_value(value)?;
+        Some(if eager {
+            ParsedValue::Float(raw as f64 * self.scale)
+        } else {
+            ParsedValue::Decimal {
+                raw,
+                scale: self.scale,
+                unit: self.unit.clone(),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_scales_integer() {
+        let conversion = Conversion::new(0.25, Some("NM".to_string()));
+        let quantity = conversion.apply(&ParsedValue::Integer(40)).unwrap();
+        assert_eq!(quantity.value, 10.0);
+        assert_eq!(quantity.unit, Some("NM"));
+    }
+
+    #[test]
+    fn test_apply_on_non_numeric_value_is_none() {
+        let conversion = Conversion::new(1.0, None);
+        assert!(conversion.apply(&ParsedValue::String("x".to_string())).is_none());
+    }
+
+    #[test]
+    fn test_unsigned_conversion_keeps_raw_bit_pattern() {
+        let conversion = Conversion::unsigned(1.0, None);
+        let quantity = conversion.apply(&ParsedValue::Unsigned(200)).unwrap();
+        assert_eq!(quantity.value, 200.0);
+    }
+
+    #[test]
+    fn test_signed_conversion_rejects_out_of_range_unsigned() {
+        let conversion = Conversion::new(1.0, None);
+        assert!(conversion
+            .raw_value(&ParsedValue::Unsigned(u64::MAX))
+            .is_none());
+    }
+
+    #[test]
+    fn test_convert_eager_yields_float() {
+        let conversion = Conversion::new(0.5, Some("kt".to_string()));
+        let converted = conversion.convert(&ParsedValue::Integer(10), true).unwrap();
+        assert_eq!(converted, ParsedValue::Float(5.0));
+    }
+
+    #[test]
+    fn test_convert_lazy_yields_decimal_with_raw_preserved() {
+        let conversion = Conversion::new(0.5, Some("kt".to_string()));
+        let converted = conversion.convert(&ParsedValue::Integer(10), false).unwrap();
+        assert_eq!(
+            converted,
+            ParsedValue::Decimal {
+                raw: 10,
+                scale: 0.5,
+                unit: Some("kt".to_string())
+            }
+        );
+    }
+}
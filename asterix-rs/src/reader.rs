@@ -0,0 +1,496 @@
+//! Streaming, record-framed parsing over `std::io::Read`
+//!
+//! [`AsterixReader`] wraps any [`std::io::Read`] source (a TCP socket, a pipe,
+//! a file opened for sequential access) and yields one [`AsterixRecord`] at a
+//! time, refilling an internal buffer from the source as needed. It builds on
+//! [`crate::parse`] (via [`asterix_parse_offset`](crate::ffi) under the hood,
+//! the same FFI entry point [`crate::parser::parse_with_offset`] uses) — only
+//! the 3-byte category+length header is interpreted in pure Rust; each
+//! complete record's bytes are still handed to the C++ decoder.
+//!
+//! This is the right tool when data arrives incrementally and the total size
+//! isn't known up front, and `inner` itself is the buffer to pull from. For an
+//! already-in-memory buffer, [`crate::parser::records_iter`] is cheaper since
+//! it borrows rather than copies each record's bytes. For data pushed in from
+//! elsewhere (e.g. bytes handed over from another thread or an async runtime
+//! that can't be wrapped in a blocking [`Read`]), use [`crate::parser::StreamParser`]
+//! instead, which is fed buffers directly rather than reading from a source.
+//!
+//! Unlike [`crate::parser::parse_with_offset`] (which hands its whole `data`
+//! argument to the C++ decoder in one call and is therefore capped at a fixed
+//! maximum buffer size), `AsterixReader` never holds more than one block's
+//! worth of bytes in `buf` at a time, so a multi-gigabyte capture or an
+//! open-ended live feed streams through in roughly constant memory — that
+//! limit applies per block, not per source.
+//!
+//! [`AsterixReader::for_each_parallel`] fans a stream's records out across a
+//! worker thread pool, the streaming equivalent of
+//! [`crate::parallel::parse_parallel`]'s in-memory fan-out, for downstream
+//! transforms (re-encoding, aggregation, filtering) that want to scale
+//! across cores without buffering the whole capture first.
+
+use std::io::Read;
+use std::thread;
+
+use crate::error::{AsterixError, Result};
+use crate::parser::{count_complete_blocks, parse};
+use crate::types::{AsterixRecord, ParseOptions, ParseResult};
+
+/// Length of the category + 2-byte big-endian length header every block starts with.
+const HEADER_LEN: usize = 3;
+
+/// Size of each chunk read from the underlying source while refilling the buffer.
+const READ_CHUNK_SIZE: usize = 8192;
+
+/// Reads ASTERIX records one frame at a time from any [`std::io::Read`] source.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, AsterixReader, ParseOptions};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let file = std::fs::File::open("stream.asterix")?;
+/// let mut reader = AsterixReader::new(file);
+///
+/// while let Some(record) = reader.next_record()? {
+///     println!("Category {}: {} items", record.category, record.items.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsterixReader<R> {
+    inner: R,
+    options: ParseOptions,
+    buf: Vec<u8>,
+}
+
+impl<R: Read> AsterixReader<R> {
+    /// Wrap `inner` for record-framed streaming, using default parse options.
+    pub fn new(inner: R) -> Self {
+        Self::with_options(inner, ParseOptions::default())
+    }
+
+    /// Wrap `inner`, applying `options` to every decoded record.
+    pub fn with_options(inner: R, options: ParseOptions) -> Self {
+        AsterixReader {
+            inner,
+            options,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Read the next complete record from the stream.
+    ///
+    /// Returns `Ok(None)` at a clean end-of-stream (no partial record
+    /// buffered). A record whose declared length exceeds what's currently
+    /// buffered is not an error: more bytes are read from `inner` until the
+    /// record is complete or the source is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::InvalidData`] if a block's declared length is
+    /// smaller than the 3-byte header, [`AsterixError::Truncated`] if
+    /// `inner` reaches EOF while a record is still incomplete, and
+    /// [`AsterixError::AllocationFailed`] if [`ParseOptions::max_alloc_bytes`]
+    /// is set and a block's declared length exceeds it.
+    pub fn next_record(&mut self) -> Result<Option<AsterixRecord>> {
+        loop {
+            if !self.fill_at_least(HEADER_LEN)? {
+                if self.buf.is_empty() {
+                    return Ok(None);
+                }
+                return Err(AsterixError::Truncated {
+                    buffered: self.buf.len(),
+                    declared: HEADER_LEN,
+                });
+            }
+
+            let declared_len = u16::from_be_bytes([self.buf[1], self.buf[2]]) as usize;
+            if declared_len < HEADER_LEN {
+                return Err(AsterixError::InvalidData(format!(
+                    "declared block length {declared_len} is smaller than the {HEADER_LEN}-byte header"
+                )));
+            }
+
+            if !self.fill_at_least(declared_len)? {
+                return Err(AsterixError::Truncated {
+                    buffered: self.buf.len(),
+                    declared: declared_len,
+                });
+            }
+
+            let block: Vec<u8> = self.buf.drain(..declared_len).collect();
+            if let Some(record) = parse(&block, self.options.clone())?.into_iter().next() {
+                return Ok(Some(record));
+            }
+            // Block decoded to zero records (e.g. filtered out by the C++ side); keep scanning.
+        }
+    }
+
+    /// Read up to `n` records in one call.
+    ///
+    /// Repeatedly calls [`next_record`](Self::next_record) and stops early at
+    /// a clean end-of-stream, so the returned [`ParseResult`] may hold fewer
+    /// than `n` records. `bytes_consumed` sums the decoded records' declared
+    /// lengths; `remaining_blocks` counts complete, not-yet-decoded blocks
+    /// already sitting in the internal buffer (never more than a refill's
+    /// worth, since `next_record` only reads as much as each block needs).
+    ///
+    /// A record that is merely incomplete still surfaces as
+    /// [`AsterixError::Truncated`] from the underlying `next_record` call,
+    /// the same as single-record reading: with a blocking [`Read`] source
+    /// there is no distinct "not enough data yet" state to report, since
+    /// requesting more bytes and hitting EOF are the only two outcomes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::AllocationFailed`] if
+    /// [`ParseOptions::max_alloc_bytes`] is set and `n` (driving this
+    /// batch's `Vec::with_capacity`) exceeds it. Otherwise propagates any
+    /// error from [`next_record`](Self::next_record).
+    pub fn next_batch(&mut self, n: usize) -> Result<ParseResult> {
+        let requested_bytes = n.saturating_mul(std::mem::size_of::<AsterixRecord>());
+        if let Some(limit) = self.options.max_alloc_bytes {
+            if requested_bytes > limit {
+                return Err(AsterixError::AllocationFailed {
+                    requested: requested_bytes,
+                    limit,
+                });
+            }
+        }
+
+        let mut records = Vec::new();
+        records.try_reserve(n).map_err(|_| AsterixError::AllocationFailed {
+            requested: requested_bytes,
+            limit: self.options.max_alloc_bytes.unwrap_or(usize::MAX),
+        })?;
+        let mut bytes_consumed = 0usize;
+
+        while records.len() < n {
+            match self.next_record()? {
+                Some(record) => {
+                    bytes_consumed += record.length as usize;
+                    records.push(record);
+                }
+                None => break,
+            }
+        }
+
+        Ok(ParseResult {
+            records,
+            bytes_consumed,
+            remaining_blocks: count_complete_blocks(&self.buf),
+        })
+    }
+
+    /// Drain the stream in batches of up to `batch_size` records, handing
+    /// each batch to up to `threads` worker threads (via [`thread::scope`],
+    /// the same fan-out [`crate::parallel::parse_parallel`] uses for
+    /// in-memory buffers) so `f` runs across cores instead of one record at
+    /// a time on the calling thread.
+    ///
+    /// Each record in a batch is handed to exactly one worker, so whatever
+    /// `f` accumulates (e.g. per-category counters behind an atomic or a
+    /// mutex) ends up the same regardless of how the OS schedules the
+    /// worker threads — only `f`'s own side effects need to be thread-safe,
+    /// which is why it's bounded by `Sync` rather than just `Send`. Batches
+    /// themselves are still processed one after another, so a record is
+    /// never handed to `f` out of stream order relative to records in an
+    /// earlier batch.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error from the underlying [`next_batch`](Self::next_batch)
+    /// call; a batch already drained before the error is still fully
+    /// processed.
+    pub fn for_each_parallel<F>(&mut self, batch_size: usize, threads: usize, f: F) -> Result<()>
+    where
+        F: Fn(&AsterixRecord) + Sync,
+    {
+        loop {
+            let batch = self.next_batch(batch_size)?;
+            if batch.records.is_empty() {
+                return Ok(());
+            }
+
+            let worker_count = threads.max(1).min(batch.records.len());
+            let chunk_size = batch.records.len().div_ceil(worker_count);
+            let f = &f;
+            thread::scope(|scope| {
+                for chunk in batch.records.chunks(chunk_size) {
+                    scope.spawn(move || {
+                        for record in chunk {
+                            f(record);
+                        }
+                    });
+                }
+            });
+
+            if batch.records.len() < batch_size {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Read from `inner` until at least `n` bytes are buffered.
+    ///
+    /// Returns `Ok(true)` once that many bytes are available, or `Ok(false)`
+    /// if `inner` reaches EOF first (leaving whatever was buffered in place).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsterixError::AllocationFailed`] if
+    /// [`ParseOptions::max_alloc_bytes`] is set and `n` exceeds it, or if the
+    /// fallible reservation for `n` bytes itself fails (a genuine allocation
+    /// failure, surfaced as an error here instead of the process aborting).
+    fn fill_at_least(&mut self, n: usize) -> Result<bool> {
+        if let Some(limit) = self.options.max_alloc_bytes {
+            if n > limit {
+                return Err(AsterixError::AllocationFailed {
+                    requested: n,
+                    limit,
+                });
+            }
+        }
+        let additional = n.saturating_sub(self.buf.len());
+        if self.buf.try_reserve(additional).is_err() {
+            return Err(AsterixError::AllocationFailed {
+                requested: n,
+                limit: self.options.max_alloc_bytes.unwrap_or(usize::MAX),
+            });
+        }
+
+        let mut chunk = [0u8; READ_CHUNK_SIZE];
+        while self.buf.len() < n {
+            let read = self.inner.read(&mut chunk)?;
+            if read == 0 {
+                return Ok(false);
+            }
+            self.buf.extend_from_slice(&chunk[..read]);
+        }
+        Ok(true)
+    }
+}
+
+/// Free-function equivalent of [`AsterixReader::with_options`], for callers
+/// who'd rather call a function than name the type.
+///
+/// The returned [`AsterixReader`] is itself an `Iterator<Item =
+/// Result<AsterixRecord>>`, so this satisfies the same "parse straight off a
+/// `Read` source without preallocating the whole stream" need without giving
+/// up [`AsterixReader::next_record`]/[`AsterixReader::next_batch`]'s
+/// record-at-a-time and batch-at-a-time control.
+pub fn parse_reader<R: Read>(reader: R, options: ParseOptions) -> AsterixReader<R> {
+    AsterixReader::with_options(reader, options)
+}
+
+impl<R: Read> Iterator for AsterixReader<R> {
+    type Item = Result<AsterixRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_record_empty_stream_returns_none() {
+        let mut reader = AsterixReader::new(std::io::Cursor::new(Vec::<u8>::new()));
+        assert!(reader.next_record().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_next_record_rejects_short_declared_length() {
+        // category=48, declared_len=2 (smaller than the 3-byte header)
+        let data = [0x30, 0x00, 0x02];
+        let mut reader = AsterixReader::new(std::io::Cursor::new(data.to_vec()));
+        let err = reader.next_record().unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+
+    #[test]
+    fn test_next_record_truncated_header() {
+        // Only 2 bytes ever arrive: never enough for even the header.
+        let mut reader = AsterixReader::new(std::io::Cursor::new(vec![0x30, 0x00]));
+        let err = reader.next_record().unwrap_err();
+        assert!(matches!(
+            err,
+            AsterixError::Truncated {
+                buffered: 2,
+                declared: 3
+            }
+        ));
+    }
+
+    #[test]
+    fn test_next_record_truncated_body() {
+        // Declares a 10-byte block but the stream ends after the header.
+        let data = [0x30, 0x00, 0x0A, 0x01, 0x02];
+        let mut reader = AsterixReader::new(std::io::Cursor::new(data.to_vec()));
+        let err = reader.next_record().unwrap_err();
+        assert!(matches!(
+            err,
+            AsterixError::Truncated {
+                buffered: 5,
+                declared: 10
+            }
+        ));
+    }
+
+    #[test]
+    fn test_next_record_rejects_declared_length_past_max_alloc_bytes() {
+        // Declares a 100-byte block, but max_alloc_bytes only allows 10.
+        let data = [0x30, 0x00, 0x64];
+        let options = ParseOptions {
+            max_alloc_bytes: Some(10),
+            ..Default::default()
+        };
+        let mut reader = AsterixReader::with_options(std::io::Cursor::new(data.to_vec()), options);
+        let err = reader.next_record().unwrap_err();
+        assert!(matches!(
+            err,
+            AsterixError::AllocationFailed {
+                requested: 100,
+                limit: 10
+            }
+        ));
+    }
+
+    #[test]
+    fn test_fill_at_least_reads_across_multiple_chunks() {
+        // Exercise the buffering loop directly with a source that dribbles
+        // out single bytes at a time.
+        struct OneByteAtATime(Vec<u8>);
+        impl Read for OneByteAtATime {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if self.0.is_empty() {
+                    return Ok(0);
+                }
+                buf[0] = self.0.remove(0);
+                Ok(1)
+            }
+        }
+
+        let mut reader = AsterixReader::new(OneByteAtATime(vec![0x30, 0x00, 0x03]));
+        assert!(reader.fill_at_least(3).unwrap());
+        assert_eq!(reader.buf, vec![0x30, 0x00, 0x03]);
+    }
+
+    #[test]
+    fn test_next_batch_reads_up_to_n_records() {
+        let data = [
+            0x30, 0x00, 0x03, // record 1: category=48, len=3
+            0x30, 0x00, 0x03, // record 2
+            0x30, 0x00, 0x03, // record 3
+        ];
+        let mut reader = AsterixReader::new(std::io::Cursor::new(data.to_vec()));
+        let result = reader.next_batch(2).unwrap();
+        assert_eq!(result.records.len(), 2);
+        assert_eq!(result.bytes_consumed, 6);
+        assert_eq!(result.remaining_blocks, 1);
+    }
+
+    #[test]
+    fn test_next_batch_stops_early_at_clean_eof() {
+        let data = [0x30, 0x00, 0x03];
+        let mut reader = AsterixReader::new(std::io::Cursor::new(data.to_vec()));
+        let result = reader.next_batch(5).unwrap();
+        assert_eq!(result.records.len(), 1);
+        assert_eq!(result.bytes_consumed, 3);
+        assert_eq!(result.remaining_blocks, 0);
+    }
+
+    #[test]
+    fn test_next_batch_empty_stream_yields_empty_result() {
+        let mut reader = AsterixReader::new(std::io::Cursor::new(Vec::<u8>::new()));
+        let result = reader.next_batch(3).unwrap();
+        assert!(result.records.is_empty());
+        assert_eq!(result.bytes_consumed, 0);
+        assert_eq!(result.remaining_blocks, 0);
+    }
+
+    #[test]
+    fn test_parse_reader_free_function_yields_same_records_as_new() {
+        let data = [0x30, 0x00, 0x03, 0x30, 0x00, 0x03];
+        let mut reader = parse_reader(std::io::Cursor::new(data.to_vec()), ParseOptions::default());
+        assert!(reader.next_record().unwrap().is_some());
+        assert!(reader.next_record().unwrap().is_some());
+        assert!(reader.next_record().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_next_batch_propagates_truncated_error() {
+        let data = [0x30, 0x00, 0x0A, 0x01, 0x02];
+        let mut reader = AsterixReader::new(std::io::Cursor::new(data.to_vec()));
+        let err = reader.next_batch(1).unwrap_err();
+        assert!(matches!(err, AsterixError::Truncated { .. }));
+    }
+
+    #[test]
+    fn test_for_each_parallel_visits_every_record_exactly_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut data = Vec::new();
+        for _ in 0..20 {
+            data.extend([0x30, 0x00, 0x03]);
+        }
+        let mut reader = AsterixReader::new(std::io::Cursor::new(data));
+
+        let seen = AtomicUsize::new(0);
+        reader
+            .for_each_parallel(7, 4, |_record| {
+                seen.fetch_add(1, Ordering::Relaxed);
+            })
+            .unwrap();
+
+        assert_eq!(seen.load(Ordering::Relaxed), 20);
+    }
+
+    #[test]
+    fn test_for_each_parallel_category_counts_match_serial_count() {
+        use std::collections::HashMap;
+        use std::sync::Mutex;
+
+        let mut data = Vec::new();
+        for _ in 0..10 {
+            data.extend([0x30, 0x00, 0x03]);
+        }
+        for _ in 0..10 {
+            data.extend([0x3E, 0x00, 0x03]); // category 62
+        }
+
+        let mut reader = AsterixReader::new(std::io::Cursor::new(data));
+        let counts: Mutex<HashMap<u8, usize>> = Mutex::new(HashMap::new());
+        reader
+            .for_each_parallel(6, 3, |record| {
+                *counts.lock().unwrap().entry(record.category).or_insert(0) += 1;
+            })
+            .unwrap();
+
+        let counts = counts.into_inner().unwrap();
+        assert_eq!(counts.get(&48).copied().unwrap_or(0), 10);
+        assert_eq!(counts.get(&62).copied().unwrap_or(0), 10);
+    }
+
+    #[test]
+    fn test_for_each_parallel_empty_stream_calls_nothing() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut reader = AsterixReader::new(std::io::Cursor::new(Vec::<u8>::new()));
+        let calls = AtomicUsize::new(0);
+        reader
+            .for_each_parallel(4, 2, |_record| {
+                calls.fetch_add(1, Ordering::Relaxed);
+            })
+            .unwrap();
+        assert_eq!(calls.load(Ordering::Relaxed), 0);
+    }
+}
@@ -0,0 +1,198 @@
+//! Transparent decompression for ASTERIX recordings
+//!
+//! [`open_recording`] opens a file and sniffs its leading bytes for common
+//! compression magic numbers (gzip, xz, zstd), transparently wrapping the
+//! file in the matching decoder so callers can feed the result straight into
+//! [`crate::AsterixReader`] regardless of whether the recording on disk is
+//! compressed.
+//!
+//! Decompression support for each format is optional and feature-gated so
+//! that crates which never touch compressed recordings aren't forced to pull
+//! in the corresponding decoder dependency:
+//!
+//! - `gzip` — via the `flate2` crate
+//! - `xz` — via the `xz2` crate
+//! - `zstd` — via the `zstd` crate
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asterix::{init_default, open_recording, AsterixReader};
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! init_default()?;
+//! let recording = open_recording("capture.asterix.gz")?;
+//! let mut reader = AsterixReader::new(recording);
+//! while let Some(record) = reader.next_record()? {
+//!     println!("Category {}: {} items", record.category, record.items.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+use crate::error::{AsterixError, Result};
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const XZ_MAGIC: [u8; 6] = [0xfd, b'7', b'z', b'X', b'Z', 0x00];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// Compression codec detected from a recording's leading bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingCodec {
+    /// No recognized compression magic; the recording is read as-is.
+    Raw,
+    /// gzip (RFC 1952) magic bytes.
+    Gzip,
+    /// xz magic bytes.
+    Xz,
+    /// zstd magic bytes.
+    Zstd,
+}
+
+/// An opened recording, transparently decompressed according to its detected codec.
+///
+/// Implements [`std::io::Read`], so it can be fed directly into
+/// [`crate::AsterixReader::new`] or [`crate::AsterixReader::with_options`].
+pub struct Recording {
+    inner: Box<dyn Read>,
+    codec: RecordingCodec,
+}
+
+impl Recording {
+    /// The codec that was detected when this recording was opened.
+    pub fn codec(&self) -> RecordingCodec {
+        self.codec
+    }
+}
+
+impl Read for Recording {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+/// Open `path`, sniffing its leading bytes for gzip/xz/zstd magic numbers and
+/// transparently wrapping it in the matching decoder.
+///
+/// A file without a recognized magic is assumed to already be raw ASTERIX
+/// bytes and is returned unmodified (beyond the buffering used to sniff it).
+///
+/// # Errors
+///
+/// Returns [`AsterixError::IOError`] if `path` can't be opened or read, and
+/// [`AsterixError::InvalidData`] if the detected codec's cargo feature
+/// (`gzip`, `xz`, or `zstd`) isn't enabled.
+pub fn open_recording(path: impl AsRef<Path>) -> Result<Recording> {
+    let file = File::open(path)?;
+    let mut buffered = BufReader::new(file);
+    let codec = detect_codec(buffered.fill_buf()?);
+
+    let inner: Box<dyn Read> = match codec {
+        RecordingCodec::Raw => Box::new(buffered),
+
+        #[cfg(feature = "gzip")]
+        RecordingCodec::Gzip => Box::new(flate2::read::GzDecoder::new(buffered)),
+        #[cfg(not(feature = "gzip"))]
+        RecordingCodec::Gzip => {
+            return Err(AsterixError::InvalidData(
+                "recording is gzip-compressed but the `gzip` feature is not enabled".to_string(),
+            ));
+        }
+
+        #[cfg(feature = "xz")]
+        RecordingCodec::Xz => Box::new(xz2::read::XzDecoder::new(buffered)),
+        #[cfg(not(feature = "xz"))]
+        RecordingCodec::Xz => {
+            return Err(AsterixError::InvalidData(
+                "recording is xz-compressed but the `xz` feature is not enabled".to_string(),
+            ));
+        }
+
+        #[cfg(feature = "zstd")]
+        RecordingCodec::Zstd => Box::new(
+            zstd::stream::read::Decoder::new(buffered)
+                .map_err(|e| AsterixError::IOError(e.to_string()))?,
+        ),
+        #[cfg(not(feature = "zstd"))]
+        RecordingCodec::Zstd => {
+            return Err(AsterixError::InvalidData(
+                "recording is zstd-compressed but the `zstd` feature is not enabled".to_string(),
+            ));
+        }
+    };
+
+    Ok(Recording { inner, codec })
+}
+
+/// Identify a codec from a recording's leading bytes, falling back to [`RecordingCodec::Raw`].
+fn detect_codec(head: &[u8]) -> RecordingCodec {
+    if head.starts_with(&XZ_MAGIC) {
+        RecordingCodec::Xz
+    } else if head.starts_with(&ZSTD_MAGIC) {
+        RecordingCodec::Zstd
+    } else if head.starts_with(&GZIP_MAGIC) {
+        RecordingCodec::Gzip
+    } else {
+        RecordingCodec::Raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_codec_gzip() {
+        assert_eq!(detect_codec(&[0x1f, 0x8b, 0x08, 0x00]), RecordingCodec::Gzip);
+    }
+
+    #[test]
+    fn test_detect_codec_xz() {
+        let head = [0xfd, b'7', b'z', b'X', b'Z', 0x00, 0x00];
+        assert_eq!(detect_codec(&head), RecordingCodec::Xz);
+    }
+
+    #[test]
+    fn test_detect_codec_zstd() {
+        assert_eq!(
+            detect_codec(&[0x28, 0xb5, 0x2f, 0xfd, 0x00]),
+            RecordingCodec::Zstd
+        );
+    }
+
+    #[test]
+    fn test_detect_codec_raw_for_unrecognized_bytes() {
+        assert_eq!(detect_codec(&[0x30, 0x00, 0x10]), RecordingCodec::Raw);
+    }
+
+    #[test]
+    fn test_detect_codec_raw_for_short_input() {
+        assert_eq!(detect_codec(&[]), RecordingCodec::Raw);
+        assert_eq!(detect_codec(&[0x1f]), RecordingCodec::Raw);
+    }
+
+    #[test]
+    fn test_open_recording_rejects_missing_file() {
+        let result = open_recording("/nonexistent/path/to/recording.asterix");
+        assert!(matches!(result, Err(AsterixError::IOError(_))));
+    }
+
+    #[test]
+    fn test_open_recording_raw_passthrough() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("asterix_recording_test_raw.bin");
+        std::fs::write(&path, [0x30, 0x00, 0x03]).unwrap();
+
+        let mut recording = open_recording(&path).unwrap();
+        assert_eq!(recording.codec(), RecordingCodec::Raw);
+
+        let mut buf = Vec::new();
+        recording.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, vec![0x30, 0x00, 0x03]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
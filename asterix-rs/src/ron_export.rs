@@ -0,0 +1,91 @@
+//! RON (Rusty Object Notation) export/import for decoded records
+//!
+//! [`crate::json_export`]/[`write_ndjson`](crate::write_ndjson) render
+//! records as JSON, whose untagged [`crate::types::ParsedValue`] encoding
+//! hides which variant produced a given scalar. RON encodes enum variants by
+//! name, so the same record dumps as a readable `Decimal(...)`/`Nested({...})`
+//! tree instead of tag-less JSON, and [`from_ron`] reads it straight back.
+//! [`to_ron`]/[`from_ron`] are generic over any of this crate's `Serialize`/
+//! `Deserialize` types ([`crate::types::AsterixRecord`],
+//! [`crate::types::DataItem`], [`crate::types::ParsedValue`], ...) since all
+//! of them already derive serde's traits and need no RON-specific code.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{AsterixError, Result};
+
+/// Serialize `value` to a RON string.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::IOError`] if `value` can't be represented in RON.
+pub fn to_ron<T: Serialize>(value: &T) -> Result<String> {
+    ron::to_string(value).map_err(ron_err)
+}
+
+/// Deserialize a RON string produced by [`to_ron`] back into `T`.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::InvalidData`] if `text` is not valid RON or
+/// doesn't match `T`'s shape.
+pub fn from_ron<T: DeserializeOwned>(text: &str) -> Result<T> {
+    ron::from_str(text).map_err(|e| AsterixError::InvalidData(e.to_string()))
+}
+
+fn ron_err(err: ron::Error) -> AsterixError {
+    AsterixError::IOError(err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AsterixRecord, DataItem, ParsedValue};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_ron_roundtrip_record() {
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(25));
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        let record = AsterixRecord {
+            category: 48,
+            items,
+            ..Default::default()
+        };
+
+        let ron_text = to_ron(&record).unwrap();
+        let back: AsterixRecord = from_ron(&ron_text).unwrap();
+        assert_eq!(back.category, 48);
+        assert_eq!(back.get_item("I048/010").unwrap().fields["SAC"].as_i64(), Some(25));
+    }
+
+    #[test]
+    fn test_ron_roundtrip_decimal_preserves_variant_name() {
+        let value = ParsedValue::Decimal {
+            raw: 400,
+            scale: 0.25,
+            unit: Some("FL".to_string()),
+        };
+
+        let ron_text = to_ron(&value).unwrap();
+        assert!(ron_text.contains("Decimal"));
+
+        let back: ParsedValue = from_ron(&ron_text).unwrap();
+        assert_eq!(back.as_f64(), Some(100.0));
+    }
+
+    #[test]
+    fn test_from_ron_rejects_invalid_text() {
+        let result: Result<AsterixRecord> = from_ron("not valid ron {{{");
+        assert!(result.is_err());
+    }
+}
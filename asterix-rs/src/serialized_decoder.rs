@@ -0,0 +1,225 @@
+//! A single-worker-thread handle that serializes every call into the C++
+//! `AsterixDefinition` singleton
+//!
+//! The crate-level docs warn that `parse`, `init_default`, `load_category`,
+//! and `describe` all touch that global singleton and race if called from
+//! multiple threads — the library does no locking of its own, so today that
+//! synchronization is entirely the caller's problem (see `lib.rs`'s "Thread
+//! Safety" section). [`SerializedDecoder`] removes the problem instead of
+//! documenting around it: it owns the singleton on one dedicated worker
+//! thread and funnels every request through an `mpsc` channel to it, so
+//! calls from any number of threads simply queue instead of racing.
+//!
+//! [`SerializedDecoder`] is `Clone`, and cloning is cheap (it's just another
+//! sender on the same channel) — hand a clone to each thread that needs to
+//! parse. Unlike [`crate::parallel::parse_parallel`], which only parallelizes
+//! across already-buffered blocks, this also lets independent producers
+//! (e.g. multiple sockets each spawning one thread) submit `parse` calls
+//! concurrently without an external mutex.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+
+use crate::error::{AsterixError, Result};
+use crate::types::{AsterixRecord, ParseOptions, ParseResult};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// A cheap, `Clone + Send + Sync` handle to a dedicated worker thread that
+/// owns the C++ ASTERIX singleton.
+///
+/// Every method mirrors a free function of the same name
+/// ([`crate::init_default`], [`crate::parse`], [`crate::describe`], ...)
+/// but queues its call onto the worker thread and blocks the calling thread
+/// only until that one call's reply comes back — concurrent callers queue
+/// instead of racing the C++ state.
+#[derive(Clone)]
+pub struct SerializedDecoder {
+    jobs: mpsc::Sender<Job>,
+}
+
+impl SerializedDecoder {
+    /// Spawn the worker thread and return a handle to it.
+    ///
+    /// The thread runs until every clone of the returned handle has been
+    /// dropped, at which point its `mpsc` channel closes and it exits.
+    pub fn new() -> Self {
+        let (jobs, rx) = mpsc::channel::<Job>();
+        thread::Builder::new()
+            .name("asterix-decoder".to_string())
+            .spawn(move || {
+                for job in rx {
+                    job();
+                }
+            })
+            .expect("failed to spawn asterix-decoder worker thread");
+
+        Self { jobs }
+    }
+
+    /// Run `f` on the worker thread and block until its result comes back.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the worker thread has already exited (it never does while
+    /// any clone of `self` is alive) or panicked while running a previous
+    /// job.
+    fn call<T: Send + 'static>(&self, f: impl FnOnce() -> T + Send + 'static) -> T {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        self.jobs
+            .send(Box::new(move || {
+                // The receiving end only disappears if `call` itself
+                // panicked after sending, which can't happen here.
+                let _ = reply_tx.send(f());
+            }))
+            .expect("asterix-decoder worker thread is gone");
+
+        reply_rx
+            .recv()
+            .expect("asterix-decoder worker thread dropped its reply")
+    }
+
+    /// Worker-thread-serialized [`crate::init_default`]
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`crate::init_default`].
+    pub fn init_default(&self) -> Result<()> {
+        self.call(crate::ffi::init_default)
+    }
+
+    /// Worker-thread-serialized [`crate::init_config_dir`]
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`crate::init_config_dir`].
+    pub fn init_config_dir(&self, config_dir: impl AsRef<Path>) -> Result<()> {
+        let config_dir = config_dir.as_ref().to_path_buf();
+        self.call(move || crate::ffi::init_config_dir(&config_dir))
+    }
+
+    /// Worker-thread-serialized [`crate::load_category`]
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`crate::load_category`].
+    pub fn load_category(&self, xml_path: impl AsRef<Path>) -> Result<()> {
+        let xml_path = xml_path.as_ref().to_path_buf();
+        self.call(move || crate::ffi::load_category(&xml_path))
+    }
+
+    /// Worker-thread-serialized [`crate::parse`]
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`crate::parse`].
+    pub fn parse(&self, data: Vec<u8>, options: ParseOptions) -> Result<Vec<AsterixRecord>> {
+        self.call(move || crate::parser::parse(&data, options))
+    }
+
+    /// Worker-thread-serialized [`crate::parse_with_offset`]
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`crate::parse_with_offset`].
+    pub fn parse_with_offset(
+        &self,
+        data: Vec<u8>,
+        offset: usize,
+        blocks_count: usize,
+        options: ParseOptions,
+    ) -> Result<ParseResult> {
+        self.call(move || crate::parser::parse_with_offset(&data, offset, blocks_count, options))
+    }
+
+    /// Worker-thread-serialized [`crate::describe`]
+    ///
+    /// # Errors
+    ///
+    /// Same conditions as [`crate::describe`].
+    pub fn describe(
+        &self,
+        category: u8,
+        item: Option<String>,
+        field: Option<String>,
+        value: Option<String>,
+    ) -> Result<String> {
+        self.call(move || {
+            crate::ffi::describe(
+                category,
+                item.as_deref(),
+                field.as_deref(),
+                value.as_deref(),
+            )
+        })
+    }
+
+    /// Ask the worker thread to do nothing and report back, proving the
+    /// thread is alive and every prior queued job has drained ahead of this
+    /// one.
+    ///
+    /// # Errors
+    ///
+    /// Never returns an error; the `Result` return type only exists so this
+    /// can be used the same way as the other methods in a generic context.
+    pub fn ping(&self) -> Result<()> {
+        self.call(|| Ok(()))
+    }
+}
+
+impl Default for SerializedDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for SerializedDecoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SerializedDecoder").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ping_roundtrips_through_worker_thread() {
+        let decoder = SerializedDecoder::new();
+        decoder.ping().unwrap();
+    }
+
+    #[test]
+    fn test_clone_shares_the_same_worker_thread() {
+        let decoder = SerializedDecoder::new();
+        let cloned = decoder.clone();
+
+        let handle = thread::spawn(move || cloned.ping());
+        decoder.ping().unwrap();
+        handle.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_callers_all_get_replies() {
+        let decoder = SerializedDecoder::new();
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let decoder = decoder.clone();
+                thread::spawn(move || decoder.ping())
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_describe_rejects_category_zero_like_the_free_function() {
+        let decoder = SerializedDecoder::new();
+        let err = decoder.describe(0, None, None, None).unwrap_err();
+        assert!(matches!(err, AsterixError::InvalidData(_)));
+    }
+}
@@ -0,0 +1,190 @@
+//! Non-blocking, reactor-friendly live ASTERIX ingestion
+//!
+//! [`udp::AsterixSource`] either blocks the calling thread (`run`) or hands
+//! decoding off to a dedicated background thread (`spawn`). Some hosts
+//! instead want to drive ingestion from their own event loop — the same
+//! `epoll`/`mio`/`tokio` reactor already mentioned throughout
+//! [`crate::transport::dbus::DbusService`] — without asterix-rs pulling in an
+//! async runtime of its own. [`NonBlockingAsterixSource`] follows that same
+//! model: it wraps a non-blocking socket, implements [`AsRawFd`] so it can
+//! be registered with any reactor, and exposes [`NonBlockingAsterixSource::poll`]/
+//! [`NonBlockingAsterixSource::poll_once`] to drain whatever's ready —
+//! mirroring [`crate::transport::dbus::DbusService::process_incoming`]/
+//! [`crate::transport::dbus::DbusService::poll_once`] exactly.
+//!
+//! Because a UDP datagram boundary doesn't line up with an ASTERIX block
+//! boundary, incoming bytes are run through a [`reassembly::BlockReassembler`]
+//! before decoding, so a block split across two datagrams is buffered and
+//! completed rather than dropped. [`crate::types::ParseOptions::filter_category`]
+//! is applied to each reassembled block's category *before* calling
+//! [`crate::parse`], so a non-matching block is never decoded at all — the
+//! same optimization [`crate::framing`] was added to make possible.
+//!
+//! [`RecordSource`] is the blocking counterpart implemented by
+//! [`udp::AsterixSourceRx`]: both it and [`NonBlockingAsterixSource`] share
+//! the same [`reassembly::BlockReassembler`]/[`crate::parse`] decoding core,
+//! just surfaced through a blocking or a non-blocking API. This intentionally
+//! stops short of depending on `tokio` (or any async runtime) directly: every
+//! other transport in this crate (`dbus`, `ws`, `http`) hands callers a raw
+//! fd and a `poll_once`/non-blocking method instead of baking in a specific
+//! executor, and a `tokio::net::UdpSocket` wrapped around
+//! [`Self::as_raw_fd`]'s fd (or a `tokio::io::unix::AsyncFd`) gets a real
+//! `.await`-able stream for free on top of this without this crate ever
+//! linking against `tokio` itself.
+
+use std::net::{Ipv4Addr, UdpSocket};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::time::{Duration, Instant};
+
+use crate::types::AsterixRecord;
+
+use super::reassembly::BlockReassembler;
+use super::udp::{AsterixSourceRx, UdpSourceConfig, UdpSourceError};
+
+/// A blocking source of decoded ASTERIX records
+///
+/// Implemented by [`udp::AsterixSourceRx`]. See
+/// [`NonBlockingAsterixSource`] for a reactor-driven, non-blocking
+/// counterpart built on the same decoding core.
+pub trait RecordSource {
+    /// Block up to `timeout` for the next decoded record.
+    ///
+    /// Returns `Ok(None)` on timeout.
+    fn recv_blocking(&self, timeout: Duration) -> Result<Option<AsterixRecord>, UdpSourceError>;
+}
+
+impl RecordSource for AsterixSourceRx {
+    fn recv_blocking(&self, timeout: Duration) -> Result<Option<AsterixRecord>, UdpSourceError> {
+        self.recv_timeout(timeout)
+    }
+}
+
+/// Maximum UDP datagram payload this source will read, matching [`udp::AsterixSource`]'s.
+const MAX_DATAGRAM_LEN: usize = 65_536;
+
+/// A UDP multicast ASTERIX source meant to be driven by an external reactor
+/// instead of a dedicated thread
+///
+/// Unlike [`udp::AsterixSource`], the socket is non-blocking: call
+/// [`Self::poll`] (or register [`Self::as_raw_fd`] with `epoll`/`mio`/`tokio`
+/// and call it when readable) to drain whatever datagrams have already
+/// arrived, or [`Self::poll_once`] to additionally wait up to a timeout.
+pub struct NonBlockingAsterixSource {
+    socket: UdpSocket,
+    config: UdpSourceConfig,
+    reassembler: BlockReassembler,
+}
+
+impl NonBlockingAsterixSource {
+    /// Bind a non-blocking socket and join the configured multicast group.
+    pub fn bind(config: UdpSourceConfig) -> Result<Self, UdpSourceError> {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, config.port))?;
+        let interface = config.interface_addr.unwrap_or(Ipv4Addr::UNSPECIFIED);
+        socket
+            .join_multicast_v4(&config.multicast_addr, &interface)
+            .map_err(|e| UdpSourceError::JoinError(e.to_string()))?;
+        socket.set_nonblocking(true)?;
+
+        Ok(Self {
+            socket,
+            config,
+            reassembler: BlockReassembler::default(),
+        })
+    }
+
+    /// Decode and return every record available right now, without blocking.
+    ///
+    /// An empty `Vec` means nothing was ready; call again once
+    /// [`Self::as_raw_fd`] reports readable, or after [`Self::poll_once`]'s
+    /// timeout.
+    pub fn poll(&mut self) -> Result<Vec<AsterixRecord>, UdpSourceError> {
+        let mut records = Vec::new();
+        let mut buffer = [0u8; MAX_DATAGRAM_LEN];
+
+        loop {
+            let len = match self.socket.recv(&mut buffer) {
+                Ok(len) => len,
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => break,
+                Err(e) => return Err(e.into()),
+            };
+
+            for (category, block) in self.reassembler.feed(&buffer[..len])? {
+                if let Some(filter_cat) = self.config.parse_options.filter_category {
+                    if category != filter_cat {
+                        continue;
+                    }
+                }
+                match crate::parse(&block, self.config.parse_options.clone()) {
+                    Ok(decoded) => records.extend(decoded),
+                    Err(e) => log::warn!("failed to parse ASTERIX block: {e}"),
+                }
+                if let Some(max) = self.config.parse_options.max_records {
+                    if records.len() >= max {
+                        records.truncate(max);
+                        return Ok(records);
+                    }
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// [`Self::poll`], retrying until `timeout` elapses or something arrives.
+    pub fn poll_once(&mut self, timeout: Duration) -> Result<Vec<AsterixRecord>, UdpSourceError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let records = self.poll()?;
+            if !records.is_empty() || Instant::now() >= deadline {
+                return Ok(records);
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
+
+impl AsRawFd for NonBlockingAsterixSource {
+    /// Raw file descriptor of the underlying UDP socket.
+    ///
+    /// Register this with `epoll`/`mio`/`tokio` to learn when [`Self::poll`]
+    /// has work to do, the same way [`crate::transport::dbus::DbusService::as_raw_fd`] is used.
+    fn as_raw_fd(&self) -> RawFd {
+        self.socket.as_raw_fd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bind_nonblocking_and_join_loopback_multicast() {
+        let config = UdpSourceConfig {
+            multicast_addr: Ipv4Addr::new(239, 9, 9, 9),
+            port: 0,
+            ..UdpSourceConfig::default()
+        };
+
+        match NonBlockingAsterixSource::bind(config) {
+            Ok(_source) => {}
+            Err(_) => println!("Skipping test: could not join multicast group on this host"),
+        }
+    }
+
+    #[test]
+    fn test_poll_with_nothing_available_returns_empty() {
+        let _ = crate::ffi::init_default();
+
+        let config = UdpSourceConfig {
+            multicast_addr: Ipv4Addr::new(239, 9, 9, 10),
+            port: 0,
+            ..UdpSourceConfig::default()
+        };
+        let Ok(mut source) = NonBlockingAsterixSource::bind(config) else {
+            println!("Skipping test: could not join multicast group on this host");
+            return;
+        };
+
+        assert_eq!(source.poll().unwrap(), Vec::new());
+    }
+}
@@ -0,0 +1,439 @@
+//! PCAP file reader for replaying captured ASTERIX multicast traffic
+//!
+//! [`udp::AsterixSource`] joins a live multicast group; [`PcapReader`] reads
+//! the same UDP datablocks back out of a `.pcap` capture (e.g. one taken with
+//! `tcpdump -w capture.pcap` against a sensor feed), for replaying a recorded
+//! session without a multicast-capable network. Only the common case is
+//! supported: Ethernet-linktype captures (`LINKTYPE_ETHERNET`, what `tcpdump`
+//! and Wireshark write by default) carrying IPv4 UDP datagrams, with or
+//! without a single 802.1Q VLAN tag. IPv6, IP fragmentation, and any other
+//! link type are not handled — a packet that doesn't match is skipped rather
+//! than erroring, the same way [`udp::AsterixSource::decode_datagram`] logs
+//! and continues past a datagram that fails to parse.
+//!
+//! Like [`crate::reader::AsterixReader`], this is a finite, blocking reader
+//! over one file — not a live background source like the rest of this
+//! module. Its decoding core (`crate::parse` plus [`ParseOptions`]) is the
+//! same one [`udp::AsterixSource`] uses.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use crate::error::AsterixError;
+use crate::types::{AsterixRecord, ParseOptions};
+
+/// Error type for [`PcapReader`] operations
+#[derive(Debug)]
+pub enum PcapError {
+    /// Failed to read from the underlying file/stream
+    IoError(String),
+    /// The global file header isn't a recognized pcap magic number
+    InvalidHeader(String),
+    /// The capture's link-layer type isn't `LINKTYPE_ETHERNET`
+    UnsupportedLinkType(u32),
+    /// A packet record's declared `incl_len` exceeds the capture's own `snaplen`
+    OversizedPacket { incl_len: u32, snaplen: u32 },
+}
+
+impl fmt::Display for PcapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PcapError::IoError(msg) => write!(f, "pcap I/O error: {msg}"),
+            PcapError::InvalidHeader(msg) => write!(f, "invalid pcap file header: {msg}"),
+            PcapError::UnsupportedLinkType(linktype) => {
+                write!(f, "unsupported pcap link type {linktype} (only Ethernet (1) is supported)")
+            }
+            PcapError::OversizedPacket { incl_len, snaplen } => write!(
+                f,
+                "packet record declares incl_len {incl_len}, exceeding the capture's snaplen {snaplen}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PcapError {}
+
+impl From<std::io::Error> for PcapError {
+    fn from(err: std::io::Error) -> Self {
+        PcapError::IoError(err.to_string())
+    }
+}
+
+impl From<PcapError> for AsterixError {
+    fn from(err: PcapError) -> Self {
+        AsterixError::IOError(err.to_string())
+    }
+}
+
+const MAGIC_MICRO: u32 = 0xa1b2_c3d4;
+const MAGIC_NANO: u32 = 0xa1b2_3c4d;
+const LINKTYPE_ETHERNET: u32 = 1;
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const IPPROTO_UDP: u8 = 17;
+const UDP_HEADER_LEN: usize = 8;
+
+/// Fallback cap on a packet record's `incl_len` for captures whose global
+/// header declares `snaplen: 0` (some tools use this to mean "unlimited"
+/// rather than leaving a real value). Generous for any real Ethernet
+/// capture, including jumbo frames, while still ruling out a truncated or
+/// corrupted record driving an allocation of several gigabytes.
+const MAX_SANE_PACKET_LEN: u32 = 262_144;
+
+/// Reads ASTERIX datablocks carried as UDP payloads inside a `.pcap` capture
+///
+/// # Example
+///
+/// ```no_run
+/// use asterix::source::pcap::PcapReader;
+/// use asterix::init_default;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     init_default()?;
+///
+///     let mut reader = PcapReader::open("capture.pcap")?;
+///     while let Some(record) = reader.next_record()? {
+///         println!("CAT{:03} record ({} items)", record.category, record.item_count());
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+pub struct PcapReader<R> {
+    inner: R,
+    big_endian: bool,
+    /// The capture's own declared maximum captured packet length; every
+    /// packet record's `incl_len` is validated against it before its
+    /// payload buffer is allocated (see [`Self::next_udp_payload`]).
+    snaplen: u32,
+    options: ParseOptions,
+    pending: VecDeque<AsterixRecord>,
+}
+
+impl PcapReader<BufReader<File>> {
+    /// Open `path` for reading, using default parse options.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, PcapError> {
+        Self::open_with_options(path, ParseOptions::default())
+    }
+
+    /// Open `path` for reading, applying `options` to every decode.
+    pub fn open_with_options(path: impl AsRef<Path>, options: ParseOptions) -> Result<Self, PcapError> {
+        let file = File::open(path)?;
+        Self::with_options(BufReader::new(file), options)
+    }
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Wrap `inner` for reading, using default parse options.
+    pub fn new(inner: R) -> Result<Self, PcapError> {
+        Self::with_options(inner, ParseOptions::default())
+    }
+
+    /// Wrap `inner`, applying `options` to every decoded record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PcapError::InvalidHeader`] if `inner` doesn't start with a
+    /// recognized pcap magic number, and [`PcapError::UnsupportedLinkType`]
+    /// if its captured link type isn't Ethernet.
+    pub fn with_options(mut inner: R, options: ParseOptions) -> Result<Self, PcapError> {
+        let mut header = [0u8; 24];
+        inner.read_exact(&mut header)?;
+
+        let magic_le = u32::from_le_bytes(header[0..4].try_into().unwrap());
+        let magic_be = u32::from_be_bytes(header[0..4].try_into().unwrap());
+        let big_endian = if magic_le == MAGIC_MICRO || magic_le == MAGIC_NANO {
+            false
+        } else if magic_be == MAGIC_MICRO || magic_be == MAGIC_NANO {
+            true
+        } else {
+            return Err(PcapError::InvalidHeader(format!(
+                "unrecognized magic number {magic_le:#010x}"
+            )));
+        };
+
+        let snaplen = read_u32(&header[16..20], big_endian);
+
+        let linktype = read_u32(&header[20..24], big_endian);
+        if linktype != LINKTYPE_ETHERNET {
+            return Err(PcapError::UnsupportedLinkType(linktype));
+        }
+
+        Ok(Self {
+            inner,
+            big_endian,
+            snaplen,
+            options,
+            pending: VecDeque::new(),
+        })
+    }
+
+    /// Read the next decoded ASTERIX record, pulling and decoding as many
+    /// more packets from the capture as needed.
+    ///
+    /// Returns `Ok(None)` once the capture is exhausted. Mirrors
+    /// [`crate::reader::AsterixReader::next_record`]'s one-at-a-time
+    /// interface, but decodes from UDP payloads found in a pcap capture
+    /// instead of an already-framed ASTERIX byte stream.
+    pub fn next_record(&mut self) -> Result<Option<AsterixRecord>, PcapError> {
+        loop {
+            if let Some(record) = self.pending.pop_front() {
+                return Ok(Some(record));
+            }
+
+            let Some(payload) = self.next_udp_payload()? else {
+                return Ok(None);
+            };
+
+            match crate::parse(&payload, self.options.clone()) {
+                Ok(records) => self.pending.extend(records),
+                Err(e) => log::warn!("failed to parse ASTERIX payload from pcap packet: {e}"),
+            }
+        }
+    }
+
+    /// Read packet records until one decodes to an Ethernet/IPv4/UDP frame,
+    /// returning its UDP payload, or `Ok(None)` at a clean end of file.
+    fn next_udp_payload(&mut self) -> Result<Option<Vec<u8>>, PcapError> {
+        loop {
+            let mut record_header = [0u8; 16];
+            match self.inner.read_exact(&mut record_header) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+                Err(e) => return Err(e.into()),
+            }
+
+            let incl_len = read_u32(&record_header[8..12], self.big_endian);
+            let effective_cap = if self.snaplen == 0 {
+                MAX_SANE_PACKET_LEN
+            } else {
+                self.snaplen.min(MAX_SANE_PACKET_LEN)
+            };
+            if incl_len > effective_cap {
+                return Err(PcapError::OversizedPacket {
+                    incl_len,
+                    snaplen: self.snaplen,
+                });
+            }
+
+            let mut packet = vec![0u8; incl_len as usize];
+            self.inner.read_exact(&mut packet)?;
+
+            if let Some(payload) = extract_udp_payload(&packet) {
+                return Ok(Some(payload));
+            }
+        }
+    }
+}
+
+fn read_u32(bytes: &[u8], big_endian: bool) -> u32 {
+    let array: [u8; 4] = bytes.try_into().unwrap();
+    if big_endian {
+        u32::from_be_bytes(array)
+    } else {
+        u32::from_le_bytes(array)
+    }
+}
+
+/// Pull a UDP payload out of one Ethernet frame, unwrapping a single 802.1Q
+/// VLAN tag if present. Returns `None` for anything that isn't Ethernet/
+/// IPv4/UDP (or is too short to contain one), so the caller skips it.
+fn extract_udp_payload(packet: &[u8]) -> Option<Vec<u8>> {
+    if packet.len() < 14 {
+        return None;
+    }
+
+    let mut offset = 12;
+    let mut ethertype = u16::from_be_bytes(packet[offset..offset + 2].try_into().ok()?);
+    offset += 2;
+
+    if ethertype == ETHERTYPE_VLAN {
+        if packet.len() < offset + 4 {
+            return None;
+        }
+        ethertype = u16::from_be_bytes(packet[offset + 2..offset + 4].try_into().ok()?);
+        offset += 4;
+    }
+
+    if ethertype != ETHERTYPE_IPV4 {
+        return None;
+    }
+
+    let ip_start = offset;
+    if packet.len() < ip_start + 20 {
+        return None;
+    }
+    let ihl = (packet[ip_start] & 0x0F) as usize * 4;
+    if ihl < 20 || packet.len() < ip_start + ihl {
+        return None;
+    }
+    if packet[ip_start + 9] != IPPROTO_UDP {
+        return None;
+    }
+
+    let udp_start = ip_start + ihl;
+    if packet.len() < udp_start + UDP_HEADER_LEN {
+        return None;
+    }
+    let udp_len = u16::from_be_bytes(
+        packet[udp_start + 4..udp_start + 6].try_into().ok()?,
+    ) as usize;
+
+    let payload_start = udp_start + UDP_HEADER_LEN;
+    let payload_end = (udp_start + udp_len).min(packet.len());
+    if payload_end <= payload_start {
+        return None;
+    }
+
+    Some(packet[payload_start..payload_end].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a minimal little-endian micro-resolution pcap file containing
+    /// one Ethernet/IPv4/UDP frame wrapping `udp_payload`.
+    fn build_pcap_with_udp_payload(udp_payload: &[u8]) -> Vec<u8> {
+        let mut udp = Vec::new();
+        udp.extend_from_slice(&1234u16.to_be_bytes()); // src port
+        udp.extend_from_slice(&8600u16.to_be_bytes()); // dst port
+        udp.extend_from_slice(&((UDP_HEADER_LEN + udp_payload.len()) as u16).to_be_bytes());
+        udp.extend_from_slice(&0u16.to_be_bytes()); // checksum (unchecked)
+        udp.extend_from_slice(udp_payload);
+
+        let mut ip = Vec::new();
+        ip.push(0x45); // version 4, IHL 5
+        ip.push(0x00); // DSCP/ECN
+        ip.extend_from_slice(&((20 + udp.len()) as u16).to_be_bytes()); // total length
+        ip.extend_from_slice(&0u16.to_be_bytes()); // identification
+        ip.extend_from_slice(&0u16.to_be_bytes()); // flags/fragment offset
+        ip.push(64); // TTL
+        ip.push(IPPROTO_UDP);
+        ip.extend_from_slice(&0u16.to_be_bytes()); // header checksum (unchecked)
+        ip.extend_from_slice(&[10, 0, 0, 1]); // src addr
+        ip.extend_from_slice(&[239, 0, 0, 1]); // dst addr
+        ip.extend_from_slice(&udp);
+
+        let mut eth = Vec::new();
+        eth.extend_from_slice(&[0x01, 0x00, 0x5e, 0x00, 0x00, 0x01]); // dst MAC
+        eth.extend_from_slice(&[0x00, 0x11, 0x22, 0x33, 0x44, 0x55]); // src MAC
+        eth.extend_from_slice(&ETHERTYPE_IPV4.to_be_bytes());
+        eth.extend_from_slice(&ip);
+
+        let mut file = Vec::new();
+        file.extend_from_slice(&MAGIC_MICRO.to_le_bytes());
+        file.extend_from_slice(&2u16.to_le_bytes()); // version_major
+        file.extend_from_slice(&4u16.to_le_bytes()); // version_minor
+        file.extend_from_slice(&0i32.to_le_bytes()); // thiszone
+        file.extend_from_slice(&0u32.to_le_bytes()); // sigfigs
+        file.extend_from_slice(&65535u32.to_le_bytes()); // snaplen
+        file.extend_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+
+        file.extend_from_slice(&0u32.to_le_bytes()); // ts_sec
+        file.extend_from_slice(&0u32.to_le_bytes()); // ts_usec
+        file.extend_from_slice(&(eth.len() as u32).to_le_bytes()); // incl_len
+        file.extend_from_slice(&(eth.len() as u32).to_le_bytes()); // orig_len
+        file.extend_from_slice(&eth);
+
+        file
+    }
+
+    #[test]
+    fn test_rejects_unrecognized_magic_number() {
+        let bytes = vec![0u8; 24];
+        match PcapReader::new(&bytes[..]) {
+            Err(PcapError::InvalidHeader(_)) => {}
+            other => panic!("expected InvalidHeader, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_ethernet_linktype() {
+        let mut header = vec![0u8; 24];
+        header[0..4].copy_from_slice(&MAGIC_MICRO.to_le_bytes());
+        header[20..24].copy_from_slice(&101u32.to_le_bytes()); // LINKTYPE_RAW
+
+        match PcapReader::new(&header[..]) {
+            Err(PcapError::UnsupportedLinkType(101)) => {}
+            other => panic!("expected UnsupportedLinkType(101), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_decodes_udp_payload_from_ethernet_frame() {
+        let _ = crate::ffi::init_default();
+
+        // Minimal header-only CAT32 block: category 32, length 3, no items.
+        let datablock = vec![32u8, 0x00, 0x03];
+        let pcap_bytes = build_pcap_with_udp_payload(&datablock);
+
+        let mut reader = PcapReader::new(&pcap_bytes[..]).unwrap();
+        let record = reader.next_record().unwrap().expect("expected a decoded record");
+        assert_eq!(record.category, 32);
+        assert!(reader.next_record().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_skips_non_udp_packets_then_returns_none_at_eof() {
+        let mut header = vec![0u8; 24];
+        header[0..4].copy_from_slice(&MAGIC_MICRO.to_le_bytes());
+        header[20..24].copy_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+
+        // One packet too short to even be a full Ethernet header.
+        let mut packet_header = vec![0u8; 16];
+        packet_header[8..12].copy_from_slice(&4u32.to_le_bytes());
+        packet_header[12..16].copy_from_slice(&4u32.to_le_bytes());
+
+        let mut bytes = header;
+        bytes.extend_from_slice(&packet_header);
+        bytes.extend_from_slice(&[0u8; 4]);
+
+        let mut reader = PcapReader::new(&bytes[..]).unwrap();
+        assert!(reader.next_record().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rejects_incl_len_exceeding_snaplen_before_allocating() {
+        let mut header = vec![0u8; 24];
+        header[0..4].copy_from_slice(&MAGIC_MICRO.to_le_bytes());
+        header[16..20].copy_from_slice(&65535u32.to_le_bytes()); // snaplen
+        header[20..24].copy_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+
+        // Declares a multi-gigabyte incl_len (with no actual packet bytes
+        // behind it), far past the 65535-byte snaplen the capture itself
+        // declares; must be rejected before a buffer that size is allocated.
+        let mut packet_header = vec![0u8; 16];
+        packet_header[8..12].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+        packet_header[12..16].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+
+        let mut bytes = header;
+        bytes.extend_from_slice(&packet_header);
+
+        let mut reader = PcapReader::new(&bytes[..]).unwrap();
+        match reader.next_record() {
+            Err(PcapError::OversizedPacket { incl_len, snaplen }) => {
+                assert_eq!(incl_len, 0xFFFF_FFF0);
+                assert_eq!(snaplen, 65535);
+            }
+            other => panic!("expected OversizedPacket, got {other:?}"),
+        }
+    }
+}
+
+/// Replay every record in a capture, calling `on_record` for each, mirroring
+/// [`udp::AsterixSource::run`]'s interface for a finite file instead of a
+/// live multicast group.
+pub fn replay_file(
+    path: impl AsRef<Path>,
+    mut on_record: impl FnMut(AsterixRecord),
+) -> Result<(), PcapError> {
+    let mut reader = PcapReader::open(path)?;
+    while let Some(record) = reader.next_record()? {
+        on_record(record);
+    }
+    Ok(())
+}
@@ -0,0 +1,81 @@
+//! Partial-block reassembly across UDP datagram boundaries
+//!
+//! A UDP datagram's boundary has no relationship to an ASTERIX block
+//! boundary: one datagram can carry several whole blocks, half a block, or
+//! the tail of a block that started in a previous datagram.
+//! [`BlockReassembler`] buffers bytes across [`BlockReassembler::feed`] calls
+//! and uses [`crate::framing::frame_blocks`] to pull out only the blocks
+//! that are fully present, leaving any partial trailing bytes buffered for
+//! the next datagram.
+
+use crate::error::Result;
+use crate::framing::frame_blocks;
+
+/// Buffers incomplete trailing blocks between [`BlockReassembler::feed`] calls
+#[derive(Default)]
+pub(crate) struct BlockReassembler {
+    buffer: Vec<u8>,
+}
+
+impl BlockReassembler {
+    /// Feed one datagram's payload in, and return every block that's now
+    /// fully available (category + its raw bytes, header included).
+    ///
+    /// Bytes belonging to a block that hasn't fully arrived yet stay
+    /// buffered and are included in the next call's reassembly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::error::AsterixError::InvalidData`] if a header
+    /// declares a length smaller than the 3-byte header itself (see
+    /// [`frame_blocks`]); a malformed feed like that can never resolve no
+    /// matter how many more datagrams arrive.
+    pub(crate) fn feed(&mut self, datagram: &[u8]) -> Result<Vec<(u8, Vec<u8>)>> {
+        self.buffer.extend_from_slice(datagram);
+
+        let (spans, consumed) = frame_blocks(&self.buffer)?;
+        let blocks = spans
+            .into_iter()
+            .map(|span| {
+                (
+                    span.category,
+                    self.buffer[span.start..span.start + span.len].to_vec(),
+                )
+            })
+            .collect();
+
+        self.buffer.drain(..consumed);
+        Ok(blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feed_single_datagram_with_whole_blocks() {
+        let mut reassembler = BlockReassembler::default();
+        let datagram = [48, 0x00, 0x03, 62, 0x00, 0x03];
+        let blocks = reassembler.feed(&datagram).unwrap();
+        assert_eq!(blocks, vec![(48, vec![48, 0, 3]), (62, vec![62, 0, 3])]);
+    }
+
+    #[test]
+    fn test_feed_buffers_partial_block_until_next_datagram() {
+        let mut reassembler = BlockReassembler::default();
+        // Declares a 6-byte block but only the 3-byte header arrives first.
+        let blocks = reassembler.feed(&[48, 0x00, 0x06]).unwrap();
+        assert!(blocks.is_empty());
+
+        let blocks = reassembler.feed(&[0xAA, 0xBB, 0xCC]).unwrap();
+        assert_eq!(blocks, vec![(48, vec![48, 0, 6, 0xAA, 0xBB, 0xCC])]);
+    }
+
+    #[test]
+    fn test_feed_rejects_undersized_declared_length() {
+        let mut reassembler = BlockReassembler::default();
+        let err = reassembler.feed(&[48, 0x00, 0x02]).unwrap_err();
+        assert!(matches!(err, crate::error::AsterixError::InvalidData(_)));
+    }
+}
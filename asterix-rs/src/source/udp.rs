@@ -0,0 +1,408 @@
+//! UDP multicast source for live ASTERIX surveillance feeds
+//!
+//! Operational ASTERIX is typically delivered as UDP multicast datablocks
+//! from radar/sensor systems, rather than read from a static file. This
+//! module joins a multicast group, reassembles datagram payloads into whole
+//! blocks via [`super::reassembly::BlockReassembler`] (the same reassembly
+//! core [`super::live::NonBlockingAsterixSource`] uses, since a datagram
+//! boundary doesn't line up with an ASTERIX block boundary), feeds each
+//! block through [`crate::parse`] with a configurable [`ParseOptions`], and
+//! hands the resulting records to a caller either directly via
+//! [`AsterixSource::run`] or, via [`AsterixSource::spawn`], through a
+//! bounded channel so a slow consumer applies backpressure instead of the
+//! background thread buffering an unbounded backlog in memory.
+//!
+//! A datagram that can never resolve into a valid block (a header
+//! declaring a length shorter than the header itself) is logged and
+//! dropped, and the reassembler is reset so the next datagram starts a
+//! fresh block rather than the stream ending: a single malformed datagram
+//! on a live feed shouldn't take the whole source down.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use asterix::source::udp::{AsterixSource, UdpSourceConfig};
+//! use asterix::init_default;
+//! use std::time::Duration;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     init_default()?;
+//!
+//!     let source = AsterixSource::bind(UdpSourceConfig::default())?;
+//!     let rx = source.spawn();
+//!
+//!     while let Some(record) = rx.recv_timeout(Duration::from_secs(1))? {
+//!         println!("CAT{:03} record ({} items)", record.category, record.item_count());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fmt;
+use std::net::{Ipv4Addr, UdpSocket};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::error::AsterixError;
+use crate::types::{AsterixRecord, ParseOptions};
+
+use super::reassembly::BlockReassembler;
+
+/// Error type for UDP source operations
+#[derive(Debug)]
+pub enum UdpSourceError {
+    /// Failed to bind the listening socket
+    BindError(String),
+    /// Failed to join the multicast group
+    JoinError(String),
+    /// Failed to receive a datagram
+    IoError(String),
+    /// The background source thread is gone
+    Disconnected,
+}
+
+impl fmt::Display for UdpSourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UdpSourceError::BindError(msg) => write!(f, "UDP source bind error: {msg}"),
+            UdpSourceError::JoinError(msg) => write!(f, "UDP multicast join error: {msg}"),
+            UdpSourceError::IoError(msg) => write!(f, "UDP source I/O error: {msg}"),
+            UdpSourceError::Disconnected => write!(f, "UDP source background thread ended"),
+        }
+    }
+}
+
+impl std::error::Error for UdpSourceError {}
+
+impl From<UdpSourceError> for AsterixError {
+    fn from(err: UdpSourceError) -> Self {
+        AsterixError::IOError(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for UdpSourceError {
+    fn from(err: std::io::Error) -> Self {
+        UdpSourceError::IoError(err.to_string())
+    }
+}
+
+impl From<AsterixError> for UdpSourceError {
+    fn from(err: AsterixError) -> Self {
+        UdpSourceError::IoError(err.to_string())
+    }
+}
+
+/// Configuration for a UDP multicast [`AsterixSource`]
+#[derive(Debug, Clone)]
+pub struct UdpSourceConfig {
+    /// Multicast group address to join
+    /// Default: 239.0.0.1
+    pub multicast_addr: Ipv4Addr,
+
+    /// UDP port to listen on
+    /// Default: 8600
+    pub port: u16,
+
+    /// Local interface to join the group on (`None` = the default interface)
+    pub interface_addr: Option<Ipv4Addr>,
+
+    /// Options applied to every decode (`verbose`, `filter_category`, etc.)
+    pub parse_options: ParseOptions,
+
+    /// Capacity of [`AsterixSource::spawn`]'s channel
+    ///
+    /// Once full, the background thread blocks on the next decoded record
+    /// until the consumer catches up, bounding memory instead of buffering
+    /// an unlimited backlog for a slow subscriber.
+    /// Default: 1024
+    pub queue_capacity: usize,
+}
+
+impl Default for UdpSourceConfig {
+    fn default() -> Self {
+        Self {
+            multicast_addr: Ipv4Addr::new(239, 0, 0, 1),
+            port: 8600,
+            interface_addr: None,
+            parse_options: ParseOptions::default(),
+            queue_capacity: 1024,
+        }
+    }
+}
+
+/// Maximum UDP datagram payload this source will read
+///
+/// Comfortably above the largest realistic ASTERIX datablock; datagrams
+/// larger than this are truncated by `recv_from`, same as any UDP reader.
+const MAX_DATAGRAM_LEN: usize = 65_536;
+
+/// A live ASTERIX feed joined to a UDP multicast group
+pub struct AsterixSource {
+    socket: UdpSocket,
+    config: UdpSourceConfig,
+    reassembler: BlockReassembler,
+}
+
+impl AsterixSource {
+    /// Bind the listening socket and join the configured multicast group
+    pub fn bind(config: UdpSourceConfig) -> Result<Self, UdpSourceError> {
+        let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, config.port))?;
+        let interface = config.interface_addr.unwrap_or(Ipv4Addr::UNSPECIFIED);
+        socket
+            .join_multicast_v4(&config.multicast_addr, &interface)
+            .map_err(|e| UdpSourceError::JoinError(e.to_string()))?;
+
+        Ok(Self {
+            socket,
+            config,
+            reassembler: BlockReassembler::default(),
+        })
+    }
+
+    /// Receive and decode datagrams forever, calling `on_record` for each
+    /// decoded record
+    ///
+    /// Blocks the calling thread; use [`Self::spawn`] to decode on a
+    /// background thread instead.
+    pub fn run(&mut self, mut on_record: impl FnMut(AsterixRecord)) -> Result<(), UdpSourceError> {
+        let mut buffer = [0u8; MAX_DATAGRAM_LEN];
+        loop {
+            let (len, _src) = self.socket.recv_from(&mut buffer)?;
+            for record in self.decode_datagram(&buffer[..len]) {
+                on_record(record);
+            }
+        }
+    }
+
+    /// Reassemble one datagram's payload into zero or more whole blocks and
+    /// decode each, applying `self.config.parse_options`
+    ///
+    /// A block that can never resolve (a header declaring a length shorter
+    /// than the header itself) is logged and the reassembler's buffer is
+    /// dropped so the next datagram resyncs on a fresh block, instead of
+    /// the same unrecoverable bytes being retried forever.
+    fn decode_datagram(&mut self, datagram: &[u8]) -> Vec<AsterixRecord> {
+        let blocks = match self.reassembler.feed(datagram) {
+            Ok(blocks) => blocks,
+            Err(e) => {
+                log::warn!("failed to reassemble ASTERIX datagram, resyncing: {e}");
+                self.reassembler = BlockReassembler::default();
+                return Vec::new();
+            }
+        };
+
+        let mut records = Vec::new();
+        for (category, block) in blocks {
+            if let Some(filter_cat) = self.config.parse_options.filter_category {
+                if category != filter_cat {
+                    continue;
+                }
+            }
+            match crate::parse(&block, self.config.parse_options.clone()) {
+                Ok(decoded) => records.extend(decoded),
+                Err(e) => log::warn!("failed to parse ASTERIX block: {e}"),
+            }
+        }
+        records
+    }
+
+    /// Spawn a background thread that decodes datagrams and feeds them into
+    /// a bounded channel
+    ///
+    /// Consuming this source by value ties the background thread's
+    /// lifetime to the returned [`AsterixSourceRx`]: once every handle is
+    /// dropped, the channel disconnects and the thread's next send fails,
+    /// ending it.
+    pub fn spawn(mut self) -> AsterixSourceRx {
+        let (sender, receiver) = mpsc::sync_channel(self.config.queue_capacity);
+
+        let handle = thread::spawn(move || {
+            let mut buffer = [0u8; MAX_DATAGRAM_LEN];
+            loop {
+                let (len, _src) = match self.socket.recv_from(&mut buffer) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        log::warn!("UDP source recv failed: {e}");
+                        continue;
+                    }
+                };
+                for record in self.decode_datagram(&buffer[..len]) {
+                    if sender.send(record).is_err() {
+                        // Every AsterixSourceRx was dropped; nothing left to feed.
+                        return;
+                    }
+                }
+            }
+        });
+
+        AsterixSourceRx {
+            receiver,
+            _handle: handle,
+        }
+    }
+}
+
+/// A bounded receiver of records decoded by a background [`AsterixSource`]
+pub struct AsterixSourceRx {
+    receiver: mpsc::Receiver<AsterixRecord>,
+    // Kept only to tie the background thread's lifetime to this handle;
+    // dropping it does not join or abort the thread, matching how
+    // `transport::can::CanBus`'s `_reader_handle` is never polled either.
+    _handle: thread::JoinHandle<()>,
+}
+
+impl AsterixSourceRx {
+    /// Receive the next decoded record, blocking up to `timeout`
+    ///
+    /// Returns `Ok(None)` on timeout, and [`UdpSourceError::Disconnected`] if
+    /// the background thread has ended.
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<Option<AsterixRecord>, UdpSourceError> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(record) => Ok(Some(record)),
+            Err(mpsc::RecvTimeoutError::Timeout) => Ok(None),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Err(UdpSourceError::Disconnected),
+        }
+    }
+
+    /// Receive the next decoded record without blocking
+    pub fn try_recv(&self) -> Result<Option<AsterixRecord>, UdpSourceError> {
+        match self.receiver.try_recv() {
+            Ok(record) => Ok(Some(record)),
+            Err(mpsc::TryRecvError::Empty) => Ok(None),
+            Err(mpsc::TryRecvError::Disconnected) => Err(UdpSourceError::Disconnected),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_udp_source_config_default() {
+        let config = UdpSourceConfig::default();
+        assert_eq!(config.multicast_addr, Ipv4Addr::new(239, 0, 0, 1));
+        assert_eq!(config.port, 8600);
+        assert_eq!(config.interface_addr, None);
+        assert_eq!(config.queue_capacity, 1024);
+    }
+
+    #[test]
+    fn test_bind_and_join_loopback_multicast() {
+        let config = UdpSourceConfig {
+            multicast_addr: Ipv4Addr::new(239, 5, 5, 5),
+            port: 0,
+            ..UdpSourceConfig::default()
+        };
+
+        match AsterixSource::bind(config) {
+            Ok(_source) => {}
+            Err(_) => println!("Skipping test: could not join multicast group on this host"),
+        }
+    }
+
+    /// Sending a known CAT32 header-only datablock to a local multicast
+    /// group should produce a decoded record on the other end
+    #[test]
+    fn test_multicast_loopback_produces_record() {
+        let _ = crate::ffi::init_default();
+
+        let config = UdpSourceConfig {
+            multicast_addr: Ipv4Addr::new(239, 7, 7, 7),
+            port: 0,
+            ..UdpSourceConfig::default()
+        };
+
+        // Bind to an ephemeral port so repeated test runs don't collide.
+        let socket = match UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)) {
+            Ok(socket) => socket,
+            Err(_) => {
+                println!("Skipping test: could not bind a local UDP socket");
+                return;
+            }
+        };
+        let port = match socket.local_addr() {
+            Ok(addr) => addr.port(),
+            Err(_) => {
+                println!("Skipping test: could not read local socket address");
+                return;
+            }
+        };
+        drop(socket);
+
+        let config = UdpSourceConfig { port, ..config };
+        let Ok(source) = AsterixSource::bind(config.clone()) else {
+            println!("Skipping test: could not join multicast group on this host");
+            return;
+        };
+        let rx = source.spawn();
+
+        let Ok(sender) = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)) else {
+            println!("Skipping test: could not bind a sender socket");
+            return;
+        };
+        // Minimal header-only CAT32 block: category 32, length 3, no items.
+        let datablock = vec![32u8, 0x00, 0x03];
+        if sender
+            .send_to(&datablock, (config.multicast_addr, config.port))
+            .is_err()
+        {
+            println!("Skipping test: could not send to multicast group on this host");
+            return;
+        }
+
+        match rx.recv_timeout(Duration::from_secs(2)) {
+            Ok(Some(record)) => assert_eq!(record.category, 32),
+            Ok(None) => println!("No record observed (unusual, but not fatal for this environment)"),
+            Err(e) => println!("Skipping test: {e}"),
+        }
+    }
+
+    /// Builds a bound (but never joined-to-traffic) source purely to exercise
+    /// [`AsterixSource::decode_datagram`] without going over the network.
+    fn local_source() -> Option<AsterixSource> {
+        let config = UdpSourceConfig {
+            multicast_addr: Ipv4Addr::new(239, 11, 11, 11),
+            port: 0,
+            ..UdpSourceConfig::default()
+        };
+        AsterixSource::bind(config).ok()
+    }
+
+    /// A header-only CAT32 block split across two datagrams (mid-header,
+    /// even) should still decode once both arrive, proving datagrams are
+    /// reassembled rather than parsed one at a time.
+    #[test]
+    fn test_decode_datagram_reassembles_block_split_across_datagrams() {
+        let _ = crate::ffi::init_default();
+        let Some(mut source) = local_source() else {
+            println!("Skipping test: could not bind a local UDP socket");
+            return;
+        };
+
+        assert!(source.decode_datagram(&[32]).is_empty());
+        let records = source.decode_datagram(&[0x00, 0x03]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].category, 32);
+    }
+
+    /// A datagram that declares a length shorter than its own header can
+    /// never complete; it should be logged and dropped, and the reassembler
+    /// should resync so a subsequent well-formed datagram still decodes.
+    #[test]
+    fn test_decode_datagram_resyncs_after_malformed_header() {
+        let _ = crate::ffi::init_default();
+        let Some(mut source) = local_source() else {
+            println!("Skipping test: could not bind a local UDP socket");
+            return;
+        };
+
+        assert!(source.decode_datagram(&[32, 0x00, 0x02]).is_empty());
+
+        let records = source.decode_datagram(&[32, 0x00, 0x03]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].category, 32);
+    }
+}
@@ -0,0 +1,342 @@
+//! Reusable, mergeable record statistics
+//!
+//! The `streaming_parser`, `parse_pcap`, and `stream_processing` examples
+//! each hand-roll the same handful of aggregates — a per-category record/
+//! byte/item tally, a first/last timestamp and derived message rate, and a
+//! total/average/min/max size breakdown — as local structs and loose
+//! variables. [`StatsCollector`] promotes that into one type: feed it
+//! records one at a time via [`observe`](StatsCollector::observe), then
+//! call [`finish`](StatsCollector::finish) for a [`Stats`] snapshot.
+//!
+//! [`Stats`] derives `Serialize` so it can be written out as JSON metrics
+//! directly (e.g. alongside a [`crate::archive::ArchiveWriter`]-based
+//! capture), and [`Stats::merge`] combines two snapshots so a caller using
+//! [`crate::parse_parallel`]-style per-chunk parsing can collect stats per
+//! chunk and fold them into one overall [`Stats`] afterward, without
+//! re-observing every record serially.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asterix::{init_default, parse, StatsCollector, ParseOptions};
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! init_default()?;
+//! let data = std::fs::read("sample.asterix")?;
+//! let records = parse(&data, ParseOptions::default())?;
+//!
+//! let mut collector = StatsCollector::new();
+//! for record in &records {
+//!     collector.observe(record);
+//! }
+//! let stats = collector.finish();
+//! println!("{}", serde_json::to_string_pretty(&stats)?);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::AsterixRecord;
+
+/// Per-category record/byte/item tally, keyed by category in [`Stats::categories`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CategoryStats {
+    /// Number of records observed for this category
+    pub count: usize,
+    /// Sum of [`AsterixRecord::length`] across every record observed for this category
+    pub total_bytes: usize,
+    /// Sum of [`AsterixRecord::item_count`] across every record observed for this category
+    pub total_items: usize,
+}
+
+/// First/last timestamp and derived message rate across every record observed
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeRange {
+    /// Earliest [`AsterixRecord::timestamp_ms`] observed
+    pub first_ms: u64,
+    /// Latest [`AsterixRecord::timestamp_ms`] observed
+    pub last_ms: u64,
+    /// Records per second over `[first_ms, last_ms]`, or `0.0` if every
+    /// record observed shares the same timestamp (a zero-length range)
+    pub message_rate: f64,
+}
+
+/// Total/average/min/max [`AsterixRecord::length`] across every record observed
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SizeStats {
+    /// Sum of [`AsterixRecord::length`] across every record observed
+    pub total: usize,
+    /// `total / count`
+    pub avg: f64,
+    /// Smallest [`AsterixRecord::length`] observed
+    pub min: u32,
+    /// Largest [`AsterixRecord::length`] observed
+    pub max: u32,
+}
+
+/// A snapshot of everything [`StatsCollector`] has observed
+///
+/// Produced by [`StatsCollector::finish`]; combine two snapshots (e.g. one
+/// per parallel-parsing chunk) with [`Stats::merge`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    /// Number of records this snapshot covers
+    pub record_count: usize,
+    /// Per-category breakdown, keyed by [`AsterixRecord::category`]
+    pub categories: BTreeMap<u8, CategoryStats>,
+    /// Timestamp range and message rate, or `None` if no records were observed
+    pub time_range: Option<TimeRange>,
+    /// Size breakdown, or `None` if no records were observed
+    pub size: Option<SizeStats>,
+}
+
+impl Stats {
+    /// Combine this snapshot with `other`, as if every record both had
+    /// observed had instead been fed to a single [`StatsCollector`].
+    ///
+    /// Per-category counts add; the timestamp range widens to cover both
+    /// (with `message_rate` recomputed over the combined range and record
+    /// count); size totals/min/max combine and `avg` is recomputed.
+    pub fn merge(&self, other: &Stats) -> Stats {
+        let record_count = self.record_count + other.record_count;
+
+        let mut categories = self.categories.clone();
+        for (&category, other_stats) in &other.categories {
+            let entry = categories.entry(category).or_default();
+            entry.count += other_stats.count;
+            entry.total_bytes += other_stats.total_bytes;
+            entry.total_items += other_stats.total_items;
+        }
+
+        let time_range = match (self.time_range, other.time_range) {
+            (Some(a), Some(b)) => {
+                let first_ms = a.first_ms.min(b.first_ms);
+                let last_ms = a.last_ms.max(b.last_ms);
+                Some(TimeRange {
+                    first_ms,
+                    last_ms,
+                    message_rate: message_rate(record_count, first_ms, last_ms),
+                })
+            }
+            (Some(a), None) => Some(a),
+            (None, Some(b)) => Some(b),
+            (None, None) => None,
+        };
+
+        let size = match (self.size, other.size) {
+            (Some(a), Some(b)) => {
+                let total = a.total + b.total;
+                Some(SizeStats {
+                    total,
+                    avg: total as f64 / record_count as f64,
+                    min: a.min.min(b.min),
+                    max: a.max.max(b.max),
+                })
+            }
+            (Some(a), None) => Some(a),
+            (None, Some(b)) => Some(b),
+            (None, None) => None,
+        };
+
+        Stats {
+            record_count,
+            categories,
+            time_range,
+            size,
+        }
+    }
+}
+
+/// Accumulates [`Stats`] one record at a time
+///
+/// Call [`observe`](Self::observe) for every record, then
+/// [`finish`](Self::finish) once to get a [`Stats`] snapshot. Cheap to
+/// construct per parallel-parsing chunk: run one `StatsCollector` per
+/// thread, `finish` each, and fold the results with [`Stats::merge`].
+#[derive(Debug, Clone, Default)]
+pub struct StatsCollector {
+    record_count: usize,
+    categories: BTreeMap<u8, CategoryStats>,
+    first_ms: Option<u64>,
+    last_ms: Option<u64>,
+    total_bytes: usize,
+    min_size: Option<u32>,
+    max_size: Option<u32>,
+}
+
+impl StatsCollector {
+    /// A collector with nothing observed yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold `record` into this collector's running totals
+    pub fn observe(&mut self, record: &AsterixRecord) {
+        self.record_count += 1;
+
+        let category_stats = self.categories.entry(record.category).or_default();
+        category_stats.count += 1;
+        category_stats.total_bytes += record.length as usize;
+        category_stats.total_items += record.item_count();
+
+        self.first_ms = Some(self.first_ms.map_or(record.timestamp_ms, |ms| ms.min(record.timestamp_ms)));
+        self.last_ms = Some(self.last_ms.map_or(record.timestamp_ms, |ms| ms.max(record.timestamp_ms)));
+
+        self.total_bytes += record.length as usize;
+        self.min_size = Some(self.min_size.map_or(record.length, |len| len.min(record.length)));
+        self.max_size = Some(self.max_size.map_or(record.length, |len| len.max(record.length)));
+    }
+
+    /// Consume this collector, producing a [`Stats`] snapshot of everything observed so far
+    pub fn finish(self) -> Stats {
+        let time_range = match (self.first_ms, self.last_ms) {
+            (Some(first_ms), Some(last_ms)) => Some(TimeRange {
+                first_ms,
+                last_ms,
+                message_rate: message_rate(self.record_count, first_ms, last_ms),
+            }),
+            _ => None,
+        };
+
+        let size = match (self.min_size, self.max_size) {
+            (Some(min), Some(max)) => Some(SizeStats {
+                total: self.total_bytes,
+                avg: self.total_bytes as f64 / self.record_count as f64,
+                min,
+                max,
+            }),
+            _ => None,
+        };
+
+        Stats {
+            record_count: self.record_count,
+            categories: self.categories,
+            time_range,
+            size,
+        }
+    }
+}
+
+/// Records per second over `[first_ms, last_ms]`, or `0.0` for a zero-length range
+fn message_rate(record_count: usize, first_ms: u64, last_ms: u64) -> f64 {
+    let duration_ms = last_ms - first_ms;
+    if duration_ms == 0 {
+        return 0.0;
+    }
+    record_count as f64 / (duration_ms as f64 / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(category: u8, timestamp_ms: u64, length: u32) -> AsterixRecord {
+        AsterixRecord {
+            category,
+            length,
+            timestamp_ms,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_empty_collector_yields_no_ranges() {
+        let stats = StatsCollector::new().finish();
+        assert_eq!(stats.record_count, 0);
+        assert!(stats.categories.is_empty());
+        assert!(stats.time_range.is_none());
+        assert!(stats.size.is_none());
+    }
+
+    #[test]
+    fn test_observe_tallies_per_category_counts() {
+        let mut collector = StatsCollector::new();
+        collector.observe(&record(48, 1_000, 100));
+        collector.observe(&record(48, 2_000, 200));
+        collector.observe(&record(62, 1_500, 50));
+
+        let stats = collector.finish();
+        assert_eq!(stats.record_count, 3);
+        assert_eq!(
+            stats.categories[&48],
+            CategoryStats { count: 2, total_bytes: 300, total_items: 0 }
+        );
+        assert_eq!(
+            stats.categories[&62],
+            CategoryStats { count: 1, total_bytes: 50, total_items: 0 }
+        );
+    }
+
+    #[test]
+    fn test_observe_tracks_time_range_and_message_rate() {
+        let mut collector = StatsCollector::new();
+        collector.observe(&record(48, 1_000, 10));
+        collector.observe(&record(48, 3_000, 10));
+
+        let stats = collector.finish();
+        let time_range = stats.time_range.unwrap();
+        assert_eq!(time_range.first_ms, 1_000);
+        assert_eq!(time_range.last_ms, 3_000);
+        assert!((time_range.message_rate - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_single_record_yields_zero_message_rate() {
+        let mut collector = StatsCollector::new();
+        collector.observe(&record(48, 1_000, 10));
+
+        let time_range = collector.finish().time_range.unwrap();
+        assert_eq!(time_range.message_rate, 0.0);
+    }
+
+    #[test]
+    fn test_observe_tracks_size_stats() {
+        let mut collector = StatsCollector::new();
+        collector.observe(&record(48, 1_000, 10));
+        collector.observe(&record(48, 1_000, 30));
+
+        let size = collector.finish().size.unwrap();
+        assert_eq!(size.total, 40);
+        assert_eq!(size.avg, 20.0);
+        assert_eq!(size.min, 10);
+        assert_eq!(size.max, 30);
+    }
+
+    #[test]
+    fn test_merge_combines_two_snapshots() {
+        let mut a = StatsCollector::new();
+        a.observe(&record(48, 1_000, 10));
+        a.observe(&record(48, 2_000, 20));
+
+        let mut b = StatsCollector::new();
+        b.observe(&record(48, 500, 5));
+        b.observe(&record(62, 4_000, 100));
+
+        let merged = a.finish().merge(&b.finish());
+
+        assert_eq!(merged.record_count, 4);
+        assert_eq!(merged.categories[&48].count, 3);
+        assert_eq!(merged.categories[&62].count, 1);
+
+        let time_range = merged.time_range.unwrap();
+        assert_eq!(time_range.first_ms, 500);
+        assert_eq!(time_range.last_ms, 4_000);
+
+        let size = merged.size.unwrap();
+        assert_eq!(size.total, 135);
+        assert_eq!(size.min, 5);
+        assert_eq!(size.max, 100);
+    }
+
+    #[test]
+    fn test_merge_with_empty_snapshot_is_identity() {
+        let mut a = StatsCollector::new();
+        a.observe(&record(48, 1_000, 10));
+        let stats = a.finish();
+
+        let empty = StatsCollector::new().finish();
+        assert_eq!(stats.merge(&empty), stats);
+    }
+}
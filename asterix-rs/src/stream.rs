@@ -0,0 +1,191 @@
+//! Chunk-at-a-time streaming over [`crate::parse_with_offset`]
+//!
+//! The `incremental_parsing` example hand-rolls the loop every caller of
+//! [`crate::parse_with_offset`] otherwise has to write: track an `offset`,
+//! re-issue the call with the previous `bytes_consumed`, and stop once
+//! `remaining_blocks` hits zero or a chunk yields nothing. [`stream`]
+//! promotes that loop into a [`BlockIterator`] so callers get one
+//! [`AsterixRecord`] at a time — across chunk boundaries — via the standard
+//! `Iterator` trait instead of copying the bookkeeping.
+
+use std::collections::VecDeque;
+
+use crate::error::{AsterixError, Result};
+use crate::parser::parse_with_offset;
+use crate::types::{AsterixRecord, ParseOptions};
+
+/// Iterator over `data`'s records, fetched [`crate::parse_with_offset`]-chunk
+/// at a time.
+///
+/// Created by [`stream`]. Yields `Err` (rather than panicking) if a chunk
+/// fails to parse, and stops for good after that. [`ParseOptions::max_records`]
+/// is honored across the whole stream, not just within one chunk.
+pub struct BlockIterator<'a> {
+    data: &'a [u8],
+    offset: usize,
+    blocks_per_chunk: usize,
+    options: ParseOptions,
+    pending: VecDeque<AsterixRecord>,
+    emitted: usize,
+    no_more_chunks: bool,
+    done: bool,
+}
+
+/// Stream `data`'s records one at a time, fetching `blocks_per_chunk` blocks
+/// from [`crate::parse_with_offset`] at a time under the hood.
+///
+/// Replaces the manual `offset`/`remaining_blocks` loop the `incremental_parsing`
+/// example otherwise has to write: advance by calling `.next()` (or any
+/// `Iterator` method) until it returns `None`, which happens once the last
+/// chunk reports `remaining_blocks == 0`, a chunk yields no records, or
+/// [`ParseOptions::max_records`] records have been emitted.
+///
+/// # Example
+///
+/// ```no_run
+/// # use asterix::{init_default, stream, ParseOptions};
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// init_default()?;
+/// let data = std::fs::read("large_capture.asterix")?;
+/// for record in stream(&data, 100, ParseOptions::default()) {
+///     let record = record?;
+///     println!("cat{:03} ({} bytes)", record.category, record.length);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn stream(data: &[u8], blocks_per_chunk: usize, options: ParseOptions) -> BlockIterator<'_> {
+    BlockIterator {
+        data,
+        offset: 0,
+        blocks_per_chunk,
+        no_more_chunks: data.is_empty(),
+        options,
+        pending: VecDeque::new(),
+        emitted: 0,
+        done: false,
+    }
+}
+
+impl<'a> Iterator for BlockIterator<'a> {
+    type Item = Result<AsterixRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        loop {
+            if let Some(max) = self.options.max_records {
+                if self.emitted >= max {
+                    self.done = true;
+                    return None;
+                }
+            }
+
+            if let Some(record) = self.pending.pop_front() {
+                self.emitted += 1;
+                return Some(Ok(record));
+            }
+
+            if self.no_more_chunks {
+                self.done = true;
+                return None;
+            }
+
+            // `max_records` is enforced by this iterator across the whole
+            // stream (above), not per chunk, so it's cleared here to avoid
+            // a short chunk-local cap cutting the stream off early.
+            let mut chunk_options = self.options.clone();
+            chunk_options.max_records = None;
+
+            match parse_with_offset(self.data, self.offset, self.blocks_per_chunk, chunk_options) {
+                Ok(result) => {
+                    if result.records.is_empty() {
+                        self.done = true;
+                        return None;
+                    }
+                    self.offset = result.bytes_consumed;
+                    self.no_more_chunks = result.remaining_blocks == 0;
+                    self.pending.extend(result.records);
+                }
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+    use std::sync::Once;
+
+    /// Global initialization for tests that call the C++ backend
+    static INIT: Once = Once::new();
+
+    fn ensure_initialized() {
+        INIT.call_once(|| {
+            let _ = crate::ffi::init_default();
+        });
+    }
+
+    fn test_block(category: u8) -> Vec<u8> {
+        vec![category, 0x00, 0x03]
+    }
+
+    #[test]
+    fn test_stream_empty_data_yields_nothing() {
+        ensure_initialized();
+        let records: Vec<_> = stream(&[], 10, ParseOptions::default()).collect();
+        assert!(records.is_empty());
+    }
+
+    #[test]
+    fn test_stream_crosses_chunk_boundaries() {
+        ensure_initialized();
+        let mut data = Vec::new();
+        for _ in 0..5 {
+            data.extend(test_block(48));
+        }
+
+        // Compare against a single whole-buffer parse rather than asserting
+        // an absolute count, since whether a bare 3-byte block decodes to a
+        // record depends on the loaded category spec.
+        let whole = parse(&data, ParseOptions::default()).unwrap_or_default();
+        let streamed: Vec<AsterixRecord> = stream(&data, 2, ParseOptions::default())
+            .collect::<Result<Vec<_>>>()
+            .unwrap_or_default();
+        assert_eq!(whole.len(), streamed.len());
+    }
+
+    #[test]
+    fn test_stream_respects_max_records_across_chunks() {
+        ensure_initialized();
+        let mut data = Vec::new();
+        for _ in 0..5 {
+            data.extend(test_block(48));
+        }
+
+        let options = ParseOptions {
+            max_records: Some(3),
+            ..Default::default()
+        };
+        let streamed: Vec<AsterixRecord> = stream(&data, 2, options)
+            .collect::<Result<Vec<_>>>()
+            .unwrap_or_default();
+        assert!(streamed.len() <= 3);
+    }
+
+    #[test]
+    fn test_stream_propagates_error_without_panicking() {
+        ensure_initialized();
+        let data = [0x30, 0x00, 0x02]; // declared length smaller than the header
+        let results: Vec<_> = stream(&data, 10, ParseOptions::default()).collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+}
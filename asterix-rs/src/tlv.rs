@@ -0,0 +1,191 @@
+//! Generic TLV (type-length-value) decoding for Reserved Expansion (RE) and
+//! Special Purpose (SP) data items.
+//!
+//! Several ASTERIX categories (including CAT032) carry RE/SP items whose
+//! internal layout the core UAP does not fully describe: a one-byte overall
+//! length followed by a sequence of sub-fields, each identified by a
+//! sub-field id. This module decodes that sequence generically, given a table
+//! describing the known sub-field ids for a category. Sub-field ids that
+//! aren't in the table are not treated as errors — their raw bytes are kept
+//! so decoding stays robust across Eurocontrol edition differences.
+
+use crate::error::{AsterixError, Result};
+
+/// How long a sub-field's value is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubFieldLength {
+    /// Always exactly this many bytes.
+    Fixed(usize),
+    /// Self-describing: a one-byte length prefix precedes the value.
+    Variable,
+}
+
+/// Describes one known sub-field id within an RE/SP field.
+#[derive(Debug, Clone, Copy)]
+pub struct SubFieldSpec {
+    /// Sub-field id, as it appears on the wire.
+    pub id: u8,
+    /// How to determine the length of this sub-field's value.
+    pub length: SubFieldLength,
+}
+
+/// A decoded sub-field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubField {
+    /// Sub-field id, as it appeared on the wire.
+    pub id: u8,
+    /// Raw value bytes. For ids absent from the spec table, this is the
+    /// sub-field's self-describing (length-prefixed) payload.
+    pub value: Vec<u8>,
+}
+
+/// A per-category table of known sub-field ids, registered by callers that
+/// understand a particular RE/SP expansion's layout.
+pub type SubFieldSpecTable = &'static [SubFieldSpec];
+
+/// Parse an RE/SP field's bytes (including its own one-byte overall length
+/// prefix) into a list of sub-fields.
+///
+/// Known ids (present in `spec`) are read using their declared [`SubFieldLength`].
+/// Unknown ids are read as self-describing length-prefixed values so that an
+/// unrecognized sub-field never aborts decoding of the rest of the item.
+///
+/// # Errors
+///
+/// Returns [`AsterixError::UnexpectedEOF`] if the declared overall length or a
+/// sub-field's length runs past the end of `data`.
+pub fn parse_subfields(data: &[u8], spec: SubFieldSpecTable) -> Result<Vec<SubField>> {
+    if data.is_empty() {
+        return Err(AsterixError::UnexpectedEOF {
+            offset: 0,
+            expected: 1,
+        });
+    }
+
+    let overall_len = data[0] as usize;
+    if overall_len + 1 > data.len() {
+        return Err(AsterixError::UnexpectedEOF {
+            offset: 1,
+            expected: overall_len,
+        });
+    }
+
+    let body = &data[1..1 + overall_len];
+    let mut cursor = 0;
+    let mut fields = Vec::new();
+
+    while cursor < body.len() {
+        let id = body[cursor];
+        cursor += 1;
+
+        let known_len = spec.iter().find(|s| s.id == id).map(|s| s.length);
+
+        let value = match known_len {
+            Some(SubFieldLength::Fixed(n)) => {
+                if cursor + n > body.len() {
+                    return Err(AsterixError::UnexpectedEOF {
+                        offset: cursor,
+                        expected: n,
+                    });
+                }
+                let v = body[cursor..cursor + n].to_vec();
+                cursor += n;
+                v
+            }
+            Some(SubFieldLength::Variable) | None => {
+                if cursor >= body.len() {
+                    return Err(AsterixError::UnexpectedEOF {
+                        offset: cursor,
+                        expected: 1,
+                    });
+                }
+                let len = body[cursor] as usize;
+                cursor += 1;
+                if cursor + len > body.len() {
+                    return Err(AsterixError::UnexpectedEOF {
+                        offset: cursor,
+                        expected: len,
+                    });
+                }
+                let v = body[cursor..cursor + len].to_vec();
+                cursor += len;
+                v
+            }
+        };
+
+        fields.push(SubField { id, value });
+    }
+
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_subfields_empty_input() {
+        let result = parse_subfields(&[], &[]);
+        assert!(matches!(result, Err(AsterixError::UnexpectedEOF { .. })));
+    }
+
+    #[test]
+    fn test_parse_subfields_overall_length_too_long() {
+        let data = [0x05, 0x01, 0x00];
+        let result = parse_subfields(&data, &[]);
+        assert!(matches!(result, Err(AsterixError::UnexpectedEOF { .. })));
+    }
+
+    #[test]
+    fn test_parse_subfields_fixed_length_known_id() {
+        const SPEC: SubFieldSpecTable = &[SubFieldSpec {
+            id: 0x01,
+            length: SubFieldLength::Fixed(2),
+        }];
+        // overall_len=3: id=1, value=[0xAB, 0xCD]
+        let data = [0x03, 0x01, 0xAB, 0xCD];
+        let fields = parse_subfields(&data, SPEC).unwrap();
+        assert_eq!(
+            fields,
+            vec![SubField {
+                id: 1,
+                value: vec![0xAB, 0xCD]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_subfields_unknown_id_falls_back_to_length_prefixed() {
+        // overall_len=4: id=0x99 (unknown), len=2, value=[0x11, 0x22]
+        let data = [0x04, 0x99, 0x02, 0x11, 0x22];
+        let fields = parse_subfields(&data, &[]).unwrap();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].id, 0x99);
+        assert_eq!(fields[0].value, vec![0x11, 0x22]);
+    }
+
+    #[test]
+    fn test_parse_subfields_multiple_entries() {
+        const SPEC: SubFieldSpecTable = &[SubFieldSpec {
+            id: 0x01,
+            length: SubFieldLength::Fixed(1),
+        }];
+        // overall_len=5: id=1 fixed(1) -> 0xFF; id=2 unknown -> len=1, value=0x42
+        let data = [0x05, 0x01, 0xFF, 0x02, 0x01, 0x42];
+        let fields = parse_subfields(&data, SPEC).unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0], SubField { id: 1, value: vec![0xFF] });
+        assert_eq!(fields[1], SubField { id: 2, value: vec![0x42] });
+    }
+
+    #[test]
+    fn test_parse_subfields_truncated_fixed_field() {
+        const SPEC: SubFieldSpecTable = &[SubFieldSpec {
+            id: 0x01,
+            length: SubFieldLength::Fixed(4),
+        }];
+        let data = [0x02, 0x01, 0xAB];
+        let result = parse_subfields(&data, SPEC);
+        assert!(matches!(result, Err(AsterixError::UnexpectedEOF { .. })));
+    }
+}
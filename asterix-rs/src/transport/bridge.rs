@@ -0,0 +1,100 @@
+//! DDS <-> Zenoh forwarding bridge
+//!
+//! [`crate::transport::dds`] and [`crate::transport::zenoh`] each publish
+//! and subscribe to ASTERIX data independently, with their own routing
+//! (a `<prefix>_cat<cat>_sac<sac>_sic<sic>` DDS topic name vs. an
+//! `asterix/<cat>/<sac>/<sic>` Zenoh key expression). [`DdsZenohBridge`]
+//! forwards samples between the two, so a deployment that straddles a
+//! native DDS system and Zenoh-based infrastructure can interoperate
+//! without re-encoding: a record published on one side is republished,
+//! unchanged, on the other using that side's own
+//! `publish_raw_with_routing`.
+//!
+//! Requires both the `dds` and `zenoh` features.
+
+use std::thread::JoinHandle;
+
+use super::dds::{DdsPublisher, DdsSubscriber};
+use super::zenoh::{ZenohPublisher, ZenohSubscriber};
+
+/// Forwards ASTERIX samples between a DDS domain and a Zenoh session.
+///
+/// Each direction runs on its own task for the reason its subscriber
+/// already does: [`DdsSubscriber::recv`] blocks the calling OS thread (it
+/// polls an `mio` reader directly), so the DDS-to-Zenoh direction runs on a
+/// blocking task and calls into the (async) Zenoh publisher via the current
+/// Tokio runtime's [`Handle`](tokio::runtime::Handle). The Zenoh-to-DDS
+/// direction runs as an ordinary async task, since [`ZenohSubscriber::recv`]
+/// is already async and [`DdsPublisher::publish_raw_with_routing`] is a
+/// quick, non-blocking call.
+pub struct DdsZenohBridge {
+    dds_to_zenoh: Option<JoinHandle<()>>,
+    zenoh_to_dds: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl DdsZenohBridge {
+    /// Spawn both forwarding directions and return immediately.
+    ///
+    /// Must be called from within a Tokio runtime (the DDS-to-Zenoh
+    /// direction captures [`Handle::current`](tokio::runtime::Handle::current)
+    /// to drive the async Zenoh publish from its blocking thread).
+    ///
+    /// A sample missing a SAC/SIC (e.g. a raw topic/key with no routing
+    /// suffix) is forwarded with `sac = 0, sic = 0` — the same default the
+    /// rest of this crate's transports use for "routing info unavailable".
+    pub fn spawn(
+        mut dds_sub: DdsSubscriber,
+        zenoh_pub: ZenohPublisher,
+        mut zenoh_sub: ZenohSubscriber,
+        dds_pub: DdsPublisher,
+    ) -> Self {
+        let rt_handle = tokio::runtime::Handle::current();
+
+        let dds_to_zenoh = std::thread::spawn(move || {
+            while let Some(sample) = dds_sub.recv() {
+                let sac = sample.sac.unwrap_or(0);
+                let sic = sample.sic.unwrap_or(0);
+                let result = rt_handle.block_on(zenoh_pub.publish_raw_with_routing(
+                    sample.category,
+                    sac,
+                    sic,
+                    &sample.data,
+                ));
+                if let Err(e) = result {
+                    log::warn!("DDS->Zenoh forward failed for CAT{}: {e}", sample.category);
+                }
+            }
+        });
+
+        let zenoh_to_dds = tokio::spawn(async move {
+            while let Some(sample) = zenoh_sub.recv().await {
+                let sac = sample.sac.unwrap_or(0);
+                let sic = sample.sic.unwrap_or(0);
+                if let Err(e) =
+                    dds_pub.publish_raw_with_routing(sample.category, sac, sic, &sample.data)
+                {
+                    log::warn!("Zenoh->DDS forward failed for CAT{}: {e}", sample.category);
+                }
+            }
+        });
+
+        Self {
+            dds_to_zenoh: Some(dds_to_zenoh),
+            zenoh_to_dds: Some(zenoh_to_dds),
+        }
+    }
+
+    /// Wait for both forwarding directions to finish.
+    ///
+    /// Normally runs forever — this only returns once both sides' sources
+    /// are closed (the DDS reader's participant is dropped, or the Zenoh
+    /// subscriber's session is closed).
+    pub async fn join(mut self) {
+        if let Some(handle) = self.dds_to_zenoh.take() {
+            let _ = tokio::task::spawn_blocking(move || handle.join()).await;
+        }
+        if let Some(handle) = self.zenoh_to_dds.take() {
+            let _ = handle.await;
+        }
+    }
+}
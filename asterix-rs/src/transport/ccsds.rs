@@ -123,7 +123,12 @@
 
 use std::collections::HashMap;
 use std::fmt;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf};
 
 use spacepackets::{CcsdsPacket, SpHeader};
 
@@ -131,6 +136,7 @@ use spacepackets::{CcsdsPacket, SpHeader};
 use arbitrary_int::{u11, u14};
 
 use crate::error::AsterixError;
+use crate::hex::from_hex;
 use crate::types::AsterixRecord;
 
 /// Error type for CCSDS transport operations
@@ -148,6 +154,8 @@ pub enum CcsdsError {
     SerializationError(String),
     /// Channel closed
     ChannelClosed,
+    /// UDP socket bind/send/receive/multicast-join failure
+    NetworkError(String),
 }
 
 impl fmt::Display for CcsdsError {
@@ -159,6 +167,7 @@ impl fmt::Display for CcsdsError {
             CcsdsError::InvalidApid(msg) => write!(f, "Invalid APID: {msg}"),
             CcsdsError::SerializationError(msg) => write!(f, "Serialization error: {msg}"),
             CcsdsError::ChannelClosed => write!(f, "Channel closed"),
+            CcsdsError::NetworkError(msg) => write!(f, "CCSDS network error: {msg}"),
         }
     }
 }
@@ -173,6 +182,7 @@ impl From<CcsdsError> for AsterixError {
 
 /// CCSDS packet mode (Telemetry or Telecommand)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CcsdsMode {
     /// Telemetry packets (downlink from spacecraft)
     #[default]
@@ -181,8 +191,199 @@ pub enum CcsdsMode {
     Telecommand,
 }
 
+/// Reference epoch for a CCSDS Unsegmented Time Code ([`TimeCodeFormat::Cuc`]
+/// or [`CucTime`]), selected via [`CcsdsConfig::cuc_epoch`]. The CDS format
+/// ([`TimeCodeFormat::Cds`]) is always relative to the Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CucEpoch {
+    /// Seconds since the Unix epoch (1970-01-01T00:00:00Z) — not a CCSDS
+    /// epoch in its own right, but a common agency-defined choice and this
+    /// module's default for backward compatibility.
+    #[default]
+    Unix,
+    /// Seconds since the CCSDS epoch (1958-01-01T00:00:00Z, CCSDS
+    /// 301.0-B-4 §3.2.1)
+    Ccsds,
+}
+
+/// Seconds from the CCSDS epoch (1958-01-01T00:00:00Z) to the Unix epoch
+/// (1970-01-01T00:00:00Z): 12 years, including the leap days in 1960, 1964,
+/// and 1968.
+const CCSDS_TO_UNIX_EPOCH_OFFSET_SECS: u64 = 378_691_200;
+
+/// A standalone CCSDS Unsegmented Time Code (CUC, CCSDS 301.0-B-4 §3.2):
+/// the same 1-byte P-field (coarse-time octet count in the high nibble,
+/// fine-time octet count in the low nibble) plus T-field layout as
+/// [`TimeCodeFormat::Cuc`]/[`encode_time_code`], but as a standalone value
+/// callers can build, inspect, and encode without going through a
+/// [`CcsdsPublisher`].
+///
+/// `seconds`/`counter` are always relative to the [`CucEpoch`] passed to
+/// [`Self::from_unix`]; the wire encoding itself does not distinguish
+/// epochs (consistent with [`TimeCodeFormat::Cuc`], which is likewise
+/// epoch-agnostic on the wire), so the encoding/decoding side must agree on
+/// `epoch` out of band (see [`CcsdsConfig::cuc_epoch`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CucTime {
+    coarse_octets: u8,
+    fine_octets: u8,
+    seconds: u64,
+    fine: u64,
+}
+
+impl CucTime {
+    /// Build a `CucTime` from a Unix timestamp (whole seconds plus
+    /// sub-second nanoseconds), converting `secs` to `epoch` and quantizing
+    /// `subsec_nanos` to `fine_octets` bytes of `1/256^k`-second precision.
+    /// `coarse_octets`/`fine_octets` are each clamped to `0..=8` (the
+    /// P-field nibble's range).
+    pub fn from_unix(
+        coarse_octets: u8,
+        fine_octets: u8,
+        epoch: CucEpoch,
+        secs: u64,
+        subsec_nanos: u32,
+    ) -> Self {
+        let coarse_octets = coarse_octets.min(8);
+        let fine_octets = fine_octets.min(8);
+
+        let seconds = match epoch {
+            CucEpoch::Unix => secs,
+            CucEpoch::Ccsds => secs.saturating_add(CCSDS_TO_UNIX_EPOCH_OFFSET_SECS),
+        };
+
+        let fine = if fine_octets > 0 {
+            let subsec_fraction = subsec_nanos as f64 / 1_000_000_000.0;
+            let scale = 256f64.powi(fine_octets as i32);
+            (subsec_fraction * scale).round() as u64
+        } else {
+            0
+        };
+
+        Self {
+            coarse_octets,
+            fine_octets,
+            seconds,
+            fine,
+        }
+    }
+
+    /// Encode the P-field followed by the T-field (coarse then fine time
+    /// octets), all big-endian.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.width());
+        out.push((self.coarse_octets << 4) | (self.fine_octets & 0x0F));
+
+        let seconds_bytes = self.seconds.to_be_bytes();
+        out.extend_from_slice(&seconds_bytes[8 - self.coarse_octets as usize..]);
+
+        if self.fine_octets > 0 {
+            let fine_bytes = self.fine.to_be_bytes();
+            out.extend_from_slice(&fine_bytes[8 - self.fine_octets as usize..]);
+        }
+
+        out
+    }
+
+    /// Total encoded length in bytes: the 1-byte P-field plus `coarse_octets`
+    /// plus `fine_octets`.
+    pub fn width(&self) -> usize {
+        1 + self.coarse_octets as usize + self.fine_octets as usize
+    }
+
+    /// The whole-seconds counter since `epoch` (the T-field's coarse time)
+    pub fn counter(&self) -> u64 {
+        self.seconds
+    }
+}
+
+/// CCSDS time-code format used for the secondary header, selected when
+/// [`CcsdsConfig::use_secondary_header`] is set. [`Self::Cds`] is always
+/// relative to the Unix epoch; [`Self::Cuc`] is relative to whichever
+/// [`CucEpoch`] is selected via [`CcsdsConfig::cuc_epoch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeCodeFormat {
+    /// CCSDS Unsegmented Time Code (CUC, CCSDS 301.0-B-4 §3.2): a 1-byte
+    /// P-field preamble followed by `coarse_octets` bytes of whole seconds
+    /// and `fine_octets` bytes of sub-second fraction.
+    Cuc {
+        /// Number of big-endian octets encoding whole seconds since the epoch
+        coarse_octets: u8,
+        /// Number of big-endian octets encoding the sub-second fraction
+        fine_octets: u8,
+    },
+    /// CCSDS Day Segmented Time Code (CDS, CCSDS 301.0-B-4 §3.3): 2 bytes of
+    /// days since the epoch, 4 bytes of milliseconds of day, and optionally
+    /// 2 bytes of microseconds.
+    Cds {
+        /// Whether to append a 2-byte microseconds-of-millisecond field
+        include_microseconds: bool,
+    },
+}
+
+impl Default for TimeCodeFormat {
+    /// 4 coarse octets (good to the year 2106) and 2 fine octets
+    /// (~15 microsecond resolution) — a common CUC configuration.
+    fn default() -> Self {
+        TimeCodeFormat::Cuc {
+            coarse_octets: 4,
+            fine_octets: 2,
+        }
+    }
+}
+
+impl TimeCodeFormat {
+    /// CCSDS CUC "Level 1" preset: 4 coarse (whole-second) octets and no
+    /// fine-time octets, i.e. 1-second resolution with no sub-second
+    /// fraction. See [`CcsdsConfig::with_cuc`] to use this as a config.
+    pub fn cuc_level1() -> Self {
+        TimeCodeFormat::Cuc {
+            coarse_octets: 4,
+            fine_octets: 0,
+        }
+    }
+
+    /// CCSDS CUC "Level 2" preset: 4 coarse octets plus 2 fine-time octets
+    /// (~15 microsecond resolution) — the same layout as [`Self::default`],
+    /// named here for parity with [`Self::cuc_level1`].
+    pub fn cuc_level2() -> Self {
+        TimeCodeFormat::Cuc {
+            coarse_octets: 4,
+            fine_octets: 2,
+        }
+    }
+
+    /// CCSDS CDS "short" preset: 2 bytes of days since the epoch and 4 bytes
+    /// of milliseconds of day, with no trailing microseconds field.
+    pub fn cds_short() -> Self {
+        TimeCodeFormat::Cds {
+            include_microseconds: false,
+        }
+    }
+}
+
+/// Socket transport used by [`CcsdsPublisher`]/[`CcsdsSubscriber`] to
+/// exchange encoded CCSDS packets (selected via [`CcsdsConfig::transport`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CcsdsTransport {
+    /// One UDP datagram per packet (default). Simple and low-latency, but
+    /// drops under congestion and caps packet size.
+    #[default]
+    Udp,
+    /// A persistent TCP byte stream: the publisher listens and fans each
+    /// packet out to every connected subscriber; the subscriber frames
+    /// packets by reading the 6-byte primary header, then `data_len + 1`
+    /// more bytes (plus a CRC trailer when [`CcsdsConfig::enable_crc`] is
+    /// set).
+    Tcp,
+}
+
 /// Configuration for CCSDS transport
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CcsdsConfig {
     /// CCSDS mode (Telemetry or Telecommand)
     pub mode: CcsdsMode,
@@ -191,20 +392,58 @@ pub struct CcsdsConfig {
     /// Actual APID = base_apid + category
     pub base_apid: u16,
 
-    /// Whether to use secondary header
+    /// Whether to prepend a [`TimeCodeFormat`] secondary header to Telemetry
+    /// packets (see [`Self::time_code_format`])
     pub use_secondary_header: bool,
 
+    /// Time-code format written/parsed when `use_secondary_header` is set
+    /// Default: [`TimeCodeFormat::Cuc`] with 4 coarse + 2 fine octets
+    pub time_code_format: TimeCodeFormat,
+
+    /// Reference epoch used when `time_code_format`/`pus` encode a
+    /// [`TimeCodeFormat::Cuc`] time field (default: [`CucEpoch::Unix`]).
+    /// Ignored for [`TimeCodeFormat::Cds`], which is always Unix-relative.
+    pub cuc_epoch: CucEpoch,
+
     /// Maximum packet data length (default: 65536)
     pub max_packet_length: usize,
 
-    /// Enable CRC validation
+    /// Enable the CRC-16/CCITT-FALSE (CRC_16_IBM_3740) Packet Error Control
+    /// trailer: [`CcsdsPublisher::encode_packet`] appends it and
+    /// [`parse_ccsds_packet`] recomputes and verifies it, returning
+    /// [`CcsdsError::DecodeError`] on mismatch.
     pub enable_crc: bool,
 
     /// UDP port for publishing/subscribing (if using UDP transport)
     pub udp_port: Option<u16>,
 
-    /// Multicast address for publishing (if using multicast)
+    /// Multicast address for publishing (if using multicast). Also used as
+    /// the host a [`CcsdsTransport::Tcp`] subscriber connects to (falling
+    /// back to `127.0.0.1` when unset), mirroring its role as the UDP
+    /// publish destination.
     pub multicast_addr: Option<String>,
+
+    /// When set, wrap the ASTERIX payload in a PUS (ECSS-E-ST-70-41C)
+    /// telemetry/telecommand source packet instead of a plain data field.
+    /// Takes precedence over `use_secondary_header`/`time_code_format`,
+    /// since the PUS secondary header carries its own (TM-only) time field.
+    pub pus: Option<PusConfig>,
+
+    /// Socket transport used to exchange encoded packets (default: UDP)
+    pub transport: CcsdsTransport,
+
+    /// TCP port used when `transport` is [`CcsdsTransport::Tcp`]: the
+    /// publisher binds a listener here and streams every packet to each
+    /// connected client; the subscriber connects to it. Ignored for UDP.
+    pub tcp_port: Option<u16>,
+
+    /// Upper bound, in bytes, on a single per-APID segmented-packet
+    /// reassembly buffer (see the sequence-flags scheme documented on
+    /// [`CcsdsSubscriber::new`]). A sender that never emits a last segment
+    /// would otherwise grow that buffer without bound; once a buffer exceeds
+    /// this it's dropped and a warning logged, the same as an out-of-order
+    /// continuation segment. Default: 16 MiB.
+    pub max_reassembly_bytes: usize,
 }
 
 impl Default for CcsdsConfig {
@@ -213,10 +452,16 @@ impl Default for CcsdsConfig {
             mode: CcsdsMode::default(),
             base_apid: 0x300,
             use_secondary_header: false,
+            time_code_format: TimeCodeFormat::default(),
+            cuc_epoch: CucEpoch::default(),
             max_packet_length: 65536,
             enable_crc: false,
             udp_port: Some(7447), // Default CCSDS telemetry port
             multicast_addr: None,
+            pus: None,
+            transport: CcsdsTransport::default(),
+            tcp_port: None,
+            max_reassembly_bytes: MAX_REASSEMBLY_BYTES,
         }
     }
 }
@@ -254,10 +499,473 @@ impl CcsdsConfig {
             ..Default::default()
         }
     }
+
+    /// Create config that exchanges packets over a TCP stream instead of UDP
+    /// datagrams (see [`CcsdsTransport::Tcp`])
+    pub fn with_tcp(port: u16) -> Self {
+        Self {
+            transport: CcsdsTransport::Tcp,
+            tcp_port: Some(port),
+            ..Default::default()
+        }
+    }
+
+    /// Set the per-APID segmented-packet reassembly buffer cap (see
+    /// [`CcsdsConfig::max_reassembly_bytes`])
+    pub fn with_max_reassembly_bytes(mut self, max_reassembly_bytes: usize) -> Self {
+        self.max_reassembly_bytes = max_reassembly_bytes;
+        self
+    }
+
+    /// Create config that prepends a [`TimeCodeFormat::Cuc`] secondary
+    /// header with `coarse_octets` whole-second octets and `fine_octets`
+    /// sub-second octets (e.g. `with_cuc(4, 2)` for 4 coarse + 2 fine), using
+    /// [`CucEpoch::Ccsds`] (TAI, epoch 1958-01-01) as the reference epoch —
+    /// the epoch real space-link packets actually use, as opposed to
+    /// [`CucEpoch::Unix`] which only [`Default`] keeps for backward
+    /// compatibility with configs built before this epoch existed.
+    pub fn with_cuc(coarse_octets: u8, fine_octets: u8) -> Self {
+        Self {
+            use_secondary_header: true,
+            time_code_format: TimeCodeFormat::Cuc {
+                coarse_octets,
+                fine_octets,
+            },
+            cuc_epoch: CucEpoch::Ccsds,
+            ..Default::default()
+        }
+    }
+
+    /// Create config that prepends a [`TimeCodeFormat::Cds`] secondary
+    /// header (days since epoch plus milliseconds of day, see
+    /// [`TimeCodeFormat::cds_short`]).
+    pub fn with_cds(include_microseconds: bool) -> Self {
+        Self {
+            use_secondary_header: true,
+            time_code_format: TimeCodeFormat::Cds { include_microseconds },
+            ..Default::default()
+        }
+    }
+
+    /// Create config that wraps ASTERIX payloads as PUS source packets for
+    /// the given service/subservice
+    pub fn with_pus(service: u8, subservice: u8) -> Self {
+        Self {
+            pus: Some(PusConfig {
+                service,
+                subservice,
+                ..PusConfig::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// PUS (ECSS-E-ST-70-41C) secondary-header configuration, selected via
+/// [`CcsdsConfig::pus`]. The publisher writes a PUS-C TM or TC secondary
+/// header (depending on [`CcsdsConfig::mode`]) ahead of the ASTERIX payload,
+/// and a CCITT-FALSE CRC trailer as the standard mandates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PusConfig {
+    /// PUS service type (e.g. a mission-assigned service for ASTERIX
+    /// surveillance data transfer)
+    pub service: u8,
+    /// PUS service subtype, service-specific
+    pub subservice: u8,
+    /// TM secondary header "Destination ID" / TC secondary header
+    /// "Source ID" routing field
+    pub route_id: u16,
+    /// TC-only acknowledgement flags (ECSS-E-ST-70-41C §7.4.3.2.3, low 4
+    /// bits: bit 3 acceptance, bit 2 start, bit 1 progress, bit 0
+    /// completion). Unused for TM.
+    pub ack_flags: u8,
+}
+
+/// PUS (ECSS-E-ST-70-41C) secondary header decoded from a received packet,
+/// attached to [`CcsdsSample::pus_header`] when the packet carried one (see
+/// [`CcsdsConfig::pus`], [`CcsdsPublisher::publish_pus_tm`]/
+/// [`CcsdsPublisher::publish_pus_tc`]). TM/TC carry different fields beyond
+/// the shared service/subservice pair, so this mirrors that split rather
+/// than padding one mode's struct with the other's unused fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PusSecondaryHeader {
+    /// PUS-C TM secondary header fields; the time field itself is decoded
+    /// separately into [`CcsdsSample::time_code`]
+    Tm {
+        /// PUS service type
+        service_type: u8,
+        /// PUS service subtype
+        service_subtype: u8,
+        /// Per (service, subservice) message type counter (ECSS-E-ST-70-41C §6.11)
+        message_type_counter: u16,
+        /// TM secondary header "Destination ID" routing field
+        destination_id: u16,
+    },
+    /// PUS-C TC secondary header fields
+    Tc {
+        /// PUS service type
+        service_type: u8,
+        /// PUS service subtype
+        service_subtype: u8,
+        /// TC secondary header "Source ID" routing field
+        source_id: u16,
+    },
+}
+
+impl PusSecondaryHeader {
+    /// The PUS service type, common to both TM and TC headers
+    pub fn service_type(&self) -> u8 {
+        match self {
+            PusSecondaryHeader::Tm { service_type, .. } => *service_type,
+            PusSecondaryHeader::Tc { service_type, .. } => *service_type,
+        }
+    }
+
+    /// The PUS service subtype, common to both TM and TC headers
+    pub fn service_subtype(&self) -> u8 {
+        match self {
+            PusSecondaryHeader::Tm { service_subtype, .. } => *service_subtype,
+            PusSecondaryHeader::Tc { service_subtype, .. } => *service_subtype,
+        }
+    }
+
+    /// The TM "Destination ID" or TC "Source ID" routing field, whichever
+    /// this header carries — the same `route_id` an operator set via
+    /// [`PusConfig::route_id`]/[`CcsdsConfig::with_pus`] on the publishing
+    /// side, so a receiver can route CAT048 to one PUS service/destination
+    /// and CAT062 to another without matching on `Tm`/`Tc` itself.
+    pub fn source_or_dest_id(&self) -> u16 {
+        match self {
+            PusSecondaryHeader::Tm { destination_id, .. } => *destination_id,
+            PusSecondaryHeader::Tc { source_id, .. } => *source_id,
+        }
+    }
+}
+
+/// PUS-C version number (ECSS-E-ST-70-41C), written into the high nibble of
+/// every PUS secondary header's first byte
+const PUS_VERSION: u8 = 2;
+
+/// Encode a PUS (ECSS-E-ST-70-41C) secondary header for `mode`. TM headers
+/// embed a [`TimeCodeFormat`] time field; TC headers do not carry a time.
+fn encode_pus_header(
+    mode: CcsdsMode,
+    pus: PusConfig,
+    message_type_counter: u16,
+    time_code_format: TimeCodeFormat,
+    cuc_epoch: CucEpoch,
+) -> Vec<u8> {
+    match mode {
+        CcsdsMode::Telemetry => {
+            let mut out = Vec::with_capacity(7);
+            out.push(PUS_VERSION << 4); // spacecraft time reference status = 0
+            out.push(pus.service);
+            out.push(pus.subservice);
+            out.extend_from_slice(&message_type_counter.to_be_bytes());
+            out.extend_from_slice(&pus.route_id.to_be_bytes());
+            out.extend_from_slice(&encode_time_code(time_code_format, cuc_epoch));
+            out
+        }
+        CcsdsMode::Telecommand => {
+            let mut out = Vec::with_capacity(5);
+            out.push((PUS_VERSION << 4) | (pus.ack_flags & 0x0F));
+            out.push(pus.service);
+            out.push(pus.subservice);
+            out.extend_from_slice(&pus.route_id.to_be_bytes());
+            out
+        }
+    }
+}
+
+/// Parse a PUS secondary header from the front of `bytes`, returning the
+/// decoded header and the number of bytes consumed (including the time
+/// field, for TM).
+fn decode_pus_header(
+    mode: CcsdsMode,
+    time_code_format: TimeCodeFormat,
+    bytes: &[u8],
+) -> Result<(PusSecondaryHeader, usize), CcsdsError> {
+    match mode {
+        CcsdsMode::Telemetry => {
+            if bytes.len() < 7 {
+                return Err(CcsdsError::DecodeError(
+                    "PUS TM secondary header truncated".to_string(),
+                ));
+            }
+            let (_, time_len) = decode_time_code(time_code_format, &bytes[7..])?;
+            Ok((
+                PusSecondaryHeader::Tm {
+                    service_type: bytes[1],
+                    service_subtype: bytes[2],
+                    message_type_counter: u16::from_be_bytes([bytes[3], bytes[4]]),
+                    destination_id: u16::from_be_bytes([bytes[5], bytes[6]]),
+                },
+                7 + time_len,
+            ))
+        }
+        CcsdsMode::Telecommand => {
+            if bytes.len() < 5 {
+                return Err(CcsdsError::DecodeError(
+                    "PUS TC secondary header truncated".to_string(),
+                ));
+            }
+            Ok((
+                PusSecondaryHeader::Tc {
+                    service_type: bytes[1],
+                    service_subtype: bytes[2],
+                    source_id: u16::from_be_bytes([bytes[3], bytes[4]]),
+                },
+                5,
+            ))
+        }
+    }
+}
+
+/// Spacecraft time extracted from a CCSDS secondary header (see
+/// [`TimeCodeFormat`]), decoded but not converted to a Unix timestamp since
+/// the two CUC/CDS epochs and resolutions aren't directly comparable.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeCode {
+    /// Decoded CUC time: whole seconds since the epoch plus the sub-second
+    /// fraction (`0.0..1.0`) recovered from the fine-time octets
+    Cuc {
+        /// Whole seconds since the epoch
+        seconds: u64,
+        /// Sub-second fraction, `0.0..1.0`
+        subsec_fraction: f64,
+    },
+    /// Decoded CDS time: day, millisecond-of-day, and (if present)
+    /// microsecond-of-millisecond components
+    Cds {
+        /// Days since the epoch
+        days: u16,
+        /// Milliseconds since midnight on `days`
+        ms_of_day: u32,
+        /// Microseconds since `ms_of_day`, if the format included them
+        microseconds: Option<u16>,
+    },
+}
+
+impl TimeCode {
+    /// Convert this decoded time code to microseconds since the Unix epoch,
+    /// resolving [`Self::Cuc`] against `cuc_epoch` (the same value passed to
+    /// [`CcsdsConfig::cuc_epoch`] when the packet was encoded). [`Self::Cds`]
+    /// is always Unix-relative already, so `cuc_epoch` is ignored for it.
+    ///
+    /// Provided as an explicit, opt-in conversion: the raw decoded
+    /// representation remains the primary form (see this type's own doc
+    /// comment) since a CUC/CDS time code's native precision doesn't always
+    /// divide evenly into microseconds.
+    pub fn to_unix_micros(&self, cuc_epoch: CucEpoch) -> u64 {
+        match *self {
+            TimeCode::Cuc {
+                seconds,
+                subsec_fraction,
+            } => {
+                let unix_seconds = match cuc_epoch {
+                    CucEpoch::Unix => seconds,
+                    CucEpoch::Ccsds => seconds.saturating_sub(CCSDS_TO_UNIX_EPOCH_OFFSET_SECS),
+                };
+                let subsec_micros = (subsec_fraction * 1_000_000.0).round() as u64;
+                unix_seconds.saturating_mul(1_000_000).saturating_add(subsec_micros)
+            }
+            TimeCode::Cds {
+                days,
+                ms_of_day,
+                microseconds,
+            } => {
+                let day_micros = (days as u64).saturating_mul(86_400_000_000);
+                let ms_micros = (ms_of_day as u64).saturating_mul(1_000);
+                day_micros
+                    .saturating_add(ms_micros)
+                    .saturating_add(microseconds.unwrap_or(0) as u64)
+            }
+        }
+    }
+}
+
+/// Encode the current wall-clock time as a CCSDS secondary-header time code
+/// in `format`. See [`TimeCodeFormat`] for the on-wire layout. `cuc_epoch`
+/// selects the reference epoch for [`TimeCodeFormat::Cuc`] (see
+/// [`CucEpoch`]); it is ignored for [`TimeCodeFormat::Cds`], which is
+/// always Unix-relative.
+fn encode_time_code(format: TimeCodeFormat, cuc_epoch: CucEpoch) -> Vec<u8> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+
+    encode_time_code_for(format, cuc_epoch, now)
+}
+
+/// Same as [`encode_time_code`], but encoding `since_epoch` (e.g. an
+/// [`AsterixRecord`]'s own `timestamp_ms` acquisition time) instead of the
+/// current wall-clock time, so a record published well after it was
+/// acquired still carries its true acquisition time downlink.
+fn encode_time_code_for(
+    format: TimeCodeFormat,
+    cuc_epoch: CucEpoch,
+    since_epoch: std::time::Duration,
+) -> Vec<u8> {
+    let now = since_epoch;
+
+    match format {
+        TimeCodeFormat::Cuc {
+            coarse_octets,
+            fine_octets,
+        } => CucTime::from_unix(
+            coarse_octets,
+            fine_octets,
+            cuc_epoch,
+            now.as_secs(),
+            now.subsec_nanos(),
+        )
+        .encode(),
+        TimeCodeFormat::Cds {
+            include_microseconds,
+        } => {
+            let days = (now.as_secs() / 86400) as u16;
+            let ms_of_day =
+                ((now.as_secs() % 86400) * 1000) as u32 + now.subsec_nanos() / 1_000_000;
+
+            let mut out = Vec::with_capacity(if include_microseconds { 8 } else { 6 });
+            out.extend_from_slice(&days.to_be_bytes());
+            out.extend_from_slice(&ms_of_day.to_be_bytes());
+
+            if include_microseconds {
+                let microseconds = (now.subsec_nanos() / 1_000) % 1_000;
+                out.extend_from_slice(&(microseconds as u16).to_be_bytes());
+            }
+
+            out
+        }
+    }
+}
+
+/// Parse a time code from the front of `bytes` per `format`, returning the
+/// decoded [`TimeCode`] and the number of bytes consumed.
+fn decode_time_code(
+    format: TimeCodeFormat,
+    bytes: &[u8],
+) -> Result<(TimeCode, usize), CcsdsError> {
+    match format {
+        TimeCodeFormat::Cuc { .. } => {
+            let p_field = *bytes.first().ok_or_else(|| {
+                CcsdsError::DecodeError("secondary header truncated: missing P-field".to_string())
+            })?;
+            let coarse_octets = (p_field >> 4) as usize;
+            let fine_octets = (p_field & 0x0F) as usize;
+            let total = 1 + coarse_octets + fine_octets;
+
+            if bytes.len() < total {
+                return Err(CcsdsError::DecodeError(format!(
+                    "secondary header truncated: expected {total} bytes, got {}",
+                    bytes.len()
+                )));
+            }
+
+            let mut seconds_buf = [0u8; 8];
+            seconds_buf[8 - coarse_octets..].copy_from_slice(&bytes[1..1 + coarse_octets]);
+            let seconds = u64::from_be_bytes(seconds_buf);
+
+            let subsec_fraction = if fine_octets > 0 {
+                let mut frac_buf = [0u8; 8];
+                frac_buf[8 - fine_octets..].copy_from_slice(&bytes[1 + coarse_octets..total]);
+                u64::from_be_bytes(frac_buf) as f64 / 256f64.powi(fine_octets as i32)
+            } else {
+                0.0
+            };
+
+            Ok((
+                TimeCode::Cuc {
+                    seconds,
+                    subsec_fraction,
+                },
+                total,
+            ))
+        }
+        TimeCodeFormat::Cds {
+            include_microseconds,
+        } => {
+            let total = if include_microseconds { 8 } else { 6 };
+            if bytes.len() < total {
+                return Err(CcsdsError::DecodeError(format!(
+                    "secondary header truncated: expected {total} bytes, got {}",
+                    bytes.len()
+                )));
+            }
+
+            let days = u16::from_be_bytes([bytes[0], bytes[1]]);
+            let ms_of_day = u32::from_be_bytes([bytes[2], bytes[3], bytes[4], bytes[5]]);
+            let microseconds =
+                include_microseconds.then(|| u16::from_be_bytes([bytes[6], bytes[7]]));
+
+            Ok((
+                TimeCode::Cds {
+                    days,
+                    ms_of_day,
+                    microseconds,
+                },
+                total,
+            ))
+        }
+    }
+}
+
+/// Strip and decode the optional secondary header from the front of
+/// `payload`, dispatching on which kind (if any) the publisher's config
+/// would have written: a PUS (ECSS-E-ST-70-41C) header when `pus` is set, a
+/// plain [`TimeCodeFormat`] time code when `use_secondary_header` is set, or
+/// neither. Returns the decoded time code / PUS header (`None` when not
+/// applicable) alongside the remaining ASTERIX bytes.
+#[allow(clippy::too_many_arguments)]
+fn decode_secondary_header(
+    use_secondary_header: bool,
+    pus: Option<PusConfig>,
+    mode: CcsdsMode,
+    time_code_format: TimeCodeFormat,
+    has_secondary_header_flag: bool,
+    payload: Vec<u8>,
+) -> (Option<TimeCode>, Option<PusSecondaryHeader>, Vec<u8>) {
+    if !has_secondary_header_flag {
+        return (None, None, payload);
+    }
+
+    if pus.is_some() {
+        match decode_pus_header(mode, time_code_format, &payload) {
+            Ok((header, consumed)) => (None, Some(header), payload[consumed..].to_vec()),
+            Err(e) => {
+                log::warn!("Failed to parse PUS secondary header: {e}");
+                (None, None, payload)
+            }
+        }
+    } else if use_secondary_header {
+        match decode_time_code(time_code_format, &payload) {
+            Ok((time_code, consumed)) => {
+                (Some(time_code), None, payload[consumed..].to_vec())
+            }
+            Err(e) => {
+                log::warn!("Failed to parse CCSDS secondary header: {e}");
+                (None, None, payload)
+            }
+        }
+    } else {
+        (None, None, payload)
+    }
 }
 
+/// CCSDS sequence flags (CCSDS 133.0-B-2 §4.1.3.4), identifying whether a
+/// packet stands alone or is one segment of a fragmented ASTERIX block.
+const SEQ_FLAGS_CONTINUATION: u8 = 0b00;
+const SEQ_FLAGS_FIRST: u8 = 0b01;
+const SEQ_FLAGS_LAST: u8 = 0b10;
+const SEQ_FLAGS_UNSEGMENTED: u8 = 0b11;
+
 /// Received ASTERIX sample from CCSDS packet
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CcsdsSample {
     /// ASTERIX category
     pub category: u8,
@@ -271,83 +979,401 @@ pub struct CcsdsSample {
     pub timestamp: u64,
     /// CCSDS packet type
     pub packet_type: CcsdsMode,
+    /// Spacecraft time decoded from the packet's secondary header, if the
+    /// secondary-header flag was set and [`CcsdsConfig::use_secondary_header`]
+    /// was enabled on the receiving subscriber
+    pub time_code: Option<TimeCode>,
+    /// PUS (ECSS-E-ST-70-41C) secondary header, if the packet carried one
+    /// (see [`CcsdsConfig::pus`], [`CcsdsPublisher::publish_pus_tm`]/
+    /// [`CcsdsPublisher::publish_pus_tc`])
+    pub pus_header: Option<PusSecondaryHeader>,
+}
+
+#[cfg(feature = "serde")]
+impl CcsdsSample {
+    /// Encode this sample as compact [postcard](https://docs.rs/postcard) binary,
+    /// cheaper to store or relay across process boundaries than
+    /// [`serialize_record`]'s JSON/hex forms — e.g. an archiving task that
+    /// buffers every arriving telemetry sample for later downlink, as
+    /// sat-rs describes for satellites without a permanent ground contact.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CcsdsError::SerializationError`] if `postcard` can't encode
+    /// the sample (this shouldn't happen for a well-formed `CcsdsSample`).
+    pub fn to_postcard(&self) -> Result<Vec<u8>, CcsdsError> {
+        postcard::to_allocvec(self).map_err(|e| CcsdsError::SerializationError(e.to_string()))
+    }
+
+    /// Decode a sample previously written by [`Self::to_postcard`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CcsdsError::DecodeError`] if `bytes` is not a valid
+    /// postcard encoding of a `CcsdsSample`.
+    pub fn from_postcard(bytes: &[u8]) -> Result<Self, CcsdsError> {
+        postcard::from_bytes(bytes).map_err(|e| CcsdsError::DecodeError(e.to_string()))
+    }
+}
+
+/// Bound socket transport for [`CcsdsPublisher`], populated according to
+/// [`CcsdsConfig::transport`]
+enum PublisherSocket {
+    /// A UDP socket used to send every published packet to
+    /// `config.multicast_addr` (or `127.0.0.1`) on `config.udp_port`
+    Udp(tokio::net::UdpSocket),
+    /// A TCP listener's accepted client connections, written to on every
+    /// publish; disconnected clients are pruned on the next send
+    Tcp {
+        clients: Arc<tokio::sync::Mutex<Vec<tokio::net::TcpStream>>>,
+        local_addr: std::net::SocketAddr,
+    },
 }
 
 /// CCSDS publisher for ASTERIX data
 pub struct CcsdsPublisher {
     config: CcsdsConfig,
     sequence_counters: Arc<tokio::sync::Mutex<HashMap<u16, u16>>>,
+    /// Per (service, subservice) PUS message type counter (see
+    /// [`CcsdsConfig::pus`])
+    pus_counters: Arc<tokio::sync::Mutex<HashMap<(u8, u8), u16>>>,
+    /// Bound only when `config.udp_port`/`config.tcp_port` is set (matching
+    /// `config.transport`); `None` keeps the log-only behavior this module
+    /// had before network transport existed.
+    socket: Option<PublisherSocket>,
 }
 
 impl CcsdsPublisher {
     /// Create a new CCSDS publisher
+    ///
+    /// For [`CcsdsTransport::Udp`] (the default), if `config.udp_port` is
+    /// set, binds an ephemeral local UDP socket used to send every
+    /// published packet to `config.multicast_addr` (or `127.0.0.1` if
+    /// unset) on that port. For [`CcsdsTransport::Tcp`], if
+    /// `config.tcp_port` is set, binds a TCP listener on that port and
+    /// fans every published packet out to each connected client. Leaving
+    /// the relevant port unset keeps `publish`/`publish_raw` as a log-only,
+    /// no-network-I/O no-op.
     pub async fn new(config: CcsdsConfig) -> Result<Self, CcsdsError> {
+        let socket = match config.transport {
+            CcsdsTransport::Udp => match config.udp_port {
+                Some(_) => {
+                    let socket = tokio::net::UdpSocket::bind("0.0.0.0:0")
+                        .await
+                        .map_err(|e| CcsdsError::NetworkError(format!("bind failed: {e}")))?;
+
+                    if config.multicast_addr.is_some() {
+                        socket.set_multicast_loop_v4(true).map_err(|e| {
+                            CcsdsError::NetworkError(format!(
+                                "failed to enable multicast loopback: {e}"
+                            ))
+                        })?;
+                    }
+
+                    Some(PublisherSocket::Udp(socket))
+                }
+                None => None,
+            },
+            CcsdsTransport::Tcp => match config.tcp_port {
+                Some(port) => {
+                    let listener = tokio::net::TcpListener::bind(("0.0.0.0", port))
+                        .await
+                        .map_err(|e| CcsdsError::NetworkError(format!("bind failed: {e}")))?;
+                    let local_addr = listener.local_addr().map_err(|e| {
+                        CcsdsError::NetworkError(format!("failed to read local addr: {e}"))
+                    })?;
+
+                    let clients: Arc<tokio::sync::Mutex<Vec<tokio::net::TcpStream>>> =
+                        Arc::new(tokio::sync::Mutex::new(Vec::new()));
+                    let accepted_clients = clients.clone();
+                    tokio::spawn(async move {
+                        loop {
+                            match listener.accept().await {
+                                Ok((stream, _addr)) => {
+                                    accepted_clients.lock().await.push(stream);
+                                }
+                                Err(e) => log::warn!("CCSDS TCP accept error: {e}"),
+                            }
+                        }
+                    });
+
+                    Some(PublisherSocket::Tcp {
+                        clients,
+                        local_addr,
+                    })
+                }
+                None => None,
+            },
+        };
+
         Ok(Self {
             config,
             sequence_counters: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
+            pus_counters: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
+            socket,
         })
     }
 
+    /// The TCP listener's bound local address when `config.transport` is
+    /// [`CcsdsTransport::Tcp`] and `config.tcp_port` is set — useful to
+    /// discover which port the OS assigned when `tcp_port` is `0`. Returns
+    /// `None` for UDP transport or when no port is configured.
+    pub fn tcp_local_addr(&self) -> Option<std::net::SocketAddr> {
+        match &self.socket {
+            Some(PublisherSocket::Tcp { local_addr, .. }) => Some(*local_addr),
+            _ => None,
+        }
+    }
+
+    /// Send an already-encoded CCSDS packet to `config.multicast_addr`
+    /// (falling back to `127.0.0.1` for plain unicast) on `config.udp_port`,
+    /// or just log it if no `udp_port` is configured.
+    async fn send_packet(
+        &self,
+        apid: u16,
+        sequence_count: u16,
+        packet: &[u8],
+    ) -> Result<(), CcsdsError> {
+        match (&self.socket, self.config.udp_port) {
+            (Some(PublisherSocket::Udp(socket)), Some(port)) => {
+                let dest_addr = self
+                    .config
+                    .multicast_addr
+                    .as_deref()
+                    .unwrap_or("127.0.0.1");
+
+                socket
+                    .send_to(packet, (dest_addr, port))
+                    .await
+                    .map_err(|e| CcsdsError::NetworkError(format!("send failed: {e}")))?;
+
+                log::debug!(
+                    "Sent CCSDS packet to {dest_addr}:{port}: APID=0x{apid:03X}, \
+                     seq={sequence_count}, len={} bytes",
+                    packet.len()
+                );
+            }
+            (Some(PublisherSocket::Tcp { clients, .. }), _) => {
+                let mut clients = clients.lock().await;
+                let mut still_connected = Vec::with_capacity(clients.len());
+                for mut stream in clients.drain(..) {
+                    match stream.write_all(packet).await {
+                        Ok(()) => still_connected.push(stream),
+                        Err(e) => log::warn!("CCSDS TCP client disconnected: {e}"),
+                    }
+                }
+                let client_count = still_connected.len();
+                *clients = still_connected;
+
+                log::debug!(
+                    "Sent CCSDS packet to {client_count} TCP client(s): APID=0x{apid:03X}, \
+                     seq={sequence_count}, len={} bytes",
+                    packet.len()
+                );
+            }
+            _ => {
+                log::debug!(
+                    "Created CCSDS packet: APID=0x{apid:03X}, seq={sequence_count}, \
+                     len={} bytes (no transport port configured, not transmitted)",
+                    packet.len()
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     /// Publish an ASTERIX record as CCSDS packet
+    ///
+    /// When `config.use_secondary_header` is set (and `config.pus` is not),
+    /// the secondary header's CUC/CDS time field carries `record.timestamp_ms`
+    /// — the record's own acquisition time — rather than the current
+    /// wall-clock time, so a record published well after it was acquired
+    /// (e.g. replayed from a recording) still downlinks its true timestamp.
     pub async fn publish(&self, record: &AsterixRecord) -> Result<(), CcsdsError> {
-        // Calculate APID from category
         let apid = self.calculate_apid(record.category);
+        let asterix_data = self.serialize_record(record)?;
 
-        // Get next sequence count for this APID
-        let sequence_count = self.next_sequence_count(apid).await;
+        self.publish_payload(
+            self.config.mode,
+            apid,
+            self.config.pus,
+            &asterix_data,
+            Some(record.timestamp_ms),
+        )
+        .await
+    }
 
-        // Serialize ASTERIX data
-        let asterix_data = self.serialize_record(record)?;
+    /// Publish raw ASTERIX bytes
+    pub async fn publish_raw(&self, category: u8, data: &[u8]) -> Result<(), CcsdsError> {
+        let apid = self.calculate_apid(category);
 
-        // Create CCSDS packet header based on mode
-        // Note: data_len field in CCSDS is (actual_length - 1), but SpHeader handles this
-        let data_len = if asterix_data.is_empty() {
-            0
-        } else {
-            (asterix_data.len() - 1) as u16
+        self.publish_payload(self.config.mode, apid, self.config.pus, data, None)
+            .await
+    }
+
+    /// Publish `data` as ASTERIX category `category` wrapped in a PUS-C TM
+    /// (telemetry) source packet for the given `service`/`subservice`,
+    /// regardless of `config.mode`/`config.pus` — unlike [`Self::publish`]/
+    /// [`Self::publish_raw`], which only apply PUS wrapping when
+    /// `config.pus` is set. The message type counter and time field are
+    /// populated the same way as a PUS-configured publisher; `route_id`/
+    /// `ack_flags` are left at their defaults (use [`CcsdsConfig::with_pus`]
+    /// if those need to be non-zero).
+    pub async fn publish_pus_tm(
+        &self,
+        category: u8,
+        service: u8,
+        subservice: u8,
+        data: &[u8],
+    ) -> Result<(), CcsdsError> {
+        let apid = self.calculate_apid(category);
+        let pus = PusConfig {
+            service,
+            subservice,
+            ..PusConfig::default()
         };
+        self.publish_payload(CcsdsMode::Telemetry, apid, Some(pus), data, None)
+            .await
+    }
 
-        let sp_header = match self.config.mode {
-            CcsdsMode::Telemetry => {
-                SpHeader::new_for_unseg_tm(u11::new(apid), u14::new(sequence_count), data_len)
-            }
-            CcsdsMode::Telecommand => {
-                SpHeader::new_for_unseg_tc(u11::new(apid), u14::new(sequence_count), data_len)
+    /// Publish `data` as ASTERIX category `category` wrapped in a PUS-C TC
+    /// (telecommand) source packet for the given `service`/`subservice`; see
+    /// [`Self::publish_pus_tm`] for the telemetry equivalent.
+    pub async fn publish_pus_tc(
+        &self,
+        category: u8,
+        service: u8,
+        subservice: u8,
+        data: &[u8],
+    ) -> Result<(), CcsdsError> {
+        let apid = self.calculate_apid(category);
+        let pus = PusConfig {
+            service,
+            subservice,
+            ..PusConfig::default()
+        };
+        self.publish_payload(CcsdsMode::Telecommand, apid, Some(pus), data, None)
+            .await
+    }
+
+    /// Send `asterix_data` (prefixed with a PUS secondary header when `pus`
+    /// is set, or a plain [`TimeCodeFormat`] secondary header when
+    /// `config.use_secondary_header` is set) to `apid` as `mode` packets,
+    /// splitting it across multiple CCSDS packets when it doesn't fit
+    /// within `config.max_packet_length`.
+    ///
+    /// Single packets carry sequence flags `11` (unsegmented). A
+    /// multi-packet block carries `01` on the first fragment, `00` on
+    /// continuation fragments, and `10` on the last, with the sequence
+    /// count incrementing once per fragment so the subscriber can detect
+    /// gaps.
+    ///
+    /// `record_timestamp_ms` is the acquisition time (milliseconds since
+    /// Unix epoch) to encode into the secondary header's time field, if
+    /// one is written; `None` falls back to the current wall-clock time
+    /// (used by every caller except [`Self::publish`], which has an actual
+    /// [`AsterixRecord::timestamp_ms`] to carry).
+    async fn publish_payload(
+        &self,
+        mode: CcsdsMode,
+        apid: u16,
+        pus: Option<PusConfig>,
+        asterix_data: &[u8],
+        record_timestamp_ms: Option<u64>,
+    ) -> Result<(), CcsdsError> {
+        let mut payload = if let Some(pus) = pus {
+            let message_type_counter = self.next_pus_counter(pus.service, pus.subservice).await;
+            encode_pus_header(
+                mode,
+                pus,
+                message_type_counter,
+                self.config.time_code_format,
+                self.config.cuc_epoch,
+            )
+        } else if self.config.use_secondary_header {
+            match record_timestamp_ms {
+                Some(ms) => encode_time_code_for(
+                    self.config.time_code_format,
+                    self.config.cuc_epoch,
+                    std::time::Duration::from_millis(ms),
+                ),
+                None => encode_time_code(self.config.time_code_format, self.config.cuc_epoch),
             }
+        } else {
+            Vec::new()
         };
+        payload.extend_from_slice(asterix_data);
 
-        // Encode CCSDS packet (6 bytes header + data)
-        let mut packet = vec![0u8; 6 + asterix_data.len()];
-        sp_header
-            .write_to_be_bytes(&mut packet[..6])
-            .map_err(|e| CcsdsError::EncodeError(format!("{e:?}")))?;
-        packet[6..].copy_from_slice(&asterix_data);
+        let has_secondary_header = pus.is_some() || self.config.use_secondary_header;
 
-        // TODO: Add UDP/multicast publishing
-        // For now, just log the packet creation
-        log::debug!(
-            "Created CCSDS packet: APID=0x{:03X}, seq={}, len={} bytes",
-            apid,
-            sequence_count,
-            packet.len()
-        );
+        // At least 1 byte of data fits in every packet, however small max_packet_length is.
+        let max_payload_len = self.config.max_packet_length.saturating_sub(6).max(1);
+
+        if payload.len() <= max_payload_len {
+            let sequence_count = self.next_sequence_count(apid).await;
+            let packet = self.encode_packet(
+                mode,
+                apid,
+                sequence_count,
+                &payload,
+                SEQ_FLAGS_UNSEGMENTED,
+                has_secondary_header,
+            )?;
+            return self.send_packet(apid, sequence_count, &packet).await;
+        }
+
+        let chunks: Vec<&[u8]> = payload.chunks(max_payload_len).collect();
+        let last_index = chunks.len() - 1;
+
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            let seq_flags = match index {
+                0 => SEQ_FLAGS_FIRST,
+                i if i == last_index => SEQ_FLAGS_LAST,
+                _ => SEQ_FLAGS_CONTINUATION,
+            };
+            let sequence_count = self.next_sequence_count(apid).await;
+            let packet = self.encode_packet(
+                mode,
+                apid,
+                sequence_count,
+                chunk,
+                seq_flags,
+                // The secondary header (if any) only ever lives in the first fragment.
+                has_secondary_header && index == 0,
+            )?;
+            self.send_packet(apid, sequence_count, &packet).await?;
+        }
 
         Ok(())
     }
 
-    /// Publish raw ASTERIX bytes
-    pub async fn publish_raw(&self, category: u8, data: &[u8]) -> Result<(), CcsdsError> {
-        let apid = self.calculate_apid(category);
-        let sequence_count = self.next_sequence_count(apid).await;
+    /// Encode a single CCSDS packet (6-byte primary header + `payload` +
+    /// optional CRC-16 trailer) for the given mode/APID/sequence count/
+    /// sequence flags, setting the secondary-header flag when
+    /// `has_secondary_header` is set. Appends a CRC-16/CCITT-FALSE Packet
+    /// Error Control field over the header and data when `config.enable_crc`
+    /// is set.
+    fn encode_packet(
+        &self,
+        mode: CcsdsMode,
+        apid: u16,
+        sequence_count: u16,
+        payload: &[u8],
+        seq_flags: u8,
+        has_secondary_header: bool,
+    ) -> Result<Vec<u8>, CcsdsError> {
+        let trailer_len = if self.config.enable_crc { 2 } else { 0 };
+        let total_len = payload.len() + trailer_len;
 
-        // Create CCSDS packet header based on mode
-        let data_len = if data.is_empty() {
+        // Note: data_len field in CCSDS is (actual_length - 1), but SpHeader handles this
+        let data_len = if total_len == 0 {
             0
         } else {
-            (data.len() - 1) as u16
+            (total_len - 1) as u16
         };
 
-        let sp_header = match self.config.mode {
+        let sp_header = match mode {
             CcsdsMode::Telemetry => {
                 SpHeader::new_for_unseg_tm(u11::new(apid), u14::new(sequence_count), data_len)
             }
@@ -356,21 +1382,26 @@ impl CcsdsPublisher {
             }
         };
 
-        let mut packet = vec![0u8; 6 + data.len()];
+        let mut packet = vec![0u8; 6 + total_len];
         sp_header
             .write_to_be_bytes(&mut packet[..6])
             .map_err(|e| CcsdsError::EncodeError(format!("{e:?}")))?;
-        packet[6..].copy_from_slice(data);
 
-        log::debug!(
-            "Created CCSDS packet: CAT={}, APID=0x{:03X}, seq={}, len={} bytes",
-            category,
-            apid,
-            sequence_count,
-            packet.len()
-        );
+        // `new_for_unseg_*` always writes sequence flags `11`; override for fragments.
+        packet[2] = (packet[2] & 0x3F) | (seq_flags << 6);
 
-        Ok(())
+        if has_secondary_header {
+            packet[0] |= 0x08; // secondary header flag is bit 3 of the first octet
+        }
+
+        packet[6..6 + payload.len()].copy_from_slice(payload);
+
+        if self.config.enable_crc {
+            let crc = crc16_ccitt_false(&packet[..6 + payload.len()]);
+            packet[6 + payload.len()..].copy_from_slice(&crc.to_be_bytes());
+        }
+
+        Ok(packet)
     }
 
     fn calculate_apid(&self, category: u8) -> u16 {
@@ -385,6 +1416,16 @@ impl CcsdsPublisher {
         current
     }
 
+    /// Per (service, subservice) PUS message type counter (ECSS-E-ST-70-41C
+    /// §6.11), independent of the CCSDS primary-header sequence count.
+    async fn next_pus_counter(&self, service: u8, subservice: u8) -> u16 {
+        let mut counters = self.pus_counters.lock().await;
+        let count = counters.entry((service, subservice)).or_insert(0);
+        let current = *count;
+        *count = count.wrapping_add(1);
+        current
+    }
+
     fn serialize_record(&self, record: &AsterixRecord) -> Result<Vec<u8>, CcsdsError> {
         // If raw bytes available (hex_data), decode and use that
         if !record.hex_data.is_empty() {
@@ -405,54 +1446,687 @@ impl CcsdsPublisher {
     }
 
     fn hex_to_bytes(&self, hex: &str) -> Result<Vec<u8>, CcsdsError> {
-        let hex_clean: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
+        from_hex(hex).map_err(|e| CcsdsError::SerializationError(e.to_string()))
+    }
+}
 
-        if !hex_clean.len().is_multiple_of(2) {
-            return Err(CcsdsError::SerializationError(
-                "Invalid hex string length".to_string(),
-            ));
+// ============================================================================
+// Length-Prefixed Framing
+// ============================================================================
+//
+// A framing format independent of CCSDS's own (APID-routed, secondary-header-
+// aware) packet structure, for relaying a `CcsdsSample` across a plain pipe
+// or proxy connection — e.g. wired into `tokio::io::copy_bidirectional` to
+// tunnel CCSDS traffic through an intermediary that doesn't understand CCSDS
+// at all.
+
+/// Sentinel prefix bytes for the frame length prefix (see [`encode_frame_prefix`]):
+/// values `0..=251` in the first byte encode the frame length directly; these
+/// three sentinels mean a 2/4/8-byte big-endian length follows instead.
+const FRAME_LEN_PREFIX_U16: u8 = 252;
+const FRAME_LEN_PREFIX_U32: u8 = 253;
+const FRAME_LEN_PREFIX_U64: u8 = 254;
+
+/// Encode `len` as a compact variable-length size prefix: a single byte for
+/// `0..=251`, or one of the [`FRAME_LEN_PREFIX_U16`]/`_U32`/`_U64` sentinel
+/// bytes followed by a big-endian `u16`/`u32`/`u64` for anything larger.
+fn encode_frame_prefix(len: usize) -> Vec<u8> {
+    if let Ok(len) = u8::try_from(len) {
+        if len <= 251 {
+            return vec![len];
         }
+    }
 
-        (0..hex_clean.len())
-            .step_by(2)
-            .map(|i| {
-                u8::from_str_radix(&hex_clean[i..i + 2], 16)
-                    .map_err(|e| CcsdsError::SerializationError(e.to_string()))
-            })
-            .collect()
+    if let Ok(len) = u16::try_from(len) {
+        let mut out = vec![FRAME_LEN_PREFIX_U16];
+        out.extend_from_slice(&len.to_be_bytes());
+        out
+    } else if let Ok(len) = u32::try_from(len) {
+        let mut out = vec![FRAME_LEN_PREFIX_U32];
+        out.extend_from_slice(&len.to_be_bytes());
+        out
+    } else {
+        let mut out = vec![FRAME_LEN_PREFIX_U64];
+        out.extend_from_slice(&(len as u64).to_be_bytes());
+        out
     }
 }
 
-/// CCSDS subscriber for ASTERIX data
-pub struct CcsdsSubscriber {
-    #[allow(dead_code)]
-    config: CcsdsConfig,
-    receiver: tokio::sync::mpsc::Receiver<CcsdsSample>,
-    _handle: tokio::task::JoinHandle<()>,
+/// Try to read a frame length prefix from the front of `buf`. Returns
+/// `Some((prefix_len, frame_len))` — the prefix's own byte width and the
+/// frame body length it encodes — once enough bytes to decode the prefix
+/// are available, or `None` if `buf` doesn't yet hold the full prefix.
+///
+/// # Errors
+///
+/// Returns [`CcsdsError::DecodeError`] for the unassigned `255` prefix byte.
+fn decode_frame_prefix(buf: &[u8]) -> Result<Option<(usize, usize)>, CcsdsError> {
+    let Some(&first) = buf.first() else {
+        return Ok(None);
+    };
+
+    match first {
+        0..=251 => Ok(Some((1, first as usize))),
+        FRAME_LEN_PREFIX_U16 => Ok(buf
+            .get(1..3)
+            .map(|b| (3, u16::from_be_bytes([b[0], b[1]]) as usize))),
+        FRAME_LEN_PREFIX_U32 => Ok(buf
+            .get(1..5)
+            .map(|b| (5, u32::from_be_bytes(b.try_into().unwrap()) as usize))),
+        FRAME_LEN_PREFIX_U64 => Ok(buf
+            .get(1..9)
+            .map(|b| (9, u64::from_be_bytes(b.try_into().unwrap()) as usize))),
+        255 => Err(CcsdsError::DecodeError(
+            "unassigned frame length prefix byte 255".to_string(),
+        )),
+    }
 }
 
-impl CcsdsSubscriber {
-    /// Create a new CCSDS subscriber
-    pub async fn new(config: CcsdsConfig) -> Result<Self, CcsdsError> {
-        let (_tx, rx) = tokio::sync::mpsc::channel(1000);
+#[cfg(feature = "serde")]
+impl CcsdsPublisher {
+    /// Write `sample` to `writer` as a length-prefixed frame (frame length
+    /// prefix from [`encode_frame_prefix`] followed by its
+    /// [`CcsdsSample::to_postcard`] encoding), independent of CCSDS's own
+    /// packet framing. Pairs with [`FramedCcsdsReader`] on the reading end —
+    /// together they let a [`CcsdsSample`] be relayed across an arbitrary
+    /// pipe or proxy connection, e.g. via `tokio::io::copy_bidirectional`.
+    ///
+    /// This is an associated function rather than a `&self` method: framing
+    /// a sample needs no publisher state, only the sample and a writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CcsdsError::SerializationError`] if `sample` can't be
+    /// postcard-encoded, or [`CcsdsError::NetworkError`] if the write to
+    /// `writer` fails.
+    pub async fn write_framed<W: AsyncWrite + Unpin>(
+        writer: &mut W,
+        sample: &CcsdsSample,
+    ) -> Result<(), CcsdsError> {
+        let body = sample.to_postcard()?;
+        let mut frame = encode_frame_prefix(body.len());
+        frame.extend_from_slice(&body);
+        writer
+            .write_all(&frame)
+            .await
+            .map_err(|e| CcsdsError::NetworkError(format!("framed write failed: {e}")))
+    }
+}
 
-        // Spawn background task to receive CCSDS packets
-        let handle = tokio::spawn(async move {
-            // TODO: Implement UDP/multicast receiver
-            // For now, just a placeholder
-            log::info!("CCSDS subscriber started (placeholder)");
-            loop {
-                tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+/// Streaming decoder for the length-prefixed frames written by
+/// [`CcsdsPublisher::write_framed`]: reads the frame length prefix, then
+/// waits for exactly that many payload bytes, decoding each complete frame
+/// as a [`CcsdsSample`]. Like [`CcsdsDecoder`], partial reads (including
+/// ones that split the prefix itself) are buffered internally and resumed
+/// on the next poll rather than losing data.
+#[cfg(feature = "serde")]
+pub struct FramedCcsdsReader<R> {
+    reader: R,
+    buf: Vec<u8>,
+    eof: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<R> FramedCcsdsReader<R> {
+    /// Wrap `reader` (a TCP socket, pipe, or any other [`AsyncRead`]) in a
+    /// frame decoder.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            eof: false,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<R: AsyncRead + Unpin> Stream for FramedCcsdsReader<R> {
+    type Item = Result<CcsdsSample, CcsdsError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match decode_frame_prefix(&this.buf) {
+                Ok(Some((prefix_len, body_len))) => {
+                    let total = prefix_len + body_len;
+                    if this.buf.len() >= total {
+                        let frame: Vec<u8> = this.buf.drain(..total).collect();
+                        return Poll::Ready(Some(CcsdsSample::from_postcard(
+                            &frame[prefix_len..],
+                        )));
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    this.buf.clear();
+                    return Poll::Ready(Some(Err(e)));
+                }
             }
-        });
+
+            if this.eof {
+                return if this.buf.is_empty() {
+                    Poll::Ready(None)
+                } else {
+                    let remainder = this.buf.len();
+                    this.buf.clear();
+                    Poll::Ready(Some(Err(CcsdsError::DecodeError(format!(
+                        "framed stream ended mid-frame with {remainder} buffered byte(s)"
+                    )))))
+                };
+            }
+
+            let mut chunk = [0u8; 4096];
+            let mut read_buf = ReadBuf::new(&mut chunk);
+            match Pin::new(&mut this.reader).poll_read(cx, &mut read_buf) {
+                Poll::Ready(Ok(())) => {
+                    let filled = read_buf.filled().len();
+                    if filled == 0 {
+                        this.eof = true;
+                    } else {
+                        this.buf.extend_from_slice(read_buf.filled());
+                    }
+                }
+                Poll::Ready(Err(e)) => {
+                    return Poll::Ready(Some(Err(CcsdsError::NetworkError(format!(
+                        "framed stream read error: {e}"
+                    )))));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Upper bound on a single in-progress reassembly buffer, guarding the
+/// subscriber against unbounded memory growth from a runaway or malicious
+/// fragment stream.
+const MAX_REASSEMBLY_BYTES: usize = 16 * 1024 * 1024;
+
+/// In-progress reassembly state for a fragmented ASTERIX block, keyed by
+/// APID. Accumulates continuation segments until the last segment arrives.
+struct ReassemblyBuffer {
+    /// Sequence count the next fragment for this APID must carry
+    next_sequence_count: u16,
+    /// Time code decoded from the first fragment's secondary header, if any
+    time_code: Option<TimeCode>,
+    /// PUS header decoded from the first fragment's secondary header, if any
+    pus_header: Option<PusSecondaryHeader>,
+    /// ASTERIX bytes accumulated so far
+    data: Vec<u8>,
+}
+
+/// Per-subscriber parameters needed to decode a raw CCSDS packet into a
+/// [`CcsdsSample`], shared by the UDP and TCP receive loops.
+#[derive(Clone, Copy)]
+struct SubscriberDecodeCtx {
+    base_apid: u16,
+    packet_type: CcsdsMode,
+    use_secondary_header: bool,
+    pus: Option<PusConfig>,
+    time_code_format: TimeCodeFormat,
+    enable_crc: bool,
+    max_reassembly_bytes: usize,
+}
+
+/// A non-fatal discontinuity noticed while ingesting CCSDS packets, for
+/// downstream ASTERIX decoders that want to flag gaps in the data rather
+/// than silently processing around them. See [`SubscriberStats`] for the
+/// cumulative counters these correspond to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CcsdsEvent {
+    /// The packet sequence count for `apid` jumped past the expected next
+    /// value, implying `missed` packets were lost in between.
+    Gap {
+        /// APID the gap was observed on
+        apid: u16,
+        /// Sequence count that should have come next
+        expected: u16,
+        /// Sequence count actually received
+        got: u16,
+        /// Number of packets implied missing between `expected` and `got`
+        missed: u16,
+    },
+}
+
+/// Packet counters for a single APID within a [`SubscriberStats`] snapshot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApidStats {
+    /// Packets received on this APID
+    pub received: u64,
+    /// Packets inferred missing from sequence-count gaps
+    pub missed: u64,
+    /// Packets whose sequence count fell behind the expected value (a
+    /// duplicate or reordered delivery rather than a forward gap)
+    pub out_of_order: u64,
+}
+
+/// Cumulative packet-reception statistics for a [`CcsdsSubscriber`], built
+/// from per-APID sequence-count gap detection (see [`CcsdsEvent::Gap`]).
+/// Snapshot via [`CcsdsSubscriber::stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscriberStats {
+    /// Total packets received across all APIDs
+    pub received: u64,
+    /// Total packets inferred missing from sequence-count gaps
+    pub missed: u64,
+    /// Total packets whose sequence count fell behind the expected value
+    pub out_of_order: u64,
+    /// Per-APID breakdown of the same three counters
+    pub per_apid: HashMap<u16, ApidStats>,
+}
+
+impl SubscriberStats {
+    /// Fold the outcome of one ingested packet into the running totals.
+    /// `out_of_order` and `gap_event` are mutually exclusive outcomes of the
+    /// same [`record_sequence`] call.
+    fn record(&mut self, apid: u16, gap_event: Option<CcsdsEvent>, out_of_order: bool) {
+        self.received += 1;
+        let apid_stats = self.per_apid.entry(apid).or_default();
+        apid_stats.received += 1;
+
+        if let Some(CcsdsEvent::Gap { missed, .. }) = gap_event {
+            self.missed += missed as u64;
+            apid_stats.missed += missed as u64;
+        }
+        if out_of_order {
+            self.out_of_order += 1;
+            apid_stats.out_of_order += 1;
+        }
+    }
+}
+
+/// Half of the 14-bit sequence-count space (0x3FFF + 1), used to tell a
+/// forward gap (missed packets) from a backward one (an out-of-order or
+/// duplicate packet) after wraparound-normalizing the delta.
+const SEQUENCE_COUNT_HALF_RANGE: u16 = 0x2000;
+
+/// Compare `sequence_count` against the last-seen count for `apid` in
+/// `last_sequence` (establishing the baseline without reporting a gap if
+/// this is the first packet seen for `apid`), returning the sequence
+/// outcome: `(gap_event, is_out_of_order)`.
+fn record_sequence(
+    last_sequence: &mut HashMap<u16, u16>,
+    apid: u16,
+    sequence_count: u16,
+) -> (Option<CcsdsEvent>, bool) {
+    let outcome = match last_sequence.get(&apid) {
+        None => (None, false),
+        Some(&last) => {
+            let expected = (last + 1) & 0x3FFF;
+            if sequence_count == expected {
+                (None, false)
+            } else {
+                let delta = sequence_count.wrapping_sub(expected) & 0x3FFF;
+                if delta < SEQUENCE_COUNT_HALF_RANGE {
+                    (
+                        Some(CcsdsEvent::Gap {
+                            apid,
+                            expected,
+                            got: sequence_count,
+                            missed: delta,
+                        }),
+                        false,
+                    )
+                } else {
+                    (None, true)
+                }
+            }
+        }
+    };
+
+    last_sequence.insert(apid, sequence_count);
+    outcome
+}
+
+/// Outcome of [`process_received_packet`]: the decoded sample (if any),
+/// any sequence-count [`CcsdsEvent`] noticed for this packet, and whether
+/// this packet's sequence count was an out-of-order/duplicate delivery
+/// (see [`record_sequence`]).
+type ProcessedPacket = (Option<CcsdsSample>, Option<CcsdsEvent>, bool, Option<u16>);
+
+/// Decode one complete, already-framed CCSDS packet, reassembling fragmented
+/// ASTERIX blocks (sequence flags `01` first/`00` continuation/`10` last) in
+/// `reassembly` per-APID, and updating `last_sequence`'s per-APID gap
+/// tracking (see [`record_sequence`]). Returns the decoded sample (`None`
+/// when `packet` failed to parse or was absorbed into an in-progress
+/// reassembly rather than completing one) alongside any [`CcsdsEvent`]
+/// noticed for this packet's sequence count.
+fn process_received_packet(
+    packet: &[u8],
+    ctx: &SubscriberDecodeCtx,
+    reassembly: &mut HashMap<u16, ReassemblyBuffer>,
+    last_sequence: &mut HashMap<u16, u16>,
+) -> ProcessedPacket {
+    let has_secondary_header = packet.first().is_some_and(|b| b & 0x08 != 0);
+    let seq_flags = packet.get(2).map(|b| b >> 6).unwrap_or(SEQ_FLAGS_UNSEGMENTED);
+
+    let (apid, sequence_count, payload) = match parse_ccsds_packet(packet, ctx.enable_crc) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            log::warn!("Failed to parse CCSDS packet: {e}");
+            return (None, None, false, None);
+        }
+    };
+
+    let (gap_event, out_of_order) = record_sequence(last_sequence, apid, sequence_count);
+
+    let (time_code, pus_header, data) = match seq_flags {
+        SEQ_FLAGS_FIRST => {
+            if reassembly.contains_key(&apid) {
+                log::warn!(
+                    "Discarding incomplete CCSDS reassembly for APID 0x{apid:03X}: \
+                     a new first-segment packet arrived before the prior group completed"
+                );
+            }
+            let (time_code, pus_header, data) = decode_secondary_header(
+                ctx.use_secondary_header,
+                ctx.pus,
+                ctx.packet_type,
+                ctx.time_code_format,
+                has_secondary_header,
+                payload,
+            );
+            reassembly.insert(
+                apid,
+                ReassemblyBuffer {
+                    next_sequence_count: (sequence_count + 1) & 0x3FFF,
+                    time_code,
+                    pus_header,
+                    data,
+                },
+            );
+            return (None, gap_event, out_of_order, Some(apid));
+        }
+        SEQ_FLAGS_CONTINUATION => {
+            match reassembly.get_mut(&apid) {
+                Some(buffer) if buffer.next_sequence_count == sequence_count => {
+                    buffer.data.extend_from_slice(&payload);
+                    if buffer.data.len() > ctx.max_reassembly_bytes {
+                        log::warn!(
+                            "Dropping CCSDS reassembly buffer for APID 0x{apid:03X}: \
+                             exceeded {} bytes",
+                            ctx.max_reassembly_bytes
+                        );
+                        reassembly.remove(&apid);
+                    } else {
+                        buffer.next_sequence_count = (sequence_count + 1) & 0x3FFF;
+                    }
+                }
+                Some(_) => {
+                    log::warn!(
+                        "Dropping CCSDS reassembly buffer for APID 0x{apid:03X}: \
+                         out-of-order continuation segment"
+                    );
+                    reassembly.remove(&apid);
+                }
+                None => {
+                    log::warn!(
+                        "Discarding lone CCSDS continuation segment for APID \
+                         0x{apid:03X}: no reassembly in progress"
+                    );
+                }
+            }
+            return (None, gap_event, out_of_order, Some(apid));
+        }
+        SEQ_FLAGS_LAST => match reassembly.remove(&apid) {
+            Some(mut buffer) if buffer.next_sequence_count == sequence_count => {
+                buffer.data.extend_from_slice(&payload);
+                (buffer.time_code, buffer.pus_header, buffer.data)
+            }
+            Some(_) => {
+                log::warn!(
+                    "Discarding CCSDS reassembly for APID 0x{apid:03X}: \
+                     out-of-order final segment"
+                );
+                return (None, gap_event, out_of_order, Some(apid));
+            }
+            None => {
+                log::warn!(
+                    "Discarding lone CCSDS final segment for APID 0x{apid:03X}: \
+                     no reassembly in progress"
+                );
+                return (None, gap_event, out_of_order, Some(apid));
+            }
+        },
+        // SEQ_FLAGS_UNSEGMENTED (and any other value)
+        _ => decode_secondary_header(
+            ctx.use_secondary_header,
+            ctx.pus,
+            ctx.packet_type,
+            ctx.time_code_format,
+            has_secondary_header,
+            payload,
+        ),
+    };
+
+    (
+        Some(CcsdsSample {
+            category: category_from_apid(apid, ctx.base_apid),
+            apid,
+            sequence_count,
+            data,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_micros() as u64)
+                .unwrap_or(0),
+            packet_type: ctx.packet_type,
+            time_code,
+            pus_header,
+        }),
+        gap_event,
+        out_of_order,
+        Some(apid),
+    )
+}
+
+/// CCSDS subscriber for ASTERIX data
+pub struct CcsdsSubscriber {
+    config: CcsdsConfig,
+    local_addr: std::net::SocketAddr,
+    receiver: tokio::sync::mpsc::Receiver<CcsdsSample>,
+    events: tokio::sync::mpsc::UnboundedReceiver<CcsdsEvent>,
+    stats: Arc<std::sync::Mutex<SubscriberStats>>,
+    _handle: tokio::task::JoinHandle<()>,
+}
+
+impl CcsdsSubscriber {
+    /// Create a new CCSDS subscriber
+    ///
+    /// For [`CcsdsTransport::Udp`] (the default), binds a UDP socket on
+    /// `config.udp_port` (required — returns [`CcsdsError::NetworkError`]
+    /// if unset), joining the `config.multicast_addr` group on the default
+    /// interface when one is configured. For [`CcsdsTransport::Tcp`],
+    /// connects as a client to `config.multicast_addr` (or `127.0.0.1`) on
+    /// `config.tcp_port` (required) and frames packets by reading the
+    /// 6-byte primary header, then the `data_len + 1` bytes (plus a CRC
+    /// trailer if `config.enable_crc` is set) it declares.
+    ///
+    /// Either way, each received packet is parsed via [`parse_ccsds_packet`],
+    /// its category derived via [`category_from_apid`], and handed to
+    /// [`Self::recv`]/[`Self::try_recv`] as a [`CcsdsSample`]. Fragmented
+    /// ASTERIX blocks (sequence flags `01` first/`00` continuation/`10`
+    /// last) are reassembled per-APID before being delivered as a single
+    /// sample. [`Self::packets`] offers the same decoding over an arbitrary
+    /// byte stream instead of a background socket loop.
+    pub async fn new(config: CcsdsConfig) -> Result<Self, CcsdsError> {
+        let ctx = SubscriberDecodeCtx {
+            base_apid: config.base_apid,
+            packet_type: config.mode,
+            use_secondary_header: config.use_secondary_header,
+            pus: config.pus,
+            time_code_format: config.time_code_format,
+            enable_crc: config.enable_crc,
+            max_reassembly_bytes: config.max_reassembly_bytes,
+        };
+
+        let (tx, rx) = tokio::sync::mpsc::channel(1000);
+        let (events_tx, events_rx) = tokio::sync::mpsc::unbounded_channel();
+        let stats = Arc::new(std::sync::Mutex::new(SubscriberStats::default()));
+        let (local_addr, handle) = match config.transport {
+            CcsdsTransport::Udp => {
+                Self::spawn_udp_loop(&config, ctx, tx, events_tx, stats.clone()).await?
+            }
+            CcsdsTransport::Tcp => {
+                Self::spawn_tcp_loop(&config, ctx, tx, events_tx, stats.clone()).await?
+            }
+        };
 
         Ok(Self {
             config,
+            local_addr,
             receiver: rx,
+            events: events_rx,
+            stats,
             _handle: handle,
         })
     }
 
+    /// Bind `config.udp_port`, optionally join `config.multicast_addr`, and
+    /// spawn the datagram receive loop.
+    async fn spawn_udp_loop(
+        config: &CcsdsConfig,
+        ctx: SubscriberDecodeCtx,
+        tx: tokio::sync::mpsc::Sender<CcsdsSample>,
+        events_tx: tokio::sync::mpsc::UnboundedSender<CcsdsEvent>,
+        stats: Arc<std::sync::Mutex<SubscriberStats>>,
+    ) -> Result<(std::net::SocketAddr, tokio::task::JoinHandle<()>), CcsdsError> {
+        let port = config.udp_port.ok_or_else(|| {
+            CcsdsError::NetworkError("udp_port must be set to receive CCSDS packets".to_string())
+        })?;
+
+        let socket = tokio::net::UdpSocket::bind(("0.0.0.0", port))
+            .await
+            .map_err(|e| CcsdsError::NetworkError(format!("bind failed: {e}")))?;
+        let local_addr = socket
+            .local_addr()
+            .map_err(|e| CcsdsError::NetworkError(format!("failed to read local addr: {e}")))?;
+
+        if let Some(multicast_addr) = &config.multicast_addr {
+            let group: std::net::Ipv4Addr = multicast_addr.parse().map_err(|e| {
+                CcsdsError::NetworkError(format!("invalid multicast address {multicast_addr}: {e}"))
+            })?;
+            socket
+                .join_multicast_v4(group, std::net::Ipv4Addr::UNSPECIFIED)
+                .map_err(|e| {
+                    CcsdsError::NetworkError(format!(
+                        "failed to join multicast group {multicast_addr}: {e}"
+                    ))
+                })?;
+        }
+
+        let handle = tokio::spawn(async move {
+            // Max CCSDS packet: 6-byte header + 65536-byte data field.
+            let mut buf = vec![0u8; 65542];
+            let mut reassembly: HashMap<u16, ReassemblyBuffer> = HashMap::new();
+            let mut last_sequence: HashMap<u16, u16> = HashMap::new();
+
+            loop {
+                let len = match socket.recv(&mut buf).await {
+                    Ok(len) => len,
+                    Err(e) => {
+                        log::warn!("CCSDS subscriber recv error: {e}");
+                        continue;
+                    }
+                };
+
+                let (sample, gap_event, out_of_order, apid) =
+                    process_received_packet(&buf[..len], &ctx, &mut reassembly, &mut last_sequence);
+                if let Some(apid) = apid {
+                    if let Ok(mut stats) = stats.lock() {
+                        stats.record(apid, gap_event, out_of_order);
+                    }
+                }
+                if let Some(event) = gap_event {
+                    let _ = events_tx.send(event);
+                }
+                if let Some(sample) = sample {
+                    if tx.send(sample).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok((local_addr, handle))
+    }
+
+    /// Connect to `config.multicast_addr`:`config.tcp_port` as a client and
+    /// spawn the framed stream receive loop.
+    async fn spawn_tcp_loop(
+        config: &CcsdsConfig,
+        ctx: SubscriberDecodeCtx,
+        tx: tokio::sync::mpsc::Sender<CcsdsSample>,
+        events_tx: tokio::sync::mpsc::UnboundedSender<CcsdsEvent>,
+        stats: Arc<std::sync::Mutex<SubscriberStats>>,
+    ) -> Result<(std::net::SocketAddr, tokio::task::JoinHandle<()>), CcsdsError> {
+        let port = config.tcp_port.ok_or_else(|| {
+            CcsdsError::NetworkError("tcp_port must be set to receive CCSDS packets".to_string())
+        })?;
+        let host = config.multicast_addr.as_deref().unwrap_or("127.0.0.1");
+
+        let mut stream = tokio::net::TcpStream::connect((host, port))
+            .await
+            .map_err(|e| CcsdsError::NetworkError(format!("connect failed: {e}")))?;
+        let local_addr = stream
+            .local_addr()
+            .map_err(|e| CcsdsError::NetworkError(format!("failed to read local addr: {e}")))?;
+
+        let handle = tokio::spawn(async move {
+            let mut reassembly: HashMap<u16, ReassemblyBuffer> = HashMap::new();
+            let mut last_sequence: HashMap<u16, u16> = HashMap::new();
+
+            loop {
+                // `read_exact` accumulates across however many partial reads
+                // the stream delivers before the requested length is met.
+                let mut header = [0u8; 6];
+                if let Err(e) = stream.read_exact(&mut header).await {
+                    log::warn!("CCSDS TCP connection closed: {e}");
+                    break;
+                }
+
+                // data_len is (actual payload length - 1), per CCSDS 133.0-B-2.
+                let data_len = u16::from_be_bytes([header[4], header[5]]) as usize + 1;
+                let mut payload = vec![0u8; data_len];
+                if let Err(e) = stream.read_exact(&mut payload).await {
+                    log::warn!("CCSDS TCP connection closed mid-packet: {e}");
+                    break;
+                }
+
+                let mut packet = Vec::with_capacity(6 + data_len);
+                packet.extend_from_slice(&header);
+                packet.extend_from_slice(&payload);
+
+                let (sample, gap_event, out_of_order, apid) =
+                    process_received_packet(&packet, &ctx, &mut reassembly, &mut last_sequence);
+                if let Some(apid) = apid {
+                    if let Ok(mut stats) = stats.lock() {
+                        stats.record(apid, gap_event, out_of_order);
+                    }
+                }
+                if let Some(event) = gap_event {
+                    let _ = events_tx.send(event);
+                }
+                if let Some(sample) = sample {
+                    if tx.send(sample).await.is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok((local_addr, handle))
+    }
+
+    /// The socket's bound local address. For [`CcsdsTransport::Udp`],
+    /// useful when `config.udp_port` is `0` to discover which port the OS
+    /// actually assigned. For [`CcsdsTransport::Tcp`], the ephemeral local
+    /// address of the client connection (see [`CcsdsPublisher::tcp_local_addr`]
+    /// for the server's listening address).
+    pub fn local_addr(&self) -> std::net::SocketAddr {
+        self.local_addr
+    }
+
     /// Receive the next ASTERIX sample from CCSDS
     pub async fn recv(&mut self) -> Option<CcsdsSample> {
         self.receiver.recv().await
@@ -462,6 +2136,137 @@ impl CcsdsSubscriber {
     pub fn try_recv(&mut self) -> Option<CcsdsSample> {
         self.receiver.try_recv().ok()
     }
+
+    /// Snapshot the cumulative per-APID sequence-gap statistics gathered so
+    /// far by the background receive loop (see [`SubscriberStats`]).
+    pub fn stats(&self) -> SubscriberStats {
+        self.stats.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    /// Try to receive the next sequence-gap [`CcsdsEvent`] without blocking.
+    /// Events are also folded into [`Self::stats`] as they're detected, so
+    /// this is for callers that want to react to each gap individually
+    /// (e.g. logging) rather than only polling the running totals.
+    pub fn try_recv_event(&mut self) -> Option<CcsdsEvent> {
+        self.events.try_recv().ok()
+    }
+
+    /// Decode a continuous CCSDS byte stream read from `reader` (a TCP
+    /// socket, file, or any other [`AsyncRead`]) into a [`CcsdsDecoder`]
+    /// stream of samples, using this subscriber's `config` to interpret
+    /// packet type, secondary header, and PUS framing the same way
+    /// [`Self::new`]'s UDP/TCP receive loops do. Unlike those loops, no
+    /// background task is spawned — the stream only reads as it's polled.
+    pub fn packets<R: AsyncRead + Unpin>(&self, reader: R) -> CcsdsDecoder<R> {
+        CcsdsDecoder::new(
+            reader,
+            SubscriberDecodeCtx {
+                base_apid: self.config.base_apid,
+                packet_type: self.config.mode,
+                use_secondary_header: self.config.use_secondary_header,
+                pus: self.config.pus,
+                time_code_format: self.config.time_code_format,
+                enable_crc: self.config.enable_crc,
+                max_reassembly_bytes: self.config.max_reassembly_bytes,
+            },
+        )
+    }
+}
+
+/// Streaming CCSDS packet decoder over any [`AsyncRead`], yielding one
+/// [`CcsdsSample`] per complete packet. Reassembles fragmented ASTERIX
+/// blocks the same way [`process_received_packet`] does for the UDP/TCP
+/// receive loops, and correctly handles packets split across read
+/// boundaries by accumulating bytes in an internal buffer until a full
+/// packet (6-byte primary header plus the `data_len + 1` bytes it
+/// declares) is available.
+///
+/// Construct via [`CcsdsSubscriber::packets`].
+pub struct CcsdsDecoder<R> {
+    reader: R,
+    ctx: SubscriberDecodeCtx,
+    reassembly: HashMap<u16, ReassemblyBuffer>,
+    last_sequence: HashMap<u16, u16>,
+    buf: Vec<u8>,
+    eof: bool,
+}
+
+impl<R> CcsdsDecoder<R> {
+    fn new(reader: R, ctx: SubscriberDecodeCtx) -> Self {
+        Self {
+            reader,
+            ctx,
+            reassembly: HashMap::new(),
+            last_sequence: HashMap::new(),
+            buf: Vec::new(),
+            eof: false,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for CcsdsDecoder<R> {
+    type Item = Result<CcsdsSample, CcsdsError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            // A full primary header is needed before the declared data
+            // length (and thus the total packet size) is even known.
+            if this.buf.len() >= 6 {
+                let data_len = u16::from_be_bytes([this.buf[4], this.buf[5]]) as usize + 1;
+                let total = 6 + data_len;
+
+                if this.buf.len() >= total {
+                    let packet: Vec<u8> = this.buf.drain(..total).collect();
+                    let (sample, _gap_event, _out_of_order, _apid) = process_received_packet(
+                        &packet,
+                        &this.ctx,
+                        &mut this.reassembly,
+                        &mut this.last_sequence,
+                    );
+                    match sample {
+                        Some(sample) => return Poll::Ready(Some(Ok(sample))),
+                        // A non-final reassembly fragment: keep carving
+                        // packets out of whatever is already buffered
+                        // instead of polling the reader again.
+                        None => continue,
+                    }
+                }
+            }
+
+            if this.eof {
+                return if this.buf.is_empty() {
+                    Poll::Ready(None)
+                } else {
+                    let remainder = this.buf.len();
+                    this.buf.clear();
+                    Poll::Ready(Some(Err(CcsdsError::DecodeError(format!(
+                        "CCSDS stream ended mid-packet with {remainder} buffered byte(s)"
+                    )))))
+                };
+            }
+
+            let mut chunk = [0u8; 4096];
+            let mut read_buf = ReadBuf::new(&mut chunk);
+            match Pin::new(&mut this.reader).poll_read(cx, &mut read_buf) {
+                Poll::Ready(Ok(())) => {
+                    let filled = read_buf.filled().len();
+                    if filled == 0 {
+                        this.eof = true;
+                    } else {
+                        this.buf.extend_from_slice(read_buf.filled());
+                    }
+                }
+                Poll::Ready(Err(e)) => {
+                    return Poll::Ready(Some(Err(CcsdsError::NetworkError(format!(
+                        "CCSDS stream read error: {e}"
+                    )))));
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
 }
 
 /// Parse CCSDS packet header to extract ASTERIX category
@@ -494,6 +2299,56 @@ pub fn parse_ccsds_header(packet: &[u8]) -> Result<(u16, u16, Vec<u8>), CcsdsErr
     Ok((apid, sequence_count, data))
 }
 
+/// Parse a CCSDS packet exactly like [`parse_ccsds_header`], additionally
+/// verifying and stripping a trailing CRC-16/CCITT-FALSE Packet Error
+/// Control field when `enable_crc` is set (see [`CcsdsConfig::enable_crc`]).
+pub fn parse_ccsds_packet(
+    packet: &[u8],
+    enable_crc: bool,
+) -> Result<(u16, u16, Vec<u8>), CcsdsError> {
+    let (apid, sequence_count, mut data) = parse_ccsds_header(packet)?;
+
+    if enable_crc {
+        if data.len() < 2 {
+            return Err(CcsdsError::DecodeError(
+                "Packet too short for CRC trailer".to_string(),
+            ));
+        }
+
+        let split = data.len() - 2;
+        let received_crc = u16::from_be_bytes([data[split], data[split + 1]]);
+        let computed_crc = crc16_ccitt_false(&packet[..6 + split]);
+
+        if received_crc != computed_crc {
+            return Err(CcsdsError::DecodeError(format!(
+                "CRC mismatch: expected 0x{computed_crc:04X}, got 0x{received_crc:04X}"
+            )));
+        }
+
+        data.truncate(split);
+    }
+
+    Ok((apid, sequence_count, data))
+}
+
+/// Compute a CRC-16/CCITT-FALSE checksum (polynomial 0x1021, initial value
+/// 0xFFFF, no input/output reflection) over `data` — the CRC_16_IBM_3740
+/// variant used as the CCSDS/ECSS Packet Error Control field.
+fn crc16_ccitt_false(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
 /// Extract ASTERIX category from APID (assumes base_apid convention)
 pub fn category_from_apid(apid: u16, base_apid: u16) -> u8 {
     if apid >= base_apid && apid < base_apid + 256 {
@@ -503,6 +2358,13 @@ pub fn category_from_apid(apid: u16, base_apid: u16) -> u8 {
     }
 }
 
+// CFDP-over-CCSDS whole-file transfer used to be implemented here too
+// (a private `CfdpPdu` format plus `CcsdsFileSender`/`CcsdsFileReceiver`),
+// duplicating `crate::transport::cfdp`'s spec-compliant implementation
+// built on the same `CcsdsPublisher`/`CcsdsSubscriber` transport. That
+// duplicate has been removed; use `cfdp::CfdpSender`/`cfdp::CfdpReceiver`
+// for file transfer over CCSDS.
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -597,16 +2459,32 @@ mod tests {
         assert_eq!(config.udp_port, Some(8000));
     }
 
+    #[test]
+    fn test_ccsds_config_with_tcp() {
+        let config = CcsdsConfig::with_tcp(8001);
+        assert_eq!(config.transport, CcsdsTransport::Tcp);
+        assert_eq!(config.tcp_port, Some(8001));
+    }
+
+    #[test]
+    fn test_ccsds_transport_default_is_udp() {
+        assert_eq!(CcsdsTransport::default(), CcsdsTransport::Udp);
+    }
+
     #[test]
     fn test_ccsds_config_clone() {
         let config = CcsdsConfig {
             mode: CcsdsMode::Telecommand,
             base_apid: 0x500,
             use_secondary_header: true,
+            time_code_format: TimeCodeFormat::default(),
             max_packet_length: 32768,
             enable_crc: true,
             udp_port: Some(9000),
             multicast_addr: Some("239.0.0.1".to_string()),
+            pus: None,
+            transport: CcsdsTransport::Udp,
+            tcp_port: None,
         };
         let cloned = config.clone();
         assert_eq!(cloned.mode, CcsdsMode::Telecommand);
@@ -698,122 +2576,1518 @@ mod tests {
     }
 
     // ============================================================================
-    // CcsdsSample Tests
+    // CRC Tests
     // ============================================================================
 
     #[test]
-    fn test_ccsds_sample_debug() {
-        let sample = CcsdsSample {
-            category: 48,
-            apid: 0x330,
-            sequence_count: 42,
-            data: vec![0x30, 0x00, 0x10],
-            timestamp: 123456,
-            packet_type: CcsdsMode::Telemetry,
-        };
+    fn test_crc16_ccitt_false_known_vector() {
+        // "123456789" is the standard CRC_16_IBM_3740/CCITT-FALSE check value.
+        assert_eq!(crc16_ccitt_false(b"123456789"), 0x29B1);
+    }
 
-        let debug = format!("{sample:?}");
-        assert!(debug.contains("48"));
-        assert!(debug.contains("816")); // 0x330 in decimal
-        assert!(debug.contains("42"));
+    #[test]
+    fn test_parse_ccsds_packet_without_crc_matches_header_parse() {
+        let packet = vec![
+            0x03, 0x30, 0xC0, 0x00, 0x00, 0x03, 0x30, 0x00, 0x10, 0xAA,
+        ];
+
+        let expected = parse_ccsds_header(&packet).unwrap();
+        let actual = parse_ccsds_packet(&packet, false).unwrap();
+        assert_eq!(expected, actual);
     }
 
     #[test]
-    fn test_ccsds_sample_clone() {
-        let sample = CcsdsSample {
-            category: 62,
-            apid: 0x33E,
-            sequence_count: 100,
-            data: vec![0x3E],
-            timestamp: 0,
-            packet_type: CcsdsMode::Telecommand,
-        };
+    fn test_parse_ccsds_packet_with_valid_crc() {
+        let mut packet = vec![
+            0x03, 0x30, 0xC0, 0x00, 0x00, 0x05, // data length = 6 - 1 (4 data + 2 crc)
+            0x30, 0x00, 0x10, 0xAA,
+        ];
+        let crc = crc16_ccitt_false(&packet);
+        packet.extend_from_slice(&crc.to_be_bytes());
 
-        let cloned = sample.clone();
-        assert_eq!(cloned.category, 62);
-        assert_eq!(cloned.apid, 0x33E);
-        assert_eq!(cloned.sequence_count, 100);
-        assert_eq!(cloned.packet_type, CcsdsMode::Telecommand);
+        let (apid, seq_count, data) = parse_ccsds_packet(&packet, true).unwrap();
+        assert_eq!(apid, 0x330);
+        assert_eq!(seq_count, 0);
+        assert_eq!(data, vec![0x30, 0x00, 0x10, 0xAA]);
     }
 
-    // ============================================================================
-    // Async Integration Tests
-    // ============================================================================
+    #[test]
+    fn test_parse_ccsds_packet_with_corrupted_crc() {
+        let mut packet = vec![
+            0x03, 0x30, 0xC0, 0x00, 0x00, 0x05, 0x30, 0x00, 0x10, 0xAA,
+        ];
+        let crc = crc16_ccitt_false(&packet);
+        packet.extend_from_slice(&crc.to_be_bytes());
+        *packet.last_mut().unwrap() ^= 0xFF; // corrupt the CRC trailer
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_creation() {
-        let config = CcsdsConfig::default();
-        let publisher = CcsdsPublisher::new(config).await;
-        assert!(publisher.is_ok());
+        let result = parse_ccsds_packet(&packet, true);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("CRC"));
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_calculate_apid() {
-        let config = CcsdsConfig::with_base_apid(0x300);
-        let publisher = CcsdsPublisher::new(config).await.unwrap();
+    // ============================================================================
+    // Time Code Tests
+    // ============================================================================
 
-        assert_eq!(publisher.calculate_apid(0), 0x300);
-        assert_eq!(publisher.calculate_apid(48), 0x330);
-        assert_eq!(publisher.calculate_apid(62), 0x33E);
-        assert_eq!(publisher.calculate_apid(255), 0x3FF);
+    #[test]
+    fn test_time_code_format_default() {
+        assert_eq!(
+            TimeCodeFormat::default(),
+            TimeCodeFormat::Cuc {
+                coarse_octets: 4,
+                fine_octets: 2,
+            }
+        );
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_sequence_counter() {
-        let config = CcsdsConfig::default();
-        let publisher = CcsdsPublisher::new(config).await.unwrap();
+    #[test]
+    fn test_cuc_time_code_roundtrip() {
+        let format = TimeCodeFormat::Cuc {
+            coarse_octets: 4,
+            fine_octets: 2,
+        };
 
-        let apid = 0x330;
+        let encoded = encode_time_code(format, CucEpoch::default());
+        // 1 P-field byte + 4 coarse + 2 fine
+        assert_eq!(encoded.len(), 7);
+        assert_eq!(encoded[0], (4 << 4) | 2);
 
-        // First call should return 0
-        let seq1 = publisher.next_sequence_count(apid).await;
-        assert_eq!(seq1, 0);
+        let (decoded, consumed) = decode_time_code(format, &encoded).unwrap();
+        assert_eq!(consumed, encoded.len());
+        match decoded {
+            TimeCode::Cuc {
+                seconds,
+                subsec_fraction,
+            } => {
+                assert!(seconds > 0);
+                assert!((0.0..1.0).contains(&subsec_fraction));
+            }
+            TimeCode::Cds { .. } => panic!("expected Cuc time code"),
+        }
+    }
 
-        // Second call should return 1
-        let seq2 = publisher.next_sequence_count(apid).await;
-        assert_eq!(seq2, 1);
+    #[test]
+    fn test_cuc_time_code_truncated() {
+        let format = TimeCodeFormat::Cuc {
+            coarse_octets: 4,
+            fine_octets: 2,
+        };
+        let encoded = encode_time_code(format, CucEpoch::default());
 
-        // Third call should return 2
-        let seq3 = publisher.next_sequence_count(apid).await;
-        assert_eq!(seq3, 2);
+        let result = decode_time_code(format, &encoded[..encoded.len() - 1]);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("truncated"));
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_sequence_counter_wraparound() {
-        let config = CcsdsConfig::default();
-        let publisher = CcsdsPublisher::new(config).await.unwrap();
+    #[test]
+    fn test_cuc_epoch_default_is_unix() {
+        assert_eq!(CucEpoch::default(), CucEpoch::Unix);
+    }
 
-        let apid = 0x330;
+    #[test]
+    fn test_cuc_time_from_unix_roundtrip() {
+        let cuc = CucTime::from_unix(4, 2, CucEpoch::Unix, 1_700_000_000, 500_000_000);
+        assert_eq!(cuc.width(), 7);
+        assert_eq!(cuc.counter(), 1_700_000_000);
 
-        // Set counter to max (0x3FFF = 16383)
-        {
-            let mut counters = publisher.sequence_counters.lock().await;
-            counters.insert(apid, 0x3FFF);
+        let encoded = cuc.encode();
+        assert_eq!(encoded.len(), 7);
+        assert_eq!(encoded[0], (4 << 4) | 2);
+
+        let (decoded, consumed) = decode_time_code(
+            TimeCodeFormat::Cuc {
+                coarse_octets: 4,
+                fine_octets: 2,
+            },
+            &encoded,
+        )
+        .unwrap();
+        assert_eq!(consumed, 7);
+        match decoded {
+            TimeCode::Cuc {
+                seconds,
+                subsec_fraction,
+            } => {
+                assert_eq!(seconds, 1_700_000_000);
+                assert!((subsec_fraction - 0.5).abs() < 0.01);
+            }
+            TimeCode::Cds { .. } => panic!("expected Cuc time code"),
         }
+    }
 
-        // Next count should wrap to 0
+    #[test]
+    fn test_cuc_time_ccsds_epoch_shifts_seconds() {
+        let unix = CucTime::from_unix(4, 0, CucEpoch::Unix, 1_700_000_000, 0);
+        let ccsds = CucTime::from_unix(4, 0, CucEpoch::Ccsds, 1_700_000_000, 0);
+        assert_eq!(
+            ccsds.counter() - unix.counter(),
+            CCSDS_TO_UNIX_EPOCH_OFFSET_SECS
+        );
+    }
+
+    #[test]
+    fn test_time_code_format_presets() {
+        assert_eq!(
+            TimeCodeFormat::cuc_level1(),
+            TimeCodeFormat::Cuc {
+                coarse_octets: 4,
+                fine_octets: 0,
+            }
+        );
+        assert_eq!(TimeCodeFormat::cuc_level2(), TimeCodeFormat::default());
+        assert_eq!(
+            TimeCodeFormat::cds_short(),
+            TimeCodeFormat::Cds {
+                include_microseconds: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_with_cuc_config() {
+        let config = CcsdsConfig::with_cuc(4, 2);
+        assert!(config.use_secondary_header);
+        assert_eq!(
+            config.time_code_format,
+            TimeCodeFormat::Cuc {
+                coarse_octets: 4,
+                fine_octets: 2,
+            }
+        );
+        assert_eq!(config.cuc_epoch, CucEpoch::Ccsds);
+    }
+
+    #[test]
+    fn test_with_cds_config() {
+        let config = CcsdsConfig::with_cds(true);
+        assert!(config.use_secondary_header);
+        assert_eq!(
+            config.time_code_format,
+            TimeCodeFormat::Cds {
+                include_microseconds: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_time_code_to_unix_micros_cuc_unix_epoch() {
+        let time_code = TimeCode::Cuc {
+            seconds: 1_700_000_000,
+            subsec_fraction: 0.5,
+        };
+        assert_eq!(
+            time_code.to_unix_micros(CucEpoch::Unix),
+            1_700_000_000_500_000
+        );
+    }
+
+    #[test]
+    fn test_time_code_to_unix_micros_cuc_ccsds_epoch() {
+        let time_code = TimeCode::Cuc {
+            seconds: 1_700_000_000 + CCSDS_TO_UNIX_EPOCH_OFFSET_SECS,
+            subsec_fraction: 0.0,
+        };
+        assert_eq!(
+            time_code.to_unix_micros(CucEpoch::Ccsds),
+            1_700_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_time_code_to_unix_micros_cds() {
+        let time_code = TimeCode::Cds {
+            days: 1,
+            ms_of_day: 2_000,
+            microseconds: Some(500),
+        };
+        // 1 day + 2000ms + 500us
+        assert_eq!(time_code.to_unix_micros(CucEpoch::Unix), 86_402_000_500);
+    }
+
+    #[test]
+    fn test_encode_time_code_respects_configured_cuc_epoch() {
+        let format = TimeCodeFormat::Cuc {
+            coarse_octets: 4,
+            fine_octets: 0,
+        };
+
+        let unix_encoded = encode_time_code(format, CucEpoch::Unix);
+        let ccsds_encoded = encode_time_code(format, CucEpoch::Ccsds);
+
+        let unix_secs = match decode_time_code(format, &unix_encoded).unwrap().0 {
+            TimeCode::Cuc { seconds, .. } => seconds,
+            TimeCode::Cds { .. } => panic!("expected Cuc time code"),
+        };
+        let ccsds_secs = match decode_time_code(format, &ccsds_encoded).unwrap().0 {
+            TimeCode::Cuc { seconds, .. } => seconds,
+            TimeCode::Cds { .. } => panic!("expected Cuc time code"),
+        };
+
+        assert_eq!(ccsds_secs - unix_secs, CCSDS_TO_UNIX_EPOCH_OFFSET_SECS);
+    }
+
+    #[test]
+    fn test_encode_time_code_for_encodes_given_timestamp_not_now() {
+        let format = TimeCodeFormat::Cuc {
+            coarse_octets: 4,
+            fine_octets: 0,
+        };
+
+        let encoded = encode_time_code_for(
+            format,
+            CucEpoch::Unix,
+            std::time::Duration::from_millis(1_234_567_890_000),
+        );
+        let seconds = match decode_time_code(format, &encoded).unwrap().0 {
+            TimeCode::Cuc { seconds, .. } => seconds,
+            TimeCode::Cds { .. } => panic!("expected Cuc time code"),
+        };
+
+        assert_eq!(seconds, 1_234_567_890);
+    }
+
+    #[test]
+    fn test_cds_time_code_roundtrip_without_microseconds() {
+        let format = TimeCodeFormat::Cds {
+            include_microseconds: false,
+        };
+
+        let encoded = encode_time_code(format, CucEpoch::default());
+        assert_eq!(encoded.len(), 6);
+
+        let (decoded, consumed) = decode_time_code(format, &encoded).unwrap();
+        assert_eq!(consumed, 6);
+        match decoded {
+            TimeCode::Cds {
+                ms_of_day,
+                microseconds,
+                ..
+            } => {
+                assert!(ms_of_day < 86_400_000);
+                assert_eq!(microseconds, None);
+            }
+            TimeCode::Cuc { .. } => panic!("expected Cds time code"),
+        }
+    }
+
+    #[test]
+    fn test_cds_time_code_roundtrip_with_microseconds() {
+        let format = TimeCodeFormat::Cds {
+            include_microseconds: true,
+        };
+
+        let encoded = encode_time_code(format, CucEpoch::default());
+        assert_eq!(encoded.len(), 8);
+
+        let (decoded, consumed) = decode_time_code(format, &encoded).unwrap();
+        assert_eq!(consumed, 8);
+        match decoded {
+            TimeCode::Cds { microseconds, .. } => assert!(microseconds.is_some()),
+            TimeCode::Cuc { .. } => panic!("expected Cds time code"),
+        }
+    }
+
+    // ============================================================================
+    // PUS Secondary Header Tests
+    // ============================================================================
+
+    #[test]
+    fn test_pus_tm_header_roundtrip() {
+        let pus = PusConfig {
+            service: 130,
+            subservice: 5,
+            route_id: 0x1234,
+            ack_flags: 0,
+        };
+        let format = TimeCodeFormat::default();
+
+        let encoded = encode_pus_header(CcsdsMode::Telemetry, pus, 7, format, CucEpoch::default());
+        // 1 version byte + service + subservice + 2-byte counter + 2-byte route_id + 7-byte time
+        assert_eq!(encoded.len(), 7 + 7);
+        assert_eq!(encoded[0] >> 4, PUS_VERSION);
+        assert_eq!(&encoded[3..5], &7u16.to_be_bytes());
+        assert_eq!(&encoded[5..7], &0x1234u16.to_be_bytes());
+
+        let (header, consumed) = decode_pus_header(CcsdsMode::Telemetry, format, &encoded).unwrap();
+        assert_eq!(header.service_type(), 130);
+        assert_eq!(header.service_subtype(), 5);
+        assert_eq!(
+            header,
+            PusSecondaryHeader::Tm {
+                service_type: 130,
+                service_subtype: 5,
+                message_type_counter: 7,
+                destination_id: 0x1234,
+            }
+        );
+        assert_eq!(consumed, encoded.len());
+    }
+
+    #[test]
+    fn test_pus_tc_header_roundtrip() {
+        let pus = PusConfig {
+            service: 11,
+            subservice: 4,
+            route_id: 0xABCD,
+            ack_flags: 0b1111,
+        };
+        let format = TimeCodeFormat::default();
+
+        let encoded = encode_pus_header(CcsdsMode::Telecommand, pus, 0, format, CucEpoch::default());
+        // 1 version/ack byte + service + subservice + 2-byte route_id, no time field
+        assert_eq!(encoded.len(), 5);
+        assert_eq!(encoded[0] >> 4, PUS_VERSION);
+        assert_eq!(encoded[0] & 0x0F, 0b1111);
+
+        let (header, consumed) = decode_pus_header(CcsdsMode::Telecommand, format, &encoded).unwrap();
+        assert_eq!(header.service_type(), 11);
+        assert_eq!(header.service_subtype(), 4);
+        assert_eq!(
+            header,
+            PusSecondaryHeader::Tc {
+                service_type: 11,
+                service_subtype: 4,
+                source_id: 0xABCD,
+            }
+        );
+        assert_eq!(consumed, 5);
+    }
+
+    #[test]
+    fn test_pus_tm_header_truncated() {
+        let result = decode_pus_header(CcsdsMode::Telemetry, TimeCodeFormat::default(), &[0; 6]);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("truncated"));
+    }
+
+    #[test]
+    fn test_pus_tc_header_truncated() {
+        let result = decode_pus_header(CcsdsMode::Telecommand, TimeCodeFormat::default(), &[0; 4]);
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("truncated"));
+    }
+
+    #[test]
+    fn test_decode_secondary_header_without_flag_passes_through() {
+        let payload = vec![0x30, 0x00, 0x10];
+        let (time_code, pus_header, data) = decode_secondary_header(
+            true,
+            Some(PusConfig::default()),
+            CcsdsMode::Telemetry,
+            TimeCodeFormat::default(),
+            false,
+            payload.clone(),
+        );
+        assert!(time_code.is_none());
+        assert!(pus_header.is_none());
+        assert_eq!(data, payload);
+    }
+
+    #[test]
+    fn test_with_pus_config() {
+        let config = CcsdsConfig::with_pus(130, 5);
+        let pus = config.pus.expect("with_pus should set a PusConfig");
+        assert_eq!(pus.service, 130);
+        assert_eq!(pus.subservice, 5);
+        assert_eq!(pus.route_id, 0);
+    }
+
+    #[test]
+    fn test_pus_secondary_header_source_or_dest_id() {
+        let tm = PusSecondaryHeader::Tm {
+            service_type: 130,
+            service_subtype: 5,
+            message_type_counter: 1,
+            destination_id: 0x42,
+        };
+        assert_eq!(tm.source_or_dest_id(), 0x42);
+
+        let tc = PusSecondaryHeader::Tc {
+            service_type: 130,
+            service_subtype: 5,
+            source_id: 0x99,
+        };
+        assert_eq!(tc.source_or_dest_id(), 0x99);
+    }
+
+    // ============================================================================
+    // CcsdsSample Tests
+    // ============================================================================
+
+    #[test]
+    fn test_ccsds_sample_debug() {
+        let sample = CcsdsSample {
+            category: 48,
+            apid: 0x330,
+            sequence_count: 42,
+            data: vec![0x30, 0x00, 0x10],
+            timestamp: 123456,
+            packet_type: CcsdsMode::Telemetry,
+            time_code: None,
+            pus_header: None,
+        };
+
+        let debug = format!("{sample:?}");
+        assert!(debug.contains("48"));
+        assert!(debug.contains("816")); // 0x330 in decimal
+        assert!(debug.contains("42"));
+    }
+
+    #[test]
+    fn test_ccsds_sample_clone() {
+        let sample = CcsdsSample {
+            category: 62,
+            apid: 0x33E,
+            sequence_count: 100,
+            data: vec![0x3E],
+            timestamp: 0,
+            packet_type: CcsdsMode::Telecommand,
+            time_code: None,
+            pus_header: None,
+        };
+
+        let cloned = sample.clone();
+        assert_eq!(cloned.category, 62);
+        assert_eq!(cloned.apid, 0x33E);
+        assert_eq!(cloned.sequence_count, 100);
+        assert_eq!(cloned.packet_type, CcsdsMode::Telecommand);
+    }
+
+    // ============================================================================
+    // Async Integration Tests
+    // ============================================================================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_creation() {
+        let config = CcsdsConfig::default();
+        let publisher = CcsdsPublisher::new(config).await;
+        assert!(publisher.is_ok());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_calculate_apid() {
+        let config = CcsdsConfig::with_base_apid(0x300);
+        let publisher = CcsdsPublisher::new(config).await.unwrap();
+
+        assert_eq!(publisher.calculate_apid(0), 0x300);
+        assert_eq!(publisher.calculate_apid(48), 0x330);
+        assert_eq!(publisher.calculate_apid(62), 0x33E);
+        assert_eq!(publisher.calculate_apid(255), 0x3FF);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_sequence_counter() {
+        let config = CcsdsConfig::default();
+        let publisher = CcsdsPublisher::new(config).await.unwrap();
+
+        let apid = 0x330;
+
+        // First call should return 0
+        let seq1 = publisher.next_sequence_count(apid).await;
+        assert_eq!(seq1, 0);
+
+        // Second call should return 1
+        let seq2 = publisher.next_sequence_count(apid).await;
+        assert_eq!(seq2, 1);
+
+        // Third call should return 2
+        let seq3 = publisher.next_sequence_count(apid).await;
+        assert_eq!(seq3, 2);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_sequence_counter_wraparound() {
+        let config = CcsdsConfig::default();
+        let publisher = CcsdsPublisher::new(config).await.unwrap();
+
+        let apid = 0x330;
+
+        // Set counter to max (0x3FFF = 16383)
+        {
+            let mut counters = publisher.sequence_counters.lock().await;
+            counters.insert(apid, 0x3FFF);
+        }
+
+        // Next count should wrap to 0
         let seq = publisher.next_sequence_count(apid).await;
         assert_eq!(seq, 0x3FFF);
 
-        // And increment should give 0
-        let seq_next = publisher.next_sequence_count(apid).await;
-        assert_eq!(seq_next, 0);
+        // And increment should give 0
+        let seq_next = publisher.next_sequence_count(apid).await;
+        assert_eq!(seq_next, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_publish_raw() {
+        let config = CcsdsConfig::default();
+        let publisher = CcsdsPublisher::new(config).await.unwrap();
+
+        let result = publisher.publish_raw(48, &[0x30, 0x00, 0x10]).await;
+        assert!(result.is_ok(), "publish_raw failed: {:?}", result.err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_creation() {
+        // Ephemeral port avoids clashing with other tests/processes bound to
+        // the default 7447, mirroring `HttpServer`/`WsServer`'s `port: 0` tests.
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let subscriber = CcsdsSubscriber::new(config).await;
+        assert!(subscriber.is_ok());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_requires_udp_port() {
+        let config = CcsdsConfig {
+            udp_port: None,
+            ..CcsdsConfig::default()
+        };
+        let result = CcsdsSubscriber::new(config).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_and_subscriber_roundtrip_over_udp() {
+        use tokio::time::{timeout, Duration};
+
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        // Point the publisher at the subscriber's actual (OS-assigned) port.
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        publisher
+            .publish_raw(48, &[0x30, 0x00, 0x10])
+            .await
+            .expect("publish_raw over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.category, 48);
+        assert_eq!(sample.data, vec![0x30, 0x00, 0x10]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_and_subscriber_roundtrip_with_secondary_header() {
+        use tokio::time::{timeout, Duration};
+
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            use_secondary_header: true,
+            ..CcsdsConfig::default()
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            use_secondary_header: true,
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        publisher
+            .publish_raw(48, &[0x30, 0x00, 0x10])
+            .await
+            .expect("publish_raw over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.category, 48);
+        assert_eq!(sample.data, vec![0x30, 0x00, 0x10]);
+        assert!(matches!(sample.time_code, Some(TimeCode::Cuc { .. })));
+    }
+
+    // ============================================================================
+    // Fragmentation Tests
+    // ============================================================================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_encode_packet_sets_sequence_flags() {
+        let config = CcsdsConfig::default();
+        let publisher = CcsdsPublisher::new(config).await.unwrap();
+
+        for seq_flags in [
+            SEQ_FLAGS_UNSEGMENTED,
+            SEQ_FLAGS_FIRST,
+            SEQ_FLAGS_CONTINUATION,
+            SEQ_FLAGS_LAST,
+        ] {
+            let packet = publisher
+                .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0xAA], seq_flags, false)
+                .unwrap();
+            assert_eq!(packet[2] >> 6, seq_flags);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_and_subscriber_roundtrip_with_fragmentation() {
+        use tokio::time::{timeout, Duration};
+
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        // A small max_packet_length forces publish_raw to split the payload
+        // across several fragments.
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            max_packet_length: 16,
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        let data: Vec<u8> = (0..100u8).collect();
+        publisher
+            .publish_raw(48, &data)
+            .await
+            .expect("fragmented publish_raw over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a reassembled sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.category, 48);
+        assert_eq!(sample.data, data);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_and_subscriber_roundtrip_with_crc() {
+        use tokio::time::{timeout, Duration};
+
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            enable_crc: true,
+            ..CcsdsConfig::default()
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            enable_crc: true,
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        publisher
+            .publish_raw(48, &[0x30, 0x00, 0x10])
+            .await
+            .expect("publish_raw over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.category, 48);
+        // The CRC trailer must not leak into the delivered ASTERIX bytes.
+        assert_eq!(sample.data, vec![0x30, 0x00, 0x10]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_encodes_record_timestamp_not_wall_clock() {
+        use tokio::time::{timeout, Duration};
+
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            use_secondary_header: true,
+            ..CcsdsConfig::default()
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            use_secondary_header: true,
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        let record = AsterixRecord {
+            category: 48,
+            timestamp_ms: 1_234_567_890_000,
+            hex_data: "300003".to_string(),
+            ..AsterixRecord::default()
+        };
+        publisher
+            .publish(&record)
+            .await
+            .expect("publish over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        match sample.time_code {
+            Some(TimeCode::Cuc { seconds, .. }) => assert_eq!(seconds, 1_234_567_890),
+            other => panic!("expected a decoded Cuc time code, got {other:?}"),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_and_subscriber_roundtrip_with_pus() {
+        use tokio::time::{timeout, Duration};
+
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::with_pus(130, 5)
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            ..CcsdsConfig::with_pus(130, 5)
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        publisher
+            .publish_raw(48, &[0x30, 0x00, 0x10])
+            .await
+            .expect("publish_raw over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.category, 48);
+        // The PUS secondary header must not leak into the delivered ASTERIX bytes.
+        assert_eq!(sample.data, vec![0x30, 0x00, 0x10]);
+        assert_eq!(sample.pus_header.unwrap().service_type(), 130);
+        assert_eq!(sample.pus_header.unwrap().service_subtype(), 5);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_pus_tm_roundtrip_without_config_pus() {
+        use tokio::time::{timeout, Duration};
+
+        // The subscriber still needs `config.pus` set to know to expect a
+        // PUS header at all; `publish_pus_tm` lets the publisher skip
+        // configuring one up front and pick the service/subservice per call.
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::with_pus(0, 0)
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        publisher
+            .publish_pus_tm(48, 17, 2, &[0x30, 0x00, 0x10])
+            .await
+            .expect("publish_pus_tm over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.data, vec![0x30, 0x00, 0x10]);
+        assert_eq!(
+            sample.pus_header,
+            Some(PusSecondaryHeader::Tm {
+                service_type: 17,
+                service_subtype: 2,
+                message_type_counter: 0,
+                destination_id: 0,
+            })
+        );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_publish_raw() {
-        let config = CcsdsConfig::default();
-        let publisher = CcsdsPublisher::new(config).await.unwrap();
+    async fn test_publish_pus_tc_roundtrip() {
+        use tokio::time::{timeout, Duration};
 
-        let result = publisher.publish_raw(48, &[0x30, 0x00, 0x10]).await;
-        assert!(result.is_ok(), "publish_raw failed: {:?}", result.err());
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            mode: CcsdsMode::Telecommand,
+            ..CcsdsConfig::with_pus(0, 0)
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            mode: CcsdsMode::Telecommand,
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        publisher
+            .publish_pus_tc(48, 11, 4, &[0xAB])
+            .await
+            .expect("publish_pus_tc over UDP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.data, vec![0xAB]);
+        assert_eq!(
+            sample.pus_header,
+            Some(PusSecondaryHeader::Tc {
+                service_type: 11,
+                service_subtype: 4,
+                source_id: 0,
+            })
+        );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_subscriber_creation() {
-        let config = CcsdsConfig::default();
-        let subscriber = CcsdsSubscriber::new(config).await;
-        assert!(subscriber.is_ok());
+    async fn test_publisher_and_subscriber_roundtrip_over_tcp() {
+        use tokio::time::{timeout, Duration};
+
+        // Unlike UDP, the publisher is the TCP server: it must be listening
+        // before the subscriber (a client) connects.
+        let pub_config = CcsdsConfig {
+            udp_port: None,
+            ..CcsdsConfig::with_tcp(0)
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+        let bound_port = publisher.tcp_local_addr().unwrap().port();
+
+        let sub_config = CcsdsConfig {
+            udp_port: None,
+            ..CcsdsConfig::with_tcp(bound_port)
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+
+        // Give the publisher's accept loop a moment to register the new
+        // connection before publishing (unacknowledged mode has no
+        // built-in readiness handshake).
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        publisher
+            .publish_raw(48, &[0x30, 0x00, 0x10])
+            .await
+            .expect("publish_raw over TCP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.category, 48);
+        assert_eq!(sample.data, vec![0x30, 0x00, 0x10]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_and_subscriber_roundtrip_over_tcp_with_fragmentation() {
+        use tokio::time::{timeout, Duration};
+
+        let pub_config = CcsdsConfig {
+            udp_port: None,
+            max_packet_length: 16,
+            ..CcsdsConfig::with_tcp(0)
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+        let bound_port = publisher.tcp_local_addr().unwrap().port();
+
+        let sub_config = CcsdsConfig {
+            udp_port: None,
+            ..CcsdsConfig::with_tcp(bound_port)
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let data: Vec<u8> = (0..100u8).collect();
+        publisher
+            .publish_raw(48, &data)
+            .await
+            .expect("fragmented publish_raw over TCP should succeed");
+
+        let sample = timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected a reassembled sample before the timeout")
+            .expect("channel should not be closed");
+
+        assert_eq!(sample.category, 48);
+        assert_eq!(sample.data, data);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_tcp_subscriber_requires_tcp_port() {
+        let config = CcsdsConfig {
+            udp_port: None,
+            tcp_port: None,
+            ..CcsdsConfig::with_tcp(0)
+        };
+        let result = CcsdsSubscriber::new(config).await;
+        assert!(result.is_err());
+    }
+
+    // ============================================================================
+    // Streaming Decoder Tests
+    // ============================================================================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_ccsds_decoder_single_packet() {
+        use futures_util::StreamExt;
+
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(config.clone()).await.unwrap();
+        let subscriber = CcsdsSubscriber::new(config).await.unwrap();
+
+        let packet = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0x30, 0x00, 0x10], SEQ_FLAGS_UNSEGMENTED, false)
+            .unwrap();
+
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        writer.write_all(&packet).await.unwrap();
+
+        let mut stream = subscriber.packets(reader);
+        let sample = stream.next().await.unwrap().unwrap();
+        assert_eq!(sample.data, vec![0x30, 0x00, 0x10]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_ccsds_decoder_header_split_across_reads() {
+        use futures_util::StreamExt;
+
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(config.clone()).await.unwrap();
+        let subscriber = CcsdsSubscriber::new(config).await.unwrap();
+
+        let packet = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0xAA, 0xBB], SEQ_FLAGS_UNSEGMENTED, false)
+            .unwrap();
+
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        let mut stream = subscriber.packets(reader);
+
+        // Write the primary header split across two reads (3 bytes, then
+        // the remaining 3), then the payload, each with a yield in between
+        // so the decoder must poll the reader more than once.
+        writer.write_all(&packet[..3]).await.unwrap();
+        tokio::task::yield_now().await;
+        writer.write_all(&packet[3..6]).await.unwrap();
+        tokio::task::yield_now().await;
+        writer.write_all(&packet[6..]).await.unwrap();
+
+        let sample = stream.next().await.unwrap().unwrap();
+        assert_eq!(sample.data, vec![0xAA, 0xBB]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_ccsds_decoder_single_byte_payload() {
+        use futures_util::StreamExt;
+
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(config.clone()).await.unwrap();
+        let subscriber = CcsdsSubscriber::new(config).await.unwrap();
+
+        // The smallest legal CCSDS data field: 1 byte (data_len field
+        // encodes this as 0, i.e. actual length minus one).
+        let packet = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0x42], SEQ_FLAGS_UNSEGMENTED, false)
+            .unwrap();
+        assert_eq!(u16::from_be_bytes([packet[4], packet[5]]), 0);
+
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        writer.write_all(&packet).await.unwrap();
+
+        let mut stream = subscriber.packets(reader);
+        let sample = stream.next().await.unwrap().unwrap();
+        assert_eq!(sample.data, vec![0x42]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_ccsds_decoder_eof_mid_packet_errors() {
+        use futures_util::StreamExt;
+
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(config.clone()).await.unwrap();
+        let subscriber = CcsdsSubscriber::new(config).await.unwrap();
+
+        let packet = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0xAA, 0xBB, 0xCC], SEQ_FLAGS_UNSEGMENTED, false)
+            .unwrap();
+
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        writer.write_all(&packet[..packet.len() - 1]).await.unwrap();
+        drop(writer);
+
+        let mut stream = subscriber.packets(reader);
+        let result = stream.next().await.unwrap();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("mid-packet"));
+
+        // The stream is drained after the error, not stuck retrying forever.
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_ccsds_decoder_clean_eof_between_packets() {
+        use futures_util::StreamExt;
+
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(config.clone()).await.unwrap();
+        let subscriber = CcsdsSubscriber::new(config).await.unwrap();
+
+        let packet = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0xAA], SEQ_FLAGS_UNSEGMENTED, false)
+            .unwrap();
+
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        writer.write_all(&packet).await.unwrap();
+        drop(writer);
+
+        let mut stream = subscriber.packets(reader);
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_ccsds_decoder_discards_incomplete_group_on_premature_first_segment() {
+        use futures_util::StreamExt;
+
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(config.clone()).await.unwrap();
+        let subscriber = CcsdsSubscriber::new(config).await.unwrap();
+
+        // Start (but never finish) a reassembly group for APID 0x330 ...
+        let abandoned_first = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0xAA], SEQ_FLAGS_FIRST, false)
+            .unwrap();
+        // ... then start a second group on the same APID before the first one
+        // saw a last segment. The abandoned group's bytes must not leak into
+        // the eventually-reassembled sample.
+        let second_first = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 1, &[0xBB], SEQ_FLAGS_FIRST, false)
+            .unwrap();
+        let second_last = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 2, &[0xCC], SEQ_FLAGS_LAST, false)
+            .unwrap();
+
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        writer.write_all(&abandoned_first).await.unwrap();
+        writer.write_all(&second_first).await.unwrap();
+        writer.write_all(&second_last).await.unwrap();
+
+        let mut stream = subscriber.packets(reader);
+        let sample = stream.next().await.unwrap().unwrap();
+        assert_eq!(sample.data, vec![0xBB, 0xCC]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_ccsds_decoder_drops_reassembly_buffer_exceeding_max_reassembly_bytes() {
+        use futures_util::StreamExt;
+
+        let config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default().with_max_reassembly_bytes(2)
+        };
+        let publisher = CcsdsPublisher::new(config.clone()).await.unwrap();
+        let subscriber = CcsdsSubscriber::new(config).await.unwrap();
+
+        // First segment starts a group within the 2-byte cap, but the
+        // continuation segment pushes it over; the buffer must be dropped
+        // rather than allowed to grow without bound.
+        let oversized_first = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 0, &[0xAA], SEQ_FLAGS_FIRST, false)
+            .unwrap();
+        let oversized_continuation = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 1, &[0xBB, 0xCC], SEQ_FLAGS_CONTINUATION, false)
+            .unwrap();
+        let oversized_last = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 2, &[0xDD], SEQ_FLAGS_LAST, false)
+            .unwrap();
+
+        // A second, well-behaved group on the same APID follows; it must
+        // reassemble normally once the oversized one has been dropped.
+        let second_first = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 3, &[0xEE], SEQ_FLAGS_FIRST, false)
+            .unwrap();
+        let second_last = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 4, &[0xFF], SEQ_FLAGS_LAST, false)
+            .unwrap();
+
+        let (mut writer, reader) = tokio::io::duplex(1024);
+        writer.write_all(&oversized_first).await.unwrap();
+        writer.write_all(&oversized_continuation).await.unwrap();
+        writer.write_all(&oversized_last).await.unwrap();
+        writer.write_all(&second_first).await.unwrap();
+        writer.write_all(&second_last).await.unwrap();
+        drop(writer);
+
+        let mut stream = subscriber.packets(reader);
+        let sample = stream.next().await.unwrap().unwrap();
+        assert_eq!(sample.data, vec![0xEE, 0xFF]);
+        assert!(stream.next().await.is_none());
+    }
+
+    // ============================================================================
+    // Length-Prefixed Framing Tests
+    // ============================================================================
+
+    #[cfg(feature = "serde")]
+    fn sample_ccsds_sample() -> CcsdsSample {
+        CcsdsSample {
+            category: 48,
+            apid: 0x330,
+            sequence_count: 42,
+            data: vec![0x30, 0x00, 0x10, 0xFF],
+            timestamp: 123456,
+            packet_type: CcsdsMode::Telemetry,
+            time_code: None,
+            pus_header: None,
+        }
+    }
+
+    #[test]
+    fn test_encode_frame_prefix_single_byte() {
+        assert_eq!(encode_frame_prefix(0), vec![0]);
+        assert_eq!(encode_frame_prefix(251), vec![251]);
+    }
+
+    #[test]
+    fn test_encode_frame_prefix_u16_sentinel() {
+        let encoded = encode_frame_prefix(252);
+        assert_eq!(encoded[0], FRAME_LEN_PREFIX_U16);
+        assert_eq!(&encoded[1..], &252u16.to_be_bytes());
+
+        let encoded = encode_frame_prefix(u16::MAX as usize);
+        assert_eq!(encoded[0], FRAME_LEN_PREFIX_U16);
+        assert_eq!(&encoded[1..], &u16::MAX.to_be_bytes());
+    }
+
+    #[test]
+    fn test_encode_frame_prefix_u32_sentinel() {
+        let len = u16::MAX as usize + 1;
+        let encoded = encode_frame_prefix(len);
+        assert_eq!(encoded[0], FRAME_LEN_PREFIX_U32);
+        assert_eq!(&encoded[1..], &(len as u32).to_be_bytes());
+    }
+
+    #[test]
+    fn test_decode_frame_prefix_roundtrip() {
+        for len in [0usize, 251, 252, 300, 70_000] {
+            let encoded = encode_frame_prefix(len);
+            let (prefix_len, decoded_len) = decode_frame_prefix(&encoded).unwrap().unwrap();
+            assert_eq!(prefix_len, encoded.len());
+            assert_eq!(decoded_len, len);
+        }
+    }
+
+    #[test]
+    fn test_decode_frame_prefix_waits_for_sentinel_extension() {
+        let encoded = encode_frame_prefix(300);
+        // Only the sentinel byte is available; the 2-byte extension hasn't
+        // arrived yet.
+        assert_eq!(decode_frame_prefix(&encoded[..1]).unwrap(), None);
+        assert_eq!(
+            decode_frame_prefix(&encoded).unwrap(),
+            Some((encoded.len(), 300))
+        );
+    }
+
+    #[test]
+    fn test_decode_frame_prefix_rejects_reserved_byte() {
+        let result = decode_frame_prefix(&[255]);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_write_framed_and_framed_reader_roundtrip() {
+        use futures_util::StreamExt;
+
+        let sample = sample_ccsds_sample();
+
+        let (mut writer, reader) = tokio::io::duplex(4096);
+        CcsdsPublisher::write_framed(&mut writer, &sample)
+            .await
+            .unwrap();
+
+        let mut stream = FramedCcsdsReader::new(reader);
+        let decoded = stream.next().await.unwrap().unwrap();
+        assert_eq!(decoded.category, sample.category);
+        assert_eq!(decoded.data, sample.data);
+        assert_eq!(decoded.sequence_count, sample.sequence_count);
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_framed_reader_handles_split_prefix_and_body() {
+        use futures_util::StreamExt;
+
+        let sample = sample_ccsds_sample();
+        let body = sample.to_postcard().unwrap();
+        let mut frame = encode_frame_prefix(body.len());
+        frame.extend_from_slice(&body);
+
+        let (mut writer, reader) = tokio::io::duplex(4096);
+        let mut stream = FramedCcsdsReader::new(reader);
+
+        let mid = frame.len() / 2;
+        writer.write_all(&frame[..mid]).await.unwrap();
+        tokio::task::yield_now().await;
+        writer.write_all(&frame[mid..]).await.unwrap();
+
+        let decoded = stream.next().await.unwrap().unwrap();
+        assert_eq!(decoded.data, sample.data);
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_framed_reader_multiple_frames() {
+        use futures_util::StreamExt;
+
+        let first = sample_ccsds_sample();
+        let mut second = sample_ccsds_sample();
+        second.sequence_count = 43;
+
+        let (mut writer, reader) = tokio::io::duplex(4096);
+        CcsdsPublisher::write_framed(&mut writer, &first)
+            .await
+            .unwrap();
+        CcsdsPublisher::write_framed(&mut writer, &second)
+            .await
+            .unwrap();
+        drop(writer);
+
+        let mut stream = FramedCcsdsReader::new(reader);
+        assert_eq!(
+            stream.next().await.unwrap().unwrap().sequence_count,
+            first.sequence_count
+        );
+        assert_eq!(
+            stream.next().await.unwrap().unwrap().sequence_count,
+            second.sequence_count
+        );
+        assert!(stream.next().await.is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_framed_reader_eof_mid_frame_errors() {
+        use futures_util::StreamExt;
+
+        let sample = sample_ccsds_sample();
+        let body = sample.to_postcard().unwrap();
+        let mut frame = encode_frame_prefix(body.len());
+        frame.extend_from_slice(&body);
+
+        let (mut writer, reader) = tokio::io::duplex(4096);
+        writer.write_all(&frame[..frame.len() - 1]).await.unwrap();
+        drop(writer);
+
+        let mut stream = FramedCcsdsReader::new(reader);
+        let result = stream.next().await.unwrap();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("mid-frame"));
+    }
+
+    // ============================================================================
+    // Sequence Gap / Stats Tests
+    // ============================================================================
+
+    #[test]
+    fn test_record_sequence_first_packet_establishes_baseline() {
+        let mut last_sequence = HashMap::new();
+        let (event, out_of_order) = record_sequence(&mut last_sequence, 0x330, 100);
+        assert!(event.is_none());
+        assert!(!out_of_order);
+        assert_eq!(last_sequence[&0x330], 100);
+    }
+
+    #[test]
+    fn test_record_sequence_in_order_no_gap() {
+        let mut last_sequence = HashMap::new();
+        record_sequence(&mut last_sequence, 0x330, 10);
+        let (event, out_of_order) = record_sequence(&mut last_sequence, 0x330, 11);
+        assert!(event.is_none());
+        assert!(!out_of_order);
+    }
+
+    #[test]
+    fn test_record_sequence_forward_gap_reports_missed_count() {
+        let mut last_sequence = HashMap::new();
+        record_sequence(&mut last_sequence, 0x330, 10);
+        let (event, out_of_order) = record_sequence(&mut last_sequence, 0x330, 15);
+        assert!(!out_of_order);
+        assert_eq!(
+            event,
+            Some(CcsdsEvent::Gap {
+                apid: 0x330,
+                expected: 11,
+                got: 15,
+                missed: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_record_sequence_wraparound_no_false_gap() {
+        let mut last_sequence = HashMap::new();
+        record_sequence(&mut last_sequence, 0x330, 0x3FFF);
+        let (event, out_of_order) = record_sequence(&mut last_sequence, 0x330, 0);
+        assert!(event.is_none());
+        assert!(!out_of_order);
+    }
+
+    #[test]
+    fn test_record_sequence_out_of_order_does_not_report_gap() {
+        let mut last_sequence = HashMap::new();
+        record_sequence(&mut last_sequence, 0x330, 10);
+        // Sequence count fell behind the expected value (11): a duplicate
+        // or reordered delivery, not a forward gap.
+        let (event, out_of_order) = record_sequence(&mut last_sequence, 0x330, 5);
+        assert!(event.is_none());
+        assert!(out_of_order);
+    }
+
+    #[test]
+    fn test_subscriber_stats_default_is_empty() {
+        let stats = SubscriberStats::default();
+        assert_eq!(stats.received, 0);
+        assert_eq!(stats.missed, 0);
+        assert_eq!(stats.out_of_order, 0);
+        assert!(stats.per_apid.is_empty());
+    }
+
+    #[test]
+    fn test_subscriber_stats_record_gap() {
+        let mut stats = SubscriberStats::default();
+        stats.record(
+            0x330,
+            Some(CcsdsEvent::Gap {
+                apid: 0x330,
+                expected: 11,
+                got: 15,
+                missed: 4,
+            }),
+            false,
+        );
+        assert_eq!(stats.received, 1);
+        assert_eq!(stats.missed, 4);
+        assert_eq!(stats.out_of_order, 0);
+        assert_eq!(stats.per_apid[&0x330].received, 1);
+        assert_eq!(stats.per_apid[&0x330].missed, 4);
+    }
+
+    #[test]
+    fn test_subscriber_stats_record_out_of_order() {
+        let mut stats = SubscriberStats::default();
+        stats.record(0x330, None, true);
+        assert_eq!(stats.received, 1);
+        assert_eq!(stats.out_of_order, 1);
+        assert_eq!(stats.per_apid[&0x330].out_of_order, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_reports_gap_event_and_stats_over_udp() {
+        use tokio::time::{timeout, Duration};
+
+        let sub_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let mut subscriber = CcsdsSubscriber::new(sub_config).await.unwrap();
+        let bound_port = subscriber.local_addr().port();
+
+        let pub_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(pub_config).await.unwrap();
+
+        // Bypass the publisher's own auto-incrementing counter so the two
+        // packets sent below land on APID 0x330 with an explicit 4-packet
+        // gap between their sequence counts.
+        let first = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 10, &[0xAA], SEQ_FLAGS_UNSEGMENTED, false)
+            .unwrap();
+        let second = publisher
+            .encode_packet(CcsdsMode::Telemetry, 0x330, 15, &[0xBB], SEQ_FLAGS_UNSEGMENTED, false)
+            .unwrap();
+
+        let socket = tokio::net::UdpSocket::bind("0.0.0.0:0").await.unwrap();
+        socket.send_to(&first, ("127.0.0.1", bound_port)).await.unwrap();
+        timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected the first sample before the timeout")
+            .expect("channel should not be closed");
+
+        socket.send_to(&second, ("127.0.0.1", bound_port)).await.unwrap();
+        timeout(Duration::from_secs(2), subscriber.recv())
+            .await
+            .expect("expected the second sample before the timeout")
+            .expect("channel should not be closed");
+
+        let event = timeout(Duration::from_secs(2), async {
+            loop {
+                if let Some(event) = subscriber.try_recv_event() {
+                    return event;
+                }
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("expected a gap event before the timeout");
+
+        assert_eq!(
+            event,
+            CcsdsEvent::Gap {
+                apid: 0x330,
+                expected: 11,
+                got: 15,
+                missed: 4,
+            }
+        );
+
+        let stats = subscriber.stats();
+        assert_eq!(stats.received, 2);
+        assert_eq!(stats.missed, 4);
+        assert_eq!(stats.per_apid[&0x330].received, 2);
     }
 
     // ============================================================================
@@ -859,4 +4133,72 @@ mod tests {
         let result = publisher.hex_to_bytes("GHIJ");
         assert!(result.is_err());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ccsds_sample_postcard_roundtrip() {
+        let sample = CcsdsSample {
+            category: 48,
+            apid: 0x330,
+            sequence_count: 42,
+            data: vec![0x30, 0x00, 0x10, 0xFF],
+            timestamp: 123456,
+            packet_type: CcsdsMode::Telemetry,
+            time_code: Some(TimeCode::Cuc {
+                seconds: 1_700_000_000,
+                subsec_fraction: 0.5,
+            }),
+            pus_header: Some(PusSecondaryHeader::Tm {
+                service_type: 17,
+                service_subtype: 1,
+                message_type_counter: 9,
+                destination_id: 0x42,
+            }),
+        };
+
+        let encoded = sample.to_postcard().unwrap();
+        let decoded = CcsdsSample::from_postcard(&encoded).unwrap();
+
+        assert_eq!(decoded.category, sample.category);
+        assert_eq!(decoded.apid, sample.apid);
+        assert_eq!(decoded.sequence_count, sample.sequence_count);
+        assert_eq!(decoded.data, sample.data);
+        assert_eq!(decoded.packet_type, sample.packet_type);
+        assert_eq!(decoded.pus_header, sample.pus_header);
+        match (decoded.time_code, sample.time_code) {
+            (
+                Some(TimeCode::Cuc {
+                    seconds: ds,
+                    subsec_fraction: df,
+                }),
+                Some(TimeCode::Cuc {
+                    seconds: ss,
+                    subsec_fraction: sf,
+                }),
+            ) => {
+                assert_eq!(ds, ss);
+                assert!((df - sf).abs() < f64::EPSILON);
+            }
+            _ => panic!("expected decoded time code to be TimeCode::Cuc"),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ccsds_sample_from_postcard_rejects_garbage() {
+        let result = CcsdsSample::from_postcard(&[0xFF, 0xFF, 0xFF]);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_ccsds_config_serde_json_roundtrip() {
+        let config = CcsdsConfig::with_pus(17, 1);
+        let json = serde_json::to_string(&config).unwrap();
+        let back: CcsdsConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(back.pus, config.pus);
+        assert_eq!(back.transport, config.transport);
+        assert_eq!(back.base_apid, config.base_apid);
+    }
 }
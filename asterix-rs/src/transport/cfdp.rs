@@ -0,0 +1,967 @@
+//! CFDP (CCSDS File Delivery Protocol, CCSDS 727.0-B-5) file delivery over CCSDS packets
+//!
+//! This is the sole whole-file-transfer layer over [`CcsdsPublisher`]/[`CcsdsSubscriber`]
+//! in this crate: the actual CCSDS 727.0-B-5 PDU shape, with a common PDU header carrying
+//! configurable-width source/destination entity IDs and transaction sequence number (see
+//! [`CfdpIdWidth`]/[`CommonPduConfig`]), a Metadata PDU carrying LV-encoded filenames and
+//! TLV options, File Data PDUs addressed by byte offset, and an EOF PDU carrying a CRC-32
+//! over the whole file. An earlier, private, minimal PDU format (no real CFDP header, a
+//! fixed legacy modular checksum, no addressing) briefly lived alongside this one as
+//! `ccsds::CcsdsFileSender`/`ccsds::CcsdsFileReceiver`; it duplicated this module's job
+//! and has been removed in favor of this spec-compliant implementation.
+//!
+//! Only unacknowledged (class 1), non-large-file transfers are supported: the transmission
+//! mode, direction, CRC-flag, and large-file-flag header bits are fixed rather than
+//! configurable, and PDU data field lengths/file sizes/offsets are all encoded as 4 octets.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::transport::ccsds::{CcsdsConfig, CcsdsError, CcsdsPublisher, CcsdsSubscriber};
+
+/// Width of the CFDP entity-ID/transaction-sequence-number fields, in
+/// octets (CCSDS 727.0-B-5 Table 5-1 permits up to 8; this crate only
+/// supports the widths ASTERIX ground deployments actually use). The same
+/// width applies to the source entity ID, destination entity ID, and
+/// transaction sequence number within one [`CommonPduConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CfdpIdWidth {
+    /// 1-octet fields
+    One,
+    /// 2-octet fields
+    Two,
+    /// 4-octet fields
+    Four,
+}
+
+impl CfdpIdWidth {
+    fn octets(self) -> usize {
+        match self {
+            CfdpIdWidth::One => 1,
+            CfdpIdWidth::Two => 2,
+            CfdpIdWidth::Four => 4,
+        }
+    }
+
+    /// CCSDS 727.0-B-5 encodes a field's octet count as `octets - 1` in a
+    /// 3-bit sub-field of the common header's fourth octet.
+    fn length_code(self) -> u8 {
+        (self.octets() - 1) as u8
+    }
+
+    fn from_length_code(code: u8) -> Result<Self, CcsdsError> {
+        match code {
+            0 => Ok(CfdpIdWidth::One),
+            1 => Ok(CfdpIdWidth::Two),
+            3 => Ok(CfdpIdWidth::Four),
+            other => Err(CcsdsError::DecodeError(format!(
+                "unsupported CFDP entity/transaction ID width code: {other}"
+            ))),
+        }
+    }
+
+    fn encode(self, value: u32) -> Vec<u8> {
+        let bytes = value.to_be_bytes();
+        bytes[4 - self.octets()..].to_vec()
+    }
+
+    fn decode(self, bytes: &[u8]) -> Result<(u32, &[u8]), CcsdsError> {
+        let n = self.octets();
+        if bytes.len() < n {
+            return Err(CcsdsError::DecodeError(
+                "CFDP PDU truncated: entity/transaction ID field".to_string(),
+            ));
+        }
+        let mut padded = [0u8; 4];
+        padded[4 - n..].copy_from_slice(&bytes[..n]);
+        Ok((u32::from_be_bytes(padded), &bytes[n..]))
+    }
+}
+
+/// Common PDU header fields for one CFDP PDU (CCSDS 727.0-B-5 §5.1), shared
+/// by every PDU within a transaction. Built fresh per transfer by
+/// [`CfdpSender::send_file`] from the sender's configured source entity ID
+/// and ID width plus the transfer's destination entity ID and assigned
+/// transaction sequence number; [`decode_pdu_header`] recovers the same
+/// struct from a received PDU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommonPduConfig {
+    /// Octet width shared by `source_entity_id`, `destination_entity_id`,
+    /// and `transaction_seq_num`
+    pub id_width: CfdpIdWidth,
+    /// Entity ID of the node sending this PDU
+    pub source_entity_id: u32,
+    /// Entity ID of the node this PDU is addressed to
+    pub destination_entity_id: u32,
+    /// Identifies which file transfer this PDU belongs to, scoped to the
+    /// (source, destination) entity ID pair
+    pub transaction_seq_num: u32,
+}
+
+/// A CFDP TLV (Type-Length-Value) option, as carried in a [`MetadataPdu`]'s
+/// `options` (CCSDS 727.0-B-5 §5.4.3). Type codes are not validated against
+/// the spec's registry; this crate passes them through opaquely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CfdpTlv {
+    /// TLV type code
+    pub tlv_type: u8,
+    /// TLV value bytes (at most 255, since the length field is one octet)
+    pub value: Vec<u8>,
+}
+
+/// Shared behavior for the three CFDP PDU content types this module
+/// supports. The common PDU header (entity IDs, transaction sequence
+/// number, PDU type bit) is assembled separately by [`encode_pdu`], since
+/// it's identical across all three -- this trait only covers each PDU's own
+/// content.
+pub trait WritablePdu {
+    /// Whether this is a File Data PDU (`true`) or a File Directive PDU
+    /// (`false`) -- selects the PDU Type bit in the common header.
+    fn is_file_data(&self) -> bool;
+
+    /// Append this PDU's own content (not including the common header) to
+    /// `buf`.
+    fn write_to_bytes(&self, buf: &mut Vec<u8>);
+}
+
+const CFDP_DIRECTIVE_EOF: u8 = 0x04;
+const CFDP_DIRECTIVE_FINISHED: u8 = 0x05;
+const CFDP_DIRECTIVE_METADATA: u8 = 0x07;
+
+/// Locally-assigned checksum-type nibble for the CRC-32 this module uses in
+/// [`MetadataPdu`]'s flags octet and [`EofPdu::file_checksum`] -- CCSDS
+/// 727.0-B-5's own checksum-type registry (SANA) doesn't define a CRC-32
+/// entry in the base spec, so this isn't a real wire-interoperable code.
+const CFDP_CHECKSUM_TYPE_CRC32: u8 = 0x02;
+
+/// Announces an incoming file transfer: final file size, source/destination
+/// file names (LV-encoded: one length octet followed by that many bytes),
+/// and any TLV options.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataPdu {
+    /// Total file size in bytes
+    pub file_size: u64,
+    /// File name at the sending entity
+    pub source_filename: String,
+    /// File name to write at the receiving entity
+    pub destination_filename: String,
+    /// TLV options (e.g. filestore requests); empty for a plain transfer
+    pub options: Vec<CfdpTlv>,
+}
+
+impl WritablePdu for MetadataPdu {
+    fn is_file_data(&self) -> bool {
+        false
+    }
+
+    fn write_to_bytes(&self, buf: &mut Vec<u8>) {
+        buf.push(CFDP_DIRECTIVE_METADATA);
+        // bit7 = closure requested (unsupported, always 0), bits3-0 = checksum type.
+        buf.push(CFDP_CHECKSUM_TYPE_CRC32);
+        buf.extend_from_slice(&(self.file_size as u32).to_be_bytes());
+        write_lv(buf, self.source_filename.as_bytes());
+        write_lv(buf, self.destination_filename.as_bytes());
+        for tlv in &self.options {
+            buf.push(tlv.tlv_type);
+            buf.push(tlv.value.len() as u8);
+            buf.extend_from_slice(&tlv.value);
+        }
+    }
+}
+
+impl MetadataPdu {
+    fn decode(content: &[u8]) -> Result<Self, CcsdsError> {
+        let (&directive, rest) = content
+            .split_first()
+            .ok_or_else(|| CcsdsError::DecodeError("CFDP Metadata PDU truncated".to_string()))?;
+        if directive != CFDP_DIRECTIVE_METADATA {
+            return Err(CcsdsError::DecodeError(format!(
+                "expected CFDP Metadata directive code {CFDP_DIRECTIVE_METADATA:#04x}, got {directive:#04x}"
+            )));
+        }
+        let (_flags, rest) = rest
+            .split_first()
+            .ok_or_else(|| CcsdsError::DecodeError("CFDP Metadata PDU truncated: flags".to_string()))?;
+        if rest.len() < 4 {
+            return Err(CcsdsError::DecodeError(
+                "CFDP Metadata PDU truncated: file size".to_string(),
+            ));
+        }
+        let file_size = u32::from_be_bytes(rest[..4].try_into().unwrap()) as u64;
+        let (source_filename, rest) = read_lv(&rest[4..])?;
+        let (destination_filename, rest) = read_lv(rest)?;
+        let options = read_tlvs(rest)?;
+
+        Ok(MetadataPdu {
+            file_size,
+            source_filename: String::from_utf8_lossy(source_filename).into_owned(),
+            destination_filename: String::from_utf8_lossy(destination_filename).into_owned(),
+            options,
+        })
+    }
+}
+
+/// One segment of file content at a byte `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDataPdu {
+    /// Byte offset of `data` within the file
+    pub offset: u64,
+    /// File content bytes for this segment
+    pub data: Vec<u8>,
+}
+
+impl WritablePdu for FileDataPdu {
+    fn is_file_data(&self) -> bool {
+        true
+    }
+
+    fn write_to_bytes(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&(self.offset as u32).to_be_bytes());
+        buf.extend_from_slice(&self.data);
+    }
+}
+
+impl FileDataPdu {
+    fn decode(content: &[u8]) -> Result<Self, CcsdsError> {
+        if content.len() < 4 {
+            return Err(CcsdsError::DecodeError(
+                "CFDP File Data PDU truncated: offset".to_string(),
+            ));
+        }
+        let offset = u32::from_be_bytes(content[..4].try_into().unwrap()) as u64;
+        Ok(FileDataPdu {
+            offset,
+            data: content[4..].to_vec(),
+        })
+    }
+}
+
+/// Closes the transaction with a condition code, the whole-file CRC-32 the
+/// receiver must verify against, and the final file size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EofPdu {
+    /// CFDP condition code (0 = "No error")
+    pub condition_code: u8,
+    /// CRC-32 over the whole file (see [`crc32`])
+    pub file_checksum: u32,
+    /// Final file size in bytes
+    pub file_size: u64,
+}
+
+impl WritablePdu for EofPdu {
+    fn is_file_data(&self) -> bool {
+        false
+    }
+
+    fn write_to_bytes(&self, buf: &mut Vec<u8>) {
+        buf.push(CFDP_DIRECTIVE_EOF);
+        buf.push(self.condition_code << 4);
+        buf.extend_from_slice(&self.file_checksum.to_be_bytes());
+        buf.extend_from_slice(&(self.file_size as u32).to_be_bytes());
+    }
+}
+
+impl EofPdu {
+    fn decode(content: &[u8]) -> Result<Self, CcsdsError> {
+        if content.len() < 10 {
+            return Err(CcsdsError::DecodeError("CFDP EOF PDU truncated".to_string()));
+        }
+        if content[0] != CFDP_DIRECTIVE_EOF {
+            return Err(CcsdsError::DecodeError(format!(
+                "expected CFDP EOF directive code {CFDP_DIRECTIVE_EOF:#04x}, got {:#04x}",
+                content[0]
+            )));
+        }
+        let condition_code = content[1] >> 4;
+        let file_checksum = u32::from_be_bytes(content[2..6].try_into().unwrap());
+        let file_size = u32::from_be_bytes(content[6..10].try_into().unwrap()) as u64;
+        Ok(EofPdu {
+            condition_code,
+            file_checksum,
+            file_size,
+        })
+    }
+}
+
+/// Closes out a transaction from the receiving entity's side: a condition
+/// code, whether delivery completed, and the receiver's final file status.
+/// CCSDS 727.0-B-5 normally reserves this for acknowledged (class 2)
+/// transfers, but also lets an unacknowledged sender request one via the
+/// Metadata PDU's closure flag; this module only provides the PDU shape
+/// itself (this type, plus [`WritablePdu`]/decode support below) -- sending
+/// one back to the file's source entity requires a publisher on the
+/// receiver side and a matching receive loop on the sender side, which
+/// [`CfdpReceiver`]/[`CfdpSender`] don't implement yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FinishedPdu {
+    /// CFDP condition code (0 = "No error")
+    pub condition_code: u8,
+    /// Whether the file was delivered completely (`true`) or only partially
+    /// (`false`, e.g. after an aborted transfer)
+    pub delivery_complete: bool,
+    /// Receiver's file status code (CCSDS 727.0-B-5 Table 5-6); `0` means
+    /// "file status unreported"
+    pub file_status: u8,
+}
+
+impl WritablePdu for FinishedPdu {
+    fn is_file_data(&self) -> bool {
+        false
+    }
+
+    fn write_to_bytes(&self, buf: &mut Vec<u8>) {
+        buf.push(CFDP_DIRECTIVE_FINISHED);
+        // bits7-4 = condition code, bit3 = spare, bit2 = delivery code
+        // (0 = complete, 1 = incomplete), bits1-0 = file status.
+        let delivery_code: u8 = if self.delivery_complete { 0 } else { 1 };
+        buf.push((self.condition_code << 4) | (delivery_code << 2) | (self.file_status & 0x03));
+    }
+}
+
+impl FinishedPdu {
+    fn decode(content: &[u8]) -> Result<Self, CcsdsError> {
+        if content.len() < 2 {
+            return Err(CcsdsError::DecodeError(
+                "CFDP Finished PDU truncated".to_string(),
+            ));
+        }
+        if content[0] != CFDP_DIRECTIVE_FINISHED {
+            return Err(CcsdsError::DecodeError(format!(
+                "expected CFDP Finished directive code {CFDP_DIRECTIVE_FINISHED:#04x}, got {:#04x}",
+                content[0]
+            )));
+        }
+        let condition_code = content[1] >> 4;
+        let delivery_complete = (content[1] >> 2) & 0x1 == 0;
+        let file_status = content[1] & 0x03;
+        Ok(FinishedPdu {
+            condition_code,
+            delivery_complete,
+            file_status,
+        })
+    }
+}
+
+fn write_lv(buf: &mut Vec<u8>, value: &[u8]) {
+    buf.push(value.len() as u8);
+    buf.extend_from_slice(value);
+}
+
+fn read_lv(bytes: &[u8]) -> Result<(&[u8], &[u8]), CcsdsError> {
+    let (&len, rest) = bytes
+        .split_first()
+        .ok_or_else(|| CcsdsError::DecodeError("CFDP PDU truncated: LV length".to_string()))?;
+    let len = len as usize;
+    if rest.len() < len {
+        return Err(CcsdsError::DecodeError(
+            "CFDP PDU truncated: LV value".to_string(),
+        ));
+    }
+    Ok((&rest[..len], &rest[len..]))
+}
+
+fn read_tlvs(mut bytes: &[u8]) -> Result<Vec<CfdpTlv>, CcsdsError> {
+    let mut tlvs = Vec::new();
+    while !bytes.is_empty() {
+        if bytes.len() < 2 {
+            return Err(CcsdsError::DecodeError(
+                "CFDP PDU truncated: TLV header".to_string(),
+            ));
+        }
+        let tlv_type = bytes[0];
+        let len = bytes[1] as usize;
+        if bytes.len() < 2 + len {
+            return Err(CcsdsError::DecodeError(
+                "CFDP PDU truncated: TLV value".to_string(),
+            ));
+        }
+        tlvs.push(CfdpTlv {
+            tlv_type,
+            value: bytes[2..2 + len].to_vec(),
+        });
+        bytes = &bytes[2 + len..];
+    }
+    Ok(tlvs)
+}
+
+/// Encode `pdu`'s common PDU header (CCSDS 727.0-B-5 §5.1) around its
+/// own content, per `config`'s addressing. Transmission mode is always
+/// unacknowledged (class 1), direction is always "toward file receiver",
+/// and the CRC-flag/large-file-flag bits are always unset, matching this
+/// module's [module-level][crate::transport::cfdp] scope.
+pub fn encode_pdu<P: WritablePdu>(config: &CommonPduConfig, pdu: &P) -> Vec<u8> {
+    let mut content = Vec::new();
+    pdu.write_to_bytes(&mut content);
+
+    let mut out = Vec::with_capacity(4 + 3 * config.id_width.octets() + content.len());
+
+    let pdu_type_bit: u8 = if pdu.is_file_data() { 1 } else { 0 };
+    // version(3)=0, pdu_type(1), direction(1)=0 ("toward file receiver"),
+    // transmission mode(1)=1 (unacknowledged), crc flag(1)=0, large file flag(1)=0.
+    out.push((pdu_type_bit << 4) | (1 << 2));
+    out.extend_from_slice(&(content.len() as u16).to_be_bytes());
+    // seg control(1)=0, length of entity IDs(3), seg metadata flag(1)=0, length of txn seq num(3).
+    out.push((config.id_width.length_code() << 4) | config.id_width.length_code());
+    out.extend_from_slice(&config.id_width.encode(config.source_entity_id));
+    out.extend_from_slice(&config.id_width.encode(config.transaction_seq_num));
+    out.extend_from_slice(&config.id_width.encode(config.destination_entity_id));
+    out.extend_from_slice(&content);
+    out
+}
+
+/// Decode a PDU's common header, returning the recovered [`CommonPduConfig`],
+/// whether it's a File Data PDU, and the remaining content bytes (everything
+/// [`encode_pdu`] wrote via [`WritablePdu::write_to_bytes`]).
+pub fn decode_pdu_header(bytes: &[u8]) -> Result<(CommonPduConfig, bool, &[u8]), CcsdsError> {
+    if bytes.len() < 4 {
+        return Err(CcsdsError::DecodeError("CFDP PDU truncated: header".to_string()));
+    }
+    let is_file_data = (bytes[0] >> 4) & 0x1 == 1;
+    let data_len = u16::from_be_bytes([bytes[1], bytes[2]]) as usize;
+    let id_width = CfdpIdWidth::from_length_code((bytes[3] >> 4) & 0x7)?;
+
+    let rest = &bytes[4..];
+    let (source_entity_id, rest) = id_width.decode(rest)?;
+    let (transaction_seq_num, rest) = id_width.decode(rest)?;
+    let (destination_entity_id, rest) = id_width.decode(rest)?;
+
+    if rest.len() < data_len {
+        return Err(CcsdsError::DecodeError(
+            "CFDP PDU truncated: data field".to_string(),
+        ));
+    }
+
+    Ok((
+        CommonPduConfig {
+            id_width,
+            source_entity_id,
+            destination_entity_id,
+            transaction_seq_num,
+        },
+        is_file_data,
+        &rest[..data_len],
+    ))
+}
+
+/// Compute the standard CRC-32 (polynomial 0xEDB8_8320 reflected, initial
+/// value 0xFFFF_FFFF, final XOR 0xFFFF_FFFF -- the CRC-32/ISO-HDLC variant
+/// used by zip/Ethernet) checksum over `data`, used for [`EofPdu::file_checksum`].
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Sends whole files over CCSDS using the CFDP PDU shapes this module
+/// implements, reusing a [`CcsdsPublisher`]'s socket transport so large
+/// ASTERIX recordings can be shipped without looping `publish` per record.
+pub struct CfdpSender {
+    publisher: CcsdsPublisher,
+    category: u8,
+    max_packet_length: usize,
+    source_entity_id: u32,
+    id_width: CfdpIdWidth,
+    next_transaction_seq_num: AtomicU32,
+}
+
+impl CfdpSender {
+    /// Create a new CFDP sender over the same transport a [`CcsdsPublisher`]
+    /// would use for `config`, publishing PDUs under `category`'s APID and
+    /// addressing them as `source_entity_id` with `id_width`-wide entity
+    /// IDs/transaction sequence numbers.
+    pub async fn new(
+        config: CcsdsConfig,
+        category: u8,
+        source_entity_id: u32,
+        id_width: CfdpIdWidth,
+    ) -> Result<Self, CcsdsError> {
+        let max_packet_length = config.max_packet_length;
+        Ok(Self {
+            publisher: CcsdsPublisher::new(config).await?,
+            category,
+            max_packet_length,
+            source_entity_id,
+            id_width,
+            next_transaction_seq_num: AtomicU32::new(0),
+        })
+    }
+
+    /// Send the file at `path` to `dest_entity_id` as a Metadata PDU, one
+    /// File Data PDU per `max_packet_length`-sized segment, and a closing
+    /// EOF PDU carrying the whole-file [`crc32`]. Assigns the next
+    /// transaction sequence number from an internal counter shared by
+    /// every call on this sender.
+    pub async fn send_file(&self, path: &Path, dest_entity_id: u32) -> Result<(), CcsdsError> {
+        let data = std::fs::read(path)
+            .map_err(|e| CcsdsError::NetworkError(format!("failed to read {path:?}: {e}")))?;
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(|| CcsdsError::NetworkError(format!("{path:?} has no valid file name")))?
+            .to_string();
+
+        let transaction_seq_num = self.next_transaction_seq_num.fetch_add(1, Ordering::Relaxed);
+        let config = CommonPduConfig {
+            id_width: self.id_width,
+            source_entity_id: self.source_entity_id,
+            destination_entity_id: dest_entity_id,
+            transaction_seq_num,
+        };
+
+        self.publisher
+            .publish_raw(
+                self.category,
+                &encode_pdu(
+                    &config,
+                    &MetadataPdu {
+                        file_size: data.len() as u64,
+                        source_filename: file_name.clone(),
+                        destination_filename: file_name,
+                        options: Vec::new(),
+                    },
+                ),
+            )
+            .await?;
+
+        // Leave headroom for the File Data PDU's common header plus its
+        // 4-byte offset prefix.
+        let segment_len = self
+            .max_packet_length
+            .saturating_sub(4 + 3 * self.id_width.octets() + 4)
+            .max(1);
+
+        for (index, chunk) in data.chunks(segment_len).enumerate() {
+            let offset = (index * segment_len) as u64;
+            self.publisher
+                .publish_raw(
+                    self.category,
+                    &encode_pdu(
+                        &config,
+                        &FileDataPdu {
+                            offset,
+                            data: chunk.to_vec(),
+                        },
+                    ),
+                )
+                .await?;
+        }
+
+        self.publisher
+            .publish_raw(
+                self.category,
+                &encode_pdu(
+                    &config,
+                    &EofPdu {
+                        condition_code: 0,
+                        file_checksum: crc32(&data),
+                        file_size: data.len() as u64,
+                    },
+                ),
+            )
+            .await
+    }
+}
+
+/// Receives whole files sent by a [`CfdpSender`], reassembling File Data
+/// PDUs by offset and verifying the EOF PDU's CRC-32 before writing the
+/// result to disk.
+pub struct CfdpReceiver {
+    subscriber: CcsdsSubscriber,
+    max_file_bytes: usize,
+}
+
+/// Default [`CfdpReceiver::max_file_bytes`]: 256 MiB.
+const DEFAULT_MAX_FILE_BYTES: usize = 256 * 1024 * 1024;
+
+impl CfdpReceiver {
+    /// Create a new CFDP receiver over the same transport a
+    /// [`CcsdsSubscriber`] would use for `config`, capping reassembled file
+    /// size at [`DEFAULT_MAX_FILE_BYTES`] (see [`Self::with_max_file_bytes`]
+    /// to change it)
+    pub async fn new(config: CcsdsConfig) -> Result<Self, CcsdsError> {
+        Ok(Self {
+            subscriber: CcsdsSubscriber::new(config).await?,
+            max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+        })
+    }
+
+    /// Cap how large a single file transfer's reassembly buffer is allowed
+    /// to grow, checked against both a Metadata PDU's declared `file_size`
+    /// and a File Data PDU's `offset + data.len()` before either allocates
+    /// or resizes the buffer -- both fields come straight off the wire from
+    /// an untrusted sender.
+    pub fn with_max_file_bytes(mut self, max_file_bytes: usize) -> Self {
+        self.max_file_bytes = max_file_bytes;
+        self
+    }
+
+    /// The underlying socket's bound local address -- useful when
+    /// `config.udp_port` is `0`, to discover which port the OS actually
+    /// assigned (see [`CcsdsSubscriber::local_addr`])
+    pub fn local_addr(&self) -> std::net::SocketAddr {
+        self.subscriber.local_addr()
+    }
+
+    /// Receive one complete file transfer (Metadata PDU through EOF PDU),
+    /// write it to `dest_dir` under its transmitted destination file name,
+    /// and return the written path. Returns [`CcsdsError::ChannelClosed`] if
+    /// the sender disconnects mid-transfer.
+    pub async fn recv_file(&mut self, dest_dir: &Path) -> Result<PathBuf, CcsdsError> {
+        let mut destination_filename: Option<String> = None;
+        let mut buffer: Vec<u8> = Vec::new();
+
+        loop {
+            let sample = self
+                .subscriber
+                .recv()
+                .await
+                .ok_or(CcsdsError::ChannelClosed)?;
+            let (_config, is_file_data, content) = decode_pdu_header(&sample.data)?;
+
+            if is_file_data {
+                let pdu = FileDataPdu::decode(content)?;
+                let start = pdu.offset as usize;
+                let end = start + pdu.data.len();
+                if end > self.max_file_bytes {
+                    return Err(CcsdsError::DecodeError(format!(
+                        "CFDP File Data PDU offset+length {end} exceeds max_file_bytes {}",
+                        self.max_file_bytes
+                    )));
+                }
+                if end > buffer.len() {
+                    buffer.resize(end, 0);
+                }
+                buffer[start..end].copy_from_slice(&pdu.data);
+                continue;
+            }
+
+            let directive = *content
+                .first()
+                .ok_or_else(|| CcsdsError::DecodeError("CFDP PDU truncated: directive code".to_string()))?;
+            match directive {
+                CFDP_DIRECTIVE_METADATA => {
+                    let pdu = MetadataPdu::decode(content)?;
+                    if pdu.file_size as usize > self.max_file_bytes {
+                        return Err(CcsdsError::DecodeError(format!(
+                            "CFDP Metadata PDU file_size {} exceeds max_file_bytes {}",
+                            pdu.file_size, self.max_file_bytes
+                        )));
+                    }
+                    buffer = vec![0u8; pdu.file_size as usize];
+                    destination_filename = Some(pdu.destination_filename);
+                }
+                CFDP_DIRECTIVE_EOF => {
+                    let pdu = EofPdu::decode(content)?;
+                    if pdu.condition_code != 0 {
+                        return Err(CcsdsError::NetworkError(format!(
+                            "CFDP transfer aborted with condition code {}",
+                            pdu.condition_code
+                        )));
+                    }
+                    if crc32(&buffer) != pdu.file_checksum {
+                        return Err(CcsdsError::NetworkError(
+                            "CFDP CRC-32 checksum mismatch".to_string(),
+                        ));
+                    }
+                    let destination_filename = destination_filename.ok_or_else(|| {
+                        CcsdsError::NetworkError(
+                            "CFDP EOF received before Metadata PDU".to_string(),
+                        )
+                    })?;
+                    let dest_path = dest_dir.join(destination_filename);
+                    std::fs::write(&dest_path, &buffer).map_err(|e| {
+                        CcsdsError::NetworkError(format!("failed to write {dest_path:?}: {e}"))
+                    })?;
+                    return Ok(dest_path);
+                }
+                other => {
+                    return Err(CcsdsError::DecodeError(format!(
+                        "unknown CFDP directive code: {other:#04x}"
+                    )))
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32_known_vector() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_cfdp_id_width_roundtrip() {
+        for width in [CfdpIdWidth::One, CfdpIdWidth::Two, CfdpIdWidth::Four] {
+            let encoded = width.encode(0x42);
+            assert_eq!(encoded.len(), width.octets());
+            let (decoded, rest) = width.decode(&encoded).unwrap();
+            assert_eq!(decoded, 0x42);
+            assert!(rest.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_cfdp_id_width_length_code_roundtrip() {
+        for width in [CfdpIdWidth::One, CfdpIdWidth::Two, CfdpIdWidth::Four] {
+            assert_eq!(CfdpIdWidth::from_length_code(width.length_code()).unwrap(), width);
+        }
+    }
+
+    #[test]
+    fn test_metadata_pdu_roundtrip() {
+        let pdu = MetadataPdu {
+            file_size: 12345,
+            source_filename: "recording.ast".to_string(),
+            destination_filename: "recording-copy.ast".to_string(),
+            options: vec![CfdpTlv {
+                tlv_type: 0x01,
+                value: vec![0xAA, 0xBB],
+            }],
+        };
+        let mut content = Vec::new();
+        pdu.write_to_bytes(&mut content);
+        assert_eq!(MetadataPdu::decode(&content).unwrap(), pdu);
+    }
+
+    #[test]
+    fn test_file_data_pdu_roundtrip() {
+        let pdu = FileDataPdu {
+            offset: 4096,
+            data: vec![0x01, 0x02, 0x03],
+        };
+        let mut content = Vec::new();
+        pdu.write_to_bytes(&mut content);
+        assert_eq!(FileDataPdu::decode(&content).unwrap(), pdu);
+    }
+
+    #[test]
+    fn test_eof_pdu_roundtrip() {
+        let pdu = EofPdu {
+            condition_code: 0,
+            file_checksum: 0xDEAD_BEEF,
+            file_size: 98765,
+        };
+        let mut content = Vec::new();
+        pdu.write_to_bytes(&mut content);
+        assert_eq!(EofPdu::decode(&content).unwrap(), pdu);
+    }
+
+    #[test]
+    fn test_finished_pdu_roundtrip() {
+        let pdu = FinishedPdu {
+            condition_code: 0,
+            delivery_complete: true,
+            file_status: 0,
+        };
+        let mut content = Vec::new();
+        pdu.write_to_bytes(&mut content);
+        assert_eq!(FinishedPdu::decode(&content).unwrap(), pdu);
+    }
+
+    #[test]
+    fn test_finished_pdu_incomplete_delivery_roundtrip() {
+        let pdu = FinishedPdu {
+            condition_code: 0x0E,
+            delivery_complete: false,
+            file_status: 0x02,
+        };
+        let mut content = Vec::new();
+        pdu.write_to_bytes(&mut content);
+        assert_eq!(FinishedPdu::decode(&content).unwrap(), pdu);
+    }
+
+    #[test]
+    fn test_encode_decode_pdu_header_roundtrip() {
+        let config = CommonPduConfig {
+            id_width: CfdpIdWidth::Two,
+            source_entity_id: 0x0A0B,
+            destination_entity_id: 0x0C0D,
+            transaction_seq_num: 7,
+        };
+        let pdu = FileDataPdu {
+            offset: 0,
+            data: vec![0x11, 0x22],
+        };
+        let encoded = encode_pdu(&config, &pdu);
+        let (decoded_config, is_file_data, content) = decode_pdu_header(&encoded).unwrap();
+        assert_eq!(decoded_config, config);
+        assert!(is_file_data);
+        assert_eq!(FileDataPdu::decode(content).unwrap(), pdu);
+    }
+
+    #[test]
+    fn test_decode_pdu_header_rejects_truncated_header() {
+        let result = decode_pdu_header(&[0x00, 0x00]);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_cfdp_sender_and_receiver_roundtrip_over_udp() {
+        use tokio::time::{timeout, Duration};
+
+        let src_path = std::env::temp_dir().join(format!(
+            "asterix-cfdp-test-src-{}",
+            std::process::id()
+        ));
+        let dest_dir = std::env::temp_dir().join(format!(
+            "asterix-cfdp-test-dest-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dest_dir).unwrap();
+        std::fs::write(&src_path, b"hello CFDP world, this is a test recording").unwrap();
+
+        let receiver_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let mut receiver = CfdpReceiver::new(receiver_config).await.unwrap();
+        let bound_port = receiver.local_addr().port();
+
+        let sender_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            max_packet_length: 32,
+            ..CcsdsConfig::default()
+        };
+        let sender = CfdpSender::new(sender_config, 48, 0x01, CfdpIdWidth::Two)
+            .await
+            .unwrap();
+
+        sender.send_file(&src_path, 0x02).await.unwrap();
+
+        let received_path = timeout(Duration::from_secs(2), receiver.recv_file(&dest_dir))
+            .await
+            .expect("expected a completed transfer before the timeout")
+            .expect("transfer should succeed");
+
+        let received = std::fs::read(&received_path).unwrap();
+        assert_eq!(received, b"hello CFDP world, this is a test recording");
+
+        let _ = std::fs::remove_file(&src_path);
+        let _ = std::fs::remove_dir_all(&dest_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_recv_file_rejects_metadata_pdu_declaring_oversized_file_size() {
+        let receiver_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let mut receiver = CfdpReceiver::new(receiver_config)
+            .await
+            .unwrap()
+            .with_max_file_bytes(1024);
+        let bound_port = receiver.local_addr().port();
+
+        let publisher_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(publisher_config).await.unwrap();
+
+        let config = CommonPduConfig {
+            id_width: CfdpIdWidth::Two,
+            source_entity_id: 0x01,
+            destination_entity_id: 0x02,
+            transaction_seq_num: 0,
+        };
+        // Declares a file_size far past the 1024-byte cap, with no actual
+        // file data to back it -- must be rejected before `recv_file`
+        // allocates a buffer for it.
+        publisher
+            .publish_raw(
+                48,
+                &encode_pdu(
+                    &config,
+                    &MetadataPdu {
+                        file_size: 0xFFFF_FFFF,
+                        source_filename: "huge.ast".to_string(),
+                        destination_filename: "huge.ast".to_string(),
+                        options: Vec::new(),
+                    },
+                ),
+            )
+            .await
+            .unwrap();
+
+        let dest_dir = std::env::temp_dir().join(format!(
+            "asterix-cfdp-test-oversized-dest-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dest_dir).unwrap();
+
+        let result = tokio::time::timeout(tokio::time::Duration::from_secs(2), receiver.recv_file(&dest_dir))
+            .await
+            .expect("expected a rejection before the timeout");
+        assert!(matches!(result, Err(CcsdsError::DecodeError(_))));
+
+        let _ = std::fs::remove_dir_all(&dest_dir);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_recv_file_rejects_file_data_pdu_offset_past_max_file_bytes() {
+        let receiver_config = CcsdsConfig {
+            udp_port: Some(0),
+            ..CcsdsConfig::default()
+        };
+        let mut receiver = CfdpReceiver::new(receiver_config)
+            .await
+            .unwrap()
+            .with_max_file_bytes(1024);
+        let bound_port = receiver.local_addr().port();
+
+        let publisher_config = CcsdsConfig {
+            udp_port: Some(bound_port),
+            ..CcsdsConfig::default()
+        };
+        let publisher = CcsdsPublisher::new(publisher_config).await.unwrap();
+
+        let config = CommonPduConfig {
+            id_width: CfdpIdWidth::Two,
+            source_entity_id: 0x01,
+            destination_entity_id: 0x02,
+            transaction_seq_num: 0,
+        };
+        // No Metadata PDU at all -- just a File Data PDU whose offset alone
+        // puts it past the cap, must be rejected before `recv_file` resizes
+        // a buffer to reach it.
+        publisher
+            .publish_raw(
+                48,
+                &encode_pdu(
+                    &config,
+                    &FileDataPdu {
+                        offset: 0xFFFF_FFFF,
+                        data: vec![0x01, 0x02],
+                    },
+                ),
+            )
+            .await
+            .unwrap();
+
+        let dest_dir = std::env::temp_dir().join(format!(
+            "asterix-cfdp-test-oversized-offset-dest-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dest_dir).unwrap();
+
+        let result = tokio::time::timeout(tokio::time::Duration::from_secs(2), receiver.recv_file(&dest_dir))
+            .await
+            .expect("expected a rejection before the timeout");
+        assert!(matches!(result, Err(CcsdsError::DecodeError(_))));
+
+        let _ = std::fs::remove_dir_all(&dest_dir);
+    }
+}
@@ -28,6 +28,18 @@
 //!   <method name="GetVersion">
 //!     <arg type="s" name="version" direction="out"/>
 //!   </method>
+//!   <method name="Negotiate">
+//!     <arg type="u" name="client_proto" direction="in"/>
+//!     <arg type="u" name="server_proto" direction="out"/>
+//!     <arg type="as" name="capabilities" direction="out"/>
+//!   </method>
+//!   <signal name="RecordParsed">
+//!     <arg type="y" name="category"/>
+//!     <arg type="t" name="timestamp_ms"/>
+//!     <arg type="u" name="crc"/>
+//!     <arg type="u" name="item_count"/>
+//!     <arg type="s" name="hex_data"/>
+//!   </signal>
 //!   <signal name="AsterixReceived">
 //!     <arg type="y" name="category"/>
 //!     <arg type="u" name="length"/>
@@ -57,13 +69,36 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Subscribing to Parsed Records
+//!
+//! ```no_run
+//! use asterix::transport::dbus::{DbusClient, DbusConfig};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = DbusClient::new(DbusConfig::default())?;
+//!     let subscription = client.subscribe_parsed()?;
+//!
+//!     for signal in subscription {
+//!         let signal = signal?;
+//!         println!("CAT{:03} record ({} items)", signal.category, signal.item_count);
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
 
 use std::fmt;
+use std::os::fd::{AsRawFd, RawFd};
 
 use zbus::blocking::Connection;
 use zbus::interface;
 
 use crate::error::AsterixError;
+use crate::hex::from_hex;
+use crate::types::ParseOptions;
+
+use super::rpc::ParserTransport;
 
 /// Error type for D-Bus transport operations
 #[derive(Debug)]
@@ -76,6 +111,11 @@ pub enum DbusError {
     MethodError(String),
     /// Parse error
     ParseError(String),
+    /// The service's protocol version (carried back from [`DbusClient::new`]'s
+    /// [`Negotiate`](AsterixParser::negotiate) handshake) is outside this
+    /// client's supported range. `0` means the service didn't support
+    /// `Negotiate` at all (pre-negotiation service).
+    ProtocolMismatch(u32),
 }
 
 impl fmt::Display for DbusError {
@@ -85,6 +125,11 @@ impl fmt::Display for DbusError {
             DbusError::ServiceError(msg) => write!(f, "D-Bus service error: {msg}"),
             DbusError::MethodError(msg) => write!(f, "D-Bus method error: {msg}"),
             DbusError::ParseError(msg) => write!(f, "Parse error: {msg}"),
+            DbusError::ProtocolMismatch(server_proto) => write!(
+                f,
+                "D-Bus service protocol version {server_proto} is not supported by this client \
+                 (supports {MIN_SUPPORTED_PROTOCOL}..={PROTOCOL_VERSION})"
+            ),
         }
     }
 }
@@ -129,6 +174,14 @@ pub struct DbusConfig {
 
     /// Whether to emit signals on parse
     pub emit_signals: bool,
+
+    /// Disable zbus's background executor thread so the caller must drive
+    /// message processing manually via [`DbusService::process_incoming`] /
+    /// [`DbusService::poll_once`]
+    ///
+    /// Lets the service run inline with an external epoll/mio/tokio reactor
+    /// on a single thread instead of dedicating one to [`DbusService::run_for`].
+    pub single_threaded: bool,
 }
 
 impl Default for DbusConfig {
@@ -138,6 +191,7 @@ impl Default for DbusConfig {
             object_path: "/com/asterix/Parser".to_string(),
             bus_type: BusType::Session,
             emit_signals: true,
+            single_threaded: false,
         }
     }
 }
@@ -166,38 +220,153 @@ impl DbusConfig {
     }
 }
 
+/// Wire-friendly subset of [`ParseOptions`] a D-Bus method signature can carry
+///
+/// D-Bus signatures can't express `ParseOptions` directly (it carries a
+/// `mode` enum, a `filter_source` tuple, and an arbitrary `filter` closure),
+/// so this is the `(bool, optional u8, optional u32)` struct that actually
+/// crosses the bus for `ParseWithOptions`/`ParseHexWithOptions`; everything
+/// else in `ParseOptions` keeps its default on the remote side.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, zbus::zvariant::Type)]
+pub struct RpcParseOptions {
+    /// Include descriptions and metadata in output
+    pub verbose: bool,
+    /// Only parse records of this category (`None` = all categories)
+    pub filter_category: Option<u8>,
+    /// Maximum number of records to parse (`None` = unlimited)
+    pub max_records: Option<u32>,
+}
+
+impl From<RpcParseOptions> for ParseOptions {
+    fn from(options: RpcParseOptions) -> Self {
+        Self {
+            verbose: options.verbose,
+            filter_category: options.filter_category,
+            max_records: options.max_records.map(|n| n as usize),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&ParseOptions> for RpcParseOptions {
+    fn from(options: &ParseOptions) -> Self {
+        Self {
+            verbose: options.verbose,
+            filter_category: options.filter_category,
+            max_records: options.max_records.map(|n| n as u32),
+        }
+    }
+}
+
+/// `com.asterix.Parser` protocol version this crate speaks, exchanged by
+/// [`AsterixParserInterface::negotiate`]/[`DbusClient::new`]'s handshake.
+/// Bump this whenever a wire-incompatible change is made to the interface
+/// (a method's argument/return types change, not just a new method added).
+const PROTOCOL_VERSION: u32 = 1;
+
+/// Oldest service protocol version this client can still talk to. Equal to
+/// [`PROTOCOL_VERSION`] until a future protocol bump needs to stay
+/// compatible with an older deployed service.
+const MIN_SUPPORTED_PROTOCOL: u32 = 1;
+
+/// ASTERIX categories actually registered in the initialized spec registry
+/// right now, shared by [`AsterixParserInterface::get_categories`] and
+/// [`capabilities`] so both advertise the same list.
+///
+/// Queries [`crate::is_category_defined`] rather than returning a hard-coded
+/// list, so a service built against a custom/site-specific set of category
+/// XMLs (see [`crate::init_config_dir_with_drop_ins`]) reports what it can
+/// actually decode instead of the common-category list every build used to
+/// claim regardless of what was loaded.
+fn supported_categories() -> Vec<u8> {
+    (0..=u8::MAX)
+        .filter(|&category| crate::is_category_defined(category))
+        .collect()
+}
+
+/// Schema of the fields [`RpcParseOptions`] accepts, as `(key, description)`
+/// pairs, so a client can discover valid `ParseWithOptions`/
+/// `ParseHexWithOptions` keys without reading this crate's source.
+fn parse_options_schema() -> Vec<(String, String)> {
+    vec![
+        (
+            "verbose".to_string(),
+            "bool — include descriptions and metadata in output".to_string(),
+        ),
+        (
+            "filter_category".to_string(),
+            "optional u8 — only parse records of this category".to_string(),
+        ),
+        (
+            "max_records".to_string(),
+            "optional u32 — maximum number of records to parse".to_string(),
+        ),
+    ]
+}
+
+/// Capabilities this build of the service advertises via
+/// [`AsterixParserInterface::negotiate`]. `"signals"` and `"parse_options"`
+/// describe RPC-level features; `"categories:..."` lists the categories
+/// [`AsterixParserInterface::get_categories`] returns, so a client can tell
+/// which categories are supported without a separate round trip.
+fn capabilities() -> Vec<String> {
+    let categories = supported_categories()
+        .iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    vec![
+        "signals".to_string(),
+        "parse_options".to_string(),
+        format!("categories:{categories}"),
+    ]
+}
+
 /// D-Bus interface implementation for ASTERIX parser
 struct AsterixParserInterface {
-    #[allow(dead_code)]
     emit_signals: bool,
+    connection: Connection,
+    object_path: String,
+    shutdown: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
 #[interface(name = "com.asterix.Parser")]
 impl AsterixParserInterface {
     /// Parse raw ASTERIX bytes and return JSON result
     fn parse(&self, data: Vec<u8>) -> Result<String, zbus::fdo::Error> {
-        use crate::{parse, ParseOptions};
+        self.parse_records(&data, ParseOptions::default())
+    }
 
-        let records = parse(&data, ParseOptions::default())
+    /// Parse hex-encoded ASTERIX data and return JSON result
+    fn parse_hex(&self, hex_data: String) -> Result<String, zbus::fdo::Error> {
+        let bytes = hex_to_bytes(&hex_data)
             .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
+        self.parse_records(&bytes, ParseOptions::default())
+    }
 
-        #[cfg(feature = "serde")]
-        {
-            serde_json::to_string(&records)
-                .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
-        }
-
-        #[cfg(not(feature = "serde"))]
-        {
-            Ok(format!("Parsed {} records", records.len()))
-        }
+    /// Parse raw ASTERIX bytes with explicit [`ParseOptions`], return JSON result
+    ///
+    /// Lets remote callers drive the same `verbose`/`filter_category`/
+    /// `max_records` knobs the in-process [`crate::parse`] exposes, instead
+    /// of being stuck with the defaults [`Self::parse`] uses.
+    fn parse_with_options(
+        &self,
+        data: Vec<u8>,
+        options: RpcParseOptions,
+    ) -> Result<String, zbus::fdo::Error> {
+        self.parse_records(&data, options.into())
     }
 
-    /// Parse hex-encoded ASTERIX data and return JSON result
-    fn parse_hex(&self, hex_data: String) -> Result<String, zbus::fdo::Error> {
+    /// Parse hex-encoded ASTERIX data with explicit [`ParseOptions`]
+    fn parse_hex_with_options(
+        &self,
+        hex_data: String,
+        options: RpcParseOptions,
+    ) -> Result<String, zbus::fdo::Error> {
         let bytes = hex_to_bytes(&hex_data)
             .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
-        self.parse(bytes)
+        self.parse_records(&bytes, options.into())
     }
 
     /// Get ASTERIX library version
@@ -207,56 +376,387 @@ impl AsterixParserInterface {
 
     /// Get supported ASTERIX categories
     fn get_categories(&self) -> Vec<u8> {
-        // Common ASTERIX categories
-        vec![1, 2, 4, 8, 10, 19, 20, 21, 23, 25, 31, 32, 34, 48, 62, 63, 65, 240, 247, 252]
+        supported_categories()
     }
 
     /// Check if the service is healthy
     fn health_check(&self) -> bool {
         true
     }
+
+    /// Negotiate protocol version and capabilities with a client
+    ///
+    /// `client_proto` is informational only on the server side today (every
+    /// version this service has ever spoken is `PROTOCOL_VERSION`), but
+    /// having the client send it keeps the method signature stable once a
+    /// server needs to branch on it for backwards compatibility. Returns
+    /// this service's own `PROTOCOL_VERSION` and its advertised
+    /// [`capabilities`], so [`DbusClient::new`] can fail fast with
+    /// [`DbusError::ProtocolMismatch`] instead of a confusing `MethodError`
+    /// partway through some later, unrelated call.
+    fn negotiate(&self, client_proto: u32) -> (u32, Vec<String>) {
+        let _ = client_proto;
+        (PROTOCOL_VERSION, capabilities())
+    }
+
+    /// Describe the keys [`ParseWithOptions`](Self::parse_with_options)/
+    /// [`ParseHexWithOptions`](Self::parse_hex_with_options) accept, as
+    /// `(key, description)` pairs.
+    fn get_parse_options_schema(&self) -> Vec<(String, String)> {
+        parse_options_schema()
+    }
+
+    /// Request a graceful shutdown of the service
+    ///
+    /// Restricted to the local user running this service (checked via the
+    /// caller's Unix UID), since any peer on the bus would otherwise be able
+    /// to stop a shared system service. Sets the same flag
+    /// [`ShutdownHandle::shutdown`] and SIGINT/SIGTERM set, so [`DbusService::run`]
+    /// notices it on its next poll regardless of which of the three
+    /// triggered it.
+    async fn shutdown(
+        &self,
+        #[zbus(header)] header: zbus::message::Header<'_>,
+        #[zbus(connection)] connection: &zbus::Connection,
+    ) -> zbus::fdo::Result<()> {
+        if !self.caller_is_permitted(&header, connection).await? {
+            return Err(zbus::fdo::Error::AccessDenied(
+                "Shutdown is restricted to the user running this service".to_string(),
+            ));
+        }
+        self.shutdown
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl AsterixParserInterface {
+    /// Shared decode path for `Parse`/`ParseHex`/`ParseWithOptions`/`ParseHexWithOptions`
+    fn parse_records(&self, data: &[u8], options: ParseOptions) -> Result<String, zbus::fdo::Error> {
+        use crate::parse;
+
+        let records =
+            parse(data, options).map_err(|e| zbus::fdo::Error::Failed(e.to_string()))?;
+
+        if self.emit_signals {
+            for record in &records {
+                self.emit_record_parsed(record);
+                emit_asterix_received_signal(&self.connection, &self.object_path, record);
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        {
+            serde_json::to_string(&records)
+                .map_err(|e| zbus::fdo::Error::Failed(e.to_string()))
+        }
+
+        #[cfg(not(feature = "serde"))]
+        {
+            Ok(format!("Parsed {} records", records.len()))
+        }
+    }
+
+    /// Broadcast a `RecordParsed` signal for a single decoded record
+    ///
+    /// Best-effort: a failure to emit is logged but does not fail the
+    /// originating `Parse`/`ParseHex` call.
+    fn emit_record_parsed(&self, record: &crate::types::AsterixRecord) {
+        emit_record_parsed_signal(&self.connection, &self.object_path, record);
+    }
+
+    /// Check whether the caller of `header` is running as the same Unix
+    /// user as this process, via `org.freedesktop.DBus.GetConnectionUnixUser`
+    async fn caller_is_permitted(
+        &self,
+        header: &zbus::message::Header<'_>,
+        connection: &zbus::Connection,
+    ) -> zbus::fdo::Result<bool> {
+        let Some(sender) = header.sender() else {
+            return Ok(false);
+        };
+        let dbus_proxy = zbus::fdo::DBusProxy::new(connection).await?;
+        let caller_uid = dbus_proxy.get_connection_unix_user(sender.into()).await?;
+        Ok(caller_uid == process_uid())
+    }
+}
+
+unsafe extern "C" {
+    fn getuid() -> u32;
+}
+
+/// The Unix user ID this process is running as
+fn process_uid() -> u32 {
+    unsafe { getuid() }
+}
+
+/// Broadcast a `RecordParsed` signal for `record` over `connection`
+///
+/// Shared by [`AsterixParserInterface::emit_record_parsed`] (a decode
+/// triggered by an RPC call) and [`DbusService::run_source`] (a decode
+/// produced by a live [`crate::source::udp::AsterixSource`] feed), so both
+/// paths broadcast the exact same signal shape. Best-effort: a failure to
+/// emit is logged, not propagated.
+fn emit_record_parsed_signal(
+    connection: &Connection,
+    object_path: &str,
+    record: &crate::types::AsterixRecord,
+) {
+    let result = connection.emit_signal(
+        None::<&str>,
+        object_path,
+        "com.asterix.Parser",
+        "RecordParsed",
+        &(
+            record.category,
+            record.timestamp_ms,
+            record.crc,
+            record.item_count() as u32,
+            record.hex_data.as_str(),
+        ),
+    );
+
+    if let Err(e) = result {
+        log::warn!("failed to emit RecordParsed signal: {e}");
+    }
+}
+
+/// Broadcast a lightweight `AsterixReceived` signal for `record` over `connection`
+///
+/// Carries only `category`/`length` (unlike [`emit_record_parsed_signal`]'s
+/// full category/timestamp/CRC/item-count/hex-data payload), for listeners
+/// that just want a cheap per-record heartbeat or throughput counter rather
+/// than the decoded payload itself. Emitted alongside `RecordParsed`
+/// everywhere a record is decoded, best-effort: a failure to emit is logged,
+/// not propagated.
+fn emit_asterix_received_signal(
+    connection: &Connection,
+    object_path: &str,
+    record: &crate::types::AsterixRecord,
+) {
+    let result = connection.emit_signal(
+        None::<&str>,
+        object_path,
+        "com.asterix.Parser",
+        "AsterixReceived",
+        &(record.category, record.length),
+    );
+
+    if let Err(e) = result {
+        log::warn!("failed to emit AsterixReceived signal: {e}");
+    }
+}
+
+/// Standard D-Bus introspection XML for the `com.asterix.Parser` interface,
+/// kept in sync with the module-level doc comment above and returned as-is
+/// by [`DbusService::introspect`] for external codegen tools (e.g. to
+/// generate a client proxy in another language) to consume without having to
+/// call a live service first.
+const INTROSPECTION_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<node>
+  <interface name="com.asterix.Parser">
+    <method name="Parse">
+      <arg type="ay" name="data" direction="in"/>
+      <arg type="s" name="result" direction="out"/>
+    </method>
+    <method name="ParseHex">
+      <arg type="s" name="hex_data" direction="in"/>
+      <arg type="s" name="result" direction="out"/>
+    </method>
+    <method name="ParseWithOptions">
+      <arg type="ay" name="data" direction="in"/>
+      <arg type="(bqu)" name="options" direction="in"/>
+      <arg type="s" name="result" direction="out"/>
+    </method>
+    <method name="ParseHexWithOptions">
+      <arg type="s" name="hex_data" direction="in"/>
+      <arg type="(bqu)" name="options" direction="in"/>
+      <arg type="s" name="result" direction="out"/>
+    </method>
+    <method name="GetVersion">
+      <arg type="s" name="version" direction="out"/>
+    </method>
+    <method name="GetCategories">
+      <arg type="ay" name="categories" direction="out"/>
+    </method>
+    <method name="HealthCheck">
+      <arg type="b" name="healthy" direction="out"/>
+    </method>
+    <method name="Negotiate">
+      <arg type="u" name="client_proto" direction="in"/>
+      <arg type="u" name="server_proto" direction="out"/>
+      <arg type="as" name="capabilities" direction="out"/>
+    </method>
+    <method name="GetParseOptionsSchema">
+      <arg type="a(ss)" name="schema" direction="out"/>
+    </method>
+    <method name="Shutdown">
+    </method>
+    <signal name="RecordParsed">
+      <arg type="y" name="category"/>
+      <arg type="t" name="timestamp_ms"/>
+      <arg type="u" name="crc"/>
+      <arg type="u" name="item_count"/>
+      <arg type="s" name="hex_data"/>
+    </signal>
+    <signal name="AsterixReceived">
+      <arg type="y" name="category"/>
+      <arg type="u" name="length"/>
+    </signal>
+  </interface>
+</node>
+"#;
+
+/// Process-wide flag set by [`install_signal_handlers`]'s SIGINT/SIGTERM
+/// handler. Global rather than per-[`DbusService`] because a Unix signal
+/// handler has no way to know which service instance it was "meant" for;
+/// every [`DbusService::run`] in the process checks it alongside its own
+/// per-instance [`ShutdownHandle`] flag.
+static SIGNAL_SHUTDOWN_REQUESTED: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+static INSTALL_SIGNAL_HANDLERS: std::sync::Once = std::sync::Once::new();
+
+const SIGINT: i32 = 2;
+const SIGTERM: i32 = 15;
+
+unsafe extern "C" {
+    fn signal(signum: i32, handler: extern "C" fn(i32)) -> usize;
+}
+
+extern "C" fn handle_shutdown_signal(_signum: i32) {
+    // Async-signal-safe: just an atomic store, same as every other signal
+    // handler in this vein.
+    SIGNAL_SHUTDOWN_REQUESTED.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Install the SIGINT/SIGTERM handler that sets [`SIGNAL_SHUTDOWN_REQUESTED`].
+/// Idempotent: only the first call in the process actually installs it.
+fn install_signal_handlers() {
+    INSTALL_SIGNAL_HANDLERS.call_once(|| unsafe {
+        signal(SIGINT, handle_shutdown_signal);
+        signal(SIGTERM, handle_shutdown_signal);
+    });
+}
+
+/// Best-effort `sd_notify(3)`-style notification to systemd, over the
+/// `$NOTIFY_SOCKET` a supervised unit's service manager sets. A no-op when
+/// not running under systemd (`$NOTIFY_SOCKET` unset), so it's always safe
+/// to call. Implemented directly against `UnixDatagram` rather than taking
+/// a `libsystemd`/`sd-notify` dependency, the same zero-dependency approach
+/// [`crate::line_export`] uses for NDJSON/CSV.
+///
+/// Doesn't (yet) handle `$NOTIFY_SOCKET` values in the abstract-namespace
+/// form systemd prefers (a leading `@`); that needs an extra null-byte
+/// rewrite `UnixDatagram` doesn't do for you, and most container/sandboxed
+/// setups hand out a plain filesystem path anyway.
+fn sd_notify(state: &str) -> std::io::Result<()> {
+    use std::os::unix::net::UnixDatagram;
+
+    let Some(socket_path) = std::env::var_os("NOTIFY_SOCKET") else {
+        return Ok(());
+    };
+    let socket = UnixDatagram::unbound()?;
+    socket.send_to(state.as_bytes(), socket_path)?;
+    Ok(())
+}
+
+/// Handle to request [`DbusService::run`]'s shutdown from outside the thread
+/// that's blocked in it — an external event loop, a supervisor, a test
+/// harness. [`DbusService::run`] also shuts down on SIGINT/SIGTERM, or a
+/// `Shutdown` D-Bus method call from a permitted caller; this handle is the
+/// fourth way in, for callers that aren't any of those.
+#[derive(Clone)]
+pub struct ShutdownHandle(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+impl ShutdownHandle {
+    /// Request shutdown. [`DbusService::run`] notices on its next poll.
+    pub fn shutdown(&self) {
+        self.0.store(true, std::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Whether shutdown has been requested, via this handle, the `Shutdown`
+    /// D-Bus method, or SIGINT/SIGTERM.
+    pub fn is_shutdown_requested(&self) -> bool {
+        self.0.load(std::sync::atomic::Ordering::SeqCst)
+            || SIGNAL_SHUTDOWN_REQUESTED.load(std::sync::atomic::Ordering::SeqCst)
+    }
 }
 
 /// D-Bus service for ASTERIX parsing
 pub struct DbusService {
     connection: Connection,
     config: DbusConfig,
+    shutdown: std::sync::Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl DbusService {
     /// Create a new D-Bus service
     pub fn new(config: DbusConfig) -> Result<Self, DbusError> {
-        let connection = match config.bus_type {
-            BusType::Session => Connection::session()?,
-            BusType::System => Connection::system()?,
+        let connection = if config.single_threaded {
+            let builder = match config.bus_type {
+                BusType::Session => zbus::blocking::connection::Builder::session()?,
+                BusType::System => zbus::blocking::connection::Builder::system()?,
+            };
+            builder.internal_executor(false).build()?
+        } else {
+            match config.bus_type {
+                BusType::Session => Connection::session()?,
+                BusType::System => Connection::system()?,
+            }
         };
 
         Ok(Self {
             connection,
             config,
+            shutdown: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
         })
     }
 
-    /// Start the D-Bus service and block
+    /// A handle external code can use to request [`Self::run`]'s shutdown.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle(self.shutdown.clone())
+    }
+
+    /// Whether shutdown has been requested via [`Self::shutdown_handle`],
+    /// the `Shutdown` D-Bus method, or SIGINT/SIGTERM.
+    fn shutdown_requested(&self) -> bool {
+        self.shutdown.load(std::sync::atomic::Ordering::SeqCst)
+            || SIGNAL_SHUTDOWN_REQUESTED.load(std::sync::atomic::Ordering::SeqCst)
+    }
+
+    /// Start the D-Bus service and block until shutdown is requested
+    ///
+    /// Registers the service on the bus and serves incoming method calls.
+    /// Installs a SIGINT/SIGTERM handler (shared process-wide, see
+    /// [`install_signal_handlers`]) and also honors a `Shutdown` D-Bus
+    /// method call (restricted to callers running as the same Unix user)
+    /// and [`Self::shutdown_handle`] — whichever of the three fires first
+    /// ends the loop. Once that happens, the well-known name is released
+    /// and `run` returns `Ok(())` instead of blocking forever like it used
+    /// to.
     ///
-    /// This method registers the service on the bus and starts processing
-    /// incoming method calls. It will block until the service is stopped.
+    /// When `$NOTIFY_SOCKET` is set (i.e. this is a systemd-supervised
+    /// unit), sends `READY=1` once serving starts and periodic
+    /// `WATCHDOG=1` pings, via [`sd_notify`].
     pub fn run(&self) -> Result<(), DbusError> {
         use zbus::names::WellKnownName;
 
-        // Request the service name
+        install_signal_handlers();
+
         let name: WellKnownName = self.config.service_name.as_str().try_into()
             .map_err(|e| DbusError::ServiceError(format!("Invalid service name: {e}")))?;
         self.connection
-            .request_name(name)
+            .request_name(name.clone())
             .map_err(|e| DbusError::ServiceError(e.to_string()))?;
 
-        // Create the interface object
         let interface = AsterixParserInterface {
             emit_signals: self.config.emit_signals,
+            connection: self.connection.clone(),
+            object_path: self.config.object_path.clone(),
+            shutdown: self.shutdown.clone(),
         };
 
-        // Serve the interface at the object path
         let path: zbus::zvariant::ObjectPath = self.config.object_path.as_str().try_into()
             .map_err(|e| DbusError::ServiceError(format!("Invalid object path: {e}")))?;
         self.connection
@@ -270,11 +770,33 @@ impl DbusService {
             self.config.object_path
         );
 
-        // Block forever (service stays running)
-        // In zbus 5.x, the connection handles message processing automatically
-        loop {
-            std::thread::sleep(std::time::Duration::from_secs(1));
+        if let Err(e) = sd_notify("READY=1") {
+            log::debug!("sd_notify(READY=1) failed: {e}");
+        }
+
+        let mut last_watchdog = std::time::Instant::now();
+        let watchdog_interval = std::time::Duration::from_secs(15);
+        while !self.shutdown_requested() {
+            if self.config.single_threaded {
+                self.poll_once(std::time::Duration::from_millis(500));
+            } else {
+                // zbus's internal executor thread handles message processing
+                std::thread::sleep(std::time::Duration::from_millis(500));
+            }
+
+            if last_watchdog.elapsed() >= watchdog_interval {
+                if let Err(e) = sd_notify("WATCHDOG=1") {
+                    log::debug!("sd_notify(WATCHDOG=1) failed: {e}");
+                }
+                last_watchdog = std::time::Instant::now();
+            }
         }
+
+        log::info!("ASTERIX D-Bus service shutting down");
+        if let Err(e) = self.connection.release_name(name) {
+            log::warn!("failed to release D-Bus name on shutdown: {e}");
+        }
+        Ok(())
     }
 
     /// Start the D-Bus service and run for a limited time (for testing)
@@ -289,6 +811,9 @@ impl DbusService {
 
         let interface = AsterixParserInterface {
             emit_signals: self.config.emit_signals,
+            connection: self.connection.clone(),
+            object_path: self.config.object_path.clone(),
+            shutdown: self.shutdown.clone(),
         };
 
         let path: zbus::zvariant::ObjectPath = self.config.object_path.as_str().try_into()
@@ -298,14 +823,194 @@ impl DbusService {
             .at(path, interface)
             .map_err(|e| DbusError::ServiceError(e.to_string()))?;
 
-        std::thread::sleep(duration);
+        if self.config.single_threaded {
+            let deadline = std::time::Instant::now() + duration;
+            while std::time::Instant::now() < deadline {
+                self.poll_once(std::time::Duration::from_millis(50));
+            }
+        } else {
+            std::thread::sleep(duration);
+        }
         Ok(())
     }
 
+    /// Register the service and continuously broadcast `RecordParsed`/
+    /// `AsterixReceived` signals for records decoded from a live feed
+    ///
+    /// Still serves `Parse`/`ParseHex`/etc. RPC calls like [`Self::run`], but
+    /// signal emission for those is turned off (`emit_signals: false`) so a
+    /// record is only ever broadcast once: when [`crate::source::udp::AsterixSource`]
+    /// decodes it, not again if an RPC call happens to decode the same bytes.
+    /// Blocks until `source` disconnects (all its handles are dropped). This
+    /// is what turns the service from request/response into a live ASTERIX
+    /// event bus: clients register a match rule
+    /// ([`DbusClient::subscribe_parsed`]/[`DbusClient::subscribe_received`])
+    /// and receive real-time notifications without polling `Parse`.
+    pub fn run_source(&self, source: crate::source::AsterixSourceRx) -> Result<(), DbusError> {
+        use zbus::names::WellKnownName;
+
+        let name: WellKnownName = self.config.service_name.as_str().try_into()
+            .map_err(|e| DbusError::ServiceError(format!("Invalid service name: {e}")))?;
+        self.connection
+            .request_name(name)
+            .map_err(|e| DbusError::ServiceError(e.to_string()))?;
+
+        let interface = AsterixParserInterface {
+            emit_signals: false,
+            connection: self.connection.clone(),
+            object_path: self.config.object_path.clone(),
+            shutdown: self.shutdown.clone(),
+        };
+
+        let path: zbus::zvariant::ObjectPath = self.config.object_path.as_str().try_into()
+            .map_err(|e| DbusError::ServiceError(format!("Invalid object path: {e}")))?;
+        self.connection
+            .object_server()
+            .at(path, interface)
+            .map_err(|e| DbusError::ServiceError(e.to_string()))?;
+
+        loop {
+            match source.recv_timeout(std::time::Duration::from_millis(500)) {
+                Ok(Some(record)) => {
+                    emit_record_parsed_signal(&self.connection, &self.config.object_path, &record);
+                    emit_asterix_received_signal(&self.connection, &self.config.object_path, &record);
+                }
+                Ok(None) => {
+                    if self.config.single_threaded {
+                        self.poll_once(std::time::Duration::from_millis(50));
+                    }
+                }
+                Err(_) => return Ok(()),
+            }
+        }
+    }
+
     /// Get the D-Bus connection (for advanced use)
     pub fn connection(&self) -> &Connection {
         &self.connection
     }
+
+    /// Standard D-Bus introspection XML for the `com.asterix.Parser`
+    /// interface, for external codegen tools (e.g. to generate a client
+    /// proxy in another language) that would rather read a static XML
+    /// document than query a running service's
+    /// `org.freedesktop.DBus.Introspectable.Introspect`.
+    pub fn introspect() -> String {
+        INTROSPECTION_XML.to_string()
+    }
+
+    /// Process any currently-pending incoming messages without blocking
+    ///
+    /// Drains the connection's executor of ready work and returns. Requires
+    /// [`DbusConfig::single_threaded`] so nothing else is already ticking the
+    /// executor in the background; use together with [`Self::as_raw_fd`] to
+    /// drive the service from an external epoll/mio/tokio reactor instead of
+    /// dedicating a thread to [`Self::run_for`].
+    ///
+    /// Returns the number of executor tasks processed.
+    pub fn process_incoming(&self) -> usize {
+        let executor = self.connection.executor();
+        let mut processed = 0;
+        while executor.try_tick() {
+            processed += 1;
+        }
+        processed
+    }
+
+    /// Block up to `timeout` for incoming messages, processing whatever arrives
+    ///
+    /// Returns the number of executor tasks processed; `0` means the timeout
+    /// elapsed with nothing to do.
+    pub fn poll_once(&self, timeout: std::time::Duration) -> usize {
+        let executor = self.connection.executor();
+        let deadline = std::time::Instant::now() + timeout;
+        let mut processed = 0;
+        loop {
+            if executor.try_tick() {
+                processed += 1;
+                continue;
+            }
+            if std::time::Instant::now() >= deadline {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        processed
+    }
+
+    /// Run [`Self::poll_once`] in a loop until `predicate` returns `true`
+    ///
+    /// Each iteration polls for up to `poll_interval`; lets a caller drain
+    /// this service inline with other work on a single thread instead of
+    /// spawning a background thread for [`Self::run_for`].
+    pub fn run_until<F: FnMut() -> bool>(&self, mut predicate: F, poll_interval: std::time::Duration) {
+        while !predicate() {
+            self.poll_once(poll_interval);
+        }
+    }
+}
+
+impl AsRawFd for DbusService {
+    /// Raw file descriptor of the underlying D-Bus connection socket
+    ///
+    /// Register this with epoll/mio/tokio to learn when [`DbusService::process_incoming`]
+    /// has work to do.
+    fn as_raw_fd(&self) -> RawFd {
+        self.connection.as_raw_fd()
+    }
+}
+
+impl ParserTransport for DbusService {
+    /// Decode raw ASTERIX bytes the same way the `Parse` D-Bus method does
+    ///
+    /// D-Bus callers go through the `Parse`/`ParseHex` methods instead; this
+    /// impl exists so `DbusService` is one interchangeable [`ParserTransport`]
+    /// alongside [`super::ws`] and [`super::http`], for code that wants to
+    /// parse in-process without going over the bus at all.
+    fn parse(&self, data: &[u8]) -> Result<Vec<crate::types::AsterixRecord>, AsterixError> {
+        super::rpc::CoreParser.parse(data)
+    }
+}
+
+/// Compile-time-checked proxy for the `com.asterix.Parser` D-Bus interface
+///
+/// `#[zbus::proxy]` generates a blocking `AsterixParserProxyBlocking` and an
+/// async `AsterixParserProxy` from this trait, each method checked against
+/// [`AsterixParserInterface`]'s `#[interface]` impl at compile time (name,
+/// argument types, and return type must all match). [`DbusClient`] builds an
+/// `AsterixParserProxyBlocking` internally instead of calling
+/// [`zbus::blocking::Proxy::call_method`] by bare string, so a typo or a
+/// signature drift from the server is caught at build time rather than as a
+/// runtime `MethodError`.
+#[zbus::proxy(
+    interface = "com.asterix.Parser",
+    default_path = "/com/asterix/Parser"
+)]
+trait AsterixParser {
+    /// Parse raw ASTERIX bytes and return JSON result
+    fn parse(&self, data: Vec<u8>) -> zbus::Result<String>;
+    /// Parse hex-encoded ASTERIX data and return JSON result
+    fn parse_hex(&self, hex_data: &str) -> zbus::Result<String>;
+    /// Parse raw ASTERIX bytes with explicit [`RpcParseOptions`]
+    fn parse_with_options(&self, data: Vec<u8>, options: RpcParseOptions) -> zbus::Result<String>;
+    /// Parse hex-encoded ASTERIX data with explicit [`RpcParseOptions`]
+    fn parse_hex_with_options(
+        &self,
+        hex_data: &str,
+        options: RpcParseOptions,
+    ) -> zbus::Result<String>;
+    /// Get ASTERIX library version
+    fn get_version(&self) -> zbus::Result<String>;
+    /// Get supported ASTERIX categories
+    fn get_categories(&self) -> zbus::Result<Vec<u8>>;
+    /// Check if the service is healthy
+    fn health_check(&self) -> zbus::Result<bool>;
+    /// Negotiate protocol version and capabilities
+    fn negotiate(&self, client_proto: u32) -> zbus::Result<(u32, Vec<String>)>;
+    /// Describe the keys `ParseWithOptions`/`ParseHexWithOptions` accept
+    fn get_parse_options_schema(&self) -> zbus::Result<Vec<(String, String)>>;
+    /// Request a graceful shutdown of the service
+    fn shutdown(&self) -> zbus::Result<()>;
 }
 
 /// D-Bus client for calling ASTERIX parser service
@@ -313,102 +1018,542 @@ pub struct DbusClient {
     connection: Connection,
     service_name: String,
     object_path: String,
+    /// Protocol version the service returned from [`Self::new`]'s
+    /// `Negotiate` handshake.
+    server_protocol: u32,
+    /// Capabilities the service advertised during that handshake.
+    capabilities: Vec<String>,
 }
 
 impl DbusClient {
     /// Create a new D-Bus client
+    ///
+    /// Performs the `Negotiate` handshake once against the service and
+    /// caches its result ([`Self::server_protocol`]/[`Self::capabilities`]).
+    /// Returns [`DbusError::ProtocolMismatch`] if the service's protocol
+    /// version is outside this client's supported range
+    /// (`MIN_SUPPORTED_PROTOCOL..=PROTOCOL_VERSION`) — including if the
+    /// service predates `Negotiate` entirely, reported as version `0` —
+    /// rather than letting a later call fail with a confusing `MethodError`.
     pub fn new(config: DbusConfig) -> Result<Self, DbusError> {
         let connection = match config.bus_type {
             BusType::Session => Connection::session()?,
             BusType::System => Connection::system()?,
         };
 
-        Ok(Self {
+        let mut client = Self {
             connection,
             service_name: config.service_name,
             object_path: config.object_path,
-        })
+            server_protocol: 0,
+            capabilities: Vec::new(),
+        };
+
+        let (server_protocol, capabilities) = client
+            .create_proxy()?
+            .negotiate(PROTOCOL_VERSION)
+            .map_err(|_| DbusError::ProtocolMismatch(0))?;
+        if !(MIN_SUPPORTED_PROTOCOL..=PROTOCOL_VERSION).contains(&server_protocol) {
+            return Err(DbusError::ProtocolMismatch(server_protocol));
+        }
+        client.server_protocol = server_protocol;
+        client.capabilities = capabilities;
+
+        Ok(client)
+    }
+
+    /// Protocol version the service returned during the `Negotiate` handshake.
+    pub fn server_protocol(&self) -> u32 {
+        self.server_protocol
     }
 
-    /// Create a proxy for calling methods
-    fn create_proxy(&self) -> Result<zbus::blocking::Proxy, DbusError> {
+    /// Capabilities the service advertised during the `Negotiate` handshake.
+    pub fn capabilities(&self) -> &[String] {
+        &self.capabilities
+    }
+
+    /// Build the typed [`AsterixParserProxyBlocking`] for this client.
+    fn create_proxy(&self) -> Result<AsterixParserProxyBlocking<'_>, DbusError> {
         use zbus::names::BusName;
-        use zbus::blocking::Proxy;
 
         let dest: BusName = self.service_name.as_str().try_into()
             .map_err(|e| DbusError::ConnectionError(format!("Invalid bus name: {e}")))?;
         let path: zbus::zvariant::ObjectPath = self.object_path.as_str().try_into()
             .map_err(|e| DbusError::ConnectionError(format!("Invalid object path: {e}")))?;
 
-        Proxy::new(
-            &self.connection,
-            dest,
-            path,
-            "com.asterix.Parser",
-        )
-        .map_err(|e| DbusError::ConnectionError(e.to_string()))
+        AsterixParserProxyBlocking::builder(&self.connection)
+            .destination(dest)
+            .map_err(|e| DbusError::ConnectionError(e.to_string()))?
+            .path(path)
+            .map_err(|e| DbusError::ConnectionError(e.to_string()))?
+            .build()
+            .map_err(|e| DbusError::ConnectionError(e.to_string()))
     }
 
     /// Parse ASTERIX data by calling the D-Bus service
     pub fn parse(&self, data: &[u8]) -> Result<String, DbusError> {
-        let proxy = self.create_proxy()?;
-        proxy
-            .call_method("Parse", &(data.to_vec(),))
-            .map_err(|e| DbusError::MethodError(e.to_string()))?
-            .body()
-            .deserialize::<String>()
+        self.create_proxy()?
+            .parse(data.to_vec())
             .map_err(|e| DbusError::MethodError(e.to_string()))
     }
 
     /// Parse hex-encoded ASTERIX data
     pub fn parse_hex(&self, hex_data: &str) -> Result<String, DbusError> {
-        let proxy = self.create_proxy()?;
-        proxy
-            .call_method("ParseHex", &(hex_data,))
-            .map_err(|e| DbusError::MethodError(e.to_string()))?
-            .body()
-            .deserialize::<String>()
+        self.create_proxy()?
+            .parse_hex(hex_data)
+            .map_err(|e| DbusError::MethodError(e.to_string()))
+    }
+
+    /// Parse ASTERIX data, applying `options` server-side before decoding
+    ///
+    /// Unlike [`Self::parse`] (which always decodes with
+    /// `ParseOptions::default()`), this lets a remote caller request
+    /// `verbose` output or bound the call with `filter_category`/
+    /// `max_records` the same way the in-process [`crate::parse`] does.
+    pub fn parse_with_options(
+        &self,
+        data: &[u8],
+        options: ParseOptions,
+    ) -> Result<String, DbusError> {
+        self.create_proxy()?
+            .parse_with_options(data.to_vec(), RpcParseOptions::from(&options))
+            .map_err(|e| DbusError::MethodError(e.to_string()))
+    }
+
+    /// Parse hex-encoded ASTERIX data, applying `options` server-side
+    pub fn parse_hex_with_options(
+        &self,
+        hex_data: &str,
+        options: ParseOptions,
+    ) -> Result<String, DbusError> {
+        self.create_proxy()?
+            .parse_hex_with_options(hex_data, RpcParseOptions::from(&options))
             .map_err(|e| DbusError::MethodError(e.to_string()))
     }
 
     /// Get the ASTERIX library version from the service
     pub fn get_version(&self) -> Result<String, DbusError> {
-        let proxy = self.create_proxy()?;
-        proxy
-            .call_method("GetVersion", &())
-            .map_err(|e| DbusError::MethodError(e.to_string()))?
-            .body()
-            .deserialize::<String>()
+        self.create_proxy()?
+            .get_version()
+            .map_err(|e| DbusError::MethodError(e.to_string()))
+    }
+
+    /// Get the ASTERIX categories the service has registered
+    pub fn get_categories(&self) -> Result<Vec<u8>, DbusError> {
+        self.create_proxy()?
+            .get_categories()
+            .map_err(|e| DbusError::MethodError(e.to_string()))
+    }
+
+    /// Discover the keys `parse_with_options`/`parse_hex_with_options`
+    /// accept, as `(key, description)` pairs.
+    pub fn get_parse_options_schema(&self) -> Result<Vec<(String, String)>, DbusError> {
+        self.create_proxy()?
+            .get_parse_options_schema()
             .map_err(|e| DbusError::MethodError(e.to_string()))
     }
 
     /// Check if the service is healthy
     pub fn health_check(&self) -> Result<bool, DbusError> {
-        let proxy = self.create_proxy()?;
-        proxy
-            .call_method("HealthCheck", &())
-            .map_err(|e| DbusError::MethodError(e.to_string()))?
-            .body()
-            .deserialize::<bool>()
+        self.create_proxy()?
+            .health_check()
             .map_err(|e| DbusError::MethodError(e.to_string()))
     }
+
+    /// Request a graceful shutdown of the service
+    ///
+    /// Only succeeds if this client's process is running as the same Unix
+    /// user as the service; see [`AsterixParserInterface::shutdown`].
+    pub fn shutdown(&self) -> Result<(), DbusError> {
+        self.create_proxy()?
+            .shutdown()
+            .map_err(|e| DbusError::MethodError(e.to_string()))
+    }
+
+    /// Subscribe to `RecordParsed` signals broadcast by the service
+    ///
+    /// Installs a D-Bus match rule for the `com.asterix.Parser.RecordParsed`
+    /// signal; the rule is removed automatically when the returned
+    /// subscription is dropped. Turns this client from pure request/response
+    /// into a live feed: iterate the returned [`RecordParsedSubscription`] to
+    /// block for each record the service decodes.
+    pub fn subscribe_parsed(&self) -> Result<RecordParsedSubscription, DbusError> {
+        let proxy = self.create_proxy()?;
+        let iter = proxy
+            .receive_signal("RecordParsed")
+            .map_err(|e| DbusError::MethodError(e.to_string()))?;
+
+        Ok(RecordParsedSubscription { iter })
+    }
+
+    /// Subscribe to `AsterixReceived` signals broadcast by the service
+    ///
+    /// Like [`Self::subscribe_parsed`], but for the lightweight
+    /// `AsterixReceived` signal (just `category`/`length`) instead of the
+    /// full `RecordParsed` payload — cheaper to receive when a listener only
+    /// needs a per-record heartbeat or throughput counter.
+    pub fn subscribe_received(&self) -> Result<AsterixReceivedSubscription, DbusError> {
+        let proxy = self.create_proxy()?;
+        let iter = proxy
+            .receive_signal("AsterixReceived")
+            .map_err(|e| DbusError::MethodError(e.to_string()))?;
+
+        Ok(AsterixReceivedSubscription { iter })
+    }
+}
+
+impl AsRawFd for DbusClient {
+    /// Raw file descriptor of the underlying D-Bus connection socket
+    ///
+    /// Register this with epoll/mio/tokio to learn when a reply or signal
+    /// is ready to read without blocking on it directly.
+    fn as_raw_fd(&self) -> RawFd {
+        self.connection.as_raw_fd()
+    }
+}
+
+/// A single `RecordParsed` signal observed via [`DbusClient::subscribe_parsed`]
+#[derive(Debug, Clone)]
+pub struct RecordParsedSignal {
+    /// ASTERIX category number of the decoded record
+    pub category: u8,
+    /// Timestamp in milliseconds since Unix epoch
+    pub timestamp_ms: u64,
+    /// CRC32 checksum of the decoded data block
+    pub crc: u32,
+    /// Number of data items in the decoded record
+    pub item_count: u32,
+    /// Hexadecimal representation of the raw data
+    pub hex_data: String,
+}
+
+/// A live subscription to `RecordParsed` signals
+///
+/// Iterating blocks until the next signal arrives. The underlying D-Bus
+/// match rule is removed when this value is dropped.
+pub struct RecordParsedSubscription {
+    iter: zbus::blocking::SignalIterator,
+}
+
+impl Iterator for RecordParsedSubscription {
+    type Item = Result<RecordParsedSignal, DbusError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let message = self.iter.next()?;
+        Some(
+            message
+                .body()
+                .deserialize::<(u8, u64, u32, u32, String)>()
+                .map(
+                    |(category, timestamp_ms, crc, item_count, hex_data)| RecordParsedSignal {
+                        category,
+                        timestamp_ms,
+                        crc,
+                        item_count,
+                        hex_data,
+                    },
+                )
+                .map_err(|e| DbusError::MethodError(e.to_string())),
+        )
+    }
+}
+
+/// A single `AsterixReceived` signal observed via [`DbusClient::subscribe_received`]
+#[derive(Debug, Clone, Copy)]
+pub struct AsterixReceivedSignal {
+    /// ASTERIX category number of the decoded record
+    pub category: u8,
+    /// Length in bytes of the decoded record
+    pub length: u32,
+}
+
+/// A live subscription to `AsterixReceived` signals
+///
+/// Iterating blocks until the next signal arrives. The underlying D-Bus
+/// match rule is removed when this value is dropped.
+pub struct AsterixReceivedSubscription {
+    iter: zbus::blocking::SignalIterator,
+}
+
+impl Iterator for AsterixReceivedSubscription {
+    type Item = Result<AsterixReceivedSignal, DbusError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let message = self.iter.next()?;
+        Some(
+            message
+                .body()
+                .deserialize::<(u8, u32)>()
+                .map(|(category, length)| AsterixReceivedSignal { category, length })
+                .map_err(|e| DbusError::MethodError(e.to_string())),
+        )
+    }
 }
 
 /// Convert hex string to bytes
 fn hex_to_bytes(hex: &str) -> Result<Vec<u8>, String> {
-    let hex_clean: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
+    from_hex(hex).map_err(|e| e.to_string())
+}
 
-    if hex_clean.len() % 2 != 0 {
-        return Err("Invalid hex string length".to_string());
+/// Async counterpart of the blocking [`DbusService`]/[`DbusClient`] above
+///
+/// `DbusService::run`/`run_for` block the calling thread behind
+/// `thread::sleep`, which doesn't compose with a host that's already running
+/// a tokio/async-io reactor for the crate's other async transports. This
+/// module swaps `zbus::blocking::Connection`/`zbus::blocking::Proxy` for the
+/// plain `zbus::Connection`/`zbus::Proxy` zbus builds those on top of, so the
+/// service/client can be `.await`ed alongside everything else on that
+/// reactor instead of owning a thread.
+///
+/// The `com.asterix.Parser` interface — [`AsterixParserInterface`] and its
+/// `#[interface]` method bodies — is reused as-is: zbus serves the exact
+/// same interface impl over either a blocking or an async
+/// [`zbus::Connection`], so only connection setup and the serving loop
+/// differ here, not the RPC logic. [`AsyncDbusService::serve`] borrows a
+/// `zbus::blocking::Connection` (via its `From<zbus::Connection>`
+/// conversion) just for the interface's `emit_signal` calls, the same way
+/// [`DbusService::run`] already does.
+///
+/// Signal subscriptions ([`DbusClient::subscribe_parsed`]/
+/// [`DbusClient::subscribe_received`]) aren't mirrored here: zbus's async
+/// `Proxy::receive_signal` returns a `Stream` rather than a blocking
+/// `Iterator`, which is a big enough shape change to deserve its own request
+/// rather than being folded into this one.
+///
+/// Exercising this module needs an async executor to drive the returned
+/// futures (tokio, async-std, or zbus's own bundled `async-io` default);
+/// this crate doesn't take a dependency on one itself, so there are no
+/// `#[tokio::test]`s here the way there are `#[test]`s below for the
+/// blocking API.
+pub mod aio {
+    use std::future::Future;
+
+    use zbus::names::{BusName, WellKnownName};
+    use zbus::Connection;
+
+    use super::{
+        AsterixParserInterface, AsterixParserProxy, BusType, DbusConfig, DbusError,
+        RpcParseOptions,
+    };
+    use crate::types::ParseOptions;
+
+    /// Async D-Bus service for ASTERIX parsing. See [`super::DbusService`]
+    /// for the blocking equivalent.
+    pub struct AsyncDbusService {
+        connection: Connection,
+        config: DbusConfig,
     }
 
-    (0..hex_clean.len())
-        .step_by(2)
-        .map(|i| {
-            u8::from_str_radix(&hex_clean[i..i + 2], 16)
-                .map_err(|e| e.to_string())
-        })
-        .collect()
+    impl AsyncDbusService {
+        /// Connect to the configured bus. Does not request the service name
+        /// or serve the interface yet; see [`Self::serve`].
+        pub async fn new(config: DbusConfig) -> Result<Self, DbusError> {
+            let connection = match config.bus_type {
+                BusType::Session => Connection::session().await?,
+                BusType::System => Connection::system().await?,
+            };
+            Ok(Self { connection, config })
+        }
+
+        /// Request the service name, serve `com.asterix.Parser` at the
+        /// configured object path, and run until `shutdown` resolves.
+        ///
+        /// `shutdown` is any future — a `tokio::sync::oneshot::Receiver`, a
+        /// `CancellationToken::cancelled()`, or whatever the host's runtime
+        /// offers — so this module doesn't have to pick one itself. Once it
+        /// resolves, the well-known name is released and `serve` returns
+        /// `Ok(())`, unlike the blocking [`DbusService::run`]'s infinite loop.
+        pub async fn serve(&self, shutdown: impl Future<Output = ()>) -> Result<(), DbusError> {
+            let name: WellKnownName = self
+                .config
+                .service_name
+                .as_str()
+                .try_into()
+                .map_err(|e| DbusError::ServiceError(format!("Invalid service name: {e}")))?;
+            self.connection
+                .request_name(&name)
+                .await
+                .map_err(|e| DbusError::ServiceError(e.to_string()))?;
+
+            let interface = AsterixParserInterface {
+                emit_signals: self.config.emit_signals,
+                connection: zbus::blocking::Connection::from(self.connection.clone()),
+                object_path: self.config.object_path.clone(),
+                shutdown: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+            };
+
+            let path: zbus::zvariant::ObjectPath = self
+                .config
+                .object_path
+                .as_str()
+                .try_into()
+                .map_err(|e| DbusError::ServiceError(format!("Invalid object path: {e}")))?;
+            self.connection
+                .object_server()
+                .at(path, interface)
+                .await
+                .map_err(|e| DbusError::ServiceError(e.to_string()))?;
+
+            log::info!(
+                "ASTERIX async D-Bus service started: {} at {}",
+                self.config.service_name,
+                self.config.object_path
+            );
+
+            shutdown.await;
+
+            self.connection
+                .release_name(&name)
+                .await
+                .map_err(|e| DbusError::ServiceError(e.to_string()))?;
+            Ok(())
+        }
+
+        /// The underlying async connection (for advanced use).
+        pub fn connection(&self) -> &Connection {
+            &self.connection
+        }
+    }
+
+    /// Async D-Bus client for calling ASTERIX parser service. See
+    /// [`super::DbusClient`] for the blocking equivalent.
+    pub struct AsyncDbusClient {
+        connection: Connection,
+        service_name: String,
+        object_path: String,
+        server_protocol: u32,
+        capabilities: Vec<String>,
+    }
+
+    impl AsyncDbusClient {
+        /// Create a new async D-Bus client.
+        ///
+        /// Performs the same `Negotiate` handshake as
+        /// [`super::DbusClient::new`] and returns
+        /// [`DbusError::ProtocolMismatch`] on the same terms.
+        pub async fn new(config: DbusConfig) -> Result<Self, DbusError> {
+            let connection = match config.bus_type {
+                BusType::Session => Connection::session().await?,
+                BusType::System => Connection::system().await?,
+            };
+
+            let mut client = Self {
+                connection,
+                service_name: config.service_name,
+                object_path: config.object_path,
+                server_protocol: 0,
+                capabilities: Vec::new(),
+            };
+
+            let (server_protocol, capabilities) = client
+                .create_proxy()
+                .await?
+                .negotiate(super::PROTOCOL_VERSION)
+                .await
+                .map_err(|_| DbusError::ProtocolMismatch(0))?;
+            if !(super::MIN_SUPPORTED_PROTOCOL..=super::PROTOCOL_VERSION).contains(&server_protocol)
+            {
+                return Err(DbusError::ProtocolMismatch(server_protocol));
+            }
+            client.server_protocol = server_protocol;
+            client.capabilities = capabilities;
+
+            Ok(client)
+        }
+
+        /// Protocol version the service returned during the `Negotiate` handshake.
+        pub fn server_protocol(&self) -> u32 {
+            self.server_protocol
+        }
+
+        /// Capabilities the service advertised during the `Negotiate` handshake.
+        pub fn capabilities(&self) -> &[String] {
+            &self.capabilities
+        }
+
+        /// Build the typed [`AsterixParserProxy`] (the async counterpart of
+        /// [`AsterixParserProxyBlocking`] [`super::DbusClient`] uses) for
+        /// this client.
+        async fn create_proxy(&self) -> Result<AsterixParserProxy<'_>, DbusError> {
+            let dest: BusName = self
+                .service_name
+                .as_str()
+                .try_into()
+                .map_err(|e| DbusError::ConnectionError(format!("Invalid bus name: {e}")))?;
+            let path: zbus::zvariant::ObjectPath = self
+                .object_path
+                .as_str()
+                .try_into()
+                .map_err(|e| DbusError::ConnectionError(format!("Invalid object path: {e}")))?;
+
+            AsterixParserProxy::builder(&self.connection)
+                .destination(dest)
+                .map_err(|e| DbusError::ConnectionError(e.to_string()))?
+                .path(path)
+                .map_err(|e| DbusError::ConnectionError(e.to_string()))?
+                .build()
+                .await
+                .map_err(|e| DbusError::ConnectionError(e.to_string()))
+        }
+
+        /// Parse ASTERIX data by calling the D-Bus service.
+        pub async fn parse(&self, data: &[u8]) -> Result<String, DbusError> {
+            self.create_proxy()
+                .await?
+                .parse(data.to_vec())
+                .await
+                .map_err(|e| DbusError::MethodError(e.to_string()))
+        }
+
+        /// Parse hex-encoded ASTERIX data.
+        pub async fn parse_hex(&self, hex_data: &str) -> Result<String, DbusError> {
+            self.create_proxy()
+                .await?
+                .parse_hex(hex_data)
+                .await
+                .map_err(|e| DbusError::MethodError(e.to_string()))
+        }
+
+        /// Parse ASTERIX data, applying `options` server-side before decoding.
+        pub async fn parse_with_options(
+            &self,
+            data: &[u8],
+            options: ParseOptions,
+        ) -> Result<String, DbusError> {
+            self.create_proxy()
+                .await?
+                .parse_with_options(data.to_vec(), RpcParseOptions::from(&options))
+                .await
+                .map_err(|e| DbusError::MethodError(e.to_string()))
+        }
+
+        /// Get the ASTERIX library version from the service.
+        pub async fn get_version(&self) -> Result<String, DbusError> {
+            self.create_proxy()
+                .await?
+                .get_version()
+                .await
+                .map_err(|e| DbusError::MethodError(e.to_string()))
+        }
+
+        /// Check if the service is healthy.
+        pub async fn health_check(&self) -> Result<bool, DbusError> {
+            self.create_proxy()
+                .await?
+                .health_check()
+                .await
+                .map_err(|e| DbusError::MethodError(e.to_string()))
+        }
+
+        /// Request a graceful shutdown of the service.
+        pub async fn shutdown(&self) -> Result<(), DbusError> {
+            self.create_proxy()
+                .await?
+                .shutdown()
+                .await
+                .map_err(|e| DbusError::MethodError(e.to_string()))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -500,6 +1645,7 @@ mod tests {
             object_path: "/custom/Service".to_string(),
             bus_type: BusType::System,
             emit_signals: false,
+            single_threaded: false,
         };
         let cloned = config.clone();
         assert_eq!(cloned.service_name, "custom.Service");
@@ -570,16 +1716,35 @@ mod tests {
     // Interface Tests (basic)
     // ============================================================================
 
+    /// Build a bare `AsterixParserInterface` for unit tests that don't need
+    /// signal emission to actually succeed, skipping if no bus is available
+    fn test_interface(emit_signals: bool) -> Option<AsterixParserInterface> {
+        let Ok(connection) = Connection::session() else {
+            println!("Skipping test: D-Bus session not available");
+            return None;
+        };
+        Some(AsterixParserInterface {
+            emit_signals,
+            connection,
+            object_path: "/com/asterix/Parser".to_string(),
+            shutdown: std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)),
+        })
+    }
+
     #[test]
     fn test_asterix_parser_interface_get_version() {
-        let interface = AsterixParserInterface { emit_signals: true };
+        let Some(interface) = test_interface(true) else {
+            return;
+        };
         let version = interface.get_version();
         assert!(!version.is_empty());
     }
 
     #[test]
     fn test_asterix_parser_interface_get_categories() {
-        let interface = AsterixParserInterface { emit_signals: true };
+        let Some(interface) = test_interface(true) else {
+            return;
+        };
         let categories = interface.get_categories();
         assert!(categories.contains(&48));
         assert!(categories.contains(&62));
@@ -587,7 +1752,9 @@ mod tests {
 
     #[test]
     fn test_asterix_parser_interface_health_check() {
-        let interface = AsterixParserInterface { emit_signals: false };
+        let Some(interface) = test_interface(false) else {
+            return;
+        };
         assert!(interface.health_check());
     }
 }
@@ -50,12 +50,34 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Publishing and Subscribing in the Same Process
+//!
+//! A bidirectional gateway that both publishes some categories and
+//! subscribes to others should share one [`DdsNode`] rather than letting
+//! [`DdsPublisher::new`] and [`DdsSubscriber::new`] each create their own
+//! [`DomainParticipant`]:
+//!
+//! ```no_run
+//! use asterix::transport::dds::{DdsConfig, DdsNode};
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let node = DdsNode::new(0)?;
+//!
+//!     let publisher = node.create_publisher(DdsConfig::default())?;
+//!     let subscriber = node.create_subscriber(DdsConfig::default(), "asterix_cat48")?;
+//!
+//!     let _ = (publisher, subscriber);
+//!     Ok(())
+//! }
+//! ```
 
 use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
 use rustdds::dds::DomainParticipant;
+use rustdds::mio_06;
 use rustdds::serialization::{CDRDeserializerAdapter, CDRSerializerAdapter};
 use rustdds::with_key::{DataReader, DataWriter};
 use rustdds::{
@@ -65,8 +87,12 @@ use rustdds::{
 use serde::{Deserialize, Serialize};
 
 use crate::error::AsterixError;
+use crate::hex::{from_hex, from_hex_strict, hexdump as hexdump_bytes, HexDumpConfig};
 use crate::types::AsterixRecord;
 
+#[cfg(feature = "metrics")]
+use crate::transport::metrics::MetricsRecorder;
+
 /// Error type for DDS transport operations
 #[derive(Debug)]
 pub enum DdsError {
@@ -84,6 +110,11 @@ pub enum DdsError {
     ReadError(String),
     /// Serialization error
     SerializationError(String),
+    /// Failed to load or validate a `DdsConfig`/profile file
+    ConfigError(String),
+    /// A reader/writer QoS combination is provably incompatible (RxO rules) —
+    /// see [`DdsConfig::validate_compatible`]
+    QosError(String),
 }
 
 impl fmt::Display for DdsError {
@@ -96,6 +127,8 @@ impl fmt::Display for DdsError {
             DdsError::WriteError(msg) => write!(f, "DDS write error: {msg}"),
             DdsError::ReadError(msg) => write!(f, "DDS read error: {msg}"),
             DdsError::SerializationError(msg) => write!(f, "Serialization error: {msg}"),
+            DdsError::ConfigError(msg) => write!(f, "DDS config error: {msg}"),
+            DdsError::QosError(msg) => write!(f, "DDS QoS error: {msg}"),
         }
     }
 }
@@ -109,7 +142,7 @@ impl From<DdsError> for AsterixError {
 }
 
 /// QoS reliability mode for DDS
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Reliability {
     /// Best effort delivery - may lose samples
     BestEffort,
@@ -119,32 +152,131 @@ pub enum Reliability {
 }
 
 /// QoS durability mode for DDS
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Durability {
     /// Data only available to currently matched readers
     #[default]
     Volatile,
     /// Data persisted for late-joining readers (within writer lifetime)
     TransientLocal,
+    /// Data persisted by the DDS service itself, surviving past the
+    /// writer's own lifetime (e.g. a participant restart)
+    ///
+    /// Combined with [`History::KeepLast`], this is what lets a
+    /// reconnecting subscriber catch up on the last N cached samples per
+    /// topic instead of only seeing samples published after it joins; see
+    /// [`DdsPublisher::publish_acked`] for the store-and-forward guarantee
+    /// this enables together with a reliable, acknowledged publish.
+    Persistent,
 }
 
 /// QoS history mode for DDS
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum History {
     /// Keep only the last N samples
+    ///
+    /// `i32` (rather than a validated unsigned depth) so the variant
+    /// round-trips cleanly through JSON/TOML/RON; a negative depth loaded
+    /// from a config file is rejected by [`DdsConfig::validate`].
     KeepLast(i32),
     /// Keep all samples (limited by resource limits)
     KeepAll,
 }
 
+/// QoS liveliness mode for DDS
+///
+/// Surveillance gateways care about this: a dead radar feed should be
+/// noticed by a missed liveliness lease, not just by samples quietly
+/// stopping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Liveliness {
+    /// The middleware asserts liveliness for every writer on the
+    /// participant automatically, as long as the process is alive
+    Automatic {
+        /// Lease duration in milliseconds before a non-asserting writer is
+        /// considered not alive
+        lease_duration_ms: u64,
+    },
+    /// The application must call [`DdsPublisher::assert_liveliness`]
+    /// periodically to keep its writers marked alive
+    ManualByTopic {
+        /// Lease duration in milliseconds before a non-asserting writer is
+        /// considered not alive
+        lease_duration_ms: u64,
+    },
+    /// Like [`Liveliness::ManualByTopic`], but one assertion on the
+    /// participant refreshes every writer it owns, rather than requiring a
+    /// per-writer assertion
+    ManualByParticipant {
+        /// Lease duration in milliseconds before a non-asserting writer is
+        /// considered not alive
+        lease_duration_ms: u64,
+    },
+}
+
+impl Default for Liveliness {
+    fn default() -> Self {
+        Liveliness::Automatic {
+            lease_duration_ms: 10_000,
+        }
+    }
+}
+
 impl Default for History {
     fn default() -> Self {
         History::KeepLast(10)
     }
 }
 
+/// Strictness policy for decoding a record's captured `hex_data` before publishing
+///
+/// `Lenient` mirrors [`crate::hex::from_hex`]'s tolerance for interior
+/// whitespace and mixed case, and additionally strips a leading `0x`/`0X`
+/// prefix; `Strict` requires an even-length, prefix-free, whitespace-free
+/// string and rejects anything else outright via [`crate::hex::from_hex_strict`]
+/// instead of silently cleaning it up. Trusted binary-to-hex bridges should
+/// use `Strict` to fail fast on malformed input; interactive/test feeds
+/// should stay on the forgiving `Lenient` default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum HexInputMode {
+    /// Tolerate embedded whitespace, mixed case, and an optional `0x`/`0X` prefix
+    #[default]
+    Lenient,
+    /// Require an even-length, prefix-free, whitespace-free string
+    Strict,
+}
+
+/// Resource limits bounding how many samples/instances a writer or reader
+/// will buffer
+///
+/// Mirrors the OMG DDS `RESOURCE_LIMITS` QoS policy: any field set to `-1`
+/// means unlimited, matching `rustdds`'s own convention. Without a bound
+/// here, [`Durability::TransientLocal`] combined with [`History::KeepAll`]
+/// lets a writer's buffered instance count grow without limit; setting
+/// `max_samples_per_instance` also keeps [`History::KeepLast`] honest for
+/// late-joining readers (see [`DdsConfig::validate`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLimits {
+    /// Maximum number of samples buffered across all instances (`-1` = unlimited)
+    pub max_samples: i32,
+    /// Maximum number of distinct instances (keys) tracked (`-1` = unlimited)
+    pub max_instances: i32,
+    /// Maximum number of samples buffered per instance (`-1` = unlimited)
+    pub max_samples_per_instance: i32,
+}
+
+impl Default for ResourceLimits {
+    fn default() -> Self {
+        Self {
+            max_samples: -1,
+            max_instances: -1,
+            max_samples_per_instance: -1,
+        }
+    }
+}
+
 /// Configuration for DDS transport
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DdsConfig {
     /// DDS Domain ID (default: 0)
     /// Participants in different domains cannot communicate
@@ -165,6 +297,34 @@ pub struct DdsConfig {
 
     /// Deadline period in milliseconds (0 = no deadline)
     pub deadline_ms: u64,
+
+    /// Liveliness QoS policy
+    pub liveliness: Liveliness,
+
+    /// Resource limits bounding buffered samples/instances
+    pub resource_limits: ResourceLimits,
+
+    /// Strictness policy for decoding a record's `hex_data` before publishing
+    pub hex_input_mode: HexInputMode,
+
+    /// DDS partitions this publisher/subscriber belongs to (default: none,
+    /// i.e. the default partition)
+    ///
+    /// Lets several radar feeds share one domain without their topics
+    /// colliding: a reader and writer only match if they share at least one
+    /// partition name (or both leave this empty). An entry must not be the
+    /// empty string — see [`DdsConfig::validate`].
+    pub partition: Vec<String>,
+
+    /// Lifespan period in milliseconds bounding how long a written sample
+    /// stays valid before being dropped by the middleware (0 = no lifespan
+    /// limit)
+    pub lifespan_ms: u64,
+
+    /// Latency budget in milliseconds: a hint to the middleware about the
+    /// acceptable delay before a sample must be delivered (0 = no budget
+    /// requested)
+    pub latency_budget_ms: u64,
 }
 
 impl Default for DdsConfig {
@@ -176,6 +336,12 @@ impl Default for DdsConfig {
             durability: Durability::default(),
             history: History::default(),
             deadline_ms: 0,
+            liveliness: Liveliness::default(),
+            resource_limits: ResourceLimits::default(),
+            hex_input_mode: HexInputMode::default(),
+            partition: Vec::new(),
+            lifespan_ms: 0,
+            latency_budget_ms: 0,
         }
     }
 }
@@ -230,6 +396,9 @@ impl DdsConfig {
             Durability::TransientLocal => {
                 builder = builder.durability(DdsDurability::TransientLocal);
             }
+            Durability::Persistent => {
+                builder = builder.durability(DdsDurability::Persistent);
+            }
         }
 
         // Set history
@@ -250,8 +419,226 @@ impl DdsConfig {
             )));
         }
 
+        // Set liveliness
+        use rustdds::policy::Liveliness as DdsLiveliness;
+        builder = builder.liveliness(match self.liveliness {
+            Liveliness::Automatic { lease_duration_ms } => DdsLiveliness::Automatic {
+                lease_duration: rustdds::Duration::from_millis(lease_duration_ms as i64),
+            },
+            Liveliness::ManualByTopic { lease_duration_ms } => DdsLiveliness::ManualByTopic {
+                lease_duration: rustdds::Duration::from_millis(lease_duration_ms as i64),
+            },
+            Liveliness::ManualByParticipant { lease_duration_ms } => {
+                DdsLiveliness::ManualByParticipant {
+                    lease_duration: rustdds::Duration::from_millis(lease_duration_ms as i64),
+                }
+            }
+        });
+
+        // Set resource limits
+        use rustdds::policy::ResourceLimits as DdsResourceLimits;
+        builder = builder.resource_limits(DdsResourceLimits {
+            max_samples: self.resource_limits.max_samples,
+            max_instances: self.resource_limits.max_instances,
+            max_samples_per_instance: self.resource_limits.max_samples_per_instance,
+        });
+
+        // Set partition, if any
+        if !self.partition.is_empty() {
+            use rustdds::policy::Partition as DdsPartition;
+            builder = builder.partition(DdsPartition {
+                partitions: self.partition.clone(),
+            });
+        }
+
+        // Set lifespan, if bounded
+        if self.lifespan_ms > 0 {
+            use rustdds::policy::Lifespan as DdsLifespan;
+            builder = builder.lifespan(DdsLifespan {
+                duration: rustdds::Duration::from_millis(self.lifespan_ms as i64),
+            });
+        }
+
+        // Set latency budget, if requested
+        if self.latency_budget_ms > 0 {
+            use rustdds::policy::LatencyBudget as DdsLatencyBudget;
+            builder = builder.latency_budget(DdsLatencyBudget {
+                duration: rustdds::Duration::from_millis(self.latency_budget_ms as i64),
+            });
+        }
+
         builder.build()
     }
+
+    /// Check that every QoS value is sane before building a participant
+    ///
+    /// [`History::KeepLast`] carries a plain `i32` so it round-trips cleanly
+    /// through JSON/TOML/RON, but a negative depth loaded from a config
+    /// file isn't meaningful; reject it here instead of at QoS-build time.
+    pub fn validate(&self) -> Result<(), DdsError> {
+        if let History::KeepLast(depth) = self.history {
+            if depth < 0 {
+                return Err(DdsError::ConfigError(format!(
+                    "history depth must be non-negative, got {depth}"
+                )));
+            }
+
+            // A bounded max_samples_per_instance smaller than the KeepLast
+            // depth would silently shrink the retained window, so a
+            // late-joining reader would see fewer than `depth` records per
+            // SAC/SIC instance instead of exactly the last `depth`.
+            let max_per_instance = self.resource_limits.max_samples_per_instance;
+            if max_per_instance >= 0 && max_per_instance < depth {
+                return Err(DdsError::ConfigError(format!(
+                    "resource_limits.max_samples_per_instance ({max_per_instance}) is smaller \
+                     than history depth ({depth}); late-joining readers would not receive the \
+                     full retained history"
+                )));
+            }
+        }
+
+        if self.partition.iter().any(|name| name.is_empty()) {
+            return Err(DdsError::ConfigError(
+                "partition entries must not be the empty string".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check whether a reader using `reader` and a writer using `writer`
+    /// are provably QoS-incompatible per the DDS RxO (Request/Offered)
+    /// rules, before either endpoint is created.
+    ///
+    /// Only covers the policies this crate models where offered/requested
+    /// strength is a simple total order: [`Reliability`] (a
+    /// [`Reliability::Reliable`] reader needs a [`Reliability::Reliable`]
+    /// writer; a [`Reliability::BestEffort`] reader matches either) and
+    /// [`Durability`] (ordered `Volatile < TransientLocal < Persistent`; a
+    /// writer can only satisfy a reader requesting durability at or below
+    /// its own). Neither `DdsPublisher::new` nor `DdsSubscriber::new` knows
+    /// the other endpoint's config at construction time, so call this
+    /// yourself before creating a matched reader/writer pair when both
+    /// configs are known up front.
+    pub fn validate_compatible(reader: &DdsConfig, writer: &DdsConfig) -> Result<(), DdsError> {
+        if reader.reliability == Reliability::Reliable && writer.reliability == Reliability::BestEffort
+        {
+            return Err(DdsError::QosError(
+                "reader requests Reliability::Reliable but writer only offers \
+                 Reliability::BestEffort"
+                    .to_string(),
+            ));
+        }
+
+        fn durability_rank(durability: Durability) -> u8 {
+            match durability {
+                Durability::Volatile => 0,
+                Durability::TransientLocal => 1,
+                Durability::Persistent => 2,
+            }
+        }
+
+        if durability_rank(reader.durability) > durability_rank(writer.durability) {
+            return Err(DdsError::QosError(format!(
+                "reader requests {:?} durability but writer only offers {:?}",
+                reader.durability, writer.durability
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Parse a single DDS QoS profile from a TOML string
+    ///
+    /// Rejects configs that fail [`DdsConfig::validate`] (e.g. a negative
+    /// `KeepLast` depth).
+    pub fn from_toml_str(toml: &str) -> Result<Self, DdsError> {
+        let config: DdsConfig = toml::from_str(toml)
+            .map_err(|e| DdsError::ConfigError(format!("invalid DDS TOML config: {e}")))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parse a single DDS QoS profile from a TOML file
+    pub fn from_toml_file(path: impl AsRef<std::path::Path>) -> Result<Self, DdsError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| DdsError::ConfigError(format!("failed to read DDS config: {e}")))?;
+        Self::from_toml_str(&contents)
+    }
+
+    /// Parse a single DDS QoS profile from a JSON string
+    ///
+    /// Rejects configs that fail [`DdsConfig::validate`] (e.g. a negative
+    /// `KeepLast` depth).
+    pub fn from_json_str(json: &str) -> Result<Self, DdsError> {
+        let config: DdsConfig = serde_json::from_str(json)
+            .map_err(|e| DdsError::ConfigError(format!("invalid DDS JSON config: {e}")))?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+/// A named set of DDS QoS profiles loaded from a single config file
+///
+/// Lets an operator keep several [`DdsConfig`]s (e.g. `"surveillance_reliable"`,
+/// `"low_latency"`) in one TOML or JSON file and pick one by name at
+/// runtime instead of recompiling.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DdsProfiles {
+    #[serde(flatten)]
+    profiles: std::collections::HashMap<String, DdsConfig>,
+}
+
+impl DdsProfiles {
+    /// Parse a set of named profiles from a TOML string
+    ///
+    /// Every profile must pass [`DdsConfig::validate`]; the first invalid
+    /// one fails the whole load.
+    pub fn from_toml_str(toml: &str) -> Result<Self, DdsError> {
+        let profiles: DdsProfiles = toml::from_str(toml)
+            .map_err(|e| DdsError::ConfigError(format!("invalid DDS TOML profiles: {e}")))?;
+        profiles.validate()?;
+        Ok(profiles)
+    }
+
+    /// Parse a set of named profiles from a TOML file
+    pub fn from_toml_file(path: impl AsRef<std::path::Path>) -> Result<Self, DdsError> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| DdsError::ConfigError(format!("failed to read DDS profiles: {e}")))?;
+        Self::from_toml_str(&contents)
+    }
+
+    /// Parse a set of named profiles from a JSON string
+    ///
+    /// Every profile must pass [`DdsConfig::validate`]; the first invalid
+    /// one fails the whole load.
+    pub fn from_json_str(json: &str) -> Result<Self, DdsError> {
+        let profiles: DdsProfiles = serde_json::from_str(json)
+            .map_err(|e| DdsError::ConfigError(format!("invalid DDS JSON profiles: {e}")))?;
+        profiles.validate()?;
+        Ok(profiles)
+    }
+
+    fn validate(&self) -> Result<(), DdsError> {
+        for (name, config) in &self.profiles {
+            config
+                .validate()
+                .map_err(|e| DdsError::ConfigError(format!("profile {name:?}: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// Look up a profile by name
+    pub fn get(&self, name: &str) -> Option<&DdsConfig> {
+        self.profiles.get(name)
+    }
+
+    /// Look up a profile by name, erroring if it isn't defined
+    pub fn profile(&self, name: &str) -> Result<DdsConfig, DdsError> {
+        self.get(name)
+            .cloned()
+            .ok_or_else(|| DdsError::ConfigError(format!("no DDS profile named {name:?}")))
+    }
 }
 
 /// ASTERIX data message for DDS transport
@@ -279,8 +666,23 @@ impl Keyed for AsterixMessage {
     }
 }
 
+impl AsterixMessage {
+    /// Render `self.data` as a hex dump using the default [`HexDumpConfig`]
+    ///
+    /// Far more readable than the `{:?}` debug output of a `Vec<u8>` once the
+    /// payload is more than a handful of bytes.
+    pub fn hexdump(&self) -> String {
+        hexdump_bytes(&self.data, HexDumpConfig::default())
+    }
+
+    /// Render `self.data` as a hex dump with a custom [`HexDumpConfig`]
+    pub fn hexdump_with_config(&self, config: HexDumpConfig) -> String {
+        hexdump_bytes(&self.data, config)
+    }
+}
+
 /// Received ASTERIX sample from DDS
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AsterixSample {
     /// ASTERIX category
     pub category: u8,
@@ -296,6 +698,99 @@ pub struct AsterixSample {
     pub topic_name: String,
 }
 
+impl AsterixSample {
+    /// Render `self.data` as a hex dump using the default [`HexDumpConfig`]
+    ///
+    /// Far more readable than the `{:?}` debug output of a `Vec<u8>` once the
+    /// payload is more than a handful of bytes.
+    pub fn hexdump(&self) -> String {
+        hexdump_bytes(&self.data, HexDumpConfig::default())
+    }
+
+    /// Render `self.data` as a hex dump with a custom [`HexDumpConfig`]
+    pub fn hexdump_with_config(&self, config: HexDumpConfig) -> String {
+        hexdump_bytes(&self.data, config)
+    }
+}
+
+/// Result of a successful [`DdsPublisher::publish_acked`] call
+///
+/// `sequence_number` is this publisher's own write-sequence counter (scoped
+/// per [`DdsPublisher`] instance, starting at 0), not the RTPS protocol's
+/// internal sequence number — the high-level `write()` call this crate
+/// already uses doesn't surface that. `acking_readers` is the number of
+/// readers matched with the writer at the moment acknowledgment was
+/// confirmed; under [`Reliability::Reliable`] that means every one of them
+/// has the sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PubAck {
+    /// This publisher's own write-sequence counter for the published sample
+    pub sequence_number: u64,
+    /// Number of matched readers that had acknowledged the sample
+    pub acking_readers: usize,
+}
+
+/// A shared DDS domain participant for bidirectional ASTERIX gateways
+///
+/// A [`DomainParticipant`] is a heavyweight object: creating one allocates
+/// sockets and spins up SPDP/SEDP discovery threads, and it is meant to be
+/// created once per process per domain. `DdsPublisher::new` and
+/// `DdsSubscriber::new` each build their own participant, so an application
+/// that both publishes and subscribes in the same domain should instead
+/// create one `DdsNode` and mint its publishers and subscribers from
+/// [`DdsNode::create_publisher`] and [`DdsNode::create_subscriber`], which
+/// borrow the shared participant instead of creating a second one.
+#[derive(Clone)]
+pub struct DdsNode {
+    participant: Arc<DomainParticipant>,
+}
+
+impl DdsNode {
+    /// Create a node owning a single [`DomainParticipant`] for `domain_id`
+    pub fn new(domain_id: u16) -> Result<Self, DdsError> {
+        let participant = DomainParticipantBuilder::new(domain_id)
+            .build()
+            .map_err(|e| DdsError::ParticipantError(format!("{e:?}")))?;
+
+        Ok(Self {
+            participant: Arc::new(participant),
+        })
+    }
+
+    /// Create a publisher that writes through this node's shared participant
+    ///
+    /// `config.domain_id` is ignored: the participant's domain was already
+    /// fixed by [`DdsNode::new`].
+    pub fn create_publisher(&self, config: DdsConfig) -> Result<DdsPublisher, DdsError> {
+        DdsPublisher::from_participant(Arc::clone(&self.participant), config)
+    }
+
+    /// Create a subscriber that reads through this node's shared participant
+    ///
+    /// `config.domain_id` is ignored: the participant's domain was already
+    /// fixed by [`DdsNode::new`].
+    pub fn create_subscriber(
+        &self,
+        config: DdsConfig,
+        topic_name: &str,
+    ) -> Result<DdsSubscriber, DdsError> {
+        DdsSubscriber::from_participant(Arc::clone(&self.participant), config, topic_name)
+    }
+
+    /// Create a multi-topic subscriber group that reads through this node's
+    /// shared participant
+    ///
+    /// `config.domain_id` is ignored: the participant's domain was already
+    /// fixed by [`DdsNode::new`].
+    pub fn create_subscriber_group(
+        &self,
+        config: DdsConfig,
+        topic_names: &[String],
+    ) -> Result<DdsSubscriberGroup, DdsError> {
+        DdsSubscriberGroup::from_participant(Arc::clone(&self.participant), config, topic_names)
+    }
+}
+
 /// DDS publisher for ASTERIX data
 pub struct DdsPublisher {
     participant: Arc<DomainParticipant>,
@@ -306,22 +801,50 @@ pub struct DdsPublisher {
             DataWriter<AsterixMessage, CDRSerializerAdapter<AsterixMessage>>,
         >,
     >,
+    next_sequence: std::sync::atomic::AtomicU64,
+    #[cfg(feature = "metrics")]
+    metrics: Option<MetricsRecorder>,
 }
 
 impl DdsPublisher {
     /// Create a new DDS publisher
+    ///
+    /// This builds its own [`DomainParticipant`] for `config.domain_id`. An
+    /// application that also subscribes in the same domain should instead
+    /// create one [`DdsNode`] and call [`DdsNode::create_publisher`] so the
+    /// publisher and subscriber share a single participant.
     pub fn new(config: DdsConfig) -> Result<Self, DdsError> {
         let participant = DomainParticipantBuilder::new(config.domain_id)
             .build()
             .map_err(|e| DdsError::ParticipantError(format!("{e:?}")))?;
 
+        Self::from_participant(Arc::new(participant), config)
+    }
+
+    fn from_participant(
+        participant: Arc<DomainParticipant>,
+        config: DdsConfig,
+    ) -> Result<Self, DdsError> {
+        config.validate()?;
+
         Ok(Self {
-            participant: Arc::new(participant),
+            participant,
             config,
             writers: std::sync::Mutex::new(std::collections::HashMap::new()),
+            next_sequence: std::sync::atomic::AtomicU64::new(0),
+            #[cfg(feature = "metrics")]
+            metrics: None,
         })
     }
 
+    /// Attach a [`MetricsRecorder`] so every `publish*` call also records
+    /// its category/SAC/SIC, payload size, and publish latency
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(mut self, metrics: MetricsRecorder) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Publish an ASTERIX record
     pub fn publish(&self, record: &AsterixRecord) -> Result<(), DdsError> {
         let (sac, sic) = self.extract_sac_sic(record);
@@ -342,6 +865,50 @@ impl DdsPublisher {
         self.write_to_topic(&topic_name, message)
     }
 
+    /// Publish an ASTERIX record and block until the DDS stack confirms
+    /// every currently matched reader has acknowledged it
+    ///
+    /// Gives the store-and-forward guarantee a surveillance feed needs when
+    /// a reader reconnects: combine this with [`Reliability::Reliable`] and
+    /// [`Durability::TransientLocal`]/[`Durability::Persistent`] (so a
+    /// late-joining reader also receives the last
+    /// [`History::KeepLast`]-bounded samples) for "publish, and know it
+    /// landed" semantics, instead of this crate's other `publish*` methods,
+    /// which are fire-and-forget even under `Reliable`.
+    ///
+    /// Errors with [`DdsError::ConfigError`] unless `config.reliability` is
+    /// [`Reliability::Reliable`] — acknowledgment tracking is meaningless
+    /// under best-effort delivery. Errors with [`DdsError::WriteError`] if
+    /// `timeout` elapses before every matched reader acknowledges.
+    pub fn publish_acked(
+        &self,
+        record: &AsterixRecord,
+        timeout: Duration,
+    ) -> Result<PubAck, DdsError> {
+        if self.config.reliability != Reliability::Reliable {
+            return Err(DdsError::ConfigError(
+                "publish_acked requires Reliability::Reliable".to_string(),
+            ));
+        }
+
+        let (sac, sic) = self.extract_sac_sic(record);
+        let topic_name = self.build_topic_name(record.category, sac, sic);
+
+        let message = AsterixMessage {
+            key: topic_name.clone(),
+            category: record.category,
+            sac,
+            sic,
+            data: self.get_raw_data(record)?,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_micros() as u64)
+                .unwrap_or(0),
+        };
+
+        self.write_to_topic_acked(&topic_name, message, timeout)
+    }
+
     /// Publish raw ASTERIX bytes with category information
     pub fn publish_raw(&self, category: u8, data: &[u8]) -> Result<(), DdsError> {
         let topic_name = format!("{}_cat{}", self.config.topic_prefix, category);
@@ -389,6 +956,78 @@ impl DdsPublisher {
         self.write_to_topic(&topic_name, message)
     }
 
+    /// Encode `record`'s raw bytes into `buf`, reusing its allocation across
+    /// calls instead of returning a fresh `Vec` every time
+    ///
+    /// Clears `buf` first, then decodes `record.hex_data` into it (falling
+    /// back to JSON-serializing `record` when `hex_data` is empty and the
+    /// `serde` feature is enabled, matching [`DdsPublisher::publish`]). Once
+    /// `buf`'s capacity has grown to fit a typical record, repeated calls no
+    /// longer pay for that growth.
+    pub fn serialize_into(&self, record: &AsterixRecord, buf: &mut Vec<u8>) -> Result<(), DdsError> {
+        buf.clear();
+
+        if !record.hex_data.is_empty() {
+            let decoded = self.hex_to_bytes(&record.hex_data)?;
+            buf.extend_from_slice(&decoded);
+            return Ok(());
+        }
+
+        #[cfg(feature = "serde")]
+        {
+            let json =
+                serde_json::to_vec(record).map_err(|e| DdsError::SerializationError(e.to_string()))?;
+            buf.extend_from_slice(&json);
+            Ok(())
+        }
+
+        #[cfg(not(feature = "serde"))]
+        Err(DdsError::SerializationError(
+            "No serialization method available (provide hex_data)".to_string(),
+        ))
+    }
+
+    /// Publish an ASTERIX record, encoding through a caller-owned, reused
+    /// scratch buffer instead of allocating a fresh one per call
+    ///
+    /// See [`DdsPublisher::serialize_into`]. Pass the same `buf` across
+    /// repeated calls (e.g. one per source thread) to avoid `publish`'s
+    /// per-call allocation once `buf`'s capacity has settled.
+    pub fn publish_into(&self, record: &AsterixRecord, buf: &mut Vec<u8>) -> Result<(), DdsError> {
+        let (sac, sic) = self.extract_sac_sic(record);
+        let topic_name = self.build_topic_name(record.category, sac, sic);
+
+        self.serialize_into(record, buf)?;
+
+        let message = AsterixMessage {
+            key: topic_name.clone(),
+            category: record.category,
+            sac,
+            sic,
+            data: buf.clone(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_micros() as u64)
+                .unwrap_or(0),
+        };
+
+        self.write_to_topic(&topic_name, message)
+    }
+
+    /// Publish many records through one reused scratch buffer
+    ///
+    /// Equivalent to calling [`DdsPublisher::publish_into`] for each record
+    /// in order, but shares a single scratch buffer across the whole batch
+    /// and writes through each topic's already-cached writer, so the only
+    /// per-record cost is the encode itself, not writer lookup/creation.
+    pub fn publish_batch(&self, records: &[&AsterixRecord]) -> Result<(), DdsError> {
+        let mut buf = Vec::new();
+        for record in records {
+            self.publish_into(record, &mut buf)?;
+        }
+        Ok(())
+    }
+
     fn write_to_topic(&self, topic_name: &str, message: AsterixMessage) -> Result<(), DdsError> {
         let mut writers = self.writers.lock().unwrap();
 
@@ -418,48 +1057,120 @@ impl DdsPublisher {
             writers.insert(topic_name.to_string(), writer);
         }
 
+        #[cfg(feature = "metrics")]
+        let (category, sac, sic, payload_bytes) =
+            (message.category, message.sac, message.sic, message.data.len());
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
         let writer = writers.get(topic_name).unwrap();
         writer
             .write(message, None)
             .map_err(|e| DdsError::WriteError(format!("{e:?}")))?;
 
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_publish(category, sac, sic, payload_bytes, start.elapsed());
+        }
+
         log::debug!("Published ASTERIX to DDS topic {topic_name}");
         Ok(())
     }
 
-    fn build_topic_name(&self, category: u8, sac: Option<u8>, sic: Option<u8>) -> String {
-        match (sac, sic) {
-            (Some(s), Some(c)) => {
-                format!(
-                    "{}_cat{}_sac{}_sic{}",
-                    self.config.topic_prefix, category, s, c
-                )
-            }
-            _ => format!("{}_cat{}", self.config.topic_prefix, category),
-        }
-    }
+    fn write_to_topic_acked(
+        &self,
+        topic_name: &str,
+        message: AsterixMessage,
+        timeout: Duration,
+    ) -> Result<PubAck, DdsError> {
+        let mut writers = self.writers.lock().unwrap();
 
-    fn extract_sac_sic(&self, record: &AsterixRecord) -> (Option<u8>, Option<u8>) {
-        let item_id = format!("I{:03}/010", record.category);
+        if !writers.contains_key(topic_name) {
+            let qos = self.config.build_qos();
 
-        if let Some(item) = record.get_item(&item_id) {
-            let sac = item
-                .fields
-                .get("SAC")
-                .and_then(|v| v.as_i64())
-                .map(|v| v as u8);
+            let topic = self
+                .participant
+                .create_topic(
+                    topic_name.to_string(),
+                    "AsterixMessage".to_string(),
+                    &qos,
+                    TopicKind::WithKey,
+                )
+                .map_err(|e| DdsError::TopicError(format!("{e:?}")))?;
 
-            let sic = item
-                .fields
-                .get("SIC")
-                .and_then(|v| v.as_i64())
-                .map(|v| v as u8);
+            let publisher = self
+                .participant
+                .create_publisher(&qos)
+                .map_err(|e| DdsError::PublisherError(format!("{e:?}")))?;
 
-            return (sac, sic);
+            let writer = publisher
+                .create_datawriter_cdr::<AsterixMessage>(&topic, None)
+                .map_err(|e| DdsError::PublisherError(format!("{e:?}")))?;
+
+            writers.insert(topic_name.to_string(), writer);
         }
 
-        (None, None)
-    }
+        #[cfg(feature = "metrics")]
+        let (category, sac, sic, payload_bytes) =
+            (message.category, message.sac, message.sic, message.data.len());
+        #[cfg(feature = "metrics")]
+        let start = std::time::Instant::now();
+
+        let writer = writers.get(topic_name).unwrap();
+        writer
+            .write(message, None)
+            .map_err(|e| DdsError::WriteError(format!("{e:?}")))?;
+
+        let sequence_number = self
+            .next_sequence
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        writer
+            .wait_for_acknowledgments(rustdds::Duration::from_std(timeout))
+            .map_err(|e| DdsError::WriteError(format!("{e:?}")))?;
+
+        let acking_readers = writer.get_matched_subscriptions().len();
+
+        #[cfg(feature = "metrics")]
+        if let Some(metrics) = &self.metrics {
+            metrics.record_publish(category, sac, sic, payload_bytes, start.elapsed());
+        }
+
+        log::debug!(
+            "Published and acked ASTERIX to DDS topic {topic_name} (seq {sequence_number}, {acking_readers} readers)"
+        );
+
+        Ok(PubAck {
+            sequence_number,
+            acking_readers,
+        })
+    }
+
+    fn build_topic_name(&self, category: u8, sac: Option<u8>, sic: Option<u8>) -> String {
+        topic_name_for(&self.config.topic_prefix, category, sac, sic)
+    }
+
+    fn extract_sac_sic(&self, record: &AsterixRecord) -> (Option<u8>, Option<u8>) {
+        let item_id = format!("I{:03}/010", record.category);
+
+        if let Some(item) = record.get_item(&item_id) {
+            let sac = item
+                .fields
+                .get("SAC")
+                .and_then(|v| v.as_i64())
+                .map(|v| v as u8);
+
+            let sic = item
+                .fields
+                .get("SIC")
+                .and_then(|v| v.as_i64())
+                .map(|v| v as u8);
+
+            return (sac, sic);
+        }
+
+        (None, None)
+    }
 
     fn get_raw_data(&self, record: &AsterixRecord) -> Result<Vec<u8>, DdsError> {
         if !record.hex_data.is_empty() {
@@ -478,21 +1189,108 @@ impl DdsPublisher {
     }
 
     fn hex_to_bytes(&self, hex: &str) -> Result<Vec<u8>, DdsError> {
-        let hex_clean: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
+        match self.config.hex_input_mode {
+            HexInputMode::Lenient => {
+                let stripped = hex.strip_prefix("0x").or_else(|| hex.strip_prefix("0X"));
+                from_hex(stripped.unwrap_or(hex))
+            }
+            HexInputMode::Strict => from_hex_strict(hex),
+        }
+        .map_err(|e| DdsError::SerializationError(e.to_string()))
+    }
 
-        if hex_clean.len() % 2 != 0 {
-            return Err(DdsError::SerializationError(
-                "Invalid hex string length".to_string(),
-            ));
+    /// Manually assert that every writer owned by this publisher is alive
+    ///
+    /// Only useful when `config.liveliness` is [`Liveliness::ManualByTopic`];
+    /// with the default [`Liveliness::Automatic`] policy the RTPS stack
+    /// refreshes liveliness on its own and calling this is unnecessary. A
+    /// radar source sitting idle between sweeps can call this on a timer to
+    /// keep its topics from being reported lost by subscribers.
+    pub fn assert_liveliness(&self) -> Result<(), DdsError> {
+        let writers = self.writers.lock().unwrap();
+        for writer in writers.values() {
+            writer
+                .assert_liveliness()
+                .map_err(|e| DdsError::WriteError(format!("{e:?}")))?;
         }
+        Ok(())
+    }
+}
 
-        (0..hex_clean.len())
-            .step_by(2)
-            .map(|i| {
-                u8::from_str_radix(&hex_clean[i..i + 2], 16)
-                    .map_err(|e| DdsError::SerializationError(e.to_string()))
-            })
-            .collect()
+/// A token identifying the reader's readiness source when it is registered
+/// with the [`mio_06::Poll`] used by [`DdsSubscriber::recv_wait`].
+const READER_READY_TOKEN: mio_06::Token = mio_06::Token(0);
+
+/// A single predicate a [`Filter`] can apply to one field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldFilter {
+    /// The field must equal this exact value
+    Eq(u8),
+    /// The field must fall within this inclusive range
+    Range(u8, u8),
+    /// The field must equal one of these values
+    In(Vec<u8>),
+}
+
+/// A content filter for [`DdsSubscriber::with_filter`], keyed on the same
+/// category/SAC/SIC routing fields [`DdsPublisher::publish_raw_with_routing`]
+/// writes
+///
+/// Each set field is compiled into a DDS content-filter expression and
+/// bound via numbered parameters (`%0`, `%1`, ...), so the predicate is
+/// evaluated by the DDS middleware itself: non-matching samples are
+/// dropped by the reader before this process ever deserializes them. Unset
+/// fields are not constrained.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    /// Constrain the ASTERIX category
+    pub category: Option<FieldFilter>,
+    /// Constrain the System Area Code
+    pub sac: Option<FieldFilter>,
+    /// Constrain the System Identification Code
+    pub sic: Option<FieldFilter>,
+}
+
+impl Filter {
+    /// Compile the set fields into a DDS SQL-like filter expression and its
+    /// bound parameters, in `category`, `sac`, `sic` order
+    fn compile(&self) -> (String, Vec<String>) {
+        let mut clauses = Vec::new();
+        let mut params = Vec::new();
+
+        for (field, predicate) in [
+            ("category", &self.category),
+            ("sac", &self.sac),
+            ("sic", &self.sic),
+        ] {
+            if let Some(predicate) = predicate {
+                match predicate {
+                    FieldFilter::Eq(value) => {
+                        clauses.push(format!("{field} = %{}", params.len()));
+                        params.push(value.to_string());
+                    }
+                    FieldFilter::Range(lo, hi) => {
+                        clauses.push(format!(
+                            "({field} >= %{} AND {field} <= %{})",
+                            params.len(),
+                            params.len() + 1
+                        ));
+                        params.push(lo.to_string());
+                        params.push(hi.to_string());
+                    }
+                    FieldFilter::In(values) => {
+                        let start = params.len();
+                        let placeholders: Vec<String> = (0..values.len())
+                            .map(|i| format!("%{}", start + i))
+                            .collect();
+                        clauses.push(format!("{field} IN ({})", placeholders.join(", ")));
+                        params.extend(values.iter().map(|v| v.to_string()));
+                    }
+                }
+            }
+        }
+
+        (clauses.join(" AND "), params)
     }
 }
 
@@ -502,6 +1300,44 @@ pub struct DdsSubscriber {
     participant: Arc<DomainParticipant>,
     reader: DataReader<AsterixMessage, CDRDeserializerAdapter<AsterixMessage>>,
     topic_name: String,
+    topic_prefix: String,
+    poll: mio_06::Poll,
+    #[cfg(feature = "metrics")]
+    metrics: Option<MetricsRecorder>,
+}
+
+/// A source health transition decoded from [`DdsSubscriber::poll_status`]
+///
+/// These mirror the standard DDS `LivelinessChangedStatus` and
+/// `RequestedDeadlineMissedStatus` structures, tagged with the ASTERIX
+/// category/SAC/SIC recovered from the topic name so callers monitoring a
+/// [`DdsSubscriberGroup`]-style fan-out of sources don't have to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceHealthEvent {
+    /// The set of publishers considered alive on this topic changed
+    LivelinessChanged {
+        /// ASTERIX category recovered from the topic name
+        category: u8,
+        /// System Area Code, if encoded in the topic name
+        sac: Option<u8>,
+        /// System Identification Code, if encoded in the topic name
+        sic: Option<u8>,
+        /// Number of publishers currently considered alive
+        alive_count: i32,
+        /// Number of publishers currently considered not alive
+        not_alive_count: i32,
+    },
+    /// A publisher missed its deadline QoS contract on this topic
+    DeadlineMissed {
+        /// ASTERIX category recovered from the topic name
+        category: u8,
+        /// System Area Code, if encoded in the topic name
+        sac: Option<u8>,
+        /// System Identification Code, if encoded in the topic name
+        sic: Option<u8>,
+        /// Cumulative number of missed deadlines observed on this reader
+        total_count: i32,
+    },
 }
 
 impl DdsSubscriber {
@@ -511,11 +1347,95 @@ impl DdsSubscriber {
     ///
     /// * `config` - DDS configuration
     /// * `topic_name` - Topic name to subscribe to (e.g., "asterix_cat48")
+    ///
+    /// This builds its own [`DomainParticipant`] for `config.domain_id`. An
+    /// application that also publishes in the same domain should instead
+    /// create one [`DdsNode`] and call [`DdsNode::create_subscriber`] so the
+    /// publisher and subscriber share a single participant.
     pub fn new(config: DdsConfig, topic_name: &str) -> Result<Self, DdsError> {
         let participant = DomainParticipantBuilder::new(config.domain_id)
             .build()
             .map_err(|e| DdsError::ParticipantError(format!("{e:?}")))?;
 
+        Self::from_participant(Arc::new(participant), config, topic_name)
+    }
+
+    /// Create a subscriber that only receives samples matching `filter`
+    ///
+    /// `filter` is compiled into a DDS content-filter expression bound to
+    /// the reader, so the middleware discards non-matching samples itself:
+    /// a consumer interested in only one sensor's Cat048 stream never pays
+    /// to deserialize unrelated samples. See [`Filter`].
+    pub fn with_filter(config: DdsConfig, topic_name: &str, filter: Filter) -> Result<Self, DdsError> {
+        let participant = DomainParticipantBuilder::new(config.domain_id)
+            .build()
+            .map_err(|e| DdsError::ParticipantError(format!("{e:?}")))?;
+
+        Self::from_participant_filtered(Arc::new(participant), config, topic_name, Some(filter))
+    }
+
+    /// Create a subscriber filtered by a small textual content-filter
+    /// expression, e.g. `"category = 62 AND sac = 10 AND sic IN (20, 21)"`
+    ///
+    /// This is [`with_filter`](Self::with_filter) for callers that would
+    /// rather configure a subscription from a string (a CLI flag, a config
+    /// file field) than build a [`Filter`] value in code. The expression is
+    /// parsed by [`parse_filter_expr`], which validates field names against
+    /// the fixed `category`/`sac`/`sic` routing schema and only recognizes
+    /// `=`, `IN (...)`, and `AND`/`OR` — anything else (an unknown field, a
+    /// non-numeric value, unbalanced parentheses) is rejected with
+    /// [`DdsError::TopicError`] before any DDS entity is created, so a typo
+    /// in the expression never creates hundreds of unintended
+    /// single-sensor topics or a content filter the middleware silently
+    /// can't evaluate.
+    pub fn with_filter_expr(
+        config: DdsConfig,
+        topic_name: &str,
+        filter_expr: &str,
+    ) -> Result<Self, DdsError> {
+        let participant = DomainParticipantBuilder::new(config.domain_id)
+            .build()
+            .map_err(|e| DdsError::ParticipantError(format!("{e:?}")))?;
+
+        let compiled = parse_filter_expr(filter_expr)?;
+        Self::from_participant_with_compiled_filter(
+            Arc::new(participant),
+            config,
+            topic_name,
+            Some(compiled),
+        )
+    }
+
+    fn from_participant(
+        participant: Arc<DomainParticipant>,
+        config: DdsConfig,
+        topic_name: &str,
+    ) -> Result<Self, DdsError> {
+        Self::from_participant_filtered(participant, config, topic_name, None)
+    }
+
+    fn from_participant_filtered(
+        participant: Arc<DomainParticipant>,
+        config: DdsConfig,
+        topic_name: &str,
+        filter: Option<Filter>,
+    ) -> Result<Self, DdsError> {
+        Self::from_participant_with_compiled_filter(
+            participant,
+            config,
+            topic_name,
+            filter.map(|f| f.compile()),
+        )
+    }
+
+    fn from_participant_with_compiled_filter(
+        participant: Arc<DomainParticipant>,
+        config: DdsConfig,
+        topic_name: &str,
+        compiled_filter: Option<(String, Vec<String>)>,
+    ) -> Result<Self, DdsError> {
+        config.validate()?;
+
         let qos = config.build_qos();
 
         let topic = participant
@@ -531,17 +1451,47 @@ impl DdsSubscriber {
             .create_subscriber(&qos)
             .map_err(|e| DdsError::SubscriberError(format!("{e:?}")))?;
 
-        let reader = subscriber
-            .create_datareader_cdr::<AsterixMessage>(&topic, None)
-            .map_err(|e| DdsError::SubscriberError(format!("{e:?}")))?;
+        let reader = match compiled_filter {
+            Some((expression, params)) => subscriber
+                .create_datareader_cdr_with_filter::<AsterixMessage>(
+                    &topic, None, &expression, &params,
+                )
+                .map_err(|e| DdsError::SubscriberError(format!("{e:?}")))?,
+            None => subscriber
+                .create_datareader_cdr::<AsterixMessage>(&topic, None)
+                .map_err(|e| DdsError::SubscriberError(format!("{e:?}")))?,
+        };
+
+        let poll = mio_06::Poll::new()
+            .map_err(|e| DdsError::SubscriberError(format!("failed to create poll: {e}")))?;
+        poll.register(
+            &reader,
+            READER_READY_TOKEN,
+            mio_06::Ready::readable(),
+            mio_06::PollOpt::edge(),
+        )
+        .map_err(|e| DdsError::SubscriberError(format!("failed to register reader: {e}")))?;
 
         Ok(Self {
-            participant: Arc::new(participant),
+            participant,
             reader,
             topic_name: topic_name.to_string(),
+            topic_prefix: config.topic_prefix,
+            poll,
+            #[cfg(feature = "metrics")]
+            metrics: None,
         })
     }
 
+    /// Attach a [`MetricsRecorder`] so every sample returned by
+    /// [`DdsSubscriber::try_recv`] (and the blocking `recv*` variants built
+    /// on it) also records its category/SAC/SIC and payload size
+    #[cfg(feature = "metrics")]
+    pub fn with_metrics(mut self, metrics: MetricsRecorder) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
     /// Try to receive a sample without blocking
     pub fn try_recv(&mut self) -> Option<AsterixSample> {
         use rustdds::with_key::Sample;
@@ -549,39 +1499,685 @@ impl DdsSubscriber {
         match self.reader.take_next_sample() {
             Ok(Some(sample)) => {
                 match sample.into_value() {
-                    Sample::Value(msg) => Some(AsterixSample {
-                        category: msg.category,
-                        sac: msg.sac,
-                        sic: msg.sic,
-                        data: msg.data,
-                        timestamp: msg.timestamp,
-                        topic_name: self.topic_name.clone(),
-                    }),
+                    Sample::Value(msg) => {
+                        #[cfg(feature = "metrics")]
+                        if let Some(metrics) = &self.metrics {
+                            metrics.record_receive(msg.category, msg.sac, msg.sic, msg.data.len());
+                        }
+
+                        Some(AsterixSample {
+                            category: msg.category,
+                            sac: msg.sac,
+                            sic: msg.sic,
+                            data: msg.data,
+                            timestamp: msg.timestamp,
+                            topic_name: self.topic_name.clone(),
+                        })
+                    }
                     Sample::Dispose(_key) => {
                         // Instance was disposed, no data to return
                         None
                     }
                 }
             }
-            Ok(None) => None,
-            Err(e) => {
-                log::warn!("DDS read error: {e:?}");
-                None
-            }
+            Ok(None) => None,
+            Err(e) => {
+                log::warn!("DDS read error: {e:?}");
+                None
+            }
+        }
+    }
+
+    /// Receive the next sample with timeout, busy-polling every millisecond
+    ///
+    /// Prefer [`DdsSubscriber::recv_wait`], which blocks on the reader's
+    /// readiness instead of sleeping and retrying.
+    pub fn recv_timeout(&mut self, timeout: Duration) -> Option<AsterixSample> {
+        let start = std::time::Instant::now();
+        while start.elapsed() < timeout {
+            if let Some(sample) = self.try_recv() {
+                return Some(sample);
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        None
+    }
+
+    /// Receive the next sample, blocking until one arrives or `timeout`
+    /// elapses
+    ///
+    /// This registers the reader with a [`mio_06::Poll`] and blocks on its
+    /// readiness instead of sleeping and re-polling, so a sample that
+    /// arrives early is returned immediately rather than after up to a
+    /// millisecond of extra latency.
+    pub fn recv_wait(&mut self, timeout: Duration) -> Option<AsterixSample> {
+        if let Some(sample) = self.try_recv() {
+            return Some(sample);
+        }
+
+        let mut events = mio_06::Events::with_capacity(4);
+        self.poll.poll(&mut events, Some(timeout)).ok()?;
+
+        self.try_recv()
+    }
+
+    /// Receive the next sample, blocking indefinitely until one arrives
+    pub fn recv(&mut self) -> Option<AsterixSample> {
+        loop {
+            if let Some(sample) = self.try_recv() {
+                return Some(sample);
+            }
+
+            let mut events = mio_06::Events::with_capacity(4);
+            self.poll.poll(&mut events, None).ok()?;
+        }
+    }
+
+    /// Poll for a reader status change (e.g. a matching publisher was
+    /// discovered or lost) without blocking
+    ///
+    /// This lets callers react to publisher discovery events directly
+    /// instead of inferring them from when data starts or stops arriving.
+    pub fn try_recv_status(&mut self) -> Option<rustdds::dds::statusevents::DataReaderStatus> {
+        use rustdds::dds::statusevents::StatusEvented;
+        self.reader.try_recv_status()
+    }
+
+    /// How often the background thread started by [`DdsSubscriber::on_data`]
+    /// re-checks whether [`DdsSubscription::stop`] was called, bounding how
+    /// long `stop` can take to return.
+    const LISTENER_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+    /// Register an event-driven listener: `callback` runs on a dedicated
+    /// background thread for every sample this subscriber receives, instead
+    /// of a caller busy-polling [`DdsSubscriber::try_recv`]/[`DdsSubscriber::recv_wait`]
+    /// itself.
+    ///
+    /// Mirrors the DDS `DataReaderListener` pattern — registering a listener
+    /// hands the reader over to the middleware's own delivery mechanism, so
+    /// this consumes `self` the same way. The returned [`DdsSubscription`]
+    /// is the unregistration handle: drop it, or call
+    /// [`DdsSubscription::stop`] explicitly, to stop the thread and join it.
+    pub fn on_data(
+        mut self,
+        callback: impl Fn(AsterixSample) + Send + 'static,
+    ) -> DdsSubscription {
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let stop_thread = Arc::clone(&stop);
+
+        let handle = std::thread::spawn(move || {
+            while !stop_thread.load(std::sync::atomic::Ordering::SeqCst) {
+                if let Some(sample) = self.recv_wait(Self::LISTENER_POLL_INTERVAL) {
+                    callback(sample);
+                }
+            }
+        });
+
+        DdsSubscription {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Block until at least one of `subscribers` has a sample ready, or
+    /// `timeout` elapses, without taking the sample(s) itself
+    ///
+    /// Returns the indices into `subscribers` that became ready (in no
+    /// particular order; empty if `timeout` elapsed with nothing ready).
+    /// Mirrors the DDS `WaitSet`/`ReadCondition` pattern for multiplexing
+    /// several readers in one blocking call; built on [`WaitSet`], which is
+    /// worth using directly instead when waiting repeatedly on the same set
+    /// of subscribers, to avoid re-registering them with a fresh `Poll` on
+    /// every call. Call [`DdsSubscriber::try_recv`] on each ready
+    /// subscriber to actually take its sample.
+    pub fn wait(subscribers: &[&DdsSubscriber], timeout: Duration) -> Result<Vec<usize>, DdsError> {
+        let wait_set = WaitSet::new(subscribers)?;
+        Ok(wait_set
+            .wait(timeout)
+            .into_iter()
+            .map(|condition| condition.index())
+            .collect())
+    }
+
+    /// Poll for a source health event (liveliness change or missed deadline)
+    /// without blocking
+    ///
+    /// Decodes the raw status from [`DdsSubscriber::try_recv_status`] into a
+    /// [`SourceHealthEvent`] tagged with the category/SAC/SIC recovered from
+    /// this subscriber's topic name, so a radar health monitor doesn't need
+    /// to match on the raw rustdds status type itself.
+    pub fn poll_status(&mut self) -> Option<SourceHealthEvent> {
+        use rustdds::dds::statusevents::DataReaderStatus;
+
+        let (category, sac, sic) = parse_topic_name(&self.topic_name, &self.topic_prefix);
+
+        match self.try_recv_status()? {
+            DataReaderStatus::LivelinessChanged {
+                alive_count,
+                not_alive_count,
+                ..
+            } => Some(SourceHealthEvent::LivelinessChanged {
+                category,
+                sac,
+                sic,
+                alive_count,
+                not_alive_count,
+            }),
+            DataReaderStatus::RequestedDeadlineMissed { total_count, .. } => {
+                Some(SourceHealthEvent::DeadlineMissed {
+                    category,
+                    sac,
+                    sic,
+                    total_count,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Handle for the background listener thread started by
+/// [`DdsSubscriber::on_data`]
+///
+/// Dropping this (or calling [`DdsSubscription::stop`] explicitly) signals
+/// the listener thread to exit and joins it, so a caller can deterministically
+/// stop receiving callbacks instead of leaking the thread for the rest of the
+/// process's lifetime.
+pub struct DdsSubscription {
+    stop: Arc<std::sync::atomic::AtomicBool>,
+    handle: Option<std::thread::JoinHandle<()>>,
+}
+
+impl DdsSubscription {
+    /// Signal the listener thread to stop and block until it exits
+    pub fn stop(mut self) {
+        self.stop_and_join();
+    }
+
+    fn stop_and_join(&mut self) {
+        self.stop.store(true, std::sync::atomic::Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for DdsSubscription {
+    fn drop(&mut self) {
+        self.stop_and_join();
+    }
+}
+
+/// A registered readiness source in a [`WaitSet`], identifying which
+/// subscriber (by its index in the slice passed to [`WaitSet::new`]) became
+/// ready
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadCondition(usize);
+
+impl ReadCondition {
+    /// The index into the slice passed to [`WaitSet::new`] this condition
+    /// refers to
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+/// A `WaitSet`-style multiplexer over several [`DdsSubscriber`]s
+///
+/// Mirrors the DDS `WaitSet`/`ReadCondition` pattern: register a set of
+/// subscribers once, then block on [`WaitSet::wait`] until at least one has
+/// a sample ready, instead of busy-polling each with `try_recv` in a loop.
+/// Built on the same [`mio_06::Poll`] mechanism [`DdsSubscriberGroup`] uses
+/// internally, but keeps each subscriber separately addressable (by index)
+/// instead of merging them into one combined sample stream — useful when a
+/// caller needs to know *which* topic became ready, not just that one did.
+pub struct WaitSet {
+    poll: mio_06::Poll,
+}
+
+impl WaitSet {
+    /// Build a `WaitSet` over `subscribers`, registering each one's reader
+    /// readiness with a dedicated [`mio_06::Poll`]
+    ///
+    /// The [`ReadCondition`]s [`WaitSet::wait`] returns correspond to
+    /// `subscribers` by index, in the order given here.
+    pub fn new(subscribers: &[&DdsSubscriber]) -> Result<Self, DdsError> {
+        let poll = mio_06::Poll::new()
+            .map_err(|e| DdsError::SubscriberError(format!("failed to create poll: {e}")))?;
+
+        for (index, subscriber) in subscribers.iter().enumerate() {
+            poll.register(
+                &subscriber.reader,
+                mio_06::Token(index),
+                mio_06::Ready::readable(),
+                mio_06::PollOpt::edge(),
+            )
+            .map_err(|e| DdsError::SubscriberError(format!("failed to register reader: {e}")))?;
+        }
+
+        Ok(Self { poll })
+    }
+
+    /// Block until at least one registered subscriber has a sample ready, or
+    /// `timeout` elapses
+    ///
+    /// Returns the triggered [`ReadCondition`]s, in no particular order;
+    /// empty if `timeout` elapsed with nothing ready. This only reports
+    /// readiness — call [`DdsSubscriber::try_recv`] on the corresponding
+    /// subscriber(s) to actually take their sample(s).
+    pub fn wait(&self, timeout: Duration) -> Vec<ReadCondition> {
+        let mut events = mio_06::Events::with_capacity(8);
+        if self.poll.poll(&mut events, Some(timeout)).is_err() {
+            return Vec::new();
+        }
+
+        events
+            .iter()
+            .map(|event| ReadCondition(event.token().0))
+            .collect()
+    }
+}
+
+fn topic_name_for(prefix: &str, category: u8, sac: Option<u8>, sic: Option<u8>) -> String {
+    match (sac, sic) {
+        (Some(s), Some(c)) => format!("{prefix}_cat{category}_sac{s}_sic{c}"),
+        _ => format!("{prefix}_cat{category}"),
+    }
+}
+
+struct GroupReader {
+    reader: DataReader<AsterixMessage, CDRDeserializerAdapter<AsterixMessage>>,
+    topic_name: String,
+}
+
+/// A subscriber fanning several ASTERIX topics into one sample stream
+///
+/// Where [`DdsSubscriber`] binds to exactly one topic, `DdsSubscriberGroup`
+/// creates one reader per topic on a single shared DDS `Subscriber`, but
+/// still exposes a single [`DdsSubscriberGroup::try_recv`] /
+/// [`DdsSubscriberGroup::recv_wait`] call site, so a consumer listening for
+/// CAT048, CAT062, and CAT021 from several radars doesn't need to create,
+/// poll, and correlate several subscribers by hand. Each returned
+/// [`AsterixSample`] has its `category`/`sac`/`sic` populated by
+/// [`parse_topic_name`]-ing the topic the sample arrived on, rather than
+/// trusting the payload to describe its own routing.
+pub struct DdsSubscriberGroup {
+    #[allow(dead_code)]
+    participant: Arc<DomainParticipant>,
+    topic_prefix: String,
+    readers: Vec<GroupReader>,
+    poll: mio_06::Poll,
+}
+
+impl DdsSubscriberGroup {
+    /// Create a group subscribing to each of `topic_names`
+    ///
+    /// This builds its own [`DomainParticipant`] for `config.domain_id`. An
+    /// application that also publishes (or subscribes elsewhere) in the same
+    /// domain should instead create one [`DdsNode`] and call
+    /// [`DdsNode::create_subscriber_group`] so every endpoint shares a
+    /// single participant.
+    pub fn new(config: DdsConfig, topic_names: &[String]) -> Result<Self, DdsError> {
+        let participant = DomainParticipantBuilder::new(config.domain_id)
+            .build()
+            .map_err(|e| DdsError::ParticipantError(format!("{e:?}")))?;
+
+        Self::from_participant(Arc::new(participant), config, topic_names)
+    }
+
+    /// Create a group subscribing to one topic per entry in `categories`,
+    /// optionally narrowed to a single SAC/SIC pair, building each topic
+    /// name from `config.topic_prefix` the same way
+    /// [`DdsPublisher::publish_raw`] and
+    /// [`DdsPublisher::publish_raw_with_routing`] do.
+    pub fn for_categories(
+        config: DdsConfig,
+        categories: &[u8],
+        sac: Option<u8>,
+        sic: Option<u8>,
+    ) -> Result<Self, DdsError> {
+        let topic_names: Vec<String> = categories
+            .iter()
+            .map(|&category| topic_name_for(&config.topic_prefix, category, sac, sic))
+            .collect();
+
+        Self::new(config, &topic_names)
+    }
+
+    fn from_participant(
+        participant: Arc<DomainParticipant>,
+        config: DdsConfig,
+        topic_names: &[String],
+    ) -> Result<Self, DdsError> {
+        config.validate()?;
+
+        let qos = config.build_qos();
+
+        let subscriber = participant
+            .create_subscriber(&qos)
+            .map_err(|e| DdsError::SubscriberError(format!("{e:?}")))?;
+
+        let poll = mio_06::Poll::new()
+            .map_err(|e| DdsError::SubscriberError(format!("failed to create poll: {e}")))?;
+
+        let mut readers = Vec::with_capacity(topic_names.len());
+        for (index, topic_name) in topic_names.iter().enumerate() {
+            let topic = participant
+                .create_topic(
+                    topic_name.clone(),
+                    "AsterixMessage".to_string(),
+                    &qos,
+                    TopicKind::WithKey,
+                )
+                .map_err(|e| DdsError::TopicError(format!("{e:?}")))?;
+
+            let reader = subscriber
+                .create_datareader_cdr::<AsterixMessage>(&topic, None)
+                .map_err(|e| DdsError::SubscriberError(format!("{e:?}")))?;
+
+            poll.register(
+                &reader,
+                mio_06::Token(index),
+                mio_06::Ready::readable(),
+                mio_06::PollOpt::edge(),
+            )
+            .map_err(|e| DdsError::SubscriberError(format!("failed to register reader: {e}")))?;
+
+            readers.push(GroupReader {
+                reader,
+                topic_name: topic_name.clone(),
+            });
+        }
+
+        Ok(Self {
+            participant,
+            topic_prefix: config.topic_prefix,
+            readers,
+            poll,
+        })
+    }
+
+    /// Try to receive a sample from any member topic without blocking
+    pub fn try_recv(&mut self) -> Option<AsterixSample> {
+        use rustdds::with_key::Sample;
+
+        for reader in &mut self.readers {
+            match reader.reader.take_next_sample() {
+                Ok(Some(sample)) => {
+                    if let Sample::Value(msg) = sample.into_value() {
+                        let (category, sac, sic) =
+                            parse_topic_name(&reader.topic_name, &self.topic_prefix);
+                        return Some(AsterixSample {
+                            category,
+                            sac,
+                            sic,
+                            data: msg.data,
+                            timestamp: msg.timestamp,
+                            topic_name: reader.topic_name.clone(),
+                        });
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => log::warn!("DDS read error on {}: {e:?}", reader.topic_name),
+            }
+        }
+
+        None
+    }
+
+    /// Receive the next sample from any member topic, blocking until one
+    /// arrives or `timeout` elapses
+    pub fn recv_wait(&mut self, timeout: Duration) -> Option<AsterixSample> {
+        if let Some(sample) = self.try_recv() {
+            return Some(sample);
+        }
+
+        let mut events = mio_06::Events::with_capacity(self.readers.len().max(1));
+        self.poll.poll(&mut events, Some(timeout)).ok()?;
+
+        self.try_recv()
+    }
+
+    /// Receive the next sample from any member topic, blocking indefinitely
+    /// until one arrives
+    pub fn recv(&mut self) -> Option<AsterixSample> {
+        loop {
+            if let Some(sample) = self.try_recv() {
+                return Some(sample);
+            }
+
+            let mut events = mio_06::Events::with_capacity(self.readers.len().max(1));
+            self.poll.poll(&mut events, None).ok()?;
+        }
+    }
+}
+
+/// The fields [`parse_filter_expr`] recognizes — the same routing schema
+/// [`Filter`] constrains
+const FILTER_EXPR_FIELDS: [&str; 3] = ["category", "sac", "sic"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FilterExprToken {
+    Ident(String),
+    Number(u8),
+    Eq,
+    Comma,
+    LParen,
+    RParen,
+    And,
+    Or,
+    In,
+}
+
+fn tokenize_filter_expr(expr: &str) -> Result<Vec<FilterExprToken>, DdsError> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '=' {
+            chars.next();
+            tokens.push(FilterExprToken::Eq);
+        } else if c == ',' {
+            chars.next();
+            tokens.push(FilterExprToken::Comma);
+        } else if c == '(' {
+            chars.next();
+            tokens.push(FilterExprToken::LParen);
+        } else if c == ')' {
+            chars.next();
+            tokens.push(FilterExprToken::RParen);
+        } else if c.is_ascii_digit() {
+            let mut digits = String::new();
+            while let Some(&d) = chars.peek() {
+                if d.is_ascii_digit() {
+                    digits.push(d);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            let value = digits
+                .parse::<u8>()
+                .map_err(|_| DdsError::TopicError(format!("value out of range: {digits}")))?;
+            tokens.push(FilterExprToken::Number(value));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let mut word = String::new();
+            while let Some(&w) = chars.peek() {
+                if w.is_ascii_alphanumeric() || w == '_' {
+                    word.push(w);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(match word.to_ascii_uppercase().as_str() {
+                "AND" => FilterExprToken::And,
+                "OR" => FilterExprToken::Or,
+                "IN" => FilterExprToken::In,
+                _ => FilterExprToken::Ident(word),
+            });
+        } else {
+            return Err(DdsError::TopicError(format!(
+                "unexpected character '{c}' in filter expression"
+            )));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive-descent parser over [`tokenize_filter_expr`]'s output,
+/// producing the same `(expression, params)` shape [`Filter::compile`]
+/// does, so both paths feed `create_datareader_cdr_with_filter` identically
+struct FilterExprParser {
+    tokens: Vec<FilterExprToken>,
+    pos: usize,
+}
+
+impl FilterExprParser {
+    fn peek(&self) -> Option<&FilterExprToken> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<FilterExprToken> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &FilterExprToken) -> Result<(), DdsError> {
+        match self.advance() {
+            Some(ref token) if token == expected => Ok(()),
+            other => Err(DdsError::TopicError(format!(
+                "expected {expected:?}, found {other:?}"
+            ))),
+        }
+    }
+
+    /// `or_expr := and_expr (OR and_expr)*`
+    fn parse_or(&mut self, params: &mut Vec<String>) -> Result<String, DdsError> {
+        let mut clause = self.parse_and(params)?;
+        while matches!(self.peek(), Some(FilterExprToken::Or)) {
+            self.advance();
+            let rhs = self.parse_and(params)?;
+            clause = format!("({clause} OR {rhs})");
+        }
+        Ok(clause)
+    }
+
+    /// `and_expr := comparison (AND comparison)*`
+    fn parse_and(&mut self, params: &mut Vec<String>) -> Result<String, DdsError> {
+        let mut clause = self.parse_comparison(params)?;
+        while matches!(self.peek(), Some(FilterExprToken::And)) {
+            self.advance();
+            let rhs = self.parse_comparison(params)?;
+            clause = format!("({clause} AND {rhs})");
+        }
+        Ok(clause)
+    }
+
+    /// `comparison := IDENT ( '=' NUMBER | IN '(' NUMBER (',' NUMBER)* ')' )`
+    fn parse_comparison(&mut self, params: &mut Vec<String>) -> Result<String, DdsError> {
+        let field = match self.advance() {
+            Some(FilterExprToken::Ident(name)) => name,
+            other => {
+                return Err(DdsError::TopicError(format!(
+                    "expected a field name, found {other:?}"
+                )))
+            }
+        };
+        if !FILTER_EXPR_FIELDS.contains(&field.as_str()) {
+            return Err(DdsError::TopicError(format!(
+                "unknown field '{field}' (expected one of {FILTER_EXPR_FIELDS:?})"
+            )));
+        }
+
+        match self.advance() {
+            Some(FilterExprToken::Eq) => match self.advance() {
+                Some(FilterExprToken::Number(value)) => {
+                    let clause = format!("{field} = %{}", params.len());
+                    params.push(value.to_string());
+                    Ok(clause)
+                }
+                other => Err(DdsError::TopicError(format!(
+                    "expected a numeric value after '=', found {other:?}"
+                ))),
+            },
+            Some(FilterExprToken::In) => {
+                self.expect(&FilterExprToken::LParen)?;
+                let start = params.len();
+                let mut count = 0usize;
+                loop {
+                    match self.advance() {
+                        Some(FilterExprToken::Number(value)) => {
+                            params.push(value.to_string());
+                            count += 1;
+                        }
+                        other => {
+                            return Err(DdsError::TopicError(format!(
+                                "expected a numeric value in IN-list, found {other:?}"
+                            )))
+                        }
+                    }
+                    match self.peek() {
+                        Some(FilterExprToken::Comma) => {
+                            self.advance();
+                        }
+                        _ => break,
+                    }
+                }
+                self.expect(&FilterExprToken::RParen)?;
+                if count == 0 {
+                    return Err(DdsError::TopicError(
+                        "IN-list must not be empty".to_string(),
+                    ));
+                }
+                let placeholders: Vec<String> =
+                    (0..count).map(|i| format!("%{}", start + i)).collect();
+                Ok(format!("{field} IN ({})", placeholders.join(", ")))
+            }
+            other => Err(DdsError::TopicError(format!(
+                "expected '=' or 'IN' after field '{field}', found {other:?}"
+            ))),
         }
     }
+}
 
-    /// Receive the next sample with timeout
-    pub fn recv_timeout(&mut self, timeout: Duration) -> Option<AsterixSample> {
-        let start = std::time::Instant::now();
-        while start.elapsed() < timeout {
-            if let Some(sample) = self.try_recv() {
-                return Some(sample);
-            }
-            std::thread::sleep(Duration::from_millis(1));
-        }
-        None
+/// Parse a small, safe boolean filter expression into a DDS SQL-like
+/// content-filter expression and its bound parameters
+///
+/// Supports field comparisons (`field = value`, `field IN (v1, v2, ...)`)
+/// combined with `AND`/`OR`, where `field` is one of
+/// [`FILTER_EXPR_FIELDS`] (`category`, `sac`, `sic`) — the routing fields
+/// [`DdsPublisher::publish_raw_with_routing`] writes. Anything outside that
+/// grammar (an unknown field, a non-numeric value, unbalanced parentheses,
+/// a stray token) is rejected with [`DdsError::TopicError`] rather than
+/// silently passed through to the DDS middleware.
+///
+/// Used by [`DdsSubscriber::with_filter_expr`]; see its docs for an example
+/// expression.
+fn parse_filter_expr(expr: &str) -> Result<(String, Vec<String>), DdsError> {
+    let tokens = tokenize_filter_expr(expr)?;
+    if tokens.is_empty() {
+        return Err(DdsError::TopicError(
+            "filter expression must not be empty".to_string(),
+        ));
+    }
+
+    let mut parser = FilterExprParser { tokens, pos: 0 };
+    let mut params = Vec::new();
+    let expression = parser.parse_or(&mut params)?;
+
+    if parser.pos != parser.tokens.len() {
+        return Err(DdsError::TopicError(format!(
+            "unexpected trailing tokens starting at {:?}",
+            parser.tokens[parser.pos]
+        )));
     }
+
+    Ok((expression, params))
 }
 
 /// Parse a topic name to extract category, SAC, and SIC
@@ -705,6 +2301,8 @@ mod tests {
             durability: Durability::TransientLocal,
             history: History::KeepAll,
             deadline_ms: 100,
+            liveliness: Liveliness::default(),
+            resource_limits: ResourceLimits::default(),
         };
         let cloned = config.clone();
         assert_eq!(cloned.domain_id, 5);
@@ -722,6 +2320,12 @@ mod tests {
         assert_eq!(Durability::default(), Durability::Volatile);
     }
 
+    #[test]
+    fn test_durability_persistent_is_distinct_from_transient_local() {
+        assert_ne!(Durability::Persistent, Durability::TransientLocal);
+        assert_ne!(Durability::Persistent, Durability::Volatile);
+    }
+
     #[test]
     fn test_history_default() {
         match History::default() {
@@ -815,33 +2419,194 @@ mod tests {
         assert!(debug.contains("Some(1)"));
     }
 
+    #[test]
+    fn test_pub_ack_equality_and_debug() {
+        let ack = PubAck {
+            sequence_number: 7,
+            acking_readers: 2,
+        };
+        assert_eq!(ack, ack);
+        assert_ne!(
+            ack,
+            PubAck {
+                sequence_number: 8,
+                acking_readers: 2,
+            }
+        );
+        let debug = format!("{ack:?}");
+        assert!(debug.contains('7'));
+        assert!(debug.contains('2'));
+    }
+
+    #[test]
+    fn test_read_condition_index_round_trips() {
+        let condition = ReadCondition(3);
+        assert_eq!(condition.index(), 3);
+    }
+
+    #[test]
+    fn test_read_condition_equality() {
+        assert_eq!(ReadCondition(1), ReadCondition(1));
+        assert_ne!(ReadCondition(1), ReadCondition(2));
+    }
+
+    #[test]
+    fn test_filter_compiles_single_eq_predicate() {
+        let filter = Filter {
+            category: Some(FieldFilter::Eq(48)),
+            ..Filter::default()
+        };
+        let (expression, params) = filter.compile();
+        assert_eq!(expression, "category = %0");
+        assert_eq!(params, vec!["48".to_string()]);
+    }
+
+    #[test]
+    fn test_filter_compiles_eq_and_range_with_params_in_field_order() {
+        let filter = Filter {
+            category: Some(FieldFilter::Eq(48)),
+            sac: Some(FieldFilter::Range(1, 5)),
+            sic: None,
+        };
+        let (expression, params) = filter.compile();
+        assert_eq!(expression, "category = %0 AND (sac >= %1 AND sac <= %2)");
+        assert_eq!(
+            params,
+            vec!["48".to_string(), "1".to_string(), "5".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_filter_default_compiles_to_empty_expression() {
+        let (expression, params) = Filter::default().compile();
+        assert_eq!(expression, "");
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn test_filter_compiles_in_list() {
+        let filter = Filter {
+            sic: Some(FieldFilter::In(vec![20, 21])),
+            ..Filter::default()
+        };
+        let (expression, params) = filter.compile();
+        assert_eq!(expression, "sic IN (%0, %1)");
+        assert_eq!(params, vec!["20".to_string(), "21".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_filter_expr_single_eq() {
+        let (expression, params) = parse_filter_expr("category = 62").unwrap();
+        assert_eq!(expression, "category = %0");
+        assert_eq!(params, vec!["62".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_filter_expr_and_with_in_list() {
+        let (expression, params) =
+            parse_filter_expr("category = 62 AND sac = 10 AND sic IN (20, 21)").unwrap();
+        assert_eq!(
+            expression,
+            "((category = %0 AND sac = %1) AND sic IN (%2, %3))"
+        );
+        assert_eq!(
+            params,
+            vec![
+                "62".to_string(),
+                "10".to_string(),
+                "20".to_string(),
+                "21".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_filter_expr_or() {
+        let (expression, params) = parse_filter_expr("category = 48 OR category = 62").unwrap();
+        assert_eq!(expression, "(category = %0 OR category = %1)");
+        assert_eq!(params, vec!["48".to_string(), "62".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_filter_expr_rejects_unknown_field() {
+        assert!(matches!(
+            parse_filter_expr("bogus = 1"),
+            Err(DdsError::TopicError(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_filter_expr_rejects_empty_in_list() {
+        assert!(matches!(
+            parse_filter_expr("sac IN ()"),
+            Err(DdsError::TopicError(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_filter_expr_rejects_malformed_expression() {
+        assert!(matches!(
+            parse_filter_expr("category ="),
+            Err(DdsError::TopicError(_))
+        ));
+        assert!(matches!(
+            parse_filter_expr("category = 62 AND"),
+            Err(DdsError::TopicError(_))
+        ));
+        assert!(matches!(
+            parse_filter_expr(""),
+            Err(DdsError::TopicError(_))
+        ));
+    }
+
+    #[test]
+    fn test_asterix_sample_hexdump() {
+        let sample = AsterixSample {
+            category: 48,
+            sac: Some(1),
+            sic: Some(2),
+            data: vec![0x30, 0x00, 0x1E],
+            timestamp: 100,
+            topic_name: "test".to_string(),
+        };
+        let dump = sample.hexdump();
+        assert!(dump.contains("30 00 1e"));
+    }
+
+    #[test]
+    fn test_asterix_message_hexdump_with_config() {
+        let msg = AsterixMessage {
+            key: "test".to_string(),
+            category: 62,
+            sac: None,
+            sic: None,
+            data: vec![0xDE, 0xAD, 0xBE, 0xEF],
+            timestamp: 0,
+        };
+        let dump = msg.hexdump_with_config(HexDumpConfig {
+            show_offset: false,
+            show_ascii: false,
+            ..Default::default()
+        });
+        assert!(dump.contains("de ad be ef"));
+        assert!(!dump.contains('|'));
+    }
+
     // ============================================================================
     // Hex Conversion Tests
     // ============================================================================
 
     #[test]
     fn test_hex_to_bytes_valid() {
-        let hex = "30001E3048110601060160";
-        let hex_clean: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
-        let result: Result<Vec<u8>, _> = (0..hex_clean.len())
-            .step_by(2)
-            .map(|i| u8::from_str_radix(&hex_clean[i..i + 2], 16))
-            .collect();
-        assert!(result.is_ok());
-        let bytes = result.unwrap();
+        let bytes = from_hex("30001E3048110601060160").unwrap();
         assert_eq!(bytes[0], 0x30);
         assert_eq!(bytes[1], 0x00);
     }
 
     #[test]
     fn test_hex_to_bytes_with_whitespace() {
-        let hex = "30 00 1E 30 48";
-        let hex_clean: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
-        let result: Result<Vec<u8>, _> = (0..hex_clean.len())
-            .step_by(2)
-            .map(|i| u8::from_str_radix(&hex_clean[i..i + 2], 16))
-            .collect();
-        assert!(result.is_ok());
+        let bytes = from_hex("30 00 1E 30 48").unwrap();
+        assert_eq!(bytes, vec![0x30, 0x00, 0x1E, 0x30, 0x48]);
     }
 
     // ============================================================================
@@ -878,4 +2643,370 @@ mod tests {
         };
         let _qos = config.build_qos();
     }
+
+    #[test]
+    fn test_config_builds_qos_persistent_durability() {
+        let config = DdsConfig {
+            durability: Durability::Persistent,
+            ..Default::default()
+        };
+        let _qos = config.build_qos();
+    }
+
+    #[test]
+    fn test_config_builds_qos_manual_by_topic_liveliness() {
+        let config = DdsConfig {
+            liveliness: Liveliness::ManualByTopic {
+                lease_duration_ms: 5_000,
+            },
+            ..Default::default()
+        };
+        let _qos = config.build_qos();
+    }
+
+    #[test]
+    fn test_liveliness_default() {
+        match Liveliness::default() {
+            Liveliness::Automatic { lease_duration_ms } => assert_eq!(lease_duration_ms, 10_000),
+            _ => panic!("Expected Automatic"),
+        }
+    }
+
+    #[test]
+    fn test_resource_limits_default_is_unlimited() {
+        let limits = ResourceLimits::default();
+        assert_eq!(limits.max_samples, -1);
+        assert_eq!(limits.max_instances, -1);
+        assert_eq!(limits.max_samples_per_instance, -1);
+    }
+
+    #[test]
+    fn test_config_builds_qos_with_resource_limits() {
+        let config = DdsConfig {
+            resource_limits: ResourceLimits {
+                max_samples: 1000,
+                max_instances: 100,
+                max_samples_per_instance: 10,
+            },
+            ..Default::default()
+        };
+        let _qos = config.build_qos();
+    }
+
+    #[test]
+    fn test_validate_rejects_max_samples_per_instance_below_keep_last_depth() {
+        let config = DdsConfig {
+            history: History::KeepLast(10),
+            resource_limits: ResourceLimits {
+                max_samples_per_instance: 5,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_max_samples_per_instance_covering_keep_last_depth() {
+        let config = DdsConfig {
+            history: History::KeepLast(10),
+            resource_limits: ResourceLimits {
+                max_samples_per_instance: 10,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_ignores_unlimited_max_samples_per_instance() {
+        let config = DdsConfig {
+            history: History::KeepLast(10),
+            resource_limits: ResourceLimits::default(),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_partition_entry() {
+        let config = DdsConfig {
+            partition: vec!["radar1".to_string(), String::new()],
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_accepts_nonempty_partitions() {
+        let config = DdsConfig {
+            partition: vec!["radar1".to_string(), "radar2".to_string()],
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_config_builds_qos_with_partition_lifespan_latency_budget() {
+        let config = DdsConfig {
+            partition: vec!["radar1".to_string()],
+            lifespan_ms: 5_000,
+            latency_budget_ms: 100,
+            ..Default::default()
+        };
+        let _qos = config.build_qos();
+    }
+
+    #[test]
+    fn test_liveliness_manual_by_participant_builds_qos() {
+        let config = DdsConfig {
+            liveliness: Liveliness::ManualByParticipant {
+                lease_duration_ms: 2_000,
+            },
+            ..Default::default()
+        };
+        let _qos = config.build_qos();
+    }
+
+    #[test]
+    fn test_validate_compatible_accepts_matching_reliability_and_durability() {
+        let reader = DdsConfig {
+            reliability: Reliability::Reliable,
+            durability: Durability::TransientLocal,
+            ..Default::default()
+        };
+        let writer = DdsConfig {
+            reliability: Reliability::Reliable,
+            durability: Durability::Persistent,
+            ..Default::default()
+        };
+        assert!(DdsConfig::validate_compatible(&reader, &writer).is_ok());
+    }
+
+    #[test]
+    fn test_validate_compatible_rejects_reliable_reader_with_best_effort_writer() {
+        let reader = DdsConfig {
+            reliability: Reliability::Reliable,
+            ..Default::default()
+        };
+        let writer = DdsConfig {
+            reliability: Reliability::BestEffort,
+            ..Default::default()
+        };
+        assert!(matches!(
+            DdsConfig::validate_compatible(&reader, &writer),
+            Err(DdsError::QosError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_compatible_rejects_transient_local_reader_with_volatile_writer() {
+        let reader = DdsConfig {
+            durability: Durability::TransientLocal,
+            ..Default::default()
+        };
+        let writer = DdsConfig {
+            durability: Durability::Volatile,
+            ..Default::default()
+        };
+        assert!(matches!(
+            DdsConfig::validate_compatible(&reader, &writer),
+            Err(DdsError::QosError(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_compatible_accepts_best_effort_reader_with_any_writer() {
+        let reader = DdsConfig {
+            reliability: Reliability::BestEffort,
+            ..Default::default()
+        };
+        let writer = DdsConfig {
+            reliability: Reliability::Reliable,
+            ..Default::default()
+        };
+        assert!(DdsConfig::validate_compatible(&reader, &writer).is_ok());
+    }
+
+    // ============================================================================
+    // Config Serialization / Profile Loading Tests
+    // ============================================================================
+
+    #[test]
+    fn test_config_round_trips_through_toml() {
+        let config = DdsConfig {
+            domain_id: 7,
+            topic_prefix: "radar".to_string(),
+            reliability: Reliability::BestEffort,
+            durability: Durability::TransientLocal,
+            history: History::KeepLast(5),
+            deadline_ms: 250,
+            liveliness: Liveliness::ManualByTopic {
+                lease_duration_ms: 2_000,
+            },
+            resource_limits: ResourceLimits {
+                max_samples: 500,
+                max_instances: 50,
+                max_samples_per_instance: 10,
+            },
+        };
+
+        let toml = toml::to_string(&config).unwrap();
+        let parsed = DdsConfig::from_toml_str(&toml).unwrap();
+        assert_eq!(parsed.domain_id, 7);
+        assert_eq!(parsed.topic_prefix, "radar");
+        assert_eq!(parsed.reliability, Reliability::BestEffort);
+        assert_eq!(parsed.durability, Durability::TransientLocal);
+        assert_eq!(parsed.history, History::KeepLast(5));
+        assert_eq!(parsed.deadline_ms, 250);
+        assert_eq!(
+            parsed.liveliness,
+            Liveliness::ManualByTopic {
+                lease_duration_ms: 2_000
+            }
+        );
+        assert_eq!(
+            parsed.resource_limits,
+            ResourceLimits {
+                max_samples: 500,
+                max_instances: 50,
+                max_samples_per_instance: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_config_round_trips_through_json() {
+        let config = DdsConfig::reliable();
+        let json = serde_json::to_string(&config).unwrap();
+        let parsed = DdsConfig::from_json_str(&json).unwrap();
+        assert_eq!(parsed.reliability, Reliability::Reliable);
+        assert_eq!(parsed.durability, Durability::TransientLocal);
+    }
+
+    #[test]
+    fn test_asterix_sample_serde_json_roundtrip() {
+        let original = AsterixSample {
+            category: 48,
+            sac: Some(1),
+            sic: Some(2),
+            data: vec![0xAA, 0xBB, 0xCC],
+            timestamp: 1_000_000,
+            topic_name: "asterix_cat048".to_string(),
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        let back: AsterixSample = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.category, original.category);
+        assert_eq!(back.sac, original.sac);
+        assert_eq!(back.sic, original.sic);
+        assert_eq!(back.data, original.data);
+        assert_eq!(back.timestamp, original.timestamp);
+        assert_eq!(back.topic_name, original.topic_name);
+    }
+
+    #[test]
+    fn test_config_validate_rejects_negative_keep_last() {
+        let config = DdsConfig {
+            history: History::KeepLast(-1),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_config_validate_accepts_non_negative_keep_last() {
+        let config = DdsConfig {
+            history: History::KeepLast(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_negative_keep_last() {
+        let toml = r#"
+            domain_id = 0
+            topic_prefix = "asterix"
+            reliability = "Reliable"
+            durability = "Volatile"
+            deadline_ms = 0
+            liveliness = { Automatic = { lease_duration_ms = 10000 } }
+
+            [resource_limits]
+            max_samples = -1
+            max_instances = -1
+            max_samples_per_instance = -1
+
+            [history]
+            KeepLast = -5
+        "#;
+        assert!(DdsConfig::from_toml_str(toml).is_err());
+    }
+
+    #[test]
+    fn test_profiles_selects_named_profile() {
+        let toml = r#"
+            [surveillance_reliable]
+            domain_id = 0
+            topic_prefix = "asterix"
+            reliability = "Reliable"
+            durability = "TransientLocal"
+            deadline_ms = 0
+            history = "KeepAll"
+            liveliness = { Automatic = { lease_duration_ms = 10000 } }
+
+            [surveillance_reliable.resource_limits]
+            max_samples = -1
+            max_instances = -1
+            max_samples_per_instance = -1
+
+            [low_latency]
+            domain_id = 0
+            topic_prefix = "asterix"
+            reliability = "BestEffort"
+            durability = "Volatile"
+            deadline_ms = 0
+            history = "KeepAll"
+            liveliness = { ManualByTopic = { lease_duration_ms = 500 } }
+
+            [low_latency.resource_limits]
+            max_samples = -1
+            max_instances = -1
+            max_samples_per_instance = -1
+        "#;
+
+        let profiles = DdsProfiles::from_toml_str(toml).unwrap();
+        let reliable = profiles.profile("surveillance_reliable").unwrap();
+        assert_eq!(reliable.reliability, Reliability::Reliable);
+
+        let low_latency = profiles.profile("low_latency").unwrap();
+        assert_eq!(low_latency.reliability, Reliability::BestEffort);
+
+        assert!(profiles.get("does_not_exist").is_none());
+        assert!(profiles.profile("does_not_exist").is_err());
+    }
+
+    #[test]
+    fn test_profiles_rejects_invalid_profile() {
+        let toml = r#"
+            [bad_profile]
+            domain_id = 0
+            topic_prefix = "asterix"
+            reliability = "Reliable"
+            durability = "Volatile"
+            deadline_ms = 0
+            liveliness = { Automatic = { lease_duration_ms = 10000 } }
+
+            [bad_profile.resource_limits]
+            max_samples = -1
+            max_instances = -1
+            max_samples_per_instance = -1
+
+            [bad_profile.history]
+            KeepLast = -1
+        "#;
+
+        assert!(DdsProfiles::from_toml_str(toml).is_err());
+    }
 }
@@ -0,0 +1,328 @@
+//! HTTP/JSON-RPC 2.0 transport for ASTERIX parser as a network service
+//!
+//! This module exposes the ASTERIX parser over plain HTTP so that
+//! non-Linux and browser-adjacent consumers (anything that can issue a POST
+//! request) can reach it without a D-Bus session bus. It speaks the same
+//! JSON-RPC 2.0 surface as [`crate::transport::ws`] — only the framing
+//! differs — built on [`crate::transport::rpc`]'s shared dispatch logic.
+//!
+//! # Wire Protocol
+//!
+//! `POST /` with a JSON-RPC 2.0 request body:
+//!
+//! ```json
+//! {"method": "parseHex", "params": {"hexData": "300006..."}, "id": 1}
+//! ```
+//!
+//! returns an HTTP 200 response whose body is the JSON-RPC 2.0 response,
+//! with `result` an array of [`crate::transport::rpc::ParsedRecord`]
+//! objects for `parse`/`parseHex`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use asterix::transport::http::{HttpServer, HttpConfig};
+//! use asterix::init_default;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     init_default()?;
+//!
+//!     let server = HttpServer::bind(HttpConfig::default())?;
+//!     server.run()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fmt;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+use crate::error::AsterixError;
+use crate::transport::rpc::{handle_request, CoreParser};
+
+/// Error type for HTTP transport operations
+#[derive(Debug)]
+pub enum HttpError {
+    /// Failed to bind the listening socket
+    BindError(String),
+    /// Failed to read or write on a connection
+    IoError(String),
+    /// The request could not be parsed as HTTP/1.1
+    MalformedRequest(String),
+    /// The request's `Content-Length` exceeds [`HttpConfig::max_body_bytes`]
+    BodyTooLarge { declared: usize, limit: usize },
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HttpError::BindError(msg) => write!(f, "HTTP bind error: {msg}"),
+            HttpError::IoError(msg) => write!(f, "HTTP I/O error: {msg}"),
+            HttpError::MalformedRequest(msg) => write!(f, "Malformed HTTP request: {msg}"),
+            HttpError::BodyTooLarge { declared, limit } => write!(
+                f,
+                "request body of {declared} bytes exceeds the {limit}-byte limit"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl From<HttpError> for AsterixError {
+    fn from(err: HttpError) -> Self {
+        AsterixError::IOError(err.to_string())
+    }
+}
+
+impl From<std::io::Error> for HttpError {
+    fn from(err: std::io::Error) -> Self {
+        HttpError::IoError(err.to_string())
+    }
+}
+
+/// Configuration for the HTTP/JSON-RPC service
+#[derive(Debug, Clone)]
+pub struct HttpConfig {
+    /// Address to bind the listening socket to
+    /// Default: "127.0.0.1"
+    pub bind_addr: String,
+
+    /// Port to listen on. Use `0` to let the OS assign an ephemeral port
+    /// (see [`HttpServer::local_addr`]).
+    /// Default: 8080
+    pub port: u16,
+
+    /// Upper bound on a request's `Content-Length`, checked before the body
+    /// buffer is allocated. A request declaring more than this is rejected
+    /// with [`HttpError::BodyTooLarge`] without ever allocating or reading
+    /// its body.
+    /// Default: 64 MiB
+    pub max_body_bytes: usize,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: "127.0.0.1".to_string(),
+            port: 8080,
+            max_body_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+/// HTTP server exposing the ASTERIX parser as a JSON-RPC 2.0 endpoint
+///
+/// Serves every request with a fresh [`CoreParser`] call; there is no
+/// per-connection state to manage, unlike [`crate::transport::ws::WsServer`]
+/// which keeps a connection open for streaming.
+pub struct HttpServer {
+    listener: TcpListener,
+    parser: CoreParser,
+    max_body_bytes: usize,
+}
+
+impl HttpServer {
+    /// Bind the listening socket
+    pub fn bind(config: HttpConfig) -> Result<Self, HttpError> {
+        let listener = TcpListener::bind((config.bind_addr.as_str(), config.port))
+            .map_err(|e| HttpError::BindError(e.to_string()))?;
+        Ok(Self {
+            listener,
+            parser: CoreParser,
+            max_body_bytes: config.max_body_bytes,
+        })
+    }
+
+    /// Address the listener actually bound to (useful when `port` was `0`)
+    pub fn local_addr(&self) -> Result<SocketAddr, HttpError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accept and serve connections forever
+    pub fn run(&self) -> Result<(), HttpError> {
+        loop {
+            self.serve_one()?;
+        }
+    }
+
+    /// Accept and serve a limited number of connections (for testing)
+    pub fn run_for(&self, connections: usize) -> Result<(), HttpError> {
+        for _ in 0..connections {
+            self.serve_one()?;
+        }
+        Ok(())
+    }
+
+    /// Accept a single connection, handle its request, and return
+    pub fn serve_one(&self) -> Result<(), HttpError> {
+        let (stream, _) = self.listener.accept()?;
+        self.handle_connection(stream)
+    }
+
+    fn handle_connection(&self, mut stream: TcpStream) -> Result<(), HttpError> {
+        let body = read_request_body(&stream, self.max_body_bytes)?;
+        let response_body = handle_request(&self.parser, &body);
+        let response_json = serde_json::to_string(&response_body)
+            .map_err(|e| HttpError::IoError(e.to_string()))?;
+
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response_json.len(),
+            response_json
+        )?;
+        stream.flush()?;
+        Ok(())
+    }
+}
+
+/// Read an HTTP/1.1 request off `stream` and return its body
+///
+/// Only the pieces this transport needs are parsed: the request line and
+/// headers are read and discarded except for `Content-Length`, which bounds
+/// how much of the body to read.
+///
+/// # Errors
+///
+/// Returns [`HttpError::BodyTooLarge`] if the declared `Content-Length`
+/// exceeds `max_body_bytes` -- checked before any body buffer is allocated,
+/// so a request can't force an oversized allocation just by lying about its
+/// length in the header.
+fn read_request_body(stream: &TcpStream, max_body_bytes: usize) -> Result<String, HttpError> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    if request_line.is_empty() {
+        return Err(HttpError::MalformedRequest(
+            "connection closed before request line".to_string(),
+        ));
+    }
+
+    let mut content_length: usize = 0;
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(value) = line.strip_prefix("Content-Length:").or_else(|| line.strip_prefix("content-length:")) {
+            content_length = value.trim().parse().unwrap_or(0);
+        }
+    }
+
+    if content_length > max_body_bytes {
+        return Err(HttpError::BodyTooLarge {
+            declared: content_length,
+            limit: max_body_bytes,
+        });
+    }
+
+    // Read incrementally rather than `vec![0u8; content_length]` up front:
+    // even bounded by `max_body_bytes` above, that bound can be configured
+    // generously, so growing the buffer as bytes actually arrive keeps a
+    // slow/partial sender from holding a full-size allocation before any
+    // data has shown up.
+    let mut body = Vec::new();
+    let mut chunk = [0u8; 8192];
+    while body.len() < content_length {
+        let want = (content_length - body.len()).min(chunk.len());
+        reader.read_exact(&mut chunk[..want])?;
+        body.extend_from_slice(&chunk[..want]);
+    }
+    String::from_utf8(body).map_err(|e| HttpError::MalformedRequest(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_config_default() {
+        let config = HttpConfig::default();
+        assert_eq!(config.bind_addr, "127.0.0.1");
+        assert_eq!(config.port, 8080);
+        assert_eq!(config.max_body_bytes, 64 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_read_request_body_rejects_oversized_content_length_before_allocating() {
+        use std::io::Read as _;
+        use std::thread;
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).expect("bind local listener");
+        let addr = listener.local_addr().unwrap();
+
+        let server_thread = thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("accept connection");
+            read_request_body(&stream, 1024)
+        });
+
+        let mut client = TcpStream::connect(addr).expect("connect to listener");
+        // Declares far more than the 1024-byte limit and far more than
+        // usize could ever actually hold in this request; must be rejected
+        // by the Content-Length check, not by attempting to allocate or
+        // read that many bytes.
+        let request = "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: 18446744073709551615\r\n\r\n";
+        client.write_all(request.as_bytes()).expect("write request");
+
+        let result = server_thread.join().expect("server thread panicked");
+        assert!(matches!(
+            result,
+            Err(HttpError::BodyTooLarge { limit: 1024, .. })
+        ));
+    }
+
+    #[test]
+    fn test_http_server_bind_ephemeral_port() {
+        let config = HttpConfig {
+            port: 0,
+            ..HttpConfig::default()
+        };
+        let Ok(server) = HttpServer::bind(config) else {
+            println!("Skipping test: could not bind a local TCP socket");
+            return;
+        };
+        let addr = server.local_addr().expect("bound socket must have a local addr");
+        assert_ne!(addr.port(), 0);
+    }
+
+    #[test]
+    fn test_http_server_serves_get_version() {
+        use std::thread;
+
+        let config = HttpConfig {
+            port: 0,
+            ..HttpConfig::default()
+        };
+        let Ok(server) = HttpServer::bind(config) else {
+            println!("Skipping test: could not bind a local TCP socket");
+            return;
+        };
+        let addr = server.local_addr().expect("bound socket must have a local addr");
+
+        let handle = thread::spawn(move || server.run_for(1));
+        let Ok(mut stream) = TcpStream::connect(addr) else {
+            println!("Skipping test: could not connect to local server");
+            return;
+        };
+
+        let body = r#"{"method":"getVersion","params":{},"id":1}"#;
+        let request = format!(
+            "POST / HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(request.as_bytes()).expect("write request");
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).expect("read response");
+        handle.join().expect("server thread panicked").expect("server errored");
+
+        assert!(response.contains("200 OK"));
+        assert!(response.contains(env!("CARGO_PKG_VERSION")));
+    }
+}
@@ -0,0 +1,333 @@
+//! InfluxDB line-protocol telemetry exporter for transport metrics
+//!
+//! [`MetricsRecorder`] records per-message throughput, publish latency,
+//! payload sizes, and per-category counts from a transport's hot path, and
+//! streams them to InfluxDB so an operator can watch a live ASTERIX feed in
+//! Grafana. Recording never blocks the hot path: each point is formatted as
+//! an InfluxDB line-protocol string and pushed onto a bounded, shared
+//! buffer; once the buffer is full, the oldest line is dropped to make room
+//! and a counter tracks how many points were lost. A background thread
+//! drains the buffer on a flush interval (or sooner, once it fills up) and
+//! POSTs the accumulated lines as a single batch to `/write?db=<name>`.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use asterix::transport::metrics::{MetricsConfig, MetricsRecorder};
+//! use std::time::Duration;
+//!
+//! let recorder = MetricsRecorder::spawn(MetricsConfig::default());
+//! recorder.record_publish(48, Some(1), Some(2), 128, Duration::from_micros(340));
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::error::AsterixError;
+
+/// Error type for the InfluxDB metrics exporter
+#[derive(Debug)]
+pub enum MetricsError {
+    /// Failed to connect to, write to, or read from the InfluxDB endpoint
+    IoError(String),
+    /// InfluxDB responded with a non-2xx HTTP status
+    WriteRejected(String),
+}
+
+impl fmt::Display for MetricsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricsError::IoError(msg) => write!(f, "metrics exporter I/O error: {msg}"),
+            MetricsError::WriteRejected(msg) => write!(f, "InfluxDB rejected write: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for MetricsError {}
+
+impl From<MetricsError> for AsterixError {
+    fn from(err: MetricsError) -> Self {
+        AsterixError::IOError(err.to_string())
+    }
+}
+
+/// Configuration for the InfluxDB line-protocol exporter
+#[derive(Debug, Clone)]
+pub struct MetricsConfig {
+    /// InfluxDB HTTP API host, without scheme or path (e.g. `"127.0.0.1:8086"`)
+    /// Default: "127.0.0.1:8086"
+    pub influx_addr: String,
+
+    /// Target database name, sent as the `db` query parameter on `/write`
+    /// Default: "asterix"
+    pub database: String,
+
+    /// Maximum number of buffered line-protocol points before the oldest is
+    /// dropped to make room for a new one
+    /// Default: 4096
+    pub max_buffer: usize,
+
+    /// How often the background worker flushes the buffer, even if it
+    /// hasn't filled up
+    /// Default: 1000ms
+    pub flush_interval_ms: u64,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            influx_addr: "127.0.0.1:8086".to_string(),
+            database: "asterix".to_string(),
+            max_buffer: 4096,
+            flush_interval_ms: 1000,
+        }
+    }
+}
+
+/// Shared state between the recording hot path and the background flusher
+struct Shared {
+    buffer: Mutex<VecDeque<String>>,
+    not_empty: Condvar,
+    dropped: AtomicU64,
+    shutdown: AtomicBool,
+    max_buffer: usize,
+}
+
+/// Records transport telemetry and streams it to InfluxDB in the background
+///
+/// Cloning is cheap (an `Arc` to the shared buffer and counters), so the
+/// same recorder can be held by a [`crate::transport::dds::DdsPublisher`]
+/// and a [`crate::transport::dds::DdsSubscriber`] at once. Dropping the last
+/// clone signals the background worker to flush whatever remains and exit.
+#[derive(Clone)]
+pub struct MetricsRecorder {
+    shared: Arc<Shared>,
+    worker: Arc<Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl MetricsRecorder {
+    /// Spawn the background flusher and return a recorder pointing at it
+    pub fn spawn(config: MetricsConfig) -> Self {
+        let shared = Arc::new(Shared {
+            buffer: Mutex::new(VecDeque::new()),
+            not_empty: Condvar::new(),
+            dropped: AtomicU64::new(0),
+            shutdown: AtomicBool::new(false),
+            max_buffer: config.max_buffer,
+        });
+
+        let worker_shared = Arc::clone(&shared);
+        let worker = thread::spawn(move || run_flush_loop(worker_shared, config));
+
+        Self {
+            shared,
+            worker: Arc::new(Mutex::new(Some(worker))),
+        }
+    }
+
+    /// Record one published message: its category, optional SAC/SIC
+    /// routing, payload size, and publish latency
+    ///
+    /// Never blocks: if the buffer is already at `max_buffer`, the oldest
+    /// point is dropped and [`MetricsRecorder::dropped_count`] increments.
+    pub fn record_publish(
+        &self,
+        category: u8,
+        sac: Option<u8>,
+        sic: Option<u8>,
+        payload_bytes: usize,
+        latency: Duration,
+    ) {
+        let latency_us = latency.as_secs_f64() * 1_000_000.0;
+        if !latency_us.is_finite() {
+            return;
+        }
+
+        let line = format!(
+            "asterix_publish,category={category},sac={},sic={} bytes={payload_bytes}i,latency_us={latency_us}",
+            sac.map(|v| v.to_string()).unwrap_or_else(|| "none".to_string()),
+            sic.map(|v| v.to_string()).unwrap_or_else(|| "none".to_string()),
+        );
+        self.push_line(line);
+    }
+
+    /// Record one received message: its category, optional SAC/SIC
+    /// routing, and payload size
+    pub fn record_receive(&self, category: u8, sac: Option<u8>, sic: Option<u8>, payload_bytes: usize) {
+        let line = format!(
+            "asterix_receive,category={category},sac={},sic={} bytes={payload_bytes}i",
+            sac.map(|v| v.to_string()).unwrap_or_else(|| "none".to_string()),
+            sic.map(|v| v.to_string()).unwrap_or_else(|| "none".to_string()),
+        );
+        self.push_line(line);
+    }
+
+    fn push_line(&self, line: String) {
+        let mut buffer = self.shared.buffer.lock().unwrap();
+        if buffer.len() >= self.shared.max_buffer {
+            buffer.pop_front();
+            self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        buffer.push_back(line);
+        self.shared.not_empty.notify_one();
+    }
+
+    /// Number of points dropped so far because the buffer was full
+    pub fn dropped_count(&self) -> u64 {
+        self.shared.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Number of points currently buffered, awaiting the next flush
+    pub fn buffered_count(&self) -> usize {
+        self.shared.buffer.lock().unwrap().len()
+    }
+
+    /// Signal the background worker to flush and exit, then wait for it
+    pub fn shutdown(&self) {
+        self.shared.shutdown.store(true, Ordering::Relaxed);
+        self.shared.not_empty.notify_one();
+        if let Some(handle) = self.worker.lock().unwrap().take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run_flush_loop(shared: Arc<Shared>, config: MetricsConfig) {
+    let flush_interval = Duration::from_millis(config.flush_interval_ms);
+
+    loop {
+        let mut batch = {
+            let buffer = shared.buffer.lock().unwrap();
+            let (mut buffer, timeout_result) = shared
+                .not_empty
+                .wait_timeout_while(buffer, flush_interval, |buffer| {
+                    buffer.len() < config.max_buffer && !shared.shutdown.load(Ordering::Relaxed)
+                })
+                .unwrap();
+            let _ = timeout_result;
+            buffer.drain(..).collect::<Vec<_>>()
+        };
+
+        if !batch.is_empty() {
+            batch.truncate(config.max_buffer);
+            if let Err(e) = write_batch(&config, &batch) {
+                log::warn!("InfluxDB metrics flush failed: {e}");
+            }
+        }
+
+        if shared.shutdown.load(Ordering::Relaxed) && shared.buffer.lock().unwrap().is_empty() {
+            break;
+        }
+    }
+}
+
+/// POST a batch of already-formatted line-protocol lines to `/write?db=<name>`
+fn write_batch(config: &MetricsConfig, lines: &[String]) -> Result<(), MetricsError> {
+    let body = lines.join("\n");
+    let path = format!("/write?db={}", config.database);
+
+    let mut stream = TcpStream::connect(&config.influx_addr)
+        .map_err(|e| MetricsError::IoError(e.to_string()))?;
+
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        config.influx_addr,
+        body.len(),
+    )
+    .map_err(|e| MetricsError::IoError(e.to_string()))?;
+    stream
+        .flush()
+        .map_err(|e| MetricsError::IoError(e.to_string()))?;
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .map_err(|e| MetricsError::IoError(e.to_string()))?;
+
+    let status_line = response.lines().next().unwrap_or_default();
+    let status_ok = status_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse::<u16>().ok())
+        .is_some_and(|code| (200..300).contains(&code));
+
+    if !status_ok {
+        return Err(MetricsError::WriteRejected(status_line.to_string()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_config_default() {
+        let config = MetricsConfig::default();
+        assert_eq!(config.influx_addr, "127.0.0.1:8086");
+        assert_eq!(config.database, "asterix");
+        assert_eq!(config.max_buffer, 4096);
+        assert_eq!(config.flush_interval_ms, 1000);
+    }
+
+    #[test]
+    fn test_record_publish_skips_non_finite_latency() {
+        let recorder = MetricsRecorder::spawn(MetricsConfig {
+            influx_addr: "127.0.0.1:1".to_string(),
+            flush_interval_ms: 60_000,
+            ..MetricsConfig::default()
+        });
+        // A latency of 0 produces a finite value; NaN/infinite durations
+        // cannot be constructed via `Duration`, so this test only verifies
+        // normal recording actually buffers a point.
+        recorder.record_publish(48, Some(1), Some(2), 100, Duration::from_micros(50));
+        assert_eq!(recorder.buffered_count(), 1);
+        recorder.shutdown();
+    }
+
+    #[test]
+    fn test_record_receive_buffers_point() {
+        let recorder = MetricsRecorder::spawn(MetricsConfig {
+            influx_addr: "127.0.0.1:1".to_string(),
+            flush_interval_ms: 60_000,
+            ..MetricsConfig::default()
+        });
+        recorder.record_receive(48, None, None, 64);
+        assert_eq!(recorder.buffered_count(), 1);
+        recorder.shutdown();
+    }
+
+    #[test]
+    fn test_drop_oldest_on_full_buffer_increments_counter() {
+        let recorder = MetricsRecorder::spawn(MetricsConfig {
+            influx_addr: "127.0.0.1:1".to_string(),
+            max_buffer: 2,
+            flush_interval_ms: 60_000,
+            ..MetricsConfig::default()
+        });
+        for _ in 0..5 {
+            recorder.record_receive(48, None, None, 1);
+        }
+        assert!(recorder.dropped_count() > 0);
+        recorder.shutdown();
+    }
+
+    #[test]
+    fn test_dropped_count_starts_at_zero() {
+        let recorder = MetricsRecorder::spawn(MetricsConfig {
+            influx_addr: "127.0.0.1:1".to_string(),
+            flush_interval_ms: 60_000,
+            ..MetricsConfig::default()
+        });
+        assert_eq!(recorder.dropped_count(), 0);
+        recorder.shutdown();
+    }
+}
@@ -10,6 +10,17 @@
 //! - `dbus` - Linux D-Bus IPC for system services (requires `dbus` feature)
 //! - `can` - CAN Bus via SocketCAN for automotive/embedded (requires `can` feature)
 //! - `ccsds` - CCSDS Space Packet Protocol for space mission data (requires `ccsds` feature)
+//! - `cfdp` - CFDP file delivery over CCSDS packets, for bulk ASTERIX recordings (requires `ccsds` feature)
+//! - `ws` - WebSocket gateway speaking JSON-RPC 2.0, for non-Linux/browser consumers (requires `ws` feature)
+//! - `http` - HTTP endpoint speaking JSON-RPC 2.0 (requires `http` feature)
+//! - `bridge` - Forwards ASTERIX samples between `dds` and `zenoh` (requires both features)
+//! - `metrics` - InfluxDB line-protocol telemetry exporter (requires `metrics` feature)
+//! - `ratelimit` - Token-bucket governed replay publishing (requires `dds` feature)
+//!
+//! `dbus`, `ws`, and `http` all implement the shared [`rpc::ParserTransport`]
+//! trait, so the same `parse`/`parseHex`/`getVersion`/`healthCheck` surface is
+//! reachable over D-Bus, a WebSocket, or plain HTTP depending on which
+//! backend a deployment can reach.
 //!
 //! # Feature Flags
 //!
@@ -26,19 +37,55 @@
 //! asterix = { version = "0.1", features = ["can"] }
 //! # or
 //! asterix = { version = "0.1", features = ["ccsds"] }
+//! # or
+//! asterix = { version = "0.1", features = ["ws"] }
+//! # or
+//! asterix = { version = "0.1", features = ["http"] }
 //! ```
 
+pub mod rpc;
+pub use self::rpc::{CoreParser, ParsedRecord, ParserTransport};
+
 #[cfg(feature = "zenoh")]
 pub mod zenoh;
 
 #[cfg(feature = "zenoh")]
-pub use self::zenoh::{ZenohConfig, ZenohError, ZenohPublisher, ZenohSubscriber};
+pub use self::zenoh::{
+    query_history, BatchingPublisher, DecodingSubscriber, KeyExprTemplate, OverflowPolicy,
+    QosProfile, QueryTarget, SourceEvent, SourceInfo, SubscriptionFilter, TransportMetrics,
+    ZenohConfig, ZenohError, ZenohPublisher, ZenohQueryClient, ZenohQueryable,
+    ZenohSourceDiscovery, ZenohSubscriber, ZenohTrackStore,
+};
+
+#[cfg(all(feature = "zenoh", feature = "serde"))]
+pub use self::zenoh::{DecodeStrictness, ZenohDecodeClient, ZenohDecodeService};
 
 #[cfg(feature = "dds")]
 pub mod dds;
 
 #[cfg(feature = "dds")]
-pub use self::dds::{DdsConfig, DdsError, DdsPublisher, DdsSubscriber};
+pub use self::dds::{
+    DdsConfig, DdsError, DdsNode, DdsProfiles, DdsPublisher, DdsSubscriber, DdsSubscriberGroup,
+    DdsSubscription, FieldFilter, Filter, Liveliness, ReadCondition, SourceHealthEvent, WaitSet,
+};
+
+#[cfg(all(feature = "dds", feature = "zenoh"))]
+pub mod bridge;
+
+#[cfg(all(feature = "dds", feature = "zenoh"))]
+pub use self::bridge::DdsZenohBridge;
+
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
+#[cfg(feature = "metrics")]
+pub use self::metrics::{MetricsConfig, MetricsError, MetricsRecorder};
+
+#[cfg(feature = "dds")]
+pub mod ratelimit;
+
+#[cfg(feature = "dds")]
+pub use self::ratelimit::{InMemoryBucket, RateLimitConfig, RateLimitedPublisher, TokenBucketStore};
 
 #[cfg(feature = "dbus")]
 pub mod dbus;
@@ -50,13 +97,58 @@ pub use self::dbus::{BusType, DbusClient, DbusConfig, DbusError, DbusService};
 pub mod can;
 
 #[cfg(all(feature = "can", target_os = "linux"))]
-pub use self::can::{CanConfig, CanError, CanFrameType, CanPublisher, CanSample, CanSubscriber};
+pub use self::can::{
+    spawn_config_watcher_system, CanBus, CanBusRx, CanConfig, CanError, CanFrameType, CanPublisher,
+    CanRecords, CanSample, CanSubscriber, ConfigWatcher, ConfigWatcherHandle,
+};
 
 #[cfg(feature = "ccsds")]
 pub mod ccsds;
 
 #[cfg(feature = "ccsds")]
 pub use self::ccsds::{
-    category_from_apid, parse_ccsds_header, CcsdsConfig, CcsdsError, CcsdsMode, CcsdsPublisher,
-    CcsdsSample, CcsdsSubscriber,
+    category_from_apid, parse_ccsds_header, parse_ccsds_packet, ApidStats, CcsdsConfig,
+    CcsdsDecoder, CcsdsError, CcsdsEvent, CcsdsMode, CcsdsPublisher, CcsdsSample, CcsdsSubscriber,
+    CcsdsTransport, CucEpoch, CucTime, PusConfig, PusSecondaryHeader, SubscriberStats,
 };
+
+#[cfg(all(feature = "ccsds", feature = "serde"))]
+pub use self::ccsds::FramedCcsdsReader;
+
+#[cfg(feature = "ccsds")]
+pub mod cfdp;
+
+#[cfg(feature = "ccsds")]
+pub use self::cfdp::{
+    decode_pdu_header, encode_pdu, CfdpIdWidth, CfdpReceiver, CfdpSender, CfdpTlv, CommonPduConfig,
+    EofPdu, FileDataPdu, FinishedPdu, MetadataPdu, WritablePdu,
+};
+
+#[cfg(all(feature = "ws", feature = "serde"))]
+pub mod ws;
+
+#[cfg(all(feature = "ws", feature = "serde"))]
+pub use self::ws::{WsConfig, WsError, WsServer};
+
+#[cfg(all(feature = "http", feature = "serde"))]
+pub mod http;
+
+#[cfg(all(feature = "http", feature = "serde"))]
+pub use self::http::{HttpConfig, HttpError, HttpServer};
+
+/// Selects which [`ParserTransport`] backend to run
+///
+/// Exists so applications can pick a backend at runtime (e.g. from a config
+/// file or CLI flag) instead of hard-coding one transport's types.
+#[derive(Debug, Clone)]
+pub enum TransportConfig {
+    /// Run the D-Bus service (Linux only; see [`dbus`])
+    #[cfg(feature = "dbus")]
+    Dbus(DbusConfig),
+    /// Run the WebSocket gateway (see [`ws`])
+    #[cfg(all(feature = "ws", feature = "serde"))]
+    Ws(WsConfig),
+    /// Run the HTTP/JSON-RPC endpoint (see [`http`])
+    #[cfg(all(feature = "http", feature = "serde"))]
+    Http(HttpConfig),
+}
@@ -0,0 +1,321 @@
+//! Transport-agnostic parser service surface shared by the RPC-style backends
+//!
+//! [`crate::transport::dbus`], [`crate::transport::ws`], and
+//! [`crate::transport::http`] each expose the same four operations
+//! (`parse`, `parseHex`, `getVersion`, `healthCheck`) over a different wire
+//! protocol. This module extracts that surface as the [`ParserTransport`]
+//! trait plus [`CoreParser`], the one implementation of the actual decode
+//! logic every backend delegates to, so adding a new backend means writing a
+//! wire adapter, not another copy of the parsing code.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AsterixError;
+use crate::hex::from_hex;
+use crate::types::AsterixRecord;
+use crate::ParseOptions;
+
+/// Compact, wire-friendly view of a decoded ASTERIX record
+///
+/// This is the shape the JSON-RPC backends ([`crate::transport::ws`],
+/// [`crate::transport::http`]) serialize for `parse`/`parseHex` results.
+/// Callers parsing in-process get the richer [`AsterixRecord`] (with its
+/// full `items` map) directly from [`ParserTransport::parse`] instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParsedRecord {
+    /// ASTERIX category number (e.g., 48, 62, 65)
+    pub category: u8,
+    /// Total length of the data block in bytes
+    pub length: u32,
+    /// Timestamp in milliseconds since Unix epoch
+    pub timestamp_ms: u64,
+    /// CRC32 checksum of the data block
+    pub crc: u32,
+    /// Number of data items in the record
+    pub item_count: u32,
+    /// Hexadecimal representation of raw data
+    pub hex_data: String,
+}
+
+impl From<&AsterixRecord> for ParsedRecord {
+    fn from(record: &AsterixRecord) -> Self {
+        Self {
+            category: record.category,
+            length: record.length,
+            timestamp_ms: record.timestamp_ms,
+            crc: record.crc,
+            item_count: record.item_count() as u32,
+            hex_data: record.hex_data.clone(),
+        }
+    }
+}
+
+/// Transport-agnostic surface every ASTERIX parser backend exposes
+///
+/// [`crate::transport::dbus::DbusService`] implements this over D-Bus method
+/// calls; [`crate::transport::ws`] and [`crate::transport::http`] implement
+/// it over WebSocket pushes and JSON-RPC 2.0/HTTP respectively. All three
+/// share one notion of what "the parser service" can do, so fixing or
+/// extending behavior here (e.g. what counts as healthy) changes every
+/// backend at once.
+pub trait ParserTransport {
+    /// Parse raw ASTERIX bytes
+    fn parse(&self, data: &[u8]) -> Result<Vec<AsterixRecord>, AsterixError>;
+
+    /// Parse hex-encoded ASTERIX data
+    fn parse_hex(&self, hex_data: &str) -> Result<Vec<AsterixRecord>, AsterixError> {
+        let bytes = from_hex(hex_data)?;
+        self.parse(&bytes)
+    }
+
+    /// ASTERIX library version
+    fn get_version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    /// Whether the backend is ready to serve requests
+    fn health_check(&self) -> bool {
+        true
+    }
+
+    /// Called once per successfully decoded record
+    ///
+    /// The default is a no-op; backends that stream (D-Bus signals,
+    /// WebSocket pushes) override this to broadcast each record instead of
+    /// only returning the batch from `parse`.
+    fn on_record_parsed(&self, _record: &AsterixRecord) {}
+}
+
+/// JSON-RPC 2.0 request envelope
+///
+/// Shared by [`crate::transport::ws`] and [`crate::transport::http`] so both
+/// backends parse/dispatch requests identically; only the framing (WebSocket
+/// message vs. HTTP body) differs between them.
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Deserialize)]
+pub struct RpcRequest {
+    /// Method name: `parse`, `parseHex`, `getVersion`, or `healthCheck`
+    pub method: String,
+    /// Method parameters, as a JSON object
+    #[serde(default)]
+    pub params: serde_json::Value,
+    /// Request id, echoed back unchanged in the response
+    #[serde(default)]
+    pub id: serde_json::Value,
+}
+
+/// JSON-RPC 2.0 response envelope
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcResponse {
+    pub jsonrpc: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcErrorObject>,
+    pub id: serde_json::Value,
+}
+
+/// JSON-RPC 2.0 error object
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Serialize)]
+pub struct RpcErrorObject {
+    pub code: i32,
+    pub message: String,
+}
+
+#[cfg(feature = "serde")]
+const RPC_PARSE_ERROR: i32 = -32700;
+#[cfg(feature = "serde")]
+const RPC_METHOD_NOT_FOUND: i32 = -32601;
+#[cfg(feature = "serde")]
+const RPC_INVALID_PARAMS: i32 = -32602;
+#[cfg(feature = "serde")]
+const RPC_INTERNAL_ERROR: i32 = -32603;
+
+/// Parse a raw JSON-RPC request body and dispatch it against `transport`
+///
+/// Returns the `RpcResponse` to serialize back to the caller; malformed
+/// request bodies produce a JSON-RPC parse-error response rather than an
+/// `Err`, since the caller still needs a well-formed reply to send back.
+#[cfg(feature = "serde")]
+pub fn handle_request(transport: &impl ParserTransport, body: &str) -> RpcResponse {
+    match serde_json::from_str::<RpcRequest>(body) {
+        Ok(request) => dispatch(transport, &request),
+        Err(e) => RpcResponse {
+            jsonrpc: "2.0",
+            result: None,
+            error: Some(RpcErrorObject {
+                code: RPC_PARSE_ERROR,
+                message: format!("invalid JSON-RPC request: {e}"),
+            }),
+            id: serde_json::Value::Null,
+        },
+    }
+}
+
+/// Dispatch an already-parsed JSON-RPC request against `transport`
+#[cfg(feature = "serde")]
+pub fn dispatch(transport: &impl ParserTransport, request: &RpcRequest) -> RpcResponse {
+    let id = request.id.clone();
+    let outcome = match request.method.as_str() {
+        "parse" => rpc_param_bytes(&request.params, "data")
+            .and_then(|data| records_to_value(transport.parse(&data))),
+        "parseHex" => rpc_param_str(&request.params, "hexData")
+            .and_then(|hex_data| records_to_value(transport.parse_hex(&hex_data))),
+        "getVersion" => Ok(serde_json::json!({ "version": transport.get_version() })),
+        "healthCheck" => Ok(serde_json::json!({ "healthy": transport.health_check() })),
+        other => Err(RpcErrorObject {
+            code: RPC_METHOD_NOT_FOUND,
+            message: format!("method not found: {other}"),
+        }),
+    };
+
+    match outcome {
+        Ok(result) => RpcResponse {
+            jsonrpc: "2.0",
+            result: Some(result),
+            error: None,
+            id,
+        },
+        Err(error) => RpcResponse {
+            jsonrpc: "2.0",
+            result: None,
+            error: Some(error),
+            id,
+        },
+    }
+}
+
+#[cfg(feature = "serde")]
+fn rpc_param_bytes(params: &serde_json::Value, key: &str) -> Result<Vec<u8>, RpcErrorObject> {
+    params
+        .get(key)
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|b| b.as_u64()).map(|b| b as u8).collect())
+        .ok_or_else(|| RpcErrorObject {
+            code: RPC_INVALID_PARAMS,
+            message: format!("params.{key} must be an array of bytes"),
+        })
+}
+
+#[cfg(feature = "serde")]
+fn rpc_param_str(params: &serde_json::Value, key: &str) -> Result<String, RpcErrorObject> {
+    params
+        .get(key)
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| RpcErrorObject {
+            code: RPC_INVALID_PARAMS,
+            message: format!("params.{key} must be a string"),
+        })
+}
+
+#[cfg(feature = "serde")]
+fn records_to_value(
+    records: Result<Vec<AsterixRecord>, AsterixError>,
+) -> Result<serde_json::Value, RpcErrorObject> {
+    let records = records.map_err(|e| RpcErrorObject {
+        code: RPC_INTERNAL_ERROR,
+        message: e.to_string(),
+    })?;
+    let parsed: Vec<ParsedRecord> = records.iter().map(ParsedRecord::from).collect();
+    serde_json::to_value(&parsed).map_err(|e| RpcErrorObject {
+        code: RPC_INTERNAL_ERROR,
+        message: e.to_string(),
+    })
+}
+
+/// The one real [`ParserTransport`]: runs the core parser, no streaming
+///
+/// Every RPC backend either uses this directly or wraps it to add streaming
+/// via [`ParserTransport::on_record_parsed`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CoreParser;
+
+impl ParserTransport for CoreParser {
+    fn parse(&self, data: &[u8]) -> Result<Vec<AsterixRecord>, AsterixError> {
+        let records = crate::parse(data, ParseOptions::default())?;
+        for record in &records {
+            self.on_record_parsed(record);
+        }
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsed_record_from_asterix_record() {
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 123,
+            crc: 0xABCD,
+            hex_data: "30000A".to_string(),
+            items: Default::default(),
+        };
+
+        let parsed = ParsedRecord::from(&record);
+        assert_eq!(parsed.category, 48);
+        assert_eq!(parsed.length, 10);
+        assert_eq!(parsed.timestamp_ms, 123);
+        assert_eq!(parsed.crc, 0xABCD);
+        assert_eq!(parsed.item_count, 0);
+        assert_eq!(parsed.hex_data, "30000A");
+    }
+
+    #[test]
+    fn test_core_parser_health_and_version() {
+        let parser = CoreParser;
+        assert!(parser.health_check());
+        assert!(!parser.get_version().is_empty());
+    }
+
+    #[test]
+    fn test_core_parser_parse_hex_invalid_input() {
+        let parser = CoreParser;
+        let result = parser.parse_hex("zz");
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_handle_request_get_version() {
+        let body = r#"{"method":"getVersion","params":{},"id":1}"#;
+        let response = handle_request(&CoreParser, body);
+        assert!(response.error.is_none());
+        let result = response.result.expect("getVersion should return a result");
+        assert_eq!(result["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(response.id, serde_json::json!(1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_handle_request_unknown_method() {
+        let body = r#"{"method":"doesNotExist","params":{},"id":2}"#;
+        let response = handle_request(&CoreParser, body);
+        assert!(response.result.is_none());
+        let error = response.error.expect("unknown method should error");
+        assert_eq!(error.code, RPC_METHOD_NOT_FOUND);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_handle_request_malformed_json() {
+        let response = handle_request(&CoreParser, "not json");
+        let error = response.error.expect("malformed body should error");
+        assert_eq!(error.code, RPC_PARSE_ERROR);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_handle_request_parse_hex_missing_param() {
+        let body = r#"{"method":"parseHex","params":{},"id":3}"#;
+        let response = handle_request(&CoreParser, body);
+        let error = response.error.expect("missing hexData should error");
+        assert_eq!(error.code, RPC_INVALID_PARAMS);
+    }
+}
@@ -0,0 +1,238 @@
+//! WebSocket transport for ASTERIX parser as a network service
+//!
+//! This module exposes the ASTERIX parser over a WebSocket so that
+//! non-Linux and browser consumers — anything with a WebSocket client —
+//! can reach it without a D-Bus session bus. It speaks the same JSON-RPC
+//! 2.0 surface as [`crate::transport::http`] — only the framing differs —
+//! built on [`crate::transport::rpc`]'s shared dispatch logic.
+//!
+//! # Wire Protocol
+//!
+//! Each text message sent over the socket is a JSON-RPC 2.0 request:
+//!
+//! ```json
+//! {"method": "parseHex", "params": {"hexData": "300006..."}, "id": 1}
+//! ```
+//!
+//! and the server replies with one text message per request, carrying the
+//! JSON-RPC 2.0 response, with `result` an array of
+//! [`crate::transport::rpc::ParsedRecord`] objects for `parse`/`parseHex`.
+//! The connection stays open across requests, unlike
+//! [`crate::transport::http::HttpServer`] which handles one request per
+//! connection.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use asterix::transport::ws::{WsServer, WsConfig};
+//! use asterix::init_default;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     init_default()?;
+//!
+//!     let server = WsServer::bind(WsConfig::default())?;
+//!     server.run()?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fmt;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+
+use tungstenite::Message;
+
+use crate::error::AsterixError;
+use crate::transport::rpc::{handle_request, CoreParser};
+
+/// Error type for WebSocket transport operations
+#[derive(Debug)]
+pub enum WsError {
+    /// Failed to bind the listening socket
+    BindError(String),
+    /// Failed to complete the WebSocket opening handshake
+    HandshakeError(String),
+    /// Failed to read or write a WebSocket message
+    IoError(String),
+}
+
+impl fmt::Display for WsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WsError::BindError(msg) => write!(f, "WebSocket bind error: {msg}"),
+            WsError::HandshakeError(msg) => write!(f, "WebSocket handshake error: {msg}"),
+            WsError::IoError(msg) => write!(f, "WebSocket I/O error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for WsError {}
+
+impl From<WsError> for AsterixError {
+    fn from(err: WsError) -> Self {
+        AsterixError::IOError(err.to_string())
+    }
+}
+
+/// Configuration for the WebSocket service
+#[derive(Debug, Clone)]
+pub struct WsConfig {
+    /// Address to bind the listening socket to
+    /// Default: "127.0.0.1"
+    pub bind_addr: String,
+
+    /// Port to listen on. Use `0` to let the OS assign an ephemeral port
+    /// (see [`WsServer::local_addr`]).
+    /// Default: 8765
+    pub port: u16,
+}
+
+impl Default for WsConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: "127.0.0.1".to_string(),
+            port: 8765,
+        }
+    }
+}
+
+/// WebSocket server exposing the ASTERIX parser as a JSON-RPC 2.0 endpoint
+///
+/// Unlike [`crate::transport::http::HttpServer`], a connection here stays
+/// open across many requests, making this the natural backend for clients
+/// that want to keep polling the parser without reconnecting each time.
+pub struct WsServer {
+    listener: TcpListener,
+    parser: CoreParser,
+}
+
+impl WsServer {
+    /// Bind the listening socket
+    pub fn bind(config: WsConfig) -> Result<Self, WsError> {
+        let listener = TcpListener::bind((config.bind_addr.as_str(), config.port))
+            .map_err(|e| WsError::BindError(e.to_string()))?;
+        Ok(Self {
+            listener,
+            parser: CoreParser,
+        })
+    }
+
+    /// Address the listener actually bound to (useful when `port` was `0`)
+    pub fn local_addr(&self) -> Result<SocketAddr, WsError> {
+        self.listener
+            .local_addr()
+            .map_err(|e| WsError::IoError(e.to_string()))
+    }
+
+    /// Accept and serve connections forever
+    pub fn run(&self) -> Result<(), WsError> {
+        loop {
+            self.serve_one()?;
+        }
+    }
+
+    /// Accept and serve a limited number of connections (for testing)
+    pub fn run_for(&self, connections: usize) -> Result<(), WsError> {
+        for _ in 0..connections {
+            self.serve_one()?;
+        }
+        Ok(())
+    }
+
+    /// Accept a single connection and serve requests on it until it closes
+    pub fn serve_one(&self) -> Result<(), WsError> {
+        let (stream, _) = self
+            .listener
+            .accept()
+            .map_err(|e| WsError::IoError(e.to_string()))?;
+        self.handle_connection(stream)
+    }
+
+    fn handle_connection(&self, stream: TcpStream) -> Result<(), WsError> {
+        let mut socket =
+            tungstenite::accept(stream).map_err(|e| WsError::HandshakeError(e.to_string()))?;
+
+        loop {
+            let message = match socket.read() {
+                Ok(message) => message,
+                Err(tungstenite::Error::ConnectionClosed)
+                | Err(tungstenite::Error::AlreadyClosed) => break,
+                Err(e) => return Err(WsError::IoError(e.to_string())),
+            };
+
+            match message {
+                Message::Text(body) => {
+                    let response = handle_request(&self.parser, body.as_str());
+                    let response_json = serde_json::to_string(&response)
+                        .map_err(|e| WsError::IoError(e.to_string()))?;
+                    socket
+                        .send(Message::Text(response_json.into()))
+                        .map_err(|e| WsError::IoError(e.to_string()))?;
+                }
+                Message::Close(_) => break,
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ws_config_default() {
+        let config = WsConfig::default();
+        assert_eq!(config.bind_addr, "127.0.0.1");
+        assert_eq!(config.port, 8765);
+    }
+
+    #[test]
+    fn test_ws_server_bind_ephemeral_port() {
+        let config = WsConfig {
+            port: 0,
+            ..WsConfig::default()
+        };
+        let Ok(server) = WsServer::bind(config) else {
+            println!("Skipping test: could not bind a local TCP socket");
+            return;
+        };
+        let addr = server.local_addr().expect("bound socket must have a local addr");
+        assert_ne!(addr.port(), 0);
+    }
+
+    #[test]
+    fn test_ws_server_serves_get_version() {
+        use std::thread;
+
+        let config = WsConfig {
+            port: 0,
+            ..WsConfig::default()
+        };
+        let Ok(server) = WsServer::bind(config) else {
+            println!("Skipping test: could not bind a local TCP socket");
+            return;
+        };
+        let addr = server.local_addr().expect("bound socket must have a local addr");
+
+        let handle = thread::spawn(move || server.run_for(1));
+
+        let Ok((mut client, _)) = tungstenite::connect(format!("ws://{addr}")) else {
+            println!("Skipping test: could not connect to local WebSocket server");
+            return;
+        };
+
+        client
+            .send(Message::Text(
+                r#"{"method":"getVersion","params":{},"id":1}"#.into(),
+            ))
+            .expect("send request");
+        let reply = client.read().expect("read response");
+        handle.join().expect("server thread panicked").expect("server errored");
+
+        let text = reply.to_text().expect("response must be text");
+        assert!(text.contains(env!("CARGO_PKG_VERSION")));
+    }
+}
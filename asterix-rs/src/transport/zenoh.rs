@@ -70,16 +70,57 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! ## Pulling Recent History
+//!
+//! A live [`ZenohSubscriber`] only sees samples published after it connects.
+//! [`ZenohQueryable`] fills that gap: it keeps a bounded per-key history of
+//! recent samples and answers Zenoh get-queries for them, so a late-joining
+//! client can catch up before switching to a live subscription.
+//!
+//! ```no_run
+//! use asterix::transport::zenoh::{ZenohQueryable, ZenohConfig, query_history};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let config = ZenohConfig {
+//!         history_depth: 100,
+//!         ..ZenohConfig::default()
+//!     };
+//!
+//!     // Runs alongside a ZenohPublisher, answering queries from its history
+//!     let _queryable = ZenohQueryable::new(config.clone(), "asterix/**").await?;
+//!
+//!     // Elsewhere: a late-joining client pulls what it missed
+//!     let history = query_history(&config, "asterix/48/**").await?;
+//!     println!("caught up on {} samples", history.len());
+//!
+//!     Ok(())
+//! }
+//! ```
 
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fmt;
-use std::sync::Arc;
-
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use futures_core::Stream;
+use hkdf::Hkdf;
+use sha2::Sha256;
 use tokio::sync::mpsc;
 use zenoh::Config;
 use zenoh::Session;
 
+use crate::cbor;
 use crate::error::AsterixError;
-use crate::types::AsterixRecord;
+use crate::framing::frame_blocks;
+use crate::hex::from_hex;
+use crate::serialized_decoder::SerializedDecoder;
+use crate::types::{AsterixRecord, ParseOptions};
 
 /// Error type for Zenoh transport operations
 #[derive(Debug)]
@@ -98,6 +139,16 @@ pub enum ZenohError {
     SerializationError(String),
     /// Channel closed
     ChannelClosed,
+    /// Invalid configuration
+    ConfigError(String),
+    /// Failed to encrypt a payload for publishing (see [`EncryptionConfig`])
+    EncryptionError(String),
+    /// Failed to decrypt/authenticate a received payload (see [`EncryptionConfig`])
+    DecryptionError(String),
+    /// A fragmented publish could not be reassembled — today this means its
+    /// remaining fragments never arrived before the reassembly timeout
+    /// elapsed
+    ReassemblyError(String),
 }
 
 impl fmt::Display for ZenohError {
@@ -110,6 +161,10 @@ impl fmt::Display for ZenohError {
             ZenohError::ReceiveError(msg) => write!(f, "Zenoh receive error: {msg}"),
             ZenohError::SerializationError(msg) => write!(f, "Serialization error: {msg}"),
             ZenohError::ChannelClosed => write!(f, "Channel closed"),
+            ZenohError::ConfigError(msg) => write!(f, "Invalid Zenoh configuration: {msg}"),
+            ZenohError::EncryptionError(msg) => write!(f, "Zenoh payload encryption error: {msg}"),
+            ZenohError::DecryptionError(msg) => write!(f, "Zenoh payload decryption error: {msg}"),
+            ZenohError::ReassemblyError(msg) => write!(f, "Zenoh fragment reassembly error: {msg}"),
         }
     }
 }
@@ -129,6 +184,10 @@ pub struct ZenohConfig {
     /// If empty, uses default multicast discovery
     pub endpoints: Vec<String>,
 
+    /// Zenoh session mode (see [`ZenohMode`])
+    /// Default: [`ZenohMode::Peer`]
+    pub mode: ZenohMode,
+
     /// Key expression prefix for ASTERIX data
     /// Default: "asterix"
     pub key_prefix: String,
@@ -142,17 +201,115 @@ pub struct ZenohConfig {
     #[cfg(feature = "serde")]
     pub include_parsed: bool,
 
+    /// Number of most recent samples [`ZenohQueryable`] retains per key to
+    /// answer get-queries with.
+    /// Default: 0 (history retention disabled)
+    pub history_depth: usize,
+
+    /// Maximum age, in seconds, a sample may reach before [`ZenohQueryable`]
+    /// drops it even if `history_depth` hasn't been reached
+    /// Default: 0 (no age limit; only `history_depth` bounds retention)
+    pub history_max_age_secs: u64,
+
     /// Congestion control mode
     /// Default: Block (wait for network)
     pub congestion_control: CongestionControl,
 
     /// Priority for published data
-    /// Default: Data
+    /// Default: RealTime
     pub priority: Priority,
+
+    /// Per-category QoS override ([`QosProfile`], bundling priority,
+    /// congestion control, and reliability together), taking precedence over
+    /// [`Self::priority`]/[`Self::congestion_control`]/[`Self::reliability`]
+    /// for any category present as a key
+    ///
+    /// Lets real-time categories (e.g. CAT021 ADS-B, CAT048 radar plots) ride
+    /// at [`Priority::RealTime`] with [`CongestionControl::Drop`]/
+    /// [`Reliability::BestEffort`] (a stale plot is worse than a dropped one)
+    /// while system-track/status categories (e.g. CAT062, CAT065) keep
+    /// [`CongestionControl::Block`]/[`Reliability::Reliable`], all on the
+    /// same publisher/session instead of one QoS setting for every category
+    /// it publishes. See [`Self::with_surveillance_qos`] for a pre-populated
+    /// default.
+    /// Default: empty (every category uses [`Self::priority`]/
+    /// [`Self::congestion_control`]/[`Self::reliability`])
+    pub qos_profiles: BTreeMap<u8, QosProfile>,
+
+    /// Delivery guarantee for published data
+    /// Default: Reliable
+    pub reliability: Reliability,
+
+    /// Enable Zenoh's low-latency transport mode.
+    ///
+    /// The low-latency path trades away message fragmentation for reduced
+    /// latency, so [`ZenohPublisher::publish_raw`] and
+    /// [`ZenohPublisher::publish_raw_with_routing`] fragment any payload
+    /// larger than [`Self::max_payload_size`] themselves instead of relying
+    /// on the transport. Mutually exclusive with QoS prioritization: enabling
+    /// this alongside a non-default [`Self::priority`] or
+    /// [`Self::congestion_control`] is rejected by [`ZenohPublisher::new`].
+    /// Default: false
+    pub low_latency: bool,
+
+    /// Maximum payload size (in bytes) published as a single Zenoh `put`
+    /// before [`ZenohPublisher::publish_raw`]/
+    /// [`ZenohPublisher::publish_raw_with_routing`] fragment it into
+    /// `asterix/.../frag/<seq>/<total>` sub-keys for
+    /// [`ZenohSubscriber::recv`] to reassemble.
+    /// Default: 65000 (a conservative default Zenoh TX batch size)
+    pub max_payload_size: usize,
+
+    /// Wire format [`ZenohPublisher::publish`] encodes each record as.
+    /// Default: [`PayloadFormat::Raw`]
+    pub payload_format: PayloadFormat,
+
+    /// Number of records [`BatchingPublisher`] buffers per key expression
+    /// before flushing them as one length-delimited batch payload.
+    /// Default: 1 (flush every record immediately, matching
+    /// [`ZenohPublisher`]'s unbatched behavior)
+    pub batch_size: usize,
+
+    /// How long [`BatchingPublisher`] lets a partial batch sit before
+    /// flushing it anyway, even if `batch_size` hasn't been reached.
+    /// Default: `Duration::ZERO` (disabled; a partial batch only flushes
+    /// once `batch_size` is reached)
+    pub linger: std::time::Duration,
+
+    /// AES-256-GCM payload encryption, keyed per-category off one master
+    /// key (see [`EncryptionConfig`]).
+    ///
+    /// When set, [`ZenohPublisher::publish`]/[`ZenohPublisher::publish_raw`]/
+    /// [`ZenohPublisher::publish_raw_with_routing`]/[`ZenohPublisher::publish_block`]
+    /// encrypt the serialized payload before it reaches the network, and
+    /// [`ZenohSubscriber`] transparently decrypts it back out — surveillance
+    /// feeds published over Zenoh often traverse shared transport, so this
+    /// lets a deployment keep payloads opaque to anything but holders of
+    /// `master_key`.
+    /// Default: `None` (payloads published in the clear)
+    pub encryption: Option<EncryptionConfig>,
+
+    /// Overrides the hardcoded `{key_prefix}/{category}/{sac}/{sic}` scheme
+    /// [`ZenohPublisher::publish`] falls back to, letting operators route
+    /// traffic by any field a record decodes to instead of just category/
+    /// SAC/SIC (see [`KeyExprTemplate`]).
+    /// Default: `None` (use the hardcoded category/SAC/SIC scheme)
+    pub key_expr_template: Option<KeyExprTemplate>,
+
+    /// Capacity of [`ZenohSubscriber`]'s internal receive channel, beyond
+    /// which [`Self::overflow_policy`] decides what happens to a sample that
+    /// can't fit.
+    /// Default: 1000
+    pub channel_capacity: usize,
+
+    /// What [`ZenohSubscriber`] does once [`Self::channel_capacity`] is
+    /// reached (see [`OverflowPolicy`])
+    /// Default: [`OverflowPolicy::Block`]
+    pub overflow_policy: OverflowPolicy,
 }
 
 /// Congestion control mode for Zenoh
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CongestionControl {
     /// Block until data can be sent
     #[default]
@@ -162,29 +319,571 @@ pub enum CongestionControl {
 }
 
 /// Priority level for Zenoh messages
-#[derive(Debug, Clone, Copy, Default)]
+///
+/// Defaults to [`Self::RealTime`]: ASTERIX carries live air-traffic
+/// surveillance tracks, so published samples are scheduled ahead of
+/// best-effort traffic on the same session unless a caller opts out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Priority {
     /// Real-time data (highest priority)
+    #[default]
     RealTime,
     /// Interactive data
     Interactive,
     /// Default data priority
-    #[default]
     Data,
     /// Background data (lowest priority)
     Background,
 }
 
+/// Zenoh session mode, controlling how a session joins the network.
+///
+/// Mirrors Zenoh's own `mode` config key. Not threaded into every
+/// session-opening constructor — like [`ZenohConfig::low_latency`], it's
+/// only applied by [`ZenohPublisher::new`]; subscribers/queryables/query
+/// clients opened against the same [`ZenohConfig`] use Zenoh's default
+/// session mode regardless of this field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ZenohMode {
+    /// Join the network as a peer, discovering other peers/routers directly.
+    /// Zenoh's own default when `mode` is left unset.
+    #[default]
+    Peer,
+    /// Connect to a router rather than peering directly.
+    Client,
+    /// Act as a router, relaying traffic between clients/peers.
+    Router,
+}
+
+/// Delivery guarantee for Zenoh messages
+///
+/// Defaults to [`Self::Reliable`], matching [`CongestionControl::Block`]'s
+/// default: surveillance track updates should not be silently dropped
+/// under load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reliability {
+    /// Deliver every sample, retransmitting as needed
+    #[default]
+    Reliable,
+    /// Deliver samples on a best-effort basis, dropping rather than retransmitting
+    BestEffort,
+}
+
+/// Bundled Zenoh QoS knobs for one category: priority, congestion control,
+/// and delivery reliability
+///
+/// Bundled together since [`ZenohPublisher::put_declared`]'s declared
+/// publisher sets all three at once when it's first created for a key — the
+/// same reason [`ZenohConfig::qos_profiles`] maps a category to one
+/// `QosProfile` rather than to three separate per-category maps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QosProfile {
+    /// Scheduling priority (see [`Priority`])
+    pub priority: Priority,
+    /// Congestion behavior (see [`CongestionControl`])
+    pub congestion_control: CongestionControl,
+    /// Delivery guarantee (see [`Reliability`])
+    pub reliability: Reliability,
+}
+
+impl Default for QosProfile {
+    fn default() -> Self {
+        Self {
+            priority: Priority::default(),
+            congestion_control: CongestionControl::default(),
+            reliability: Reliability::default(),
+        }
+    }
+}
+
+/// Conservative default Zenoh TX batch size, used as [`ZenohConfig::max_payload_size`]'s default.
+const DEFAULT_MAX_PAYLOAD_SIZE: usize = 65_000;
+
+/// Key expression segment under which [`ZenohPublisher::with_source_discovery`]
+/// declares its liveliness token and [`ZenohSourceDiscovery`] watches for them.
+const SOURCE_DISCOVERY_SEGMENT: &str = "@/sources";
+
+/// Wire format [`ZenohPublisher::publish`] encodes an [`AsterixRecord`] as.
+///
+/// Today a subscriber needs the same category XML definitions and FFI
+/// decoder as the publisher just to read a SAC/SIC or track number back out
+/// of a sample. [`Json`](Self::Json), [`Cbor`](Self::Cbor), and
+/// [`MessagePack`](Self::MessagePack) instead ship the already-decoded
+/// record as a self-describing structured value, so a subscriber can pull
+/// fields directly off it — [`AsterixSample::encoding`] tags every received
+/// sample with whichever of these the publisher used, and
+/// [`AsterixSample::decode_record`] dispatches on that tag to hand back an
+/// [`AsterixRecord`] without the caller needing to know in advance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PayloadFormat {
+    /// The original ASTERIX bytes (decoded from `hex_data`), or — if
+    /// `include_raw_bytes` is false or `hex_data` is empty — the same JSON
+    /// fallback as [`Self::Json`]. Today's default behavior.
+    #[default]
+    Raw,
+    /// The decoded record, serialized as JSON (requires the `serde` feature)
+    Json,
+    /// The decoded record, serialized as CBOR (RFC 8949) via
+    /// [`crate::cbor::encode_record`] — more compact than JSON and, unlike
+    /// [`Self::Raw`], doesn't require the `serde` feature
+    Cbor,
+    /// The decoded record, serialized as MessagePack (requires the `serde`
+    /// feature) — more compact than JSON like [`Self::Cbor`], for
+    /// deployments that already standardize on MessagePack elsewhere
+    MessagePack,
+}
+
+/// A user-defined Zenoh key-expression scheme, for operators who want to
+/// route traffic by something other than the hardcoded
+/// `{key_prefix}/{category}/{sac}/{sic}` scheme [`ZenohPublisher::publish`]
+/// falls back to when [`ZenohConfig::key_expr_template`] is unset.
+///
+/// The template body (everything after `key_prefix`) may reference:
+/// - `{category}` — the record's category
+/// - `{sac}` / `{sic}` — pulled from the record's `I<CAT>/010` item, the
+///   same item [`ZenohPublisher::build_key_expr`]'s hardcoded scheme reads
+/// - `{I<CAT>/<ITEM>.<FIELD>}` — any other decoded field, e.g.
+///   `{I048/010.SAC}` (equivalent to `{sac}` for CAT048) or
+///   `{I062/380.ADR}` for a system track's target address
+///
+/// e.g. `KeyExprTemplate::new("{category}/{sac}/{sic}")` reproduces the
+/// default scheme; `KeyExprTemplate::new("{I062/380.ADR}/{category}")`
+/// partitions CAT062 traffic by target address instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyExprTemplate(String);
+
+impl KeyExprTemplate {
+    /// Build a template from its placeholder-bearing body (not including
+    /// `key_prefix`, which [`Self::resolve`]/[`Self::subscribe_expr`]
+    /// prepend)
+    pub fn new(template: impl Into<String>) -> Self {
+        Self(template.into())
+    }
+
+    /// Resolve this template against `record` for [`ZenohPublisher::publish`]
+    /// to put it under. A placeholder whose item/field isn't present in
+    /// `record` resolves to `_` rather than failing the publish outright.
+    pub fn resolve(&self, key_prefix: &str, record: &AsterixRecord) -> String {
+        let body = Self::substitute(&self.0, |placeholder| {
+            Self::resolve_placeholder(placeholder, record).unwrap_or_else(|| "_".to_string())
+        });
+        format!("{key_prefix}/{body}")
+    }
+
+    /// Compile this template into a subscribe key expression: every
+    /// placeholder not given an explicit value in `pinned` (matched by the
+    /// same name used in the template, e.g. `"category"` or
+    /// `"I048/010.SAC"`) resolves to Zenoh's single-level wildcard `*`,
+    /// letting a subscriber narrow to one category/sensor/field while
+    /// leaving the rest of the key open.
+    pub fn subscribe_expr(&self, key_prefix: &str, pinned: &[(&str, &str)]) -> String {
+        let body = Self::substitute(&self.0, |placeholder| {
+            pinned
+                .iter()
+                .find(|(name, _)| *name == placeholder)
+                .map(|(_, value)| (*value).to_string())
+                .unwrap_or_else(|| "*".to_string())
+        });
+        format!("{key_prefix}/{body}")
+    }
+
+    fn substitute(template: &str, mut resolve: impl FnMut(&str) -> String) -> String {
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            out.push_str(&rest[..start]);
+            rest = &rest[start + 1..];
+            match rest.find('}') {
+                Some(end) => {
+                    out.push_str(&resolve(&rest[..end]));
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    // Unterminated `{`: no placeholder to resolve, keep it verbatim.
+                    out.push('{');
+                    break;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    fn resolve_placeholder(placeholder: &str, record: &AsterixRecord) -> Option<String> {
+        match placeholder {
+            "category" => Some(record.category.to_string()),
+            "sac" => Self::sac_sic_item(record)?
+                .fields
+                .get("SAC")
+                .and_then(|v| v.as_i64())
+                .map(|v| v.to_string()),
+            "sic" => Self::sac_sic_item(record)?
+                .fields
+                .get("SIC")
+                .and_then(|v| v.as_i64())
+                .map(|v| v.to_string()),
+            _ => {
+                let (item_id, field) = placeholder.split_once('.')?;
+                let value = record.get_item(item_id)?.fields.get(field)?;
+                Some(key_expr_value(value))
+            }
+        }
+    }
+
+    fn sac_sic_item(record: &AsterixRecord) -> Option<&crate::types::DataItem> {
+        record.get_item(&format!("I{:03}/010", record.category))
+    }
+}
+
+/// Render a decoded field value as a Zenoh key-expression segment, replacing
+/// any `/` (which would otherwise split into extra key levels) with `_`.
+fn key_expr_value(value: &crate::types::ParsedValue) -> String {
+    use crate::types::ParsedValue;
+
+    let rendered = match value {
+        ParsedValue::Integer(v) => v.to_string(),
+        ParsedValue::Unsigned(v) => v.to_string(),
+        ParsedValue::Float(v) => v.to_string(),
+        ParsedValue::String(v) => v.clone(),
+        ParsedValue::Boolean(v) => v.to_string(),
+        ParsedValue::Decimal { raw, scale, .. } => (*raw as f64 * scale).to_string(),
+        other => format!("{other:?}"),
+    };
+    rendered.replace('/', "_")
+}
+
+/// What [`ZenohSubscriber`] does when its receive channel is already at
+/// [`ZenohConfig::channel_capacity`] and another sample arrives before the
+/// consumer drains it. Every non-[`Block`](Self::Block) case increments
+/// [`TransportMetrics::dropped_samples`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Evict the oldest buffered sample to make room for the new one —
+    /// the consumer always sees the most recent data, at the cost of
+    /// losing history. Recommended for a high-rate feed (e.g. CAT048
+    /// plots) where a stale sample is worse than a missing one.
+    DropOldest,
+    /// Discard the new sample, keeping everything already buffered
+    DropNewest,
+    /// Apply backpressure to the task feeding this channel instead of
+    /// dropping anything — today's behavior, and still the right choice
+    /// when every sample must be delivered (e.g. CAT065 SDPS status).
+    #[default]
+    Block,
+}
+
+/// Upper bounds (in microseconds) of [`TransportMetrics`]'s publish-latency
+/// histogram buckets, loosely modeled on Prometheus' own defaults but
+/// narrowed to the microsecond range this transport actually operates in
+/// (see `benches/zenoh_benchmark.rs`).
+const LATENCY_BUCKET_BOUNDS_US: [u64; 9] =
+    [50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000, 50_000];
+
+#[derive(Default)]
+struct MetricsInner {
+    messages_published: AtomicU64,
+    bytes_published: AtomicU64,
+    messages_received: AtomicU64,
+    decode_failures: AtomicU64,
+    dropped_samples: AtomicU64,
+    latency_buckets: [AtomicU64; LATENCY_BUCKET_BOUNDS_US.len()],
+    latency_overflow: AtomicU64,
+    latency_sum_us: AtomicU64,
+    latency_count: AtomicU64,
+}
+
+/// Runtime counters for a [`ZenohPublisher`]/[`ZenohSubscriber`], reachable
+/// via [`ZenohPublisher::metrics`]/[`ZenohSubscriber::metrics`].
+///
+/// Cloning is cheap (an `Arc` to the shared counters), matching
+/// [`crate::transport::metrics::MetricsRecorder`]'s handle pattern — the
+/// difference is this one is read on demand (e.g. scraped as
+/// [`Self::render_prometheus`]) instead of pushed to InfluxDB on an
+/// interval.
+#[derive(Clone, Default)]
+pub struct TransportMetrics(Arc<MetricsInner>);
+
+impl TransportMetrics {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn record_publish(&self, bytes: usize, latency: std::time::Duration) {
+        self.0.messages_published.fetch_add(1, Ordering::Relaxed);
+        self.0
+            .bytes_published
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+
+        let latency_us = latency.as_micros().min(u64::MAX as u128) as u64;
+        self.0
+            .latency_sum_us
+            .fetch_add(latency_us, Ordering::Relaxed);
+        self.0.latency_count.fetch_add(1, Ordering::Relaxed);
+
+        match LATENCY_BUCKET_BOUNDS_US
+            .iter()
+            .position(|bound| latency_us <= *bound)
+        {
+            Some(index) => self.0.latency_buckets[index].fetch_add(1, Ordering::Relaxed),
+            None => self.0.latency_overflow.fetch_add(1, Ordering::Relaxed),
+        };
+    }
+
+    fn record_received(&self) {
+        self.0.messages_received.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_decode_failure(&self) {
+        self.0.decode_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_dropped(&self) {
+        self.0.dropped_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Total messages successfully published
+    pub fn messages_published(&self) -> u64 {
+        self.0.messages_published.load(Ordering::Relaxed)
+    }
+
+    /// Total payload bytes successfully published
+    pub fn bytes_published(&self) -> u64 {
+        self.0.bytes_published.load(Ordering::Relaxed)
+    }
+
+    /// Total messages handed to a subscriber's receive channel
+    pub fn messages_received(&self) -> u64 {
+        self.0.messages_received.load(Ordering::Relaxed)
+    }
+
+    /// Total samples a subscriber failed to decode
+    pub fn decode_failures(&self) -> u64 {
+        self.0.decode_failures.load(Ordering::Relaxed)
+    }
+
+    /// Total samples dropped because a subscriber's receive channel was
+    /// full (see [`OverflowPolicy`])
+    pub fn dropped_samples(&self) -> u64 {
+        self.0.dropped_samples.load(Ordering::Relaxed)
+    }
+
+    /// Render these counters as OpenMetrics/Prometheus text exposition
+    /// format, suitable for a `/metrics` scrape endpoint.
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP asterix_zenoh_messages_published_total Total messages published.\n");
+        out.push_str("# TYPE asterix_zenoh_messages_published_total counter\n");
+        out.push_str(&format!(
+            "asterix_zenoh_messages_published_total {}\n",
+            self.messages_published()
+        ));
+
+        out.push_str("# HELP asterix_zenoh_bytes_published_total Total payload bytes published.\n");
+        out.push_str("# TYPE asterix_zenoh_bytes_published_total counter\n");
+        out.push_str(&format!(
+            "asterix_zenoh_bytes_published_total {}\n",
+            self.bytes_published()
+        ));
+
+        out.push_str("# HELP asterix_zenoh_messages_received_total Total messages received.\n");
+        out.push_str("# TYPE asterix_zenoh_messages_received_total counter\n");
+        out.push_str(&format!(
+            "asterix_zenoh_messages_received_total {}\n",
+            self.messages_received()
+        ));
+
+        out.push_str("# HELP asterix_zenoh_decode_failures_total Total samples that failed to decode.\n");
+        out.push_str("# TYPE asterix_zenoh_decode_failures_total counter\n");
+        out.push_str(&format!(
+            "asterix_zenoh_decode_failures_total {}\n",
+            self.decode_failures()
+        ));
+
+        out.push_str(
+            "# HELP asterix_zenoh_dropped_samples_total Total samples dropped by a full receive channel.\n",
+        );
+        out.push_str("# TYPE asterix_zenoh_dropped_samples_total counter\n");
+        out.push_str(&format!(
+            "asterix_zenoh_dropped_samples_total {}\n",
+            self.dropped_samples()
+        ));
+
+        out.push_str("# HELP asterix_zenoh_publish_latency_microseconds Publish latency.\n");
+        out.push_str("# TYPE asterix_zenoh_publish_latency_microseconds histogram\n");
+        let mut cumulative = 0u64;
+        for (bound, bucket) in LATENCY_BUCKET_BOUNDS_US
+            .iter()
+            .zip(self.0.latency_buckets.iter())
+        {
+            cumulative += bucket.load(Ordering::Relaxed);
+            out.push_str(&format!(
+                "asterix_zenoh_publish_latency_microseconds_bucket{{le=\"{bound}\"}} {cumulative}\n"
+            ));
+        }
+        cumulative += self.0.latency_overflow.load(Ordering::Relaxed);
+        out.push_str(&format!(
+            "asterix_zenoh_publish_latency_microseconds_bucket{{le=\"+Inf\"}} {cumulative}\n"
+        ));
+        out.push_str(&format!(
+            "asterix_zenoh_publish_latency_microseconds_sum {}\n",
+            self.0.latency_sum_us.load(Ordering::Relaxed)
+        ));
+        out.push_str(&format!(
+            "asterix_zenoh_publish_latency_microseconds_count {}\n",
+            self.0.latency_count.load(Ordering::Relaxed)
+        ));
+
+        out
+    }
+}
+
+/// Backing queue for [`ZenohSubscriber`]'s receive side: a bounded
+/// `VecDeque` with the same "drop oldest to make room" option
+/// [`crate::transport::metrics::MetricsRecorder`] uses, generalized to all
+/// of [`OverflowPolicy`] and adapted to async (a registered [`Waker`] per
+/// side instead of a blocking [`std::sync::Condvar`], since the producer
+/// here is a Tokio task, not an OS thread).
+struct BoundedSampleQueue {
+    capacity: usize,
+    state: Mutex<BoundedQueueState>,
+}
+
+struct BoundedQueueState {
+    queue: VecDeque<AsterixSample>,
+    closed: bool,
+    read_waker: Option<Waker>,
+    write_waker: Option<Waker>,
+}
+
+enum PushOutcome {
+    Enqueued,
+    /// Dropped a sample (the oldest buffered one, or `sample` itself) to
+    /// stay within capacity
+    Dropped,
+    /// Only returned under [`OverflowPolicy::Block`]; hands `sample` back so
+    /// the caller can wait for room via [`BoundedSampleQueue::poll_send`]
+    /// instead of losing it
+    Full(AsterixSample),
+}
+
+impl BoundedSampleQueue {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            state: Mutex::new(BoundedQueueState {
+                queue: VecDeque::new(),
+                closed: false,
+                read_waker: None,
+                write_waker: None,
+            }),
+        }
+    }
+
+    /// Enqueue `sample` per `policy`, non-blocking. Under
+    /// [`OverflowPolicy::Block`] this never drops — it returns
+    /// [`PushOutcome::Full`] instead, for the caller to retry via
+    /// [`Self::poll_send`].
+    fn push(&self, sample: AsterixSample, policy: OverflowPolicy) -> PushOutcome {
+        let mut state = self.state.lock().unwrap();
+
+        let outcome = if state.queue.len() < self.capacity {
+            state.queue.push_back(sample);
+            PushOutcome::Enqueued
+        } else {
+            match policy {
+                OverflowPolicy::DropNewest => PushOutcome::Dropped,
+                OverflowPolicy::DropOldest => {
+                    state.queue.pop_front();
+                    state.queue.push_back(sample);
+                    PushOutcome::Dropped
+                }
+                OverflowPolicy::Block => return PushOutcome::Full(sample),
+            }
+        };
+
+        if let Some(waker) = state.read_waker.take() {
+            waker.wake();
+        }
+        outcome
+    }
+
+    /// Poll to enqueue `sample` under [`OverflowPolicy::Block`]'s
+    /// backpressure semantics: ready once there's room, pending (registering
+    /// `cx`'s waker to be woken on the next [`Self::poll_recv`]) otherwise.
+    fn poll_send(&self, sample: &mut Option<AsterixSample>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = self.state.lock().unwrap();
+        if state.queue.len() < self.capacity {
+            state.queue.push_back(sample.take().expect("poll_send called after completion"));
+            if let Some(waker) = state.read_waker.take() {
+                waker.wake();
+            }
+            return Poll::Ready(());
+        }
+        state.write_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    fn poll_recv(&self, cx: &mut Context<'_>) -> Poll<Option<AsterixSample>> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(sample) = state.queue.pop_front() {
+            if let Some(waker) = state.write_waker.take() {
+                waker.wake();
+            }
+            return Poll::Ready(Some(sample));
+        }
+        if state.closed {
+            return Poll::Ready(None);
+        }
+        state.read_waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+
+    fn try_recv(&self) -> Option<AsterixSample> {
+        let mut state = self.state.lock().unwrap();
+        let sample = state.queue.pop_front();
+        if sample.is_some() {
+            if let Some(waker) = state.write_waker.take() {
+                waker.wake();
+            }
+        }
+        sample
+    }
+
+    fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        if let Some(waker) = state.read_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
 impl Default for ZenohConfig {
     fn default() -> Self {
         Self {
             endpoints: Vec::new(),
+            mode: ZenohMode::default(),
             key_prefix: "asterix".to_string(),
             include_raw_bytes: true,
             #[cfg(feature = "serde")]
             include_parsed: true,
+            history_depth: 0,
+            history_max_age_secs: 0,
             congestion_control: CongestionControl::default(),
             priority: Priority::default(),
+            qos_profiles: BTreeMap::new(),
+            reliability: Reliability::default(),
+            low_latency: false,
+            max_payload_size: DEFAULT_MAX_PAYLOAD_SIZE,
+            payload_format: PayloadFormat::default(),
+            batch_size: 1,
+            linger: std::time::Duration::ZERO,
+            encryption: None,
+            key_expr_template: None,
+            channel_capacity: 1000,
+            overflow_policy: OverflowPolicy::default(),
         }
     }
 }
@@ -210,10 +909,381 @@ impl ZenohConfig {
             ..Default::default()
         }
     }
+
+    /// Set the publisher priority (see [`Priority`])
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set the congestion control mode (see [`CongestionControl`])
+    pub fn with_congestion_control(mut self, congestion_control: CongestionControl) -> Self {
+        self.congestion_control = congestion_control;
+        self
+    }
+
+    /// Override priority/congestion_control/reliability together for one
+    /// category (see [`Self::qos_profiles`])
+    pub fn with_qos_profile(mut self, category: u8, profile: QosProfile) -> Self {
+        self.qos_profiles.insert(category, profile);
+        self
+    }
+
+    /// Set the delivery reliability guarantee (see [`Reliability`])
+    pub fn with_reliability(mut self, reliability: Reliability) -> Self {
+        self.reliability = reliability;
+        self
+    }
+
+    /// Pre-populate [`Self::qos_profiles`] with sensible defaults for common
+    /// surveillance categories, so operators get reasonable prioritization
+    /// without hand-tuning each one: CAT021 (ADS-B) and CAT048 (radar plots)
+    /// get [`Priority::RealTime`] + [`CongestionControl::Drop`] +
+    /// [`Reliability::BestEffort`] (a stale plot is worse than a dropped
+    /// one), while CAT062 (system tracks) and CAT065 (SDPS status) get
+    /// [`Priority::Interactive`] + [`CongestionControl::Block`] +
+    /// [`Reliability::Reliable`].
+    pub fn with_surveillance_qos() -> Self {
+        let realtime = QosProfile {
+            priority: Priority::RealTime,
+            congestion_control: CongestionControl::Drop,
+            reliability: Reliability::BestEffort,
+        };
+        let interactive = QosProfile {
+            priority: Priority::Interactive,
+            congestion_control: CongestionControl::Block,
+            reliability: Reliability::Reliable,
+        };
+
+        Self::default()
+            .with_qos_profile(21, realtime)
+            .with_qos_profile(48, realtime)
+            .with_qos_profile(62, interactive)
+            .with_qos_profile(65, interactive)
+    }
+
+    /// Set [`BatchingPublisher`]'s flush threshold (see [`Self::batch_size`])
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set [`BatchingPublisher`]'s linger timeout (see [`Self::linger`])
+    pub fn with_linger(mut self, linger: std::time::Duration) -> Self {
+        self.linger = linger;
+        self
+    }
+
+    /// Enable AES-256-GCM payload encryption under `master_key` (see
+    /// [`Self::encryption`])
+    pub fn with_encryption(mut self, master_key: [u8; 32]) -> Self {
+        self.encryption = Some(EncryptionConfig::new(master_key));
+        self
+    }
+
+    /// Route published records by `template` instead of the hardcoded
+    /// category/SAC/SIC scheme (see [`Self::key_expr_template`])
+    pub fn with_key_expr_template(mut self, template: KeyExprTemplate) -> Self {
+        self.key_expr_template = Some(template);
+        self
+    }
+
+    /// Set [`ZenohSubscriber`]'s receive channel capacity (see
+    /// [`Self::channel_capacity`])
+    pub fn with_channel_capacity(mut self, channel_capacity: usize) -> Self {
+        self.channel_capacity = channel_capacity;
+        self
+    }
+
+    /// Set [`ZenohSubscriber`]'s receive channel overflow policy (see
+    /// [`Self::overflow_policy`])
+    pub fn with_overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+
+    /// Parse a Zenoh connection string into a config, so a deployment can be
+    /// driven entirely off one env var or CLI arg instead of constructing a
+    /// [`ZenohConfig`] in code.
+    ///
+    /// Expected form: `zenoh://<mode>/<endpoint>?<query>`, e.g.
+    /// `zenoh://router/tcp/192.0.2.1:7447?mode=client&prefix=asterix&priority=realtime&congestion=drop&raw=true&format=cbor`.
+    ///
+    /// - The segment right after `zenoh://` is the session mode
+    ///   (`peer`/`client`/`router`, see [`ZenohMode`]); everything after it up
+    ///   to `?` becomes the single entry of [`Self::endpoints`].
+    /// - `mode` in the query string, if present, overrides the mode segment —
+    ///   useful when a caller only wants to vary the mode without rebuilding
+    ///   the rest of the string.
+    /// - `prefix` sets [`Self::key_prefix`], `priority` sets [`Self::priority`]
+    ///   (`realtime`/`interactive`/`data`/`background`), `congestion` sets
+    ///   [`Self::congestion_control`] (`block`/`drop`), `raw` sets
+    ///   [`Self::include_raw_bytes`] (`true`/`false`), and `format` sets
+    ///   [`Self::payload_format`] (`raw`/`json`/`cbor`/`messagepack`).
+    ///
+    /// Every other field keeps [`ZenohConfig::default`]'s value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::ConfigError`] if `addr` doesn't start with
+    /// `zenoh://`, has no endpoint segment, or has a query key/value this
+    /// function doesn't recognize — unrecognized query keys are rejected
+    /// rather than silently ignored, so a typo'd parameter doesn't silently
+    /// fall back to a default.
+    pub fn from_addr(addr: &str) -> Result<Self, ZenohError> {
+        let rest = addr.strip_prefix("zenoh://").ok_or_else(|| {
+            ZenohError::ConfigError(format!(
+                "expected a connection string starting with \"zenoh://\", got {addr:?}"
+            ))
+        })?;
+
+        let (path, query) = match rest.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (rest, None),
+        };
+
+        let (mode_segment, endpoint) = path.split_once('/').ok_or_else(|| {
+            ZenohError::ConfigError(format!(
+                "connection string {addr:?} is missing a \"<mode>/<endpoint>\" path"
+            ))
+        })?;
+        let mut mode = parse_zenoh_mode(mode_segment)?;
+
+        if endpoint.is_empty() {
+            return Err(ZenohError::ConfigError(format!(
+                "connection string {addr:?} has an empty endpoint"
+            )));
+        }
+
+        let mut config = Self {
+            endpoints: vec![endpoint.to_string()],
+            ..Self::default()
+        };
+
+        for pair in query.into_iter().flat_map(|q| q.split('&')) {
+            if pair.is_empty() {
+                continue;
+            }
+            let (key, value) = pair.split_once('=').ok_or_else(|| {
+                ZenohError::ConfigError(format!(
+                    "query parameter {pair:?} in {addr:?} is missing a value"
+                ))
+            })?;
+
+            match key {
+                "mode" => mode = parse_zenoh_mode(value)?,
+                "prefix" => config.key_prefix = value.to_string(),
+                "priority" => config.priority = parse_priority(value)?,
+                "congestion" => config.congestion_control = parse_congestion_control(value)?,
+                "raw" => {
+                    config.include_raw_bytes = value.parse::<bool>().map_err(|_| {
+                        ZenohError::ConfigError(format!(
+                            "invalid value for \"raw\": {value:?} (expected \"true\" or \"false\")"
+                        ))
+                    })?;
+                }
+                "format" => config.payload_format = parse_payload_format(value)?,
+                other => {
+                    return Err(ZenohError::ConfigError(format!(
+                        "unrecognized ZenohConfig::from_addr query parameter {other:?}"
+                    )))
+                }
+            }
+        }
+
+        config.mode = mode;
+        Ok(config)
+    }
+}
+
+/// Parse a `mode`/path-segment value for [`ZenohConfig::from_addr`] into a [`ZenohMode`].
+fn parse_zenoh_mode(value: &str) -> Result<ZenohMode, ZenohError> {
+    match value {
+        "peer" => Ok(ZenohMode::Peer),
+        "client" => Ok(ZenohMode::Client),
+        "router" => Ok(ZenohMode::Router),
+        other => Err(ZenohError::ConfigError(format!(
+            "unrecognized Zenoh mode {other:?} (expected \"peer\", \"client\", or \"router\")"
+        ))),
+    }
+}
+
+/// Parse a `priority` query value for [`ZenohConfig::from_addr`] into a [`Priority`].
+fn parse_priority(value: &str) -> Result<Priority, ZenohError> {
+    match value {
+        "realtime" => Ok(Priority::RealTime),
+        "interactive" => Ok(Priority::Interactive),
+        "data" => Ok(Priority::Data),
+        "background" => Ok(Priority::Background),
+        other => Err(ZenohError::ConfigError(format!(
+            "unrecognized priority {other:?} (expected \"realtime\", \"interactive\", \"data\", or \"background\")"
+        ))),
+    }
+}
+
+/// Parse a `congestion` query value for [`ZenohConfig::from_addr`] into a [`CongestionControl`].
+fn parse_congestion_control(value: &str) -> Result<CongestionControl, ZenohError> {
+    match value {
+        "block" => Ok(CongestionControl::Block),
+        "drop" => Ok(CongestionControl::Drop),
+        other => Err(ZenohError::ConfigError(format!(
+            "unrecognized congestion control {other:?} (expected \"block\" or \"drop\")"
+        ))),
+    }
+}
+
+/// Parse a `format` query value for [`ZenohConfig::from_addr`] into a [`PayloadFormat`].
+fn parse_payload_format(value: &str) -> Result<PayloadFormat, ZenohError> {
+    match value {
+        "raw" => Ok(PayloadFormat::Raw),
+        "json" => Ok(PayloadFormat::Json),
+        "cbor" => Ok(PayloadFormat::Cbor),
+        "messagepack" => Ok(PayloadFormat::MessagePack),
+        other => Err(ZenohError::ConfigError(format!(
+            "unrecognized payload format {other:?} (expected \"raw\", \"json\", \"cbor\", or \"messagepack\")"
+        ))),
+    }
+}
+
+/// AES-256-GCM payload encryption config for [`ZenohConfig::encryption`]
+///
+/// Holds one 32-byte master key; every publish/receive derives a
+/// per-category key from it via HKDF-SHA256 (see [`derive_category_key`])
+/// rather than using the master key directly, so a compromised per-category
+/// key never exposes another category's traffic or the master key itself.
+#[derive(Clone)]
+pub struct EncryptionConfig {
+    master_key: [u8; 32],
+}
+
+impl EncryptionConfig {
+    /// Create an encryption config from a 32-byte master key
+    pub fn new(master_key: [u8; 32]) -> Self {
+        Self { master_key }
+    }
+}
+
+impl fmt::Debug for EncryptionConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncryptionConfig")
+            .field("master_key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Length, in bytes, of the random nonce [`encrypt_payload`] prepends to
+/// every ciphertext (AES-GCM's standard 96-bit nonce size).
+const ENCRYPTION_NONCE_LEN: usize = 12;
+
+/// Length, in bytes, of the AES-GCM authentication tag [`encrypt_payload`]
+/// appends to every ciphertext.
+const ENCRYPTION_TAG_LEN: usize = 16;
+
+/// Derive the per-category AES-256-GCM key for `category` from `master_key`
+/// via HKDF-SHA256, with an empty salt and `info = b"asterix-cat-" ||
+/// category`, so each category's traffic is encrypted under its own key.
+fn derive_category_key(master_key: &[u8; 32], category: u8) -> [u8; 32] {
+    let hkdf = Hkdf::<Sha256>::new(None, master_key);
+    let mut info = b"asterix-cat-".to_vec();
+    info.push(category);
+
+    let mut key = [0u8; 32];
+    hkdf.expand(&info, &mut key)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// Encrypt `plaintext` for `category` under `config`, authenticating
+/// `key_expr` as additional data, and return the framed
+/// `nonce(12) || ciphertext || tag(16)` blob to publish in its place.
+///
+/// A fresh nonce is drawn from the OS RNG for every call — a (key, nonce)
+/// pair must never repeat under AES-GCM, so nonce generation failing is
+/// treated as fatal rather than falling back to a weaker source or a
+/// deterministic value.
+///
+/// # Errors
+///
+/// Returns [`ZenohError::EncryptionError`] if the OS RNG fails to supply a
+/// nonce, or if the underlying cipher rejects the key or plaintext.
+fn encrypt_payload(
+    config: &EncryptionConfig,
+    category: u8,
+    key_expr: &str,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, ZenohError> {
+    let key_bytes = derive_category_key(&config.master_key, category);
+    let cipher = Aes256Gcm::new_from_slice(&key_bytes)
+        .map_err(|e| ZenohError::EncryptionError(e.to_string()))?;
+
+    let mut nonce_bytes = [0u8; ENCRYPTION_NONCE_LEN];
+    getrandom::getrandom(&mut nonce_bytes)
+        .map_err(|e| ZenohError::EncryptionError(format!("failed to generate nonce: {e}")))?;
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(
+            nonce,
+            Payload {
+                msg: plaintext,
+                aad: key_expr.as_bytes(),
+            },
+        )
+        .map_err(|e| ZenohError::EncryptionError(e.to_string()))?;
+
+    let mut framed = Vec::with_capacity(ENCRYPTION_NONCE_LEN + ciphertext.len());
+    framed.extend_from_slice(&nonce_bytes);
+    framed.extend_from_slice(&ciphertext);
+    Ok(framed)
+}
+
+/// Reverse [`encrypt_payload`]: split `framed` back into its nonce and
+/// ciphertext, then decrypt and authenticate it for `category` under
+/// `config`, checking `key_expr` as the same additional data the publisher
+/// authenticated.
+///
+/// # Errors
+///
+/// Returns [`ZenohError::DecryptionError`] if `framed` is too short to hold
+/// the nonce and tag, or if authentication fails — a wrong key, wrong
+/// `key_expr`, or tampered ciphertext all surface as this same error rather
+/// than distinguishing the cause, so a caller can't use the failure mode to
+/// probe for a valid key.
+fn decrypt_payload(
+    config: &EncryptionConfig,
+    category: u8,
+    key_expr: &str,
+    framed: &[u8],
+) -> Result<Vec<u8>, ZenohError> {
+    if framed.len() < ENCRYPTION_NONCE_LEN + ENCRYPTION_TAG_LEN {
+        return Err(ZenohError::DecryptionError(format!(
+            "encrypted payload is {} byte(s), shorter than the {}-byte nonce+tag overhead",
+            framed.len(),
+            ENCRYPTION_NONCE_LEN + ENCRYPTION_TAG_LEN
+        )));
+    }
+
+    let key_bytes = derive_category_key(&config.master_key, category);
+    let cipher = Aes256Gcm::new_from_slice(&key_bytes)
+        .map_err(|e| ZenohError::DecryptionError(e.to_string()))?;
+
+    let (nonce_bytes, ciphertext) = framed.split_at(ENCRYPTION_NONCE_LEN);
+    let nonce = Nonce::from_slice(nonce_bytes);
+
+    cipher
+        .decrypt(
+            nonce,
+            Payload {
+                msg: ciphertext,
+                aad: key_expr.as_bytes(),
+            },
+        )
+        .map_err(|_| ZenohError::DecryptionError("authentication failed".to_string()))
 }
 
 /// Received ASTERIX sample from Zenoh
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AsterixSample {
     /// ASTERIX category
     pub category: u8,
@@ -227,19 +1297,105 @@ pub struct AsterixSample {
     pub timestamp: u64,
     /// Key expression the sample was published on
     pub key_expr: String,
+    /// Wire format `data` is encoded as, read back off the sample's Zenoh
+    /// encoding attribute (see [`Self::decode_record`]).
+    /// Default: [`PayloadFormat::Raw`], for any sample published without
+    /// one (e.g. not via [`ZenohPublisher::publish`])
+    pub encoding: PayloadFormat,
+}
+
+impl AsterixSample {
+    /// Decode `self.data` into an [`AsterixRecord`], dispatching on
+    /// `self.encoding` instead of requiring the caller to already know which
+    /// [`PayloadFormat`] [`ZenohPublisher::publish`] used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::SerializationError`] if:
+    /// - `self.encoding` is [`PayloadFormat::Raw`] — raw ASTERIX bytes need
+    ///   a [`crate::serialized_decoder::SerializedDecoder`] (see
+    ///   [`DecodingSubscriber`]), not a plain deserialize
+    /// - `self.encoding` is [`PayloadFormat::Cbor`] — [`crate::cbor`] is an
+    ///   encoder only by design (see its module docs)
+    /// - the configured format's deserializer rejects `self.data`
+    pub fn decode_record(&self) -> Result<AsterixRecord, ZenohError> {
+        match self.encoding {
+            PayloadFormat::Raw => Err(ZenohError::SerializationError(
+                "PayloadFormat::Raw samples are raw ASTERIX bytes, not a serialized \
+                 AsterixRecord; use DecodingSubscriber to decode them instead"
+                    .to_string(),
+            )),
+            PayloadFormat::Json => {
+                #[cfg(feature = "serde")]
+                {
+                    serde_json::from_slice(&self.data)
+                        .map_err(|e| ZenohError::SerializationError(e.to_string()))
+                }
+
+                #[cfg(not(feature = "serde"))]
+                Err(ZenohError::SerializationError(
+                    "PayloadFormat::Json requires the 'serde' feature".to_string(),
+                ))
+            }
+            PayloadFormat::Cbor => Err(ZenohError::SerializationError(
+                "crate::cbor is encode-only; decoding a PayloadFormat::Cbor sample back into \
+                 an AsterixRecord isn't supported"
+                    .to_string(),
+            )),
+            PayloadFormat::MessagePack => {
+                #[cfg(feature = "serde")]
+                {
+                    rmp_serde::from_slice(&self.data)
+                        .map_err(|e| ZenohError::SerializationError(e.to_string()))
+                }
+
+                #[cfg(not(feature = "serde"))]
+                Err(ZenohError::SerializationError(
+                    "PayloadFormat::MessagePack requires the 'serde' feature".to_string(),
+                ))
+            }
+        }
+    }
 }
 
 /// Zenoh publisher for ASTERIX data
 pub struct ZenohPublisher {
     session: Arc<Session>,
     config: ZenohConfig,
+    /// Declared Zenoh publishers, cached per key expression so
+    /// `priority`/`congestion_control`/`reliability` are applied once at
+    /// declaration time instead of being silently ignored by a plain
+    /// `session.put(...)`. See [`Self::put_declared`].
+    publishers: tokio::sync::Mutex<HashMap<String, zenoh::pubsub::Publisher<'static>>>,
+    /// Held only when constructed via [`Self::with_source_discovery`]; Zenoh
+    /// undeclares the token (and so removes this source from every
+    /// [`ZenohSourceDiscovery`] watching it) when this is dropped, the
+    /// session closes, or the process crashes and the session's liveliness
+    /// lease lapses.
+    _liveliness_token: Option<zenoh::liveliness::LivelinessToken>,
+    metrics: TransportMetrics,
 }
 
 impl ZenohPublisher {
     /// Create a new Zenoh publisher
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::ConfigError`] if `config.low_latency` is set
+    /// alongside a non-default `priority` or `congestion_control` — Zenoh's
+    /// low-latency transport doesn't support QoS prioritization.
     pub async fn new(config: ZenohConfig) -> Result<Self, ZenohError> {
-        let mut zenoh_config = Config::default();
-
+        if config.low_latency
+            && (config.priority != Priority::default()
+                || config.congestion_control != CongestionControl::default())
+        {
+            return Err(ZenohError::ConfigError(
+                "low_latency is mutually exclusive with QoS prioritization (non-default priority or congestion_control)".to_string(),
+            ));
+        }
+
+        let mut zenoh_config = Config::default();
+
         // Configure endpoints if specified
         if !config.endpoints.is_empty() {
             let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
@@ -248,6 +1404,26 @@ impl ZenohPublisher {
                 .map_err(|e| ZenohError::SessionError(e.to_string()))?;
         }
 
+        if config.low_latency {
+            zenoh_config
+                .insert_json5("transport/unicast/lowlatency", "true")
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+            zenoh_config
+                .insert_json5("transport/unicast/qos/enabled", "false")
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
+        if config.mode != ZenohMode::default() {
+            let mode_str = match config.mode {
+                ZenohMode::Peer => "peer",
+                ZenohMode::Client => "client",
+                ZenohMode::Router => "router",
+            };
+            zenoh_config
+                .insert_json5("mode", &format!("\"{mode_str}\""))
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
         let session = zenoh::open(zenoh_config)
             .await
             .map_err(|e| ZenohError::SessionError(e.to_string()))?;
@@ -255,22 +1431,176 @@ impl ZenohPublisher {
         Ok(Self {
             session: Arc::new(session),
             config,
+            publishers: tokio::sync::Mutex::new(HashMap::new()),
+            _liveliness_token: None,
+            metrics: TransportMetrics::new(),
         })
     }
 
+    /// Create a publisher that also announces itself for
+    /// [`ZenohSourceDiscovery`] to find.
+    ///
+    /// Declares a Zenoh liveliness token under
+    /// `{config.key_prefix}/@/sources/{source_id}/{categories}` (categories
+    /// dash-joined, e.g. `48-62`) in addition to everything [`Self::new`]
+    /// does. The token — and so this source's entry in every
+    /// [`ZenohSourceDiscovery`]'s live set — disappears automatically when
+    /// the returned publisher is dropped/closed or the process's Zenoh
+    /// session otherwise goes away, with no separate heartbeat channel
+    /// needed.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::new`], plus [`ZenohError::PublisherError`] if the
+    /// liveliness token can't be declared.
+    pub async fn with_source_discovery(
+        config: ZenohConfig,
+        source_id: &str,
+        categories: &[u8],
+    ) -> Result<Self, ZenohError> {
+        let publisher = Self::new(config).await?;
+
+        let categories_segment = categories
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join("-");
+        let discovery_key = format!(
+            "{}/{SOURCE_DISCOVERY_SEGMENT}/{source_id}/{categories_segment}",
+            publisher.config.key_prefix
+        );
+
+        let token = publisher
+            .session
+            .liveliness()
+            .declare_token(&discovery_key)
+            .await
+            .map_err(|e| ZenohError::PublisherError(e.to_string()))?;
+
+        Ok(Self {
+            _liveliness_token: Some(token),
+            ..publisher
+        })
+    }
+
+    /// The [`QosProfile`] this publisher uses for `category`: its
+    /// `qos_profiles` override if one is configured, else `config.priority`/
+    /// `config.congestion_control`/`config.reliability`. See
+    /// [`ZenohConfig::qos_profiles`].
+    fn effective_qos(&self, category: u8) -> QosProfile {
+        self.config.qos_profiles.get(&category).copied().unwrap_or(QosProfile {
+            priority: self.config.priority,
+            congestion_control: self.config.congestion_control,
+            reliability: self.config.reliability,
+        })
+    }
+
+    /// The priority this publisher uses for `category` (see
+    /// [`Self::effective_qos`]).
+    fn effective_priority(&self, category: u8) -> Priority {
+        self.effective_qos(category).priority
+    }
+
+    /// Publish `payload` under `key_expr` through a declared [`zenoh::pubsub::Publisher`]
+    /// cached for `key_expr`, so `qos`'s priority/congestion_control/reliability
+    /// are applied once at declaration instead of being silently ignored by a
+    /// plain `session.put(...)`
+    async fn put_declared(
+        &self,
+        key_expr: &str,
+        payload: Vec<u8>,
+        qos: QosProfile,
+        encoding: Option<zenoh::bytes::Encoding>,
+    ) -> Result<(), ZenohError> {
+        let mut publishers = self.publishers.lock().await;
+
+        if !publishers.contains_key(key_expr) {
+            let publisher = self
+                .session
+                .declare_publisher(key_expr.to_string())
+                .priority(zenoh_priority(qos.priority))
+                .congestion_control(zenoh_congestion_control(qos.congestion_control))
+                .reliability(zenoh_reliability(qos.reliability))
+                .await
+                .map_err(|e| ZenohError::PublisherError(e.to_string()))?;
+            publishers.insert(key_expr.to_string(), publisher);
+        }
+
+        let payload_len = payload.len();
+        let started = std::time::Instant::now();
+
+        let publisher = publishers.get(key_expr).unwrap();
+        let builder = publisher.put(payload);
+        let builder = match encoding {
+            Some(encoding) => builder.encoding(encoding),
+            None => builder,
+        };
+        let result = builder
+            .await
+            .map_err(|e| ZenohError::PublishError(e.to_string()));
+
+        if result.is_ok() {
+            self.metrics.record_publish(payload_len, started.elapsed());
+        }
+
+        result
+    }
+
+    /// Runtime counters for this publisher (messages/bytes published,
+    /// publish latency — see [`TransportMetrics`])
+    pub fn metrics(&self) -> TransportMetrics {
+        self.metrics.clone()
+    }
+
     /// Publish an ASTERIX record
+    ///
+    /// The record is serialized according to [`ZenohConfig::payload_format`]
+    /// and the sample's Zenoh encoding attribute is set to match, so a
+    /// subscriber can tell a [`PayloadFormat::Cbor`]/[`PayloadFormat::Json`]
+    /// payload apart from raw ASTERIX bytes without any out-of-band
+    /// agreement.
     pub async fn publish(&self, record: &AsterixRecord) -> Result<(), ZenohError> {
         // Build key expression: asterix/{category}/{sac}/{sic}
         let key_expr = self.build_key_expr(record);
 
         // Serialize the record
-        let payload = self.serialize_record(record)?;
+        let (payload, actual_format) = self.serialize_record(record)?;
+
+        // A low-latency session can't fragment (see `ZenohConfig::low_latency`),
+        // and `publish` — unlike `publish_raw`/`publish_raw_with_routing` — never
+        // has a chance to split this payload across sub-keys, so warn instead of
+        // silently shipping an oversized put.
+        if self.config.low_latency
+            && self.config.max_payload_size != 0
+            && payload.len() > self.config.max_payload_size
+        {
+            log::warn!(
+                "Serialized ASTERIX CAT{} record is {} bytes, exceeding max_payload_size ({}) \
+                 with low_latency enabled; this payload cannot be fragmented and may be dropped \
+                 or truncated by the transport",
+                record.category,
+                payload.len(),
+                self.config.max_payload_size
+            );
+        }
 
-        // Publish
-        self.session
-            .put(&key_expr, payload)
-            .await
-            .map_err(|e| ZenohError::PublishError(e.to_string()))?;
+        // Encrypt the serialized payload, if `ZenohConfig::encryption` is set,
+        // before it reaches a declared publisher.
+        let payload = match &self.config.encryption {
+            Some(enc) => encrypt_payload(enc, record.category, &key_expr, &payload)?,
+            None => payload,
+        };
+
+        // Publish through a declared publisher so priority/congestion_control/
+        // reliability apply, using this category's QoS override if one is
+        // configured.
+        self.put_declared(
+            &key_expr,
+            payload,
+            self.effective_qos(record.category),
+            Some(zenoh_encoding(actual_format)),
+        )
+        .await?;
 
         log::debug!("Published ASTERIX CAT{} to {}", record.category, key_expr);
 
@@ -278,13 +1608,14 @@ impl ZenohPublisher {
     }
 
     /// Publish raw ASTERIX bytes with category information
+    ///
+    /// `data` larger than [`ZenohConfig::max_payload_size`] is fragmented
+    /// across `asterix/.../frag/<seq>/<total>` sub-keys rather than sent as
+    /// a single oversized `put` — see [`ZenohConfig::low_latency`].
     pub async fn publish_raw(&self, category: u8, data: &[u8]) -> Result<(), ZenohError> {
         let key_expr = format!("{}/{}", self.config.key_prefix, category);
 
-        self.session
-            .put(&key_expr, data.to_vec())
-            .await
-            .map_err(|e| ZenohError::PublishError(e.to_string()))?;
+        self.publish_chunked(&key_expr, data, category).await?;
 
         log::debug!(
             "Published {} bytes of CAT{} to {}",
@@ -297,6 +1628,10 @@ impl ZenohPublisher {
     }
 
     /// Publish raw ASTERIX bytes with full routing info
+    ///
+    /// `data` larger than [`ZenohConfig::max_payload_size`] is fragmented
+    /// across `asterix/.../frag/<seq>/<total>` sub-keys rather than sent as
+    /// a single oversized `put` — see [`ZenohConfig::low_latency`].
     pub async fn publish_raw_with_routing(
         &self,
         category: u8,
@@ -306,10 +1641,7 @@ impl ZenohPublisher {
     ) -> Result<(), ZenohError> {
         let key_expr = format!("{}/{}/{}/{}", self.config.key_prefix, category, sac, sic);
 
-        self.session
-            .put(&key_expr, data.to_vec())
-            .await
-            .map_err(|e| ZenohError::PublishError(e.to_string()))?;
+        self.publish_chunked(&key_expr, data, category).await?;
 
         log::debug!(
             "Published {} bytes of CAT{} SAC={} SIC={} to {}",
@@ -323,6 +1655,94 @@ impl ZenohPublisher {
         Ok(())
     }
 
+    /// Publish a raw ASTERIX datablock, splitting it into its constituent
+    /// per-category blocks (via [`frame_blocks`]) and publishing each one
+    /// under its own key rather than as a single combined message.
+    ///
+    /// Unlike [`Self::publish_raw`]/[`Self::publish_raw_with_routing`],
+    /// this never relies on [`Self::publish_chunked`]'s fragmentation to
+    /// shrink an oversized message: with [`ZenohConfig::low_latency`] set,
+    /// the transport doesn't reassemble fragments, so a block that still
+    /// exceeds [`ZenohConfig::max_payload_size`] on its own is rejected
+    /// outright instead of silently fragmented or dropped by the transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::PublishError`] if `data` isn't a sequence of
+    /// complete, validly-framed ASTERIX blocks, or if `config.low_latency`
+    /// is set and a single block exceeds `config.max_payload_size`.
+    pub async fn publish_block(&self, data: &[u8]) -> Result<(), ZenohError> {
+        let (spans, consumed) = frame_blocks(data)
+            .map_err(|e| ZenohError::PublishError(format!("malformed ASTERIX block: {e}")))?;
+
+        if consumed != data.len() {
+            return Err(ZenohError::PublishError(format!(
+                "trailing {} byte(s) at offset {consumed} don't form a complete ASTERIX block",
+                data.len() - consumed
+            )));
+        }
+
+        for span in spans {
+            let record = &data[span.start..span.start + span.len];
+
+            if self.config.low_latency
+                && self.config.max_payload_size != 0
+                && record.len() > self.config.max_payload_size
+            {
+                return Err(ZenohError::PublishError(format!(
+                    "CAT{} block is {} bytes, exceeding max_payload_size ({}) with low_latency \
+                     enabled; low-latency transport cannot fragment this block",
+                    span.category,
+                    record.len(),
+                    self.config.max_payload_size
+                )));
+            }
+
+            let key_expr = format!("{}/{}", self.config.key_prefix, span.category);
+            self.publish_chunked(&key_expr, record, span.category)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Publish `data` under `key_expr`, encrypting it first if
+    /// `config.encryption` is set, then fragmenting into numbered
+    /// `key_expr/frag/<seq>/<total>` sub-keys if it exceeds
+    /// `config.max_payload_size`. Every key published to a declared
+    /// publisher using `category`'s effective QoS (see
+    /// [`Self::effective_qos`]).
+    async fn publish_chunked(
+        &self,
+        key_expr: &str,
+        data: &[u8],
+        category: u8,
+    ) -> Result<(), ZenohError> {
+        let qos = self.effective_qos(category);
+
+        let data = match &self.config.encryption {
+            Some(enc) => encrypt_payload(enc, category, key_expr, data)?,
+            None => data.to_vec(),
+        };
+        let data = data.as_slice();
+
+        if data.len() <= self.config.max_payload_size || self.config.max_payload_size == 0 {
+            return self
+                .put_declared(key_expr, data.to_vec(), qos, None)
+                .await;
+        }
+
+        let chunks: Vec<&[u8]> = data.chunks(self.config.max_payload_size).collect();
+        let total = chunks.len();
+        for (seq, chunk) in chunks.into_iter().enumerate() {
+            let frag_key = format!("{key_expr}/frag/{seq}/{total}");
+            self.put_declared(&frag_key, chunk.to_vec(), qos, None)
+                .await?;
+        }
+
+        Ok(())
+    }
+
     /// Close the publisher and release resources
     pub async fn close(self) -> Result<(), ZenohError> {
         self.session
@@ -332,6 +1752,10 @@ impl ZenohPublisher {
     }
 
     fn build_key_expr(&self, record: &AsterixRecord) -> String {
+        if let Some(template) = &self.config.key_expr_template {
+            return template.resolve(&self.config.key_prefix, record);
+        }
+
         // Try to extract SAC/SIC from I010 item if present
         let (sac, sic) = self.extract_sac_sic(record);
 
@@ -367,67 +1791,365 @@ impl ZenohPublisher {
         (None, None)
     }
 
-    fn serialize_record(&self, record: &AsterixRecord) -> Result<Vec<u8>, ZenohError> {
-        // If raw bytes requested and hex_data available, decode and use that
-        if self.config.include_raw_bytes && !record.hex_data.is_empty() {
-            return self.hex_to_bytes(&record.hex_data);
+    /// Serialize `record` per [`ZenohConfig::payload_format`], returning the
+    /// bytes alongside the format actually produced.
+    ///
+    /// [`PayloadFormat::Raw`] falls back to JSON when `include_raw_bytes` is
+    /// false or `hex_data` is empty, so the returned format can differ from
+    /// the configured one — callers (e.g. [`ZenohPublisher::publish`]) must
+    /// use the returned format, not [`ZenohConfig::payload_format`], when
+    /// setting the sample's encoding attribute.
+    fn serialize_record(
+        &self,
+        record: &AsterixRecord,
+    ) -> Result<(Vec<u8>, PayloadFormat), ZenohError> {
+        match self.config.payload_format {
+            PayloadFormat::Raw => {
+                // If raw bytes requested and hex_data available, decode and use that
+                if self.config.include_raw_bytes && !record.hex_data.is_empty() {
+                    return Ok((self.hex_to_bytes(&record.hex_data)?, PayloadFormat::Raw));
+                }
+
+                // Fallback: serialize as JSON if serde available
+                #[cfg(feature = "serde")]
+                {
+                    let payload = serde_json::to_vec(record)
+                        .map_err(|e| ZenohError::SerializationError(e.to_string()))?;
+                    Ok((payload, PayloadFormat::Json))
+                }
+
+                #[cfg(not(feature = "serde"))]
+                Err(ZenohError::SerializationError(
+                    "No serialization method available (enable 'serde' feature or provide hex_data)"
+                        .to_string(),
+                ))
+            }
+            PayloadFormat::Json => {
+                #[cfg(feature = "serde")]
+                {
+                    let payload = serde_json::to_vec(record)
+                        .map_err(|e| ZenohError::SerializationError(e.to_string()))?;
+                    Ok((payload, PayloadFormat::Json))
+                }
+
+                #[cfg(not(feature = "serde"))]
+                Err(ZenohError::SerializationError(
+                    "PayloadFormat::Json requires the 'serde' feature".to_string(),
+                ))
+            }
+            PayloadFormat::Cbor => Ok((cbor::encode_record(record), PayloadFormat::Cbor)),
+            PayloadFormat::MessagePack => {
+                #[cfg(feature = "serde")]
+                {
+                    let payload = rmp_serde::to_vec(record)
+                        .map_err(|e| ZenohError::SerializationError(e.to_string()))?;
+                    Ok((payload, PayloadFormat::MessagePack))
+                }
+
+                #[cfg(not(feature = "serde"))]
+                Err(ZenohError::SerializationError(
+                    "PayloadFormat::MessagePack requires the 'serde' feature".to_string(),
+                ))
+            }
         }
+    }
 
-        // Fallback: serialize as JSON if serde available
-        #[cfg(feature = "serde")]
-        {
-            serde_json::to_vec(record).map_err(|e| ZenohError::SerializationError(e.to_string()))
+    fn hex_to_bytes(&self, hex: &str) -> Result<Vec<u8>, ZenohError> {
+        from_hex(hex).map_err(|e| ZenohError::SerializationError(e.to_string()))
+    }
+}
+
+/// Frame `records` as a length-delimited sequence — a 4-byte big-endian
+/// length prefix per record — into one payload, the wire format
+/// [`BatchingPublisher`] flushes a batch as and
+/// [`ZenohSubscriber::recv_batch`] splits back apart.
+fn encode_batch(records: &[Vec<u8>]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(records.iter().map(|r| 4 + r.len()).sum());
+    for record in records {
+        out.extend_from_slice(&(record.len() as u32).to_be_bytes());
+        out.extend_from_slice(record);
+    }
+    out
+}
+
+/// Inverse of [`encode_batch`].
+///
+/// # Errors
+///
+/// Returns [`ZenohError::ReceiveError`] if `data` ends mid-length-prefix or
+/// mid-record — it is not a batch [`BatchingPublisher`] produced.
+fn decode_batch(data: &[u8]) -> Result<Vec<Vec<u8>>, ZenohError> {
+    let mut records = Vec::new();
+    let mut offset = 0;
+
+    while offset < data.len() {
+        if offset + 4 > data.len() {
+            return Err(ZenohError::ReceiveError(format!(
+                "truncated batch: {} trailing byte(s) too short for a length prefix at offset {offset}",
+                data.len() - offset
+            )));
+        }
+        let len = u32::from_be_bytes([
+            data[offset],
+            data[offset + 1],
+            data[offset + 2],
+            data[offset + 3],
+        ]) as usize;
+        offset += 4;
+
+        if offset + len > data.len() {
+            return Err(ZenohError::ReceiveError(format!(
+                "truncated batch: record at offset {offset} declares length {len} but only {} byte(s) remain",
+                data.len() - offset
+            )));
         }
 
-        #[cfg(not(feature = "serde"))]
-        Err(ZenohError::SerializationError(
-            "No serialization method available (enable 'serde' feature or provide hex_data)"
-                .to_string(),
-        ))
+        records.push(data[offset..offset + len].to_vec());
+        offset += len;
     }
 
-    fn hex_to_bytes(&self, hex: &str) -> Result<Vec<u8>, ZenohError> {
-        // Remove any whitespace and decode hex string to bytes
-        let hex_clean: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
+    Ok(records)
+}
 
-        if hex_clean.len() % 2 != 0 {
-            return Err(ZenohError::SerializationError(
-                "Invalid hex string length".to_string(),
-            ));
+/// One key expression's in-progress batch: records buffered so far and when
+/// the first of them arrived, for [`ZenohConfig::linger`] to time out against.
+struct PendingBatch {
+    category: u8,
+    records: Vec<Vec<u8>>,
+    first_buffered: std::time::Instant,
+}
+
+impl PendingBatch {
+    fn new(category: u8) -> Self {
+        Self {
+            category,
+            records: Vec::new(),
+            first_buffered: std::time::Instant::now(),
+        }
+    }
+}
+
+/// Wraps a [`ZenohPublisher`], buffering records per key expression and
+/// flushing each key's buffer as one length-delimited batch payload (see
+/// [`encode_batch`]) instead of one Zenoh `put` per record.
+///
+/// A buffer flushes once it reaches [`ZenohConfig::batch_size`] records, or
+/// once [`ZenohConfig::linger`] elapses since its first record was buffered,
+/// whichever comes first — a background task polls for linger expiry so a
+/// lull in traffic still flushes a partial batch promptly rather than
+/// leaving it stranded until the next publish. `batch_size` defaults to 1,
+/// so wrapping a publisher with the default config flushes every record
+/// immediately, identical to calling [`ZenohPublisher::publish_raw`]
+/// directly. The companion [`ZenohSubscriber::recv_batch`] splits a received
+/// batch back into its constituent samples.
+pub struct BatchingPublisher {
+    inner: Arc<ZenohPublisher>,
+    batch_size: usize,
+    linger: std::time::Duration,
+    buffers: Arc<tokio::sync::Mutex<HashMap<String, PendingBatch>>>,
+    _linger_handle: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl BatchingPublisher {
+    /// Wrap `inner`, batching its publishes per `inner`'s own
+    /// [`ZenohConfig::batch_size`]/[`ZenohConfig::linger`].
+    pub fn new(inner: ZenohPublisher) -> Self {
+        let batch_size = inner.config.batch_size.max(1);
+        let linger = inner.config.linger;
+        let inner = Arc::new(inner);
+        let buffers: Arc<tokio::sync::Mutex<HashMap<String, PendingBatch>>> =
+            Arc::new(tokio::sync::Mutex::new(HashMap::new()));
+
+        let linger_handle = if linger > std::time::Duration::ZERO {
+            let sweep_inner = inner.clone();
+            let sweep_buffers = buffers.clone();
+            Some(tokio::spawn(async move {
+                let mut ticker = tokio::time::interval(linger);
+                loop {
+                    ticker.tick().await;
+
+                    let expired: Vec<String> = {
+                        let buffers = sweep_buffers.lock().await;
+                        buffers
+                            .iter()
+                            .filter(|(_, pending)| {
+                                !pending.records.is_empty()
+                                    && pending.first_buffered.elapsed() >= linger
+                            })
+                            .map(|(key, _)| key.clone())
+                            .collect()
+                    };
+
+                    for key_expr in expired {
+                        let _ = Self::flush_key(&sweep_inner, &sweep_buffers, &key_expr).await;
+                    }
+                }
+            }))
+        } else {
+            None
+        };
+
+        Self {
+            inner,
+            batch_size,
+            linger,
+            buffers,
+            _linger_handle: linger_handle,
+        }
+    }
+
+    /// Buffer raw ASTERIX bytes for `category`, flushing immediately if this
+    /// fills the batch (see [`Self`]'s docs).
+    pub async fn publish_raw(&self, category: u8, data: &[u8]) -> Result<(), ZenohError> {
+        let key_expr = format!("{}/{}", self.inner.config.key_prefix, category);
+        self.buffer_and_maybe_flush(key_expr, category, data.to_vec())
+            .await
+    }
+
+    /// Buffer raw ASTERIX bytes with full routing info, flushing immediately
+    /// if this fills the batch (see [`Self`]'s docs).
+    pub async fn publish_raw_with_routing(
+        &self,
+        category: u8,
+        sac: u8,
+        sic: u8,
+        data: &[u8],
+    ) -> Result<(), ZenohError> {
+        let key_expr = format!(
+            "{}/{}/{}/{}",
+            self.inner.config.key_prefix, category, sac, sic
+        );
+        self.buffer_and_maybe_flush(key_expr, category, data.to_vec())
+            .await
+    }
+
+    async fn buffer_and_maybe_flush(
+        &self,
+        key_expr: String,
+        category: u8,
+        data: Vec<u8>,
+    ) -> Result<(), ZenohError> {
+        let should_flush = {
+            let mut buffers = self.buffers.lock().await;
+            let pending = buffers
+                .entry(key_expr.clone())
+                .or_insert_with(|| PendingBatch::new(category));
+            pending.records.push(data);
+            pending.records.len() >= self.batch_size
+        };
+
+        if should_flush {
+            Self::flush_key(&self.inner, &self.buffers, &key_expr).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Flush every key expression with a non-empty buffer, regardless of
+    /// `batch_size`/`linger` — for graceful shutdown, so no buffered record
+    /// is silently lost when the caller is done publishing.
+    pub async fn flush(&self) -> Result<(), ZenohError> {
+        let keys: Vec<String> = self.buffers.lock().await.keys().cloned().collect();
+        for key_expr in keys {
+            Self::flush_key(&self.inner, &self.buffers, &key_expr).await?;
+        }
+        Ok(())
+    }
+
+    async fn flush_key(
+        inner: &ZenohPublisher,
+        buffers: &tokio::sync::Mutex<HashMap<String, PendingBatch>>,
+        key_expr: &str,
+    ) -> Result<(), ZenohError> {
+        let pending = buffers.lock().await.remove(key_expr);
+        let Some(pending) = pending else {
+            return Ok(());
+        };
+        if pending.records.is_empty() {
+            return Ok(());
         }
 
-        (0..hex_clean.len())
-            .step_by(2)
-            .map(|i| {
-                u8::from_str_radix(&hex_clean[i..i + 2], 16)
-                    .map_err(|e| ZenohError::SerializationError(e.to_string()))
-            })
-            .collect()
+        let payload = encode_batch(&pending.records);
+        inner
+            .publish_chunked(key_expr, &payload, pending.category)
+            .await
+    }
+
+    /// The wrapped [`ZenohPublisher`], for calls this wrapper doesn't cover
+    /// (e.g. [`ZenohPublisher::publish`])
+    pub fn inner(&self) -> &ZenohPublisher {
+        &self.inner
     }
 }
 
-/// Zenoh subscriber for ASTERIX data
-pub struct ZenohSubscriber {
+/// A source observed via [`ZenohSourceDiscovery`]: its liveliness token was
+/// either just declared (joined, now publishing) or just undeclared (left —
+/// closed cleanly or its session's liveliness lease lapsed).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SourceEvent {
+    /// A source started publishing.
+    Joined(SourceInfo),
+    /// A previously-live source stopped publishing.
+    Left {
+        /// The `source_id` the departing source was declared with.
+        source_id: String,
+    },
+}
+
+/// Identity and declared capabilities of a source found via
+/// [`ZenohSourceDiscovery`], decoded from its liveliness token's key
+/// expression (Zenoh liveliness tokens carry no payload of their own).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceInfo {
+    /// The identifier [`ZenohPublisher::with_source_discovery`] was given.
+    pub source_id: String,
+    /// The `key_prefix` the source publishes data under.
+    pub key_prefix: String,
+    /// ASTERIX categories the source declared it publishes.
+    pub categories: Vec<u8>,
+}
+
+/// Decode a [`SourceInfo`] out of a
+/// `{key_prefix}/@/sources/{source_id}/{categories}` liveliness key
+/// expression, as declared by [`ZenohPublisher::with_source_discovery`].
+fn parse_source_info(key: &str) -> Option<SourceInfo> {
+    let marker = format!("/{SOURCE_DISCOVERY_SEGMENT}/");
+    let marker_pos = key.find(&marker)?;
+    let key_prefix = key[..marker_pos].to_string();
+    let rest = &key[marker_pos + marker.len()..];
+
+    let mut parts = rest.splitn(2, '/');
+    let source_id = parts.next()?.to_string();
+    let categories = parts
+        .next()
+        .unwrap_or_default()
+        .split('-')
+        .filter_map(|c| c.parse::<u8>().ok())
+        .collect();
+
+    Some(SourceInfo {
+        source_id,
+        key_prefix,
+        categories,
+    })
+}
+
+/// Discovers ASTERIX sources announced via
+/// [`ZenohPublisher::with_source_discovery`] by watching Zenoh liveliness
+/// tokens, yielding [`SourceEvent::Joined`]/[`SourceEvent::Left`] as sources
+/// come online and go dark — without a separate heartbeat channel.
+pub struct ZenohSourceDiscovery {
     session: Arc<Session>,
-    receiver: mpsc::Receiver<AsterixSample>,
+    receiver: mpsc::Receiver<SourceEvent>,
     _handle: tokio::task::JoinHandle<()>,
 }
 
-impl ZenohSubscriber {
-    /// Create a new Zenoh subscriber
-    ///
-    /// # Arguments
-    ///
-    /// * `config` - Zenoh configuration
-    /// * `key_expr` - Key expression to subscribe to (e.g., "asterix/**" for all data)
-    ///
-    /// # Key Expression Examples
-    ///
-    /// - `asterix/**` - All ASTERIX data
-    /// - `asterix/48/**` - All CAT048 data
-    /// - `asterix/62/1/2` - CAT062 from SAC=1, SIC=2
-    /// - `asterix/*/1/*` - All categories from SAC=1
-    pub async fn new(config: ZenohConfig, key_expr: &str) -> Result<Self, ZenohError> {
+impl ZenohSourceDiscovery {
+    /// Start watching for sources across every `key_prefix`
+    /// (`*/@/sources/**`). Seeds the live set with sources already
+    /// publishing at the time of the call, then streams join/leave events as
+    /// they happen.
+    pub async fn new(config: ZenohConfig) -> Result<Self, ZenohError> {
         let mut zenoh_config = Config::default();
 
         if !config.endpoints.is_empty() {
@@ -443,36 +2165,46 @@ impl ZenohSubscriber {
                 .map_err(|e| ZenohError::SessionError(e.to_string()))?,
         );
 
+        let discovery_pattern = format!("*/{SOURCE_DISCOVERY_SEGMENT}/**");
+
         let (tx, rx) = mpsc::channel(1000);
 
-        let subscriber = session
-            .declare_subscriber(key_expr)
+        // Seed the live set with tokens already declared before we started watching.
+        let replies = session
+            .liveliness()
+            .get(&discovery_pattern)
+            .await
+            .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
+        while let Ok(reply) = replies.recv_async().await {
+            if let Ok(sample) = reply.result() {
+                if let Some(info) = parse_source_info(&sample.key_expr().to_string()) {
+                    let _ = tx.try_send(SourceEvent::Joined(info));
+                }
+            }
+        }
+
+        let liveliness_subscriber = session
+            .liveliness()
+            .declare_subscriber(&discovery_pattern)
             .await
             .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
 
-        let key_prefix = config.key_prefix.clone();
         let handle = tokio::spawn(async move {
-            while let Ok(sample) = subscriber.recv_async().await {
+            while let Ok(sample) = liveliness_subscriber.recv_async().await {
                 let key = sample.key_expr().to_string();
-                let data: Vec<u8> = sample.payload().to_bytes().to_vec();
-
-                // Parse key expression to extract category/sac/sic
-                let (category, sac, sic) = parse_key_expr(&key, &key_prefix);
-
-                let asterix_sample = AsterixSample {
-                    category,
-                    sac,
-                    sic,
-                    data,
-                    timestamp: std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .map(|d| d.as_micros() as u64)
-                        .unwrap_or(0),
-                    key_expr: key,
+                let event = match sample.kind() {
+                    zenoh::sample::SampleKind::Put => parse_source_info(&key).map(SourceEvent::Joined),
+                    zenoh::sample::SampleKind::Delete => {
+                        parse_source_info(&key).map(|info| SourceEvent::Left {
+                            source_id: info.source_id,
+                        })
+                    }
                 };
 
-                if tx.send(asterix_sample).await.is_err() {
-                    break;
+                if let Some(event) = event {
+                    if tx.send(event).await.is_err() {
+                        break;
+                    }
                 }
             }
         });
@@ -484,17 +2216,17 @@ impl ZenohSubscriber {
         })
     }
 
-    /// Receive the next ASTERIX sample
-    pub async fn recv(&mut self) -> Option<AsterixSample> {
+    /// Receive the next join/leave event
+    pub async fn recv(&mut self) -> Option<SourceEvent> {
         self.receiver.recv().await
     }
 
-    /// Try to receive a sample without blocking
-    pub fn try_recv(&mut self) -> Option<AsterixSample> {
+    /// Try to receive an event without blocking
+    pub fn try_recv(&mut self) -> Option<SourceEvent> {
         self.receiver.try_recv().ok()
     }
 
-    /// Close the subscriber and release resources
+    /// Close the discovery session and release resources
     pub async fn close(self) -> Result<(), ZenohError> {
         self.session
             .close()
@@ -503,433 +2235,3966 @@ impl ZenohSubscriber {
     }
 }
 
-/// Parse a key expression to extract category, SAC, and SIC
-fn parse_key_expr(key: &str, prefix: &str) -> (u8, Option<u8>, Option<u8>) {
-    let stripped = key.strip_prefix(prefix).unwrap_or(key);
-    let parts: Vec<&str> = stripped.trim_start_matches('/').split('/').collect();
-
-    let category = parts
-        .first()
-        .and_then(|s| s.parse::<u8>().ok())
-        .unwrap_or(0);
+/// Zenoh subscriber for ASTERIX data
+/// Declarative filter for [`ZenohSubscriber::subscribe_filtered`]: narrows a
+/// live subscription to a set of categories and/or a specific SAC/SIC, plus
+/// an arbitrary predicate over the received [`AsterixSample`].
+///
+/// [`Self::key_expr`] compiles the category/SAC/SIC constraints into a Zenoh
+/// key expression for in-engine routing wherever that's precise (a single
+/// category, optionally pinned to one SAC/SIC); anything looser than that —
+/// more than one category, or the predicate — falls back to matching
+/// in-process via [`Self::matches`] once a sample's arrived, rather than
+/// over-constraining the subscription and silently missing data.
+#[derive(Clone, Default)]
+pub struct SubscriptionFilter {
+    categories: Option<std::collections::BTreeSet<u8>>,
+    sac: Option<u8>,
+    sic: Option<u8>,
+    predicate: Option<Arc<dyn Fn(&AsterixSample) -> bool + Send + Sync>>,
+}
 
-    let sac = parts.get(1).and_then(|s| s.parse::<u8>().ok());
+impl fmt::Debug for SubscriptionFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubscriptionFilter")
+            .field("categories", &self.categories)
+            .field("sac", &self.sac)
+            .field("sic", &self.sic)
+            .field("predicate", &self.predicate.as_ref().map(|_| "<fn>"))
+            .finish()
+    }
+}
 
-    let sic = parts.get(2).and_then(|s| s.parse::<u8>().ok());
+impl SubscriptionFilter {
+    /// A filter that matches every sample (no constraints)
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    (category, sac, sic)
-}
+    /// Only match samples whose category is one of `categories`
+    pub fn with_categories(mut self, categories: impl IntoIterator<Item = u8>) -> Self {
+        self.categories = Some(categories.into_iter().collect());
+        self
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Only match samples with this SAC
+    pub fn with_sac(mut self, sac: u8) -> Self {
+        self.sac = Some(sac);
+        self
+    }
 
-    // ============================================================================
-    // Key Expression Parsing Tests
-    // ============================================================================
+    /// Only match samples with this SIC
+    pub fn with_sic(mut self, sic: u8) -> Self {
+        self.sic = Some(sic);
+        self
+    }
 
-    #[test]
-    fn test_parse_key_expr_category_only() {
-        assert_eq!(parse_key_expr("asterix/48", "asterix"), (48, None, None));
-        assert_eq!(parse_key_expr("asterix/62", "asterix"), (62, None, None));
-        assert_eq!(parse_key_expr("asterix/255", "asterix"), (255, None, None));
+    /// Only match samples for which `predicate` returns true, evaluated
+    /// in-process after the category/SAC/SIC narrowing above — it can
+    /// inspect anything on [`AsterixSample`], including fields pulled out
+    /// via [`AsterixSample::decode_record`]
+    pub fn with_predicate(
+        mut self,
+        predicate: impl Fn(&AsterixSample) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.predicate = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Compile this filter into the Zenoh key expression
+    /// [`ZenohSubscriber::subscribe_filtered`] subscribes under, rooted at
+    /// `prefix` (see [`ZenohConfig::key_prefix`]).
+    ///
+    /// Only a single category can be expressed precisely, as
+    /// `<prefix>/<cat>/<sac-or-*>/<sic-or-*>`; an empty or multi-category
+    /// filter falls back to `<prefix>/**`, leaving category narrowing to
+    /// [`Self::matches`].
+    pub fn key_expr(&self, prefix: &str) -> String {
+        let single_category = match &self.categories {
+            Some(categories) if categories.len() == 1 => categories.iter().next().copied(),
+            _ => None,
+        };
+
+        match single_category {
+            Some(category) => {
+                let sac = self
+                    .sac
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| "*".to_string());
+                let sic = self
+                    .sic
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|| "*".to_string());
+                format!("{prefix}/{category}/{sac}/{sic}")
+            }
+            None => format!("{prefix}/**"),
+        }
+    }
+
+    /// Does `sample` satisfy every constraint this filter specifies?
+    pub fn matches(&self, sample: &AsterixSample) -> bool {
+        if let Some(categories) = &self.categories {
+            if !categories.contains(&sample.category) {
+                return false;
+            }
+        }
+        if let Some(sac) = self.sac {
+            if sample.sac != Some(sac) {
+                return false;
+            }
+        }
+        if let Some(sic) = self.sic {
+            if sample.sic != Some(sic) {
+                return false;
+            }
+        }
+        if let Some(predicate) = &self.predicate {
+            if !predicate(sample) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+pub struct ZenohSubscriber {
+    session: Arc<Session>,
+    receiver: Arc<BoundedSampleQueue>,
+    key_prefix: String,
+    encryption: Option<EncryptionConfig>,
+    filter: SubscriptionFilter,
+    metrics: TransportMetrics,
+    _handle: tokio::task::JoinHandle<()>,
+}
+
+impl ZenohSubscriber {
+    /// Create a new Zenoh subscriber
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Zenoh configuration
+    /// * `key_expr` - Key expression to subscribe to (e.g., "asterix/**" for all data)
+    ///
+    /// # Key Expression Examples
+    ///
+    /// - `asterix/**` - All ASTERIX data
+    /// - `asterix/48/**` - All CAT048 data
+    /// - `asterix/62/1/2` - CAT062 from SAC=1, SIC=2
+    /// - `asterix/*/1/*` - All categories from SAC=1
+    pub async fn new(config: ZenohConfig, key_expr: &str) -> Result<Self, ZenohError> {
+        let mut zenoh_config = Config::default();
+
+        if !config.endpoints.is_empty() {
+            let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
+            zenoh_config
+                .insert_json5("connect/endpoints", &endpoints_json)
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
+        let session = Arc::new(
+            zenoh::open(zenoh_config)
+                .await
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?,
+        );
+
+        let queue = Arc::new(BoundedSampleQueue::new(config.channel_capacity));
+        let overflow_policy = config.overflow_policy;
+        let metrics = TransportMetrics::new();
+
+        let subscriber = session
+            .declare_subscriber(key_expr)
+            .await
+            .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
+
+        let key_prefix = config.key_prefix.clone();
+        let encryption = config.encryption.clone();
+        let producer_queue = Arc::clone(&queue);
+        let producer_metrics = metrics.clone();
+        let handle = tokio::spawn(async move {
+            let mut fragments: HashMap<String, FragmentAssembly> = HashMap::new();
+
+            while let Ok(sample) = subscriber.recv_async().await {
+                let key = sample.key_expr().to_string();
+                let data: Vec<u8> = sample.payload().to_bytes().to_vec();
+
+                let mut timed_out = Vec::new();
+                fragments.retain(|base_key, assembly| {
+                    let alive = assembly.first_seen.elapsed() < FRAGMENT_TIMEOUT;
+                    if !alive {
+                        timed_out.push((base_key.clone(), assembly.received, assembly.parts.len()));
+                    }
+                    alive
+                });
+                for (base_key, received, total) in timed_out {
+                    log::warn!(
+                        "{}",
+                        ZenohError::ReassemblyError(format!(
+                            "dropping incomplete fragment group for {base_key}: \
+                             received {received}/{total} fragments before timeout"
+                        ))
+                    );
+                }
+
+                let (base_key, frag_info) = split_frag_suffix(&key);
+                let (assembled_key, assembled_data) = match frag_info {
+                    None => (key, data),
+                    Some((seq, total)) => {
+                        let assembly = fragments
+                            .entry(base_key.clone())
+                            .or_insert_with(|| FragmentAssembly::new(total));
+
+                        match assembly.insert(seq, data) {
+                            Some(reassembled) => {
+                                fragments.remove(&base_key);
+                                (base_key, reassembled)
+                            }
+                            None => continue,
+                        }
+                    }
+                };
+
+                // Parse key expression to extract category/sac/sic
+                let (category, sac, sic) = parse_key_expr(&assembled_key, &key_prefix);
+
+                let assembled_data = match &encryption {
+                    Some(enc) => {
+                        match decrypt_payload(enc, category, &assembled_key, &assembled_data) {
+                            Ok(plaintext) => plaintext,
+                            Err(err) => {
+                                log::warn!(
+                                    "Dropping undecryptable Zenoh sample on {assembled_key}: {err}"
+                                );
+                                continue;
+                            }
+                        }
+                    }
+                    None => assembled_data,
+                };
+
+                let asterix_sample = AsterixSample {
+                    category,
+                    sac,
+                    sic,
+                    data: assembled_data,
+                    timestamp: std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_micros() as u64)
+                        .unwrap_or(0),
+                    key_expr: assembled_key,
+                    encoding: payload_format_from_encoding(sample.encoding()),
+                };
+
+                match producer_queue.push(asterix_sample, overflow_policy) {
+                    PushOutcome::Enqueued => {
+                        producer_metrics.record_received();
+                    }
+                    PushOutcome::Dropped => {
+                        producer_metrics.record_received();
+                        producer_metrics.record_dropped();
+                    }
+                    PushOutcome::Full(sample) => {
+                        // Only reachable under `OverflowPolicy::Block`: wait for
+                        // the consumer to drain room rather than dropping.
+                        let mut pending = Some(sample);
+                        std::future::poll_fn(|cx| producer_queue.poll_send(&mut pending, cx)).await;
+                        producer_metrics.record_received();
+                    }
+                }
+            }
+
+            producer_queue.close();
+        });
+
+        Ok(Self {
+            session,
+            receiver: queue,
+            key_prefix: config.key_prefix,
+            encryption: config.encryption,
+            filter: SubscriptionFilter::new(),
+            metrics,
+            _handle: handle,
+        })
+    }
+
+    /// Create a subscriber whose live stream only yields samples matching
+    /// `filter` (see [`SubscriptionFilter`]).
+    ///
+    /// Subscribes under [`SubscriptionFilter::key_expr`] for in-engine
+    /// category/SAC/SIC routing, then applies the rest of `filter`
+    /// (including its predicate) to every sample out of [`Self::recv`]/
+    /// [`Self::try_recv`] — see [`SubscriptionFilter`]'s own docs for which
+    /// constraints are compiled into the key expression versus checked
+    /// in-process.
+    pub async fn subscribe_filtered(
+        config: ZenohConfig,
+        filter: SubscriptionFilter,
+    ) -> Result<Self, ZenohError> {
+        let key_expr = filter.key_expr(&config.key_prefix);
+        let mut subscriber = Self::new(config, &key_expr).await?;
+        subscriber.filter = filter;
+        Ok(subscriber)
+    }
+
+    /// Receive the next ASTERIX sample matching this subscriber's
+    /// [`SubscriptionFilter`] (every sample, for one created via [`Self::new`])
+    pub async fn recv(&mut self) -> Option<AsterixSample> {
+        loop {
+            let sample = std::future::poll_fn(|cx| self.receiver.poll_recv(cx)).await?;
+            if self.filter.matches(&sample) {
+                return Some(sample);
+            }
+        }
+    }
+
+    /// Runtime counters for this subscriber (messages received, decode
+    /// failures, samples dropped by a full channel — see [`TransportMetrics`])
+    pub fn metrics(&self) -> TransportMetrics {
+        self.metrics.clone()
+    }
+
+    /// Issue a Zenoh get() against `selector`, optionally restricted to
+    /// `time_range` (an inclusive `(start, stop)` range in microseconds since
+    /// the Unix epoch, matching [`AsterixSample::timestamp`]), and collect
+    /// every matching sample a [`ZenohQueryable`] replies with.
+    ///
+    /// Lets a subscriber that just started backfill recent history (e.g. a
+    /// display that just connected recovering the last few seconds of
+    /// CAT048 plots) instead of waiting for the next live update, without
+    /// standing up a separate [`ZenohQueryClient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::ReceiveError`] if the get() itself fails, or
+    /// [`ZenohError::DecryptionError`] if `ZenohConfig::encryption` is set
+    /// and a reply fails to decrypt.
+    pub async fn query(
+        &self,
+        selector: &str,
+        time_range: Option<(u64, u64)>,
+    ) -> Result<Vec<AsterixSample>, ZenohError> {
+        let selector = selector_with_time_range(selector, time_range);
+
+        let replies = self
+            .session
+            .get(&selector)
+            .await
+            .map_err(|e| ZenohError::ReceiveError(e.to_string()))?;
+
+        let mut samples = Vec::new();
+        while let Ok(reply) = replies.recv_async().await {
+            if let Ok(sample) = reply.result() {
+                let key = sample.key_expr().to_string();
+                let data: Vec<u8> = sample.payload().to_bytes().to_vec();
+                let (category, sac, sic) = parse_key_expr(&key, &self.key_prefix);
+
+                let data = match &self.encryption {
+                    Some(enc) => decrypt_payload(enc, category, &key, &data)?,
+                    None => data,
+                };
+
+                samples.push(AsterixSample {
+                    category,
+                    sac,
+                    sic,
+                    data,
+                    timestamp: std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_micros() as u64)
+                        .unwrap_or(0),
+                    key_expr: key,
+                    encoding: payload_format_from_encoding(sample.encoding()),
+                });
+            }
+        }
+
+        Ok(samples)
+    }
+
+    /// Receive the next sample and split it back into the individual records
+    /// a [`BatchingPublisher`] combined into it.
+    ///
+    /// Every split-out [`AsterixSample`] inherits the batch's own
+    /// category/SAC/SIC/key expression/timestamp, since a batch only ever
+    /// combines same-key records (see [`BatchingPublisher`]) and so those
+    /// fields don't vary within one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::ReceiveError`] if the received payload isn't a
+    /// validly-framed batch — e.g. it was published via
+    /// [`ZenohPublisher::publish_raw`] directly rather than through a
+    /// [`BatchingPublisher`]; use [`Self::recv`] for that.
+    pub async fn recv_batch(&mut self) -> Option<Result<Vec<AsterixSample>, ZenohError>> {
+        let sample = std::future::poll_fn(|cx| self.receiver.poll_recv(cx)).await?;
+
+        Some(decode_batch(&sample.data).map(|records| {
+            records
+                .into_iter()
+                .map(|data| AsterixSample {
+                    data,
+                    ..sample.clone()
+                })
+                .collect()
+        }))
+    }
+
+    /// Try to receive a sample matching this subscriber's
+    /// [`SubscriptionFilter`] without blocking
+    pub fn try_recv(&mut self) -> Option<AsterixSample> {
+        loop {
+            let sample = self.receiver.try_recv()?;
+            if self.filter.matches(&sample) {
+                return Some(sample);
+            }
+        }
+    }
+
+    /// Close the subscriber and release resources
+    pub async fn close(self) -> Result<(), ZenohError> {
+        self.session
+            .close()
+            .await
+            .map_err(|e| ZenohError::SessionError(e.to_string()))
+    }
+}
+
+/// Lets a [`ZenohSubscriber`] be driven with `.await`-free combinators
+/// (`.take(n)`, `.filter()`, `tokio_stream`'s `.timeout()`, `select_all`
+/// across multiple subscribers for cross-category fan-in, etc.) instead of
+/// a hand-rolled `loop { subscriber.recv().await }`.
+///
+/// Just forwards to the underlying [`BoundedSampleQueue::poll_recv`] — the
+/// same "pending while nothing buffered, `None` once the publisher side has
+/// gone away and the channel drains" behavior [`ZenohSubscriber::recv`]
+/// already has, exposed as a poll fn instead of a future.
+impl Stream for ZenohSubscriber {
+    type Item = AsterixSample;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match this.receiver.poll_recv(cx) {
+                Poll::Ready(Some(sample)) => {
+                    if this.filter.matches(&sample) {
+                        return Poll::Ready(Some(sample));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
+
+/// A [`ZenohSubscriber`] that decodes each received sample's bytes into
+/// [`AsterixRecord`]s before handing them to the caller, instead of leaving
+/// every consumer to separately call [`crate::parse`].
+///
+/// Decoding runs through a caller-supplied [`SerializedDecoder`] rather than
+/// [`crate::parse`] directly: the underlying C++ singleton isn't
+/// thread-safe (see `lib.rs`'s "Thread Safety" section), and a multi-thread
+/// Tokio runtime may poll several `DecodingSubscriber`s concurrently. Share
+/// one [`SerializedDecoder`] across every `DecodingSubscriber`/publisher in
+/// a process to keep all decode calls serialized onto its single worker
+/// thread.
+pub struct DecodingSubscriber {
+    subscriber: ZenohSubscriber,
+    decoder: SerializedDecoder,
+    options: ParseOptions,
+}
+
+impl DecodingSubscriber {
+    /// Create a decoding subscriber.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` / `key_expr` - same as [`ZenohSubscriber::new`]
+    /// * `decoder` - shared handle to the worker thread that owns the C++
+    ///   singleton; clone the same [`SerializedDecoder`] into every
+    ///   `DecodingSubscriber`/publisher that needs to decode or parse
+    /// * `options` - applied to every decode, e.g. set `filter_category` to
+    ///   surface only one category's records
+    pub async fn new(
+        config: ZenohConfig,
+        key_expr: &str,
+        decoder: SerializedDecoder,
+        options: ParseOptions,
+    ) -> Result<Self, ZenohError> {
+        let subscriber = ZenohSubscriber::new(config, key_expr).await?;
+        Ok(Self {
+            subscriber,
+            decoder,
+            options,
+        })
+    }
+
+    /// Receive the next sample and decode it, skipping samples that decode
+    /// to zero records (e.g. every record filtered out by `options`) until
+    /// one yields at least one [`AsterixRecord`], or the underlying
+    /// subscriber closes.
+    ///
+    /// Returns every record the sample's bytes decoded to — usually one, but
+    /// mirrors [`crate::parse`]'s own `Vec` return, since a single published
+    /// blob isn't guaranteed to contain exactly one block.
+    pub async fn recv(&mut self) -> Option<Vec<AsterixRecord>> {
+        loop {
+            let sample = self.subscriber.recv().await?;
+            match self.decoder.parse(sample.data, self.options.clone()) {
+                Ok(records) if !records.is_empty() => return Some(records),
+                Ok(_) => continue,
+                Err(err) => {
+                    self.subscriber.metrics.record_decode_failure();
+                    log::warn!("Failed to decode Zenoh sample: {err}");
+                    continue;
+                }
+            }
+        }
+    }
+
+    /// Receive the next sample and decode it, surfacing a decode failure to
+    /// the caller instead of [`Self::recv`]'s log-and-skip behavior.
+    ///
+    /// Returns `None` once the underlying subscriber closes (a clean
+    /// disconnect). Returns `Some(Ok(records))` for a sample that decoded to
+    /// at least one record (samples that decode to zero records, e.g.
+    /// everything filtered out by `options`, are skipped just like
+    /// [`Self::recv`]). Returns `Some(Err((sample, err)))` if `sample`'s
+    /// bytes failed to decode, with `err` always a
+    /// [`ZenohError::ReceiveError`] — the caller gets the raw [`AsterixSample`]
+    /// back alongside it so a decode failure doesn't lose the bytes that
+    /// caused it.
+    pub async fn recv_result(&mut self) -> Option<Result<Vec<AsterixRecord>, (AsterixSample, ZenohError)>> {
+        loop {
+            let sample = self.subscriber.recv().await?;
+            match self.decoder.parse(sample.data.clone(), self.options.clone()) {
+                Ok(records) if !records.is_empty() => return Some(Ok(records)),
+                Ok(_) => continue,
+                Err(err) => {
+                    self.subscriber.metrics.record_decode_failure();
+                    return Some(Err((sample, ZenohError::ReceiveError(err.to_string()))));
+                }
+            }
+        }
+    }
+
+    /// Close the underlying subscriber and release resources
+    pub async fn close(self) -> Result<(), ZenohError> {
+        self.subscriber.close().await
+    }
+}
+
+/// Zenoh queryable that answers get-queries with recent ASTERIX history
+///
+/// Subscribes to `key_expr` the same way [`ZenohSubscriber`] does, but
+/// instead of (or in addition to) handing samples to a live receiver, it
+/// keeps the most recent [`ZenohConfig::history_depth`] samples per key in a
+/// bounded ring buffer and declares a Zenoh queryable over the same
+/// `key_expr` to answer get-queries from it. This lets a client that
+/// connects after data was published still retrieve what it missed, via
+/// [`query_history`].
+pub struct ZenohQueryable {
+    session: Arc<Session>,
+    history: Arc<Mutex<HashMap<String, VecDeque<(AsterixSample, std::time::Instant)>>>>,
+    _subscriber_handle: tokio::task::JoinHandle<()>,
+    _queryable_handle: tokio::task::JoinHandle<()>,
+}
+
+impl ZenohQueryable {
+    /// Create a new queryable, subscribing to `key_expr` to build history and
+    /// declaring a queryable over the same `key_expr` to serve it.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Zenoh configuration; `history_depth` caps samples
+    ///   retained per key, `key_prefix` must match what publishers use
+    /// * `key_expr` - Key expression to subscribe to and answer queries for
+    ///   (e.g. `"asterix/**"` for every category)
+    pub async fn new(config: ZenohConfig, key_expr: &str) -> Result<Self, ZenohError> {
+        let mut zenoh_config = Config::default();
+
+        if !config.endpoints.is_empty() {
+            let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
+            zenoh_config
+                .insert_json5("connect/endpoints", &endpoints_json)
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
+        let session = Arc::new(
+            zenoh::open(zenoh_config)
+                .await
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?,
+        );
+
+        let history: Arc<Mutex<HashMap<String, VecDeque<(AsterixSample, std::time::Instant)>>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+        let history_depth = config.history_depth;
+        let history_max_age = std::time::Duration::from_secs(config.history_max_age_secs);
+        let key_prefix = config.key_prefix.clone();
+
+        let subscriber = session
+            .declare_subscriber(key_expr)
+            .await
+            .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
+
+        let subscriber_history = history.clone();
+        let subscriber_prefix = key_prefix.clone();
+        let subscriber_handle = tokio::spawn(async move {
+            while let Ok(sample) = subscriber.recv_async().await {
+                if history_depth == 0 {
+                    continue;
+                }
+
+                let key = sample.key_expr().to_string();
+                let data: Vec<u8> = sample.payload().to_bytes().to_vec();
+                let (category, sac, sic) = parse_key_expr(&key, &subscriber_prefix);
+
+                let asterix_sample = AsterixSample {
+                    category,
+                    sac,
+                    sic,
+                    data,
+                    timestamp: std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_micros() as u64)
+                        .unwrap_or(0),
+                    key_expr: key.clone(),
+                    encoding: payload_format_from_encoding(sample.encoding()),
+                };
+
+                let mut history = subscriber_history.lock().unwrap();
+                let ring = history.entry(key).or_default();
+                ring.push_back((asterix_sample, std::time::Instant::now()));
+                while ring.len() > history_depth {
+                    ring.pop_front();
+                }
+                if history_max_age > std::time::Duration::ZERO {
+                    while ring
+                        .front()
+                        .is_some_and(|(_, seen_at)| seen_at.elapsed() > history_max_age)
+                    {
+                        ring.pop_front();
+                    }
+                }
+            }
+        });
+
+        let queryable = session
+            .declare_queryable(key_expr)
+            .await
+            .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
+
+        let queryable_history = history.clone();
+        let queryable_prefix = key_prefix;
+        let queryable_handle = tokio::spawn(async move {
+            while let Ok(query) = queryable.recv_async().await {
+                let selector = query.selector().key_expr().to_string();
+                let time_range = parse_time_range_param(&query);
+                let limit = parse_limit_param(&query);
+
+                let mut matches: Vec<AsterixSample> = {
+                    let history = queryable_history.lock().unwrap();
+                    history
+                        .values()
+                        .flatten()
+                        .filter(|(_, seen_at)| {
+                            history_max_age == std::time::Duration::ZERO
+                                || seen_at.elapsed() <= history_max_age
+                        })
+                        .map(|(sample, _)| sample)
+                        .filter(|sample| {
+                            sample_matches_selector(sample, &selector, &queryable_prefix)
+                        })
+                        .filter(|sample| match time_range {
+                            Some((start, stop)) => (start..=stop).contains(&sample.timestamp),
+                            None => true,
+                        })
+                        .cloned()
+                        .collect()
+                };
+
+                if let Some(limit) = limit {
+                    matches.sort_by_key(|sample| sample.timestamp);
+                    if matches.len() > limit {
+                        matches.drain(..matches.len() - limit);
+                    }
+                }
+
+                for sample in matches {
+                    let _ = query.reply(sample.key_expr.clone(), sample.data.clone()).await;
+                }
+            }
+        });
+
+        Ok(Self {
+            session,
+            history,
+            _subscriber_handle: subscriber_handle,
+            _queryable_handle: queryable_handle,
+        })
+    }
+
+    /// Number of samples currently retained for `key_expr` (the exact key a
+    /// sample was published on, not a wildcard selector).
+    pub fn history_len(&self, key_expr: &str) -> usize {
+        self.history
+            .lock()
+            .unwrap()
+            .get(key_expr)
+            .map_or(0, VecDeque::len)
+    }
+
+    /// Close the queryable and release resources
+    pub async fn close(self) -> Result<(), ZenohError> {
+        self.session
+            .close()
+            .await
+            .map_err(|e| ZenohError::SessionError(e.to_string()))
+    }
+}
+
+/// Which queryables [`ZenohQueryClient::get`] collects replies from
+///
+/// Defaults to [`Self::All`]: a late-joining subscriber recovering missed
+/// history wants every matching [`ZenohQueryable`] to contribute its
+/// retained samples, not just the one Zenoh considers the "best" match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QueryTarget {
+    /// Query every matching queryable and collect every reply
+    #[default]
+    All,
+    /// Query only the queryable Zenoh considers the best match
+    BestMatching,
+    /// Query every matching queryable that can answer the whole selector on
+    /// its own
+    AllComplete,
+}
+
+fn zenoh_query_target(target: QueryTarget) -> zenoh::query::QueryTarget {
+    match target {
+        QueryTarget::All => zenoh::query::QueryTarget::All,
+        QueryTarget::BestMatching => zenoh::query::QueryTarget::BestMatching,
+        QueryTarget::AllComplete => zenoh::query::QueryTarget::AllComplete,
+    }
+}
+
+/// A standing session for repeatedly querying [`ZenohQueryable`]s' retained
+/// history
+///
+/// Unlike [`query_history`], which opens and closes a fresh session on every
+/// call, `ZenohQueryClient` keeps one session open across calls — the right
+/// choice for a controller workstation that polls for recently-missed
+/// records on a timer rather than querying exactly once at startup.
+pub struct ZenohQueryClient {
+    session: Arc<Session>,
+    key_prefix: String,
+}
+
+impl ZenohQueryClient {
+    /// Open a session for issuing queries against `config.endpoints`
+    pub async fn new(config: &ZenohConfig) -> Result<Self, ZenohError> {
+        let mut zenoh_config = Config::default();
+
+        if !config.endpoints.is_empty() {
+            let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
+            zenoh_config
+                .insert_json5("connect/endpoints", &endpoints_json)
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
+        let session = Arc::new(
+            zenoh::open(zenoh_config)
+                .await
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?,
+        );
+
+        Ok(Self {
+            session,
+            key_prefix: config.key_prefix.clone(),
+        })
+    }
+
+    /// Issue a get() against `selector`, querying queryables per `target`,
+    /// and collect every matching [`AsterixSample`] replied with
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::ReceiveError`] if the get() itself fails.
+    pub async fn get(
+        &self,
+        selector: &str,
+        target: QueryTarget,
+    ) -> Result<Vec<AsterixSample>, ZenohError> {
+        self.query(selector, None, target).await
+    }
+
+    /// Issue a get() against `selector` restricted to `time_range` (an
+    /// inclusive `(start, stop)` range in microseconds since the Unix epoch,
+    /// matching [`AsterixSample::timestamp`]; see [`ZenohQueryable`] for how
+    /// it's honored on the reply side), querying queryables per `target`, and
+    /// collect every matching [`AsterixSample`] replied with, ordered oldest
+    /// to newest.
+    ///
+    /// The standing-session counterpart to [`ZenohSubscriber::query`] — the
+    /// right choice for a client that only ever wants historical data (e.g.
+    /// replaying a time window for post-incident analysis) rather than also
+    /// subscribing live.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::ReceiveError`] if the get() itself fails.
+    pub async fn query(
+        &self,
+        selector: &str,
+        time_range: Option<(u64, u64)>,
+        target: QueryTarget,
+    ) -> Result<Vec<AsterixSample>, ZenohError> {
+        self.query_with_limit(selector, time_range, None, target).await
+    }
+
+    /// Same as [`Self::query`], additionally capping the reply to at most
+    /// `limit` of the most recent matching samples (see [`ZenohQueryable`]'s
+    /// `_limit` query parameter).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ZenohError::ReceiveError`] if the get() itself fails.
+    pub async fn query_with_limit(
+        &self,
+        selector: &str,
+        time_range: Option<(u64, u64)>,
+        limit: Option<usize>,
+        target: QueryTarget,
+    ) -> Result<Vec<AsterixSample>, ZenohError> {
+        let selector = selector_with_time_range(selector, time_range);
+        let selector = selector_with_limit(&selector, limit);
+
+        let replies = self
+            .session
+            .get(&selector)
+            .target(zenoh_query_target(target))
+            .await
+            .map_err(|e| ZenohError::ReceiveError(e.to_string()))?;
+
+        let mut samples = Vec::new();
+        while let Ok(reply) = replies.recv_async().await {
+            if let Ok(sample) = reply.result() {
+                let key = sample.key_expr().to_string();
+                let data: Vec<u8> = sample.payload().to_bytes().to_vec();
+                let (category, sac, sic) = parse_key_expr(&key, &self.key_prefix);
+
+                samples.push(AsterixSample {
+                    category,
+                    sac,
+                    sic,
+                    data,
+                    timestamp: std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_micros() as u64)
+                        .unwrap_or(0),
+                    key_expr: key,
+                    encoding: payload_format_from_encoding(sample.encoding()),
+                });
+            }
+        }
+
+        samples.sort_by_key(|sample| sample.timestamp);
+        Ok(samples)
+    }
+
+    /// Close the client and release its session
+    pub async fn close(self) -> Result<(), ZenohError> {
+        self.session
+            .close()
+            .await
+            .map_err(|e| ZenohError::SessionError(e.to_string()))
+    }
+}
+
+/// Issue a Zenoh get() against `selector` and collect every matching
+/// [`AsterixSample`] a [`ZenohQueryable`] replies with.
+///
+/// Opens its own short-lived session rather than reusing a
+/// [`ZenohPublisher`]/[`ZenohSubscriber`]'s, since a one-shot query doesn't
+/// need a standing pub/sub declaration.
+///
+/// # Errors
+///
+/// Returns [`ZenohError::SessionError`] if the session can't be opened, or
+/// [`ZenohError::ReceiveError`] if the get() itself fails.
+pub async fn query_history(
+    config: &ZenohConfig,
+    selector: &str,
+) -> Result<Vec<AsterixSample>, ZenohError> {
+    let mut zenoh_config = Config::default();
+
+    if !config.endpoints.is_empty() {
+        let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
+        zenoh_config
+            .insert_json5("connect/endpoints", &endpoints_json)
+            .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+    }
+
+    let session = zenoh::open(zenoh_config)
+        .await
+        .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+
+    let replies = session
+        .get(selector)
+        .await
+        .map_err(|e| ZenohError::ReceiveError(e.to_string()))?;
+
+    let mut samples = Vec::new();
+    while let Ok(reply) = replies.recv_async().await {
+        if let Ok(sample) = reply.result() {
+            let key = sample.key_expr().to_string();
+            let data: Vec<u8> = sample.payload().to_bytes().to_vec();
+            let (category, sac, sic) = parse_key_expr(&key, &config.key_prefix);
+
+            samples.push(AsterixSample {
+                category,
+                sac,
+                sic,
+                data,
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_micros() as u64)
+                    .unwrap_or(0),
+                key_expr: key,
+                encoding: payload_format_from_encoding(sample.encoding()),
+            });
+        }
+    }
+
+    session
+        .close()
+        .await
+        .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+
+    Ok(samples)
+}
+
+/// Per-key last-value cache of decoded records, answering get-queries from
+/// late-joining subscribers.
+///
+/// Plain pub/sub never replays past state, so a subscriber that connects
+/// after a track update sees nothing for that track until the next one
+/// arrives. Like [`ZenohQueryable`], `ZenohTrackStore` subscribes to
+/// `key_expr` and declares a queryable over the same key expression, but
+/// instead of retaining a bounded history of raw samples it decodes every
+/// sample (via a shared [`SerializedDecoder`]) and retains only the single
+/// most recent [`AsterixRecord`] per key, expiring it once `ttl` elapses
+/// without an update — so a dead track's key drops out of query results
+/// instead of serving a stale snapshot forever. Query replies are
+/// CBOR-encoded ([`crate::cbor`]) since that doesn't require the `serde`
+/// feature.
+pub struct ZenohTrackStore {
+    session: Arc<Session>,
+    cache: Arc<Mutex<HashMap<String, (AsterixRecord, std::time::Instant)>>>,
+    ttl: std::time::Duration,
+    _subscriber_handle: tokio::task::JoinHandle<()>,
+    _queryable_handle: tokio::task::JoinHandle<()>,
+}
+
+impl ZenohTrackStore {
+    /// Create a new track store, subscribing to `key_expr` to keep the cache
+    /// fresh and declaring a queryable over the same `key_expr` to serve it.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Zenoh configuration; `key_prefix` must match what publishers use
+    /// * `key_expr` - Key expression to subscribe to and answer queries for
+    ///   (e.g. `"asterix/48/**"` for every CAT048 track)
+    /// * `decoder` - Shared decoder handle used to turn received bytes into [`AsterixRecord`]s
+    /// * `options` - Parse options applied to every received sample
+    /// * `ttl` - How long a cached record stays eligible for query replies
+    ///   after its last update before it's treated as a dead track
+    pub async fn new(
+        config: ZenohConfig,
+        key_expr: &str,
+        decoder: SerializedDecoder,
+        options: ParseOptions,
+        ttl: std::time::Duration,
+    ) -> Result<Self, ZenohError> {
+        let mut zenoh_config = Config::default();
+
+        if !config.endpoints.is_empty() {
+            let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
+            zenoh_config
+                .insert_json5("connect/endpoints", &endpoints_json)
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
+        let session = Arc::new(
+            zenoh::open(zenoh_config)
+                .await
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?,
+        );
+
+        let cache: Arc<Mutex<HashMap<String, (AsterixRecord, std::time::Instant)>>> =
+            Arc::new(Mutex::new(HashMap::new()));
+
+        let subscriber = session
+            .declare_subscriber(key_expr)
+            .await
+            .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
+
+        let subscriber_cache = cache.clone();
+        let subscriber_decoder = decoder;
+        let subscriber_handle = tokio::spawn(async move {
+            while let Ok(sample) = subscriber.recv_async().await {
+                let key = sample.key_expr().to_string();
+                let data: Vec<u8> = sample.payload().to_bytes().to_vec();
+
+                let record = match subscriber_decoder.parse(data, options.clone()) {
+                    Ok(records) => records.into_iter().next(),
+                    Err(err) => {
+                        log::warn!("ZenohTrackStore failed to decode sample on {key}: {err}");
+                        continue;
+                    }
+                };
+
+                let Some(record) = record else { continue };
+
+                let mut cache = subscriber_cache.lock().unwrap();
+                cache.insert(key, (record, std::time::Instant::now()));
+                cache.retain(|_, (_, updated_at)| updated_at.elapsed() < ttl);
+            }
+        });
+
+        let queryable = session
+            .declare_queryable(key_expr)
+            .await
+            .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
+
+        let queryable_cache = cache.clone();
+        let queryable_prefix = config.key_prefix.clone();
+        let queryable_handle = tokio::spawn(async move {
+            while let Ok(query) = queryable.recv_async().await {
+                let selector = query.selector().key_expr().to_string();
+
+                let matches: Vec<(String, AsterixRecord)> = {
+                    let mut cache = queryable_cache.lock().unwrap();
+                    cache.retain(|_, (_, updated_at)| updated_at.elapsed() < ttl);
+                    cache
+                        .iter()
+                        .filter(|(key, _)| key_matches_selector(key, &selector, &queryable_prefix))
+                        .map(|(key, (record, _))| (key.clone(), record.clone()))
+                        .collect()
+                };
+
+                for (key, record) in matches {
+                    let payload = cbor::encode_record(&record);
+                    let _ = query
+                        .reply(key, payload)
+                        .encoding(zenoh_encoding(PayloadFormat::Cbor))
+                        .await;
+                }
+            }
+        });
+
+        Ok(Self {
+            session,
+            cache,
+            ttl,
+            _subscriber_handle: subscriber_handle,
+            _queryable_handle: queryable_handle,
+        })
+    }
+
+    /// Number of live (non-expired) tracks currently cached.
+    pub fn len(&self) -> usize {
+        let mut cache = self.cache.lock().unwrap();
+        let ttl = self.ttl;
+        cache.retain(|_, (_, updated_at)| updated_at.elapsed() < ttl);
+        cache.len()
+    }
+
+    /// Whether the cache currently has no live tracks.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The cached record for the exact key `key_expr` was published on, if
+    /// present and not yet expired.
+    pub fn get(&self, key_expr: &str) -> Option<AsterixRecord> {
+        let cache = self.cache.lock().unwrap();
+        let (record, updated_at) = cache.get(key_expr)?;
+        if updated_at.elapsed() < self.ttl {
+            Some(record.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Close the track store and release resources
+    pub async fn close(self) -> Result<(), ZenohError> {
+        self.session
+            .close()
+            .await
+            .map_err(|e| ZenohError::SessionError(e.to_string()))
+    }
+}
+
+/// Per-request parse strictness override for [`ZenohDecodeService`], carried
+/// as a query attachment so a [`ZenohDecodeClient`] can opt into strict or
+/// lenient parsing without sending the server its full [`ParseOptions`].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeStrictness {
+    /// Stop at the first malformed record (`ParseOptions::continue_on_error = false`)
+    Strict,
+    /// Skip malformed records and keep decoding the rest (`continue_on_error = true`)
+    Lenient,
+}
+
+#[cfg(feature = "serde")]
+impl DecodeStrictness {
+    fn as_attachment(self) -> &'static [u8] {
+        match self {
+            Self::Strict => b"strict",
+            Self::Lenient => b"lenient",
+        }
+    }
+
+    fn from_attachment(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"strict" => Some(Self::Strict),
+            b"lenient" => Some(Self::Lenient),
+            _ => None,
+        }
+    }
+
+    fn continue_on_error(self) -> bool {
+        matches!(self, Self::Lenient)
+    }
+}
+
+/// Request/response ASTERIX decode service over a Zenoh queryable.
+///
+/// Where [`DecodingSubscriber`]/[`ZenohTrackStore`] decode a continuous
+/// pub/sub stream, `ZenohDecodeService` answers one-shot decode requests: a
+/// thin client sends raw ASTERIX bytes as a query payload and gets back the
+/// decoded [`AsterixRecord`]s (or an error), so the client doesn't need the
+/// category XML config or the FFI decoder locally at all. Requires the
+/// `serde` feature, since replies round-trip full `AsterixRecord`s as JSON.
+#[cfg(feature = "serde")]
+pub struct ZenohDecodeService {
+    session: Arc<Session>,
+    _queryable_handle: tokio::task::JoinHandle<()>,
+}
+
+#[cfg(feature = "serde")]
+impl ZenohDecodeService {
+    /// Start serving decode requests on `service_key` (e.g. `"asterix/rpc/decode"`).
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Zenoh configuration
+    /// * `service_key` - Key expression clients issue `get()`s against
+    /// * `decoder` - Shared decoder handle; clone the same [`SerializedDecoder`]
+    ///   into every service/subscriber/publisher that needs to decode or parse
+    /// * `default_options` - Used for every request, except a request's
+    ///   [`DecodeStrictness`] attachment (if present) overrides `continue_on_error`
+    pub async fn new(
+        config: ZenohConfig,
+        service_key: &str,
+        decoder: SerializedDecoder,
+        default_options: ParseOptions,
+    ) -> Result<Self, ZenohError> {
+        let mut zenoh_config = Config::default();
+
+        if !config.endpoints.is_empty() {
+            let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
+            zenoh_config
+                .insert_json5("connect/endpoints", &endpoints_json)
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
+        let session = Arc::new(
+            zenoh::open(zenoh_config)
+                .await
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?,
+        );
+
+        let queryable = session
+            .declare_queryable(service_key)
+            .await
+            .map_err(|e| ZenohError::SubscriberError(e.to_string()))?;
+
+        let queryable_handle = tokio::spawn(async move {
+            while let Ok(query) = queryable.recv_async().await {
+                let data: Vec<u8> = query
+                    .payload()
+                    .map(|p| p.to_bytes().to_vec())
+                    .unwrap_or_default();
+
+                let mut options = default_options.clone();
+                if let Some(strictness) = query
+                    .attachment()
+                    .and_then(|a| DecodeStrictness::from_attachment(&a.to_bytes()))
+                {
+                    options.continue_on_error = strictness.continue_on_error();
+                }
+
+                let reply_key = query.selector().key_expr().to_string();
+                match decoder.parse(data, options) {
+                    Ok(records) => match serde_json::to_vec(&records) {
+                        Ok(payload) => {
+                            let _ = query
+                                .reply(reply_key, payload)
+                                .encoding(zenoh::bytes::Encoding::APPLICATION_JSON)
+                                .await;
+                        }
+                        Err(err) => {
+                            let _ = query.reply_err(err.to_string().into_bytes()).await;
+                        }
+                    },
+                    Err(err) => {
+                        let _ = query.reply_err(err.to_string().into_bytes()).await;
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            session,
+            _queryable_handle: queryable_handle,
+        })
+    }
+
+    /// Stop serving and release resources
+    pub async fn close(self) -> Result<(), ZenohError> {
+        self.session
+            .close()
+            .await
+            .map_err(|e| ZenohError::SessionError(e.to_string()))
+    }
+}
+
+/// Client for [`ZenohDecodeService`]: sends raw ASTERIX bytes in a query and
+/// deserializes the decoded [`AsterixRecord`]s back out of the reply.
+#[cfg(feature = "serde")]
+pub struct ZenohDecodeClient {
+    session: Arc<Session>,
+    service_key: String,
+}
+
+#[cfg(feature = "serde")]
+impl ZenohDecodeClient {
+    /// Connect a client for the decode service listening on `service_key`.
+    pub async fn new(config: ZenohConfig, service_key: &str) -> Result<Self, ZenohError> {
+        let mut zenoh_config = Config::default();
+
+        if !config.endpoints.is_empty() {
+            let endpoints_json = format!(r#"["{}"]"#, config.endpoints.join(r#"",""#));
+            zenoh_config
+                .insert_json5("connect/endpoints", &endpoints_json)
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?;
+        }
+
+        let session = Arc::new(
+            zenoh::open(zenoh_config)
+                .await
+                .map_err(|e| ZenohError::SessionError(e.to_string()))?,
+        );
+
+        Ok(Self {
+            session,
+            service_key: service_key.to_string(),
+        })
+    }
+
+    /// Decode `data` using the service's default [`ParseOptions`].
+    pub async fn decode(&self, data: &[u8]) -> Result<Vec<AsterixRecord>, ZenohError> {
+        self.decode_with_strictness(data, None).await
+    }
+
+    /// Decode `data`, overriding the service's default strict/lenient
+    /// behavior via a [`DecodeStrictness`] query attachment.
+    pub async fn decode_with_strictness(
+        &self,
+        data: &[u8],
+        strictness: Option<DecodeStrictness>,
+    ) -> Result<Vec<AsterixRecord>, ZenohError> {
+        let mut get_builder = self.session.get(&self.service_key).payload(data.to_vec());
+        if let Some(strictness) = strictness {
+            get_builder = get_builder.attachment(strictness.as_attachment());
+        }
+
+        let replies = get_builder
+            .await
+            .map_err(|e| ZenohError::ReceiveError(e.to_string()))?;
+
+        let mut records = Vec::new();
+        while let Ok(reply) = replies.recv_async().await {
+            match reply.result() {
+                Ok(sample) => {
+                    let bytes = sample.payload().to_bytes();
+                    let decoded: Vec<AsterixRecord> = serde_json::from_slice(&bytes)
+                        .map_err(|e| ZenohError::SerializationError(e.to_string()))?;
+                    records.extend(decoded);
+                }
+                Err(reply_err) => {
+                    return Err(ZenohError::ReceiveError(format!(
+                        "decode service returned an error: {reply_err:?}"
+                    )));
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Close the client and release resources
+    pub async fn close(self) -> Result<(), ZenohError> {
+        self.session
+            .close()
+            .await
+            .map_err(|e| ZenohError::SessionError(e.to_string()))
+    }
+}
+
+/// Parse a selector's key-expression segments into per-position components,
+/// the same way [`parse_key_expr`] parses a concrete key, except a `*` or
+/// `**` wildcard segment becomes `None` (unconstrained) instead of `0`.
+fn parse_selector_components(selector: &str, prefix: &str) -> [Option<u8>; 3] {
+    let stripped = selector.strip_prefix(prefix).unwrap_or(selector);
+    let parts: Vec<&str> = stripped.trim_start_matches('/').split('/').collect();
+
+    let mut components = [None; 3];
+    for (i, part) in parts.iter().take(3).enumerate() {
+        if *part == "*" || *part == "**" {
+            continue;
+        }
+        components[i] = part.parse::<u8>().ok();
+    }
+    components
+}
+
+/// Does `sample` match `selector` (a key expression, possibly with `*`/`**`
+/// wildcards) under `prefix`?
+///
+/// A selector component matches if it's a wildcard or equals the sample's
+/// corresponding component; a `**` (or running out of selector segments)
+/// leaves everything after it unconstrained.
+fn sample_matches_selector(sample: &AsterixSample, selector: &str, prefix: &str) -> bool {
+    let sample_components = [Some(sample.category), sample.sac, sample.sic];
+    components_match_selector(&sample_components, selector, prefix)
+}
+
+/// Does the key expression `key` (e.g. `"asterix/48/1/2"`) match `selector`
+/// under `prefix`? Same semantics as [`sample_matches_selector`], for
+/// callers (like [`ZenohTrackStore`]) that cache by key expression rather
+/// than by [`AsterixSample`].
+fn key_matches_selector(key: &str, selector: &str, prefix: &str) -> bool {
+    let (category, sac, sic) = parse_key_expr(key, prefix);
+    components_match_selector(&[Some(category), sac, sic], selector, prefix)
+}
+
+/// Shared matching logic for [`sample_matches_selector`]/[`key_matches_selector`]:
+/// a selector component matches if it's a wildcard or equals the
+/// corresponding `[category, sac, sic]` component.
+fn components_match_selector(components: &[Option<u8>; 3], selector: &str, prefix: &str) -> bool {
+    let selector_components = parse_selector_components(selector, prefix);
+
+    selector_components
+        .iter()
+        .zip(components.iter())
+        .all(|(expected, actual)| expected.is_none() || expected == actual)
+}
+
+/// Append a `_time=[start..stop]` query parameter (an inclusive range of
+/// microseconds since the Unix epoch, matching [`AsterixSample::timestamp`])
+/// to `selector`, for issuing a time-bounded get() against a
+/// [`ZenohQueryable`]. Leaves `selector` untouched when `time_range` is `None`.
+fn selector_with_time_range(selector: &str, time_range: Option<(u64, u64)>) -> String {
+    match time_range {
+        Some((start, stop)) => format!("{selector}?_time=[{start}..{stop}]"),
+        None => selector.to_string(),
+    }
+}
+
+/// Append the `_limit=<n>` query parameter [`parse_limit_param`] reads back
+/// off the selector, the counterpart to [`selector_with_time_range`].
+fn selector_with_limit(selector: &str, limit: Option<usize>) -> String {
+    match limit {
+        Some(limit) => {
+            let separator = if selector.contains('?') { '&' } else { '?' };
+            format!("{selector}{separator}_limit={limit}")
+        }
+        None => selector.to_string(),
+    }
+}
+
+/// Parse the `_time=[start..stop]` query parameter off an incoming
+/// [`zenoh::query::Query`], the counterpart to [`selector_with_time_range`].
+/// Returns `None` if the parameter is absent or malformed, in which case the
+/// caller should not filter by time at all.
+fn parse_time_range_param(query: &zenoh::query::Query) -> Option<(u64, u64)> {
+    parse_time_range(query.selector().parameters().get("_time")?)
+}
+
+fn parse_time_range(raw: &str) -> Option<(u64, u64)> {
+    let raw = raw.strip_prefix('[')?.strip_suffix(']')?;
+    let (start, stop) = raw.split_once("..")?;
+    Some((start.parse().ok()?, stop.parse().ok()?))
+}
+
+/// Parse the `_limit=<n>` query parameter off an incoming
+/// [`zenoh::query::Query`] -- caps how many of the most recent matching
+/// samples [`ZenohQueryable`] replies with, named like [`parse_time_range_param`]'s
+/// `_time` since both are this module's own selector conventions rather
+/// than anything Zenoh itself standardizes. Returns `None` if the
+/// parameter is absent or malformed, in which case the caller should not
+/// cap the reply count at all.
+fn parse_limit_param(query: &zenoh::query::Query) -> Option<usize> {
+    query.selector().parameters().get("_limit")?.parse().ok()
+}
+
+/// How long [`ZenohSubscriber`] waits for the remaining fragments of a
+/// partially-received [`FragmentAssembly`] before dropping it.
+const FRAGMENT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// In-progress reassembly of a fragmented publish, keyed by its base
+/// (un-fragmented) key expression.
+struct FragmentAssembly {
+    parts: Vec<Option<Vec<u8>>>,
+    received: usize,
+    first_seen: std::time::Instant,
+}
+
+impl FragmentAssembly {
+    fn new(total: usize) -> Self {
+        Self {
+            parts: vec![None; total],
+            received: 0,
+            first_seen: std::time::Instant::now(),
+        }
+    }
+
+    /// Record a fragment arriving at `seq`, regardless of arrival order, and
+    /// return the reassembled payload once every slot has been filled.
+    ///
+    /// A duplicate delivery of an already-filled `seq` (e.g. from a Zenoh
+    /// retransmit) is a no-op rather than overwriting the first copy, so
+    /// fragments may arrive out of order and/or be re-delivered without
+    /// corrupting the reassembled payload.
+    fn insert(&mut self, seq: usize, data: Vec<u8>) -> Option<Vec<u8>> {
+        if seq < self.parts.len() && self.parts[seq].is_none() {
+            self.parts[seq] = Some(data);
+            self.received += 1;
+        }
+
+        if self.received < self.parts.len() {
+            return None;
+        }
+
+        Some(
+            std::mem::take(&mut self.parts)
+                .into_iter()
+                .flat_map(|part| part.unwrap_or_default())
+                .collect(),
+        )
+    }
+}
+
+/// Split a key expression into its base key and, if it ends in a
+/// `/frag/<seq>/<total>` suffix (as [`ZenohPublisher::publish_chunked`]
+/// produces), the fragment's sequence number and total fragment count.
+fn split_frag_suffix(key: &str) -> (String, Option<(usize, usize)>) {
+    if let Some(frag_start) = key.rfind("/frag/") {
+        let (base, suffix) = key.split_at(frag_start);
+        let suffix = &suffix["/frag/".len()..];
+        let mut parts = suffix.split('/');
+        if let (Some(seq), Some(total), None) = (parts.next(), parts.next(), parts.next()) {
+            if let (Ok(seq), Ok(total)) = (seq.parse::<usize>(), total.parse::<usize>()) {
+                if total > 0 && seq < total {
+                    return (base.to_string(), Some((seq, total)));
+                }
+            }
+        }
+    }
+
+    (key.to_string(), None)
+}
+
+/// Map our [`Priority`] to Zenoh's own, applied to each `put` since
+/// [`ZenohPublisher`] builds a fresh key expression per record rather than
+/// declaring one long-lived publisher to attach QoS to up front.
+fn zenoh_priority(priority: Priority) -> zenoh::qos::Priority {
+    match priority {
+        Priority::RealTime => zenoh::qos::Priority::RealTime,
+        Priority::Interactive => zenoh::qos::Priority::InteractiveLow,
+        Priority::Data => zenoh::qos::Priority::Data,
+        Priority::Background => zenoh::qos::Priority::Background,
+    }
+}
+
+/// Map our [`CongestionControl`] to Zenoh's own.
+fn zenoh_congestion_control(congestion_control: CongestionControl) -> zenoh::qos::CongestionControl {
+    match congestion_control {
+        CongestionControl::Block => zenoh::qos::CongestionControl::Block,
+        CongestionControl::Drop => zenoh::qos::CongestionControl::Drop,
+    }
+}
+
+/// Map our [`Reliability`] to Zenoh's own.
+fn zenoh_reliability(reliability: Reliability) -> zenoh::qos::Reliability {
+    match reliability {
+        Reliability::Reliable => zenoh::qos::Reliability::Reliable,
+        Reliability::BestEffort => zenoh::qos::Reliability::BestEffort,
+    }
+}
+
+/// Map a [`PayloadFormat`] to the Zenoh encoding attribute
+/// [`ZenohPublisher::publish`] tags the sample with, so a subscriber can
+/// dispatch on the sample's encoding instead of out-of-band agreement.
+fn zenoh_encoding(format: PayloadFormat) -> zenoh::bytes::Encoding {
+    match format {
+        PayloadFormat::Raw => zenoh::bytes::Encoding::APPLICATION_OCTET_STREAM,
+        PayloadFormat::Json => zenoh::bytes::Encoding::APPLICATION_JSON,
+        PayloadFormat::Cbor => zenoh::bytes::Encoding::APPLICATION_CBOR,
+        PayloadFormat::MessagePack => zenoh::bytes::Encoding::from(MESSAGEPACK_ENCODING_ID),
+    }
+}
+
+/// Inverse of [`zenoh_encoding`]: recover the [`PayloadFormat`] a received
+/// sample was published with from its Zenoh encoding attribute, defaulting
+/// to [`PayloadFormat::Raw`] for anything else (e.g. a sample published by
+/// something other than [`ZenohPublisher::publish`]).
+fn payload_format_from_encoding(encoding: &zenoh::bytes::Encoding) -> PayloadFormat {
+    if *encoding == zenoh::bytes::Encoding::APPLICATION_JSON {
+        PayloadFormat::Json
+    } else if *encoding == zenoh::bytes::Encoding::APPLICATION_CBOR {
+        PayloadFormat::Cbor
+    } else if *encoding == zenoh::bytes::Encoding::from(MESSAGEPACK_ENCODING_ID) {
+        PayloadFormat::MessagePack
+    } else {
+        PayloadFormat::Raw
+    }
+}
+
+/// Zenoh encoding identifier [`zenoh_encoding`]/[`payload_format_from_encoding`]
+/// use for [`PayloadFormat::MessagePack`] — Zenoh has no built-in MessagePack
+/// constant like its `APPLICATION_JSON`/`APPLICATION_CBOR`, so this registers
+/// a custom one with the standard MessagePack MIME type.
+const MESSAGEPACK_ENCODING_ID: &str = "application/msgpack";
+
+/// Parse a key expression to extract category, SAC, and SIC
+fn parse_key_expr(key: &str, prefix: &str) -> (u8, Option<u8>, Option<u8>) {
+    let stripped = key.strip_prefix(prefix).unwrap_or(key);
+    let parts: Vec<&str> = stripped.trim_start_matches('/').split('/').collect();
+
+    let category = parts
+        .first()
+        .and_then(|s| s.parse::<u8>().ok())
+        .unwrap_or(0);
+
+    let sac = parts.get(1).and_then(|s| s.parse::<u8>().ok());
+
+    let sic = parts.get(2).and_then(|s| s.parse::<u8>().ok());
+
+    (category, sac, sic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ============================================================================
+    // Key Expression Parsing Tests
+    // ============================================================================
+
+    #[test]
+    fn test_parse_key_expr_category_only() {
+        assert_eq!(parse_key_expr("asterix/48", "asterix"), (48, None, None));
+        assert_eq!(parse_key_expr("asterix/62", "asterix"), (62, None, None));
+        assert_eq!(parse_key_expr("asterix/255", "asterix"), (255, None, None));
+    }
+
+    #[test]
+    fn test_parse_key_expr_with_sac_sic() {
+        assert_eq!(
+            parse_key_expr("asterix/62/1/2", "asterix"),
+            (62, Some(1), Some(2))
+        );
+        assert_eq!(
+            parse_key_expr("asterix/65/10/20", "asterix"),
+            (65, Some(10), Some(20))
+        );
+        assert_eq!(
+            parse_key_expr("asterix/48/255/255", "asterix"),
+            (48, Some(255), Some(255))
+        );
+    }
+
+    #[test]
+    fn test_parse_key_expr_partial_routing() {
+        // Only SAC, no SIC
+        assert_eq!(
+            parse_key_expr("asterix/48/1", "asterix"),
+            (48, Some(1), None)
+        );
+    }
+
+    #[test]
+    fn test_parse_key_expr_invalid_category() {
+        // Invalid category returns 0
+        assert_eq!(
+            parse_key_expr("asterix/invalid", "asterix"),
+            (0, None, None)
+        );
+        assert_eq!(parse_key_expr("asterix/", "asterix"), (0, None, None));
+    }
+
+    #[test]
+    fn test_parse_key_expr_different_prefix() {
+        // Using different prefix
+        assert_eq!(
+            parse_key_expr("custom/48/1/2", "custom"),
+            (48, Some(1), Some(2))
+        );
+        assert_eq!(
+            parse_key_expr("atm/surveillance/62", "atm/surveillance"),
+            (62, None, None)
+        );
+    }
+
+    #[test]
+    fn test_parse_key_expr_no_prefix() {
+        // If key doesn't start with prefix, parse from beginning
+        assert_eq!(parse_key_expr("48/1/2", "asterix"), (48, Some(1), Some(2)));
+    }
+
+    // ============================================================================
+    // ZenohConfig Tests
+    // ============================================================================
+
+    #[test]
+    fn test_zenoh_config_default() {
+        let config = ZenohConfig::default();
+        assert!(config.endpoints.is_empty());
+        assert_eq!(config.key_prefix, "asterix");
+        assert!(config.include_raw_bytes);
+        assert!(matches!(
+            config.congestion_control,
+            CongestionControl::Block
+        ));
+        assert!(matches!(config.priority, Priority::RealTime));
+        assert!(matches!(config.reliability, Reliability::Reliable));
+    }
+
+    #[test]
+    fn test_zenoh_config_with_router() {
+        let config = ZenohConfig::with_router("tcp/192.168.1.1:7447");
+        assert_eq!(config.endpoints.len(), 1);
+        assert_eq!(config.endpoints[0], "tcp/192.168.1.1:7447");
+        assert_eq!(config.key_prefix, "asterix"); // Should preserve defaults
+    }
+
+    #[test]
+    fn test_zenoh_config_with_multiple_endpoints() {
+        let endpoints = vec![
+            "tcp/10.0.0.1:7447".to_string(),
+            "tcp/10.0.0.2:7447".to_string(),
+            "udp/10.0.0.3:7448".to_string(),
+        ];
+        let config = ZenohConfig::with_endpoints(endpoints.clone());
+        assert_eq!(config.endpoints, endpoints);
+    }
+
+    #[test]
+    fn test_zenoh_config_peer_to_peer() {
+        let config = ZenohConfig::peer_to_peer();
+        assert!(config.endpoints.is_empty());
+    }
+
+    #[test]
+    fn test_zenoh_config_clone() {
+        let config = ZenohConfig::with_router("tcp/10.0.0.1:7447");
+        let cloned = config.clone();
+        assert_eq!(config.endpoints, cloned.endpoints);
+        assert_eq!(config.key_prefix, cloned.key_prefix);
+    }
+
+    // ============================================================================
+    // ZenohError Tests
+    // ============================================================================
+
+    #[test]
+    fn test_zenoh_error_display_variants() {
+        let errors = vec![
+            (ZenohError::SessionError("test".to_string()), "session"),
+            (ZenohError::PublisherError("test".to_string()), "publisher"),
+            (
+                ZenohError::SubscriberError("test".to_string()),
+                "subscriber",
+            ),
+            (ZenohError::PublishError("test".to_string()), "publish"),
+            (ZenohError::ReceiveError("test".to_string()), "receive"),
+            (
+                ZenohError::SerializationError("test".to_string()),
+                "Serialization",
+            ),
+            (ZenohError::ChannelClosed, "closed"),
+        ];
+
+        for (err, expected_substr) in errors {
+            let display = err.to_string();
+            assert!(
+                display
+                    .to_lowercase()
+                    .contains(&expected_substr.to_lowercase()),
+                "Expected '{}' to contain '{}': got '{}'",
+                stringify!(err),
+                expected_substr,
+                display
+            );
+        }
+    }
+
+    #[test]
+    fn test_zenoh_error_debug() {
+        let err = ZenohError::SessionError("debug test".to_string());
+        let debug_str = format!("{err:?}");
+        assert!(debug_str.contains("SessionError"));
+        assert!(debug_str.contains("debug test"));
+    }
+
+    #[test]
+    fn test_zenoh_error_to_asterix_error() {
+        use crate::error::AsterixError;
+
+        let zenoh_err = ZenohError::PublishError("publish failed".to_string());
+        let asterix_err: AsterixError = zenoh_err.into();
+
+        match asterix_err {
+            AsterixError::IOError(msg) => {
+                assert!(msg.contains("publish"));
+            }
+            _ => panic!("Expected IOError variant"),
+        }
+    }
+
+    // ============================================================================
+    // CongestionControl and Priority Tests
+    // ============================================================================
+
+    #[test]
+    fn test_congestion_control_default() {
+        let cc: CongestionControl = Default::default();
+        assert!(matches!(cc, CongestionControl::Block));
+    }
+
+    #[test]
+    fn test_priority_default() {
+        let p: Priority = Default::default();
+        assert!(matches!(p, Priority::RealTime));
+    }
+
+    #[test]
+    fn test_priority_variants_exist() {
+        // Ensure all variants can be constructed
+        let _ = Priority::RealTime;
+        let _ = Priority::Interactive;
+        let _ = Priority::Data;
+        let _ = Priority::Background;
+    }
+
+    #[test]
+    fn test_congestion_control_copy() {
+        let cc = CongestionControl::Drop;
+        let cc_copy = cc; // Copy
+        assert!(matches!(cc_copy, CongestionControl::Drop));
+    }
+
+    // ============================================================================
+    // AsterixSample Tests
+    // ============================================================================
+
+    #[test]
+    fn test_asterix_sample_clone() {
+        let sample = AsterixSample {
+            category: 48,
+            sac: Some(1),
+            sic: Some(2),
+            data: vec![0x30, 0x00, 0x10],
+            timestamp: 123456,
+            key_expr: "asterix/48/1/2".to_string(),
+            encoding: PayloadFormat::Raw,
+        };
+
+        let cloned = sample.clone();
+        assert_eq!(sample.category, cloned.category);
+        assert_eq!(sample.sac, cloned.sac);
+        assert_eq!(sample.sic, cloned.sic);
+        assert_eq!(sample.data, cloned.data);
+        assert_eq!(sample.timestamp, cloned.timestamp);
+        assert_eq!(sample.key_expr, cloned.key_expr);
+    }
+
+    #[test]
+    fn test_asterix_sample_debug() {
+        let sample = AsterixSample {
+            category: 62,
+            sac: None,
+            sic: None,
+            data: vec![0x3E],
+            timestamp: 0,
+            key_expr: "asterix/62".to_string(),
+            encoding: PayloadFormat::Raw,
+        };
+
+        let debug_str = format!("{sample:?}");
+        assert!(debug_str.contains("62"));
+        assert!(debug_str.contains("AsterixSample"));
+    }
+
+    // ============================================================================
+    // TransportMetrics / BoundedSampleQueue Tests
+    // ============================================================================
+
+    fn test_sample(category: u8) -> AsterixSample {
+        AsterixSample {
+            category,
+            sac: Some(1),
+            sic: Some(2),
+            data: vec![0x30, 0x00, 0x10],
+            timestamp: 0,
+            key_expr: format!("asterix/{category}/1/2"),
+            encoding: PayloadFormat::Raw,
+        }
+    }
+
+    #[test]
+    fn test_overflow_policy_default_is_block() {
+        assert_eq!(OverflowPolicy::default(), OverflowPolicy::Block);
+    }
+
+    #[test]
+    fn test_transport_metrics_counters_start_at_zero() {
+        let metrics = TransportMetrics::new();
+        assert_eq!(metrics.messages_published(), 0);
+        assert_eq!(metrics.bytes_published(), 0);
+        assert_eq!(metrics.messages_received(), 0);
+        assert_eq!(metrics.decode_failures(), 0);
+        assert_eq!(metrics.dropped_samples(), 0);
+    }
+
+    #[test]
+    fn test_transport_metrics_record_publish_accumulates() {
+        let metrics = TransportMetrics::new();
+        metrics.record_publish(100, std::time::Duration::from_micros(10));
+        metrics.record_publish(200, std::time::Duration::from_micros(20));
+        assert_eq!(metrics.messages_published(), 2);
+        assert_eq!(metrics.bytes_published(), 300);
+    }
+
+    #[test]
+    fn test_transport_metrics_render_prometheus_contains_expected_metrics() {
+        let metrics = TransportMetrics::new();
+        metrics.record_publish(128, std::time::Duration::from_micros(42));
+        metrics.record_received();
+        metrics.record_decode_failure();
+        metrics.record_dropped();
+
+        let text = metrics.render_prometheus();
+        assert!(text.contains("asterix_zenoh_messages_published_total 1"));
+        assert!(text.contains("asterix_zenoh_bytes_published_total 128"));
+        assert!(text.contains("asterix_zenoh_messages_received_total 1"));
+        assert!(text.contains("asterix_zenoh_decode_failures_total 1"));
+        assert!(text.contains("asterix_zenoh_dropped_samples_total 1"));
+        assert!(text.contains("asterix_zenoh_publish_latency_microseconds_bucket"));
+        assert!(text.contains("asterix_zenoh_publish_latency_microseconds_sum 42"));
+        assert!(text.contains("asterix_zenoh_publish_latency_microseconds_count 1"));
+    }
+
+    #[test]
+    fn test_bounded_sample_queue_drop_newest_keeps_oldest() {
+        let queue = BoundedSampleQueue::new(2);
+        assert!(matches!(
+            queue.push(test_sample(1), OverflowPolicy::DropNewest),
+            PushOutcome::Enqueued
+        ));
+        assert!(matches!(
+            queue.push(test_sample(2), OverflowPolicy::DropNewest),
+            PushOutcome::Enqueued
+        ));
+        assert!(matches!(
+            queue.push(test_sample(3), OverflowPolicy::DropNewest),
+            PushOutcome::Dropped
+        ));
+
+        assert_eq!(queue.try_recv().unwrap().category, 1);
+        assert_eq!(queue.try_recv().unwrap().category, 2);
+        assert!(queue.try_recv().is_none());
+    }
+
+    #[test]
+    fn test_bounded_sample_queue_drop_oldest_keeps_newest() {
+        let queue = BoundedSampleQueue::new(2);
+        assert!(matches!(
+            queue.push(test_sample(1), OverflowPolicy::DropOldest),
+            PushOutcome::Enqueued
+        ));
+        assert!(matches!(
+            queue.push(test_sample(2), OverflowPolicy::DropOldest),
+            PushOutcome::Enqueued
+        ));
+        assert!(matches!(
+            queue.push(test_sample(3), OverflowPolicy::DropOldest),
+            PushOutcome::Dropped
+        ));
+
+        assert_eq!(queue.try_recv().unwrap().category, 2);
+        assert_eq!(queue.try_recv().unwrap().category, 3);
+        assert!(queue.try_recv().is_none());
+    }
+
+    #[test]
+    fn test_bounded_sample_queue_block_reports_full_and_returns_sample() {
+        let queue = BoundedSampleQueue::new(1);
+        assert!(matches!(
+            queue.push(test_sample(1), OverflowPolicy::Block),
+            PushOutcome::Enqueued
+        ));
+        match queue.push(test_sample(2), OverflowPolicy::Block) {
+            PushOutcome::Full(sample) => assert_eq!(sample.category, 2),
+            _ => panic!("expected Full under Block policy at capacity"),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_bounded_sample_queue_poll_send_completes_once_room_frees() {
+        let queue = Arc::new(BoundedSampleQueue::new(1));
+        queue.push(test_sample(1), OverflowPolicy::Block);
+
+        let mut pending = Some(test_sample(2));
+        let waiter_queue = Arc::clone(&queue);
+        let waiter = tokio::spawn(async move {
+            std::future::poll_fn(|cx| waiter_queue.poll_send(&mut pending, cx)).await;
+        });
+
+        // Give the waiter task a chance to register its waker before freeing room.
+        tokio::task::yield_now().await;
+        assert_eq!(queue.try_recv().unwrap().category, 1);
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), waiter)
+            .await
+            .expect("poll_send should complete once room frees")
+            .unwrap();
+
+        assert_eq!(queue.try_recv().unwrap().category, 2);
+    }
+
+    // ============================================================================
+    // Async Integration Tests for Publisher/Subscriber functions
+    // These test the internal helper functions through the public API
+    // ============================================================================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_publish_record() {
+        use crate::types::AsterixRecord;
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return, // Skip if Zenoh unavailable
+        };
+
+        // Create a minimal AsterixRecord
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30000A".to_string(), // Valid hex data
+            items: ItemMap::new(),
+            crc: 0,
+        };
+
+        // This exercises: publish(), build_key_expr(), extract_sac_sic(), serialize_record(), hex_to_bytes()
+        let result = publisher.publish(&record).await;
+        assert!(
+            result.is_ok(),
+            "Failed to publish record: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_metrics_track_successful_publishes() {
+        use crate::types::AsterixRecord;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return, // Skip if Zenoh unavailable
+        };
+
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30000A".to_string(),
+            items: ItemMap::new(),
+            crc: 0,
+        };
+
+        assert_eq!(publisher.metrics().messages_published(), 0);
+
+        publisher.publish(&record).await.expect("publish failed");
+
+        let metrics = publisher.metrics();
+        assert_eq!(metrics.messages_published(), 1);
+        assert!(metrics.bytes_published() > 0);
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_publish_record_with_sac_sic() {
+        use crate::types::{AsterixRecord, DataItem, ParsedValue};
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Create record with I048/010 containing SAC/SIC
+        let mut items = BTreeMap::new();
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(1));
+        fields.insert("SIC".to_string(), ParsedValue::Integer(2));
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: Some("Data Source Identifier".to_string()),
+                fields,
+            },
+        );
+
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30000A".to_string(),
+            items,
+            crc: 0,
+        };
+
+        // This exercises build_key_expr with SAC/SIC extraction
+        let result = publisher.publish(&record).await;
+        assert!(result.is_ok());
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_publish_record_empty_hex() {
+        use crate::types::AsterixRecord;
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Record with empty hex_data - will fallback to serde JSON serialization
+        let record = AsterixRecord {
+            category: 62,
+            length: 5,
+            timestamp_ms: 12345,
+            hex_data: String::new(), // Empty - triggers JSON serialization path
+            items: ItemMap::new(),
+            crc: 0,
+        };
+
+        let result = publisher.publish(&record).await;
+        // With serde feature enabled, this should succeed using JSON serialization
+        assert!(result.is_ok(), "Failed with empty hex: {:?}", result.err());
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_with_router_config() {
+        // Test with router endpoint (exercises config.endpoints branch)
+        let config = ZenohConfig::with_router("tcp/127.0.0.1:7447");
+
+        // This will likely fail to connect, but exercises the endpoint config path
+        let result = ZenohPublisher::new(config).await;
+
+        // Either connects or fails gracefully
+        match result {
+            Ok(p) => {
+                let _ = p.close().await;
+            }
+            Err(e) => {
+                // Expected - no router running
+                assert!(e.to_string().contains("session") || !e.to_string().is_empty());
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_recv_timeout() {
+        use std::time::Duration;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/test/**").await {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+
+        // Try to receive with short timeout (no data expected)
+        let result = tokio::time::timeout(Duration::from_millis(100), subscriber.recv()).await;
+
+        // Should timeout since no publisher is sending
+        assert!(result.is_err() || result.unwrap().is_none());
+
+        let _ = subscriber.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_pubsub_roundtrip() {
+        use std::time::Duration;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/99/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        // Allow subscription to establish
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Publish data
+        let test_data = vec![0x63, 0x00, 0x05, 0xAB, 0xCD]; // Category 99 test data
+        publisher
+            .publish_raw_with_routing(99, 10, 20, &test_data)
+            .await
+            .unwrap();
+
+        // Receive with timeout
+        let result = tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await;
+
+        if let Ok(Some(sample)) = result {
+            assert_eq!(sample.category, 99);
+            assert_eq!(sample.sac, Some(10));
+            assert_eq!(sample.sic, Some(20));
+            assert_eq!(sample.data, test_data);
+        }
+
+        let _ = publisher.close().await;
+        let _ = subscriber.close().await;
+    }
+
+    // ============================================================================
+    // Error Handling Tests
+    // ============================================================================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_publish_invalid_hex_odd_length() {
+        use crate::types::AsterixRecord;
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Record with odd-length hex string (invalid)
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30000".to_string(), // 5 chars - odd length, should fail
+            items: ItemMap::new(),
+            crc: 0,
+        };
+
+        let result = publisher.publish(&record).await;
+        // This should fail due to invalid hex length
+        assert!(result.is_err(), "Should fail with odd-length hex");
+
+        if let Err(e) = result {
+            assert!(
+                e.to_string().contains("hex") || e.to_string().contains("Serialization"),
+                "Error should mention hex or serialization: {e}"
+            );
+        }
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_publish_invalid_hex_chars() {
+        use crate::types::AsterixRecord;
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Record with invalid hex characters
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "GHIJ".to_string(), // Invalid hex chars
+            items: ItemMap::new(),
+            crc: 0,
+        };
+
+        let result = publisher.publish(&record).await;
+        // This should fail due to invalid hex characters
+        assert!(result.is_err(), "Should fail with invalid hex chars");
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_publish_hex_with_whitespace() {
+        use crate::types::AsterixRecord;
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Record with whitespace in hex (should be handled correctly)
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30 00 0A".to_string(), // Valid hex with spaces
+            items: ItemMap::new(),
+            crc: 0,
+        };
+
+        let result = publisher.publish(&record).await;
+        // Should succeed - whitespace is stripped
+        assert!(
+            result.is_ok(),
+            "Should handle whitespace in hex: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_with_router_config() {
+        // Test subscriber with router endpoint (exercises config.endpoints branch)
+        let config = ZenohConfig::with_router("tcp/127.0.0.1:7447");
+
+        // This will likely fail to connect, but exercises the endpoint config path
+        let result = ZenohSubscriber::new(config, "asterix/**").await;
+
+        // Either connects or fails gracefully
+        match result {
+            Ok(s) => {
+                let _ = s.close().await;
+            }
+            Err(e) => {
+                // Expected - no router running, but we exercised the config path
+                assert!(!e.to_string().is_empty());
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_with_multiple_endpoints() {
+        // Test subscriber with multiple endpoints
+        let config = ZenohConfig::with_endpoints(vec![
+            "tcp/127.0.0.1:7447".to_string(),
+            "tcp/127.0.0.1:7448".to_string(),
+        ]);
+
+        let result = ZenohSubscriber::new(config, "asterix/**").await;
+
+        match result {
+            Ok(s) => {
+                let _ = s.close().await;
+            }
+            Err(e) => {
+                // Expected if routers not running
+                assert!(!e.to_string().is_empty());
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_close_twice() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Close should succeed
+        let result = publisher.close().await;
+        assert!(result.is_ok(), "First close should succeed");
+
+        // Note: Can't close twice as close() consumes self
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_close() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let subscriber = match ZenohSubscriber::new(config, "asterix/**").await {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+
+        // Close should succeed
+        let result = subscriber.close().await;
+        assert!(result.is_ok(), "Subscriber close should succeed");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_raw_with_routing_success() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Test publish_raw_with_routing
+        let result = publisher
+            .publish_raw_with_routing(48, 1, 2, &[0x30, 0x00, 0x10])
+            .await;
+        assert!(
+            result.is_ok(),
+            "publish_raw_with_routing failed: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    #[test]
+    fn test_zenoh_error_variants_complete() {
+        // Test all ZenohError variants have proper Display implementations
+        let errors = vec![
+            ZenohError::SessionError("session error".to_string()),
+            ZenohError::PublisherError("publisher error".to_string()),
+            ZenohError::SubscriberError("subscriber error".to_string()),
+            ZenohError::PublishError("publish error".to_string()),
+            ZenohError::ReceiveError("receive error".to_string()),
+            ZenohError::SerializationError("serialization error".to_string()),
+            ZenohError::ChannelClosed,
+        ];
+
+        for err in errors {
+            let display = format!("{err}");
+            let debug = format!("{err:?}");
+            assert!(!display.is_empty(), "Display should not be empty");
+            assert!(!debug.is_empty(), "Debug should not be empty");
+        }
+    }
+
+    #[test]
+    fn test_zenoh_error_is_std_error() {
+        let err: Box<dyn std::error::Error> =
+            Box::new(ZenohError::SessionError("test".to_string()));
+        assert!(err.to_string().contains("test"));
+    }
+
+    // ============================================================================
+    // End-to-End Tests with Real ASTERIX Data
+    // ============================================================================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_e2e_publish_real_asterix_record() {
+        use crate::types::{AsterixRecord, DataItem, ParsedValue};
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Create a realistic CAT048 record structure
+        let mut items = BTreeMap::new();
+
+        // I048/010 - Data Source Identifier
+        let mut fields_010 = BTreeMap::new();
+        fields_010.insert("SAC".to_string(), ParsedValue::Integer(25));
+        fields_010.insert("SIC".to_string(), ParsedValue::Integer(100));
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: Some("Data Source Identifier".to_string()),
+                fields: fields_010,
+            },
+        );
+
+        // I048/140 - Time of Day
+        let mut fields_140 = BTreeMap::new();
+        fields_140.insert("ToD".to_string(), ParsedValue::Float(43200.5)); // 12:00:00.5
+        items.insert(
+            "I048/140".to_string(),
+            DataItem {
+                description: Some("Time of Day".to_string()),
+                fields: fields_140,
+            },
+        );
+
+        // I048/020 - Target Report Descriptor
+        let mut fields_020 = BTreeMap::new();
+        fields_020.insert(
+            "TYP".to_string(),
+            ParsedValue::String("Single SSR".to_string()),
+        );
+        fields_020.insert("SIM".to_string(), ParsedValue::Boolean(false));
+        items.insert(
+            "I048/020".to_string(),
+            DataItem {
+                description: Some("Target Report Descriptor".to_string()),
+                fields: fields_020,
+            },
+        );
+
+        let record = AsterixRecord {
+            category: 48,
+            length: 25,
+            timestamp_ms: 1700000000000,
+            hex_data: "300019F8250164".to_string(), // Sample CAT048 hex
+            items,
+            crc: 0xABCD1234,
+        };
+
+        // Publish should succeed
+        let result = publisher.publish(&record).await;
+        assert!(result.is_ok(), "E2E publish failed: {:?}", result.err());
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_e2e_pubsub_with_real_asterix() {
+        use crate::types::{AsterixRecord, DataItem, ParsedValue};
+        use std::collections::BTreeMap;
+        use std::time::Duration;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        // Create publisher
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Create subscriber for CAT048
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/48/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        // Wait for subscription to establish
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // Create CAT048 record with SAC/SIC
+        let mut items = BTreeMap::new();
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(5));
+        fields.insert("SIC".to_string(), ParsedValue::Integer(10));
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: Some("Data Source Identifier".to_string()),
+                fields,
+            },
+        );
+
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30000A050A".to_string(),
+            items,
+            crc: 0,
+        };
+
+        // Publish the record
+        publisher.publish(&record).await.expect("Publish failed");
+
+        // Receive with timeout
+        let result = tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await;
+
+        if let Ok(Some(sample)) = result {
+            // Verify the category is correct
+            assert_eq!(sample.category, 48);
+            // SAC/SIC may or may not be present depending on key parsing
+            // If present, verify correct values
+            if let Some(sac) = sample.sac {
+                assert_eq!(sac, 5, "SAC mismatch");
+            }
+            if let Some(sic) = sample.sic {
+                assert_eq!(sic, 10, "SIC mismatch");
+            }
+            // Data should be the decoded hex
+            assert!(!sample.data.is_empty());
+        }
+
+        let _ = publisher.close().await;
+        let _ = subscriber.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_e2e_multiple_categories() {
+        use std::time::Duration;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Subscribe to all categories
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // Publish multiple categories
+        let categories = vec![
+            (48, vec![0x30, 0x00, 0x05]), // CAT048
+            (62, vec![0x3E, 0x00, 0x05]), // CAT062
+            (65, vec![0x41, 0x00, 0x05]), // CAT065
+        ];
+
+        for (cat, data) in &categories {
+            publisher
+                .publish_raw(*cat, data)
+                .await
+                .expect("Publish failed");
+        }
+
+        // Try to receive multiple samples
+        let mut received_cats = Vec::new();
+        for _ in 0..3 {
+            match tokio::time::timeout(Duration::from_millis(500), subscriber.recv()).await {
+                Ok(Some(sample)) => received_cats.push(sample.category),
+                _ => break,
+            }
+        }
+
+        // Should have received at least some categories
+        // (exact number depends on timing)
+
+        let _ = publisher.close().await;
+        let _ = subscriber.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_category_scoped_subscriber_ignores_other_categories() {
+        use std::time::Duration;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Only subscribe to the CAT048 subtree, not the full "asterix/**" key space.
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/48/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        publisher
+            .publish_raw(62, &[0x3E, 0x00, 0x05])
+            .await
+            .expect("publish failed");
+        publisher
+            .publish_raw(48, &[0x30, 0x00, 0x05])
+            .await
+            .expect("publish failed");
+
+        if let Ok(Some(sample)) =
+            tokio::time::timeout(Duration::from_millis(500), subscriber.recv()).await
+        {
+            // Whatever arrives on this subtree must be CAT048 — CAT062 never
+            // matches the "asterix/48/**" key expression.
+            assert_eq!(sample.category, 48);
+        }
+
+        let _ = publisher.close().await;
+        let _ = subscriber.close().await;
+    }
+
+    // ============================================================================
+    // Connection Failure Tests
+    // ============================================================================
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publisher_connection_to_nonexistent_router() {
+        // Try to connect to a router that definitely doesn't exist
+        // This should fail during session creation
+        let config = ZenohConfig::with_endpoints(vec![
+            "tcp/192.0.2.1:7447".to_string(), // TEST-NET-1, guaranteed unreachable
+        ]);
+
+        let result = ZenohPublisher::new(config).await;
+
+        // This exercises the zenoh::open error path (line 256)
+        // Zenoh may succeed with multicast fallback or fail - both are valid
+        match result {
+            Ok(p) => {
+                // Connected via multicast discovery despite bad endpoint
+                let _ = p.close().await;
+            }
+            Err(e) => {
+                // Failed to connect - expected
+                assert!(!e.to_string().is_empty());
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_connection_to_nonexistent_router() {
+        let config = ZenohConfig::with_endpoints(vec!["tcp/192.0.2.1:7447".to_string()]);
+
+        let result = ZenohSubscriber::new(config, "asterix/**").await;
+
+        match result {
+            Ok(s) => {
+                let _ = s.close().await;
+            }
+            Err(e) => {
+                assert!(!e.to_string().is_empty());
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_config_with_empty_key_prefix() {
+        let config = ZenohConfig {
+            key_prefix: String::new(),
+            ..Default::default()
+        };
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Publishing with empty prefix creates key like "/48" which may fail
+        // depending on Zenoh version - test that it doesn't panic
+        let result = publisher.publish_raw(48, &[0x30, 0x00, 0x05]).await;
+        // Either success or clean error is acceptable
+        if result.is_err() {
+            let err = result.err().unwrap();
+            // Should be a publish error, not a panic
+            assert!(!err.to_string().is_empty());
+        }
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_empty_data() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Publishing empty data should work
+        let result = publisher.publish_raw(48, &[]).await;
+        assert!(
+            result.is_ok(),
+            "Publishing empty data failed: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_large_data() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Publish a large payload (64KB)
+        let large_data = vec![0xAB; 65536];
+        let result = publisher.publish_raw(48, &large_data).await;
+        assert!(
+            result.is_ok(),
+            "Publishing large data failed: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    // ============================================================================
+    // Error Path Coverage Tests (Issue #100)
+    // These tests specifically target uncovered error paths
+    // ============================================================================
+
+    /// Test serialization with empty hex_data triggers JSON serialization path (Line 368)
+    /// Note: With serde enabled and valid data, JSON serialization succeeds
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_serialize_record_json_path() {
+        use crate::types::{AsterixRecord, DataItem, ParsedValue};
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Create record with empty hex_data to trigger JSON serialization path
+        let mut items = BTreeMap::new();
+        let mut fields = BTreeMap::new();
+        fields.insert("test_value".to_string(), ParsedValue::Integer(42));
+        items.insert(
+            "I048/999".to_string(),
+            DataItem {
+                description: Some("Test item".to_string()),
+                fields,
+            },
+        );
+
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 12345,
+            hex_data: String::new(), // Empty hex_data forces serde JSON path
+            items,
+            crc: 0,
+        };
+
+        // With serde feature and valid data, this exercises the JSON serialization path
+        let result = publisher.publish(&record).await;
+        assert!(
+            result.is_ok(),
+            "JSON serialization should succeed: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    /// Test serialization with include_raw_bytes disabled forces JSON path
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_serialize_record_json_path_disabled_raw() {
+        use crate::types::{AsterixRecord, DataItem, ParsedValue};
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig {
+            include_raw_bytes: false, // Force JSON path even with hex_data
+            ..ZenohConfig::peer_to_peer()
+        };
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let mut items = BTreeMap::new();
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(1));
+        fields.insert("SIC".to_string(), ParsedValue::Integer(2));
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30000A".to_string(), // Has hex_data but include_raw_bytes is false
+            items,
+            crc: 0,
+        };
+
+        // With include_raw_bytes=false, should use JSON serialization regardless of hex_data
+        let result = publisher.publish(&record).await;
+        // This actually uses hex_data because the condition is `include_raw_bytes && !hex_data.is_empty()`
+        // So with include_raw_bytes=false, it goes to JSON path
+        assert!(
+            result.is_ok(),
+            "JSON serialization should succeed: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    /// Test publisher error display contains expected text
+    #[test]
+    fn test_publisher_error_display() {
+        let err = ZenohError::PublisherError("test publisher error".to_string());
+        let display = err.to_string();
+        assert!(
+            display.contains("publisher"),
+            "Display should contain 'publisher'"
+        );
+        assert!(
+            display.contains("test publisher error"),
+            "Display should contain message"
+        );
+    }
+
+    /// Test receiver error display
+    #[test]
+    fn test_receive_error_display() {
+        let err = ZenohError::ReceiveError("channel disconnected".to_string());
+        let display = err.to_string();
+        assert!(
+            display.contains("receive"),
+            "Display should contain 'receive'"
+        );
+        assert!(display.contains("channel disconnected"));
+    }
+
+    /// Test hex_to_bytes with edge cases
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_hex_to_bytes_edge_cases() {
+        use crate::types::AsterixRecord;
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Test with tabs and newlines in hex (should be stripped)
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "30\t00\n0A".to_string(),
+            items: ItemMap::new(),
+            crc: 0,
+        };
+
+        let result = publisher.publish(&record).await;
+        assert!(
+            result.is_ok(),
+            "Should handle whitespace in hex: {:?}",
+            result.err()
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    /// Test config clone and debug
+    #[test]
+    fn test_zenoh_config_debug_and_clone() {
+        let config = ZenohConfig {
+            congestion_control: CongestionControl::Drop,
+            priority: Priority::RealTime,
+            ..Default::default()
+        };
+
+        let debug_str = format!("{config:?}");
+        assert!(debug_str.contains("ZenohConfig"));
+        assert!(debug_str.contains("asterix"));
+
+        let cloned = config.clone();
+        assert!(matches!(cloned.congestion_control, CongestionControl::Drop));
+        assert!(matches!(cloned.priority, Priority::RealTime));
+    }
+
+    /// Test priority clone and copy
+    #[test]
+    fn test_priority_clone_copy() {
+        let p1 = Priority::Interactive;
+        let p2 = p1; // Copy
+        let p3 = p1; // Clone
+        assert!(matches!(p2, Priority::Interactive));
+        assert!(matches!(p3, Priority::Interactive));
+    }
+
+    /// Test congestion control debug
+    #[test]
+    fn test_congestion_control_debug() {
+        let cc = CongestionControl::Block;
+        let debug_str = format!("{cc:?}");
+        assert!(debug_str.contains("Block"));
+
+        let cc2 = CongestionControl::Drop;
+        let debug_str2 = format!("{cc2:?}");
+        assert!(debug_str2.contains("Drop"));
+    }
+
+    /// Test AsterixSample with empty data
+    #[test]
+    fn test_asterix_sample_empty_data() {
+        let sample = AsterixSample {
+            category: 0,
+            sac: None,
+            sic: None,
+            data: Vec::new(),
+            timestamp: 0,
+            key_expr: String::new(),
+            encoding: PayloadFormat::Raw,
+        };
+
+        assert!(sample.data.is_empty());
+        assert_eq!(sample.category, 0);
+    }
+
+    /// Test parse_key_expr with edge cases
+    #[test]
+    fn test_parse_key_expr_edge_cases() {
+        // Empty string
+        assert_eq!(parse_key_expr("", "asterix"), (0, None, None));
+
+        // Just prefix
+        assert_eq!(parse_key_expr("asterix", "asterix"), (0, None, None));
+
+        // Prefix with trailing slash
+        assert_eq!(parse_key_expr("asterix/", "asterix"), (0, None, None));
+
+        // Very large category number (overflows u8)
+        assert_eq!(parse_key_expr("asterix/999", "asterix"), (0, None, None));
+
+        // Negative numbers - category fails to parse but SAC/SIC still parsed
+        assert_eq!(
+            parse_key_expr("asterix/-1/1/2", "asterix"),
+            (0, Some(1), Some(2))
+        );
+
+        // With extra path segments
+        assert_eq!(
+            parse_key_expr("asterix/48/1/2/extra", "asterix"),
+            (48, Some(1), Some(2))
+        );
+    }
+
+    /// Test extract_sac_sic with different item ID formats
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_extract_sac_sic_edge_cases() {
+        use crate::types::{AsterixRecord, DataItem, ParsedValue};
+        use std::collections::BTreeMap;
+
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        // Test with missing SAC field (only SIC present)
+        let mut items = BTreeMap::new();
+        let mut fields = BTreeMap::new();
+        fields.insert("SIC".to_string(), ParsedValue::Integer(5));
+        // No SAC field
+        items.insert(
+            "I048/010".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: "300005".to_string(),
+            items,
+            crc: 0,
+        };
+
+        // Should still publish successfully, just without full routing
+        let result = publisher.publish(&record).await;
+        assert!(result.is_ok());
+
+        let _ = publisher.close().await;
+    }
+
+    // ============================================================================
+    // KeyExprTemplate Tests
+    // ============================================================================
+
+    fn sample_record_with_sac_sic(category: u8, sac: i64, sic: i64) -> crate::types::AsterixRecord {
+        use crate::types::{AsterixRecord, DataItem, ParsedValue};
+        use std::collections::BTreeMap;
+
+        let mut fields = BTreeMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(sac));
+        fields.insert("SIC".to_string(), ParsedValue::Integer(sic));
+        let mut items = BTreeMap::new();
+        items.insert(
+            format!("I{category:03}/010"),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+
+        AsterixRecord {
+            category,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: String::new(),
+            items,
+            crc: 0,
+        }
     }
 
     #[test]
-    fn test_parse_key_expr_with_sac_sic() {
+    fn test_key_expr_template_reproduces_default_scheme() {
+        let template = KeyExprTemplate::new("{category}/{sac}/{sic}");
+        let record = sample_record_with_sac_sic(48, 1, 2);
+        assert_eq!(template.resolve("asterix", &record), "asterix/48/1/2");
+    }
+
+    #[test]
+    fn test_key_expr_template_missing_placeholder_resolves_to_underscore() {
+        let template = KeyExprTemplate::new("{category}/{sac}/{sic}");
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: String::new(),
+            items: BTreeMap::new(),
+            crc: 0,
+        };
+        assert_eq!(template.resolve("asterix", &record), "asterix/48/_/_");
+    }
+
+    #[test]
+    fn test_key_expr_template_generic_item_field_placeholder() {
+        let template = KeyExprTemplate::new("{category}/{I048/010.SAC}");
+        let record = sample_record_with_sac_sic(48, 7, 9);
+        assert_eq!(template.resolve("asterix", &record), "asterix/48/7");
+    }
+
+    #[test]
+    fn test_key_expr_template_sanitizes_slash_in_string_field() {
+        use crate::types::{DataItem, ParsedValue};
+
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "CALLSIGN".to_string(),
+            ParsedValue::String("AB/CD".to_string()),
+        );
+        let mut items = BTreeMap::new();
+        items.insert(
+            "I048/240".to_string(),
+            DataItem {
+                description: None,
+                fields,
+            },
+        );
+        let record = AsterixRecord {
+            category: 48,
+            length: 10,
+            timestamp_ms: 0,
+            hex_data: String::new(),
+            items,
+            crc: 0,
+        };
+
+        let template = KeyExprTemplate::new("{category}/{I048/240.CALLSIGN}");
+        assert_eq!(template.resolve("asterix", &record), "asterix/48/AB_CD");
+    }
+
+    #[test]
+    fn test_key_expr_template_subscribe_expr_wildcards_unpinned_placeholders() {
+        let template = KeyExprTemplate::new("{category}/{sac}/{sic}");
         assert_eq!(
-            parse_key_expr("asterix/62/1/2", "asterix"),
-            (62, Some(1), Some(2))
+            template.subscribe_expr("asterix", &[("category", "48")]),
+            "asterix/48/*/*"
         );
         assert_eq!(
-            parse_key_expr("asterix/65/10/20", "asterix"),
-            (65, Some(10), Some(20))
+            template.subscribe_expr("asterix", &[]),
+            "asterix/*/*/*"
         );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_uses_key_expr_template() {
+        let config = ZenohConfig::peer_to_peer()
+            .with_key_expr_template(KeyExprTemplate::new("custom/{category}/{sac}"));
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let record = sample_record_with_sac_sic(48, 3, 4);
         assert_eq!(
-            parse_key_expr("asterix/48/255/255", "asterix"),
-            (48, Some(255), Some(255))
+            publisher.build_key_expr(&record),
+            "custom/48/3"
+        );
+
+        let _ = publisher.close().await;
+    }
+
+    /// Test publish with non-standard category numbers
+    // ============================================================================
+    // ZenohQueryable / query_history Tests
+    // ============================================================================
+
+    #[test]
+    fn test_parse_selector_components_exact() {
+        assert_eq!(
+            parse_selector_components("asterix/48/1/2", "asterix"),
+            [Some(48), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_parse_selector_components_double_wildcard() {
+        assert_eq!(
+            parse_selector_components("asterix/48/**", "asterix"),
+            [Some(48), None, None]
+        );
+    }
+
+    #[test]
+    fn test_parse_selector_components_single_wildcards() {
+        assert_eq!(
+            parse_selector_components("asterix/*/1/*", "asterix"),
+            [None, Some(1), None]
         );
     }
 
-    #[test]
-    fn test_parse_key_expr_partial_routing() {
-        // Only SAC, no SIC
-        assert_eq!(
-            parse_key_expr("asterix/48/1", "asterix"),
-            (48, Some(1), None)
-        );
-    }
+    #[test]
+    fn test_parse_selector_components_category_only() {
+        assert_eq!(
+            parse_selector_components("asterix/62", "asterix"),
+            [Some(62), None, None]
+        );
+    }
+
+    #[test]
+    fn test_sample_matches_selector_category_wildcard() {
+        let sample = AsterixSample {
+            category: 48,
+            sac: Some(1),
+            sic: Some(2),
+            data: vec![],
+            timestamp: 0,
+            key_expr: "asterix/48/1/2".to_string(),
+            encoding: PayloadFormat::Raw,
+        };
+        assert!(sample_matches_selector(&sample, "asterix/**", "asterix"));
+        assert!(sample_matches_selector(&sample, "asterix/48/**", "asterix"));
+        assert!(!sample_matches_selector(&sample, "asterix/62/**", "asterix"));
+    }
+
+    #[test]
+    fn test_sample_matches_selector_sac_sic_constrained() {
+        let sample = AsterixSample {
+            category: 48,
+            sac: Some(1),
+            sic: Some(2),
+            data: vec![],
+            timestamp: 0,
+            key_expr: "asterix/48/1/2".to_string(),
+            encoding: PayloadFormat::Raw,
+        };
+        assert!(sample_matches_selector(&sample, "asterix/*/1/*", "asterix"));
+        assert!(!sample_matches_selector(&sample, "asterix/*/9/*", "asterix"));
+        assert!(sample_matches_selector(&sample, "asterix/48/1/2", "asterix"));
+    }
+
+    #[test]
+    fn test_sample_matches_selector_missing_sample_routing() {
+        // Sample has no SAC/SIC; a selector that requires one shouldn't match.
+        let sample = AsterixSample {
+            category: 48,
+            sac: None,
+            sic: None,
+            data: vec![],
+            timestamp: 0,
+            key_expr: "asterix/48".to_string(),
+            encoding: PayloadFormat::Raw,
+        };
+        assert!(sample_matches_selector(&sample, "asterix/48/**", "asterix"));
+        assert!(!sample_matches_selector(&sample, "asterix/48/1/*", "asterix"));
+    }
+
+    #[test]
+    fn test_key_matches_selector_agrees_with_sample_matches_selector() {
+        assert!(key_matches_selector("asterix/48/1/2", "asterix/**", "asterix"));
+        assert!(key_matches_selector(
+            "asterix/48/1/2",
+            "asterix/48/**",
+            "asterix"
+        ));
+        assert!(!key_matches_selector(
+            "asterix/48/1/2",
+            "asterix/62/**",
+            "asterix"
+        ));
+        assert!(key_matches_selector(
+            "asterix/48/1/2",
+            "asterix/*/1/*",
+            "asterix"
+        ));
+        assert!(!key_matches_selector(
+            "asterix/48/1/2",
+            "asterix/*/9/*",
+            "asterix"
+        ));
+    }
+
+    #[test]
+    fn test_zenoh_config_default_history_depth_is_zero() {
+        let config = ZenohConfig::default();
+        assert_eq!(config.history_depth, 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_queryable_history_len_starts_empty() {
+        let config = ZenohConfig {
+            history_depth: 10,
+            ..ZenohConfig::peer_to_peer()
+        };
+
+        let queryable = match ZenohQueryable::new(config, "asterix/test/**").await {
+            Ok(q) => q,
+            Err(_) => return,
+        };
+
+        assert_eq!(queryable.history_len("asterix/48/1/2"), 0);
+
+        let _ = queryable.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_then_query_history_roundtrip() {
+        use std::time::Duration;
+
+        let config = ZenohConfig {
+            history_depth: 10,
+            ..ZenohConfig::peer_to_peer()
+        };
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let queryable = match ZenohQueryable::new(config.clone(), "asterix/77/**").await {
+            Ok(q) => q,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        // Allow the queryable's subscriber to establish.
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let test_data = vec![0x4d, 0x00, 0x05, 0xAB, 0xCD];
+        publisher
+            .publish_raw_with_routing(77, 3, 4, &test_data)
+            .await
+            .unwrap();
+
+        // Allow the published sample to land in the queryable's history.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let history = query_history(&config, "asterix/77/**").await;
+        if let Ok(samples) = history {
+            if let Some(sample) = samples.into_iter().find(|s| s.category == 77) {
+                assert_eq!(sample.sac, Some(3));
+                assert_eq!(sample.sic, Some(4));
+                assert_eq!(sample.data, test_data);
+            }
+        }
+
+        let _ = publisher.close().await;
+        let _ = queryable.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_queryable_ignores_history_when_depth_zero() {
+        use std::time::Duration;
+
+        let config = ZenohConfig::peer_to_peer(); // history_depth: 0 by default
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let queryable = match ZenohQueryable::new(config.clone(), "asterix/88/**").await {
+            Ok(q) => q,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
 
-    #[test]
-    fn test_parse_key_expr_invalid_category() {
-        // Invalid category returns 0
-        assert_eq!(
-            parse_key_expr("asterix/invalid", "asterix"),
-            (0, None, None)
-        );
-        assert_eq!(parse_key_expr("asterix/", "asterix"), (0, None, None));
-    }
+        tokio::time::sleep(Duration::from_millis(100)).await;
 
-    #[test]
-    fn test_parse_key_expr_different_prefix() {
-        // Using different prefix
-        assert_eq!(
-            parse_key_expr("custom/48/1/2", "custom"),
-            (48, Some(1), Some(2))
-        );
-        assert_eq!(
-            parse_key_expr("atm/surveillance/62", "atm/surveillance"),
-            (62, None, None)
-        );
-    }
+        publisher
+            .publish_raw(88, &[0x58, 0x00, 0x05])
+            .await
+            .unwrap();
 
-    #[test]
-    fn test_parse_key_expr_no_prefix() {
-        // If key doesn't start with prefix, parse from beginning
-        assert_eq!(parse_key_expr("48/1/2", "asterix"), (48, Some(1), Some(2)));
-    }
+        tokio::time::sleep(Duration::from_millis(200)).await;
 
-    // ============================================================================
-    // ZenohConfig Tests
-    // ============================================================================
+        assert_eq!(queryable.history_len("asterix/88"), 0);
 
-    #[test]
-    fn test_zenoh_config_default() {
-        let config = ZenohConfig::default();
-        assert!(config.endpoints.is_empty());
-        assert_eq!(config.key_prefix, "asterix");
-        assert!(config.include_raw_bytes);
-        assert!(matches!(
-            config.congestion_control,
-            CongestionControl::Block
-        ));
-        assert!(matches!(config.priority, Priority::Data));
+        let _ = publisher.close().await;
+        let _ = queryable.close().await;
     }
 
     #[test]
-    fn test_zenoh_config_with_router() {
-        let config = ZenohConfig::with_router("tcp/192.168.1.1:7447");
-        assert_eq!(config.endpoints.len(), 1);
-        assert_eq!(config.endpoints[0], "tcp/192.168.1.1:7447");
-        assert_eq!(config.key_prefix, "asterix"); // Should preserve defaults
+    fn test_zenoh_config_default_history_max_age_is_zero() {
+        let config = ZenohConfig::default();
+        assert_eq!(config.history_max_age_secs, 0);
     }
 
-    #[test]
-    fn test_zenoh_config_with_multiple_endpoints() {
-        let endpoints = vec![
-            "tcp/10.0.0.1:7447".to_string(),
-            "tcp/10.0.0.2:7447".to_string(),
-            "udp/10.0.0.3:7448".to_string(),
-        ];
-        let config = ZenohConfig::with_endpoints(endpoints.clone());
-        assert_eq!(config.endpoints, endpoints);
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_queryable_prunes_samples_older_than_history_max_age() {
+        use std::time::Duration;
+
+        let config = ZenohConfig {
+            history_depth: 10,
+            history_max_age_secs: 1,
+            ..ZenohConfig::peer_to_peer()
+        };
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let queryable = match ZenohQueryable::new(config.clone(), "asterix/99/**").await {
+            Ok(q) => q,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        publisher
+            .publish_raw_with_routing(99, 1, 2, &[0x63, 0x00, 0x05])
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(queryable.history_len("asterix/99/1/2"), 1);
+
+        // Wait past the configured max age; the background task's next
+        // prune pass should drop the now-stale sample.
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        publisher
+            .publish_raw_with_routing(99, 3, 4, &[0x63, 0x00, 0x05])
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert_eq!(queryable.history_len("asterix/99/1/2"), 0);
+
+        let _ = publisher.close().await;
+        let _ = queryable.close().await;
     }
 
     #[test]
-    fn test_zenoh_config_peer_to_peer() {
-        let config = ZenohConfig::peer_to_peer();
-        assert!(config.endpoints.is_empty());
+    fn test_query_target_defaults_to_all() {
+        assert_eq!(QueryTarget::default(), QueryTarget::All);
     }
 
-    #[test]
-    fn test_zenoh_config_clone() {
-        let config = ZenohConfig::with_router("tcp/10.0.0.1:7447");
-        let cloned = config.clone();
-        assert_eq!(config.endpoints, cloned.endpoints);
-        assert_eq!(config.key_prefix, cloned.key_prefix);
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_query_client_get_retrieves_published_sample() {
+        use std::time::Duration;
+
+        let config = ZenohConfig {
+            history_depth: 10,
+            ..ZenohConfig::peer_to_peer()
+        };
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let queryable = match ZenohQueryable::new(config.clone(), "asterix/66/**").await {
+            Ok(q) => q,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        let client = match ZenohQueryClient::new(&config).await {
+            Ok(c) => c,
+            Err(_) => {
+                let _ = publisher.close().await;
+                let _ = queryable.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let test_data = vec![0x42, 0x00, 0x05, 0xAB, 0xCD];
+        publisher
+            .publish_raw_with_routing(66, 5, 6, &test_data)
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        if let Ok(samples) = client.get("asterix/66/**", QueryTarget::All).await {
+            if let Some(sample) = samples.into_iter().find(|s| s.category == 66) {
+                assert_eq!(sample.sac, Some(5));
+                assert_eq!(sample.sic, Some(6));
+                assert_eq!(sample.data, test_data);
+            }
+        }
+
+        let _ = publisher.close().await;
+        let _ = queryable.close().await;
+        let _ = client.close().await;
     }
 
-    // ============================================================================
-    // ZenohError Tests
-    // ============================================================================
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_query_client_query_with_time_range_is_ordered_by_timestamp() {
+        use std::time::Duration;
 
-    #[test]
-    fn test_zenoh_error_display_variants() {
-        let errors = vec![
-            (ZenohError::SessionError("test".to_string()), "session"),
-            (ZenohError::PublisherError("test".to_string()), "publisher"),
-            (
-                ZenohError::SubscriberError("test".to_string()),
-                "subscriber",
-            ),
-            (ZenohError::PublishError("test".to_string()), "publish"),
-            (ZenohError::ReceiveError("test".to_string()), "receive"),
-            (
-                ZenohError::SerializationError("test".to_string()),
-                "Serialization",
-            ),
-            (ZenohError::ChannelClosed, "closed"),
-        ];
+        let config = ZenohConfig {
+            history_depth: 10,
+            ..ZenohConfig::peer_to_peer()
+        };
 
-        for (err, expected_substr) in errors {
-            let display = err.to_string();
-            assert!(
-                display
-                    .to_lowercase()
-                    .contains(&expected_substr.to_lowercase()),
-                "Expected '{}' to contain '{}': got '{}'",
-                stringify!(err),
-                expected_substr,
-                display
-            );
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let queryable = match ZenohQueryable::new(config.clone(), "asterix/68/**").await {
+            Ok(q) => q,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        let client = match ZenohQueryClient::new(&config).await {
+            Ok(c) => c,
+            Err(_) => {
+                let _ = publisher.close().await;
+                let _ = queryable.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        publisher
+            .publish_raw_with_routing(68, 1, 1, &[0x01])
+            .await
+            .unwrap();
+        publisher
+            .publish_raw_with_routing(68, 1, 2, &[0x02])
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // A window that can't possibly contain "now" must return nothing.
+        if let Ok(samples) = client.query("asterix/68/**", Some((1, 2)), QueryTarget::All).await {
+            assert!(samples.is_empty());
+        }
+
+        if let Ok(samples) = client.query("asterix/68/**", None, QueryTarget::All).await {
+            let timestamps: Vec<u64> = samples.iter().map(|s| s.timestamp).collect();
+            let mut sorted = timestamps.clone();
+            sorted.sort_unstable();
+            assert_eq!(timestamps, sorted);
         }
+
+        let _ = publisher.close().await;
+        let _ = queryable.close().await;
+        let _ = client.close().await;
     }
 
     #[test]
-    fn test_zenoh_error_debug() {
-        let err = ZenohError::SessionError("debug test".to_string());
-        let debug_str = format!("{err:?}");
-        assert!(debug_str.contains("SessionError"));
-        assert!(debug_str.contains("debug test"));
+    fn test_parse_time_range_roundtrips_through_selector_with_time_range() {
+        let selector = selector_with_time_range("asterix/48/**", Some((100, 200)));
+        assert_eq!(selector, "asterix/48/**?_time=[100..200]");
+
+        let params = selector.split_once('?').unwrap().1;
+        let raw = params.strip_prefix("_time=").unwrap();
+        assert_eq!(parse_time_range(raw), Some((100, 200)));
     }
 
     #[test]
-    fn test_zenoh_error_to_asterix_error() {
-        use crate::error::AsterixError;
+    fn test_selector_with_time_range_is_unchanged_when_no_range_given() {
+        assert_eq!(
+            selector_with_time_range("asterix/48/**", None),
+            "asterix/48/**"
+        );
+    }
 
-        let zenoh_err = ZenohError::PublishError("publish failed".to_string());
-        let asterix_err: AsterixError = zenoh_err.into();
+    #[test]
+    fn test_parse_time_range_rejects_malformed_input() {
+        assert_eq!(parse_time_range("100..200"), None); // missing brackets
+        assert_eq!(parse_time_range("[100-200]"), None); // missing ".."
+        assert_eq!(parse_time_range("[abc..200]"), None); // non-numeric
+    }
 
-        match asterix_err {
-            AsterixError::IOError(msg) => {
-                assert!(msg.contains("publish"));
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_query_client_query_with_limit_caps_reply_count() {
+        use std::time::Duration;
+
+        let config = ZenohConfig {
+            history_depth: 10,
+            ..ZenohConfig::peer_to_peer()
+        };
+
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let queryable = match ZenohQueryable::new(config.clone(), "asterix/69/**").await {
+            Ok(q) => q,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
             }
-            _ => panic!("Expected IOError variant"),
+        };
+
+        let client = match ZenohQueryClient::new(&config).await {
+            Ok(c) => c,
+            Err(_) => {
+                let _ = publisher.close().await;
+                let _ = queryable.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        for i in 0..5u8 {
+            publisher
+                .publish_raw_with_routing(69, 1, 1, &[i])
+                .await
+                .unwrap();
         }
-    }
 
-    // ============================================================================
-    // CongestionControl and Priority Tests
-    // ============================================================================
+        tokio::time::sleep(Duration::from_millis(200)).await;
 
-    #[test]
-    fn test_congestion_control_default() {
-        let cc: CongestionControl = Default::default();
-        assert!(matches!(cc, CongestionControl::Block));
+        if let Ok(samples) = client
+            .query_with_limit("asterix/69/**", None, Some(2), QueryTarget::All)
+            .await
+        {
+            assert!(samples.len() <= 2);
+        }
+
+        let _ = publisher.close().await;
+        let _ = queryable.close().await;
+        let _ = client.close().await;
     }
 
     #[test]
-    fn test_priority_default() {
-        let p: Priority = Default::default();
-        assert!(matches!(p, Priority::Data));
+    fn test_selector_with_limit_is_unchanged_when_no_limit_given() {
+        assert_eq!(selector_with_limit("asterix/48/**", None), "asterix/48/**");
     }
 
     #[test]
-    fn test_priority_variants_exist() {
-        // Ensure all variants can be constructed
-        let _ = Priority::RealTime;
-        let _ = Priority::Interactive;
-        let _ = Priority::Data;
-        let _ = Priority::Background;
+    fn test_selector_with_limit_appends_query_param() {
+        assert_eq!(
+            selector_with_limit("asterix/48/**", Some(5)),
+            "asterix/48/**?_limit=5"
+        );
     }
 
     #[test]
-    fn test_congestion_control_copy() {
-        let cc = CongestionControl::Drop;
-        let cc_copy = cc; // Copy
-        assert!(matches!(cc_copy, CongestionControl::Drop));
+    fn test_selector_with_limit_combines_with_existing_time_range_param() {
+        let with_time = selector_with_time_range("asterix/48/**", Some((100, 200)));
+        assert_eq!(
+            selector_with_limit(&with_time, Some(5)),
+            "asterix/48/**?_time=[100..200]&_limit=5"
+        );
     }
 
-    // ============================================================================
-    // AsterixSample Tests
-    // ============================================================================
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_subscriber_query_filters_by_time_range() {
+        use std::time::Duration;
 
-    #[test]
-    fn test_asterix_sample_clone() {
-        let sample = AsterixSample {
-            category: 48,
-            sac: Some(1),
-            sic: Some(2),
-            data: vec![0x30, 0x00, 0x10],
-            timestamp: 123456,
-            key_expr: "asterix/48/1/2".to_string(),
+        let config = ZenohConfig {
+            history_depth: 10,
+            ..ZenohConfig::peer_to_peer()
         };
 
-        let cloned = sample.clone();
-        assert_eq!(sample.category, cloned.category);
-        assert_eq!(sample.sac, cloned.sac);
-        assert_eq!(sample.sic, cloned.sic);
-        assert_eq!(sample.data, cloned.data);
-        assert_eq!(sample.timestamp, cloned.timestamp);
-        assert_eq!(sample.key_expr, cloned.key_expr);
-    }
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
 
-    #[test]
-    fn test_asterix_sample_debug() {
-        let sample = AsterixSample {
-            category: 62,
-            sac: None,
-            sic: None,
-            data: vec![0x3E],
-            timestamp: 0,
-            key_expr: "asterix/62".to_string(),
+        let queryable = match ZenohQueryable::new(config.clone(), "asterix/67/**").await {
+            Ok(q) => q,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
         };
 
-        let debug_str = format!("{sample:?}");
-        assert!(debug_str.contains("62"));
-        assert!(debug_str.contains("AsterixSample"));
+        let subscriber = match ZenohSubscriber::new(config.clone(), "asterix/67/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                let _ = queryable.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        publisher
+            .publish_raw_with_routing(67, 7, 8, &[0x01])
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // A window that can't possibly contain "now" must return nothing.
+        if let Ok(samples) = subscriber.query("asterix/67/**", Some((1, 2))).await {
+            assert!(samples.is_empty());
+        }
+
+        let _ = publisher.close().await;
+        let _ = queryable.close().await;
+        let _ = subscriber.close().await;
     }
 
     // ============================================================================
-    // Async Integration Tests for Publisher/Subscriber functions
-    // These test the internal helper functions through the public API
+    // Low-Latency / Fragmentation Tests
     // ============================================================================
 
+    #[test]
+    fn test_zenoh_config_low_latency_defaults() {
+        let config = ZenohConfig::default();
+        assert!(!config.low_latency);
+        assert_eq!(config.max_payload_size, DEFAULT_MAX_PAYLOAD_SIZE);
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_publish_record() {
-        use crate::types::AsterixRecord;
-        use std::collections::BTreeMap;
+    async fn test_low_latency_rejects_non_default_priority() {
+        let config = ZenohConfig {
+            low_latency: true,
+            priority: Priority::Background,
+            ..ZenohConfig::peer_to_peer()
+        };
 
-        let config = ZenohConfig::peer_to_peer();
+        let err = ZenohPublisher::new(config).await.unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+        assert!(err.to_string().to_lowercase().contains("low_latency"));
+    }
 
-        let publisher = match ZenohPublisher::new(config).await {
-            Ok(p) => p,
-            Err(_) => return, // Skip if Zenoh unavailable
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_low_latency_rejects_non_default_congestion_control() {
+        let config = ZenohConfig {
+            low_latency: true,
+            congestion_control: CongestionControl::Drop,
+            ..ZenohConfig::peer_to_peer()
         };
 
-        // Create a minimal AsterixRecord
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "30000A".to_string(), // Valid hex data
-            items: BTreeMap::new(),
-            crc: 0,
+        let err = ZenohPublisher::new(config).await.unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_low_latency_with_default_qos_is_accepted() {
+        let config = ZenohConfig {
+            low_latency: true,
+            ..ZenohConfig::peer_to_peer()
         };
 
-        // This exercises: publish(), build_key_expr(), extract_sac_sic(), serialize_record(), hex_to_bytes()
-        let result = publisher.publish(&record).await;
-        assert!(
-            result.is_ok(),
-            "Failed to publish record: {:?}",
-            result.err()
+        // Either connects (low_latency accepted) or fails for unrelated network
+        // reasons — what matters is it's not rejected as a ConfigError.
+        if let Err(e) = ZenohPublisher::new(config).await {
+            assert!(!matches!(e, ZenohError::ConfigError(_)));
+        }
+    }
+
+    #[test]
+    fn test_split_frag_suffix_matches_fragment_key() {
+        assert_eq!(
+            split_frag_suffix("asterix/48/1/2/frag/1/3"),
+            ("asterix/48/1/2".to_string(), Some((1, 3)))
         );
+    }
 
-        let _ = publisher.close().await;
+    #[test]
+    fn test_split_frag_suffix_non_fragment_key_is_unchanged() {
+        assert_eq!(
+            split_frag_suffix("asterix/48/1/2"),
+            ("asterix/48/1/2".to_string(), None)
+        );
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_publish_record_with_sac_sic() {
-        use crate::types::{AsterixRecord, DataItem, ParsedValue};
-        use std::collections::BTreeMap;
+    #[test]
+    fn test_split_frag_suffix_rejects_malformed_fragment_suffix() {
+        assert_eq!(
+            split_frag_suffix("asterix/48/frag/notanumber/3"),
+            ("asterix/48/frag/notanumber/3".to_string(), None)
+        );
+        assert_eq!(
+            split_frag_suffix("asterix/48/frag/5/3"), // seq >= total
+            ("asterix/48/frag/5/3".to_string(), None)
+        );
+    }
 
-        let config = ZenohConfig::peer_to_peer();
+    #[test]
+    fn test_fragment_assembly_reassembles_out_of_order() {
+        let mut assembly = FragmentAssembly::new(3);
+        assert_eq!(assembly.insert(2, vec![2]), None);
+        assert_eq!(assembly.insert(0, vec![0]), None);
+        assert_eq!(assembly.insert(1, vec![1]), Some(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn test_fragment_assembly_dedups_repeated_index() {
+        let mut assembly = FragmentAssembly::new(2);
+        assert_eq!(assembly.insert(0, vec![0]), None);
+        // A re-delivery of the same index must not double-count toward
+        // `received`, or the assembly would report complete with a missing
+        // slot still `None`.
+        assert_eq!(assembly.insert(0, vec![0xff]), None);
+        assert_eq!(assembly.insert(1, vec![1]), Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_reassembly_error_display_mentions_fragment() {
+        let err = ZenohError::ReassemblyError("received 1/3 fragments before timeout".to_string());
+        assert!(err.to_string().to_lowercase().contains("reassembly"));
+        assert!(err.to_string().contains("1/3"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_raw_fragments_oversized_payload() {
+        let config = ZenohConfig {
+            max_payload_size: 4,
+            ..ZenohConfig::peer_to_peer()
+        };
 
         let publisher = match ZenohPublisher::new(config).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Create record with I048/010 containing SAC/SIC
-        let mut items = BTreeMap::new();
-        let mut fields = BTreeMap::new();
-        fields.insert("SAC".to_string(), ParsedValue::Integer(1));
-        fields.insert("SIC".to_string(), ParsedValue::Integer(2));
-        items.insert(
-            "I048/010".to_string(),
-            DataItem {
-                description: Some("Data Source Identifier".to_string()),
-                fields,
-            },
-        );
-
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "30000A".to_string(),
-            items,
-            crc: 0,
-        };
-
-        // This exercises build_key_expr with SAC/SIC extraction
-        let result = publisher.publish(&record).await;
-        assert!(result.is_ok());
+        // 10 bytes with a 4-byte max payload size fragments into 3 pieces.
+        let result = publisher.publish_raw(48, &[0u8; 10]).await;
+        assert!(result.is_ok(), "fragmented publish failed: {:?}", result.err());
 
         let _ = publisher.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_publish_record_empty_hex() {
-        use crate::types::AsterixRecord;
-        use std::collections::BTreeMap;
+    async fn test_fragmented_publish_reassembles_on_subscriber() {
+        use std::time::Duration;
 
-        let config = ZenohConfig::peer_to_peer();
+        let config = ZenohConfig {
+            max_payload_size: 4,
+            ..ZenohConfig::peer_to_peer()
+        };
 
-        let publisher = match ZenohPublisher::new(config).await {
+        let publisher = match ZenohPublisher::new(config.clone()).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Record with empty hex_data - will fallback to serde JSON serialization
-        let record = AsterixRecord {
-            category: 62,
-            length: 5,
-            timestamp_ms: 12345,
-            hex_data: String::new(), // Empty - triggers JSON serialization path
-            items: BTreeMap::new(),
-            crc: 0,
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/91/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
         };
 
-        let result = publisher.publish(&record).await;
-        // With serde feature enabled, this should succeed using JSON serialization
-        assert!(result.is_ok(), "Failed with empty hex: {:?}", result.err());
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let payload: Vec<u8> = (0..10).collect();
+        publisher
+            .publish_raw_with_routing(91, 7, 8, &payload)
+            .await
+            .unwrap();
+
+        // Collect up to 3 fragments worth of receives, looking for the fully
+        // reassembled sample (fragment sub-keys don't parse as SAC/SIC, so
+        // only the reassembled sample will carry them).
+        let mut reassembled = None;
+        for _ in 0..5 {
+            match tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await {
+                Ok(Some(sample)) if sample.data == payload => {
+                    reassembled = Some(sample);
+                    break;
+                }
+                Ok(Some(_)) => continue,
+                _ => break,
+            }
+        }
+
+        if let Some(sample) = reassembled {
+            assert_eq!(sample.category, 91);
+            assert_eq!(sample.sac, Some(7));
+            assert_eq!(sample.sic, Some(8));
+            assert_eq!(sample.data, payload);
+        }
 
         let _ = publisher.close().await;
+        let _ = subscriber.close().await;
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_with_router_config() {
-        // Test with router endpoint (exercises config.endpoints branch)
-        let config = ZenohConfig::with_router("tcp/127.0.0.1:7447");
-
-        // This will likely fail to connect, but exercises the endpoint config path
-        let result = ZenohPublisher::new(config).await;
+    // ============================================================================
+    // Batching Tests
+    // ============================================================================
 
-        // Either connects or fails gracefully
-        match result {
-            Ok(p) => {
-                let _ = p.close().await;
-            }
-            Err(e) => {
-                // Expected - no router running
-                assert!(e.to_string().contains("session") || !e.to_string().is_empty());
-            }
-        }
+    #[test]
+    fn test_zenoh_config_default_batch_size_is_one() {
+        let config = ZenohConfig::default();
+        assert_eq!(config.batch_size, 1);
+        assert_eq!(config.linger, std::time::Duration::ZERO);
+    }
+
+    #[test]
+    fn test_encode_decode_batch_roundtrips() {
+        let records = vec![vec![1, 2, 3], vec![], vec![4, 5]];
+        let encoded = encode_batch(&records);
+        assert_eq!(decode_batch(&encoded).unwrap(), records);
+    }
+
+    #[test]
+    fn test_decode_batch_rejects_truncated_length_prefix() {
+        assert!(decode_batch(&[0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_decode_batch_rejects_truncated_record() {
+        // Declares a 10-byte record but only provides 2.
+        let mut data = 10u32.to_be_bytes().to_vec();
+        data.extend_from_slice(&[1, 2]);
+        assert!(decode_batch(&data).is_err());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_subscriber_recv_timeout() {
+    async fn test_batching_publisher_flushes_at_batch_size() {
         use std::time::Duration;
 
-        let config = ZenohConfig::peer_to_peer();
+        let config = ZenohConfig {
+            batch_size: 2,
+            ..ZenohConfig::peer_to_peer()
+        };
 
-        let mut subscriber = match ZenohSubscriber::new(config, "asterix/test/**").await {
-            Ok(s) => s,
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
             Err(_) => return,
         };
 
-        // Try to receive with short timeout (no data expected)
-        let result = tokio::time::timeout(Duration::from_millis(100), subscriber.recv()).await;
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/71/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
 
-        // Should timeout since no publisher is sending
-        assert!(result.is_err() || result.unwrap().is_none());
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let batching = BatchingPublisher::new(publisher);
+        batching.publish_raw(71, &[0xAA]).await.unwrap();
+        // Still below batch_size; nothing should have been published yet.
+        batching.publish_raw(71, &[0xBB]).await.unwrap();
+
+        match tokio::time::timeout(Duration::from_secs(2), subscriber.recv_batch()).await {
+            Ok(Some(Ok(records))) => {
+                assert_eq!(records.len(), 2);
+                assert_eq!(records[0].data, vec![0xAA]);
+                assert_eq!(records[1].data, vec![0xBB]);
+            }
+            _ => {} // Zenoh not actually reachable in this sandbox; skip.
+        }
 
         let _ = subscriber.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_pubsub_roundtrip() {
+    async fn test_batching_publisher_flush_sends_partial_batch() {
         use std::time::Duration;
 
-        let config = ZenohConfig::peer_to_peer();
+        let config = ZenohConfig {
+            batch_size: 10,
+            ..ZenohConfig::peer_to_peer()
+        };
 
         let publisher = match ZenohPublisher::new(config.clone()).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        let mut subscriber = match ZenohSubscriber::new(config, "asterix/99/**").await {
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/72/**").await {
             Ok(s) => s,
             Err(_) => {
                 let _ = publisher.close().await;
@@ -937,542 +6202,716 @@ mod tests {
             }
         };
 
-        // Allow subscription to establish
-        tokio::time::sleep(Duration::from_millis(50)).await;
-
-        // Publish data
-        let test_data = vec![0x63, 0x00, 0x05, 0xAB, 0xCD]; // Category 99 test data
-        publisher
-            .publish_raw_with_routing(99, 10, 20, &test_data)
-            .await
-            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
 
-        // Receive with timeout
-        let result = tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await;
+        let batching = BatchingPublisher::new(publisher);
+        batching.publish_raw(72, &[0x01]).await.unwrap();
+        batching.flush().await.unwrap();
 
-        if let Ok(Some(sample)) = result {
-            assert_eq!(sample.category, 99);
-            assert_eq!(sample.sac, Some(10));
-            assert_eq!(sample.sic, Some(20));
-            assert_eq!(sample.data, test_data);
+        match tokio::time::timeout(Duration::from_secs(2), subscriber.recv_batch()).await {
+            Ok(Some(Ok(records))) => {
+                assert_eq!(records.len(), 1);
+                assert_eq!(records[0].data, vec![0x01]);
+            }
+            _ => {} // Zenoh not actually reachable in this sandbox; skip.
         }
 
-        let _ = publisher.close().await;
         let _ = subscriber.close().await;
     }
 
     // ============================================================================
-    // Error Handling Tests
+    // Stream Tests
     // ============================================================================
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_publish_invalid_hex_odd_length() {
-        use crate::types::AsterixRecord;
-        use std::collections::BTreeMap;
+    async fn test_zenoh_subscriber_stream_yields_published_samples() {
+        use futures_util::StreamExt;
+        use std::time::Duration;
 
         let config = ZenohConfig::peer_to_peer();
 
-        let publisher = match ZenohPublisher::new(config).await {
+        let publisher = match ZenohPublisher::new(config.clone()).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Record with odd-length hex string (invalid)
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "30000".to_string(), // 5 chars - odd length, should fail
-            items: BTreeMap::new(),
-            crc: 0,
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/73/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
         };
 
-        let result = publisher.publish(&record).await;
-        // This should fail due to invalid hex length
-        assert!(result.is_err(), "Should fail with odd-length hex");
+        tokio::time::sleep(Duration::from_millis(100)).await;
 
-        if let Err(e) = result {
-            assert!(
-                e.to_string().contains("hex") || e.to_string().contains("Serialization"),
-                "Error should mention hex or serialization: {e}"
-            );
+        publisher.publish_raw(73, &[0x01]).await.unwrap();
+
+        if let Ok(Some(sample)) =
+            tokio::time::timeout(Duration::from_secs(2), subscriber.next()).await
+        {
+            assert_eq!(sample.category, 73);
+            assert_eq!(sample.data, vec![0x01]);
         }
 
         let _ = publisher.close().await;
+        let _ = subscriber.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_publish_invalid_hex_chars() {
-        use crate::types::AsterixRecord;
-        use std::collections::BTreeMap;
+    async fn test_zenoh_subscriber_stream_pending_when_nothing_buffered() {
+        use futures_util::StreamExt;
 
         let config = ZenohConfig::peer_to_peer();
 
-        let publisher = match ZenohPublisher::new(config).await {
-            Ok(p) => p,
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/74/**").await {
+            Ok(s) => s,
             Err(_) => return,
         };
 
-        // Record with invalid hex characters
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "GHIJ".to_string(), // Invalid hex chars
-            items: BTreeMap::new(),
-            crc: 0,
-        };
+        // Nothing published yet: polling the stream must not resolve.
+        let result = tokio::time::timeout(
+            std::time::Duration::from_millis(200),
+            subscriber.next(),
+        )
+        .await;
+        assert!(result.is_err(), "stream resolved with no sample published");
 
-        let result = publisher.publish(&record).await;
-        // This should fail due to invalid hex characters
-        assert!(result.is_err(), "Should fail with invalid hex chars");
+        let _ = subscriber.close().await;
+    }
 
-        let _ = publisher.close().await;
+    // ============================================================================
+    // Encryption Tests
+    // ============================================================================
+
+    #[test]
+    fn test_zenoh_config_default_encryption_is_none() {
+        assert!(ZenohConfig::default().encryption.is_none());
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_publish_hex_with_whitespace() {
-        use crate::types::AsterixRecord;
-        use std::collections::BTreeMap;
+    #[test]
+    fn test_encrypt_decrypt_payload_roundtrips() {
+        let config = EncryptionConfig::new([0x42; 32]);
+        let plaintext = b"CAT048 surveillance track";
 
-        let config = ZenohConfig::peer_to_peer();
+        let framed = encrypt_payload(&config, 48, "asterix/48/1/2", plaintext).unwrap();
+        assert_eq!(
+            framed.len(),
+            ENCRYPTION_NONCE_LEN + plaintext.len() + ENCRYPTION_TAG_LEN
+        );
 
-        let publisher = match ZenohPublisher::new(config).await {
-            Ok(p) => p,
-            Err(_) => return,
-        };
+        let decrypted = decrypt_payload(&config, 48, "asterix/48/1/2", &framed).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
 
-        // Record with whitespace in hex (should be handled correctly)
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "30 00 0A".to_string(), // Valid hex with spaces
-            items: BTreeMap::new(),
-            crc: 0,
-        };
+    #[test]
+    fn test_encrypt_payload_uses_fresh_nonce_every_call() {
+        let config = EncryptionConfig::new([0x07; 32]);
+        let plaintext = b"same plaintext twice";
 
-        let result = publisher.publish(&record).await;
-        // Should succeed - whitespace is stripped
-        assert!(
-            result.is_ok(),
-            "Should handle whitespace in hex: {:?}",
-            result.err()
+        let first = encrypt_payload(&config, 62, "asterix/62", plaintext).unwrap();
+        let second = encrypt_payload(&config, 62, "asterix/62", plaintext).unwrap();
+
+        assert_ne!(
+            first[..ENCRYPTION_NONCE_LEN],
+            second[..ENCRYPTION_NONCE_LEN],
+            "reused nonce for two messages under the same (key, AAD)"
         );
+        assert_ne!(first, second);
+    }
 
-        let _ = publisher.close().await;
+    #[test]
+    fn test_decrypt_payload_rejects_wrong_category_key() {
+        let config = EncryptionConfig::new([0x11; 32]);
+        let framed = encrypt_payload(&config, 48, "asterix/48", b"plaintext").unwrap();
+
+        // Category 62 derives a different HKDF-expanded key than category 48.
+        let err = decrypt_payload(&config, 62, "asterix/48", &framed).unwrap_err();
+        assert!(matches!(err, ZenohError::DecryptionError(_)));
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_subscriber_with_router_config() {
-        // Test subscriber with router endpoint (exercises config.endpoints branch)
-        let config = ZenohConfig::with_router("tcp/127.0.0.1:7447");
+    #[test]
+    fn test_decrypt_payload_rejects_mismatched_aad() {
+        let config = EncryptionConfig::new([0x22; 32]);
+        let framed = encrypt_payload(&config, 48, "asterix/48/1/2", b"plaintext").unwrap();
 
-        // This will likely fail to connect, but exercises the endpoint config path
-        let result = ZenohSubscriber::new(config, "asterix/**").await;
+        let err = decrypt_payload(&config, 48, "asterix/48/1/3", &framed).unwrap_err();
+        assert!(matches!(err, ZenohError::DecryptionError(_)));
+    }
 
-        // Either connects or fails gracefully
-        match result {
-            Ok(s) => {
-                let _ = s.close().await;
-            }
-            Err(e) => {
-                // Expected - no router running, but we exercised the config path
-                assert!(!e.to_string().is_empty());
-            }
-        }
+    #[test]
+    fn test_decrypt_payload_rejects_tampered_ciphertext() {
+        let config = EncryptionConfig::new([0x33; 32]);
+        let mut framed = encrypt_payload(&config, 48, "asterix/48", b"plaintext").unwrap();
+        let last = framed.len() - 1;
+        framed[last] ^= 0xFF;
+
+        let err = decrypt_payload(&config, 48, "asterix/48", &framed).unwrap_err();
+        assert!(matches!(err, ZenohError::DecryptionError(_)));
+    }
+
+    #[test]
+    fn test_decrypt_payload_rejects_truncated_framed_blob() {
+        let config = EncryptionConfig::new([0x44; 32]);
+
+        let err = decrypt_payload(&config, 48, "asterix/48", &[0u8; 4]).unwrap_err();
+        assert!(matches!(err, ZenohError::DecryptionError(_)));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_subscriber_with_multiple_endpoints() {
-        // Test subscriber with multiple endpoints
-        let config = ZenohConfig::with_endpoints(vec![
-            "tcp/127.0.0.1:7447".to_string(),
-            "tcp/127.0.0.1:7448".to_string(),
-        ]);
+    async fn test_e2e_encrypted_publish_and_subscribe() {
+        use std::time::Duration;
 
-        let result = ZenohSubscriber::new(config, "asterix/**").await;
+        let master_key = [0x99; 32];
+        let config = ZenohConfig::peer_to_peer().with_encryption(master_key);
 
-        match result {
-            Ok(s) => {
-                let _ = s.close().await;
-            }
-            Err(e) => {
-                // Expected if routers not running
-                assert!(!e.to_string().is_empty());
+        let publisher = match ZenohPublisher::new(config.clone()).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/75/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
             }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        publisher.publish_raw(75, &[0xAA, 0xBB]).await.unwrap();
+
+        if let Ok(Some(sample)) =
+            tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await
+        {
+            // The subscriber transparently decrypted the payload back to plaintext.
+            assert_eq!(sample.data, vec![0xAA, 0xBB]);
         }
+
+        let _ = publisher.close().await;
+        let _ = subscriber.close().await;
     }
 
+    // ============================================================================
+    // DecodingSubscriber Tests
+    // ============================================================================
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_close_twice() {
+    async fn test_decoding_subscriber_new_and_close() {
+        use crate::serialized_decoder::SerializedDecoder;
+        use crate::types::ParseOptions;
+
         let config = ZenohConfig::peer_to_peer();
+        let decoder = SerializedDecoder::new();
 
-        let publisher = match ZenohPublisher::new(config).await {
-            Ok(p) => p,
+        let subscriber = match DecodingSubscriber::new(
+            config,
+            "asterix/**",
+            decoder,
+            ParseOptions::default(),
+        )
+        .await
+        {
+            Ok(s) => s,
             Err(_) => return,
         };
 
-        // Close should succeed
-        let result = publisher.close().await;
-        assert!(result.is_ok(), "First close should succeed");
-
-        // Note: Can't close twice as close() consumes self
+        let _ = subscriber.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_subscriber_close() {
-        let config = ZenohConfig::peer_to_peer();
+    async fn test_decoding_subscriber_shares_decoder_across_instances() {
+        use crate::serialized_decoder::SerializedDecoder;
+        use crate::types::ParseOptions;
 
-        let subscriber = match ZenohSubscriber::new(config, "asterix/**").await {
+        let config = ZenohConfig::peer_to_peer();
+        let decoder = SerializedDecoder::new();
+
+        // Two subscribers sharing one decoder handle should both construct
+        // successfully, proving the handle is `Clone` and reusable.
+        let first = match DecodingSubscriber::new(
+            config.clone(),
+            "asterix/48/**",
+            decoder.clone(),
+            ParseOptions::default(),
+        )
+        .await
+        {
             Ok(s) => s,
             Err(_) => return,
         };
 
-        // Close should succeed
-        let result = subscriber.close().await;
-        assert!(result.is_ok(), "Subscriber close should succeed");
+        let second = match DecodingSubscriber::new(
+            config,
+            "asterix/62/**",
+            decoder,
+            ParseOptions {
+                filter_category: Some(62),
+                ..Default::default()
+            },
+        )
+        .await
+        {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = first.close().await;
+                return;
+            }
+        };
+
+        let _ = first.close().await;
+        let _ = second.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publish_raw_with_routing_success() {
+    async fn test_decoding_subscriber_recv_result_surfaces_decode_error() {
+        use crate::serialized_decoder::SerializedDecoder;
+        use crate::types::ParseOptions;
+        use std::time::Duration;
+
         let config = ZenohConfig::peer_to_peer();
+        let decoder = SerializedDecoder::new();
 
-        let publisher = match ZenohPublisher::new(config).await {
+        let publisher = match ZenohPublisher::new(config.clone()).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Test publish_raw_with_routing
-        let result = publisher
-            .publish_raw_with_routing(48, 1, 2, &[0x30, 0x00, 0x10])
-            .await;
-        assert!(
-            result.is_ok(),
-            "publish_raw_with_routing failed: {:?}",
-            result.err()
-        );
+        let mut subscriber = match DecodingSubscriber::new(
+            config,
+            "asterix/200/**",
+            decoder,
+            ParseOptions::default(),
+        )
+        .await
+        {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
 
-        let _ = publisher.close().await;
-    }
+        tokio::time::sleep(Duration::from_millis(100)).await;
 
-    #[test]
-    fn test_zenoh_error_variants_complete() {
-        // Test all ZenohError variants have proper Display implementations
-        let errors = vec![
-            ZenohError::SessionError("session error".to_string()),
-            ZenohError::PublisherError("publisher error".to_string()),
-            ZenohError::SubscriberError("subscriber error".to_string()),
-            ZenohError::PublishError("publish error".to_string()),
-            ZenohError::ReceiveError("receive error".to_string()),
-            ZenohError::SerializationError("serialization error".to_string()),
-            ZenohError::ChannelClosed,
-        ];
+        // Category 200 has no loaded definition, so this payload fails to decode.
+        let garbage = vec![0xFF, 0xFF, 0xFF];
+        publisher.publish_raw(200, &garbage).await.unwrap();
 
-        for err in errors {
-            let display = format!("{err}");
-            let debug = format!("{err:?}");
-            assert!(!display.is_empty(), "Display should not be empty");
-            assert!(!debug.is_empty(), "Debug should not be empty");
+        match tokio::time::timeout(Duration::from_secs(2), subscriber.recv_result()).await {
+            Ok(Some(Err((sample, err)))) => {
+                assert_eq!(sample.data, garbage);
+                assert!(matches!(err, ZenohError::ReceiveError(_)));
+            }
+            Ok(Some(Ok(_))) => panic!("expected a decode failure for an invalid category"),
+            _ => {} // timed out or channel closed; network-dependent, not a failure here
         }
-    }
 
-    #[test]
-    fn test_zenoh_error_is_std_error() {
-        let err: Box<dyn std::error::Error> =
-            Box::new(ZenohError::SessionError("test".to_string()));
-        assert!(err.to_string().contains("test"));
+        let _ = publisher.close().await;
+        let _ = subscriber.close().await;
     }
 
     // ============================================================================
-    // End-to-End Tests with Real ASTERIX Data
+    // ZenohTrackStore Tests
     // ============================================================================
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_e2e_publish_real_asterix_record() {
-        use crate::types::{AsterixRecord, DataItem, ParsedValue};
-        use std::collections::BTreeMap;
+    async fn test_track_store_new_and_close() {
+        use crate::serialized_decoder::SerializedDecoder;
+        use crate::types::ParseOptions;
+        use std::time::Duration;
 
         let config = ZenohConfig::peer_to_peer();
+        let decoder = SerializedDecoder::new();
 
-        let publisher = match ZenohPublisher::new(config).await {
-            Ok(p) => p,
+        let store = match ZenohTrackStore::new(
+            config,
+            "asterix/48/**",
+            decoder,
+            ParseOptions::default(),
+            Duration::from_secs(30),
+        )
+        .await
+        {
+            Ok(s) => s,
             Err(_) => return,
         };
 
-        // Create a realistic CAT048 record structure
-        let mut items = BTreeMap::new();
+        let _ = store.close().await;
+    }
 
-        // I048/010 - Data Source Identifier
-        let mut fields_010 = BTreeMap::new();
-        fields_010.insert("SAC".to_string(), ParsedValue::Integer(25));
-        fields_010.insert("SIC".to_string(), ParsedValue::Integer(100));
-        items.insert(
-            "I048/010".to_string(),
-            DataItem {
-                description: Some("Data Source Identifier".to_string()),
-                fields: fields_010,
-            },
-        );
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_track_store_starts_empty() {
+        use crate::serialized_decoder::SerializedDecoder;
+        use crate::types::ParseOptions;
+        use std::time::Duration;
 
-        // I048/140 - Time of Day
-        let mut fields_140 = BTreeMap::new();
-        fields_140.insert("ToD".to_string(), ParsedValue::Float(43200.5)); // 12:00:00.5
-        items.insert(
-            "I048/140".to_string(),
-            DataItem {
-                description: Some("Time of Day".to_string()),
-                fields: fields_140,
-            },
-        );
+        let config = ZenohConfig::peer_to_peer();
+        let decoder = SerializedDecoder::new();
 
-        // I048/020 - Target Report Descriptor
-        let mut fields_020 = BTreeMap::new();
-        fields_020.insert(
-            "TYP".to_string(),
-            ParsedValue::String("Single SSR".to_string()),
+        let store = match ZenohTrackStore::new(
+            config,
+            "asterix/48/**",
+            decoder,
+            ParseOptions::default(),
+            Duration::from_secs(30),
+        )
+        .await
+        {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+
+        assert_eq!(store.len(), 0);
+        assert!(store.is_empty());
+        assert!(store.get("asterix/48/1/2").is_none());
+
+        let _ = store.close().await;
+    }
+
+    // ============================================================================
+    // ZenohDecodeService / ZenohDecodeClient Tests
+    // ============================================================================
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_decode_strictness_attachment_roundtrip() {
+        assert_eq!(
+            DecodeStrictness::from_attachment(DecodeStrictness::Strict.as_attachment()),
+            Some(DecodeStrictness::Strict)
         );
-        fields_020.insert("SIM".to_string(), ParsedValue::Boolean(false));
-        items.insert(
-            "I048/020".to_string(),
-            DataItem {
-                description: Some("Target Report Descriptor".to_string()),
-                fields: fields_020,
-            },
+        assert_eq!(
+            DecodeStrictness::from_attachment(DecodeStrictness::Lenient.as_attachment()),
+            Some(DecodeStrictness::Lenient)
         );
+        assert_eq!(DecodeStrictness::from_attachment(b"unknown"), None);
+        assert!(!DecodeStrictness::Strict.continue_on_error());
+        assert!(DecodeStrictness::Lenient.continue_on_error());
+    }
 
-        let record = AsterixRecord {
-            category: 48,
-            length: 25,
-            timestamp_ms: 1700000000000,
-            hex_data: "300019F8250164".to_string(), // Sample CAT048 hex
-            items,
-            crc: 0xABCD1234,
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    #[cfg(feature = "serde")]
+    async fn test_decode_service_and_client_new_and_close() {
+        use crate::serialized_decoder::SerializedDecoder;
+        use crate::types::ParseOptions;
+
+        let config = ZenohConfig::peer_to_peer();
+        let decoder = SerializedDecoder::new();
+
+        let service = match ZenohDecodeService::new(
+            config.clone(),
+            "asterix/rpc/decode",
+            decoder,
+            ParseOptions::default(),
+        )
+        .await
+        {
+            Ok(s) => s,
+            Err(_) => return,
         };
 
-        // Publish should succeed
-        let result = publisher.publish(&record).await;
-        assert!(result.is_ok(), "E2E publish failed: {:?}", result.err());
+        let client = match ZenohDecodeClient::new(config, "asterix/rpc/decode").await {
+            Ok(c) => c,
+            Err(_) => {
+                let _ = service.close().await;
+                return;
+            }
+        };
 
-        let _ = publisher.close().await;
+        let _ = client.close().await;
+        let _ = service.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_e2e_pubsub_with_real_asterix() {
-        use crate::types::{AsterixRecord, DataItem, ParsedValue};
-        use std::collections::BTreeMap;
+    #[cfg(feature = "serde")]
+    async fn test_decode_client_roundtrips_records_through_service() {
+        use crate::serialized_decoder::SerializedDecoder;
+        use crate::types::ParseOptions;
         use std::time::Duration;
 
         let config = ZenohConfig::peer_to_peer();
-
-        // Create publisher
-        let publisher = match ZenohPublisher::new(config.clone()).await {
-            Ok(p) => p,
+        let decoder = SerializedDecoder::new();
+
+        let service = match ZenohDecodeService::new(
+            config.clone(),
+            "asterix/rpc/decode",
+            decoder,
+            ParseOptions::default(),
+        )
+        .await
+        {
+            Ok(s) => s,
             Err(_) => return,
         };
 
-        // Create subscriber for CAT048
-        let mut subscriber = match ZenohSubscriber::new(config, "asterix/48/**").await {
-            Ok(s) => s,
+        let client = match ZenohDecodeClient::new(config, "asterix/rpc/decode").await {
+            Ok(c) => c,
             Err(_) => {
-                let _ = publisher.close().await;
+                let _ = service.close().await;
                 return;
             }
         };
 
-        // Wait for subscription to establish
         tokio::time::sleep(Duration::from_millis(100)).await;
 
-        // Create CAT048 record with SAC/SIC
-        let mut items = BTreeMap::new();
-        let mut fields = BTreeMap::new();
-        fields.insert("SAC".to_string(), ParsedValue::Integer(5));
-        fields.insert("SIC".to_string(), ParsedValue::Integer(10));
-        items.insert(
-            "I048/010".to_string(),
-            DataItem {
-                description: Some("Data Source Identifier".to_string()),
-                fields,
-            },
-        );
+        // Not valid ASTERIX bytes, but the point here is exercising the
+        // query/reply round-trip and JSON (de)serialization, not decode
+        // correctness — either an empty/partial record set or a service-side
+        // parse error is an acceptable outcome.
+        let _ = client.decode(&[0x30, 0x00, 0x03]).await;
+        let _ = client
+            .decode_with_strictness(&[0x30, 0x00, 0x03], Some(DecodeStrictness::Lenient))
+            .await;
 
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "30000A050A".to_string(),
-            items,
-            crc: 0,
-        };
+        let _ = client.close().await;
+        let _ = service.close().await;
+    }
 
-        // Publish the record
-        publisher.publish(&record).await.expect("Publish failed");
+    // ============================================================================
+    // Source Discovery Tests
+    // ============================================================================
 
-        // Receive with timeout
-        let result = tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await;
+    #[test]
+    fn test_parse_source_info_roundtrips_key_expr() {
+        let info = parse_source_info("asterix/@/sources/radar-1/48-62").unwrap();
+        assert_eq!(info.source_id, "radar-1");
+        assert_eq!(info.key_prefix, "asterix");
+        assert_eq!(info.categories, vec![48, 62]);
+    }
 
-        if let Ok(Some(sample)) = result {
-            // Verify the category is correct
-            assert_eq!(sample.category, 48);
-            // SAC/SIC may or may not be present depending on key parsing
-            // If present, verify correct values
-            if let Some(sac) = sample.sac {
-                assert_eq!(sac, 5, "SAC mismatch");
-            }
-            if let Some(sic) = sample.sic {
-                assert_eq!(sic, 10, "SIC mismatch");
-            }
-            // Data should be the decoded hex
-            assert!(!sample.data.is_empty());
-        }
+    #[test]
+    fn test_parse_source_info_no_categories() {
+        let info = parse_source_info("asterix/@/sources/radar-1/").unwrap();
+        assert_eq!(info.source_id, "radar-1");
+        assert!(info.categories.is_empty());
+    }
 
-        let _ = publisher.close().await;
-        let _ = subscriber.close().await;
+    #[test]
+    fn test_parse_source_info_rejects_unrelated_key() {
+        assert!(parse_source_info("asterix/48/1/2").is_none());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_e2e_multiple_categories() {
+    async fn test_publisher_with_source_discovery_new_and_close() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher =
+            match ZenohPublisher::with_source_discovery(config, "radar-1", &[48, 62]).await {
+                Ok(p) => p,
+                Err(_) => return,
+            };
+
+        let close_result = publisher.close().await;
+        assert!(close_result.is_ok());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_source_discovery_new_and_close() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let discovery = match ZenohSourceDiscovery::new(config).await {
+            Ok(d) => d,
+            Err(_) => return,
+        };
+
+        let close_result = discovery.close().await;
+        assert!(close_result.is_ok());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_source_discovery_observes_publisher_join() {
         use std::time::Duration;
+        use tokio::time::timeout;
 
         let config = ZenohConfig::peer_to_peer();
 
-        let publisher = match ZenohPublisher::new(config.clone()).await {
-            Ok(p) => p,
+        let mut discovery = match ZenohSourceDiscovery::new(config.clone()).await {
+            Ok(d) => d,
             Err(_) => return,
         };
 
-        // Subscribe to all categories
-        let mut subscriber = match ZenohSubscriber::new(config, "asterix/**").await {
-            Ok(s) => s,
-            Err(_) => {
-                let _ = publisher.close().await;
-                return;
+        let publisher =
+            match ZenohPublisher::with_source_discovery(config, "radar-42", &[48]).await {
+                Ok(p) => p,
+                Err(_) => {
+                    let _ = discovery.close().await;
+                    return;
+                }
+            };
+
+        // Best-effort: on a real network this observes a Joined(radar-42)
+        // event, but peer discovery timing isn't guaranteed in a sandboxed
+        // CI environment, so this only exercises the API surface.
+        let _ = timeout(Duration::from_secs(2), async {
+            loop {
+                match discovery.recv().await {
+                    Some(SourceEvent::Joined(info)) if info.source_id == "radar-42" => break,
+                    Some(_) => continue,
+                    None => break,
+                }
             }
+        })
+        .await;
+
+        let _ = publisher.close().await;
+        let _ = discovery.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_edge_category_numbers() {
+        let config = ZenohConfig::peer_to_peer();
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
         };
 
-        tokio::time::sleep(Duration::from_millis(100)).await;
+        // Test with category 0 (edge case)
+        let result = publisher.publish_raw(0, &[0x00, 0x00, 0x05]).await;
+        assert!(result.is_ok());
 
-        // Publish multiple categories
-        let categories = vec![
-            (48, vec![0x30, 0x00, 0x05]), // CAT048
-            (62, vec![0x3E, 0x00, 0x05]), // CAT062
-            (65, vec![0x41, 0x00, 0x05]), // CAT065
-        ];
+        // Test with category 255 (max)
+        let result = publisher.publish_raw(255, &[0xFF, 0x00, 0x05]).await;
+        assert!(result.is_ok());
 
-        for (cat, data) in &categories {
-            publisher
-                .publish_raw(*cat, data)
-                .await
-                .expect("Publish failed");
-        }
+        let _ = publisher.close().await;
+    }
 
-        // Try to receive multiple samples
-        let mut received_cats = Vec::new();
-        for _ in 0..3 {
-            match tokio::time::timeout(Duration::from_millis(500), subscriber.recv()).await {
-                Ok(Some(sample)) => received_cats.push(sample.category),
-                _ => break,
-            }
-        }
+    // ============================================================================
+    // QoS (priority / congestion control / reliability) Tests
+    // ============================================================================
 
-        // Should have received at least some categories
-        // (exact number depends on timing)
+    #[test]
+    fn test_reliability_default_is_reliable() {
+        assert_eq!(Reliability::default(), Reliability::Reliable);
+        assert_eq!(ZenohConfig::default().reliability, Reliability::Reliable);
+    }
 
-        let _ = publisher.close().await;
-        let _ = subscriber.close().await;
+    #[test]
+    fn test_config_builder_methods_set_qos_fields() {
+        let config = ZenohConfig::peer_to_peer()
+            .with_priority(Priority::Background)
+            .with_congestion_control(CongestionControl::Drop)
+            .with_reliability(Reliability::BestEffort);
+
+        assert_eq!(config.priority, Priority::Background);
+        assert_eq!(config.congestion_control, CongestionControl::Drop);
+        assert_eq!(config.reliability, Reliability::BestEffort);
     }
 
-    // ============================================================================
-    // Connection Failure Tests
-    // ============================================================================
+    #[test]
+    fn test_with_qos_profile_sets_override_for_that_category_only() {
+        let profile = QosProfile {
+            priority: Priority::Background,
+            congestion_control: CongestionControl::Drop,
+            reliability: Reliability::BestEffort,
+        };
+        let config = ZenohConfig::peer_to_peer().with_qos_profile(48, profile);
+
+        assert_eq!(config.qos_profiles.get(&48), Some(&profile));
+        assert_eq!(config.qos_profiles.get(&62), None);
+    }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publisher_connection_to_nonexistent_router() {
-        // Try to connect to a router that definitely doesn't exist
-        // This should fail during session creation
-        let config = ZenohConfig::with_endpoints(vec![
-            "tcp/192.0.2.1:7447".to_string(), // TEST-NET-1, guaranteed unreachable
-        ]);
+    async fn test_effective_qos_falls_back_to_config_defaults_for_unlisted_category() {
+        let config = ZenohConfig::peer_to_peer()
+            .with_priority(Priority::Interactive)
+            .with_qos_profile(
+                65,
+                QosProfile {
+                    priority: Priority::Background,
+                    congestion_control: CongestionControl::Drop,
+                    reliability: Reliability::BestEffort,
+                },
+            );
 
-        let result = ZenohPublisher::new(config).await;
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
 
-        // This exercises the zenoh::open error path (line 256)
-        // Zenoh may succeed with multicast fallback or fail - both are valid
-        match result {
-            Ok(p) => {
-                // Connected via multicast discovery despite bad endpoint
-                let _ = p.close().await;
-            }
-            Err(e) => {
-                // Failed to connect - expected
-                assert!(!e.to_string().is_empty());
-            }
-        }
+        assert_eq!(publisher.effective_priority(48), Priority::Interactive);
+        assert_eq!(publisher.effective_priority(65), Priority::Background);
+        assert_eq!(
+            publisher.effective_qos(65).congestion_control,
+            CongestionControl::Drop
+        );
+        assert_eq!(
+            publisher.effective_qos(48).congestion_control,
+            CongestionControl::Block
+        );
     }
 
-    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_subscriber_connection_to_nonexistent_router() {
-        let config = ZenohConfig::with_endpoints(vec!["tcp/192.0.2.1:7447".to_string()]);
+    #[test]
+    fn test_with_surveillance_qos_prioritizes_realtime_categories() {
+        let config = ZenohConfig::with_surveillance_qos();
 
-        let result = ZenohSubscriber::new(config, "asterix/**").await;
+        let cat48 = config.qos_profiles.get(&48).expect("CAT048 profile set");
+        assert_eq!(cat48.priority, Priority::RealTime);
+        assert_eq!(cat48.congestion_control, CongestionControl::Drop);
+        assert_eq!(cat48.reliability, Reliability::BestEffort);
 
-        match result {
-            Ok(s) => {
-                let _ = s.close().await;
-            }
-            Err(e) => {
-                assert!(!e.to_string().is_empty());
-            }
-        }
+        let cat62 = config.qos_profiles.get(&62).expect("CAT062 profile set");
+        assert_eq!(cat62.priority, Priority::Interactive);
+        assert_eq!(cat62.congestion_control, CongestionControl::Block);
+        assert_eq!(cat62.reliability, Reliability::Reliable);
+
+        assert!(config.qos_profiles.get(&1).is_none());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_config_with_empty_key_prefix() {
-        let config = ZenohConfig {
-            key_prefix: String::new(),
-            ..Default::default()
-        };
+    async fn test_publish_with_custom_qos_succeeds() {
+        let config = ZenohConfig::peer_to_peer()
+            .with_priority(Priority::Background)
+            .with_congestion_control(CongestionControl::Drop)
+            .with_reliability(Reliability::BestEffort);
 
         let publisher = match ZenohPublisher::new(config).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Publishing with empty prefix creates key like "/48" which may fail
-        // depending on Zenoh version - test that it doesn't panic
-        let result = publisher.publish_raw(48, &[0x30, 0x00, 0x05]).await;
-        // Either success or clean error is acceptable
-        if result.is_err() {
-            let err = result.err().unwrap();
-            // Should be a publish error, not a panic
-            assert!(!err.to_string().is_empty());
-        }
+        let record = AsterixRecord {
+            category: 48,
+            ..Default::default()
+        };
+        let result = publisher.publish(&record).await;
+        assert!(result.is_ok(), "qos publish failed: {:?}", result.err());
 
         let _ = publisher.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publish_empty_data() {
-        let config = ZenohConfig::peer_to_peer();
+    async fn test_publish_warns_but_succeeds_when_low_latency_payload_exceeds_max_size() {
+        let config = ZenohConfig {
+            low_latency: true,
+            max_payload_size: 4,
+            ..ZenohConfig::peer_to_peer()
+        };
 
         let publisher = match ZenohPublisher::new(config).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Publishing empty data should work
-        let result = publisher.publish_raw(48, &[]).await;
-        assert!(
-            result.is_ok(),
-            "Publishing empty data failed: {:?}",
-            result.err()
-        );
+        // hex_data decodes to more than 4 bytes, so this crosses
+        // max_payload_size while low_latency can't fragment — `publish`
+        // should still succeed (it only warns), unlike `publish_raw`
+        // which would fragment the same payload.
+        let record = AsterixRecord {
+            category: 48,
+            hex_data: "300019F8250164".to_string(),
+            ..Default::default()
+        };
+        let result = publisher.publish(&record).await;
+        assert!(result.is_ok(), "oversized low-latency publish failed: {:?}", result.err());
 
         let _ = publisher.close().await;
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publish_large_data() {
+    async fn test_publish_block_splits_and_publishes_each_record() {
         let config = ZenohConfig::peer_to_peer();
 
         let publisher = match ZenohPublisher::new(config).await {
@@ -1480,30 +6919,17 @@ mod tests {
             Err(_) => return,
         };
 
-        // Publish a large payload (64KB)
-        let large_data = vec![0xAB; 65536];
-        let result = publisher.publish_raw(48, &large_data).await;
-        assert!(
-            result.is_ok(),
-            "Publishing large data failed: {:?}",
-            result.err()
-        );
+        // Two complete blocks: CAT48 (3-byte header + 2 bytes) and CAT62
+        // (3-byte header + 1 byte).
+        let data = [48u8, 0x00, 0x05, 0xAA, 0xBB, 62u8, 0x00, 0x04, 0xCC];
+        let result = publisher.publish_block(&data).await;
+        assert!(result.is_ok(), "publish_block failed: {:?}", result.err());
 
         let _ = publisher.close().await;
     }
 
-    // ============================================================================
-    // Error Path Coverage Tests (Issue #100)
-    // These tests specifically target uncovered error paths
-    // ============================================================================
-
-    /// Test serialization with empty hex_data triggers JSON serialization path (Line 368)
-    /// Note: With serde enabled and valid data, JSON serialization succeeds
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_serialize_record_json_path() {
-        use crate::types::{AsterixRecord, DataItem, ParsedValue};
-        use std::collections::BTreeMap;
-
+    async fn test_publish_block_rejects_trailing_incomplete_block() {
         let config = ZenohConfig::peer_to_peer();
 
         let publisher = match ZenohPublisher::new(config).await {
@@ -1511,46 +6937,19 @@ mod tests {
             Err(_) => return,
         };
 
-        // Create record with empty hex_data to trigger JSON serialization path
-        let mut items = BTreeMap::new();
-        let mut fields = BTreeMap::new();
-        fields.insert("test_value".to_string(), ParsedValue::Integer(42));
-        items.insert(
-            "I048/999".to_string(),
-            DataItem {
-                description: Some("Test item".to_string()),
-                fields,
-            },
-        );
-
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 12345,
-            hex_data: String::new(), // Empty hex_data forces serde JSON path
-            items,
-            crc: 0,
-        };
-
-        // With serde feature and valid data, this exercises the JSON serialization path
-        let result = publisher.publish(&record).await;
-        assert!(
-            result.is_ok(),
-            "JSON serialization should succeed: {:?}",
-            result.err()
-        );
+        // A complete CAT48 block followed by a truncated header.
+        let data = [48u8, 0x00, 0x03, 62u8, 0x00];
+        let result = publisher.publish_block(&data).await;
+        assert!(matches!(result, Err(ZenohError::PublishError(_))));
 
         let _ = publisher.close().await;
     }
 
-    /// Test serialization with include_raw_bytes disabled forces JSON path
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_serialize_record_json_path_disabled_raw() {
-        use crate::types::{AsterixRecord, DataItem, ParsedValue};
-        use std::collections::BTreeMap;
-
+    async fn test_publish_block_rejects_oversized_block_in_low_latency_mode() {
         let config = ZenohConfig {
-            include_raw_bytes: false, // Force JSON path even with hex_data
+            low_latency: true,
+            max_payload_size: 4,
             ..ZenohConfig::peer_to_peer()
         };
 
@@ -1559,244 +6958,497 @@ mod tests {
             Err(_) => return,
         };
 
-        let mut items = BTreeMap::new();
-        let mut fields = BTreeMap::new();
-        fields.insert("SAC".to_string(), ParsedValue::Integer(1));
-        fields.insert("SIC".to_string(), ParsedValue::Integer(2));
-        items.insert(
-            "I048/010".to_string(),
-            DataItem {
-                description: None,
-                fields,
-            },
-        );
+        // A single 6-byte block exceeds the 4-byte max_payload_size, and
+        // low_latency can't fragment it away like publish_chunked would.
+        let data = [48u8, 0x00, 0x06, 0xAA, 0xBB, 0xCC];
+        let result = publisher.publish_block(&data).await;
+        assert!(matches!(result, Err(ZenohError::PublishError(_))));
 
-        let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "30000A".to_string(), // Has hex_data but include_raw_bytes is false
-            items,
-            crc: 0,
-        };
+        let _ = publisher.close().await;
+    }
 
-        // With include_raw_bytes=false, should use JSON serialization regardless of hex_data
-        let result = publisher.publish(&record).await;
-        // This actually uses hex_data because the condition is `include_raw_bytes && !hex_data.is_empty()`
-        // So with include_raw_bytes=false, it goes to JSON path
-        assert!(
-            result.is_ok(),
-            "JSON serialization should succeed: {:?}",
-            result.err()
-        );
+    // ============================================================================
+    // PayloadFormat Tests
+    // ============================================================================
 
-        let _ = publisher.close().await;
+    #[test]
+    fn test_payload_format_default_is_raw() {
+        assert_eq!(PayloadFormat::default(), PayloadFormat::Raw);
+        assert_eq!(ZenohConfig::default().payload_format, PayloadFormat::Raw);
     }
 
-    /// Test publisher error display contains expected text
     #[test]
-    fn test_publisher_error_display() {
-        let err = ZenohError::PublisherError("test publisher error".to_string());
-        let display = err.to_string();
-        assert!(
-            display.contains("publisher"),
-            "Display should contain 'publisher'"
+    fn test_zenoh_encoding_maps_each_format() {
+        assert_eq!(
+            zenoh_encoding(PayloadFormat::Raw),
+            zenoh::bytes::Encoding::APPLICATION_OCTET_STREAM
         );
-        assert!(
-            display.contains("test publisher error"),
-            "Display should contain message"
+        assert_eq!(
+            zenoh_encoding(PayloadFormat::Json),
+            zenoh::bytes::Encoding::APPLICATION_JSON
+        );
+        assert_eq!(
+            zenoh_encoding(PayloadFormat::Cbor),
+            zenoh::bytes::Encoding::APPLICATION_CBOR
+        );
+        assert_eq!(
+            zenoh_encoding(PayloadFormat::MessagePack),
+            zenoh::bytes::Encoding::from(MESSAGEPACK_ENCODING_ID)
         );
     }
 
-    /// Test receiver error display
     #[test]
-    fn test_receive_error_display() {
-        let err = ZenohError::ReceiveError("channel disconnected".to_string());
-        let display = err.to_string();
-        assert!(
-            display.contains("receive"),
-            "Display should contain 'receive'"
-        );
-        assert!(display.contains("channel disconnected"));
+    fn test_payload_format_from_encoding_roundtrips() {
+        for format in [
+            PayloadFormat::Raw,
+            PayloadFormat::Json,
+            PayloadFormat::Cbor,
+            PayloadFormat::MessagePack,
+        ] {
+            assert_eq!(
+                payload_format_from_encoding(&zenoh_encoding(format)),
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_payload_format_from_encoding_unknown_defaults_to_raw() {
+        let unrelated = zenoh::bytes::Encoding::from("text/plain");
+        assert_eq!(payload_format_from_encoding(&unrelated), PayloadFormat::Raw);
     }
 
-    /// Test hex_to_bytes with edge cases
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_hex_to_bytes_edge_cases() {
-        use crate::types::AsterixRecord;
-        use std::collections::BTreeMap;
+    async fn test_publish_cbor_format_succeeds() {
+        let config = ZenohConfig {
+            payload_format: PayloadFormat::Cbor,
+            ..ZenohConfig::peer_to_peer()
+        };
 
-        let config = ZenohConfig::peer_to_peer();
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
+        };
+
+        let record = AsterixRecord {
+            category: 48,
+            ..Default::default()
+        };
+        let result = publisher.publish(&record).await;
+        assert!(result.is_ok(), "cbor publish failed: {:?}", result.err());
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_raw_without_hex_data_falls_back_to_json() {
+        let config = ZenohConfig {
+            payload_format: PayloadFormat::Raw,
+            ..ZenohConfig::peer_to_peer()
+        };
 
         let publisher = match ZenohPublisher::new(config).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Test with tabs and newlines in hex (should be stripped)
+        // No hex_data, so PayloadFormat::Raw should fall back to JSON rather
+        // than erroring, the same as it did before PayloadFormat existed.
         let record = AsterixRecord {
             category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "30\t00\n0A".to_string(),
-            items: BTreeMap::new(),
-            crc: 0,
+            hex_data: String::new(),
+            ..Default::default()
+        };
+
+        let result = publisher.publish(&record).await;
+        #[cfg(feature = "serde")]
+        assert!(result.is_ok(), "json fallback publish failed: {:?}", result.err());
+        #[cfg(not(feature = "serde"))]
+        assert!(matches!(result, Err(ZenohError::SerializationError(_))));
+
+        let _ = publisher.close().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+    async fn test_publish_messagepack_format_succeeds() {
+        let config = ZenohConfig {
+            payload_format: PayloadFormat::MessagePack,
+            ..ZenohConfig::peer_to_peer()
+        };
+
+        let publisher = match ZenohPublisher::new(config).await {
+            Ok(p) => p,
+            Err(_) => return,
         };
 
+        let record = AsterixRecord {
+            category: 48,
+            ..Default::default()
+        };
         let result = publisher.publish(&record).await;
+        #[cfg(feature = "serde")]
         assert!(
             result.is_ok(),
-            "Should handle whitespace in hex: {:?}",
+            "messagepack publish failed: {:?}",
             result.err()
         );
+        #[cfg(not(feature = "serde"))]
+        assert!(matches!(result, Err(ZenohError::SerializationError(_))));
 
         let _ = publisher.close().await;
     }
 
-    /// Test config clone and debug
+    // ============================================================================
+    // AsterixSample::decode_record Tests
+    // ============================================================================
+
     #[test]
-    fn test_zenoh_config_debug_and_clone() {
-        let config = ZenohConfig {
-            congestion_control: CongestionControl::Drop,
-            priority: Priority::RealTime,
-            ..Default::default()
+    fn test_decode_record_raw_is_rejected() {
+        let sample = AsterixSample {
+            category: 48,
+            sac: None,
+            sic: None,
+            data: vec![],
+            timestamp: 0,
+            key_expr: "asterix/48".to_string(),
+            encoding: PayloadFormat::Raw,
         };
-
-        let debug_str = format!("{config:?}");
-        assert!(debug_str.contains("ZenohConfig"));
-        assert!(debug_str.contains("asterix"));
-
-        let cloned = config.clone();
-        assert!(matches!(cloned.congestion_control, CongestionControl::Drop));
-        assert!(matches!(cloned.priority, Priority::RealTime));
+        assert!(matches!(
+            sample.decode_record(),
+            Err(ZenohError::SerializationError(_))
+        ));
     }
 
-    /// Test priority clone and copy
     #[test]
-    fn test_priority_clone_copy() {
-        let p1 = Priority::Interactive;
-        let p2 = p1; // Copy
-        let p3 = p1; // Clone
-        assert!(matches!(p2, Priority::Interactive));
-        assert!(matches!(p3, Priority::Interactive));
+    fn test_decode_record_cbor_is_rejected() {
+        let sample = AsterixSample {
+            category: 48,
+            sac: None,
+            sic: None,
+            data: vec![],
+            timestamp: 0,
+            key_expr: "asterix/48".to_string(),
+            encoding: PayloadFormat::Cbor,
+        };
+        assert!(matches!(
+            sample.decode_record(),
+            Err(ZenohError::SerializationError(_))
+        ));
     }
 
-    /// Test congestion control debug
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_congestion_control_debug() {
-        let cc = CongestionControl::Block;
-        let debug_str = format!("{cc:?}");
-        assert!(debug_str.contains("Block"));
-
-        let cc2 = CongestionControl::Drop;
-        let debug_str2 = format!("{cc2:?}");
-        assert!(debug_str2.contains("Drop"));
+    fn test_asterix_sample_serde_json_roundtrip() {
+        let original = AsterixSample {
+            category: 48,
+            sac: Some(1),
+            sic: Some(2),
+            data: vec![0xAA, 0xBB, 0xCC],
+            timestamp: 1_000_000,
+            key_expr: "asterix/48".to_string(),
+            encoding: PayloadFormat::Cbor,
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        let back: AsterixSample = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.category, original.category);
+        assert_eq!(back.sac, original.sac);
+        assert_eq!(back.sic, original.sic);
+        assert_eq!(back.data, original.data);
+        assert_eq!(back.timestamp, original.timestamp);
+        assert_eq!(back.key_expr, original.key_expr);
+        assert_eq!(back.encoding, original.encoding);
     }
 
-    /// Test AsterixSample with empty data
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_asterix_sample_empty_data() {
+    fn test_decode_record_json_roundtrips() {
+        let record = AsterixRecord {
+            category: 48,
+            ..Default::default()
+        };
+        let data = serde_json::to_vec(&record).unwrap();
+
         let sample = AsterixSample {
-            category: 0,
+            category: 48,
             sac: None,
             sic: None,
-            data: Vec::new(),
+            data,
             timestamp: 0,
-            key_expr: String::new(),
+            key_expr: "asterix/48".to_string(),
+            encoding: PayloadFormat::Json,
         };
 
-        assert!(sample.data.is_empty());
-        assert_eq!(sample.category, 0);
+        let decoded = sample.decode_record().unwrap();
+        assert_eq!(decoded.category, 48);
     }
 
-    /// Test parse_key_expr with edge cases
+    #[cfg(feature = "serde")]
     #[test]
-    fn test_parse_key_expr_edge_cases() {
-        // Empty string
-        assert_eq!(parse_key_expr("", "asterix"), (0, None, None));
-
-        // Just prefix
-        assert_eq!(parse_key_expr("asterix", "asterix"), (0, None, None));
-
-        // Prefix with trailing slash
-        assert_eq!(parse_key_expr("asterix/", "asterix"), (0, None, None));
+    fn test_decode_record_messagepack_roundtrips() {
+        let record = AsterixRecord {
+            category: 62,
+            ..Default::default()
+        };
+        let data = rmp_serde::to_vec(&record).unwrap();
 
-        // Very large category number (overflows u8)
-        assert_eq!(parse_key_expr("asterix/999", "asterix"), (0, None, None));
+        let sample = AsterixSample {
+            category: 62,
+            sac: None,
+            sic: None,
+            data,
+            timestamp: 0,
+            key_expr: "asterix/62".to_string(),
+            encoding: PayloadFormat::MessagePack,
+        };
 
-        // Negative numbers - category fails to parse but SAC/SIC still parsed
-        assert_eq!(
-            parse_key_expr("asterix/-1/1/2", "asterix"),
-            (0, Some(1), Some(2))
-        );
+        let decoded = sample.decode_record().unwrap();
+        assert_eq!(decoded.category, 62);
+    }
 
-        // With extra path segments
-        assert_eq!(
-            parse_key_expr("asterix/48/1/2/extra", "asterix"),
-            (48, Some(1), Some(2))
-        );
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_decode_record_messagepack_rejects_malformed_data() {
+        let sample = AsterixSample {
+            category: 48,
+            sac: None,
+            sic: None,
+            data: vec![0xFF, 0xFF, 0xFF],
+            timestamp: 0,
+            key_expr: "asterix/48".to_string(),
+            encoding: PayloadFormat::MessagePack,
+        };
+        assert!(matches!(
+            sample.decode_record(),
+            Err(ZenohError::SerializationError(_))
+        ));
     }
 
-    /// Test extract_sac_sic with different item ID formats
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_extract_sac_sic_edge_cases() {
-        use crate::types::{AsterixRecord, DataItem, ParsedValue};
-        use std::collections::BTreeMap;
+    async fn test_e2e_messagepack_publish_decodes_back_to_record() {
+        use std::time::Duration;
 
-        let config = ZenohConfig::peer_to_peer();
+        let config = ZenohConfig {
+            payload_format: PayloadFormat::MessagePack,
+            ..ZenohConfig::peer_to_peer()
+        };
 
-        let publisher = match ZenohPublisher::new(config).await {
+        let publisher = match ZenohPublisher::new(config.clone()).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Test with missing SAC field (only SIC present)
-        let mut items = BTreeMap::new();
-        let mut fields = BTreeMap::new();
-        fields.insert("SIC".to_string(), ParsedValue::Integer(5));
-        // No SAC field
-        items.insert(
-            "I048/010".to_string(),
-            DataItem {
-                description: None,
-                fields,
-            },
-        );
+        let mut subscriber = match ZenohSubscriber::new(config, "asterix/76/**").await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
 
         let record = AsterixRecord {
-            category: 48,
-            length: 10,
-            timestamp_ms: 0,
-            hex_data: "300005".to_string(),
-            items,
-            crc: 0,
+            category: 76,
+            ..Default::default()
         };
+        publisher.publish(&record).await.unwrap();
 
-        // Should still publish successfully, just without full routing
-        let result = publisher.publish(&record).await;
-        assert!(result.is_ok());
+        if let Ok(Some(sample)) =
+            tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await
+        {
+            assert_eq!(sample.encoding, PayloadFormat::MessagePack);
+            let decoded = sample.decode_record().unwrap();
+            assert_eq!(decoded.category, 76);
+        }
 
         let _ = publisher.close().await;
+        let _ = subscriber.close().await;
+    }
+
+    // ============================================================================
+    // SubscriptionFilter Tests
+    // ============================================================================
+
+    fn sample_with(category: u8, sac: Option<u8>, sic: Option<u8>) -> AsterixSample {
+        AsterixSample {
+            category,
+            sac,
+            sic,
+            data: vec![],
+            timestamp: 0,
+            key_expr: format!("asterix/{category}"),
+            encoding: PayloadFormat::Raw,
+        }
+    }
+
+    #[test]
+    fn test_subscription_filter_default_matches_everything() {
+        let filter = SubscriptionFilter::new();
+        assert!(filter.matches(&sample_with(48, Some(1), Some(2))));
+        assert!(filter.matches(&sample_with(62, None, None)));
+    }
+
+    #[test]
+    fn test_subscription_filter_category_set() {
+        let filter = SubscriptionFilter::new().with_categories([48, 62]);
+        assert!(filter.matches(&sample_with(48, None, None)));
+        assert!(filter.matches(&sample_with(62, None, None)));
+        assert!(!filter.matches(&sample_with(65, None, None)));
+    }
+
+    #[test]
+    fn test_subscription_filter_sac_sic() {
+        let filter = SubscriptionFilter::new().with_sac(10).with_sic(20);
+        assert!(filter.matches(&sample_with(48, Some(10), Some(20))));
+        assert!(!filter.matches(&sample_with(48, Some(11), Some(20))));
+        assert!(!filter.matches(&sample_with(48, Some(10), Some(21))));
+        assert!(!filter.matches(&sample_with(48, None, None)));
+    }
+
+    #[test]
+    fn test_subscription_filter_predicate() {
+        let filter = SubscriptionFilter::new().with_predicate(|s| s.data.len() > 2);
+        let mut long_sample = sample_with(48, None, None);
+        long_sample.data = vec![1, 2, 3];
+        assert!(filter.matches(&long_sample));
+        assert!(!filter.matches(&sample_with(48, None, None)));
+    }
+
+    #[test]
+    fn test_subscription_filter_key_expr_single_category() {
+        let filter = SubscriptionFilter::new().with_categories([48]).with_sac(10);
+        assert_eq!(filter.key_expr("asterix"), "asterix/48/10/*");
+    }
+
+    #[test]
+    fn test_subscription_filter_key_expr_no_category_is_wildcard() {
+        let filter = SubscriptionFilter::new().with_sac(10);
+        assert_eq!(filter.key_expr("asterix"), "asterix/**");
+    }
+
+    #[test]
+    fn test_subscription_filter_key_expr_multi_category_is_wildcard() {
+        let filter = SubscriptionFilter::new().with_categories([48, 62]);
+        assert_eq!(filter.key_expr("asterix"), "asterix/**");
+    }
+
+    #[test]
+    fn test_subscription_filter_debug_redacts_predicate() {
+        let filter = SubscriptionFilter::new().with_predicate(|_| true);
+        assert!(format!("{filter:?}").contains("<fn>"));
     }
 
-    /// Test publish with non-standard category numbers
     #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
-    async fn test_publish_edge_category_numbers() {
+    async fn test_subscribe_filtered_only_yields_matching_category() {
+        use std::time::Duration;
+
         let config = ZenohConfig::peer_to_peer();
 
-        let publisher = match ZenohPublisher::new(config).await {
+        let publisher = match ZenohPublisher::new(config.clone()).await {
             Ok(p) => p,
             Err(_) => return,
         };
 
-        // Test with category 0 (edge case)
-        let result = publisher.publish_raw(0, &[0x00, 0x00, 0x05]).await;
-        assert!(result.is_ok());
+        let filter = SubscriptionFilter::new().with_categories([77]);
+        let mut subscriber = match ZenohSubscriber::subscribe_filtered(config, filter).await {
+            Ok(s) => s,
+            Err(_) => {
+                let _ = publisher.close().await;
+                return;
+            }
+        };
 
-        // Test with category 255 (max)
-        let result = publisher.publish_raw(255, &[0xFF, 0x00, 0x05]).await;
-        assert!(result.is_ok());
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        publisher.publish_raw(77, &[0x01]).await.unwrap();
+
+        if let Ok(Some(sample)) =
+            tokio::time::timeout(Duration::from_secs(2), subscriber.recv()).await
+        {
+            assert_eq!(sample.category, 77);
+        }
 
         let _ = publisher.close().await;
+        let _ = subscriber.close().await;
+    }
+
+    // ============================================================================
+    // ZenohConfig::from_addr Tests
+    // ============================================================================
+
+    #[test]
+    fn test_from_addr_parses_full_connection_string() {
+        let config = ZenohConfig::from_addr(
+            "zenoh://router/tcp/192.0.2.1:7447?mode=client&prefix=radar&priority=realtime&congestion=drop&raw=false&format=cbor",
+        )
+        .unwrap();
+
+        assert_eq!(config.endpoints, vec!["tcp/192.0.2.1:7447".to_string()]);
+        // The "mode=client" query parameter overrides the "router" path segment.
+        assert_eq!(config.mode, ZenohMode::Client);
+        assert_eq!(config.key_prefix, "radar");
+        assert_eq!(config.priority, Priority::RealTime);
+        assert_eq!(config.congestion_control, CongestionControl::Drop);
+        assert!(!config.include_raw_bytes);
+        assert_eq!(config.payload_format, PayloadFormat::Cbor);
+    }
+
+    #[test]
+    fn test_from_addr_without_query_string_keeps_defaults() {
+        let config = ZenohConfig::from_addr("zenoh://peer/tcp/10.0.0.1:7447").unwrap();
+
+        assert_eq!(config.endpoints, vec!["tcp/10.0.0.1:7447".to_string()]);
+        assert_eq!(config.mode, ZenohMode::Peer);
+        assert_eq!(config.key_prefix, "asterix");
+        assert_eq!(config.priority, Priority::default());
+        assert_eq!(config.congestion_control, CongestionControl::default());
+        assert!(config.include_raw_bytes);
+        assert_eq!(config.payload_format, PayloadFormat::Raw);
+    }
+
+    #[test]
+    fn test_from_addr_rejects_wrong_scheme() {
+        let err = ZenohConfig::from_addr("http://peer/tcp/10.0.0.1:7447").unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+    }
+
+    #[test]
+    fn test_from_addr_rejects_missing_endpoint_segment() {
+        let err = ZenohConfig::from_addr("zenoh://peer").unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+    }
+
+    #[test]
+    fn test_from_addr_rejects_unknown_mode() {
+        let err = ZenohConfig::from_addr("zenoh://bogus/tcp/10.0.0.1:7447").unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+    }
+
+    #[test]
+    fn test_from_addr_rejects_unknown_query_key() {
+        let err =
+            ZenohConfig::from_addr("zenoh://peer/tcp/10.0.0.1:7447?bogus=1").unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+    }
+
+    #[test]
+    fn test_from_addr_rejects_unknown_priority_value() {
+        let err = ZenohConfig::from_addr("zenoh://peer/tcp/10.0.0.1:7447?priority=urgent")
+            .unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+    }
+
+    #[test]
+    fn test_from_addr_rejects_malformed_query_pair() {
+        let err =
+            ZenohConfig::from_addr("zenoh://peer/tcp/10.0.0.1:7447?priority").unwrap_err();
+        assert!(matches!(err, ZenohError::ConfigError(_)));
+    }
+
+    #[test]
+    fn test_from_addr_accepts_messagepack_format() {
+        let config =
+            ZenohConfig::from_addr("zenoh://peer/tcp/10.0.0.1:7447?format=messagepack").unwrap();
+        assert_eq!(config.payload_format, PayloadFormat::MessagePack);
     }
 }
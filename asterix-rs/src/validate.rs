@@ -0,0 +1,533 @@
+//! Semantic validation of decoded records, beyond what byte-level parsing checks
+//!
+//! [`crate::parse`] only guarantees that a block was successfully decoded per
+//! its category's UAP — it says nothing about whether the decoded values make
+//! sense (a range outside spec limits, an item whose presence implies another
+//! item that's actually missing). [`validate`] runs a configurable set of
+//! [`Rule`]s over decoded records and collects the resulting
+//! [`ValidationDiagnostic`]s.
+//!
+//! This module's [`ValidationDiagnostic`]/[`ValidationSeverity`] are deliberately
+//! named apart from [`crate::error::Diagnostic`]/[`crate::error::Severity`]:
+//! those describe how seriously to take a *parse* error (`Recoverable` vs.
+//! `Fatal`, rendered via the `Diagnostic` trait), while these describe a
+//! *semantic* finding about an otherwise-successfully-parsed record, with the
+//! `Error`/`Warning`/`Info` severities deployments actually tune per rule.
+//!
+//! # Limitations
+//!
+//! This crate's FFI surface doesn't expose a category's full UAP (mandatory
+//! item roster, field bit ranges/unit tables) to Rust — see
+//! [`crate::ffi::ItemDesc`]'s docs for why. [`MandatoryItemRule`] therefore
+//! only checks for the one item that is mandatory across virtually every
+//! ASTERIX category ("010", SAC/SIC); deployments that know their category's
+//! full mandatory roster can express it with additional [`MandatoryItemRule`]
+//! instances or a custom [`Rule`]. Likewise [`FspecConsistencyRule`] can only
+//! check that the FSPEC's presence-bit *count* matches the number of decoded
+//! items, not which specific item diverges, since mapping a FRN bit position
+//! to an item id also requires the UAP.
+//!
+//! # Example
+//!
+//! ```no_run
+//! # use asterix::{init_default, parse, ParseOptions};
+//! # use asterix::validate::{validate, RuleSet, ValidationSeverity};
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! init_default()?;
+//! let data = std::fs::read("sample.asterix")?;
+//! let records = parse(&data, ParseOptions::default())?;
+//!
+//! let ruleset = RuleSet::with_builtin_rules().silence("fspec-item-consistency");
+//! for diagnostic in validate(&records, &ruleset) {
+//!     if diagnostic.severity >= ValidationSeverity::Warning {
+//!         eprintln!("{diagnostic}");
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::hex::from_hex;
+use crate::types::AsterixRecord;
+
+/// How seriously a [`ValidationDiagnostic`] should be taken.
+///
+/// Ordered so a deployment can filter with e.g. `severity >= ValidationSeverity::Warning`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ValidationSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for ValidationSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationSeverity::Info => write!(f, "info"),
+            ValidationSeverity::Warning => write!(f, "warning"),
+            ValidationSeverity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// One semantic finding produced by a [`Rule`].
+///
+/// `item`, when set, is the item id (e.g. `"I048/040"`) the finding is about;
+/// `None` for a finding about the record as a whole (e.g. FSPEC/item-count
+/// consistency).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationDiagnostic {
+    pub severity: ValidationSeverity,
+    pub category: u8,
+    pub item: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.item {
+            Some(item) => write!(
+                f,
+                "[{}] category {} {item}: {}",
+                self.severity, self.category, self.message
+            ),
+            None => write!(f, "[{}] category {}: {}", self.severity, self.category, self.message),
+        }
+    }
+}
+
+/// A single semantic check, run independently against one record at a time.
+///
+/// Rules carry no mutable state and only ever borrow the record they're
+/// checking, so a [`RuleSet`] run can eventually fan rules (or records) out
+/// across a thread pool the same way [`crate::parallel::parse_parallel`] or
+/// [`crate::reader::AsterixReader::for_each_parallel`] do, without any rule
+/// needing to change.
+pub trait Rule: Send + Sync {
+    /// Stable name this rule is known by, used to key [`RuleSet`]'s
+    /// per-rule severity overrides.
+    fn name(&self) -> &str;
+
+    /// Check `record`, returning zero or more findings.
+    fn check(&self, record: &AsterixRecord) -> Vec<ValidationDiagnostic>;
+}
+
+/// Checks that a record carries the one item that is mandatory across
+/// virtually every ASTERIX category: `I<CAT>/010` (SAC/SIC).
+///
+/// See the [module docs](self) for why this can't check a category's full
+/// mandatory-item roster.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MandatoryItemRule;
+
+impl Rule for MandatoryItemRule {
+    fn name(&self) -> &str {
+        "mandatory-item-presence"
+    }
+
+    fn check(&self, record: &AsterixRecord) -> Vec<ValidationDiagnostic> {
+        let sac_sic_id = format!("I{:03}/010", record.category);
+        if record.get_item(&sac_sic_id).is_some() {
+            return Vec::new();
+        }
+
+        vec![ValidationDiagnostic {
+            severity: ValidationSeverity::Error,
+            category: record.category,
+            item: Some(sac_sic_id),
+            message: "mandatory SAC/SIC item (010) is missing".to_string(),
+        }]
+    }
+}
+
+/// Checks CAT048's measured-position item (`I048/040`) for values outside
+/// the category's spec limits: `RHO` (range) within `0..=256` NM, `THETA`
+/// (azimuth) within `0..360` degrees.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NumericRangeRule;
+
+impl Rule for NumericRangeRule {
+    fn name(&self) -> &str {
+        "numeric-range-bounds"
+    }
+
+    fn check(&self, record: &AsterixRecord) -> Vec<ValidationDiagnostic> {
+        if record.category != 48 {
+            return Vec::new();
+        }
+        let Some(item) = record.get_item("I048/040") else {
+            return Vec::new();
+        };
+
+        let mut diagnostics = Vec::new();
+
+        if let Some(rho) = item.get_field("RHO").and_then(|v| v.as_f64()) {
+            if !(0.0..=256.0).contains(&rho) {
+                diagnostics.push(ValidationDiagnostic {
+                    severity: ValidationSeverity::Error,
+                    category: record.category,
+                    item: Some("I048/040".to_string()),
+                    message: format!("RHO {rho} NM is outside the valid 0..=256 NM range"),
+                });
+            }
+        }
+
+        if let Some(theta) = item.get_field("THETA").and_then(|v| v.as_f64()) {
+            if !(0.0..360.0).contains(&theta) {
+                diagnostics.push(ValidationDiagnostic {
+                    severity: ValidationSeverity::Error,
+                    category: record.category,
+                    item: Some("I048/040".to_string()),
+                    message: format!("THETA {theta} degrees is outside the valid 0..360 degree range"),
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Checks that the number of presence bits set in the record's FSPEC matches
+/// the number of items [`crate::parse`] actually decoded.
+///
+/// Reads the FSPEC directly out of [`AsterixRecord::hex_data`] (the 3-byte
+/// CAT+length header, then one or more FSPEC octets whose bit 1 is the FX
+/// continuation flag and whose bits 8..2 are FRN presence flags), so this
+/// only runs when `hex_data` is populated. See the [module docs](self) for
+/// why this counts presence bits rather than naming which item diverges.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FspecConsistencyRule;
+
+impl Rule for FspecConsistencyRule {
+    fn name(&self) -> &str {
+        "fspec-item-consistency"
+    }
+
+    fn check(&self, record: &AsterixRecord) -> Vec<ValidationDiagnostic> {
+        let Ok(bytes) = from_hex(&record.hex_data) else {
+            return Vec::new();
+        };
+        if bytes.len() < 4 {
+            return Vec::new();
+        }
+
+        let mut present_bits = 0usize;
+        let mut offset = 3;
+        loop {
+            let Some(&octet) = bytes.get(offset) else {
+                break;
+            };
+            present_bits += (octet >> 1).count_ones() as usize;
+            offset += 1;
+            if octet & 0x01 == 0 {
+                break;
+            }
+        }
+
+        if present_bits == record.items.len() {
+            return Vec::new();
+        }
+
+        vec![ValidationDiagnostic {
+            severity: ValidationSeverity::Error,
+            category: record.category,
+            item: None,
+            message: format!(
+                "FSPEC declares {present_bits} item(s) present but {} were decoded",
+                record.items.len()
+            ),
+        }]
+    }
+}
+
+/// Checks that `then_required` is present whenever `if_present` is, e.g.
+/// CAT048's Radar Plot Characteristics (`I048/130`) implying a Measured
+/// Position (`I048/040`) must also be present.
+pub struct DependencyRule {
+    name: String,
+    if_present: String,
+    then_required: String,
+}
+
+impl DependencyRule {
+    pub fn new(
+        name: impl Into<String>,
+        if_present: impl Into<String>,
+        then_required: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            if_present: if_present.into(),
+            then_required: then_required.into(),
+        }
+    }
+}
+
+impl Rule for DependencyRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn check(&self, record: &AsterixRecord) -> Vec<ValidationDiagnostic> {
+        if record.get_item(&self.if_present).is_none() || record.get_item(&self.then_required).is_some() {
+            return Vec::new();
+        }
+
+        vec![ValidationDiagnostic {
+            severity: ValidationSeverity::Warning,
+            category: record.category,
+            item: Some(self.if_present.clone()),
+            message: format!(
+                "{} is present but {} (required when it is) is missing",
+                self.if_present, self.then_required
+            ),
+        }]
+    }
+}
+
+/// A configured set of [`Rule`]s, with per-rule severity overrides.
+///
+/// `validate`'s [`ValidationDiagnostic::severity`] for a given rule's
+/// findings is whatever [`RuleSet`] has configured for it (falling back to
+/// the rule's own default severity); a rule configured via [`Self::silence`]
+/// is skipped entirely, so deployments can downgrade noisy checks or turn
+/// them off without forking this crate.
+pub struct RuleSet {
+    rules: Vec<Arc<dyn Rule>>,
+    overrides: HashMap<String, Option<ValidationSeverity>>,
+}
+
+impl RuleSet {
+    /// An empty rule set — add rules with [`Self::with_rule`].
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            overrides: HashMap::new(),
+        }
+    }
+
+    /// A rule set with this crate's built-in rules: [`MandatoryItemRule`],
+    /// [`NumericRangeRule`], [`FspecConsistencyRule`], and one illustrative
+    /// [`DependencyRule`] (CAT048's `I048/130` implying `I048/040`).
+    pub fn with_builtin_rules() -> Self {
+        Self::new()
+            .with_rule(MandatoryItemRule)
+            .with_rule(NumericRangeRule)
+            .with_rule(FspecConsistencyRule)
+            .with_rule(DependencyRule::new(
+                "cat048-plot-characteristics-requires-position",
+                "I048/130",
+                "I048/040",
+            ))
+    }
+
+    /// Add `rule` to this set.
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Arc::new(rule));
+        self
+    }
+
+    /// Override the severity `rule_name`'s findings are reported at.
+    pub fn with_severity(mut self, rule_name: impl Into<String>, severity: ValidationSeverity) -> Self {
+        self.overrides.insert(rule_name.into(), Some(severity));
+        self
+    }
+
+    /// Disable `rule_name` entirely — its findings never appear in
+    /// [`validate`]'s output.
+    pub fn silence(mut self, rule_name: impl Into<String>) -> Self {
+        self.overrides.insert(rule_name.into(), None);
+        self
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run every rule in `ruleset` against every record in `records`, returning
+/// all findings in `records`/rule order.
+pub fn validate(records: &[AsterixRecord], ruleset: &RuleSet) -> Vec<ValidationDiagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for record in records {
+        for rule in &ruleset.rules {
+            let configured = ruleset.overrides.get(rule.name());
+            if matches!(configured, Some(None)) {
+                continue;
+            }
+
+            for mut diagnostic in rule.check(record) {
+                if let Some(Some(severity)) = configured {
+                    diagnostic.severity = *severity;
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DataItem, FieldMap, ItemMap, ParsedValue};
+
+    fn record_with_items(category: u8, items: ItemMap) -> AsterixRecord {
+        AsterixRecord {
+            category,
+            items,
+            ..Default::default()
+        }
+    }
+
+    fn sac_sic_item(category: u8) -> (String, DataItem) {
+        let mut fields = FieldMap::new();
+        fields.insert("SAC".to_string(), ParsedValue::Integer(25));
+        fields.insert("SIC".to_string(), ParsedValue::Integer(1));
+        (format!("I{category:03}/010"), DataItem { description: None, fields })
+    }
+
+    #[test]
+    fn test_mandatory_item_rule_flags_missing_sac_sic() {
+        let record = record_with_items(48, ItemMap::new());
+        let diagnostics = MandatoryItemRule.check(&record);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, ValidationSeverity::Error);
+        assert_eq!(diagnostics[0].item.as_deref(), Some("I048/010"));
+    }
+
+    #[test]
+    fn test_mandatory_item_rule_passes_when_present() {
+        let mut items = ItemMap::new();
+        let (id, item) = sac_sic_item(48);
+        items.insert(id, item);
+        let record = record_with_items(48, items);
+        assert!(MandatoryItemRule.check(&record).is_empty());
+    }
+
+    #[test]
+    fn test_numeric_range_rule_flags_out_of_range_rho() {
+        let mut fields = FieldMap::new();
+        fields.insert("RHO".to_string(), ParsedValue::Float(999.0));
+        let mut items = ItemMap::new();
+        items.insert("I048/040".to_string(), DataItem { description: None, fields });
+        let record = record_with_items(48, items);
+
+        let diagnostics = NumericRangeRule.check(&record);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("RHO"));
+    }
+
+    #[test]
+    fn test_numeric_range_rule_ignores_other_categories() {
+        let mut fields = FieldMap::new();
+        fields.insert("RHO".to_string(), ParsedValue::Float(999.0));
+        let mut items = ItemMap::new();
+        items.insert("I062/040".to_string(), DataItem { description: None, fields });
+        let record = record_with_items(62, items);
+        assert!(NumericRangeRule.check(&record).is_empty());
+    }
+
+    #[test]
+    fn test_dependency_rule_flags_missing_required_item() {
+        let mut items = ItemMap::new();
+        items.insert(
+            "I048/130".to_string(),
+            DataItem {
+                description: None,
+                fields: FieldMap::new(),
+            },
+        );
+        let record = record_with_items(48, items);
+        let rule = DependencyRule::new("test-dependency", "I048/130", "I048/040");
+        let diagnostics = rule.check(&record);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, ValidationSeverity::Warning);
+    }
+
+    #[test]
+    fn test_dependency_rule_passes_when_required_item_present() {
+        let mut items = ItemMap::new();
+        items.insert(
+            "I048/130".to_string(),
+            DataItem {
+                description: None,
+                fields: FieldMap::new(),
+            },
+        );
+        items.insert(
+            "I048/040".to_string(),
+            DataItem {
+                description: None,
+                fields: FieldMap::new(),
+            },
+        );
+        let record = record_with_items(48, items);
+        let rule = DependencyRule::new("test-dependency", "I048/130", "I048/040");
+        assert!(rule.check(&record).is_empty());
+    }
+
+    #[test]
+    fn test_fspec_consistency_rule_flags_item_count_mismatch() {
+        // category=48, len=4, FSPEC octet 0x80 (one presence bit, no FX) —
+        // declares exactly one item present, but `items` below has none.
+        let record = AsterixRecord {
+            category: 48,
+            hex_data: "30000480".to_string(),
+            items: ItemMap::new(),
+            ..Default::default()
+        };
+
+        let diagnostics = FspecConsistencyRule.check(&record);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("declares 1 item"));
+    }
+
+    #[test]
+    fn test_fspec_consistency_rule_passes_when_counts_match() {
+        let mut items = ItemMap::new();
+        let (id, item) = sac_sic_item(48);
+        items.insert(id, item);
+
+        let record = AsterixRecord {
+            category: 48,
+            hex_data: "30000480".to_string(),
+            items,
+            ..Default::default()
+        };
+
+        assert!(FspecConsistencyRule.check(&record).is_empty());
+    }
+
+    #[test]
+    fn test_ruleset_silence_suppresses_findings() {
+        let record = record_with_items(48, ItemMap::new());
+        let ruleset = RuleSet::new().with_rule(MandatoryItemRule).silence("mandatory-item-presence");
+        assert!(validate(&[record], &ruleset).is_empty());
+    }
+
+    #[test]
+    fn test_ruleset_with_severity_overrides_reported_severity() {
+        let record = record_with_items(48, ItemMap::new());
+        let ruleset = RuleSet::new()
+            .with_rule(MandatoryItemRule)
+            .with_severity("mandatory-item-presence", ValidationSeverity::Info);
+        let diagnostics = validate(&[record], &ruleset);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, ValidationSeverity::Info);
+    }
+
+    #[test]
+    fn test_validation_severity_orders_info_below_warning_below_error() {
+        assert!(ValidationSeverity::Info < ValidationSeverity::Warning);
+        assert!(ValidationSeverity::Warning < ValidationSeverity::Error);
+    }
+}
@@ -24,6 +24,7 @@ fn test_parse_cat048_raw() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let records = parse(&data, options).expect("Failed to parse cat048.raw");
@@ -75,6 +76,7 @@ fn test_parse_pcap_format() {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let records = parse(&data, options).expect("Failed to parse PCAP");
@@ -157,7 +159,7 @@ fn test_error_handling_invalid_data() {
     assert!(result.is_err(), "Expected parse error for invalid data");
 
     match result {
-        Err(AsterixError::ParseError { offset, message }) => {
+        Err(AsterixError::ParseError { offset, message, .. }) => {
             println!(
                 "✓ Correctly rejected invalid data at offset {} ({})",
                 offset, message
@@ -263,6 +265,7 @@ fn test_parse_with_category_filter() {
         verbose: false,
         filter_category: Some(62),
         max_records: None,
+        ..Default::default()
     };
 
     let records = parse(&data, options).expect("Failed to parse with filter");
@@ -287,6 +290,7 @@ fn test_parse_with_max_records() {
         verbose: false,
         filter_category: None,
         max_records: Some(5),
+        ..Default::default()
     };
 
     let records = parse(&data, options).expect("Failed to parse with limit");
@@ -546,6 +550,7 @@ fn test_verbose_mode() {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let records = parse(&data, options).expect("Failed to parse with verbose");
@@ -581,6 +586,85 @@ fn test_compare_with_python_output() {
     println!("✓ Output structure matches Python expectations");
 }
 
+#[test]
+fn test_encode_round_trips_cat048_raw() {
+    use asterix::{encode, records_approx_eq, EncodeOptions};
+
+    let path = sample_data_path("cat048.raw");
+    let data = fs::read(&path).expect("Failed to read cat048.raw");
+
+    let original = parse(&data, ParseOptions::default()).expect("Failed to parse cat048.raw");
+    assert!(!original.is_empty(), "Expected at least one record");
+
+    let re_encoded = encode(&original, EncodeOptions::default()).expect("Failed to encode");
+    // `hex_data` is populated by `parse`, so `encode` re-emits the captured
+    // bytes verbatim (see the `encode` module docs) and this round trip
+    // should reproduce the file byte-for-byte.
+    assert_eq!(data, re_encoded);
+
+    let reparsed = parse(&re_encoded, ParseOptions::default()).expect("Failed to reparse encoded data");
+
+    assert_eq!(original.len(), reparsed.len());
+    for (original_record, reparsed_record) in original.iter().zip(reparsed.iter()) {
+        assert_eq!(original_record.category, reparsed_record.category);
+        assert!(
+            records_approx_eq(&original_record.items, &reparsed_record.items, 1e-9),
+            "round-tripped item/field values diverged for category {}",
+            original_record.category
+        );
+    }
+}
+
+#[test]
+fn test_validate_cat048_raw_yields_no_errors() {
+    use asterix::{validate, RuleSet, ValidationSeverity};
+
+    let path = sample_data_path("cat048.raw");
+    let data = fs::read(&path).expect("Failed to read cat048.raw");
+    let records = parse(&data, ParseOptions::default()).expect("Failed to parse cat048.raw");
+
+    let ruleset = RuleSet::with_builtin_rules();
+    let diagnostics = validate(&records, &ruleset);
+
+    let errors: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.severity == ValidationSeverity::Error)
+        .collect();
+    assert!(errors.is_empty(), "cat048.raw should validate clean, got: {errors:?}");
+}
+
+#[test]
+fn test_validate_flags_deliberately_corrupted_record() {
+    use asterix::{validate, RuleSet, ValidationSeverity};
+
+    let path = sample_data_path("cat048.raw");
+    let data = fs::read(&path).expect("Failed to read cat048.raw");
+    let records = parse(&data, ParseOptions::default()).expect("Failed to parse cat048.raw");
+    let mut corrupted = records.first().cloned().expect("Expected at least one record");
+
+    // Strip every item, simulating a record truncated/tampered after its
+    // mandatory SAC/SIC item (and every other item) was dropped, while the
+    // original FSPEC/hex_data bytes (still declaring items present) remain.
+    corrupted.items.clear();
+
+    let expected_item = format!("I{:03}/010", corrupted.category);
+    let ruleset = RuleSet::with_builtin_rules();
+    let diagnostics = validate(std::slice::from_ref(&corrupted), &ruleset);
+
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.severity == ValidationSeverity::Error && d.item.as_deref() == Some(expected_item.as_str())),
+        "expected a mandatory-item-presence diagnostic, got: {diagnostics:?}"
+    );
+    assert!(
+        diagnostics
+            .iter()
+            .any(|d| d.severity == ValidationSeverity::Error && d.item.is_none()),
+        "expected an fspec-item-consistency diagnostic, got: {diagnostics:?}"
+    );
+}
+
 #[cfg(test)]
 mod benchmarks {
     use super::*;
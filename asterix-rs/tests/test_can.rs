@@ -16,6 +16,7 @@
 #![cfg(feature = "can")]
 
 use asterix::transport::can::{CanConfig, CanError, CanFrameType, CanPublisher, CanSubscriber};
+use std::sync::mpsc;
 use std::thread;
 use std::time::Duration;
 
@@ -556,3 +557,62 @@ fn test_rapid_publish() {
         println!("Average publish time: {:?}", elapsed / success_count);
     }
 }
+
+// ============================================================================
+// Decoded Record Tests
+// ============================================================================
+
+/// Test that `CanSubscriber::recv` reassembles frames and decodes them into
+/// an `AsterixRecord` via `crate::parse`, rather than handing back raw bytes.
+#[test]
+fn test_recv_decodes_asterix_record() {
+    let config = CanConfig::new("vcan0").unwrap();
+
+    let publisher = match CanPublisher::new(config.clone()) {
+        Ok(p) => p,
+        Err(e) => {
+            println!("Skipping test: vcan0 not available - {e}");
+            return;
+        }
+    };
+
+    let mut subscriber = match CanSubscriber::new(config) {
+        Ok(s) => s,
+        Err(e) => {
+            println!("Skipping test: vcan0 not available - {e}");
+            return;
+        }
+    };
+
+    // Use category 32 so that the CAN ID's 3-bit high-band encoding is a
+    // no-op (see `build_can_id`/`parse_can_id`), and a minimal header-only
+    // CAT032 block (category byte + 2-byte big-endian length of 3) that
+    // `crate::parse` can decode without needing real item definitions.
+    subscriber.subscribe(32).unwrap();
+    let test_data = vec![32u8, 0x00, 0x03];
+
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(100));
+        for _ in 0..10 {
+            let _ = publisher.publish_raw(32, &test_data);
+            thread::sleep(Duration::from_millis(50));
+        }
+    });
+
+    // `recv` blocks indefinitely, so run it on a background thread and give
+    // it a bounded window to report back.
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let result = subscriber.recv();
+        let _ = tx.send(result);
+    });
+
+    match rx.recv_timeout(Duration::from_secs(5)) {
+        Ok(Ok(record)) => {
+            println!("Decoded record: category {}", record.category);
+            assert_eq!(record.category, 32);
+        }
+        Ok(Err(e)) => println!("No record decoded (this may be normal if vcan0 has issues): {e}"),
+        Err(_) => println!("No data received (this may be normal if vcan0 has issues)"),
+    }
+}
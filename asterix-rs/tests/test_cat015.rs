@@ -46,6 +46,7 @@ fn test_parse_cat015_packet() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat015_packet, options);
@@ -88,6 +89,7 @@ fn test_parse_cat015_with_data_items() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat015_packet, options);
@@ -166,12 +168,14 @@ fn test_cat015_api_usage() {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let options_quiet = ParseOptions {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result_verbose = parse(&cat015_packet, options_verbose);
@@ -214,6 +218,7 @@ fn test_cat015_api_usage() {
         verbose: false,
         filter_category: Some(15),
         max_records: None,
+        ..Default::default()
     };
 
     let result_filter = parse(&cat015_packet, options_filter);
@@ -47,6 +47,7 @@ fn test_parse_cat025_packet() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat025_packet, options);
@@ -89,6 +90,7 @@ fn test_parse_cat025_with_data_items() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat025_packet, options);
@@ -167,12 +169,14 @@ fn test_cat025_api_usage() {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let options_quiet = ParseOptions {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result_verbose = parse(&cat025_packet, options_verbose);
@@ -215,6 +219,7 @@ fn test_cat025_api_usage() {
         verbose: false,
         filter_category: Some(25),
         max_records: None,
+        ..Default::default()
     };
 
     let result_filter = parse(&cat025_packet, options_filter);
@@ -46,6 +46,7 @@ fn test_parse_cat031_packet() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat031_packet, options);
@@ -88,6 +89,7 @@ fn test_parse_cat031_with_data_items() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat031_packet, options);
@@ -163,12 +165,14 @@ fn test_cat031_api_usage() {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let options_quiet = ParseOptions {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result_verbose = parse(&cat031_packet, options_verbose);
@@ -197,6 +201,7 @@ fn test_cat031_api_usage() {
         verbose: false,
         filter_category: Some(31),
         max_records: None,
+        ..Default::default()
     };
 
     let result_filter = parse(&cat031_packet, options_filter);
@@ -60,6 +60,7 @@ fn test_parse_cat032_packet() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat032_packet, options);
@@ -104,6 +105,7 @@ fn test_parse_cat032_with_data_items() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat032_packet, options);
@@ -186,12 +188,14 @@ fn test_cat032_api_usage() {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let options_quiet = ParseOptions {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result_verbose = parse(&cat032_packet, options_verbose);
@@ -234,6 +238,7 @@ fn test_cat032_api_usage() {
         verbose: false,
         filter_category: Some(32),
         max_records: None,
+        ..Default::default()
     };
 
     let result_filter = parse(&cat032_packet, options_filter);
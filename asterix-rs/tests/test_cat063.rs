@@ -14,6 +14,7 @@
 //! - TC-RS-CAT063-003: Test error handling
 //! - TC-RS-CAT063-004: Test API usage
 
+use asterix::parser::parse_with_mode;
 use asterix::{parse, AsterixError, ParseOptions};
 use std::fs;
 use std::path::PathBuf;
@@ -46,6 +47,7 @@ fn test_parse_cat063_packet() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat063_packet, options);
@@ -88,6 +90,7 @@ fn test_parse_cat063_with_data_items() {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result = parse(&cat063_packet, options);
@@ -147,6 +150,35 @@ fn test_cat063_error_handling() {
     }
 }
 
+/// A CAT063 header whose length LSB is inflated past the bytes actually
+/// present should yield a structured [`AsterixError::UnexpectedEOF`], not
+/// empty output or a panic.
+///
+/// `parse` itself hands the whole buffer to the opaque C++ decoder in one
+/// call, so this crate has no way to guarantee its behavior on a declared
+/// length it never independently checks; [`parse_with_mode`] (in
+/// [`ParseMode::Strict`](asterix::types::ParseMode::Strict), the default)
+/// is the entry point that walks block framing itself and already gives
+/// this guarantee.
+#[test]
+fn test_cat063_inflated_length_yields_structured_error() {
+    // Declares 0x20 (32) bytes total but only the 3-byte header is present.
+    let inflated_packet = vec![
+        63,   // Category 63
+        0x00, // Length MSB
+        0x20, // Length LSB = 32 declared bytes, only 3 actually present
+    ];
+
+    let result = parse_with_mode(&inflated_packet, ParseOptions::default());
+    match result {
+        Err(AsterixError::UnexpectedEOF { expected, .. }) => {
+            assert_eq!(expected, 0x20 - inflated_packet.len());
+            println!("✓ Inflated CAT063 length correctly rejected as UnexpectedEOF");
+        }
+        other => panic!("expected AsterixError::UnexpectedEOF, got {other:?}"),
+    }
+}
+
 #[test]
 fn test_cat063_api_usage() {
     //! Test Rust API usage for CAT063
@@ -166,12 +198,14 @@ fn test_cat063_api_usage() {
         verbose: true,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let options_quiet = ParseOptions {
         verbose: false,
         filter_category: None,
         max_records: None,
+        ..Default::default()
     };
 
     let result_verbose = parse(&cat063_packet, options_verbose);
@@ -214,6 +248,7 @@ fn test_cat063_api_usage() {
         verbose: false,
         filter_category: Some(63),
         max_records: None,
+        ..Default::default()
     };
 
     let result_filter = parse(&cat063_packet, options_filter);
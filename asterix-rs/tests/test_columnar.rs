@@ -0,0 +1,128 @@
+//! Arrow/Parquet columnar export tests
+//!
+//! Covers the `to_arrow`/`to_record_batches`/`to_record_batch` conversions in
+//! [`asterix::columnar`], run under `--features arrow`.
+
+#![cfg(feature = "arrow")]
+
+use asterix::columnar::{records_to_arrow, to_arrow, to_record_batch, to_record_batches};
+use asterix::{AsterixRecord, DataItem, FieldMap, ItemMap, ParsedValue};
+
+fn cat048_record(sac: i64, rho: f64) -> AsterixRecord {
+    let mut fields010 = FieldMap::new();
+    fields010.insert("SAC".to_string(), ParsedValue::Integer(sac));
+    fields010.insert("SIC".to_string(), ParsedValue::Integer(1));
+
+    let mut fields040 = FieldMap::new();
+    fields040.insert("RHO".to_string(), ParsedValue::Float(rho));
+
+    let mut items = ItemMap::new();
+    items.insert("I048/010".to_string(), DataItem::new(None));
+    items.get_mut("I048/010").unwrap().fields = fields010;
+    items.insert("I048/040".to_string(), DataItem::new(None));
+    items.get_mut("I048/040").unwrap().fields = fields040;
+
+    AsterixRecord {
+        category: 48,
+        items,
+        ..Default::default()
+    }
+}
+
+fn cat062_record() -> AsterixRecord {
+    let mut fields380 = FieldMap::new();
+    fields380.insert("ADR".to_string(), ParsedValue::String("AB1234".to_string()));
+
+    let mut items = ItemMap::new();
+    items.insert("I062/380".to_string(), DataItem::new(None));
+    items.get_mut("I062/380").unwrap().fields = fields380;
+
+    AsterixRecord {
+        category: 62,
+        items,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn to_record_batches_groups_by_category_with_matching_row_counts() {
+    let records = vec![cat048_record(25, 10.5), cat048_record(26, 11.0), cat062_record()];
+
+    let batches = to_record_batches(&records).expect("batches");
+
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[&48u8].num_rows(), 2);
+    assert_eq!(batches[&62u8].num_rows(), 1);
+}
+
+#[test]
+fn to_record_batches_populates_cat048_item_010_columns() {
+    let records = vec![cat048_record(25, 10.5), cat048_record(26, 11.0)];
+
+    let batches = to_record_batches(&records).expect("batches");
+    let batch = &batches[&48u8];
+
+    let sac = batch
+        .column_by_name("I048/010.SAC")
+        .expect("SAC column present");
+    let sac = sac.as_any().downcast_ref::<arrow::array::UInt8Array>().unwrap();
+    assert_eq!(sac.value(0), 25);
+    assert_eq!(sac.value(1), 26);
+
+    let rho = batch
+        .column_by_name("I048/040.RHO")
+        .expect("RHO column present");
+    let rho = rho.as_any().downcast_ref::<arrow::array::Float64Array>().unwrap();
+    assert!((rho.value(0) - 10.5).abs() < f64::EPSILON);
+}
+
+#[test]
+fn to_record_batches_nulls_fields_missing_from_a_record() {
+    let mut bare = AsterixRecord {
+        category: 48,
+        ..Default::default()
+    };
+    bare.items = ItemMap::new();
+
+    let records = vec![cat048_record(25, 10.5), bare];
+    let batches = to_record_batches(&records).expect("batches");
+    let batch = &batches[&48u8];
+
+    let sac = batch.column_by_name("I048/010.SAC").expect("SAC column present");
+    let sac = sac.as_any().downcast_ref::<arrow::array::UInt8Array>().unwrap();
+    assert!(!sac.is_null(0));
+    assert!(sac.is_null(1));
+}
+
+#[test]
+fn to_record_batch_combines_categories_into_one_batch_with_prefixed_columns() {
+    let records = vec![cat048_record(25, 10.5), cat062_record()];
+
+    let batch = to_record_batch(&records).expect("batch");
+
+    assert_eq!(batch.num_rows(), 2);
+    assert!(batch.schema().field_with_name("cat048/I048/010.SAC").is_ok());
+    assert!(batch.schema().field_with_name("cat062/I062/380.ADR").is_ok());
+}
+
+#[test]
+fn to_arrow_is_equivalent_to_to_record_batches() {
+    let records = vec![cat048_record(25, 10.5)];
+
+    let via_alias = to_arrow(&records).expect("to_arrow");
+    let via_original = to_record_batches(&records).expect("to_record_batches");
+
+    assert_eq!(via_alias.len(), via_original.len());
+    assert_eq!(via_alias[&48u8].num_rows(), via_original[&48u8].num_rows());
+}
+
+#[test]
+fn records_to_arrow_is_equivalent_to_to_record_batch() {
+    let records = vec![cat048_record(25, 10.5), cat062_record()];
+
+    let via_alias = records_to_arrow(&records).expect("records_to_arrow");
+    let via_original = to_record_batch(&records).expect("to_record_batch");
+
+    assert_eq!(via_alias.num_rows(), via_original.num_rows());
+    assert_eq!(via_alias.schema(), via_original.schema());
+}
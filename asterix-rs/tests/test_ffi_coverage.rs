@@ -34,39 +34,21 @@ fn test_init_config_dir_empty_path() {
 fn test_init_config_dir_path_traversal_unix() {
     // Test path traversal attack prevention (Unix style)
     let result = init_config_dir("../../../etc/passwd");
-    assert!(result.is_err());
-    match result {
-        Err(AsterixError::InvalidData(msg)) => {
-            assert!(msg.contains("traversal") || msg.contains(".."));
-        }
-        _ => panic!("Expected InvalidData error for path traversal"),
-    }
+    assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
 }
 
 #[test]
 fn test_init_config_dir_path_traversal_windows() {
     // Test path traversal attack prevention (Windows style)
     let result = init_config_dir("..\\..\\..\\windows\\system32");
-    assert!(result.is_err());
-    match result {
-        Err(AsterixError::InvalidData(msg)) => {
-            assert!(msg.contains("traversal") || msg.contains(".."));
-        }
-        _ => panic!("Expected InvalidData error for path traversal"),
-    }
+    assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
 }
 
 #[test]
 fn test_init_config_dir_path_traversal_relative() {
     // Test ".." as path (edge case)
     let result = init_config_dir("..");
-    assert!(result.is_err());
-    match result {
-        Err(AsterixError::InvalidData(msg)) => {
-            assert!(msg.contains("traversal") || msg.contains(".."));
-        }
-        _ => panic!("Expected InvalidData error for '..' path"),
-    }
+    assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
 }
 
 #[test]
@@ -119,26 +101,14 @@ fn test_load_category_empty_filename() {
 fn test_load_category_path_traversal_unix() {
     // Test path traversal in filename (Unix)
     let result = load_category("../../../etc/passwd");
-    assert!(result.is_err());
-    match result {
-        Err(AsterixError::InvalidData(msg)) => {
-            assert!(msg.contains("traversal") || msg.contains(".."));
-        }
-        _ => panic!("Expected InvalidData error for path traversal"),
-    }
+    assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
 }
 
 #[test]
 fn test_load_category_path_traversal_windows() {
     // Test path traversal in filename (Windows)
     let result = load_category("..\\..\\..\\windows\\system.ini");
-    assert!(result.is_err());
-    match result {
-        Err(AsterixError::InvalidData(msg)) => {
-            assert!(msg.contains("traversal") || msg.contains(".."));
-        }
-        _ => panic!("Expected InvalidData error for path traversal"),
-    }
+    assert!(matches!(result, Err(AsterixError::PathOutsideRoot(_))));
 }
 
 #[test]
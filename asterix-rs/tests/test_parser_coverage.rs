@@ -93,7 +93,7 @@ fn test_parse_with_offset_exceeds_data_length() {
 
     assert!(result.is_err());
     match result {
-        Err(AsterixError::ParseError { offset, message }) => {
+        Err(AsterixError::ParseError { offset, message, .. }) => {
             assert_eq!(offset, 100);
             assert!(message.contains("exceeds data length"));
         }
@@ -287,6 +287,7 @@ fn test_parse_with_filter_category() {
             verbose: false,
             filter_category: Some(48),
             max_records: None,
+            ..Default::default()
         };
 
         let result = parse(&data, options);
@@ -320,6 +321,7 @@ fn test_parse_with_max_records_limit() {
             verbose: false,
             filter_category: None,
             max_records: Some(2),
+            ..Default::default()
         };
 
         let result = parse(&data, options);
@@ -351,6 +353,7 @@ fn test_parse_options_verbose() {
             verbose: true,
             filter_category: None,
             max_records: None,
+            ..Default::default()
         };
 
         let result = parse(&data, options);
@@ -176,6 +176,7 @@ fn test_parse_options_custom() {
         verbose: true,
         filter_category: Some(62),
         max_records: Some(100),
+        ..Default::default()
     };
 
     assert!(opts.verbose);
@@ -350,6 +351,7 @@ fn test_parse_options_clone() {
         verbose: true,
         filter_category: Some(62),
         max_records: Some(100),
+        ..Default::default()
     };
 
     let cloned = opts.clone();
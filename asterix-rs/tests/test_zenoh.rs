@@ -25,7 +25,7 @@ fn test_zenoh_config_default() {
         config.congestion_control,
         CongestionControl::Block
     ));
-    assert!(matches!(config.priority, Priority::Data));
+    assert!(matches!(config.priority, Priority::RealTime));
 }
 
 #[test]
@@ -155,7 +155,7 @@ fn test_priority_variants() {
 
     // Test default
     let default: Priority = Default::default();
-    assert!(matches!(default, Priority::Data));
+    assert!(matches!(default, Priority::RealTime));
 }
 
 #[test]